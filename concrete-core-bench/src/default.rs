@@ -57,6 +57,17 @@ bench! {
     ((BinaryKeyDistribution), GlweCiphertextDiscardingTrivialEncryptionFixture, (PlaintextVector, GlweCiphertextMutView)),
     ((BinaryKeyDistribution), GlweCiphertextEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextTrivialEncryptionFixture, (PlaintextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextAccumulatorGenerationFixture, (GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingAdditionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingAdditionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingSubtractionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingSubtractionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingOppositeFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingOppositeFixture, (GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorDiscardingAdditionFixture, (GlweCiphertext, PlaintextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorFusingAdditionFixture, (GlweCiphertext, PlaintextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextCleartextVectorDiscardingMultiplicationFixture, (GlweCiphertext, CleartextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextCleartextVectorFusingMultiplicationFixture, (GlweCiphertext, CleartextVector)),
     ((BinaryKeyDistribution), GlweCiphertextZeroEncryptionFixture, (GlweSecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextVectorEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
     ((BinaryKeyDistribution), GlweCiphertextVectorDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
@@ -134,6 +145,11 @@ bench! {
     ((BinaryKeyDistribution), LweCiphertextPlaintextDiscardingSubtractionFixture, (LweCiphertext, Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextPlaintextFusingSubtractionFixture, (Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingExtractionFixture, (GlweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDiscardingExtractionFixture, (GlweCiphertext, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), GlweCiphertextDiscardingKeyswitchFixture, (GlweKeyswitchKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingTraceFixture, (GlweAutomorphismKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingTracePackingFixture, (GlweAutomorphismKey,
+        LweCiphertextVector, GlweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchFixture, (LweCiphertextVector,
         LwePackingKeyswitchKey, GlweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextCreationFixture, (LweCiphertext, Vec)),
@@ -168,6 +184,11 @@ bench! {
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededKeyswitchKeyGenerationFixture, (LweSecretKey, LweSecretKey, LweSeededKeyswitchKey)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededKeyswitchKeyToLweKeyswitchKeyTransformationFixture,(LweSecretKey, LweSecretKey, LweSeededKeyswitchKey, LweKeyswitchKey)),
     ((BinaryKeyDistribution), LweToGlweSecretKeyTransformationFixture, (LweSecretKey, GlweSecretKey)),
+    ((BinaryKeyDistribution), LweCiphertextGswCiphertextExternalProductFixture, (LweCiphertext, GswCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextsGswCiphertextFusingCmuxFixture, (LweCiphertext, LweCiphertext, GswCiphertext)),
+    ((BinaryKeyDistribution), GgswCiphertextVectorScalarEncryptionFixture, (GlweSecretKey, PlaintextVector, GgswCiphertextVector)),
+    ((BinaryKeyDistribution), GgswSeededCiphertextToGgswCiphertextTransformationFixture, (GgswSeededCiphertext,
+        GgswCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchFixture,
         (LweCiphertextVector, LwePrivateFunctionalPackingKeyswitchKey, GlweCiphertext)),
     ((), PlaintextCreationFixture, (Plaintext)),
@@ -212,6 +233,7 @@ bench_parallel! {
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweBootstrapKeyGenerationFixture, (LweSecretKey, GlweSecretKey, LweBootstrapKey)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededBootstrapKeyGenerationFixture, (LweSecretKey, GlweSecretKey, LweSeededBootstrapKey)),
     ((BinaryKeyDistribution), LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDiscardingExtractionFixture, (GlweCiphertext, LweCiphertextVector)),
     ((BinaryKeyDistribution), LwePublicKeyGenerationFixture, (LweSecretKey, LwePublicKey)),
     ((BinaryKeyDistribution, BinaryKeyDistribution),
     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationFixture,
@@ -37,15 +37,63 @@ macro_rules! bench {
 #[cfg(feature = "backend_fft")]
 bench! {
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture1, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextMultiValueBootstrapFixture, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBlindRotationFixture, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture2, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FftFourierGgswCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextsGgswCiphertextFusingCmuxFixture, (GlweCiphertext,
         GlweCiphertext, FftFourierGgswCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextTensorProductFixture, (GlweCiphertext, GlweCiphertext,
+        GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextMultiplicationFixture, (GlweCiphertext, GlweCiphertext,
+        FftFourierGlweRelinearizationKey, GlweCiphertext)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), GlweCiphertextDiscardingKeyswitchFixture,
+        (FftFourierGlweKeyswitchKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingTraceFixture,
+        (FftFourierGlweAutomorphismKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingTracePackingFixture,
+        (FftFourierGlweAutomorphismKey, LweCiphertextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), GgswCiphertextVectorConversionFixture, (GgswCiphertextVector,
+        FftFourierGgswCiphertextVector)),
+    ((BinaryKeyDistribution), GgswSeededCiphertextConversionFixture, (GgswSeededCiphertext,
+        FftFourierGgswCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingCmuxTreeFixture, (FftFourierGgswCiphertextVector,
+        PlaintextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextDiscardingVerticalPackingFixture, (FftFourierGgswCiphertextVector,
+        PlaintextVector, LweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBitExtractFixture,
         (FftFourierLweBootstrapKey, LweKeyswitchKey, LweCiphertext, LweCiphertextVector)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingFixture,
         (FftFourierLweBootstrapKey, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
-        PlaintextVector, LweCiphertextVectorView, LweCiphertextVectorMutView))
+        PlaintextVector, LweCiphertextVectorView, LweCiphertextVectorMutView)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingAndFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingNandFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingNorFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingOrFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingXorFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingXnorFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingAndFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingNandFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingNorFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingOrFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingXorFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingXnorFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingMuxFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution), LweCiphertextDiscardingNotFixture,
+        (LweCiphertext, LweCiphertext))
 }
 
 #[cfg(feature = "backend_fft_parallel")]
@@ -80,5 +128,17 @@ macro_rules! bench_parallel {
 bench_parallel! {
     ((BinaryKeyDistribution, BinaryKeyDistribution),
         LweCiphertextVectorDiscardingBootstrapFixture1, (FftFourierLweBootstrapKey,
-        GlweCiphertextVector, LweCiphertextVector, LweCiphertextVector))
+        GlweCiphertextVector, LweCiphertextVector, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingAndFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingNandFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingNorFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingOrFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingXorFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingXnorFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey))
 }
@@ -0,0 +1,176 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGgswCiphertextVector, PrototypesGlweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::SynthesizesGgswCiphertextVector;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount,
+    GgswCiphertextVectorConversionEngine, GgswCiphertextVectorEntity, GlweDimension,
+    PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `GgswCiphertextVectorConversionEngine` trait.
+pub struct GgswCiphertextVectorConversionFixture;
+
+#[derive(Debug)]
+pub struct GgswCiphertextVectorConversionParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub count: GgswCiphertextCount,
+}
+
+impl<Precision, KeyDistribution, Engine, InputCiphertextVector, OutputCiphertextVector>
+    Fixture<Precision, (KeyDistribution,), Engine, (InputCiphertextVector, OutputCiphertextVector)>
+    for GgswCiphertextVectorConversionFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GgswCiphertextVectorConversionEngine<InputCiphertextVector, OutputCiphertextVector>,
+    InputCiphertextVector: GgswCiphertextVectorEntity,
+    OutputCiphertextVector: GgswCiphertextVectorEntity,
+    Maker: SynthesizesGgswCiphertextVector<Precision, KeyDistribution, InputCiphertextVector>
+        + SynthesizesGgswCiphertextVector<Precision, KeyDistribution, OutputCiphertextVector>,
+{
+    type Parameters = GgswCiphertextVectorConversionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGgswCiphertextVector<
+            Precision,
+            KeyDistribution,
+        >>::GgswCiphertextVectorProto,
+    );
+    type SamplePrototypes = ();
+    type PreExecutionContext = (InputCiphertextVector,);
+    type PostExecutionContext = (InputCiphertextVector, OutputCiphertextVector);
+    type Criteria = ();
+    type Outcome = (
+        GgswCiphertextCount,
+        GlweDimension,
+        PolynomialSize,
+        DecompositionLevelCount,
+        DecompositionBaseLog,
+    );
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GgswCiphertextVectorConversionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    count: GgswCiphertextCount(1),
+                },
+                GgswCiphertextVectorConversionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_level_count: DecompositionLevelCount(2),
+                    decomposition_base_log: DecompositionBaseLog(10),
+                    count: GgswCiphertextCount(10),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let raw_plaintext_vector = Precision::Raw::uniform_between_vec(0..2, parameters.count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_ciphertext_vector = maker.encrypt_plaintext_vector_to_ggsw_ciphertext_vector(
+            &proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+        );
+        (proto_ciphertext_vector,)
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_ciphertext_vector,) = repetition_proto;
+        let synth_ciphertext_vector =
+            maker.synthesize_ggsw_ciphertext_vector(proto_ciphertext_vector);
+        (synth_ciphertext_vector,)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext_vector,) = context;
+        let output_ciphertext_vector =
+            unsafe { engine.convert_ggsw_ciphertext_vector_unchecked(&input_ciphertext_vector) };
+        (input_ciphertext_vector, output_ciphertext_vector)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext_vector, output_ciphertext_vector) = context;
+        let outcome = (
+            output_ciphertext_vector.ggsw_ciphertext_count(),
+            output_ciphertext_vector.glwe_dimension(),
+            output_ciphertext_vector.polynomial_size(),
+            output_ciphertext_vector.decomposition_level_count(),
+            output_ciphertext_vector.decomposition_base_log(),
+        );
+        maker.destroy_ggsw_ciphertext_vector(input_ciphertext_vector);
+        maker.destroy_ggsw_ciphertext_vector(output_ciphertext_vector);
+        outcome
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        // The backward conversion is not available yet, so we only check that the converted vector
+        // has the expected shape.
+        outputs.iter().all(|outcome| {
+            *outcome
+                == (
+                    parameters.count,
+                    parameters.glwe_dimension,
+                    parameters.polynomial_size,
+                    parameters.decomposition_level_count,
+                    parameters.decomposition_base_log,
+                )
+        })
+    }
+}
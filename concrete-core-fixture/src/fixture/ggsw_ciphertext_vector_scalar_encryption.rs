@@ -0,0 +1,179 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{PrototypesGlweSecretKey, PrototypesPlaintextVector};
+use crate::generation::synthesizing::{
+    SynthesizesGgswCiphertextVector, SynthesizesGlweSecretKey, SynthesizesPlaintextVector,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GgswCiphertextVectorEntity,
+    GgswCiphertextVectorScalarEncryptionEngine, GlweDimension, GlweSecretKeyEntity,
+    PlaintextVectorEntity, PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `GgswCiphertextVectorScalarEncryptionEngine` trait.
+pub struct GgswCiphertextVectorScalarEncryptionFixture;
+
+#[derive(Debug)]
+pub struct GgswCiphertextVectorScalarEncryptionParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub count: GgswCiphertextCount,
+}
+
+impl<Precision, KeyDistribution, Engine, SecretKey, PlaintextVector, CiphertextVector>
+    Fixture<Precision, (KeyDistribution,), Engine, (SecretKey, PlaintextVector, CiphertextVector)>
+    for GgswCiphertextVectorScalarEncryptionFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine:
+        GgswCiphertextVectorScalarEncryptionEngine<SecretKey, PlaintextVector, CiphertextVector>,
+    SecretKey: GlweSecretKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    CiphertextVector: GgswCiphertextVectorEntity,
+    Maker: SynthesizesGlweSecretKey<Precision, KeyDistribution, SecretKey>
+        + SynthesizesPlaintextVector<Precision, PlaintextVector>
+        + SynthesizesGgswCiphertextVector<Precision, KeyDistribution, CiphertextVector>,
+{
+    type Parameters = GgswCiphertextVectorScalarEncryptionParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,);
+    type SamplePrototypes =
+        (<Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,);
+    type PreExecutionContext = (SecretKey, PlaintextVector);
+    type PostExecutionContext = (SecretKey, PlaintextVector, CiphertextVector);
+    type Criteria = ();
+    type Outcome = (
+        GgswCiphertextCount,
+        GlweDimension,
+        PolynomialSize,
+        DecompositionLevelCount,
+        DecompositionBaseLog,
+    );
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GgswCiphertextVectorScalarEncryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    count: GgswCiphertextCount(1),
+                },
+                GgswCiphertextVectorScalarEncryptionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_level_count: DecompositionLevelCount(2),
+                    decomposition_base_log: DecompositionBaseLog(10),
+                    count: GgswCiphertextCount(10),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_plaintext_vector = Precision::Raw::uniform_between_vec(0..2, parameters.count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        (proto_plaintext_vector,)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext_vector,) = sample_proto;
+        let synth_secret_key = maker.synthesize_glwe_secret_key(proto_secret_key);
+        let synth_plaintext_vector = maker.synthesize_plaintext_vector(proto_plaintext_vector);
+        (synth_secret_key, synth_plaintext_vector)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, plaintext_vector) = context;
+        let ciphertext_vector = unsafe {
+            engine.encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+                &secret_key,
+                &plaintext_vector,
+                parameters.noise,
+                parameters.decomposition_level_count,
+                parameters.decomposition_base_log,
+            )
+        };
+        (secret_key, plaintext_vector, ciphertext_vector)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (secret_key, plaintext_vector, ciphertext_vector) = context;
+        let outcome = (
+            ciphertext_vector.ggsw_ciphertext_count(),
+            ciphertext_vector.glwe_dimension(),
+            ciphertext_vector.polynomial_size(),
+            ciphertext_vector.decomposition_level_count(),
+            ciphertext_vector.decomposition_base_log(),
+        );
+        maker.destroy_ggsw_ciphertext_vector(ciphertext_vector);
+        maker.destroy_plaintext_vector(plaintext_vector);
+        maker.destroy_glwe_secret_key(secret_key);
+        outcome
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        // The GGSW ciphertexts can not be decrypted yet, so we only check that the vector was
+        // generated with the expected shape.
+        outputs.iter().all(|outcome| {
+            *outcome
+                == (
+                    parameters.count,
+                    parameters.glwe_dimension,
+                    parameters.polynomial_size,
+                    parameters.decomposition_level_count,
+                    parameters.decomposition_base_log,
+                )
+        })
+    }
+}
@@ -0,0 +1,169 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGgswSeededCiphertext, PrototypesGlweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{SynthesizesGgswCiphertext, SynthesizesGgswSeededCiphertext};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextEntity,
+    GgswSeededCiphertextConversionEngine, GgswSeededCiphertextEntity, GlweDimension,
+    PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `GgswSeededCiphertextConversionEngine` trait.
+pub struct GgswSeededCiphertextConversionFixture;
+
+#[derive(Debug)]
+pub struct GgswSeededCiphertextConversionParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+}
+
+impl<Precision, KeyDistribution, Engine, InputCiphertext, OutputCiphertext>
+    Fixture<Precision, (KeyDistribution,), Engine, (InputCiphertext, OutputCiphertext)>
+    for GgswSeededCiphertextConversionFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GgswSeededCiphertextConversionEngine<InputCiphertext, OutputCiphertext>,
+    InputCiphertext: GgswSeededCiphertextEntity,
+    OutputCiphertext: GgswCiphertextEntity,
+    Maker: SynthesizesGgswSeededCiphertext<Precision, KeyDistribution, InputCiphertext>
+        + SynthesizesGgswCiphertext<Precision, KeyDistribution, OutputCiphertext>,
+{
+    type Parameters = GgswSeededCiphertextConversionParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (
+        <Maker as PrototypesGgswSeededCiphertext<
+            Precision,
+            KeyDistribution,
+        >>::GgswSeededCiphertextProto,
+    );
+    type PreExecutionContext = (InputCiphertext,);
+    type PostExecutionContext = (InputCiphertext, OutputCiphertext);
+    type Criteria = ();
+    type Outcome = (
+        GlweDimension,
+        PolynomialSize,
+        DecompositionLevelCount,
+        DecompositionBaseLog,
+    );
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GgswSeededCiphertextConversionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                },
+                GgswSeededCiphertextConversionParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_level_count: DecompositionLevelCount(2),
+                    decomposition_base_log: DecompositionBaseLog(10),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let raw_plaintext = Precision::Raw::uniform_between(0..2);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_seeded_ciphertext = maker.encrypt_plaintext_to_ggsw_seeded_ciphertext(
+            &proto_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+        );
+        (proto_seeded_ciphertext,)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_seeded_ciphertext,) = sample_proto;
+        let synth_seeded_ciphertext =
+            maker.synthesize_ggsw_seeded_ciphertext(proto_seeded_ciphertext);
+        (synth_seeded_ciphertext,)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (seeded_ciphertext,) = context;
+        let ciphertext =
+            unsafe { engine.convert_ggsw_seeded_ciphertext_unchecked(&seeded_ciphertext) };
+        (seeded_ciphertext, ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (seeded_ciphertext, ciphertext) = context;
+        let outcome = (
+            ciphertext.glwe_dimension(),
+            ciphertext.polynomial_size(),
+            ciphertext.decomposition_level_count(),
+            ciphertext.decomposition_base_log(),
+        );
+        maker.destroy_ggsw_seeded_ciphertext(seeded_ciphertext);
+        maker.destroy_ggsw_ciphertext(ciphertext);
+        outcome
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        // The backward conversion is not available yet, so we only check that the converted
+        // ciphertext has the expected shape.
+        outputs.iter().all(|outcome| {
+            *outcome
+                == (
+                    parameters.glwe_dimension,
+                    parameters.polynomial_size,
+                    parameters.decomposition_level_count,
+                    parameters.decomposition_base_log,
+                )
+        })
+    }
+}
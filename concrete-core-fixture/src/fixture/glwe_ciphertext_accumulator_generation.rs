@@ -0,0 +1,167 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::PrototypesGlweCiphertext;
+use crate::generation::synthesizing::SynthesizesGlweCiphertext;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_core::prelude::{
+    GlweCiphertextAccumulatorGenerationEngine, GlweCiphertextEntity, GlweDimension,
+    MessageBitsCount, PaddingBitsCount, PolynomialSize,
+};
+
+/// A fixture for the types implementing the `GlweCiphertextAccumulatorGenerationEngine` trait.
+pub struct GlweCiphertextAccumulatorGenerationFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextAccumulatorGenerationParameters {
+    pub message_bits: MessageBitsCount,
+    pub padding_bits: PaddingBitsCount,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+}
+
+impl<Precision, KeyDistribution, Engine, Accumulator>
+    Fixture<Precision, (KeyDistribution,), Engine, (Accumulator,)>
+    for GlweCiphertextAccumulatorGenerationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextAccumulatorGenerationEngine<Accumulator, Precision::Raw>,
+    Accumulator: GlweCiphertextEntity,
+    Maker: SynthesizesGlweCiphertext<Precision, KeyDistribution, Accumulator>,
+{
+    type Parameters = GlweCiphertextAccumulatorGenerationParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = ();
+    type PreExecutionContext = ();
+    type PostExecutionContext = (Accumulator,);
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>,);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextAccumulatorGenerationParameters {
+                    message_bits: MessageBitsCount(2),
+                    padding_bits: PaddingBitsCount(1),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(512),
+                },
+                GlweCiphertextAccumulatorGenerationParameters {
+                    message_bits: MessageBitsCount(4),
+                    padding_bits: PaddingBitsCount(1),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweCiphertextAccumulatorGenerationParameters {
+                    message_bits: MessageBitsCount(3),
+                    padding_bits: PaddingBitsCount(2),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        _context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let message_bits = parameters.message_bits;
+        let accumulator = unsafe {
+            engine.generate_new_glwe_ciphertext_accumulator_unchecked(
+                &|m| lookup_function(m, message_bits),
+                parameters.message_bits,
+                parameters.padding_bits,
+                parameters.glwe_dimension,
+                parameters.polynomial_size,
+            )
+        };
+        (accumulator,)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (accumulator,) = context;
+        let proto_accumulator = maker.unsynthesize_glwe_ciphertext(accumulator);
+        let proto_plaintext_vector = maker.trivially_decrypt_glwe_ciphertext(&proto_accumulator);
+        (maker.transform_plaintext_vector_to_raw_vec(&proto_plaintext_vector),)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let expected = expected_lookup_table::<Precision::Raw>(parameters);
+        outputs.iter().all(|(actual,)| *actual == expected)
+    }
+}
+
+// The function evaluated by the accumulators of the fixture: m -> m^2 + 1 on the message space.
+fn lookup_function<Raw: RawUnsignedIntegers>(m: Raw, message_bits: MessageBitsCount) -> Raw {
+    let message_mask = (Raw::ONE << message_bits.0) - Raw::ONE;
+    m.wrapping_mul(m).wrapping_add(Raw::ONE) & message_mask
+}
+
+// Computes the lookup table expected for the parameters, coefficient by coefficient. The
+// coefficient `i` of the accumulator should hold the encoded image of the box containing
+// `i + box_size / 2`, the coefficients wrapping around the polynomial being negated.
+fn expected_lookup_table<Raw: RawUnsignedIntegers>(
+    parameters: &GlweCiphertextAccumulatorGenerationParameters,
+) -> Vec<Raw> {
+    let message_bits = parameters.message_bits.0;
+    let padding_bits = parameters.padding_bits.0;
+    let polynomial_size = parameters.polynomial_size.0;
+    let delta_log = Raw::BITS - message_bits - padding_bits;
+    let box_size = polynomial_size >> (message_bits + padding_bits - 1);
+    let message_mask = (1 << message_bits) - 1;
+    let encoded_image = |index: usize| {
+        let message = Raw::cast_from(((index / box_size) & message_mask) as f64);
+        lookup_function(message, parameters.message_bits) << delta_log
+    };
+    (0..polynomial_size)
+        .map(|i| i + box_size / 2)
+        .map(|index| {
+            if index < polynomial_size {
+                encoded_image(index)
+            } else {
+                encoded_image(index - polynomial_size).wrapping_neg()
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,223 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesCleartextVector, PrototypesGlweCiphertext, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesCleartextVector, SynthesizesGlweCiphertext};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::commons::numeric::UnsignedInteger;
+use concrete_core::prelude::{
+    CleartextVectorEntity, DispersionParameter,
+    GlweCiphertextCleartextVectorDiscardingMultiplicationEngine, GlweCiphertextEntity,
+    GlweDimension, LogStandardDev, PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the
+/// `GlweCiphertextCleartextVectorDiscardingMultiplicationEngine` trait.
+pub struct GlweCiphertextCleartextVectorDiscardingMultiplicationFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextCleartextVectorDiscardingMultiplicationParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub cleartext_width: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, InputCiphertext, CleartextVector, OutputCiphertext>
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (InputCiphertext, CleartextVector, OutputCiphertext),
+    > for GlweCiphertextCleartextVectorDiscardingMultiplicationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextCleartextVectorDiscardingMultiplicationEngine<
+        InputCiphertext,
+        CleartextVector,
+        OutputCiphertext,
+    >,
+    InputCiphertext: GlweCiphertextEntity,
+    CleartextVector: CleartextVectorEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    Maker: SynthesizesGlweCiphertext<Precision, KeyDistribution, InputCiphertext>
+        + SynthesizesCleartextVector<Precision, CleartextVector>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, OutputCiphertext>,
+{
+    type Parameters = GlweCiphertextCleartextVectorDiscardingMultiplicationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesCleartextVector<Precision>>::CleartextVectorProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (InputCiphertext, CleartextVector, OutputCiphertext);
+    type PostExecutionContext = (InputCiphertext, CleartextVector, OutputCiphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextCleartextVectorDiscardingMultiplicationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-15.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    cleartext_width: 8,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let raw_cleartext_vector = Precision::Raw::uniform_zero_centered_vec(
+            parameters.cleartext_width,
+            parameters.polynomial_size.0,
+        );
+        let proto_cleartext_vector =
+            maker.transform_raw_vec_to_cleartext_vector(raw_cleartext_vector.as_slice());
+        (proto_secret_key, proto_cleartext_vector)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.polynomial_size.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_input_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        let proto_output_ciphertext = maker.trivially_encrypt_zeros_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+        );
+        (
+            proto_plaintext_vector,
+            proto_input_ciphertext,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_cleartext_vector) = repetition_proto;
+        let (_, proto_input_ciphertext, proto_output_ciphertext) = sample_proto;
+        (
+            maker.synthesize_glwe_ciphertext(proto_input_ciphertext),
+            maker.synthesize_cleartext_vector(proto_cleartext_vector),
+            maker.synthesize_glwe_ciphertext(proto_output_ciphertext),
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext, cleartext_vector, mut output_ciphertext) = context;
+        unsafe {
+            engine.discard_mul_glwe_ciphertext_cleartext_vector_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &cleartext_vector,
+            )
+        };
+        (input_ciphertext, cleartext_vector, output_ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext, cleartext_vector, output_ciphertext) = context;
+        let (proto_plaintext_vector, ..) = sample_proto;
+        let (proto_secret_key, proto_cleartext_vector) = repetition_proto;
+        let raw_plaintext_vector =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector);
+        let raw_cleartext_vector =
+            maker.transform_cleartext_vector_to_raw_vec(proto_cleartext_vector);
+        let expected_mean = negacyclic_product(&raw_plaintext_vector, &raw_cleartext_vector);
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_glwe_ciphertext(input_ciphertext);
+        maker.destroy_cleartext_vector(cleartext_vector);
+        (
+            expected_mean,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let (_, proto_cleartext_vector) = repetition_proto;
+        let raw_cleartext_vector =
+            maker.transform_cleartext_vector_to_raw_vec(proto_cleartext_vector);
+        let predicted_variance: Variance =
+            concrete_npe::estimate_polynomial_plaintext_multiplication_noise::<Precision::Raw, _>(
+                parameters.noise,
+                &raw_cleartext_vector,
+            );
+        (predicted_variance,)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+/// Computes the product of two polynomials modulo $X^N + 1$, given by their coefficients.
+pub(crate) fn negacyclic_product<Raw: UnsignedInteger>(lhs: &[Raw], rhs: &[Raw]) -> Vec<Raw> {
+    let size = lhs.len();
+    let mut output = vec![Raw::ZERO; size];
+    for (i, lhs_coef) in lhs.iter().enumerate() {
+        for (j, rhs_coef) in rhs.iter().enumerate() {
+            let product = lhs_coef.wrapping_mul(*rhs_coef);
+            if i + j < size {
+                output[i + j] = output[i + j].wrapping_add(product);
+            } else {
+                output[i + j - size] = output[i + j - size].wrapping_sub(product);
+            }
+        }
+    }
+    output
+}
@@ -0,0 +1,223 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGgswCiphertextVector, PrototypesGlweCiphertext, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGgswCiphertextVector, SynthesizesGlweCiphertext, SynthesizesPlaintextVector,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_core::commons::math::decomposition::SignedDecomposer;
+use concrete_core::commons::numeric::UnsignedInteger;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GgswCiphertextCount,
+    GgswCiphertextVectorEntity, GlweCiphertextDiscardingCmuxTreeEngine, GlweCiphertextEntity,
+    GlweDimension, LogStandardDev, PlaintextVectorEntity, PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `GlweCiphertextDiscardingCmuxTreeEngine` trait.
+pub struct GlweCiphertextDiscardingCmuxTreeFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextDiscardingCmuxTreeParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub ggsw_count: GgswCiphertextCount,
+}
+
+// The number of bits of message stored in the look-up tables, a bit of padding is kept on top.
+const MESSAGE_BITS: usize = 4;
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, GgswCiphertextVector, LookUpTables, OutputCiphertext>
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (GgswCiphertextVector, LookUpTables, OutputCiphertext),
+    > for GlweCiphertextDiscardingCmuxTreeFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextDiscardingCmuxTreeEngine<
+        GgswCiphertextVector,
+        LookUpTables,
+        OutputCiphertext,
+    >,
+    GgswCiphertextVector: GgswCiphertextVectorEntity,
+    LookUpTables: PlaintextVectorEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    Maker: SynthesizesGgswCiphertextVector<Precision, KeyDistribution, GgswCiphertextVector>
+        + SynthesizesPlaintextVector<Precision, LookUpTables>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, OutputCiphertext>,
+{
+    type Parameters = GlweCiphertextDiscardingCmuxTreeParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        Vec<Precision::Raw>,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesGgswCiphertextVector<
+            Precision,
+            KeyDistribution,
+        >>::GgswCiphertextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        usize,
+    );
+    type PreExecutionContext = (GgswCiphertextVector, LookUpTables, OutputCiphertext);
+    type PostExecutionContext = (GgswCiphertextVector, LookUpTables, OutputCiphertext);
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextDiscardingCmuxTreeParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-30.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    ggsw_count: GgswCiphertextCount(1),
+                },
+                GlweCiphertextDiscardingCmuxTreeParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-30.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    ggsw_count: GgswCiphertextCount(5),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        // Each look-up table is a constant polynomial holding a random message.
+        let raw_lut_values: Vec<Precision::Raw> =
+            Precision::Raw::uniform_between_vec(0..1 << MESSAGE_BITS, 1 << parameters.ggsw_count.0)
+                .into_iter()
+                .map(|message| message << (Precision::Raw::BITS - MESSAGE_BITS - 1))
+                .collect();
+        let raw_luts: Vec<Precision::Raw> = raw_lut_values
+            .iter()
+            .flat_map(|value| vec![*value; parameters.polynomial_size.0])
+            .collect();
+        let proto_luts = maker.transform_raw_vec_to_plaintext_vector(&raw_luts);
+        (proto_secret_key, proto_luts, raw_lut_values)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, ..) = repetition_proto;
+        // The selector bits are encrypted from the most significant to the least significant one.
+        let raw_bits = Precision::Raw::uniform_between_vec(0..2, parameters.ggsw_count.0);
+        let index = raw_bits.iter().fold(0, |index, bit| {
+            (index << 1) | (*bit == Precision::Raw::one()) as usize
+        });
+        let proto_bits = maker.transform_raw_vec_to_plaintext_vector(&raw_bits);
+        let proto_ggsw_vector = maker.encrypt_plaintext_vector_to_ggsw_ciphertext_vector(
+            proto_secret_key,
+            &proto_bits,
+            parameters.noise,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+        );
+        let proto_output_ciphertext = maker.trivially_encrypt_zeros_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+        );
+        (proto_ggsw_vector, proto_output_ciphertext, index)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_luts, _) = repetition_proto;
+        let (proto_ggsw_vector, proto_output_ciphertext, _) = sample_proto;
+        let synth_ggsw_vector = maker.synthesize_ggsw_ciphertext_vector(proto_ggsw_vector);
+        let synth_luts = maker.synthesize_plaintext_vector(proto_luts);
+        let synth_output_ciphertext = maker.synthesize_glwe_ciphertext(proto_output_ciphertext);
+        (synth_ggsw_vector, synth_luts, synth_output_ciphertext)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ggsw_vector, luts, mut output_ciphertext) = context;
+        unsafe {
+            engine.discard_cmux_tree_glwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &luts,
+                &ggsw_vector,
+            )
+        };
+        (ggsw_vector, luts, output_ciphertext)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (ggsw_vector, luts, output_ciphertext) = context;
+        let (proto_secret_key, _, raw_lut_values) = repetition_proto;
+        let (.., index) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_ggsw_ciphertext_vector(ggsw_vector);
+        maker.destroy_plaintext_vector(luts);
+        (
+            vec![raw_lut_values[*index]; parameters.polynomial_size.0],
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let decomposer = SignedDecomposer::new(
+            DecompositionBaseLog(MESSAGE_BITS + 1),
+            DecompositionLevelCount(1),
+        );
+        outputs.iter().all(|(expected, actual)| {
+            expected
+                .iter()
+                .zip(actual.iter())
+                .all(|(e, a)| *e == decomposer.closest_representable(*a))
+        })
+    }
+}
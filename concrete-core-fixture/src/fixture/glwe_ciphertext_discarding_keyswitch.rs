@@ -0,0 +1,278 @@
+use crate::fixture::lwe_ciphertext_discarding_keyswitch::fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweKeyswitchKey, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesGlweCiphertext, SynthesizesGlweKeyswitchKey};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweCiphertextDiscardingKeyswitchEngine,
+    GlweCiphertextEntity, GlweDimension, GlweKeyswitchKeyEntity, LogStandardDev, LweDimension,
+    PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `GlweCiphertextDiscardingKeyswitchEngine` trait.
+pub struct GlweCiphertextDiscardingKeyswitchFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextDiscardingKeyswitchParameters {
+    pub n_bit_msg: usize,
+    pub input_noise: Variance,
+    pub ksk_noise: Variance,
+    pub input_glwe_dimension: GlweDimension,
+    pub output_glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub decomp_base_log: DecompositionBaseLog,
+}
+
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        KeyswitchKey,
+        InputCiphertext,
+        OutputCiphertext,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (KeyswitchKey, InputCiphertext, OutputCiphertext),
+    > for GlweCiphertextDiscardingKeyswitchFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine:
+        GlweCiphertextDiscardingKeyswitchEngine<KeyswitchKey, InputCiphertext, OutputCiphertext>,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    KeyswitchKey: GlweKeyswitchKeyEntity,
+    Maker: SynthesizesGlweKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            KeyswitchKey,
+        > + SynthesizesGlweCiphertext<Precision, InputKeyDistribution, InputCiphertext>
+        + SynthesizesGlweCiphertext<Precision, OutputKeyDistribution, OutputCiphertext>,
+{
+    type Parameters = GlweCiphertextDiscardingKeyswitchParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, InputKeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::GlweKeyswitchKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, InputKeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, OutputKeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (OutputCiphertext, InputCiphertext, KeyswitchKey);
+    type PostExecutionContext = (OutputCiphertext, InputCiphertext, KeyswitchKey);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextDiscardingKeyswitchParameters {
+                    n_bit_msg: 8,
+                    input_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-10.).get_variance(),
+                    ),
+                    ksk_noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    input_glwe_dimension: GlweDimension(2),
+                    output_glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    decomp_level_count: DecompositionLevelCount(8),
+                    decomp_base_log: DecompositionBaseLog(3),
+                },
+                GlweCiphertextDiscardingKeyswitchParameters {
+                    n_bit_msg: 8,
+                    input_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-10.).get_variance(),
+                    ),
+                    ksk_noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    input_glwe_dimension: GlweDimension(1),
+                    output_glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(512),
+                    decomp_level_count: DecompositionLevelCount(8),
+                    decomp_base_log: DecompositionBaseLog(3),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_input_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker,
+            parameters.input_glwe_dimension,
+            parameters.polynomial_size,
+        );
+        let proto_output_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker,
+            parameters.output_glwe_dimension,
+            parameters.polynomial_size,
+        );
+        let proto_keyswitch_key = maker.new_glwe_keyswitch_key(
+            &proto_input_secret_key,
+            &proto_output_secret_key,
+            parameters.decomp_level_count,
+            parameters.decomp_base_log,
+            parameters.ksk_noise,
+        );
+        (
+            proto_input_secret_key,
+            proto_output_secret_key,
+            proto_keyswitch_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_input_secret_key, ..) = repetition_proto;
+        let raw_plaintext_vector =
+            Precision::Raw::uniform_n_msb_vec(parameters.n_bit_msg, parameters.polynomial_size.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_input_ciphertext = <Maker as PrototypesGlweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_vector_to_glwe_ciphertext(
+            maker,
+            proto_input_secret_key,
+            &proto_plaintext_vector,
+            parameters.input_noise,
+        );
+        let proto_output_ciphertext = <Maker as PrototypesGlweCiphertext<
+            Precision,
+            OutputKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            maker,
+            parameters.output_glwe_dimension,
+            parameters.polynomial_size,
+        );
+        (
+            proto_plaintext_vector,
+            proto_input_ciphertext,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_keyswitch_key) = repetition_proto;
+        let (_, proto_input_ciphertext, proto_output_ciphertext) = sample_proto;
+        let synth_keyswitch_key = maker.synthesize_glwe_keyswitch_key(proto_keyswitch_key);
+        let synth_input_ciphertext = maker.synthesize_glwe_ciphertext(proto_input_ciphertext);
+        let synth_output_ciphertext = maker.synthesize_glwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_output_ciphertext,
+            synth_input_ciphertext,
+            synth_keyswitch_key,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut output_ciphertext, input_ciphertext, keyswitch_key) = context;
+        unsafe {
+            engine.discard_keyswitch_glwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &keyswitch_key,
+            )
+        };
+        (output_ciphertext, input_ciphertext, keyswitch_key)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (output_ciphertext, input_ciphertext, keyswitch_key) = context;
+        let (_, proto_output_secret_key, _) = repetition_proto;
+        let (proto_plaintext_vector, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        let proto_output_plaintext_vector = <Maker as PrototypesGlweCiphertext<
+            Precision,
+            OutputKeyDistribution,
+        >>::decrypt_glwe_ciphertext_to_plaintext_vector(
+            maker,
+            proto_output_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_glwe_ciphertext(input_ciphertext);
+        maker.destroy_glwe_keyswitch_key(keyswitch_key);
+        (
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector),
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // Every output coefficient accumulates the decomposition of all the input mask
+        // coefficients, which behaves like an LWE keyswitch of dimension k * N.
+        let predicted_variance: Variance =
+            fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+                Precision::Raw,
+                _,
+                _,
+                OutputKeyDistribution,
+            >(
+                LweDimension(parameters.input_glwe_dimension.0 * parameters.polynomial_size.0),
+                parameters.input_noise,
+                parameters.ksk_noise,
+                parameters.decomp_base_log,
+                parameters.decomp_level_count,
+            );
+        (predicted_variance,)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
@@ -0,0 +1,167 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::SynthesizesGlweCiphertext;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::commons::numeric::UnsignedInteger;
+use concrete_core::prelude::{
+    DispersionParameter, GlweCiphertextDiscardingOppositeEngine, GlweCiphertextEntity,
+    GlweDimension, LogStandardDev, PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `GlweCiphertextDiscardingOppositeEngine` trait.
+pub struct GlweCiphertextDiscardingOppositeFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextDiscardingOppositeParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, InputCiphertext, OutputCiphertext>
+    Fixture<Precision, (KeyDistribution,), Engine, (InputCiphertext, OutputCiphertext)>
+    for GlweCiphertextDiscardingOppositeFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextDiscardingOppositeEngine<InputCiphertext, OutputCiphertext>,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    Maker: SynthesizesGlweCiphertext<Precision, KeyDistribution, InputCiphertext>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, OutputCiphertext>,
+{
+    type Parameters = GlweCiphertextDiscardingOppositeParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,);
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (InputCiphertext, OutputCiphertext);
+    type PostExecutionContext = (InputCiphertext, OutputCiphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![GlweCiphertextDiscardingOppositeParameters {
+                noise: Variance(LogStandardDev::from_log_standard_dev(-15.).get_variance()),
+                glwe_dimension: GlweDimension(2),
+                polynomial_size: PolynomialSize(256),
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.polynomial_size.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_input_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        let proto_output_ciphertext = maker.trivially_encrypt_zeros_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+        );
+        (
+            proto_plaintext_vector,
+            proto_input_ciphertext,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_input_ciphertext, proto_output_ciphertext) = sample_proto;
+        (
+            maker.synthesize_glwe_ciphertext(proto_input_ciphertext),
+            maker.synthesize_glwe_ciphertext(proto_output_ciphertext),
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext, mut output_ciphertext) = context;
+        unsafe {
+            engine.discard_opp_glwe_ciphertext_unchecked(&mut output_ciphertext, &input_ciphertext)
+        };
+        (input_ciphertext, output_ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext, output_ciphertext) = context;
+        let (proto_plaintext_vector, ..) = sample_proto;
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector);
+        let expected_mean = raw_plaintext_vector
+            .iter()
+            .map(|a| a.wrapping_neg())
+            .collect();
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_glwe_ciphertext(input_ciphertext);
+        (
+            expected_mean,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.noise,)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
@@ -0,0 +1,259 @@
+use crate::fixture::lwe_ciphertext_discarding_keyswitch::fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweAutomorphismKey, PrototypesGlweCiphertext, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesGlweAutomorphismKey, SynthesizesGlweCiphertext};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweAutomorphismKeyEntity,
+    GlweCiphertextDiscardingTraceEngine, GlweCiphertextEntity, GlweDimension, LogStandardDev,
+    LweDimension, PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `GlweCiphertextDiscardingTraceEngine` trait.
+pub struct GlweCiphertextDiscardingTraceFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextDiscardingTraceParameters {
+    pub n_bit_msg: usize,
+    pub input_noise: Variance,
+    pub ak_noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub decomp_base_log: DecompositionBaseLog,
+}
+
+impl<Precision, KeyDistribution, Engine, AutomorphismKey, InputCiphertext, OutputCiphertext>
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (AutomorphismKey, InputCiphertext, OutputCiphertext),
+    > for GlweCiphertextDiscardingTraceFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextDiscardingTraceEngine<AutomorphismKey, InputCiphertext, OutputCiphertext>,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+    Maker: SynthesizesGlweAutomorphismKey<Precision, KeyDistribution, AutomorphismKey>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, InputCiphertext>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, OutputCiphertext>,
+{
+    type Parameters = GlweCiphertextDiscardingTraceParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweAutomorphismKey<Precision, KeyDistribution>>::GlweAutomorphismKeyProto,
+    );
+    type SamplePrototypes = (
+        Vec<Precision::Raw>,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (OutputCiphertext, InputCiphertext, AutomorphismKey);
+    type PostExecutionContext = (OutputCiphertext, InputCiphertext, AutomorphismKey);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextDiscardingTraceParameters {
+                    n_bit_msg: 4,
+                    input_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    ak_noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    decomp_level_count: DecompositionLevelCount(8),
+                    decomp_base_log: DecompositionBaseLog(3),
+                },
+                GlweCiphertextDiscardingTraceParameters {
+                    n_bit_msg: 4,
+                    input_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    ak_noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(512),
+                    decomp_level_count: DecompositionLevelCount(8),
+                    decomp_base_log: DecompositionBaseLog(3),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_automorphism_key = maker.new_glwe_automorphism_key(
+            &proto_secret_key,
+            parameters.decomp_level_count,
+            parameters.decomp_base_log,
+            parameters.ak_noise,
+        );
+        (proto_secret_key, proto_automorphism_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        // The trace multiplies the constant coefficient by the polynomial size, hence the messages
+        // are encoded with a scaling factor divided by N.
+        let log_poly_size = parameters.polynomial_size.log2().0;
+        let raw_plaintext_vector: Vec<Precision::Raw> =
+            Precision::Raw::uniform_n_msb_vec(parameters.n_bit_msg, parameters.polynomial_size.0)
+                .into_iter()
+                .map(|raw| raw >> log_poly_size)
+                .collect();
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_input_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.input_noise,
+        );
+        let proto_output_ciphertext = maker.trivially_encrypt_zeros_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+        );
+        // Only the constant coefficient survives the trace.
+        let mut raw_expected = Precision::Raw::zero_vec(parameters.polynomial_size.0);
+        raw_expected[0] = raw_plaintext_vector[0] << log_poly_size;
+        (
+            raw_expected,
+            proto_input_ciphertext,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_automorphism_key) = repetition_proto;
+        let (_, proto_input_ciphertext, proto_output_ciphertext) = sample_proto;
+        let synth_automorphism_key = maker.synthesize_glwe_automorphism_key(proto_automorphism_key);
+        let synth_input_ciphertext = maker.synthesize_glwe_ciphertext(proto_input_ciphertext);
+        let synth_output_ciphertext = maker.synthesize_glwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_output_ciphertext,
+            synth_input_ciphertext,
+            synth_automorphism_key,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut output_ciphertext, input_ciphertext, automorphism_key) = context;
+        unsafe {
+            engine.discard_trace_glwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &automorphism_key,
+            )
+        };
+        (output_ciphertext, input_ciphertext, automorphism_key)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (output_ciphertext, input_ciphertext, automorphism_key) = context;
+        let (proto_secret_key, _) = repetition_proto;
+        let (raw_expected, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_glwe_ciphertext(input_ciphertext);
+        maker.destroy_glwe_automorphism_key(automorphism_key);
+        (
+            raw_expected.to_owned(),
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance = fix_estimate_trace_noise::<Precision::Raw, KeyDistribution>(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+            parameters.input_noise,
+            parameters.ak_noise,
+            parameters.decomp_base_log,
+            parameters.decomp_level_count,
+        );
+        (predicted_variance,)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+/// Estimates the variance of the constant coefficient of a GLWE trace.
+///
+/// Each of the $\log\_2(N)$ steps of the trace doubles the ciphertext after a keyswitch, so the
+/// input noise ends up multiplied by $N$, and the noise of the $j$-th keyswitch by $2^{n - j}$. The
+/// non-constant coefficients are less noisy, since the trace removes their share of input noise.
+pub(crate) fn fix_estimate_trace_noise<T, K>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    dispersion_input: Variance,
+    dispersion_ak: Variance,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+) -> Variance
+where
+    T: RawUnsignedIntegers,
+    K: KeyDistributionMarker,
+{
+    let keyswitch_variance =
+        fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<T, _, _, K>(
+            LweDimension(glwe_dimension.0 * polynomial_size.0),
+            Variance(0.),
+            dispersion_ak,
+            base_log,
+            level,
+        );
+    let squared_poly_size = (polynomial_size.0 * polynomial_size.0) as f64;
+    Variance(
+        squared_poly_size * dispersion_input.get_variance()
+            + (squared_poly_size - 1.) / 3. * keyswitch_variance.get_variance(),
+    )
+}
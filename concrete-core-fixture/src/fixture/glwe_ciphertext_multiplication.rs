@@ -0,0 +1,372 @@
+use crate::fixture::glwe_ciphertext_cleartext_vector_discarding_multiplication::negacyclic_product;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweRelinearizationKey, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweCiphertext, SynthesizesGlweRelinearizationKey,
+};
+use crate::generation::{
+    BinaryKeyDistribution, GaussianKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker,
+    TernaryKeyDistribution,
+};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::commons::numeric::UnsignedInteger;
+use concrete_core::prelude::{
+    BinaryKeyKind, DecompositionBaseLog, DecompositionLevelCount, DeltaLog, DispersionParameter,
+    GaussianKeyKind, GlweCiphertextEntity, GlweCiphertextMultiplicationEngine, GlweDimension,
+    GlweRelinearizationKeyEntity, LogStandardDev, PolynomialSize, TernaryKeyKind, Variance,
+};
+use std::any::TypeId;
+
+/// A fixture for the types implementing the `GlweCiphertextMultiplicationEngine` trait.
+pub struct GlweCiphertextMultiplicationFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextMultiplicationParameters {
+    pub glwe_noise: Variance,
+    pub relinearization_key_noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub message_bits: usize,
+    pub delta_complement_log: usize,
+}
+
+impl GlweCiphertextMultiplicationParameters {
+    fn delta_log<Raw: UnsignedInteger>(&self) -> DeltaLog {
+        DeltaLog(Raw::BITS - self.delta_complement_log)
+    }
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        KeyDistribution,
+        Engine,
+        InputCiphertext1,
+        InputCiphertext2,
+        RelinearizationKey,
+        OutputCiphertext,
+    >
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (
+            InputCiphertext1,
+            InputCiphertext2,
+            RelinearizationKey,
+            OutputCiphertext,
+        ),
+    > for GlweCiphertextMultiplicationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextMultiplicationEngine<
+        InputCiphertext1,
+        InputCiphertext2,
+        RelinearizationKey,
+        OutputCiphertext,
+    >,
+    InputCiphertext1: GlweCiphertextEntity,
+    InputCiphertext2: GlweCiphertextEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    Maker: SynthesizesGlweCiphertext<Precision, KeyDistribution, InputCiphertext1>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, InputCiphertext2>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, OutputCiphertext>
+        + SynthesizesGlweRelinearizationKey<Precision, KeyDistribution, RelinearizationKey>,
+{
+    type Parameters = GlweCiphertextMultiplicationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweRelinearizationKey<Precision, KeyDistribution>>::GlweRelinearizationKeyProto,
+    );
+    type SamplePrototypes = (
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (InputCiphertext1, InputCiphertext2, RelinearizationKey);
+    type PostExecutionContext = (
+        InputCiphertext1,
+        InputCiphertext2,
+        RelinearizationKey,
+        OutputCiphertext,
+    );
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextMultiplicationParameters {
+                    glwe_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    relinearization_key_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    message_bits: 2,
+                    delta_complement_log: 12,
+                },
+                GlweCiphertextMultiplicationParameters {
+                    glwe_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    relinearization_key_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    message_bits: 2,
+                    delta_complement_log: 12,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_relinearization_key = maker.new_glwe_relinearization_key(
+            &proto_secret_key,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+            parameters.relinearization_key_noise,
+        );
+        (proto_secret_key, proto_relinearization_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        let delta_log = parameters.delta_log::<Precision::Raw>();
+        let encrypt_random_message = |maker: &mut Maker| {
+            let raw_message = Precision::Raw::uniform_between_vec(
+                0..1 << parameters.message_bits,
+                parameters.polynomial_size.0,
+            );
+            let raw_plaintext_vector = raw_message
+                .iter()
+                .map(|&m| m << delta_log.0)
+                .collect::<Vec<_>>();
+            let proto_plaintext_vector =
+                maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+            let proto_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+                proto_secret_key,
+                &proto_plaintext_vector,
+                parameters.glwe_noise,
+            );
+            (raw_message, proto_ciphertext)
+        };
+        let (raw_message_1, proto_ciphertext_1) = encrypt_random_message(maker);
+        let (raw_message_2, proto_ciphertext_2) = encrypt_random_message(maker);
+        (
+            raw_message_1,
+            raw_message_2,
+            proto_ciphertext_1,
+            proto_ciphertext_2,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_relinearization_key) = repetition_proto;
+        let (_, _, proto_ciphertext_1, proto_ciphertext_2) = sample_proto;
+        (
+            maker.synthesize_glwe_ciphertext(proto_ciphertext_1),
+            maker.synthesize_glwe_ciphertext(proto_ciphertext_2),
+            maker.synthesize_glwe_relinearization_key(proto_relinearization_key),
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext_1, input_ciphertext_2, relinearization_key) = context;
+        let output_ciphertext = unsafe {
+            engine.mul_glwe_ciphertext_unchecked(
+                &input_ciphertext_1,
+                &input_ciphertext_2,
+                &relinearization_key,
+                parameters.delta_log::<Precision::Raw>(),
+            )
+        };
+        (
+            input_ciphertext_1,
+            input_ciphertext_2,
+            relinearization_key,
+            output_ciphertext,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext_1, input_ciphertext_2, relinearization_key, output_ciphertext) =
+            context;
+        let (proto_secret_key, _) = repetition_proto;
+        let (raw_message_1, raw_message_2, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        maker.destroy_glwe_ciphertext(input_ciphertext_1);
+        maker.destroy_glwe_ciphertext(input_ciphertext_2);
+        maker.destroy_glwe_relinearization_key(relinearization_key);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        let delta_log = parameters.delta_log::<Precision::Raw>();
+        let raw_expected_plaintext_vector = negacyclic_product(raw_message_1, raw_message_2)
+            .into_iter()
+            .map(|v| v << delta_log.0)
+            .collect();
+        (
+            raw_expected_plaintext_vector,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let delta = 2_f64.powi(parameters.delta_log::<Precision::Raw>().0 as i32);
+        let max_message = ((1 << parameters.message_bits) - 1) as f64;
+        let output_variance = fix_estimate_multiplication_noise::<
+            Precision::Raw,
+            Variance,
+            Variance,
+            Variance,
+            KeyDistribution,
+        >(
+            parameters.polynomial_size,
+            parameters.glwe_dimension,
+            parameters.glwe_noise,
+            parameters.glwe_noise,
+            delta,
+            delta,
+            max_message,
+            max_message,
+            parameters.relinearization_key_noise,
+            parameters.decomposition_base_log,
+            parameters.decomposition_level_count,
+        );
+        (output_variance,)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means = means.into_iter().flatten().collect::<Vec<_>>();
+        let actual = actual.into_iter().flatten().collect::<Vec<_>>();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+// FIXME:
+// The current NPE does not use the key distribution markers of concrete-core. This function makes
+// the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
+#[allow(clippy::too_many_arguments)]
+fn fix_estimate_multiplication_noise<T, D1, D2, D3, K>(
+    poly_size: PolynomialSize,
+    mask_size: GlweDimension,
+    dispersion_glwe1: D1,
+    dispersion_glwe2: D2,
+    delta_1: f64,
+    delta_2: f64,
+    max_msg_1: f64,
+    max_msg_2: f64,
+    dispersion_rlk: D3,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    D3: DispersionParameter,
+    K: KeyDistributionMarker,
+{
+    let k_type_id = TypeId::of::<K>();
+    if k_type_id == TypeId::of::<BinaryKeyDistribution>() {
+        concrete_npe::estimate_multiplication_noise::<D1, D2, D3, BinaryKeyKind>(
+            poly_size,
+            mask_size,
+            dispersion_glwe1,
+            dispersion_glwe2,
+            delta_1,
+            delta_2,
+            max_msg_1,
+            max_msg_2,
+            dispersion_rlk,
+            base_log,
+            level,
+            T::BITS as u32,
+        )
+    } else if k_type_id == TypeId::of::<TernaryKeyDistribution>() {
+        concrete_npe::estimate_multiplication_noise::<D1, D2, D3, TernaryKeyKind>(
+            poly_size,
+            mask_size,
+            dispersion_glwe1,
+            dispersion_glwe2,
+            delta_1,
+            delta_2,
+            max_msg_1,
+            max_msg_2,
+            dispersion_rlk,
+            base_log,
+            level,
+            T::BITS as u32,
+        )
+    } else if k_type_id == TypeId::of::<GaussianKeyDistribution>() {
+        concrete_npe::estimate_multiplication_noise::<D1, D2, D3, GaussianKeyKind>(
+            poly_size,
+            mask_size,
+            dispersion_glwe1,
+            dispersion_glwe2,
+            delta_1,
+            delta_2,
+            max_msg_1,
+            max_msg_2,
+            dispersion_rlk,
+            base_log,
+            level,
+            T::BITS as u32,
+        )
+    } else {
+        panic!("Unknown key distribution encountered.")
+    }
+}
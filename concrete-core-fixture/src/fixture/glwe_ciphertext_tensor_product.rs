@@ -0,0 +1,338 @@
+use crate::fixture::glwe_ciphertext_cleartext_vector_discarding_multiplication::negacyclic_product;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::SynthesizesGlweCiphertext;
+use crate::generation::{
+    BinaryKeyDistribution, GaussianKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker,
+    TernaryKeyDistribution,
+};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::commons::numeric::UnsignedInteger;
+use concrete_core::prelude::{
+    BinaryKeyKind, DeltaLog, DispersionParameter, GaussianKeyKind, GlweCiphertextEntity,
+    GlweCiphertextTensorProductEngine, GlweDimension, LogStandardDev, PolynomialSize,
+    TernaryKeyKind, Variance,
+};
+use std::any::TypeId;
+
+/// A fixture for the types implementing the `GlweCiphertextTensorProductEngine` trait.
+pub struct GlweCiphertextTensorProductFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextTensorProductParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub message_bits: usize,
+    pub delta_complement_log: usize,
+}
+
+impl GlweCiphertextTensorProductParameters {
+    fn delta_log<Raw: UnsignedInteger>(&self) -> DeltaLog {
+        DeltaLog(Raw::BITS - self.delta_complement_log)
+    }
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, InputCiphertext1, InputCiphertext2, OutputCiphertext>
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (InputCiphertext1, InputCiphertext2, OutputCiphertext),
+    > for GlweCiphertextTensorProductFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextTensorProductEngine<InputCiphertext1, InputCiphertext2, OutputCiphertext>,
+    InputCiphertext1: GlweCiphertextEntity,
+    InputCiphertext2: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    Maker: SynthesizesGlweCiphertext<Precision, KeyDistribution, InputCiphertext1>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, InputCiphertext2>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, OutputCiphertext>,
+{
+    type Parameters = GlweCiphertextTensorProductParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        Vec<Vec<Precision::Raw>>,
+    );
+    type SamplePrototypes = (
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (InputCiphertext1, InputCiphertext2);
+    type PostExecutionContext = (InputCiphertext1, InputCiphertext2, OutputCiphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextTensorProductParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    message_bits: 2,
+                    delta_complement_log: 12,
+                },
+                GlweCiphertextTensorProductParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    message_bits: 2,
+                    delta_complement_log: 12,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        // The raw key polynomials are recovered by decrypting, for each i, a noiseless ciphertext
+        // whose only non-zero coefficient is the constant coefficient of the i-th mask. Its phase
+        // is -S_i.
+        let raw_secret_key = (0..parameters.glwe_dimension.0)
+            .map(|i| {
+                let mut raw_ciphertext = Precision::Raw::zero_vec(
+                    (parameters.glwe_dimension.0 + 1) * parameters.polynomial_size.0,
+                );
+                raw_ciphertext[i * parameters.polynomial_size.0] = Precision::Raw::one();
+                let proto_ciphertext = maker.transform_raw_vec_to_glwe_ciphertext(
+                    &raw_ciphertext,
+                    parameters.polynomial_size,
+                );
+                let proto_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+                    &proto_secret_key,
+                    &proto_ciphertext,
+                );
+                maker
+                    .transform_plaintext_vector_to_raw_vec(&proto_plaintext_vector)
+                    .into_iter()
+                    .map(|v| v.wrapping_neg())
+                    .collect()
+            })
+            .collect();
+        (proto_secret_key, raw_secret_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        let delta_log = parameters.delta_log::<Precision::Raw>();
+        let encrypt_random_message = |maker: &mut Maker| {
+            let raw_message = Precision::Raw::uniform_between_vec(
+                0..1 << parameters.message_bits,
+                parameters.polynomial_size.0,
+            );
+            let raw_plaintext_vector = raw_message
+                .iter()
+                .map(|&m| m << delta_log.0)
+                .collect::<Vec<_>>();
+            let proto_plaintext_vector =
+                maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+            let proto_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+                proto_secret_key,
+                &proto_plaintext_vector,
+                parameters.noise,
+            );
+            (raw_message, proto_ciphertext)
+        };
+        let (raw_message_1, proto_ciphertext_1) = encrypt_random_message(maker);
+        let (raw_message_2, proto_ciphertext_2) = encrypt_random_message(maker);
+        (
+            raw_message_1,
+            raw_message_2,
+            proto_ciphertext_1,
+            proto_ciphertext_2,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_ciphertext_1, proto_ciphertext_2) = sample_proto;
+        (
+            maker.synthesize_glwe_ciphertext(proto_ciphertext_1),
+            maker.synthesize_glwe_ciphertext(proto_ciphertext_2),
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext_1, input_ciphertext_2) = context;
+        let output_ciphertext = unsafe {
+            engine.tensor_product_glwe_ciphertext_unchecked(
+                &input_ciphertext_1,
+                &input_ciphertext_2,
+                parameters.delta_log::<Precision::Raw>(),
+            )
+        };
+        (input_ciphertext_1, input_ciphertext_2, output_ciphertext)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext_1, input_ciphertext_2, output_ciphertext) = context;
+        let (_, raw_secret_key) = repetition_proto;
+        let (raw_message_1, raw_message_2, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        maker.destroy_glwe_ciphertext(input_ciphertext_1);
+        maker.destroy_glwe_ciphertext(input_ciphertext_2);
+        let (raw_output_ciphertext, polynomial_size) =
+            maker.transform_glwe_ciphertext_to_raw_vec(&proto_output_ciphertext);
+
+        // The output is decrypted under the tensored key: the quadratic terms S_i * S_j for
+        // i <= j in lexicographic order, followed by the linear terms S_i.
+        let mut raw_output_polynomials = raw_output_ciphertext.chunks(polynomial_size.0);
+        let mut tensored_key = Vec::new();
+        for i in 0..raw_secret_key.len() {
+            for j in i..raw_secret_key.len() {
+                tensored_key.push(negacyclic_product(&raw_secret_key[i], &raw_secret_key[j]));
+            }
+        }
+        tensored_key.extend(raw_secret_key.iter().cloned());
+        let mut raw_phase = Precision::Raw::zero_vec(polynomial_size.0);
+        for key_polynomial in tensored_key.iter() {
+            let mask_polynomial = raw_output_polynomials.next().unwrap();
+            let product = negacyclic_product(mask_polynomial, key_polynomial);
+            raw_phase
+                .iter_mut()
+                .zip(product.iter())
+                .for_each(|(p, v)| *p = p.wrapping_sub(*v));
+        }
+        raw_phase
+            .iter_mut()
+            .zip(raw_output_polynomials.next().unwrap().iter())
+            .for_each(|(p, b)| *p = p.wrapping_add(*b));
+
+        let delta_log = parameters.delta_log::<Precision::Raw>();
+        let raw_expected_phase = negacyclic_product(raw_message_1, raw_message_2)
+            .into_iter()
+            .map(|v| v << delta_log.0)
+            .collect();
+        (raw_expected_phase, raw_phase)
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let delta = 2_f64.powi(parameters.delta_log::<Precision::Raw>().0 as i32);
+        let max_message = ((1 << parameters.message_bits) - 1) as f64;
+        let output_variance = fix_estimate_tensor_product_noise::<
+            Precision::Raw,
+            Variance,
+            Variance,
+            KeyDistribution,
+        >(
+            parameters.polynomial_size,
+            parameters.glwe_dimension,
+            parameters.noise,
+            parameters.noise,
+            delta,
+            delta,
+            max_message,
+            max_message,
+        );
+        (output_variance,)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means = means.into_iter().flatten().collect::<Vec<_>>();
+        let actual = actual.into_iter().flatten().collect::<Vec<_>>();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+// FIXME:
+// The current NPE does not use the key distribution markers of concrete-core. This function makes
+// the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
+#[allow(clippy::too_many_arguments)]
+fn fix_estimate_tensor_product_noise<T, D1, D2, K>(
+    poly_size: PolynomialSize,
+    rlwe_dimension: GlweDimension,
+    dispersion_glwe1: D1,
+    dispersion_glwe2: D2,
+    delta_1: f64,
+    delta_2: f64,
+    max_msg_1: f64,
+    max_msg_2: f64,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    K: KeyDistributionMarker,
+{
+    let k_type_id = TypeId::of::<K>();
+    if k_type_id == TypeId::of::<BinaryKeyDistribution>() {
+        concrete_npe::estimate_tensor_product_noise::<D1, D2, BinaryKeyKind>(
+            poly_size,
+            rlwe_dimension,
+            dispersion_glwe1,
+            dispersion_glwe2,
+            delta_1,
+            delta_2,
+            max_msg_1,
+            max_msg_2,
+            T::BITS as u32,
+        )
+    } else if k_type_id == TypeId::of::<TernaryKeyDistribution>() {
+        concrete_npe::estimate_tensor_product_noise::<D1, D2, TernaryKeyKind>(
+            poly_size,
+            rlwe_dimension,
+            dispersion_glwe1,
+            dispersion_glwe2,
+            delta_1,
+            delta_2,
+            max_msg_1,
+            max_msg_2,
+            T::BITS as u32,
+        )
+    } else if k_type_id == TypeId::of::<GaussianKeyDistribution>() {
+        concrete_npe::estimate_tensor_product_noise::<D1, D2, GaussianKeyKind>(
+            poly_size,
+            rlwe_dimension,
+            dispersion_glwe1,
+            dispersion_glwe2,
+            delta_1,
+            delta_2,
+            max_msg_1,
+            max_msg_2,
+            T::BITS as u32,
+        )
+    } else {
+        panic!("Unknown key distribution encountered.")
+    }
+}
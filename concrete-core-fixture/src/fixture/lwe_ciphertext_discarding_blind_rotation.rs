@@ -0,0 +1,302 @@
+use crate::fixture::lwe_ciphertext_discarding_bootstrap_1::fix_estimate_pbs_noise;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesLweBootstrapKey,
+    PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweCiphertext, SynthesizesLweBootstrapKey, SynthesizesLweCiphertext,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_delta_std_dev;
+use concrete_core::commons::numeric::{Numeric, UnsignedInteger};
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweCiphertextEntity, GlweDimension,
+    LogStandardDev, LweBootstrapKeyEntity, LweCiphertextDiscardingBlindRotationEngine,
+    LweCiphertextEntity, LweDimension, PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingBlindRotationEngine` trait.
+pub struct LweCiphertextDiscardingBlindRotationFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingBlindRotationParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub decomp_base_log: DecompositionBaseLog,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (BootstrapKey, Accumulator, InputCiphertext, OutputCiphertext),
+    > for LweCiphertextDiscardingBlindRotationFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextDiscardingBlindRotationEngine<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    >,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    Accumulator: GlweCiphertextEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    Maker: SynthesizesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            BootstrapKey,
+        > + SynthesizesGlweCiphertext<Precision, OutputKeyDistribution, Accumulator>
+        + SynthesizesLweCiphertext<Precision, InputKeyDistribution, InputCiphertext>
+        + SynthesizesGlweCiphertext<Precision, OutputKeyDistribution, OutputCiphertext>,
+{
+    type Parameters = LweCiphertextDiscardingBlindRotationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweCiphertext<Precision, OutputKeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweBootstrapKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, OutputKeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (BootstrapKey, Accumulator, OutputCiphertext, InputCiphertext);
+    type PostExecutionContext = (BootstrapKey, Accumulator, OutputCiphertext, InputCiphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextDiscardingBlindRotationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+                    lwe_dimension: LweDimension(630),
+                    glwe_dimension: GlweDimension(2),
+                    poly_size: PolynomialSize(512),
+                    decomp_level_count: DecompositionLevelCount(3),
+                    decomp_base_log: DecompositionBaseLog(7),
+                },
+                LweCiphertextDiscardingBlindRotationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+                    lwe_dimension: LweDimension(630),
+                    glwe_dimension: GlweDimension(1),
+                    poly_size: PolynomialSize(1024),
+                    decomp_level_count: DecompositionLevelCount(2),
+                    decomp_base_log: DecompositionBaseLog(10),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let raw_plaintext_vector = vec![lut_value::<Precision::Raw>(); parameters.poly_size.0];
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_accumulator = maker.trivially_encrypt_plaintext_vector_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            &proto_plaintext_vector,
+        );
+        let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+            &proto_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.decomp_level_count,
+            parameters.decomp_base_log,
+            parameters.noise,
+        );
+        (
+            proto_accumulator,
+            proto_lwe_secret_key,
+            proto_glwe_secret_key,
+            proto_bootstrap_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (_, proto_lwe_secret_key, ..) = repetition_proto;
+        // A phase of 1/4 rotates the accumulator by (around) half of its coefficients.
+        let raw_plaintext = Precision::Raw::ONE << (Precision::Raw::BITS - 2);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_input_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_lwe_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        let proto_output_ciphertext = <Maker as PrototypesGlweCiphertext<
+            Precision,
+            OutputKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            maker,
+            parameters.glwe_dimension,
+            parameters.poly_size,
+        );
+        (proto_input_ciphertext, proto_output_ciphertext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_accumulator, _, _, proto_bootstrap_key) = repetition_proto;
+        let (proto_input_ciphertext, proto_output_ciphertext) = sample_proto;
+        let synth_bootstrap_key = maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key);
+        let synth_accumulator = maker.synthesize_glwe_ciphertext(proto_accumulator);
+        let synth_input_ciphertext = maker.synthesize_lwe_ciphertext(proto_input_ciphertext);
+        let synth_output_ciphertext = maker.synthesize_glwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_bootstrap_key,
+            synth_accumulator,
+            synth_output_ciphertext,
+            synth_input_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (bootstrap_key, accumulator, mut output_ciphertext, input_ciphertext) = context;
+        unsafe {
+            engine.discard_blind_rotate_lwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &accumulator,
+                &bootstrap_key,
+            )
+        };
+        (
+            bootstrap_key,
+            accumulator,
+            output_ciphertext,
+            input_ciphertext,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (bootstrap_key, accumulator, output_ciphertext, input_ciphertext) = context;
+        let (_, _, proto_glwe_secret_key, _) = repetition_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        let proto_output_plaintext_vector = <Maker as PrototypesGlweCiphertext<
+            Precision,
+            OutputKeyDistribution,
+        >>::decrypt_glwe_ciphertext_to_plaintext_vector(
+            maker,
+            proto_glwe_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        maker.destroy_glwe_ciphertext(accumulator);
+        let raw_output =
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector);
+
+        // The rotation by X^(-N/2) moves the upper half of the constant lookup table to the lower
+        // half, and the negated lower half to the upper half. The coefficients close to the
+        // middle are skipped, as the rotation amount depends on the noise of the input.
+        let quarter = parameters.poly_size.0 / 4;
+        let lut = lut_value::<Precision::Raw>();
+        let (expected, actual) = raw_output
+            .iter()
+            .enumerate()
+            .filter_map(|(i, actual)| {
+                if i < quarter {
+                    Some((lut, *actual))
+                } else if i >= 3 * quarter {
+                    Some((lut.wrapping_neg(), *actual))
+                } else {
+                    None
+                }
+            })
+            .unzip();
+        (expected, actual)
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance: Variance =
+            fix_estimate_pbs_noise::<Precision::Raw, Variance, OutputKeyDistribution>(
+                parameters.lwe_dimension,
+                parameters.poly_size,
+                parameters.glwe_dimension,
+                parameters.decomp_base_log,
+                parameters.decomp_level_count,
+                parameters.noise,
+            );
+        (predicted_variance,)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_delta_std_dev(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+// The value stored in every coefficient of the accumulator.
+fn lut_value<Raw: RawUnsignedIntegers>() -> Raw {
+    Raw::ONE << (Raw::BITS - 3)
+}
@@ -0,0 +1,1093 @@
+use super::lwe_ciphertext_vector_discarding_boolean_gates::{decode_boolean, encode_boolean};
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesLweBootstrapKey, PrototypesLweCiphertext,
+    PrototypesLweKeyswitchKey, PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweBootstrapKey, SynthesizesLweCiphertext, SynthesizesLweKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension,
+    LogStandardDev, LweBootstrapKeyEntity, LweCiphertextDiscardingAndEngine,
+    LweCiphertextDiscardingMuxEngine, LweCiphertextDiscardingMuxError,
+    LweCiphertextDiscardingNandEngine, LweCiphertextDiscardingNorEngine,
+    LweCiphertextDiscardingNotEngine, LweCiphertextDiscardingOrEngine,
+    LweCiphertextDiscardingXnorEngine, LweCiphertextDiscardingXorEngine, LweCiphertextEntity,
+    LweDimension, LweKeyswitchKeyEntity, PolynomialSize, Variance,
+};
+
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingBooleanGateParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub decomp_level_count_bsk: DecompositionLevelCount,
+    pub decomp_base_log_bsk: DecompositionBaseLog,
+    pub decomp_level_count_ksk: DecompositionLevelCount,
+    pub decomp_base_log_ksk: DecompositionBaseLog,
+}
+
+fn generate_boolean_gate_parameters(
+) -> Box<dyn Iterator<Item = LweCiphertextDiscardingBooleanGateParameters>> {
+    Box::new(
+        vec![LweCiphertextDiscardingBooleanGateParameters {
+            noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+            lwe_dimension: LweDimension(630),
+            glwe_dimension: GlweDimension(1),
+            poly_size: PolynomialSize(1024),
+            decomp_level_count_bsk: DecompositionLevelCount(3),
+            decomp_base_log_bsk: DecompositionBaseLog(7),
+            decomp_level_count_ksk: DecompositionLevelCount(5),
+            decomp_base_log_ksk: DecompositionBaseLog(3),
+        }]
+        .into_iter(),
+    )
+}
+
+// The keys are only used to check that mismatched dimensions are rejected, so small parameters
+// are enough.
+fn generate_key_mismatch_parameters(
+) -> Box<dyn Iterator<Item = LweCiphertextDiscardingBooleanGateParameters>> {
+    Box::new(
+        vec![LweCiphertextDiscardingBooleanGateParameters {
+            noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+            lwe_dimension: LweDimension(10),
+            glwe_dimension: GlweDimension(1),
+            poly_size: PolynomialSize(256),
+            decomp_level_count_bsk: DecompositionLevelCount(3),
+            decomp_base_log_bsk: DecompositionBaseLog(7),
+            decomp_level_count_ksk: DecompositionLevelCount(5),
+            decomp_base_log_ksk: DecompositionBaseLog(3),
+        }]
+        .into_iter(),
+    )
+}
+
+fn random_encoded_boolean<Raw: RawUnsignedIntegers>() -> Raw {
+    Raw::pick(&[encode_boolean(true), encode_boolean(false)])
+}
+
+fn verify_boolean_output<Raw: RawUnsignedIntegers>(outputs: &[(Raw, Raw)]) -> bool {
+    outputs
+        .iter()
+        .all(|(expected, actual)| decode_boolean(*expected) == decode_boolean(*actual))
+}
+
+macro_rules! binary_gate_fixture {
+    ($fixture: ident, $engine_trait: ident, $method: ident, $gate: expr) => {
+        #[doc = concat!("A fixture for the types implementing the `", stringify!($engine_trait), "` trait.")]
+        pub struct $fixture;
+
+        #[allow(clippy::type_complexity)]
+        impl<
+                Precision,
+                InputKeyDistribution,
+                OutputKeyDistribution,
+                Engine,
+                Ciphertext,
+                BootstrapKey,
+                KeyswitchKey,
+            >
+            Fixture<
+                Precision,
+                (InputKeyDistribution, OutputKeyDistribution),
+                Engine,
+                (Ciphertext, BootstrapKey, KeyswitchKey),
+            > for $fixture
+        where
+            Precision: IntegerPrecision,
+            InputKeyDistribution: KeyDistributionMarker,
+            OutputKeyDistribution: KeyDistributionMarker,
+            Engine: $engine_trait<Ciphertext, Ciphertext, BootstrapKey, KeyswitchKey>,
+            Ciphertext: LweCiphertextEntity,
+            BootstrapKey: LweBootstrapKeyEntity,
+            KeyswitchKey: LweKeyswitchKeyEntity,
+            Maker: SynthesizesLweBootstrapKey<
+                    Precision,
+                    InputKeyDistribution,
+                    OutputKeyDistribution,
+                    BootstrapKey,
+                > + SynthesizesLweKeyswitchKey<
+                    Precision,
+                    OutputKeyDistribution,
+                    InputKeyDistribution,
+                    KeyswitchKey,
+                > + SynthesizesLweCiphertext<Precision, InputKeyDistribution, Ciphertext>,
+        {
+            type Parameters = LweCiphertextDiscardingBooleanGateParameters;
+            type RepetitionPrototypes = (
+                <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+                <Maker as PrototypesLweBootstrapKey<
+                    Precision,
+                    InputKeyDistribution,
+                    OutputKeyDistribution,
+                >>::LweBootstrapKeyProto,
+                <Maker as PrototypesLweKeyswitchKey<
+                    Precision,
+                    OutputKeyDistribution,
+                    InputKeyDistribution,
+                >>::LweKeyswitchKeyProto,
+            );
+            type SamplePrototypes = (
+                <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+                <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+                <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+                <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+                <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+            );
+            type PreExecutionContext = (Ciphertext, Ciphertext, Ciphertext, BootstrapKey, KeyswitchKey);
+            type PostExecutionContext =
+                (Ciphertext, Ciphertext, Ciphertext, BootstrapKey, KeyswitchKey);
+            type Criteria = ();
+            type Outcome = (Precision::Raw, Precision::Raw);
+
+            fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+                generate_boolean_gate_parameters()
+            }
+
+            fn generate_random_repetition_prototypes(
+                parameters: &Self::Parameters,
+                maker: &mut Maker,
+            ) -> Self::RepetitionPrototypes {
+                let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+                    Precision,
+                    InputKeyDistribution,
+                >>::new_lwe_secret_key(
+                    maker, parameters.lwe_dimension
+                );
+                let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+                    Precision,
+                    OutputKeyDistribution,
+                >>::new_glwe_secret_key(
+                    maker, parameters.glwe_dimension, parameters.poly_size
+                );
+                let proto_large_lwe_secret_key = <Maker as PrototypesGlweSecretKey<
+                    Precision,
+                    OutputKeyDistribution,
+                >>::transform_glwe_secret_key_to_lwe_secret_key(
+                    maker, &proto_glwe_secret_key
+                );
+                let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+                    &proto_lwe_secret_key,
+                    &proto_glwe_secret_key,
+                    parameters.decomp_level_count_bsk,
+                    parameters.decomp_base_log_bsk,
+                    parameters.noise,
+                );
+                let proto_keyswitch_key = maker.new_lwe_keyswitch_key(
+                    &proto_large_lwe_secret_key,
+                    &proto_lwe_secret_key,
+                    parameters.decomp_level_count_ksk,
+                    parameters.decomp_base_log_ksk,
+                    parameters.noise,
+                );
+                (
+                    proto_lwe_secret_key,
+                    proto_bootstrap_key,
+                    proto_keyswitch_key,
+                )
+            }
+
+            fn generate_random_sample_prototypes(
+                parameters: &Self::Parameters,
+                maker: &mut Maker,
+                repetition_proto: &Self::RepetitionPrototypes,
+            ) -> Self::SamplePrototypes {
+                let (proto_lwe_secret_key, ..) = repetition_proto;
+                let proto_plaintext_1 =
+                    maker.transform_raw_to_plaintext(&random_encoded_boolean());
+                let proto_plaintext_2 =
+                    maker.transform_raw_to_plaintext(&random_encoded_boolean());
+                let proto_input_ciphertext_1 = <Maker as PrototypesLweCiphertext<
+                    Precision,
+                    InputKeyDistribution,
+                >>::encrypt_plaintext_to_lwe_ciphertext(
+                    maker,
+                    proto_lwe_secret_key,
+                    &proto_plaintext_1,
+                    parameters.noise,
+                );
+                let proto_input_ciphertext_2 = <Maker as PrototypesLweCiphertext<
+                    Precision,
+                    InputKeyDistribution,
+                >>::encrypt_plaintext_to_lwe_ciphertext(
+                    maker,
+                    proto_lwe_secret_key,
+                    &proto_plaintext_2,
+                    parameters.noise,
+                );
+                let proto_output_ciphertext = <Maker as PrototypesLweCiphertext<
+                    Precision,
+                    InputKeyDistribution,
+                >>::trivially_encrypt_zero_to_lwe_ciphertext(
+                    maker, parameters.lwe_dimension
+                );
+                (
+                    proto_plaintext_1,
+                    proto_plaintext_2,
+                    proto_input_ciphertext_1,
+                    proto_input_ciphertext_2,
+                    proto_output_ciphertext,
+                )
+            }
+
+            fn prepare_context(
+                _parameters: &Self::Parameters,
+                maker: &mut Maker,
+                repetition_proto: &Self::RepetitionPrototypes,
+                sample_proto: &Self::SamplePrototypes,
+            ) -> Self::PreExecutionContext {
+                let (_, proto_bootstrap_key, proto_keyswitch_key) = repetition_proto;
+                let (
+                    _,
+                    _,
+                    proto_input_ciphertext_1,
+                    proto_input_ciphertext_2,
+                    proto_output_ciphertext,
+                ) = sample_proto;
+                (
+                    maker.synthesize_lwe_ciphertext(proto_output_ciphertext),
+                    maker.synthesize_lwe_ciphertext(proto_input_ciphertext_1),
+                    maker.synthesize_lwe_ciphertext(proto_input_ciphertext_2),
+                    maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key),
+                    maker.synthesize_lwe_keyswitch_key(proto_keyswitch_key),
+                )
+            }
+
+            fn execute_engine(
+                _parameters: &Self::Parameters,
+                engine: &mut Engine,
+                context: Self::PreExecutionContext,
+            ) -> Self::PostExecutionContext {
+                let (
+                    mut output_ciphertext,
+                    input_ciphertext_1,
+                    input_ciphertext_2,
+                    bootstrap_key,
+                    keyswitch_key,
+                ) = context;
+                unsafe {
+                    engine.$method(
+                        &mut output_ciphertext,
+                        &input_ciphertext_1,
+                        &input_ciphertext_2,
+                        &bootstrap_key,
+                        &keyswitch_key,
+                    )
+                };
+                (
+                    output_ciphertext,
+                    input_ciphertext_1,
+                    input_ciphertext_2,
+                    bootstrap_key,
+                    keyswitch_key,
+                )
+            }
+
+            fn process_context(
+                _parameters: &Self::Parameters,
+                maker: &mut Maker,
+                repetition_proto: &Self::RepetitionPrototypes,
+                sample_proto: &Self::SamplePrototypes,
+                context: Self::PostExecutionContext,
+            ) -> Self::Outcome {
+                let (
+                    output_ciphertext,
+                    input_ciphertext_1,
+                    input_ciphertext_2,
+                    bootstrap_key,
+                    keyswitch_key,
+                ) = context;
+                let (proto_lwe_secret_key, ..) = repetition_proto;
+                let (proto_plaintext_1, proto_plaintext_2, ..) = sample_proto;
+                let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(output_ciphertext);
+                let proto_output_plaintext = <Maker as PrototypesLweCiphertext<
+                    Precision,
+                    InputKeyDistribution,
+                >>::decrypt_lwe_ciphertext_to_plaintext(
+                    maker,
+                    proto_lwe_secret_key,
+                    &proto_output_ciphertext,
+                );
+                maker.destroy_lwe_ciphertext(input_ciphertext_1);
+                maker.destroy_lwe_ciphertext(input_ciphertext_2);
+                maker.destroy_lwe_bootstrap_key(bootstrap_key);
+                maker.destroy_lwe_keyswitch_key(keyswitch_key);
+                let gate: fn(bool, bool) -> bool = $gate;
+                let expected = encode_boolean(gate(
+                    decode_boolean(maker.transform_plaintext_to_raw(proto_plaintext_1)),
+                    decode_boolean(maker.transform_plaintext_to_raw(proto_plaintext_2)),
+                ));
+                (
+                    expected,
+                    maker.transform_plaintext_to_raw(&proto_output_plaintext),
+                )
+            }
+
+            fn compute_criteria(
+                _parameters: &Self::Parameters,
+                _maker: &mut Maker,
+                _repetition_proto: &Self::RepetitionPrototypes,
+            ) -> Self::Criteria {
+            }
+
+            fn verify(
+                _parameters: &Self::Parameters,
+                _criteria: &Self::Criteria,
+                outputs: &[Self::Outcome],
+            ) -> bool {
+                verify_boolean_output(outputs)
+            }
+        }
+    };
+}
+
+binary_gate_fixture!(
+    LweCiphertextDiscardingAndFixture,
+    LweCiphertextDiscardingAndEngine,
+    discard_and_lwe_ciphertext_unchecked,
+    |a, b| a & b
+);
+binary_gate_fixture!(
+    LweCiphertextDiscardingNandFixture,
+    LweCiphertextDiscardingNandEngine,
+    discard_nand_lwe_ciphertext_unchecked,
+    |a, b| !(a & b)
+);
+binary_gate_fixture!(
+    LweCiphertextDiscardingNorFixture,
+    LweCiphertextDiscardingNorEngine,
+    discard_nor_lwe_ciphertext_unchecked,
+    |a, b| !(a | b)
+);
+binary_gate_fixture!(
+    LweCiphertextDiscardingOrFixture,
+    LweCiphertextDiscardingOrEngine,
+    discard_or_lwe_ciphertext_unchecked,
+    |a, b| a | b
+);
+binary_gate_fixture!(
+    LweCiphertextDiscardingXorFixture,
+    LweCiphertextDiscardingXorEngine,
+    discard_xor_lwe_ciphertext_unchecked,
+    |a, b| a ^ b
+);
+binary_gate_fixture!(
+    LweCiphertextDiscardingXnorFixture,
+    LweCiphertextDiscardingXnorEngine,
+    discard_xnor_lwe_ciphertext_unchecked,
+    |a, b| !(a ^ b)
+);
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingMuxEngine` trait.
+pub struct LweCiphertextDiscardingMuxFixture;
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        Ciphertext,
+        BootstrapKey,
+        KeyswitchKey,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (Ciphertext, BootstrapKey, KeyswitchKey),
+    > for LweCiphertextDiscardingMuxFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextDiscardingMuxEngine<Ciphertext, Ciphertext, BootstrapKey, KeyswitchKey>,
+    Ciphertext: LweCiphertextEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    Maker: SynthesizesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            BootstrapKey,
+        > + SynthesizesLweKeyswitchKey<
+            Precision,
+            OutputKeyDistribution,
+            InputKeyDistribution,
+            KeyswitchKey,
+        > + SynthesizesLweCiphertext<Precision, InputKeyDistribution, Ciphertext>,
+{
+    type Parameters = LweCiphertextDiscardingBooleanGateParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweBootstrapKeyProto,
+        <Maker as PrototypesLweKeyswitchKey<
+            Precision,
+            OutputKeyDistribution,
+            InputKeyDistribution,
+        >>::LweKeyswitchKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (
+        Ciphertext,
+        Ciphertext,
+        Ciphertext,
+        Ciphertext,
+        BootstrapKey,
+        KeyswitchKey,
+    );
+    type PostExecutionContext = (
+        Ciphertext,
+        Ciphertext,
+        Ciphertext,
+        Ciphertext,
+        BootstrapKey,
+        KeyswitchKey,
+    );
+    type Criteria = ();
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        generate_boolean_gate_parameters()
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_large_lwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::transform_glwe_secret_key_to_lwe_secret_key(
+            maker, &proto_glwe_secret_key
+        );
+        let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+            &proto_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.decomp_level_count_bsk,
+            parameters.decomp_base_log_bsk,
+            parameters.noise,
+        );
+        let proto_keyswitch_key = maker.new_lwe_keyswitch_key(
+            &proto_large_lwe_secret_key,
+            &proto_lwe_secret_key,
+            parameters.decomp_level_count_ksk,
+            parameters.decomp_base_log_ksk,
+            parameters.noise,
+        );
+        (
+            proto_lwe_secret_key,
+            proto_bootstrap_key,
+            proto_keyswitch_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_lwe_secret_key, ..) = repetition_proto;
+        let proto_plaintext_select = maker.transform_raw_to_plaintext(&random_encoded_boolean());
+        let proto_plaintext_1 = maker.transform_raw_to_plaintext(&random_encoded_boolean());
+        let proto_plaintext_2 = maker.transform_raw_to_plaintext(&random_encoded_boolean());
+        let proto_select_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_lwe_secret_key,
+            &proto_plaintext_select,
+            parameters.noise,
+        );
+        let proto_input_ciphertext_1 = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_lwe_secret_key,
+            &proto_plaintext_1,
+            parameters.noise,
+        );
+        let proto_input_ciphertext_2 = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_lwe_secret_key,
+            &proto_plaintext_2,
+            parameters.noise,
+        );
+        let proto_output_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            maker, parameters.lwe_dimension
+        );
+        (
+            proto_plaintext_select,
+            proto_plaintext_1,
+            proto_plaintext_2,
+            proto_select_ciphertext,
+            proto_input_ciphertext_1,
+            proto_input_ciphertext_2,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_bootstrap_key, proto_keyswitch_key) = repetition_proto;
+        let (
+            _,
+            _,
+            _,
+            proto_select_ciphertext,
+            proto_input_ciphertext_1,
+            proto_input_ciphertext_2,
+            proto_output_ciphertext,
+        ) = sample_proto;
+        (
+            maker.synthesize_lwe_ciphertext(proto_output_ciphertext),
+            maker.synthesize_lwe_ciphertext(proto_select_ciphertext),
+            maker.synthesize_lwe_ciphertext(proto_input_ciphertext_1),
+            maker.synthesize_lwe_ciphertext(proto_input_ciphertext_2),
+            maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key),
+            maker.synthesize_lwe_keyswitch_key(proto_keyswitch_key),
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (
+            mut output_ciphertext,
+            select_ciphertext,
+            input_ciphertext_1,
+            input_ciphertext_2,
+            bootstrap_key,
+            keyswitch_key,
+        ) = context;
+        unsafe {
+            engine.discard_mux_lwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &select_ciphertext,
+                &input_ciphertext_1,
+                &input_ciphertext_2,
+                &bootstrap_key,
+                &keyswitch_key,
+            )
+        };
+        (
+            output_ciphertext,
+            select_ciphertext,
+            input_ciphertext_1,
+            input_ciphertext_2,
+            bootstrap_key,
+            keyswitch_key,
+        )
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (
+            output_ciphertext,
+            select_ciphertext,
+            input_ciphertext_1,
+            input_ciphertext_2,
+            bootstrap_key,
+            keyswitch_key,
+        ) = context;
+        let (proto_lwe_secret_key, ..) = repetition_proto;
+        let (proto_plaintext_select, proto_plaintext_1, proto_plaintext_2, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(output_ciphertext);
+        let proto_output_plaintext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::decrypt_lwe_ciphertext_to_plaintext(
+            maker,
+            proto_lwe_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_lwe_ciphertext(select_ciphertext);
+        maker.destroy_lwe_ciphertext(input_ciphertext_1);
+        maker.destroy_lwe_ciphertext(input_ciphertext_2);
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        maker.destroy_lwe_keyswitch_key(keyswitch_key);
+        let expected = if decode_boolean(maker.transform_plaintext_to_raw(proto_plaintext_select)) {
+            maker.transform_plaintext_to_raw(proto_plaintext_1)
+        } else {
+            maker.transform_plaintext_to_raw(proto_plaintext_2)
+        };
+        (
+            expected,
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        verify_boolean_output(outputs)
+    }
+}
+
+/// A fixture checking that the types implementing the `LweCiphertextDiscardingMuxEngine` trait
+/// reject keys whose dimensions do not match the inputs.
+pub struct LweCiphertextDiscardingMuxKeyMismatchFixture;
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        Ciphertext,
+        BootstrapKey,
+        KeyswitchKey,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (Ciphertext, BootstrapKey, KeyswitchKey),
+    > for LweCiphertextDiscardingMuxKeyMismatchFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextDiscardingMuxEngine<Ciphertext, Ciphertext, BootstrapKey, KeyswitchKey>,
+    Ciphertext: LweCiphertextEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    Maker: SynthesizesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            BootstrapKey,
+        > + SynthesizesLweKeyswitchKey<
+            Precision,
+            OutputKeyDistribution,
+            InputKeyDistribution,
+            KeyswitchKey,
+        > + SynthesizesLweCiphertext<Precision, InputKeyDistribution, Ciphertext>,
+{
+    type Parameters = LweCiphertextDiscardingBooleanGateParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweBootstrapKeyProto,
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweBootstrapKeyProto,
+        <Maker as PrototypesLweKeyswitchKey<
+            Precision,
+            OutputKeyDistribution,
+            InputKeyDistribution,
+        >>::LweKeyswitchKeyProto,
+        <Maker as PrototypesLweKeyswitchKey<
+            Precision,
+            OutputKeyDistribution,
+            InputKeyDistribution,
+        >>::LweKeyswitchKeyProto,
+    );
+    type SamplePrototypes =
+        (<Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,);
+    type PreExecutionContext = (
+        Ciphertext,
+        Ciphertext,
+        BootstrapKey,
+        BootstrapKey,
+        KeyswitchKey,
+        KeyswitchKey,
+    );
+    type PostExecutionContext = (
+        Ciphertext,
+        Ciphertext,
+        BootstrapKey,
+        BootstrapKey,
+        KeyswitchKey,
+        KeyswitchKey,
+        bool,
+    );
+    type Criteria = ();
+    type Outcome = bool;
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        generate_key_mismatch_parameters()
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        // A secret key of a different dimension, used to generate the mismatched keys.
+        let proto_other_lwe_secret_key =
+            <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::new_lwe_secret_key(
+                maker,
+                LweDimension(parameters.lwe_dimension.0 + 1),
+            );
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_large_lwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::transform_glwe_secret_key_to_lwe_secret_key(
+            maker, &proto_glwe_secret_key
+        );
+        let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+            &proto_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.decomp_level_count_bsk,
+            parameters.decomp_base_log_bsk,
+            parameters.noise,
+        );
+        let proto_mismatched_bootstrap_key = maker.new_lwe_bootstrap_key(
+            &proto_other_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.decomp_level_count_bsk,
+            parameters.decomp_base_log_bsk,
+            parameters.noise,
+        );
+        let proto_keyswitch_key = maker.new_lwe_keyswitch_key(
+            &proto_large_lwe_secret_key,
+            &proto_lwe_secret_key,
+            parameters.decomp_level_count_ksk,
+            parameters.decomp_base_log_ksk,
+            parameters.noise,
+        );
+        let proto_mismatched_keyswitch_key = maker.new_lwe_keyswitch_key(
+            &proto_large_lwe_secret_key,
+            &proto_other_lwe_secret_key,
+            parameters.decomp_level_count_ksk,
+            parameters.decomp_base_log_ksk,
+            parameters.noise,
+        );
+        (
+            proto_bootstrap_key,
+            proto_mismatched_bootstrap_key,
+            proto_keyswitch_key,
+            proto_mismatched_keyswitch_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let proto_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            maker, parameters.lwe_dimension
+        );
+        (proto_ciphertext,)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (
+            proto_bootstrap_key,
+            proto_mismatched_bootstrap_key,
+            proto_keyswitch_key,
+            proto_mismatched_keyswitch_key,
+        ) = repetition_proto;
+        let (proto_ciphertext,) = sample_proto;
+        (
+            maker.synthesize_lwe_ciphertext(proto_ciphertext),
+            maker.synthesize_lwe_ciphertext(proto_ciphertext),
+            maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key),
+            maker.synthesize_lwe_bootstrap_key(proto_mismatched_bootstrap_key),
+            maker.synthesize_lwe_keyswitch_key(proto_keyswitch_key),
+            maker.synthesize_lwe_keyswitch_key(proto_mismatched_keyswitch_key),
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (
+            mut output_ciphertext,
+            input_ciphertext,
+            bootstrap_key,
+            mismatched_bootstrap_key,
+            keyswitch_key,
+            mismatched_keyswitch_key,
+        ) = context;
+        let bootstrap_key_rejected = matches!(
+            engine.discard_mux_lwe_ciphertext(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &input_ciphertext,
+                &input_ciphertext,
+                &mismatched_bootstrap_key,
+                &keyswitch_key,
+            ),
+            Err(LweCiphertextDiscardingMuxError::BootstrapKeyDimensionMismatch)
+        );
+        let keyswitch_key_rejected = matches!(
+            engine.discard_mux_lwe_ciphertext(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &input_ciphertext,
+                &input_ciphertext,
+                &bootstrap_key,
+                &mismatched_keyswitch_key,
+            ),
+            Err(LweCiphertextDiscardingMuxError::KeyswitchKeyDimensionMismatch)
+        );
+        (
+            output_ciphertext,
+            input_ciphertext,
+            bootstrap_key,
+            mismatched_bootstrap_key,
+            keyswitch_key,
+            mismatched_keyswitch_key,
+            bootstrap_key_rejected && keyswitch_key_rejected,
+        )
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (
+            output_ciphertext,
+            input_ciphertext,
+            bootstrap_key,
+            mismatched_bootstrap_key,
+            keyswitch_key,
+            mismatched_keyswitch_key,
+            rejected,
+        ) = context;
+        maker.destroy_lwe_ciphertext(output_ciphertext);
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        maker.destroy_lwe_bootstrap_key(mismatched_bootstrap_key);
+        maker.destroy_lwe_keyswitch_key(keyswitch_key);
+        maker.destroy_lwe_keyswitch_key(mismatched_keyswitch_key);
+        rejected
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        outputs.iter().all(|rejected| *rejected)
+    }
+}
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingNotEngine` trait.
+pub struct LweCiphertextDiscardingNotFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingNotParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, InputCiphertext, OutputCiphertext>
+    Fixture<Precision, (KeyDistribution,), Engine, (InputCiphertext, OutputCiphertext)>
+    for LweCiphertextDiscardingNotFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextDiscardingNotEngine<InputCiphertext, OutputCiphertext>,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+    Maker: SynthesizesLweCiphertext<Precision, KeyDistribution, InputCiphertext>
+        + SynthesizesLweCiphertext<Precision, KeyDistribution, OutputCiphertext>,
+{
+    type Parameters = LweCiphertextDiscardingNotParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,);
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, KeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertext<Precision, KeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (OutputCiphertext, InputCiphertext);
+    type PostExecutionContext = (OutputCiphertext, InputCiphertext);
+    type Criteria = ();
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![LweCiphertextDiscardingNotParameters {
+                noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+                lwe_dimension: LweDimension(630),
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let proto_plaintext = maker.transform_raw_to_plaintext(&random_encoded_boolean());
+        let proto_input_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        let proto_output_ciphertext =
+            maker.trivially_encrypt_zero_to_lwe_ciphertext(parameters.lwe_dimension);
+        (
+            proto_plaintext,
+            proto_input_ciphertext,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_input_ciphertext, proto_output_ciphertext) = sample_proto;
+        (
+            maker.synthesize_lwe_ciphertext(proto_output_ciphertext),
+            maker.synthesize_lwe_ciphertext(proto_input_ciphertext),
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut output_ciphertext, input_ciphertext) = context;
+        unsafe {
+            engine.discard_not_lwe_ciphertext_unchecked(&mut output_ciphertext, &input_ciphertext)
+        };
+        (output_ciphertext, input_ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (output_ciphertext, input_ciphertext) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(output_ciphertext);
+        let proto_output_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_output_ciphertext);
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        let expected = encode_boolean(!decode_boolean(
+            maker.transform_plaintext_to_raw(proto_plaintext),
+        ));
+        (
+            expected,
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        verify_boolean_output(outputs)
+    }
+}
@@ -272,9 +272,17 @@ where
         _maker: &mut Maker,
         _repetition_proto: &Self::RepetitionPrototypes,
     ) -> Self::Criteria {
+        // Bootstrap keys for ternary input keys perform two external products for each coefficient
+        // of the input key.
+        let external_product_count =
+            if TypeId::of::<InputKeyDistribution>() == TypeId::of::<TernaryKeyDistribution>() {
+                LweDimension(2 * parameters.lwe_dimension.0)
+            } else {
+                parameters.lwe_dimension
+            };
         let predicted_variance: Variance =
             fix_estimate_pbs_noise::<Precision::Raw, Variance, OutputKeyDistribution>(
-                parameters.lwe_dimension,
+                external_product_count,
                 parameters.poly_size,
                 parameters.glwe_dimension,
                 parameters.decomp_base_log,
@@ -0,0 +1,234 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGgswCiphertextVector, PrototypesGlweSecretKey, PrototypesLweCiphertext,
+    PrototypesLweSecretKey, PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGgswCiphertextVector, SynthesizesLweCiphertext, SynthesizesPlaintextVector,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_core::commons::math::decomposition::SignedDecomposer;
+use concrete_core::commons::numeric::UnsignedInteger;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GgswCiphertextCount,
+    GgswCiphertextVectorEntity, GlweDimension, LogStandardDev,
+    LweCiphertextDiscardingVerticalPackingEngine, LweCiphertextEntity, LweDimension,
+    PlaintextVectorEntity, PolynomialCount, PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingVerticalPackingEngine` trait.
+pub struct LweCiphertextDiscardingVerticalPackingFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingVerticalPackingParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub ggsw_count: GgswCiphertextCount,
+    pub lut_polynomial_count: PolynomialCount,
+}
+
+// The number of bits of message stored in the look-up table, a bit of padding is kept on top.
+const MESSAGE_BITS: usize = 4;
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, GgswCiphertextVector, LookUpTable, OutputCiphertext>
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (GgswCiphertextVector, LookUpTable, OutputCiphertext),
+    > for LweCiphertextDiscardingVerticalPackingFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextDiscardingVerticalPackingEngine<
+        GgswCiphertextVector,
+        LookUpTable,
+        OutputCiphertext,
+    >,
+    GgswCiphertextVector: GgswCiphertextVectorEntity,
+    LookUpTable: PlaintextVectorEntity,
+    OutputCiphertext: LweCiphertextEntity,
+    Maker: SynthesizesGgswCiphertextVector<Precision, KeyDistribution, GgswCiphertextVector>
+        + SynthesizesPlaintextVector<Precision, LookUpTable>
+        + SynthesizesLweCiphertext<Precision, KeyDistribution, OutputCiphertext>,
+{
+    type Parameters = LweCiphertextDiscardingVerticalPackingParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        Vec<Precision::Raw>,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesGgswCiphertextVector<
+            Precision,
+            KeyDistribution,
+        >>::GgswCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertext<Precision, KeyDistribution>>::LweCiphertextProto,
+        usize,
+    );
+    type PreExecutionContext = (GgswCiphertextVector, LookUpTable, OutputCiphertext);
+    type PostExecutionContext = (GgswCiphertextVector, LookUpTable, OutputCiphertext);
+    type Criteria = ();
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                // A single polynomial in the look-up table only triggers a blind rotation.
+                LweCiphertextDiscardingVerticalPackingParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-30.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    ggsw_count: GgswCiphertextCount(4),
+                    lut_polynomial_count: PolynomialCount(1),
+                },
+                // Several polynomials trigger a cmux tree before the blind rotation.
+                LweCiphertextDiscardingVerticalPackingParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-30.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    ggsw_count: GgswCiphertextCount(10),
+                    lut_polynomial_count: PolynomialCount(4),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_glwe_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_lwe_secret_key =
+            maker.transform_glwe_secret_key_to_lwe_secret_key(&proto_glwe_secret_key);
+        let raw_lut: Vec<Precision::Raw> = Precision::Raw::uniform_between_vec(
+            0..1 << MESSAGE_BITS,
+            parameters.lut_polynomial_count.0 * parameters.polynomial_size.0,
+        )
+        .into_iter()
+        .map(|message| message << (Precision::Raw::BITS - MESSAGE_BITS - 1))
+        .collect();
+        let proto_lut = maker.transform_raw_vec_to_plaintext_vector(&raw_lut);
+        (
+            proto_glwe_secret_key,
+            proto_lwe_secret_key,
+            proto_lut,
+            raw_lut,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_glwe_secret_key, ..) = repetition_proto;
+        // The index bits are encrypted from the most significant to the least significant one.
+        let raw_bits = Precision::Raw::uniform_between_vec(0..2, parameters.ggsw_count.0);
+        let value = raw_bits.iter().fold(0, |value, bit| {
+            (value << 1) | (*bit == Precision::Raw::one()) as usize
+        });
+        // The most significant bits select a polynomial of the look-up table, and the remaining
+        // ones a coefficient of this polynomial.
+        let blind_rotation_bits =
+            parameters.ggsw_count.0 - parameters.lut_polynomial_count.0.trailing_zeros() as usize;
+        let index = (value >> blind_rotation_bits) * parameters.polynomial_size.0
+            + (value & ((1 << blind_rotation_bits) - 1));
+        let proto_bits = maker.transform_raw_vec_to_plaintext_vector(&raw_bits);
+        let proto_ggsw_vector = maker.encrypt_plaintext_vector_to_ggsw_ciphertext_vector(
+            proto_glwe_secret_key,
+            &proto_bits,
+            parameters.noise,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+        );
+        let proto_output_ciphertext = maker.trivially_encrypt_zero_to_lwe_ciphertext(LweDimension(
+            parameters.glwe_dimension.0 * parameters.polynomial_size.0,
+        ));
+        (proto_ggsw_vector, proto_output_ciphertext, index)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_lut, _) = repetition_proto;
+        let (proto_ggsw_vector, proto_output_ciphertext, _) = sample_proto;
+        let synth_ggsw_vector = maker.synthesize_ggsw_ciphertext_vector(proto_ggsw_vector);
+        let synth_lut = maker.synthesize_plaintext_vector(proto_lut);
+        let synth_output_ciphertext = maker.synthesize_lwe_ciphertext(proto_output_ciphertext);
+        (synth_ggsw_vector, synth_lut, synth_output_ciphertext)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ggsw_vector, lut, mut output_ciphertext) = context;
+        unsafe {
+            engine.discard_vertical_packing_lwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &lut,
+                &ggsw_vector,
+            )
+        };
+        (ggsw_vector, lut, output_ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (ggsw_vector, lut, output_ciphertext) = context;
+        let (_, proto_lwe_secret_key, _, raw_lut) = repetition_proto;
+        let (.., index) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(output_ciphertext);
+        let proto_output_plaintext = maker
+            .decrypt_lwe_ciphertext_to_plaintext(proto_lwe_secret_key, &proto_output_ciphertext);
+        maker.destroy_ggsw_ciphertext_vector(ggsw_vector);
+        maker.destroy_plaintext_vector(lut);
+        (
+            raw_lut[*index],
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let decomposer = SignedDecomposer::new(
+            DecompositionBaseLog(MESSAGE_BITS + 1),
+            DecompositionLevelCount(1),
+        );
+        outputs
+            .iter()
+            .all(|(expected, actual)| *expected == decomposer.closest_representable(*actual))
+    }
+}
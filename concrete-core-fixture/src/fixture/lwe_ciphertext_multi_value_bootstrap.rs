@@ -0,0 +1,328 @@
+use crate::fixture::lwe_ciphertext_discarding_bootstrap_1::fix_estimate_pbs_noise;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesLweBootstrapKey,
+    PrototypesLweCiphertext, PrototypesLweCiphertextVector, PrototypesLweSecretKey,
+    PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweCiphertext, SynthesizesLweBootstrapKey, SynthesizesLweCiphertext,
+    SynthesizesLweCiphertextVector,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_delta_std_dev;
+use concrete_core::commons::numeric::Numeric;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweCiphertextEntity,
+    GlweDimension, LogStandardDev, LutCountLog, LweBootstrapKeyEntity, LweCiphertextEntity,
+    LweCiphertextMultiValueBootstrapEngine, LweCiphertextVectorEntity, LweDimension,
+    MessageBitsCount, PaddingBitsCount, PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `LweCiphertextMultiValueBootstrapEngine` trait.
+pub struct LweCiphertextMultiValueBootstrapFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextMultiValueBootstrapParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub decomp_base_log: DecompositionBaseLog,
+    pub lut_count_log: LutCountLog,
+    pub message_bits: MessageBitsCount,
+    pub padding_bits: PaddingBitsCount,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertextVector,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (
+            BootstrapKey,
+            Accumulator,
+            InputCiphertext,
+            OutputCiphertextVector,
+        ),
+    > for LweCiphertextMultiValueBootstrapFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextMultiValueBootstrapEngine<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertextVector,
+    >,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+    Accumulator: GlweCiphertextEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    Maker: SynthesizesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            BootstrapKey,
+        > + SynthesizesGlweCiphertext<Precision, OutputKeyDistribution, Accumulator>
+        + SynthesizesLweCiphertext<Precision, InputKeyDistribution, InputCiphertext>
+        + SynthesizesLweCiphertextVector<Precision, OutputKeyDistribution, OutputCiphertextVector>,
+{
+    type Parameters = LweCiphertextMultiValueBootstrapParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweCiphertext<Precision, OutputKeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweBootstrapKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (BootstrapKey, Accumulator, InputCiphertext);
+    type PostExecutionContext = (
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertextVector,
+    );
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextMultiValueBootstrapParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+                    lwe_dimension: LweDimension(630),
+                    glwe_dimension: GlweDimension(1),
+                    poly_size: PolynomialSize(1024),
+                    decomp_level_count: DecompositionLevelCount(2),
+                    decomp_base_log: DecompositionBaseLog(10),
+                    lut_count_log: LutCountLog(1),
+                    message_bits: MessageBitsCount(3),
+                    padding_bits: PaddingBitsCount(1),
+                },
+                LweCiphertextMultiValueBootstrapParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+                    lwe_dimension: LweDimension(630),
+                    glwe_dimension: GlweDimension(1),
+                    poly_size: PolynomialSize(2048),
+                    decomp_level_count: DecompositionLevelCount(3),
+                    decomp_base_log: DecompositionBaseLog(7),
+                    lut_count_log: LutCountLog(2),
+                    message_bits: MessageBitsCount(3),
+                    padding_bits: PaddingBitsCount(1),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        // The accumulator packs the lookup tables of the functions, the j-th one being stored in
+        // the coefficients of index j modulo the number of lookup tables.
+        let message_bits = parameters.message_bits;
+        let functions = (0..1 << parameters.lut_count_log.0)
+            .map(|j| move |m: Precision::Raw| lookup_function(j, m, message_bits))
+            .collect::<Vec<_>>();
+        let functions = functions
+            .iter()
+            .map(|f| f as &dyn Fn(Precision::Raw) -> Precision::Raw)
+            .collect::<Vec<_>>();
+        let proto_accumulator = maker.generate_new_glwe_ciphertext_multi_function_accumulator(
+            functions.as_slice(),
+            parameters.message_bits,
+            parameters.padding_bits,
+            parameters.glwe_dimension,
+            parameters.poly_size,
+        );
+        let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+            &proto_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.decomp_level_count,
+            parameters.decomp_base_log,
+            parameters.noise,
+        );
+        (
+            proto_accumulator,
+            proto_lwe_secret_key,
+            proto_glwe_secret_key,
+            proto_bootstrap_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (_, proto_lwe_secret_key, ..) = repetition_proto;
+        let raw_message = Precision::Raw::uniform_between(0..1 << parameters.message_bits.0);
+        let raw_plaintext = raw_message << delta_log::<Precision::Raw>(parameters);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_input_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_lwe_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        (proto_plaintext, proto_input_ciphertext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_accumulator, _, _, proto_bootstrap_key) = repetition_proto;
+        let (_, proto_input_ciphertext) = sample_proto;
+        let synth_bootstrap_key = maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key);
+        let synth_accumulator = maker.synthesize_glwe_ciphertext(proto_accumulator);
+        let synth_input_ciphertext = maker.synthesize_lwe_ciphertext(proto_input_ciphertext);
+        (
+            synth_bootstrap_key,
+            synth_accumulator,
+            synth_input_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (bootstrap_key, accumulator, input_ciphertext) = context;
+        let output_ciphertext_vector = unsafe {
+            engine.multi_value_bootstrap_lwe_ciphertext_unchecked(
+                &input_ciphertext,
+                &accumulator,
+                &bootstrap_key,
+                parameters.lut_count_log,
+            )
+        };
+        (
+            bootstrap_key,
+            accumulator,
+            input_ciphertext,
+            output_ciphertext_vector,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (bootstrap_key, accumulator, input_ciphertext, output_ciphertext_vector) = context;
+        let (_, _, proto_glwe_secret_key, _) = repetition_proto;
+        let (proto_plaintext, _) = sample_proto;
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(output_ciphertext_vector);
+        let proto_output_lwe_secret_key =
+            maker.transform_glwe_secret_key_to_lwe_secret_key(proto_glwe_secret_key);
+        let proto_output_plaintext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            OutputKeyDistribution,
+        >>::decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+            maker,
+            &proto_output_lwe_secret_key,
+            &proto_output_ciphertext_vector,
+        );
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        maker.destroy_glwe_ciphertext(accumulator);
+        let delta_log = delta_log::<Precision::Raw>(parameters);
+        let raw_message = maker.transform_plaintext_to_raw(proto_plaintext) >> delta_log;
+        let expected = (0..1 << parameters.lut_count_log.0)
+            .map(|j| lookup_function(j, raw_message, parameters.message_bits) << delta_log)
+            .collect();
+        (
+            expected,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance: Variance =
+            fix_estimate_pbs_noise::<Precision::Raw, Variance, OutputKeyDistribution>(
+                parameters.lwe_dimension,
+                parameters.poly_size,
+                parameters.glwe_dimension,
+                parameters.decomp_base_log,
+                parameters.decomp_level_count,
+                parameters.noise,
+            );
+        (predicted_variance,)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_delta_std_dev(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+// The j-th function evaluated by the multi-value bootstrap: m -> (2j + 1) * m + j on the message
+// space, such that every output depends on both the message and the lookup table.
+fn lookup_function<Raw: RawUnsignedIntegers>(
+    j: usize,
+    m: Raw,
+    message_bits: MessageBitsCount,
+) -> Raw {
+    let message_mask = (Raw::ONE << message_bits.0) - Raw::ONE;
+    let j = Raw::cast_from(j as f64);
+    (j + j + Raw::ONE).wrapping_mul(m).wrapping_add(j) & message_mask
+}
+
+// The logarithm of the scaling factor of the messages, encoded below the padding bits.
+fn delta_log<Raw: RawUnsignedIntegers>(
+    parameters: &LweCiphertextMultiValueBootstrapParameters,
+) -> usize {
+    Raw::BITS - parameters.message_bits.0 - parameters.padding_bits.0
+}
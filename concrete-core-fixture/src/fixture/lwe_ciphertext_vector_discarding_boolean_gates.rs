@@ -0,0 +1,911 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesLweBootstrapKey, PrototypesLweCiphertextVector,
+    PrototypesLweKeyswitchKey, PrototypesLweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweBootstrapKey, SynthesizesLweCiphertextVector, SynthesizesLweKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension,
+    LogStandardDev, LweBootstrapKeyEntity, LweCiphertextCount,
+    LweCiphertextVectorDiscardingAndEngine, LweCiphertextVectorDiscardingAndError,
+    LweCiphertextVectorDiscardingNandEngine, LweCiphertextVectorDiscardingNandError,
+    LweCiphertextVectorDiscardingNorEngine, LweCiphertextVectorDiscardingNorError,
+    LweCiphertextVectorDiscardingNotEngine, LweCiphertextVectorDiscardingOrEngine,
+    LweCiphertextVectorDiscardingOrError, LweCiphertextVectorDiscardingXnorEngine,
+    LweCiphertextVectorDiscardingXnorError, LweCiphertextVectorDiscardingXorEngine,
+    LweCiphertextVectorDiscardingXorError, LweCiphertextVectorEntity, LweDimension,
+    LweKeyswitchKeyEntity, PolynomialSize, Variance,
+};
+
+#[derive(Debug)]
+pub struct LweCiphertextVectorDiscardingBooleanGateParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub decomp_level_count_bsk: DecompositionLevelCount,
+    pub decomp_base_log_bsk: DecompositionBaseLog,
+    pub decomp_level_count_ksk: DecompositionLevelCount,
+    pub decomp_base_log_ksk: DecompositionBaseLog,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+}
+
+fn generate_boolean_gate_parameters(
+) -> Box<dyn Iterator<Item = LweCiphertextVectorDiscardingBooleanGateParameters>> {
+    Box::new(
+        vec![LweCiphertextVectorDiscardingBooleanGateParameters {
+            noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+            lwe_dimension: LweDimension(630),
+            glwe_dimension: GlweDimension(1),
+            poly_size: PolynomialSize(1024),
+            decomp_level_count_bsk: DecompositionLevelCount(3),
+            decomp_base_log_bsk: DecompositionBaseLog(7),
+            decomp_level_count_ksk: DecompositionLevelCount(5),
+            decomp_base_log_ksk: DecompositionBaseLog(3),
+            lwe_ciphertext_count: LweCiphertextCount(2),
+        }]
+        .into_iter(),
+    )
+}
+
+// The keys are only used to check that mismatched dimensions are rejected, so small parameters
+// are enough.
+fn generate_key_mismatch_parameters(
+) -> Box<dyn Iterator<Item = LweCiphertextVectorDiscardingBooleanGateParameters>> {
+    Box::new(
+        vec![LweCiphertextVectorDiscardingBooleanGateParameters {
+            noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+            lwe_dimension: LweDimension(10),
+            glwe_dimension: GlweDimension(1),
+            poly_size: PolynomialSize(256),
+            decomp_level_count_bsk: DecompositionLevelCount(3),
+            decomp_base_log_bsk: DecompositionBaseLog(7),
+            decomp_level_count_ksk: DecompositionLevelCount(5),
+            decomp_base_log_ksk: DecompositionBaseLog(3),
+            lwe_ciphertext_count: LweCiphertextCount(1),
+        }]
+        .into_iter(),
+    )
+}
+
+// Booleans are encoded as 1/8 for true, and -1/8 for false.
+pub(crate) fn encode_boolean<Raw: RawUnsignedIntegers>(value: bool) -> Raw {
+    let true_plaintext = Raw::ONE << (Raw::BITS - 3);
+    if value {
+        true_plaintext
+    } else {
+        Raw::ZERO.wrapping_sub(true_plaintext)
+    }
+}
+
+// The values in the upper half of the torus are negative, and decode to false.
+pub(crate) fn decode_boolean<Raw: RawUnsignedIntegers>(value: Raw) -> bool {
+    value < Raw::ONE << (Raw::BITS - 1)
+}
+
+fn random_encoded_booleans<Raw: RawUnsignedIntegers>(count: usize) -> Vec<Raw> {
+    Raw::pick_vec(&[encode_boolean(true), encode_boolean(false)], count)
+}
+
+fn verify_boolean_outputs<Raw: RawUnsignedIntegers>(outputs: &[(Vec<Raw>, Vec<Raw>)]) -> bool {
+    outputs.iter().all(|(expected, actual)| {
+        expected
+            .iter()
+            .zip(actual.iter())
+            .all(|(e, a)| decode_boolean(*e) == decode_boolean(*a))
+    })
+}
+
+macro_rules! binary_gate_fixture {
+    ($fixture: ident, $engine_trait: ident, $method: ident, $gate: expr) => {
+        #[doc = concat!("A fixture for the types implementing the `", stringify!($engine_trait), "` trait.")]
+        pub struct $fixture;
+
+        #[allow(clippy::type_complexity)]
+        impl<
+                Precision,
+                InputKeyDistribution,
+                OutputKeyDistribution,
+                Engine,
+                CiphertextVector,
+                BootstrapKey,
+                KeyswitchKey,
+            >
+            Fixture<
+                Precision,
+                (InputKeyDistribution, OutputKeyDistribution),
+                Engine,
+                (CiphertextVector, BootstrapKey, KeyswitchKey),
+            > for $fixture
+        where
+            Precision: IntegerPrecision,
+            InputKeyDistribution: KeyDistributionMarker,
+            OutputKeyDistribution: KeyDistributionMarker,
+            Engine: $engine_trait<CiphertextVector, CiphertextVector, BootstrapKey, KeyswitchKey>,
+            CiphertextVector: LweCiphertextVectorEntity,
+            BootstrapKey: LweBootstrapKeyEntity,
+            KeyswitchKey: LweKeyswitchKeyEntity,
+            Maker: SynthesizesLweBootstrapKey<
+                    Precision,
+                    InputKeyDistribution,
+                    OutputKeyDistribution,
+                    BootstrapKey,
+                > + SynthesizesLweKeyswitchKey<
+                    Precision,
+                    OutputKeyDistribution,
+                    InputKeyDistribution,
+                    KeyswitchKey,
+                > + SynthesizesLweCiphertextVector<Precision, InputKeyDistribution, CiphertextVector>,
+        {
+            type Parameters = LweCiphertextVectorDiscardingBooleanGateParameters;
+            type RepetitionPrototypes = (
+                <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+                <Maker as PrototypesLweBootstrapKey<
+                    Precision,
+                    InputKeyDistribution,
+                    OutputKeyDistribution,
+                >>::LweBootstrapKeyProto,
+                <Maker as PrototypesLweKeyswitchKey<
+                    Precision,
+                    OutputKeyDistribution,
+                    InputKeyDistribution,
+                >>::LweKeyswitchKeyProto,
+            );
+            type SamplePrototypes = (
+                <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+                <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+                <Maker as PrototypesLweCiphertextVector<
+                    Precision,
+                    InputKeyDistribution,
+                >>::LweCiphertextVectorProto,
+                <Maker as PrototypesLweCiphertextVector<
+                    Precision,
+                    InputKeyDistribution,
+                >>::LweCiphertextVectorProto,
+                <Maker as PrototypesLweCiphertextVector<
+                    Precision,
+                    InputKeyDistribution,
+                >>::LweCiphertextVectorProto,
+            );
+            type PreExecutionContext = (
+                CiphertextVector,
+                CiphertextVector,
+                CiphertextVector,
+                BootstrapKey,
+                KeyswitchKey,
+            );
+            type PostExecutionContext = (
+                CiphertextVector,
+                CiphertextVector,
+                CiphertextVector,
+                BootstrapKey,
+                KeyswitchKey,
+            );
+            type Criteria = ();
+            type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+            fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+                generate_boolean_gate_parameters()
+            }
+
+            fn generate_random_repetition_prototypes(
+                parameters: &Self::Parameters,
+                maker: &mut Maker,
+            ) -> Self::RepetitionPrototypes {
+                let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+                    Precision,
+                    InputKeyDistribution,
+                >>::new_lwe_secret_key(
+                    maker, parameters.lwe_dimension
+                );
+                let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+                    Precision,
+                    OutputKeyDistribution,
+                >>::new_glwe_secret_key(
+                    maker, parameters.glwe_dimension, parameters.poly_size
+                );
+                let proto_large_lwe_secret_key = <Maker as PrototypesGlweSecretKey<
+                    Precision,
+                    OutputKeyDistribution,
+                >>::transform_glwe_secret_key_to_lwe_secret_key(
+                    maker, &proto_glwe_secret_key
+                );
+                let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+                    &proto_lwe_secret_key,
+                    &proto_glwe_secret_key,
+                    parameters.decomp_level_count_bsk,
+                    parameters.decomp_base_log_bsk,
+                    parameters.noise,
+                );
+                let proto_keyswitch_key = maker.new_lwe_keyswitch_key(
+                    &proto_large_lwe_secret_key,
+                    &proto_lwe_secret_key,
+                    parameters.decomp_level_count_ksk,
+                    parameters.decomp_base_log_ksk,
+                    parameters.noise,
+                );
+                (
+                    proto_lwe_secret_key,
+                    proto_bootstrap_key,
+                    proto_keyswitch_key,
+                )
+            }
+
+            fn generate_random_sample_prototypes(
+                parameters: &Self::Parameters,
+                maker: &mut Maker,
+                repetition_proto: &Self::RepetitionPrototypes,
+            ) -> Self::SamplePrototypes {
+                let (proto_lwe_secret_key, ..) = repetition_proto;
+                let proto_plaintext_vector_1 = maker.transform_raw_vec_to_plaintext_vector(
+                    &random_encoded_booleans(parameters.lwe_ciphertext_count.0),
+                );
+                let proto_plaintext_vector_2 = maker.transform_raw_vec_to_plaintext_vector(
+                    &random_encoded_booleans(parameters.lwe_ciphertext_count.0),
+                );
+                let proto_input_ciphertext_vector_1 = <Maker as PrototypesLweCiphertextVector<
+                    Precision,
+                    InputKeyDistribution,
+                >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                    maker,
+                    proto_lwe_secret_key,
+                    &proto_plaintext_vector_1,
+                    parameters.noise,
+                );
+                let proto_input_ciphertext_vector_2 = <Maker as PrototypesLweCiphertextVector<
+                    Precision,
+                    InputKeyDistribution,
+                >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                    maker,
+                    proto_lwe_secret_key,
+                    &proto_plaintext_vector_2,
+                    parameters.noise,
+                );
+                let proto_output_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+                    Precision,
+                    InputKeyDistribution,
+                >>::trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+                    maker,
+                    parameters.lwe_dimension,
+                    parameters.lwe_ciphertext_count,
+                );
+                (
+                    proto_plaintext_vector_1,
+                    proto_plaintext_vector_2,
+                    proto_input_ciphertext_vector_1,
+                    proto_input_ciphertext_vector_2,
+                    proto_output_ciphertext_vector,
+                )
+            }
+
+            fn prepare_context(
+                _parameters: &Self::Parameters,
+                maker: &mut Maker,
+                repetition_proto: &Self::RepetitionPrototypes,
+                sample_proto: &Self::SamplePrototypes,
+            ) -> Self::PreExecutionContext {
+                let (_, proto_bootstrap_key, proto_keyswitch_key) = repetition_proto;
+                let (
+                    _,
+                    _,
+                    proto_input_ciphertext_vector_1,
+                    proto_input_ciphertext_vector_2,
+                    proto_output_ciphertext_vector,
+                ) = sample_proto;
+                (
+                    maker.synthesize_lwe_ciphertext_vector(proto_output_ciphertext_vector),
+                    maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector_1),
+                    maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector_2),
+                    maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key),
+                    maker.synthesize_lwe_keyswitch_key(proto_keyswitch_key),
+                )
+            }
+
+            fn execute_engine(
+                _parameters: &Self::Parameters,
+                engine: &mut Engine,
+                context: Self::PreExecutionContext,
+            ) -> Self::PostExecutionContext {
+                let (
+                    mut output_ciphertext_vector,
+                    input_ciphertext_vector_1,
+                    input_ciphertext_vector_2,
+                    bootstrap_key,
+                    keyswitch_key,
+                ) = context;
+                unsafe {
+                    engine.$method(
+                        &mut output_ciphertext_vector,
+                        &input_ciphertext_vector_1,
+                        &input_ciphertext_vector_2,
+                        &bootstrap_key,
+                        &keyswitch_key,
+                    )
+                };
+                (
+                    output_ciphertext_vector,
+                    input_ciphertext_vector_1,
+                    input_ciphertext_vector_2,
+                    bootstrap_key,
+                    keyswitch_key,
+                )
+            }
+
+            fn process_context(
+                _parameters: &Self::Parameters,
+                maker: &mut Maker,
+                repetition_proto: &Self::RepetitionPrototypes,
+                sample_proto: &Self::SamplePrototypes,
+                context: Self::PostExecutionContext,
+            ) -> Self::Outcome {
+                let (
+                    output_ciphertext_vector,
+                    input_ciphertext_vector_1,
+                    input_ciphertext_vector_2,
+                    bootstrap_key,
+                    keyswitch_key,
+                ) = context;
+                let (proto_lwe_secret_key, ..) = repetition_proto;
+                let (proto_plaintext_vector_1, proto_plaintext_vector_2, ..) = sample_proto;
+                let proto_output_ciphertext_vector =
+                    maker.unsynthesize_lwe_ciphertext_vector(output_ciphertext_vector);
+                let proto_output_plaintext_vector = <Maker as PrototypesLweCiphertextVector<
+                    Precision,
+                    InputKeyDistribution,
+                >>::decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+                    maker,
+                    proto_lwe_secret_key,
+                    &proto_output_ciphertext_vector,
+                );
+                maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector_1);
+                maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector_2);
+                maker.destroy_lwe_bootstrap_key(bootstrap_key);
+                maker.destroy_lwe_keyswitch_key(keyswitch_key);
+                let gate: fn(bool, bool) -> bool = $gate;
+                let expected = maker
+                    .transform_plaintext_vector_to_raw_vec(proto_plaintext_vector_1)
+                    .into_iter()
+                    .zip(maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector_2))
+                    .map(|(a, b)| encode_boolean(gate(decode_boolean(a), decode_boolean(b))))
+                    .collect();
+                (
+                    expected,
+                    maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+                )
+            }
+
+            fn compute_criteria(
+                _parameters: &Self::Parameters,
+                _maker: &mut Maker,
+                _repetition_proto: &Self::RepetitionPrototypes,
+            ) -> Self::Criteria {
+            }
+
+            fn verify(
+                _parameters: &Self::Parameters,
+                _criteria: &Self::Criteria,
+                outputs: &[Self::Outcome],
+            ) -> bool {
+                verify_boolean_outputs(outputs)
+            }
+        }
+    };
+}
+
+binary_gate_fixture!(
+    LweCiphertextVectorDiscardingAndFixture,
+    LweCiphertextVectorDiscardingAndEngine,
+    discard_and_lwe_ciphertext_vector_unchecked,
+    |a, b| a & b
+);
+binary_gate_fixture!(
+    LweCiphertextVectorDiscardingNandFixture,
+    LweCiphertextVectorDiscardingNandEngine,
+    discard_nand_lwe_ciphertext_vector_unchecked,
+    |a, b| !(a & b)
+);
+binary_gate_fixture!(
+    LweCiphertextVectorDiscardingNorFixture,
+    LweCiphertextVectorDiscardingNorEngine,
+    discard_nor_lwe_ciphertext_vector_unchecked,
+    |a, b| !(a | b)
+);
+binary_gate_fixture!(
+    LweCiphertextVectorDiscardingOrFixture,
+    LweCiphertextVectorDiscardingOrEngine,
+    discard_or_lwe_ciphertext_vector_unchecked,
+    |a, b| a | b
+);
+binary_gate_fixture!(
+    LweCiphertextVectorDiscardingXorFixture,
+    LweCiphertextVectorDiscardingXorEngine,
+    discard_xor_lwe_ciphertext_vector_unchecked,
+    |a, b| a ^ b
+);
+binary_gate_fixture!(
+    LweCiphertextVectorDiscardingXnorFixture,
+    LweCiphertextVectorDiscardingXnorEngine,
+    discard_xnor_lwe_ciphertext_vector_unchecked,
+    |a, b| !(a ^ b)
+);
+
+macro_rules! binary_gate_key_mismatch_fixture {
+    ($fixture: ident, $engine_trait: ident, $error: ident, $method: ident) => {
+        #[doc = concat!("A fixture checking that the types implementing the `", stringify!($engine_trait), "` trait reject keys whose dimensions do not match the inputs.")]
+        pub struct $fixture;
+
+        #[allow(clippy::type_complexity)]
+        impl<
+                Precision,
+                InputKeyDistribution,
+                OutputKeyDistribution,
+                Engine,
+                CiphertextVector,
+                BootstrapKey,
+                KeyswitchKey,
+            >
+            Fixture<
+                Precision,
+                (InputKeyDistribution, OutputKeyDistribution),
+                Engine,
+                (CiphertextVector, BootstrapKey, KeyswitchKey),
+            > for $fixture
+        where
+            Precision: IntegerPrecision,
+            InputKeyDistribution: KeyDistributionMarker,
+            OutputKeyDistribution: KeyDistributionMarker,
+            Engine: $engine_trait<CiphertextVector, CiphertextVector, BootstrapKey, KeyswitchKey>,
+            CiphertextVector: LweCiphertextVectorEntity,
+            BootstrapKey: LweBootstrapKeyEntity,
+            KeyswitchKey: LweKeyswitchKeyEntity,
+            Maker: SynthesizesLweBootstrapKey<
+                    Precision,
+                    InputKeyDistribution,
+                    OutputKeyDistribution,
+                    BootstrapKey,
+                > + SynthesizesLweKeyswitchKey<
+                    Precision,
+                    OutputKeyDistribution,
+                    InputKeyDistribution,
+                    KeyswitchKey,
+                > + SynthesizesLweCiphertextVector<Precision, InputKeyDistribution, CiphertextVector>,
+        {
+            type Parameters = LweCiphertextVectorDiscardingBooleanGateParameters;
+            type RepetitionPrototypes = (
+                <Maker as PrototypesLweBootstrapKey<
+                    Precision,
+                    InputKeyDistribution,
+                    OutputKeyDistribution,
+                >>::LweBootstrapKeyProto,
+                <Maker as PrototypesLweBootstrapKey<
+                    Precision,
+                    InputKeyDistribution,
+                    OutputKeyDistribution,
+                >>::LweBootstrapKeyProto,
+                <Maker as PrototypesLweKeyswitchKey<
+                    Precision,
+                    OutputKeyDistribution,
+                    InputKeyDistribution,
+                >>::LweKeyswitchKeyProto,
+                <Maker as PrototypesLweKeyswitchKey<
+                    Precision,
+                    OutputKeyDistribution,
+                    InputKeyDistribution,
+                >>::LweKeyswitchKeyProto,
+            );
+            type SamplePrototypes = (
+                <Maker as PrototypesLweCiphertextVector<
+                    Precision,
+                    InputKeyDistribution,
+                >>::LweCiphertextVectorProto,
+            );
+            type PreExecutionContext = (
+                CiphertextVector,
+                CiphertextVector,
+                BootstrapKey,
+                BootstrapKey,
+                KeyswitchKey,
+                KeyswitchKey,
+            );
+            type PostExecutionContext = (
+                CiphertextVector,
+                CiphertextVector,
+                BootstrapKey,
+                BootstrapKey,
+                KeyswitchKey,
+                KeyswitchKey,
+                bool,
+            );
+            type Criteria = ();
+            type Outcome = bool;
+
+            fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+                generate_key_mismatch_parameters()
+            }
+
+            fn generate_random_repetition_prototypes(
+                parameters: &Self::Parameters,
+                maker: &mut Maker,
+            ) -> Self::RepetitionPrototypes {
+                let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+                    Precision,
+                    InputKeyDistribution,
+                >>::new_lwe_secret_key(
+                    maker, parameters.lwe_dimension
+                );
+                // A secret key of a different dimension, used to generate the mismatched keys.
+                let proto_other_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+                    Precision,
+                    InputKeyDistribution,
+                >>::new_lwe_secret_key(
+                    maker, LweDimension(parameters.lwe_dimension.0 + 1)
+                );
+                let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+                    Precision,
+                    OutputKeyDistribution,
+                >>::new_glwe_secret_key(
+                    maker, parameters.glwe_dimension, parameters.poly_size
+                );
+                let proto_large_lwe_secret_key = <Maker as PrototypesGlweSecretKey<
+                    Precision,
+                    OutputKeyDistribution,
+                >>::transform_glwe_secret_key_to_lwe_secret_key(
+                    maker, &proto_glwe_secret_key
+                );
+                let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+                    &proto_lwe_secret_key,
+                    &proto_glwe_secret_key,
+                    parameters.decomp_level_count_bsk,
+                    parameters.decomp_base_log_bsk,
+                    parameters.noise,
+                );
+                let proto_mismatched_bootstrap_key = maker.new_lwe_bootstrap_key(
+                    &proto_other_lwe_secret_key,
+                    &proto_glwe_secret_key,
+                    parameters.decomp_level_count_bsk,
+                    parameters.decomp_base_log_bsk,
+                    parameters.noise,
+                );
+                let proto_keyswitch_key = maker.new_lwe_keyswitch_key(
+                    &proto_large_lwe_secret_key,
+                    &proto_lwe_secret_key,
+                    parameters.decomp_level_count_ksk,
+                    parameters.decomp_base_log_ksk,
+                    parameters.noise,
+                );
+                let proto_mismatched_keyswitch_key = maker.new_lwe_keyswitch_key(
+                    &proto_large_lwe_secret_key,
+                    &proto_other_lwe_secret_key,
+                    parameters.decomp_level_count_ksk,
+                    parameters.decomp_base_log_ksk,
+                    parameters.noise,
+                );
+                (
+                    proto_bootstrap_key,
+                    proto_mismatched_bootstrap_key,
+                    proto_keyswitch_key,
+                    proto_mismatched_keyswitch_key,
+                )
+            }
+
+            fn generate_random_sample_prototypes(
+                parameters: &Self::Parameters,
+                maker: &mut Maker,
+                _repetition_proto: &Self::RepetitionPrototypes,
+            ) -> Self::SamplePrototypes {
+                let proto_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+                    Precision,
+                    InputKeyDistribution,
+                >>::trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+                    maker,
+                    parameters.lwe_dimension,
+                    parameters.lwe_ciphertext_count,
+                );
+                (proto_ciphertext_vector,)
+            }
+
+            fn prepare_context(
+                _parameters: &Self::Parameters,
+                maker: &mut Maker,
+                repetition_proto: &Self::RepetitionPrototypes,
+                sample_proto: &Self::SamplePrototypes,
+            ) -> Self::PreExecutionContext {
+                let (
+                    proto_bootstrap_key,
+                    proto_mismatched_bootstrap_key,
+                    proto_keyswitch_key,
+                    proto_mismatched_keyswitch_key,
+                ) = repetition_proto;
+                let (proto_ciphertext_vector,) = sample_proto;
+                (
+                    maker.synthesize_lwe_ciphertext_vector(proto_ciphertext_vector),
+                    maker.synthesize_lwe_ciphertext_vector(proto_ciphertext_vector),
+                    maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key),
+                    maker.synthesize_lwe_bootstrap_key(proto_mismatched_bootstrap_key),
+                    maker.synthesize_lwe_keyswitch_key(proto_keyswitch_key),
+                    maker.synthesize_lwe_keyswitch_key(proto_mismatched_keyswitch_key),
+                )
+            }
+
+            fn execute_engine(
+                _parameters: &Self::Parameters,
+                engine: &mut Engine,
+                context: Self::PreExecutionContext,
+            ) -> Self::PostExecutionContext {
+                let (
+                    mut output_ciphertext_vector,
+                    input_ciphertext_vector,
+                    bootstrap_key,
+                    mismatched_bootstrap_key,
+                    keyswitch_key,
+                    mismatched_keyswitch_key,
+                ) = context;
+                let bootstrap_key_rejected = matches!(
+                    engine.$method(
+                        &mut output_ciphertext_vector,
+                        &input_ciphertext_vector,
+                        &input_ciphertext_vector,
+                        &mismatched_bootstrap_key,
+                        &keyswitch_key,
+                    ),
+                    Err($error::BootstrapKeyDimensionMismatch)
+                );
+                let keyswitch_key_rejected = matches!(
+                    engine.$method(
+                        &mut output_ciphertext_vector,
+                        &input_ciphertext_vector,
+                        &input_ciphertext_vector,
+                        &bootstrap_key,
+                        &mismatched_keyswitch_key,
+                    ),
+                    Err($error::KeyswitchKeyDimensionMismatch)
+                );
+                (
+                    output_ciphertext_vector,
+                    input_ciphertext_vector,
+                    bootstrap_key,
+                    mismatched_bootstrap_key,
+                    keyswitch_key,
+                    mismatched_keyswitch_key,
+                    bootstrap_key_rejected && keyswitch_key_rejected,
+                )
+            }
+
+            fn process_context(
+                _parameters: &Self::Parameters,
+                maker: &mut Maker,
+                _repetition_proto: &Self::RepetitionPrototypes,
+                _sample_proto: &Self::SamplePrototypes,
+                context: Self::PostExecutionContext,
+            ) -> Self::Outcome {
+                let (
+                    output_ciphertext_vector,
+                    input_ciphertext_vector,
+                    bootstrap_key,
+                    mismatched_bootstrap_key,
+                    keyswitch_key,
+                    mismatched_keyswitch_key,
+                    rejected,
+                ) = context;
+                maker.destroy_lwe_ciphertext_vector(output_ciphertext_vector);
+                maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector);
+                maker.destroy_lwe_bootstrap_key(bootstrap_key);
+                maker.destroy_lwe_bootstrap_key(mismatched_bootstrap_key);
+                maker.destroy_lwe_keyswitch_key(keyswitch_key);
+                maker.destroy_lwe_keyswitch_key(mismatched_keyswitch_key);
+                rejected
+            }
+
+            fn compute_criteria(
+                _parameters: &Self::Parameters,
+                _maker: &mut Maker,
+                _repetition_proto: &Self::RepetitionPrototypes,
+            ) -> Self::Criteria {
+            }
+
+            fn verify(
+                _parameters: &Self::Parameters,
+                _criteria: &Self::Criteria,
+                outputs: &[Self::Outcome],
+            ) -> bool {
+                outputs.iter().all(|rejected| *rejected)
+            }
+        }
+    };
+}
+
+binary_gate_key_mismatch_fixture!(
+    LweCiphertextVectorDiscardingAndKeyMismatchFixture,
+    LweCiphertextVectorDiscardingAndEngine,
+    LweCiphertextVectorDiscardingAndError,
+    discard_and_lwe_ciphertext_vector
+);
+binary_gate_key_mismatch_fixture!(
+    LweCiphertextVectorDiscardingNandKeyMismatchFixture,
+    LweCiphertextVectorDiscardingNandEngine,
+    LweCiphertextVectorDiscardingNandError,
+    discard_nand_lwe_ciphertext_vector
+);
+binary_gate_key_mismatch_fixture!(
+    LweCiphertextVectorDiscardingNorKeyMismatchFixture,
+    LweCiphertextVectorDiscardingNorEngine,
+    LweCiphertextVectorDiscardingNorError,
+    discard_nor_lwe_ciphertext_vector
+);
+binary_gate_key_mismatch_fixture!(
+    LweCiphertextVectorDiscardingOrKeyMismatchFixture,
+    LweCiphertextVectorDiscardingOrEngine,
+    LweCiphertextVectorDiscardingOrError,
+    discard_or_lwe_ciphertext_vector
+);
+binary_gate_key_mismatch_fixture!(
+    LweCiphertextVectorDiscardingXorKeyMismatchFixture,
+    LweCiphertextVectorDiscardingXorEngine,
+    LweCiphertextVectorDiscardingXorError,
+    discard_xor_lwe_ciphertext_vector
+);
+binary_gate_key_mismatch_fixture!(
+    LweCiphertextVectorDiscardingXnorKeyMismatchFixture,
+    LweCiphertextVectorDiscardingXnorEngine,
+    LweCiphertextVectorDiscardingXnorError,
+    discard_xnor_lwe_ciphertext_vector
+);
+
+/// A fixture for the types implementing the `LweCiphertextVectorDiscardingNotEngine` trait.
+pub struct LweCiphertextVectorDiscardingNotFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextVectorDiscardingNotParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, InputCiphertextVector, OutputCiphertextVector>
+    Fixture<Precision, (KeyDistribution,), Engine, (InputCiphertextVector, OutputCiphertextVector)>
+    for LweCiphertextVectorDiscardingNotFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextVectorDiscardingNotEngine<InputCiphertextVector, OutputCiphertextVector>,
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+    Maker: SynthesizesLweCiphertextVector<Precision, KeyDistribution, InputCiphertextVector>
+        + SynthesizesLweCiphertextVector<Precision, KeyDistribution, OutputCiphertextVector>,
+{
+    type Parameters = LweCiphertextVectorDiscardingNotParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,);
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<Precision, KeyDistribution>>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<Precision, KeyDistribution>>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (OutputCiphertextVector, InputCiphertextVector);
+    type PostExecutionContext = (OutputCiphertextVector, InputCiphertextVector);
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![LweCiphertextVectorDiscardingNotParameters {
+                noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+                lwe_dimension: LweDimension(630),
+                lwe_ciphertext_count: LweCiphertextCount(100),
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let proto_plaintext_vector = maker.transform_raw_vec_to_plaintext_vector(
+            &random_encoded_booleans(parameters.lwe_ciphertext_count.0),
+        );
+        let proto_input_ciphertext_vector = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                proto_secret_key,
+                &proto_plaintext_vector,
+                parameters.noise,
+            );
+        let proto_output_ciphertext_vector = maker
+            .trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+                parameters.lwe_dimension,
+                parameters.lwe_ciphertext_count,
+            );
+        (
+            proto_plaintext_vector,
+            proto_input_ciphertext_vector,
+            proto_output_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_input_ciphertext_vector, proto_output_ciphertext_vector) = sample_proto;
+        (
+            maker.synthesize_lwe_ciphertext_vector(proto_output_ciphertext_vector),
+            maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector),
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut output_ciphertext_vector, input_ciphertext_vector) = context;
+        unsafe {
+            engine.discard_not_lwe_ciphertext_vector_unchecked(
+                &mut output_ciphertext_vector,
+                &input_ciphertext_vector,
+            )
+        };
+        (output_ciphertext_vector, input_ciphertext_vector)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (output_ciphertext_vector, input_ciphertext_vector) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext_vector, ..) = sample_proto;
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(output_ciphertext_vector);
+        let proto_output_plaintext_vector = maker
+            .decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+                proto_secret_key,
+                &proto_output_ciphertext_vector,
+            );
+        maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector);
+        let expected = maker
+            .transform_plaintext_vector_to_raw_vec(proto_plaintext_vector)
+            .into_iter()
+            .map(|a| encode_boolean(!decode_boolean(a)))
+            .collect();
+        (
+            expected,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        verify_boolean_outputs(outputs)
+    }
+}
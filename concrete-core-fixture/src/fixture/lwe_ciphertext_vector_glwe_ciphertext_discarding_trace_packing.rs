@@ -0,0 +1,249 @@
+use crate::fixture::glwe_ciphertext_discarding_trace::fix_estimate_trace_noise;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweAutomorphismKey, PrototypesGlweCiphertext, PrototypesGlweSecretKey,
+    PrototypesLweCiphertextVector, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweAutomorphismKey, SynthesizesGlweCiphertext, SynthesizesLweCiphertextVector,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweAutomorphismKeyEntity, GlweCiphertextEntity,
+    GlweDimension, LogStandardDev, LweCiphertextCount, LweCiphertextVectorEntity,
+    LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine, PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the
+/// `LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine` trait.
+pub struct LweCiphertextVectorGlweCiphertextDiscardingTracePackingFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextVectorGlweCiphertextDiscardingTracePackingParameters {
+    pub n_bit_msg: usize,
+    pub input_noise: Variance,
+    pub ak_noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub decomp_base_log: DecompositionBaseLog,
+    pub count: LweCiphertextCount,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, AutomorphismKey, CiphertextVector, OutputCiphertext>
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (AutomorphismKey, CiphertextVector, OutputCiphertext),
+    > for LweCiphertextVectorGlweCiphertextDiscardingTracePackingFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine<
+        AutomorphismKey,
+        CiphertextVector,
+        OutputCiphertext,
+    >,
+    CiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+    Maker: SynthesizesGlweAutomorphismKey<Precision, KeyDistribution, AutomorphismKey>
+        + SynthesizesLweCiphertextVector<Precision, KeyDistribution, CiphertextVector>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, OutputCiphertext>,
+{
+    type Parameters = LweCiphertextVectorGlweCiphertextDiscardingTracePackingParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweAutomorphismKey<Precision, KeyDistribution>>::GlweAutomorphismKeyProto,
+    );
+    type SamplePrototypes = (
+        Vec<Precision::Raw>,
+        <Maker as PrototypesLweCiphertextVector<Precision, KeyDistribution>>::LweCiphertextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (OutputCiphertext, CiphertextVector, AutomorphismKey);
+    type PostExecutionContext = (OutputCiphertext, CiphertextVector, AutomorphismKey);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextVectorGlweCiphertextDiscardingTracePackingParameters {
+                    n_bit_msg: 4,
+                    input_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    ak_noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    decomp_level_count: DecompositionLevelCount(8),
+                    decomp_base_log: DecompositionBaseLog(3),
+                    count: LweCiphertextCount(16),
+                },
+                LweCiphertextVectorGlweCiphertextDiscardingTracePackingParameters {
+                    n_bit_msg: 4,
+                    input_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    ak_noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(512),
+                    decomp_level_count: DecompositionLevelCount(8),
+                    decomp_base_log: DecompositionBaseLog(3),
+                    count: LweCiphertextCount(100),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_automorphism_key = maker.new_glwe_automorphism_key(
+            &proto_secret_key,
+            parameters.decomp_level_count,
+            parameters.decomp_base_log,
+            parameters.ak_noise,
+        );
+        (proto_secret_key, proto_automorphism_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_glwe_secret_key, _) = repetition_proto;
+        // The packing multiplies the messages by the polynomial size, hence they are encoded with
+        // a scaling factor divided by N.
+        let log_poly_size = parameters.polynomial_size.log2().0;
+        let raw_plaintext_vector: Vec<Precision::Raw> =
+            Precision::Raw::uniform_n_msb_vec(parameters.n_bit_msg, parameters.count.0)
+                .into_iter()
+                .map(|raw| raw >> log_poly_size)
+                .collect();
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_lwe_secret_key =
+            maker.transform_glwe_secret_key_to_lwe_secret_key(proto_glwe_secret_key);
+        let proto_input_ciphertext_vector = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+                &proto_lwe_secret_key,
+                &proto_plaintext_vector,
+                parameters.input_noise,
+            );
+        let proto_output_ciphertext = maker.trivially_encrypt_zeros_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+        );
+        // The i-th message lands on the coefficient of degree i * N / 2^l, 2^l being the number
+        // of inputs rounded up to a power of two. Every other coefficient encrypts zero.
+        let stride = parameters.polynomial_size.0 / parameters.count.0.next_power_of_two();
+        let mut raw_expected = Precision::Raw::zero_vec(parameters.polynomial_size.0);
+        for (i, raw) in raw_plaintext_vector.iter().enumerate() {
+            raw_expected[i * stride] = *raw << log_poly_size;
+        }
+        (
+            raw_expected,
+            proto_input_ciphertext_vector,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_automorphism_key) = repetition_proto;
+        let (_, proto_input_ciphertext_vector, proto_output_ciphertext) = sample_proto;
+        let synth_automorphism_key = maker.synthesize_glwe_automorphism_key(proto_automorphism_key);
+        let synth_input_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector);
+        let synth_output_ciphertext = maker.synthesize_glwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_output_ciphertext,
+            synth_input_ciphertext_vector,
+            synth_automorphism_key,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut output_ciphertext, input_ciphertext_vector, automorphism_key) = context;
+        unsafe {
+            engine.discard_trace_packing_lwe_ciphertext_vector_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext_vector,
+                &automorphism_key,
+            )
+        };
+        (output_ciphertext, input_ciphertext_vector, automorphism_key)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (output_ciphertext, input_ciphertext_vector, automorphism_key) = context;
+        let (proto_secret_key, _) = repetition_proto;
+        let (raw_expected, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector);
+        maker.destroy_glwe_automorphism_key(automorphism_key);
+        (
+            raw_expected.to_owned(),
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // The merging steps and the final trace double the ciphertexts log2(N) times overall, as
+        // in a full trace. The automorphism key noise is doubled to account for the merged
+        // ciphertexts carrying the keyswitch noise of both of their halves.
+        let predicted_variance = fix_estimate_trace_noise::<Precision::Raw, KeyDistribution>(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+            parameters.input_noise,
+            Variance(2. * parameters.ak_noise.0),
+            parameters.decomp_base_log,
+            parameters.decomp_level_count,
+        );
+        (predicted_variance,)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
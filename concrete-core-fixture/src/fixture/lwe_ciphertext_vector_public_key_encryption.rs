@@ -0,0 +1,187 @@
+use concrete_core::prelude::{
+    DispersionParameter, LweCiphertextCount, LweCiphertextVectorEntity,
+    LweCiphertextVectorPublicKeyEncryptionEngine, LweDimension, LwePublicKeyEntity,
+    LwePublicKeyZeroEncryptionCount, PlaintextVectorEntity, Variance,
+};
+
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertextVector, PrototypesLwePublicKey, PrototypesLweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCiphertextVector, SynthesizesLwePublicKey, SynthesizesPlaintextVector,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_delta_std_dev;
+
+/// A fixture for the types implementing the `LweCiphertextVectorPublicKeyEncryptionEngine` trait.
+pub struct LweCiphertextVectorPublicKeyEncryptionFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextVectorPublicKeyEncryptionParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+}
+
+impl<Precision, KeyDistribution, Engine, PlaintextVector, PublicKey, CiphertextVector>
+    Fixture<Precision, (KeyDistribution,), Engine, (PlaintextVector, PublicKey, CiphertextVector)>
+    for LweCiphertextVectorPublicKeyEncryptionFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine:
+        LweCiphertextVectorPublicKeyEncryptionEngine<PublicKey, PlaintextVector, CiphertextVector>,
+    PlaintextVector: PlaintextVectorEntity,
+    PublicKey: LwePublicKeyEntity,
+    CiphertextVector: LweCiphertextVectorEntity,
+    Maker: SynthesizesPlaintextVector<Precision, PlaintextVector>
+        + SynthesizesLwePublicKey<Precision, KeyDistribution, PublicKey>
+        + SynthesizesLweCiphertextVector<Precision, KeyDistribution, CiphertextVector>,
+{
+    type Parameters = LweCiphertextVectorPublicKeyEncryptionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLwePublicKey<Precision, KeyDistribution>>::LwePublicKeyProto,
+    );
+    type SamplePrototypes =
+        (<Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,);
+    type PreExecutionContext = (PublicKey, PlaintextVector);
+    type PostExecutionContext = (PublicKey, PlaintextVector, CiphertextVector);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextVectorPublicKeyEncryptionParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(1),
+                    lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount(1),
+                    lwe_ciphertext_count: LweCiphertextCount(1),
+                },
+                LweCiphertextVectorPublicKeyEncryptionParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(630),
+                    lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount(10),
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        let proto_public_key = maker.generate_new_lwe_public_key(
+            &proto_secret_key,
+            parameters.lwe_public_key_zero_encryption_count,
+            parameters.noise,
+        );
+        (proto_secret_key, proto_public_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.lwe_ciphertext_count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        (proto_plaintext_vector,)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_public_key) = repetition_proto;
+        let (proto_plaintext_vector,) = sample_proto;
+        (
+            maker.synthesize_lwe_public_key(proto_public_key),
+            maker.synthesize_plaintext_vector(proto_plaintext_vector),
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (public_key, plaintext_vector) = context;
+        let ciphertext_vector = unsafe {
+            engine.encrypt_lwe_ciphertext_vector_with_public_key_unchecked(
+                &public_key,
+                &plaintext_vector,
+            )
+        };
+        (public_key, plaintext_vector, ciphertext_vector)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (proto_plaintext_vector,) = sample_proto;
+        let (proto_secret_key, _) = repetition_proto;
+        let (public_key, plaintext_vector, ciphertext_vector) = context;
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(ciphertext_vector);
+        let proto_output_plaintext_vector = maker
+            .decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+                proto_secret_key,
+                &proto_output_ciphertext_vector,
+            );
+        maker.destroy_plaintext_vector(plaintext_vector);
+        maker.destroy_lwe_public_key(public_key);
+        (
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector),
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (public_key_encryption_noise_bound(
+            parameters.noise,
+            parameters.lwe_public_key_zero_encryption_count,
+        ),)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_delta_std_dev(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+}
+
+/// Returns a bound on the variance of the noise of a ciphertext encrypted with a public key.
+///
+/// The encryption sums a random subset of the encryptions of zero of the public key, whose noise
+/// variance is at most the one of the sum of all of them.
+pub(crate) fn public_key_encryption_noise_bound(
+    noise: Variance,
+    lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+) -> Variance {
+    Variance(noise.get_variance() * lwe_public_key_zero_encryption_count.0 as f64)
+}
@@ -0,0 +1,288 @@
+use crate::fixture::lwe_ciphertext_discarding_keyswitch::fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCiphertext, SynthesizesLweKeyswitchKey, SynthesizesLweSecretKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, LogStandardDev,
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextEntity, LweDimension,
+    LweKeyswitchKeyEntity, LweKeyswitchKeyGenerationEngine, LweSecretKeyEntity, Variance,
+};
+
+/// A fixture for the types implementing both the `LweKeyswitchKeyGenerationEngine` and the
+/// `LweCiphertextDiscardingKeyswitchEngine` traits.
+///
+/// The keyswitch key is generated by the engine from secret keys of the given distributions, and
+/// is then used to keyswitch a ciphertext, whose decryption is checked against the noise expected
+/// for such keys.
+pub struct LweKeyswitchKeyGenerationKeyswitchFixture;
+
+#[derive(Debug)]
+pub struct LweKeyswitchKeyGenerationKeyswitchParameters {
+    pub n_bit_msg: usize,
+    pub input_noise: Variance,
+    pub ksk_noise: Variance,
+    pub input_lwe_dimension: LweDimension,
+    pub output_lwe_dimension: LweDimension,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub decomp_base_log: DecompositionBaseLog,
+}
+
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        InputSecretKey,
+        OutputSecretKey,
+        KeyswitchKey,
+        InputCiphertext,
+        OutputCiphertext,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (
+            InputSecretKey,
+            OutputSecretKey,
+            KeyswitchKey,
+            InputCiphertext,
+            OutputCiphertext,
+        ),
+    > for LweKeyswitchKeyGenerationKeyswitchFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweKeyswitchKeyGenerationEngine<InputSecretKey, OutputSecretKey, KeyswitchKey>
+        + LweCiphertextDiscardingKeyswitchEngine<KeyswitchKey, InputCiphertext, OutputCiphertext>,
+    InputSecretKey: LweSecretKeyEntity,
+    OutputSecretKey: LweSecretKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+    Maker: SynthesizesLweKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            KeyswitchKey,
+        > + SynthesizesLweSecretKey<Precision, InputKeyDistribution, InputSecretKey>
+        + SynthesizesLweSecretKey<Precision, OutputKeyDistribution, OutputSecretKey>
+        + SynthesizesLweCiphertext<Precision, InputKeyDistribution, InputCiphertext>
+        + SynthesizesLweCiphertext<Precision, OutputKeyDistribution, OutputCiphertext>,
+{
+    type Parameters = LweKeyswitchKeyGenerationKeyswitchParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, OutputKeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertext<Precision, OutputKeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (
+        InputSecretKey,
+        OutputSecretKey,
+        OutputCiphertext,
+        InputCiphertext,
+    );
+    type PostExecutionContext = (
+        InputSecretKey,
+        OutputSecretKey,
+        OutputCiphertext,
+        InputCiphertext,
+        KeyswitchKey,
+    );
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![LweKeyswitchKeyGenerationKeyswitchParameters {
+                n_bit_msg: 8,
+                input_noise: Variance(LogStandardDev::from_log_standard_dev(-10.).get_variance()),
+                ksk_noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                input_lwe_dimension: LweDimension(600),
+                output_lwe_dimension: LweDimension(1024),
+                decomp_level_count: DecompositionLevelCount(8),
+                decomp_base_log: DecompositionBaseLog(3),
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_input_secret_key =
+            <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::new_lwe_secret_key(
+                maker,
+                parameters.input_lwe_dimension,
+            );
+        let proto_output_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_lwe_secret_key(
+            maker, parameters.output_lwe_dimension
+        );
+        (proto_input_secret_key, proto_output_secret_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_input_secret_key, _) = repetition_proto;
+        let raw_plaintext = Precision::Raw::uniform_n_msb(parameters.n_bit_msg);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_input_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_input_secret_key,
+            &proto_plaintext,
+            parameters.input_noise,
+        );
+        let proto_output_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            OutputKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            maker, parameters.output_lwe_dimension
+        );
+        (
+            proto_plaintext,
+            proto_input_ciphertext,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_input_secret_key, proto_output_secret_key) = repetition_proto;
+        let (_, proto_input_ciphertext, proto_output_ciphertext) = sample_proto;
+        let synth_input_secret_key = maker.synthesize_lwe_secret_key(proto_input_secret_key);
+        let synth_output_secret_key = maker.synthesize_lwe_secret_key(proto_output_secret_key);
+        let synth_input_ciphertext = maker.synthesize_lwe_ciphertext(proto_input_ciphertext);
+        let synth_output_ciphertext = maker.synthesize_lwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_input_secret_key,
+            synth_output_secret_key,
+            synth_output_ciphertext,
+            synth_input_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_secret_key, output_secret_key, mut output_ciphertext, input_ciphertext) =
+            context;
+        let keyswitch_key = unsafe {
+            engine.generate_new_lwe_keyswitch_key_unchecked(
+                &input_secret_key,
+                &output_secret_key,
+                parameters.decomp_level_count,
+                parameters.decomp_base_log,
+                parameters.ksk_noise,
+            )
+        };
+        unsafe {
+            engine.discard_keyswitch_lwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &keyswitch_key,
+            )
+        };
+        (
+            input_secret_key,
+            output_secret_key,
+            output_ciphertext,
+            input_ciphertext,
+            keyswitch_key,
+        )
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (
+            input_secret_key,
+            output_secret_key,
+            output_ciphertext,
+            input_ciphertext,
+            keyswitch_key,
+        ) = context;
+        let (_, proto_output_secret_key) = repetition_proto;
+        let (proto_plaintext, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(output_ciphertext);
+        let proto_output_plaintext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            OutputKeyDistribution,
+        >>::decrypt_lwe_ciphertext_to_plaintext(
+            maker,
+            proto_output_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_lwe_secret_key(input_secret_key);
+        maker.destroy_lwe_secret_key(output_secret_key);
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        maker.destroy_lwe_keyswitch_key(keyswitch_key);
+        (
+            maker.transform_plaintext_to_raw(proto_plaintext),
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // The noise added by the decomposition of the input mask depends on the distribution of
+        // the input key.
+        let predicted_variance: Variance =
+            fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+                Precision::Raw,
+                _,
+                _,
+                InputKeyDistribution,
+            >(
+                parameters.input_lwe_dimension,
+                parameters.input_noise,
+                parameters.ksk_noise,
+                parameters.decomp_base_log,
+                parameters.decomp_level_count,
+            );
+        (predicted_variance,)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
@@ -0,0 +1,167 @@
+use concrete_core::prelude::{
+    LweCiphertextCount, LweDimension, LwePublicKeyZeroEncryptionCount, LweSecretKeyEntity,
+    LweSeededPublicKeyEntity, LweSeededPublicKeyGenerationEngine, Variance,
+};
+
+use crate::fixture::{public_key_encryption_noise_bound, Fixture};
+use crate::generation::prototyping::{
+    PrototypesLweCiphertextVector, PrototypesLwePublicKey, PrototypesLweSecretKey,
+    PrototypesLweSeededPublicKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesLweSecretKey, SynthesizesLweSeededPublicKey};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_delta_std_dev;
+
+/// A fixture for the types implementing the `LweSeededPublicKeyGenerationEngine` trait.
+///
+/// The generated key is expanded into a regular public key, which is used to encrypt plaintexts
+/// whose decryption is checked.
+pub struct LweSeededPublicKeyGenerationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededPublicKeyGenerationParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+}
+
+impl<Precision, KeyDistribution, Engine, SecretKey, SeededPublicKey>
+    Fixture<Precision, (KeyDistribution,), Engine, (SecretKey, SeededPublicKey)>
+    for LweSeededPublicKeyGenerationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededPublicKeyGenerationEngine<SecretKey, SeededPublicKey>,
+    SecretKey: LweSecretKeyEntity,
+    SeededPublicKey: LweSeededPublicKeyEntity,
+    Maker: SynthesizesLweSecretKey<Precision, KeyDistribution, SecretKey>
+        + SynthesizesLweSeededPublicKey<Precision, KeyDistribution, SeededPublicKey>,
+{
+    type Parameters = LweSeededPublicKeyGenerationParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,);
+    type SamplePrototypes =
+        (<Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,);
+    type PreExecutionContext = (SecretKey,);
+    type PostExecutionContext = (SecretKey, SeededPublicKey);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededPublicKeyGenerationParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(1),
+                    lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount(1),
+                    lwe_ciphertext_count: LweCiphertextCount(1),
+                },
+                LweSeededPublicKeyGenerationParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(630),
+                    lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount(10),
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        (maker.new_lwe_secret_key(parameters.lwe_dimension),)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.lwe_ciphertext_count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        (proto_plaintext_vector,)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = repetition_proto;
+        (maker.synthesize_lwe_secret_key(proto_secret_key),)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key,) = context;
+        let seeded_public_key = unsafe {
+            engine.generate_new_lwe_seeded_public_key_unchecked(
+                &secret_key,
+                parameters.noise,
+                parameters.lwe_public_key_zero_encryption_count,
+            )
+        };
+        (secret_key, seeded_public_key)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (proto_plaintext_vector,) = sample_proto;
+        let (proto_secret_key,) = repetition_proto;
+        let (secret_key, seeded_public_key) = context;
+        let proto_seeded_public_key = maker.unsynthesize_lwe_seeded_public_key(seeded_public_key);
+        let proto_public_key =
+            maker.transform_lwe_seeded_public_key_to_lwe_public_key(&proto_seeded_public_key);
+        let proto_ciphertext_vector = maker
+            .encrypt_plaintext_vector_to_lwe_ciphertext_vector_with_public_key(
+                &proto_public_key,
+                proto_plaintext_vector,
+            );
+        let proto_output_plaintext_vector = maker
+            .decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+                proto_secret_key,
+                &proto_ciphertext_vector,
+            );
+        maker.destroy_lwe_secret_key(secret_key);
+        (
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector),
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (public_key_encryption_noise_bound(
+            parameters.noise,
+            parameters.lwe_public_key_zero_encryption_count,
+        ),)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_delta_std_dev(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+}
@@ -256,6 +256,9 @@ pub use glwe_ciphertext_trivial_decryption::*;
 mod glwe_ciphertext_trivial_encryption;
 pub use glwe_ciphertext_trivial_encryption::*;
 
+mod glwe_ciphertext_accumulator_generation;
+pub use glwe_ciphertext_accumulator_generation::*;
+
 mod glwe_ciphertext_encryption;
 pub use glwe_ciphertext_encryption::*;
 
@@ -271,6 +274,36 @@ pub use glwe_ciphertext_discarding_decryption::*;
 mod glwe_ciphertext_discarding_trivial_encryption;
 pub use glwe_ciphertext_discarding_trivial_encryption::*;
 
+mod glwe_ciphertext_discarding_addition;
+pub use glwe_ciphertext_discarding_addition::*;
+
+mod glwe_ciphertext_fusing_addition;
+pub use glwe_ciphertext_fusing_addition::*;
+
+mod glwe_ciphertext_discarding_subtraction;
+pub use glwe_ciphertext_discarding_subtraction::*;
+
+mod glwe_ciphertext_fusing_subtraction;
+pub use glwe_ciphertext_fusing_subtraction::*;
+
+mod glwe_ciphertext_discarding_opposite;
+pub use glwe_ciphertext_discarding_opposite::*;
+
+mod glwe_ciphertext_fusing_opposite;
+pub use glwe_ciphertext_fusing_opposite::*;
+
+mod glwe_ciphertext_plaintext_vector_discarding_addition;
+pub use glwe_ciphertext_plaintext_vector_discarding_addition::*;
+
+mod glwe_ciphertext_plaintext_vector_fusing_addition;
+pub use glwe_ciphertext_plaintext_vector_fusing_addition::*;
+
+mod glwe_ciphertext_cleartext_vector_discarding_multiplication;
+pub use glwe_ciphertext_cleartext_vector_discarding_multiplication::*;
+
+mod glwe_ciphertext_cleartext_vector_fusing_multiplication;
+pub use glwe_ciphertext_cleartext_vector_fusing_multiplication::*;
+
 mod glwe_ciphertext_vector_encryption;
 pub use glwe_ciphertext_vector_encryption::*;
 
@@ -352,6 +385,9 @@ pub use lwe_ciphertext_vector_discarding_decryption::*;
 mod lwe_ciphertext_vector_discarding_encryption;
 pub use lwe_ciphertext_vector_discarding_encryption::*;
 
+mod lwe_ciphertext_vector_discarding_public_key_encryption;
+pub use lwe_ciphertext_vector_discarding_public_key_encryption::*;
+
 mod lwe_ciphertext_discarding_keyswitch;
 pub use lwe_ciphertext_discarding_keyswitch::*;
 
@@ -400,6 +436,9 @@ pub use lwe_ciphertext_vector_discarding_subtraction::*;
 mod lwe_ciphertext_vector_encryption;
 pub use lwe_ciphertext_vector_encryption::*;
 
+mod lwe_ciphertext_vector_public_key_encryption;
+pub use lwe_ciphertext_vector_public_key_encryption::*;
+
 mod lwe_ciphertext_vector_conversion;
 pub use lwe_ciphertext_vector_conversion::*;
 
@@ -430,6 +469,18 @@ pub use lwe_ciphertext_discarding_bootstrap_1::*;
 mod lwe_ciphertext_discarding_bootstrap_2;
 pub use lwe_ciphertext_discarding_bootstrap_2::*;
 
+mod lwe_ciphertext_multi_value_bootstrap;
+pub use lwe_ciphertext_multi_value_bootstrap::*;
+
+mod lwe_ciphertext_discarding_blind_rotation;
+pub use lwe_ciphertext_discarding_blind_rotation::*;
+
+mod glwe_ciphertext_discarding_cmux_tree;
+pub use glwe_ciphertext_discarding_cmux_tree::*;
+
+mod lwe_ciphertext_discarding_vertical_packing;
+pub use lwe_ciphertext_discarding_vertical_packing::*;
+
 mod lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing;
 pub use lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing::*;
 
@@ -442,9 +493,18 @@ pub use lwe_ciphertext_vector_discarding_bootstrap_1::*;
 mod lwe_ciphertext_vector_discarding_bootstrap_2;
 pub use lwe_ciphertext_vector_discarding_bootstrap_2::*;
 
+mod lwe_ciphertext_vector_discarding_boolean_gates;
+pub use lwe_ciphertext_vector_discarding_boolean_gates::*;
+
+mod lwe_ciphertext_discarding_boolean_gates;
+pub use lwe_ciphertext_discarding_boolean_gates::*;
+
 mod lwe_ciphertext_discarding_extraction;
 pub use lwe_ciphertext_discarding_extraction::*;
 
+mod lwe_ciphertext_vector_discarding_extraction;
+pub use lwe_ciphertext_vector_discarding_extraction::*;
+
 mod plaintext_creation;
 pub use plaintext_creation::*;
 
@@ -454,6 +514,33 @@ pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product::*;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
 pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
 
+mod glwe_ciphertext_tensor_product;
+pub use glwe_ciphertext_tensor_product::*;
+
+mod glwe_ciphertext_multiplication;
+pub use glwe_ciphertext_multiplication::*;
+
+mod glwe_ciphertext_discarding_keyswitch;
+pub use glwe_ciphertext_discarding_keyswitch::*;
+
+mod lwe_ciphertext_gsw_ciphertext_external_product;
+pub use lwe_ciphertext_gsw_ciphertext_external_product::*;
+
+mod lwe_ciphertexts_gsw_ciphertext_fusing_cmux;
+pub use lwe_ciphertexts_gsw_ciphertext_fusing_cmux::*;
+
+mod ggsw_ciphertext_vector_scalar_encryption;
+pub use ggsw_ciphertext_vector_scalar_encryption::*;
+
+mod ggsw_ciphertext_vector_conversion;
+pub use ggsw_ciphertext_vector_conversion::*;
+
+mod ggsw_seeded_ciphertext_to_ggsw_ciphertext_transformation;
+pub use ggsw_seeded_ciphertext_to_ggsw_ciphertext_transformation::*;
+
+mod ggsw_seeded_ciphertext_conversion;
+pub use ggsw_seeded_ciphertext_conversion::*;
+
 mod glwe_ciphertext_consuming_retrieval;
 pub use glwe_ciphertext_consuming_retrieval::*;
 
@@ -475,6 +562,9 @@ pub use plaintext_vector_retrieval::*;
 mod lwe_keyswitch_key_generation;
 pub use lwe_keyswitch_key_generation::*;
 
+mod lwe_keyswitch_key_generation_keyswitch;
+pub use lwe_keyswitch_key_generation_keyswitch::*;
+
 mod lwe_secret_key_generation;
 pub use lwe_secret_key_generation::*;
 
@@ -550,8 +640,20 @@ pub use glwe_ciphertext_conversion::*;
 mod lwe_public_key_generation;
 pub use lwe_public_key_generation::*;
 
+mod lwe_seeded_public_key_generation;
+pub use lwe_seeded_public_key_generation::*;
+
+mod lwe_seeded_public_key_to_lwe_public_key_transformation;
+pub use lwe_seeded_public_key_to_lwe_public_key_transformation::*;
+
 mod lwe_ciphertext_vector_plaintext_vector_discarding_addition;
 pub use lwe_ciphertext_vector_plaintext_vector_discarding_addition::*;
 
 mod lwe_ciphertext_vector_cleartext_vector_discarding_multiplication;
 pub use lwe_ciphertext_vector_cleartext_vector_discarding_multiplication::*;
+
+mod glwe_ciphertext_discarding_trace;
+pub use glwe_ciphertext_discarding_trace::*;
+
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_trace_packing;
+pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_trace_packing::*;
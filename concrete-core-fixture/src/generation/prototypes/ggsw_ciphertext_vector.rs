@@ -0,0 +1,24 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{GgswCiphertextVector32, GgswCiphertextVector64};
+
+/// A trait implemented by ggsw ciphertext vector prototypes.
+pub trait GgswCiphertextVectorPrototype {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary ggsw ciphertext vector entity.
+pub struct ProtoBinaryGgswCiphertextVector32(pub(crate) GgswCiphertextVector32);
+impl GgswCiphertextVectorPrototype for ProtoBinaryGgswCiphertextVector32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary ggsw ciphertext vector entity.
+pub struct ProtoBinaryGgswCiphertextVector64(pub(crate) GgswCiphertextVector64);
+impl GgswCiphertextVectorPrototype for ProtoBinaryGgswCiphertextVector64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
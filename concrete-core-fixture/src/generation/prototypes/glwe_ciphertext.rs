@@ -1,5 +1,6 @@
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+    BinaryKeyDistribution, GaussianKeyDistribution, IntegerPrecision, KeyDistributionMarker,
+    Precision32, Precision64, TernaryKeyDistribution,
 };
 use concrete_core::prelude::{GlweCiphertext32, GlweCiphertext64};
 
@@ -22,3 +23,31 @@ impl GlweCiphertextPrototype for ProtoBinaryGlweCiphertext64 {
     type KeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 32 bit ternary glwe ciphertext entity.
+pub struct ProtoTernaryGlweCiphertext32(pub(crate) GlweCiphertext32);
+impl GlweCiphertextPrototype for ProtoTernaryGlweCiphertext32 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit ternary glwe ciphertext entity.
+pub struct ProtoTernaryGlweCiphertext64(pub(crate) GlweCiphertext64);
+impl GlweCiphertextPrototype for ProtoTernaryGlweCiphertext64 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision64;
+}
+
+/// A type representing the prototype of a 32 bit gaussian glwe ciphertext entity.
+pub struct ProtoGaussianGlweCiphertext32(pub(crate) GlweCiphertext32);
+impl GlweCiphertextPrototype for ProtoGaussianGlweCiphertext32 {
+    type KeyDistribution = GaussianKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit gaussian glwe ciphertext entity.
+pub struct ProtoGaussianGlweCiphertext64(pub(crate) GlweCiphertext64);
+impl GlweCiphertextPrototype for ProtoGaussianGlweCiphertext64 {
+    type KeyDistribution = GaussianKeyDistribution;
+    type Precision = Precision64;
+}
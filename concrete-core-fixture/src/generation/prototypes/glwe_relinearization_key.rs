@@ -0,0 +1,24 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{GlweRelinearizationKey32, GlweRelinearizationKey64};
+
+/// A trait implemented by glwe relinearization key prototypes.
+pub trait GlweRelinearizationKeyPrototype {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary glwe relinearization key entity.
+pub struct ProtoBinaryGlweRelinearizationKey32(pub(crate) GlweRelinearizationKey32);
+impl GlweRelinearizationKeyPrototype for ProtoBinaryGlweRelinearizationKey32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary glwe relinearization key entity.
+pub struct ProtoBinaryGlweRelinearizationKey64(pub(crate) GlweRelinearizationKey64);
+impl GlweRelinearizationKeyPrototype for ProtoBinaryGlweRelinearizationKey64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
@@ -1,7 +1,11 @@
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+    BinaryKeyDistribution, GaussianKeyDistribution, IntegerPrecision, KeyDistributionMarker,
+    Precision32, Precision64, TernaryKeyDistribution,
+};
+use concrete_core::prelude::{
+    GlweGaussianSecretKey32, GlweGaussianSecretKey64, GlweSecretKey32, GlweSecretKey64,
+    GlweTernarySecretKey32, GlweTernarySecretKey64,
 };
-use concrete_core::prelude::{GlweSecretKey32, GlweSecretKey64};
 
 /// A trait implemented by glwe secret key prototypes.
 pub trait GlweSecretKeyPrototype: PartialEq {
@@ -24,3 +28,35 @@ impl GlweSecretKeyPrototype for ProtoBinaryGlweSecretKey64 {
     type KeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 32 bit ternary glwe secret key entity.
+#[derive(PartialEq, Eq)]
+pub struct ProtoTernaryGlweSecretKey32(pub(crate) GlweTernarySecretKey32);
+impl GlweSecretKeyPrototype for ProtoTernaryGlweSecretKey32 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit ternary glwe secret key entity.
+#[derive(PartialEq, Eq)]
+pub struct ProtoTernaryGlweSecretKey64(pub(crate) GlweTernarySecretKey64);
+impl GlweSecretKeyPrototype for ProtoTernaryGlweSecretKey64 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision64;
+}
+
+/// A type representing the prototype of a 32 bit gaussian glwe secret key entity.
+#[derive(PartialEq, Eq)]
+pub struct ProtoGaussianGlweSecretKey32(pub(crate) GlweGaussianSecretKey32);
+impl GlweSecretKeyPrototype for ProtoGaussianGlweSecretKey32 {
+    type KeyDistribution = GaussianKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit gaussian glwe secret key entity.
+#[derive(PartialEq, Eq)]
+pub struct ProtoGaussianGlweSecretKey64(pub(crate) GlweGaussianSecretKey64);
+impl GlweSecretKeyPrototype for ProtoGaussianGlweSecretKey64 {
+    type KeyDistribution = GaussianKeyDistribution;
+    type Precision = Precision64;
+}
@@ -1,5 +1,6 @@
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+    BinaryKeyDistribution, GaussianKeyDistribution, IntegerPrecision, KeyDistributionMarker,
+    Precision32, Precision64, TernaryKeyDistribution,
 };
 use concrete_core::prelude::{LweCiphertext32, LweCiphertext64};
 
@@ -22,3 +23,31 @@ impl LweCiphertextPrototype for ProtoBinaryLweCiphertext64 {
     type KeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 32 bit ternary lwe ciphertext entity.
+pub struct ProtoTernaryLweCiphertext32(pub(crate) LweCiphertext32);
+impl LweCiphertextPrototype for ProtoTernaryLweCiphertext32 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit ternary lwe ciphertext entity.
+pub struct ProtoTernaryLweCiphertext64(pub(crate) LweCiphertext64);
+impl LweCiphertextPrototype for ProtoTernaryLweCiphertext64 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision64;
+}
+
+/// A type representing the prototype of a 32 bit gaussian lwe ciphertext entity.
+pub struct ProtoGaussianLweCiphertext32(pub(crate) LweCiphertext32);
+impl LweCiphertextPrototype for ProtoGaussianLweCiphertext32 {
+    type KeyDistribution = GaussianKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit gaussian lwe ciphertext entity.
+pub struct ProtoGaussianLweCiphertext64(pub(crate) LweCiphertext64);
+impl LweCiphertextPrototype for ProtoGaussianLweCiphertext64 {
+    type KeyDistribution = GaussianKeyDistribution;
+    type Precision = Precision64;
+}
@@ -1,5 +1,6 @@
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+    BinaryKeyDistribution, GaussianKeyDistribution, IntegerPrecision, KeyDistributionMarker,
+    Precision32, Precision64, TernaryKeyDistribution,
 };
 use concrete_core::prelude::{LweKeyswitchKey32, LweKeyswitchKey64};
 
@@ -25,3 +26,35 @@ impl LweKeyswitchKeyPrototype for ProtoBinaryBinaryLweKeyswitchKey64 {
     type OutputKeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 32 bit ternary to ternary lwe keyswitch key entity.
+pub struct ProtoTernaryTernaryLweKeyswitchKey32(pub(crate) LweKeyswitchKey32);
+impl LweKeyswitchKeyPrototype for ProtoTernaryTernaryLweKeyswitchKey32 {
+    type InputKeyDistribution = TernaryKeyDistribution;
+    type OutputKeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit ternary to ternary lwe keyswitch key entity.
+pub struct ProtoTernaryTernaryLweKeyswitchKey64(pub(crate) LweKeyswitchKey64);
+impl LweKeyswitchKeyPrototype for ProtoTernaryTernaryLweKeyswitchKey64 {
+    type InputKeyDistribution = TernaryKeyDistribution;
+    type OutputKeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision64;
+}
+
+/// A type representing the prototype of a 32 bit gaussian to gaussian lwe keyswitch key entity.
+pub struct ProtoGaussianGaussianLweKeyswitchKey32(pub(crate) LweKeyswitchKey32);
+impl LweKeyswitchKeyPrototype for ProtoGaussianGaussianLweKeyswitchKey32 {
+    type InputKeyDistribution = GaussianKeyDistribution;
+    type OutputKeyDistribution = GaussianKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit gaussian to gaussian lwe keyswitch key entity.
+pub struct ProtoGaussianGaussianLweKeyswitchKey64(pub(crate) LweKeyswitchKey64);
+impl LweKeyswitchKeyPrototype for ProtoGaussianGaussianLweKeyswitchKey64 {
+    type InputKeyDistribution = GaussianKeyDistribution;
+    type OutputKeyDistribution = GaussianKeyDistribution;
+    type Precision = Precision64;
+}
@@ -1,7 +1,11 @@
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+    BinaryKeyDistribution, GaussianKeyDistribution, IntegerPrecision, KeyDistributionMarker,
+    Precision32, Precision64, TernaryKeyDistribution,
+};
+use concrete_core::prelude::{
+    LweGaussianSecretKey32, LweGaussianSecretKey64, LweSecretKey32, LweSecretKey64,
+    LweTernarySecretKey32, LweTernarySecretKey64,
 };
-use concrete_core::prelude::{LweSecretKey32, LweSecretKey64};
 
 /// A trait implemented by lwe secret key prototypes.
 pub trait LweSecretKeyPrototype: PartialEq {
@@ -24,3 +28,35 @@ impl LweSecretKeyPrototype for ProtoBinaryLweSecretKey64 {
     type KeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 32 bit ternary lwe secret key entity.
+#[derive(PartialEq, Eq)]
+pub struct ProtoTernaryLweSecretKey32(pub(crate) LweTernarySecretKey32);
+impl LweSecretKeyPrototype for ProtoTernaryLweSecretKey32 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit ternary lwe secret key entity.
+#[derive(PartialEq, Eq)]
+pub struct ProtoTernaryLweSecretKey64(pub(crate) LweTernarySecretKey64);
+impl LweSecretKeyPrototype for ProtoTernaryLweSecretKey64 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision64;
+}
+
+/// A type representing the prototype of a 32 bit gaussian lwe secret key entity.
+#[derive(PartialEq, Eq)]
+pub struct ProtoGaussianLweSecretKey32(pub(crate) LweGaussianSecretKey32);
+impl LweSecretKeyPrototype for ProtoGaussianLweSecretKey32 {
+    type KeyDistribution = GaussianKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit gaussian lwe secret key entity.
+#[derive(PartialEq, Eq)]
+pub struct ProtoGaussianLweSecretKey64(pub(crate) LweGaussianSecretKey64);
+impl LweSecretKeyPrototype for ProtoGaussianLweSecretKey64 {
+    type KeyDistribution = GaussianKeyDistribution;
+    type Precision = Precision64;
+}
@@ -0,0 +1,84 @@
+use crate::generation::prototypes::{
+    GgswCiphertextVectorPrototype, ProtoBinaryGgswCiphertextVector32,
+    ProtoBinaryGgswCiphertextVector64,
+};
+use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
+use crate::generation::prototyping::plaintext_vector::PrototypesPlaintextVector;
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextVectorScalarEncryptionEngine,
+    Variance,
+};
+
+/// A trait allowing to manipulate GGSW ciphertext vector prototypes.
+pub trait PrototypesGgswCiphertextVector<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesPlaintextVector<Precision> + PrototypesGlweSecretKey<Precision, KeyDistribution>
+{
+    type GgswCiphertextVectorProto: GgswCiphertextVectorPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn encrypt_plaintext_vector_to_ggsw_ciphertext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::GgswCiphertextVectorProto;
+}
+
+impl PrototypesGgswCiphertextVector<Precision32, BinaryKeyDistribution> for Maker {
+    type GgswCiphertextVectorProto = ProtoBinaryGgswCiphertextVector32;
+
+    fn encrypt_plaintext_vector_to_ggsw_ciphertext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::GgswCiphertextVectorProto {
+        ProtoBinaryGgswCiphertextVector32(
+            self.default_engine
+                .encrypt_scalar_ggsw_ciphertext_vector(
+                    &secret_key.0,
+                    &plaintext_vector.0,
+                    noise,
+                    decomposition_level_count,
+                    decomposition_base_log,
+                )
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesGgswCiphertextVector<Precision64, BinaryKeyDistribution> for Maker {
+    type GgswCiphertextVectorProto = ProtoBinaryGgswCiphertextVector64;
+
+    fn encrypt_plaintext_vector_to_ggsw_ciphertext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::GgswCiphertextVectorProto {
+        ProtoBinaryGgswCiphertextVector64(
+            self.default_engine
+                .encrypt_scalar_ggsw_ciphertext_vector(
+                    &secret_key.0,
+                    &plaintext_vector.0,
+                    noise,
+                    decomposition_level_count,
+                    decomposition_base_log,
+                )
+                .unwrap(),
+        )
+    }
+}
@@ -0,0 +1,113 @@
+use crate::generation::prototypes::{
+    GgswSeededCiphertextPrototype, ProtoBinaryGgswCiphertext32, ProtoBinaryGgswCiphertext64,
+    ProtoBinaryGgswSeededCiphertext32, ProtoBinaryGgswSeededCiphertext64,
+};
+use crate::generation::prototyping::ggsw_ciphertext::PrototypesGgswCiphertext;
+use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
+use crate::generation::prototyping::plaintext::PrototypesPlaintext;
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswSeededCiphertextScalarEncryptionEngine,
+    GgswSeededCiphertextToGgswCiphertextTransformationEngine, Variance,
+};
+
+/// A trait allowing to manipulate seeded GGSW ciphertext prototypes.
+pub trait PrototypesGgswSeededCiphertext<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesPlaintext<Precision>
+    + PrototypesGlweSecretKey<Precision, KeyDistribution>
+    + PrototypesGgswCiphertext<Precision, KeyDistribution>
+{
+    type GgswSeededCiphertextProto: GgswSeededCiphertextPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn encrypt_plaintext_to_ggsw_seeded_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::GgswSeededCiphertextProto;
+    fn transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext(
+        &mut self,
+        seeded_ciphertext: &Self::GgswSeededCiphertextProto,
+    ) -> Self::GgswCiphertextProto;
+}
+
+impl PrototypesGgswSeededCiphertext<Precision32, BinaryKeyDistribution> for Maker {
+    type GgswSeededCiphertextProto = ProtoBinaryGgswSeededCiphertext32;
+
+    fn encrypt_plaintext_to_ggsw_seeded_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::GgswSeededCiphertextProto {
+        ProtoBinaryGgswSeededCiphertext32(
+            self.default_engine
+                .encrypt_scalar_ggsw_seeded_ciphertext(
+                    &secret_key.0,
+                    &plaintext.0,
+                    noise,
+                    decomposition_level_count,
+                    decomposition_base_log,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext(
+        &mut self,
+        seeded_ciphertext: &Self::GgswSeededCiphertextProto,
+    ) -> ProtoBinaryGgswCiphertext32 {
+        ProtoBinaryGgswCiphertext32(
+            self.default_engine
+                .transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext(seeded_ciphertext.0.to_owned())
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesGgswSeededCiphertext<Precision64, BinaryKeyDistribution> for Maker {
+    type GgswSeededCiphertextProto = ProtoBinaryGgswSeededCiphertext64;
+
+    fn encrypt_plaintext_to_ggsw_seeded_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::GgswSeededCiphertextProto {
+        ProtoBinaryGgswSeededCiphertext64(
+            self.default_engine
+                .encrypt_scalar_ggsw_seeded_ciphertext(
+                    &secret_key.0,
+                    &plaintext.0,
+                    noise,
+                    decomposition_level_count,
+                    decomposition_base_log,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext(
+        &mut self,
+        seeded_ciphertext: &Self::GgswSeededCiphertextProto,
+    ) -> ProtoBinaryGgswCiphertext64 {
+        ProtoBinaryGgswCiphertext64(
+            self.default_engine
+                .transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext(seeded_ciphertext.0.to_owned())
+                .unwrap(),
+        )
+    }
+}
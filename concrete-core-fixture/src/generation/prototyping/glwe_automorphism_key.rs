@@ -0,0 +1,76 @@
+use crate::generation::prototypes::{
+    GlweAutomorphismKeyPrototype, ProtoBinaryGlweAutomorphismKey32,
+    ProtoBinaryGlweAutomorphismKey64,
+};
+use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweAutomorphismKeyGenerationEngine, Variance,
+};
+
+/// A trait allowing to manipulate glwe automorphism key prototypes.
+pub trait PrototypesGlweAutomorphismKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesGlweSecretKey<Precision, KeyDistribution>
+{
+    type GlweAutomorphismKeyProto: GlweAutomorphismKeyPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn new_glwe_automorphism_key(
+        &mut self,
+        key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweAutomorphismKeyProto;
+}
+
+impl PrototypesGlweAutomorphismKey<Precision32, BinaryKeyDistribution> for Maker {
+    type GlweAutomorphismKeyProto = ProtoBinaryGlweAutomorphismKey32;
+
+    fn new_glwe_automorphism_key(
+        &mut self,
+        key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweAutomorphismKeyProto {
+        ProtoBinaryGlweAutomorphismKey32(
+            self.default_engine
+                .generate_new_glwe_automorphism_key(
+                    &key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesGlweAutomorphismKey<Precision64, BinaryKeyDistribution> for Maker {
+    type GlweAutomorphismKeyProto = ProtoBinaryGlweAutomorphismKey64;
+
+    fn new_glwe_automorphism_key(
+        &mut self,
+        key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweAutomorphismKeyProto {
+        ProtoBinaryGlweAutomorphismKey64(
+            self.default_engine
+                .generate_new_glwe_automorphism_key(
+                    &key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
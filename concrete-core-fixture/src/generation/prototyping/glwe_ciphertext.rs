@@ -1,16 +1,19 @@
 use crate::generation::prototypes::{
     GlweCiphertextPrototype, ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64,
-    ProtoPlaintextVector32, ProtoPlaintextVector64,
+    ProtoGaussianGlweCiphertext32, ProtoGaussianGlweCiphertext64, ProtoPlaintextVector32,
+    ProtoPlaintextVector64, ProtoTernaryGlweCiphertext32, ProtoTernaryGlweCiphertext64,
 };
 use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
 use crate::generation::prototyping::plaintext_vector::PrototypesPlaintextVector;
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+    BinaryKeyDistribution, GaussianKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker,
+    Precision32, Precision64, TernaryKeyDistribution,
 };
 use concrete_core::prelude::{
     GlweCiphertextConsumingRetrievalEngine, GlweCiphertextCreationEngine,
     GlweCiphertextDecryptionEngine, GlweCiphertextEncryptionEngine, GlweCiphertextEntity,
-    GlweCiphertextTrivialDecryptionEngine, GlweCiphertextTrivialEncryptionEngine, GlweDimension,
+    GlweCiphertextMultiFunctionAccumulatorGenerationEngine, GlweCiphertextTrivialDecryptionEngine,
+    GlweCiphertextTrivialEncryptionEngine, GlweDimension, MessageBitsCount, PaddingBitsCount,
     PlaintextVectorCreationEngine, PolynomialSize, Variance,
 };
 
@@ -50,6 +53,14 @@ pub trait PrototypesGlweCiphertext<
         secret_key: &Self::GlweSecretKeyProto,
         ciphertext: &Self::GlweCiphertextProto,
     ) -> Self::PlaintextVectorProto;
+    fn generate_new_glwe_ciphertext_multi_function_accumulator(
+        &mut self,
+        functions: &[&dyn Fn(Precision::Raw) -> Precision::Raw],
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto;
     fn transform_raw_vec_to_glwe_ciphertext(
         &mut self,
         raw: &[Precision::Raw],
@@ -131,6 +142,27 @@ impl PrototypesGlweCiphertext<Precision32, BinaryKeyDistribution> for Maker {
         )
     }
 
+    fn generate_new_glwe_ciphertext_multi_function_accumulator(
+        &mut self,
+        functions: &[&dyn Fn(u32) -> u32],
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        ProtoBinaryGlweCiphertext32(
+            self.default_engine
+                .generate_new_glwe_ciphertext_multi_function_accumulator(
+                    functions,
+                    message_bits,
+                    padding_bits,
+                    glwe_dimension,
+                    poly_size,
+                )
+                .unwrap(),
+        )
+    }
+
     fn transform_raw_vec_to_glwe_ciphertext(
         &mut self,
         raw: &[u32],
@@ -228,6 +260,27 @@ impl PrototypesGlweCiphertext<Precision64, BinaryKeyDistribution> for Maker {
         )
     }
 
+    fn generate_new_glwe_ciphertext_multi_function_accumulator(
+        &mut self,
+        functions: &[&dyn Fn(u64) -> u64],
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        ProtoBinaryGlweCiphertext64(
+            self.default_engine
+                .generate_new_glwe_ciphertext_multi_function_accumulator(
+                    functions,
+                    message_bits,
+                    padding_bits,
+                    glwe_dimension,
+                    poly_size,
+                )
+                .unwrap(),
+        )
+    }
+
     fn transform_raw_vec_to_glwe_ciphertext(
         &mut self,
         raw: &[u64],
@@ -254,3 +307,475 @@ impl PrototypesGlweCiphertext<Precision64, BinaryKeyDistribution> for Maker {
         )
     }
 }
+
+impl PrototypesGlweCiphertext<Precision32, TernaryKeyDistribution> for Maker {
+    type GlweCiphertextProto = ProtoTernaryGlweCiphertext32;
+
+    fn trivially_encrypt_zeros_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        let plaintext_vector = self
+            .default_engine
+            .create_plaintext_vector_from(&vec![0u32; poly_size.0])
+            .unwrap();
+        ProtoTernaryGlweCiphertext32(
+            self.default_engine
+                .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        plaintext_vector: &Self::PlaintextVectorProto,
+    ) -> Self::GlweCiphertextProto {
+        ProtoTernaryGlweCiphertext32(
+            self.default_engine
+                .trivially_encrypt_glwe_ciphertext(
+                    glwe_dimension.to_glwe_size(),
+                    &plaintext_vector.0,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn trivially_decrypt_glwe_ciphertext(
+        &mut self,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector32(
+            self.default_engine
+                .trivially_decrypt_glwe_ciphertext(&ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::GlweCiphertextProto {
+        ProtoTernaryGlweCiphertext32(
+            self.default_engine
+                .encrypt_glwe_ciphertext(&secret_key.0, &plaintext_vector.0, noise)
+                .unwrap(),
+        )
+    }
+
+    fn decrypt_glwe_ciphertext_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector32(
+            self.default_engine
+                .decrypt_glwe_ciphertext(&secret_key.0, &ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn generate_new_glwe_ciphertext_multi_function_accumulator(
+        &mut self,
+        functions: &[&dyn Fn(u32) -> u32],
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        ProtoTernaryGlweCiphertext32(
+            self.default_engine
+                .generate_new_glwe_ciphertext_multi_function_accumulator(
+                    functions,
+                    message_bits,
+                    padding_bits,
+                    glwe_dimension,
+                    poly_size,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_raw_vec_to_glwe_ciphertext(
+        &mut self,
+        raw: &[u32],
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        let glwe_ciphertext = self
+            .default_engine
+            .create_glwe_ciphertext_from(raw.to_owned(), polynomial_size)
+            .unwrap();
+        ProtoTernaryGlweCiphertext32(glwe_ciphertext)
+    }
+
+    fn transform_glwe_ciphertext_to_raw_vec(
+        &mut self,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> (Vec<u32>, PolynomialSize) {
+        let ciphertext = ciphertext.0.to_owned();
+        let polynomial_size = ciphertext.polynomial_size();
+        (
+            self.default_engine
+                .consume_retrieve_glwe_ciphertext(ciphertext)
+                .unwrap(),
+            polynomial_size,
+        )
+    }
+}
+
+impl PrototypesGlweCiphertext<Precision64, TernaryKeyDistribution> for Maker {
+    type GlweCiphertextProto = ProtoTernaryGlweCiphertext64;
+
+    fn trivially_encrypt_zeros_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        let plaintext_vector = self
+            .default_engine
+            .create_plaintext_vector_from(&vec![0u64; poly_size.0])
+            .unwrap();
+        ProtoTernaryGlweCiphertext64(
+            self.default_engine
+                .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        plaintext_vector: &Self::PlaintextVectorProto,
+    ) -> Self::GlweCiphertextProto {
+        ProtoTernaryGlweCiphertext64(
+            self.default_engine
+                .trivially_encrypt_glwe_ciphertext(
+                    glwe_dimension.to_glwe_size(),
+                    &plaintext_vector.0,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn trivially_decrypt_glwe_ciphertext(
+        &mut self,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector64(
+            self.default_engine
+                .trivially_decrypt_glwe_ciphertext(&ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::GlweCiphertextProto {
+        ProtoTernaryGlweCiphertext64(
+            self.default_engine
+                .encrypt_glwe_ciphertext(&secret_key.0, &plaintext_vector.0, noise)
+                .unwrap(),
+        )
+    }
+
+    fn decrypt_glwe_ciphertext_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector64(
+            self.default_engine
+                .decrypt_glwe_ciphertext(&secret_key.0, &ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn generate_new_glwe_ciphertext_multi_function_accumulator(
+        &mut self,
+        functions: &[&dyn Fn(u64) -> u64],
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        ProtoTernaryGlweCiphertext64(
+            self.default_engine
+                .generate_new_glwe_ciphertext_multi_function_accumulator(
+                    functions,
+                    message_bits,
+                    padding_bits,
+                    glwe_dimension,
+                    poly_size,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_raw_vec_to_glwe_ciphertext(
+        &mut self,
+        raw: &[u64],
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        let glwe_ciphertext = self
+            .default_engine
+            .create_glwe_ciphertext_from(raw.to_owned(), polynomial_size)
+            .unwrap();
+        ProtoTernaryGlweCiphertext64(glwe_ciphertext)
+    }
+
+    fn transform_glwe_ciphertext_to_raw_vec(
+        &mut self,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> (Vec<u64>, PolynomialSize) {
+        let ciphertext = ciphertext.0.to_owned();
+        let polynomial_size = ciphertext.polynomial_size();
+        (
+            self.default_engine
+                .consume_retrieve_glwe_ciphertext(ciphertext)
+                .unwrap(),
+            polynomial_size,
+        )
+    }
+}
+
+impl PrototypesGlweCiphertext<Precision32, GaussianKeyDistribution> for Maker {
+    type GlweCiphertextProto = ProtoGaussianGlweCiphertext32;
+
+    fn trivially_encrypt_zeros_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        let plaintext_vector = self
+            .default_engine
+            .create_plaintext_vector_from(&vec![0u32; poly_size.0])
+            .unwrap();
+        ProtoGaussianGlweCiphertext32(
+            self.default_engine
+                .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        plaintext_vector: &Self::PlaintextVectorProto,
+    ) -> Self::GlweCiphertextProto {
+        ProtoGaussianGlweCiphertext32(
+            self.default_engine
+                .trivially_encrypt_glwe_ciphertext(
+                    glwe_dimension.to_glwe_size(),
+                    &plaintext_vector.0,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn trivially_decrypt_glwe_ciphertext(
+        &mut self,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector32(
+            self.default_engine
+                .trivially_decrypt_glwe_ciphertext(&ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::GlweCiphertextProto {
+        ProtoGaussianGlweCiphertext32(
+            self.default_engine
+                .encrypt_glwe_ciphertext(&secret_key.0, &plaintext_vector.0, noise)
+                .unwrap(),
+        )
+    }
+
+    fn decrypt_glwe_ciphertext_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector32(
+            self.default_engine
+                .decrypt_glwe_ciphertext(&secret_key.0, &ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn generate_new_glwe_ciphertext_multi_function_accumulator(
+        &mut self,
+        functions: &[&dyn Fn(u32) -> u32],
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        ProtoGaussianGlweCiphertext32(
+            self.default_engine
+                .generate_new_glwe_ciphertext_multi_function_accumulator(
+                    functions,
+                    message_bits,
+                    padding_bits,
+                    glwe_dimension,
+                    poly_size,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_raw_vec_to_glwe_ciphertext(
+        &mut self,
+        raw: &[u32],
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        let glwe_ciphertext = self
+            .default_engine
+            .create_glwe_ciphertext_from(raw.to_owned(), polynomial_size)
+            .unwrap();
+        ProtoGaussianGlweCiphertext32(glwe_ciphertext)
+    }
+
+    fn transform_glwe_ciphertext_to_raw_vec(
+        &mut self,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> (Vec<u32>, PolynomialSize) {
+        let ciphertext = ciphertext.0.to_owned();
+        let polynomial_size = ciphertext.polynomial_size();
+        (
+            self.default_engine
+                .consume_retrieve_glwe_ciphertext(ciphertext)
+                .unwrap(),
+            polynomial_size,
+        )
+    }
+}
+
+impl PrototypesGlweCiphertext<Precision64, GaussianKeyDistribution> for Maker {
+    type GlweCiphertextProto = ProtoGaussianGlweCiphertext64;
+
+    fn trivially_encrypt_zeros_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        let plaintext_vector = self
+            .default_engine
+            .create_plaintext_vector_from(&vec![0u64; poly_size.0])
+            .unwrap();
+        ProtoGaussianGlweCiphertext64(
+            self.default_engine
+                .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        plaintext_vector: &Self::PlaintextVectorProto,
+    ) -> Self::GlweCiphertextProto {
+        ProtoGaussianGlweCiphertext64(
+            self.default_engine
+                .trivially_encrypt_glwe_ciphertext(
+                    glwe_dimension.to_glwe_size(),
+                    &plaintext_vector.0,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn trivially_decrypt_glwe_ciphertext(
+        &mut self,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector64(
+            self.default_engine
+                .trivially_decrypt_glwe_ciphertext(&ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::GlweCiphertextProto {
+        ProtoGaussianGlweCiphertext64(
+            self.default_engine
+                .encrypt_glwe_ciphertext(&secret_key.0, &plaintext_vector.0, noise)
+                .unwrap(),
+        )
+    }
+
+    fn decrypt_glwe_ciphertext_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector64(
+            self.default_engine
+                .decrypt_glwe_ciphertext(&secret_key.0, &ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn generate_new_glwe_ciphertext_multi_function_accumulator(
+        &mut self,
+        functions: &[&dyn Fn(u64) -> u64],
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        ProtoGaussianGlweCiphertext64(
+            self.default_engine
+                .generate_new_glwe_ciphertext_multi_function_accumulator(
+                    functions,
+                    message_bits,
+                    padding_bits,
+                    glwe_dimension,
+                    poly_size,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_raw_vec_to_glwe_ciphertext(
+        &mut self,
+        raw: &[u64],
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        let glwe_ciphertext = self
+            .default_engine
+            .create_glwe_ciphertext_from(raw.to_owned(), polynomial_size)
+            .unwrap();
+        ProtoGaussianGlweCiphertext64(glwe_ciphertext)
+    }
+
+    fn transform_glwe_ciphertext_to_raw_vec(
+        &mut self,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> (Vec<u64>, PolynomialSize) {
+        let ciphertext = ciphertext.0.to_owned();
+        let polynomial_size = ciphertext.polynomial_size();
+        (
+            self.default_engine
+                .consume_retrieve_glwe_ciphertext(ciphertext)
+                .unwrap(),
+            polynomial_size,
+        )
+    }
+}
@@ -1,10 +1,14 @@
 use crate::generation::prototypes::{
     GlweSecretKeyPrototype, ProtoBinaryGlweSecretKey32, ProtoBinaryGlweSecretKey64,
-    ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64,
+    ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64, ProtoGaussianGlweSecretKey32,
+    ProtoGaussianGlweSecretKey64, ProtoGaussianLweSecretKey32, ProtoGaussianLweSecretKey64,
+    ProtoTernaryGlweSecretKey32, ProtoTernaryGlweSecretKey64, ProtoTernaryLweSecretKey32,
+    ProtoTernaryLweSecretKey64,
 };
 use crate::generation::prototyping::PrototypesLweSecretKey;
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+    BinaryKeyDistribution, GaussianKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker,
+    Precision32, Precision64, TernaryKeyDistribution,
 };
 use concrete_core::prelude::{
     GlweDimension, GlweSecretKeyGenerationEngine, GlweToLweSecretKeyTransformationEngine,
@@ -116,3 +120,159 @@ impl PrototypesGlweSecretKey<Precision64, BinaryKeyDistribution> for Maker {
         )
     }
 }
+
+impl PrototypesGlweSecretKey<Precision32, TernaryKeyDistribution> for Maker {
+    type GlweSecretKeyProto = ProtoTernaryGlweSecretKey32;
+
+    fn new_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoTernaryGlweSecretKey32(
+            self.default_engine
+                .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)
+                .unwrap(),
+        )
+    }
+
+    fn transform_glwe_secret_key_to_lwe_secret_key(
+        &mut self,
+        glwe_key: &Self::GlweSecretKeyProto,
+    ) -> Self::LweSecretKeyProto {
+        ProtoTernaryLweSecretKey32(
+            self.default_engine
+                .transform_glwe_secret_key_to_lwe_secret_key(glwe_key.0.to_owned())
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_key: &Self::LweSecretKeyProto,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoTernaryGlweSecretKey32(
+            self.default_engine
+                .transform_lwe_secret_key_to_glwe_secret_key(lwe_key.0.to_owned(), polynomial_size)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesGlweSecretKey<Precision64, TernaryKeyDistribution> for Maker {
+    type GlweSecretKeyProto = ProtoTernaryGlweSecretKey64;
+
+    fn new_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoTernaryGlweSecretKey64(
+            self.default_engine
+                .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)
+                .unwrap(),
+        )
+    }
+
+    fn transform_glwe_secret_key_to_lwe_secret_key(
+        &mut self,
+        glwe_key: &Self::GlweSecretKeyProto,
+    ) -> Self::LweSecretKeyProto {
+        ProtoTernaryLweSecretKey64(
+            self.default_engine
+                .transform_glwe_secret_key_to_lwe_secret_key(glwe_key.0.to_owned())
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_key: &Self::LweSecretKeyProto,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoTernaryGlweSecretKey64(
+            self.default_engine
+                .transform_lwe_secret_key_to_glwe_secret_key(lwe_key.0.to_owned(), polynomial_size)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesGlweSecretKey<Precision32, GaussianKeyDistribution> for Maker {
+    type GlweSecretKeyProto = ProtoGaussianGlweSecretKey32;
+
+    fn new_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoGaussianGlweSecretKey32(
+            self.default_engine
+                .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)
+                .unwrap(),
+        )
+    }
+
+    fn transform_glwe_secret_key_to_lwe_secret_key(
+        &mut self,
+        glwe_key: &Self::GlweSecretKeyProto,
+    ) -> Self::LweSecretKeyProto {
+        ProtoGaussianLweSecretKey32(
+            self.default_engine
+                .transform_glwe_secret_key_to_lwe_secret_key(glwe_key.0.to_owned())
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_key: &Self::LweSecretKeyProto,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoGaussianGlweSecretKey32(
+            self.default_engine
+                .transform_lwe_secret_key_to_glwe_secret_key(lwe_key.0.to_owned(), polynomial_size)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesGlweSecretKey<Precision64, GaussianKeyDistribution> for Maker {
+    type GlweSecretKeyProto = ProtoGaussianGlweSecretKey64;
+
+    fn new_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoGaussianGlweSecretKey64(
+            self.default_engine
+                .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)
+                .unwrap(),
+        )
+    }
+
+    fn transform_glwe_secret_key_to_lwe_secret_key(
+        &mut self,
+        glwe_key: &Self::GlweSecretKeyProto,
+    ) -> Self::LweSecretKeyProto {
+        ProtoGaussianLweSecretKey64(
+            self.default_engine
+                .transform_glwe_secret_key_to_lwe_secret_key(glwe_key.0.to_owned())
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        lwe_key: &Self::LweSecretKeyProto,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoGaussianGlweSecretKey64(
+            self.default_engine
+                .transform_lwe_secret_key_to_glwe_secret_key(lwe_key.0.to_owned(), polynomial_size)
+                .unwrap(),
+        )
+    }
+}
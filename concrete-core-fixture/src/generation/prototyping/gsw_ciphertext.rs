@@ -0,0 +1,81 @@
+use crate::generation::prototypes::{
+    GswCiphertextPrototype, ProtoBinaryGswCiphertext32, ProtoBinaryGswCiphertext64,
+};
+use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
+use crate::generation::prototyping::plaintext::PrototypesPlaintext;
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GswCiphertextScalarEncryptionEngine, Variance,
+};
+
+/// A trait allowing to manipulate GSW ciphertext prototypes.
+pub trait PrototypesGswCiphertext<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesPlaintext<Precision> + PrototypesLweSecretKey<Precision, KeyDistribution>
+{
+    type GswCiphertextProto: GswCiphertextPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn encrypt_plaintext_to_gsw_ciphertext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::GswCiphertextProto;
+}
+
+impl PrototypesGswCiphertext<Precision32, BinaryKeyDistribution> for Maker {
+    type GswCiphertextProto = ProtoBinaryGswCiphertext32;
+
+    fn encrypt_plaintext_to_gsw_ciphertext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::GswCiphertextProto {
+        ProtoBinaryGswCiphertext32(
+            self.default_engine
+                .encrypt_scalar_gsw_ciphertext(
+                    &secret_key.0,
+                    &plaintext.0,
+                    noise,
+                    decomposition_level_count,
+                    decomposition_base_log,
+                )
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesGswCiphertext<Precision64, BinaryKeyDistribution> for Maker {
+    type GswCiphertextProto = ProtoBinaryGswCiphertext64;
+
+    fn encrypt_plaintext_to_gsw_ciphertext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::GswCiphertextProto {
+        ProtoBinaryGswCiphertext64(
+            self.default_engine
+                .encrypt_scalar_gsw_ciphertext(
+                    &secret_key.0,
+                    &plaintext.0,
+                    noise,
+                    decomposition_level_count,
+                    decomposition_base_log,
+                )
+                .unwrap(),
+        )
+    }
+}
@@ -1,11 +1,13 @@
 use crate::generation::prototypes::{
     LweBootstrapKeyPrototype, ProtoBinaryBinaryLweBootstrapKey32,
-    ProtoBinaryBinaryLweBootstrapKey64,
+    ProtoBinaryBinaryLweBootstrapKey64, ProtoTernaryBinaryLweBootstrapKey32,
+    ProtoTernaryBinaryLweBootstrapKey64,
 };
 use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
 use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32,
+    Precision64, TernaryKeyDistribution,
 };
 use concrete_core::prelude::{
     DecompositionBaseLog, DecompositionLevelCount, GlweSize,
@@ -164,3 +166,93 @@ impl PrototypesLweBootstrapKey<Precision64, BinaryKeyDistribution, BinaryKeyDist
             .unwrap()
     }
 }
+
+impl PrototypesLweBootstrapKey<Precision32, TernaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type LweBootstrapKeyProto = ProtoTernaryBinaryLweBootstrapKey32;
+
+    fn new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &Self::LweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweBootstrapKeyProto {
+        ProtoTernaryBinaryLweBootstrapKey32(
+            self.default_engine
+                .generate_new_lwe_bootstrap_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_base_log,
+                    decomposition_level,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_raw_vec_to_lwe_bootstrap_key(
+        &mut self,
+        _raw: &[u32],
+        _glwe_size: GlweSize,
+        _polynomial_size: PolynomialSize,
+        _decomposition_level_count: DecompositionLevelCount,
+        _decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::LweBootstrapKeyProto {
+        unimplemented!("The creation of ternary bootstrap keys from a container is not implemented")
+    }
+
+    fn transform_lwe_bootstrap_key_to_raw_vec(
+        &mut self,
+        _lwe_bootstrap_key: &Self::LweBootstrapKeyProto,
+    ) -> Vec<u32> {
+        unimplemented!("The retrieval of ternary bootstrap keys containers is not implemented")
+    }
+}
+
+impl PrototypesLweBootstrapKey<Precision64, TernaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type LweBootstrapKeyProto = ProtoTernaryBinaryLweBootstrapKey64;
+
+    fn new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &Self::LweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweBootstrapKeyProto {
+        ProtoTernaryBinaryLweBootstrapKey64(
+            self.default_engine
+                .generate_new_lwe_bootstrap_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_base_log,
+                    decomposition_level,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_raw_vec_to_lwe_bootstrap_key(
+        &mut self,
+        _raw: &[u64],
+        _glwe_size: GlweSize,
+        _polynomial_size: PolynomialSize,
+        _decomposition_level_count: DecompositionLevelCount,
+        _decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::LweBootstrapKeyProto {
+        unimplemented!("The creation of ternary bootstrap keys from a container is not implemented")
+    }
+
+    fn transform_lwe_bootstrap_key_to_raw_vec(
+        &mut self,
+        _lwe_bootstrap_key: &Self::LweBootstrapKeyProto,
+    ) -> Vec<u64> {
+        unimplemented!("The retrieval of ternary bootstrap keys containers is not implemented")
+    }
+}
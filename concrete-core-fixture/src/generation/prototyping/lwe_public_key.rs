@@ -1,19 +1,21 @@
 use crate::generation::prototypes::{
-    LwePublicKeyPrototype, ProtoBinaryLwePublicKey32, ProtoBinaryLwePublicKey64,
+    LwePublicKeyPrototype, ProtoBinaryLweCiphertextVector32, ProtoBinaryLweCiphertextVector64,
+    ProtoBinaryLwePublicKey32, ProtoBinaryLwePublicKey64,
 };
-use crate::generation::prototyping::PrototypesLweSecretKey;
+use crate::generation::prototyping::PrototypesLweCiphertextVector;
 use crate::generation::{
     BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
 };
 use concrete_core::prelude::{
-    LwePublicKeyGenerationEngine, LwePublicKeyZeroEncryptionCount, Variance,
+    LweCiphertextVectorPublicKeyEncryptionEngine, LwePublicKeyGenerationEngine,
+    LwePublicKeyZeroEncryptionCount, Variance,
 };
 
 /// A trait allowing to manipulate lwe secret key prototypes.
 pub trait PrototypesLwePublicKey<
     Precision: IntegerPrecision,
     KeyDistribution: KeyDistributionMarker,
->: PrototypesLweSecretKey<Precision, KeyDistribution>
+>: PrototypesLweCiphertextVector<Precision, KeyDistribution>
 {
     type LwePublicKeyProto: LwePublicKeyPrototype<
         Precision = Precision,
@@ -25,6 +27,11 @@ pub trait PrototypesLwePublicKey<
         count: LwePublicKeyZeroEncryptionCount,
         noise: Variance,
     ) -> Self::LwePublicKeyProto;
+    fn encrypt_plaintext_vector_to_lwe_ciphertext_vector_with_public_key(
+        &mut self,
+        public_key: &Self::LwePublicKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+    ) -> Self::LweCiphertextVectorProto;
 }
 
 impl PrototypesLwePublicKey<Precision32, BinaryKeyDistribution> for Maker {
@@ -42,6 +49,18 @@ impl PrototypesLwePublicKey<Precision32, BinaryKeyDistribution> for Maker {
                 .unwrap(),
         )
     }
+
+    fn encrypt_plaintext_vector_to_lwe_ciphertext_vector_with_public_key(
+        &mut self,
+        public_key: &Self::LwePublicKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+    ) -> Self::LweCiphertextVectorProto {
+        ProtoBinaryLweCiphertextVector32(
+            self.default_engine
+                .encrypt_lwe_ciphertext_vector_with_public_key(&public_key.0, &plaintext_vector.0)
+                .unwrap(),
+        )
+    }
 }
 
 impl PrototypesLwePublicKey<Precision64, BinaryKeyDistribution> for Maker {
@@ -59,4 +78,16 @@ impl PrototypesLwePublicKey<Precision64, BinaryKeyDistribution> for Maker {
                 .unwrap(),
         )
     }
+
+    fn encrypt_plaintext_vector_to_lwe_ciphertext_vector_with_public_key(
+        &mut self,
+        public_key: &Self::LwePublicKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+    ) -> Self::LweCiphertextVectorProto {
+        ProtoBinaryLweCiphertextVector64(
+            self.default_engine
+                .encrypt_lwe_ciphertext_vector_with_public_key(&public_key.0, &plaintext_vector.0)
+                .unwrap(),
+        )
+    }
 }
@@ -1,8 +1,11 @@
 use crate::generation::prototypes::{
     LweSecretKeyPrototype, ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64,
+    ProtoGaussianLweSecretKey32, ProtoGaussianLweSecretKey64, ProtoTernaryLweSecretKey32,
+    ProtoTernaryLweSecretKey64,
 };
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+    BinaryKeyDistribution, GaussianKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker,
+    Precision32, Precision64, TernaryKeyDistribution,
 };
 use concrete_core::prelude::{LweDimension, LweSecretKeyGenerationEngine};
 
@@ -42,3 +45,51 @@ impl PrototypesLweSecretKey<Precision64, BinaryKeyDistribution> for Maker {
         )
     }
 }
+
+impl PrototypesLweSecretKey<Precision32, TernaryKeyDistribution> for Maker {
+    type LweSecretKeyProto = ProtoTernaryLweSecretKey32;
+
+    fn new_lwe_secret_key(&mut self, lwe_dimension: LweDimension) -> Self::LweSecretKeyProto {
+        ProtoTernaryLweSecretKey32(
+            self.default_engine
+                .generate_new_lwe_secret_key(lwe_dimension)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLweSecretKey<Precision64, TernaryKeyDistribution> for Maker {
+    type LweSecretKeyProto = ProtoTernaryLweSecretKey64;
+
+    fn new_lwe_secret_key(&mut self, lwe_dimension: LweDimension) -> Self::LweSecretKeyProto {
+        ProtoTernaryLweSecretKey64(
+            self.default_engine
+                .generate_new_lwe_secret_key(lwe_dimension)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLweSecretKey<Precision32, GaussianKeyDistribution> for Maker {
+    type LweSecretKeyProto = ProtoGaussianLweSecretKey32;
+
+    fn new_lwe_secret_key(&mut self, lwe_dimension: LweDimension) -> Self::LweSecretKeyProto {
+        ProtoGaussianLweSecretKey32(
+            self.default_engine
+                .generate_new_lwe_secret_key(lwe_dimension)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLweSecretKey<Precision64, GaussianKeyDistribution> for Maker {
+    type LweSecretKeyProto = ProtoGaussianLweSecretKey64;
+
+    fn new_lwe_secret_key(&mut self, lwe_dimension: LweDimension) -> Self::LweSecretKeyProto {
+        ProtoGaussianLweSecretKey64(
+            self.default_engine
+                .generate_new_lwe_secret_key(lwe_dimension)
+                .unwrap(),
+        )
+    }
+}
@@ -0,0 +1,90 @@
+use crate::generation::prototypes::{
+    LweSeededPublicKeyPrototype, ProtoBinaryLwePublicKey32, ProtoBinaryLwePublicKey64,
+    ProtoBinaryLweSeededPublicKey32, ProtoBinaryLweSeededPublicKey64,
+};
+use crate::generation::prototyping::PrototypesLwePublicKey;
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_core::prelude::{
+    LwePublicKeyZeroEncryptionCount, LweSeededPublicKeyGenerationEngine,
+    LweSeededPublicKeyToLwePublicKeyTransformationEngine, Variance,
+};
+
+/// A trait allowing to manipulate seeded lwe public key prototypes.
+pub trait PrototypesLweSeededPublicKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesLwePublicKey<Precision, KeyDistribution>
+{
+    type LweSeededPublicKeyProto: LweSeededPublicKeyPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn generate_new_lwe_seeded_public_key(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        count: LwePublicKeyZeroEncryptionCount,
+        noise: Variance,
+    ) -> Self::LweSeededPublicKeyProto;
+    fn transform_lwe_seeded_public_key_to_lwe_public_key(
+        &mut self,
+        seeded_public_key: &Self::LweSeededPublicKeyProto,
+    ) -> Self::LwePublicKeyProto;
+}
+
+impl PrototypesLweSeededPublicKey<Precision32, BinaryKeyDistribution> for Maker {
+    type LweSeededPublicKeyProto = ProtoBinaryLweSeededPublicKey32;
+
+    fn generate_new_lwe_seeded_public_key(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        count: LwePublicKeyZeroEncryptionCount,
+        noise: Variance,
+    ) -> Self::LweSeededPublicKeyProto {
+        ProtoBinaryLweSeededPublicKey32(
+            self.default_engine
+                .generate_new_lwe_seeded_public_key(&secret_key.0, noise, count)
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_seeded_public_key_to_lwe_public_key(
+        &mut self,
+        seeded_public_key: &Self::LweSeededPublicKeyProto,
+    ) -> Self::LwePublicKeyProto {
+        ProtoBinaryLwePublicKey32(
+            self.default_engine
+                .transform_lwe_seeded_public_key_to_lwe_public_key(seeded_public_key.0.to_owned())
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLweSeededPublicKey<Precision64, BinaryKeyDistribution> for Maker {
+    type LweSeededPublicKeyProto = ProtoBinaryLweSeededPublicKey64;
+
+    fn generate_new_lwe_seeded_public_key(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        count: LwePublicKeyZeroEncryptionCount,
+        noise: Variance,
+    ) -> Self::LweSeededPublicKeyProto {
+        ProtoBinaryLweSeededPublicKey64(
+            self.default_engine
+                .generate_new_lwe_seeded_public_key(&secret_key.0, noise, count)
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_seeded_public_key_to_lwe_public_key(
+        &mut self,
+        seeded_public_key: &Self::LweSeededPublicKeyProto,
+    ) -> Self::LwePublicKeyProto {
+        ProtoBinaryLwePublicKey64(
+            self.default_engine
+                .transform_lwe_seeded_public_key_to_lwe_public_key(seeded_public_key.0.to_owned())
+                .unwrap(),
+        )
+    }
+}
@@ -4,11 +4,17 @@ mod cleartext;
 mod cleartext_vector;
 mod container;
 mod ggsw_ciphertext;
+mod ggsw_ciphertext_vector;
+mod ggsw_seeded_ciphertext;
+mod glwe_automorphism_key;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
+mod glwe_keyswitch_key;
+mod glwe_relinearization_key;
 mod glwe_secret_key;
 mod glwe_seeded_ciphertext;
 mod glwe_seeded_ciphertext_vector;
+mod gsw_ciphertext;
 mod lwe_bootstrap_key;
 mod lwe_ciphertext;
 mod lwe_ciphertext_vector;
@@ -22,6 +28,7 @@ mod lwe_seeded_bootstrap_key;
 mod lwe_seeded_ciphertext;
 mod lwe_seeded_ciphertext_vector;
 mod lwe_seeded_keyswitch_key;
+mod lwe_seeded_public_key;
 mod plaintext;
 mod plaintext_vector;
 
@@ -29,11 +36,17 @@ pub use cleartext::*;
 pub use cleartext_vector::*;
 pub use container::*;
 pub use ggsw_ciphertext::*;
+pub use ggsw_ciphertext_vector::*;
+pub use ggsw_seeded_ciphertext::*;
+pub use glwe_automorphism_key::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
+pub use glwe_keyswitch_key::*;
+pub use glwe_relinearization_key::*;
 pub use glwe_secret_key::*;
 pub use glwe_seeded_ciphertext::*;
 pub use glwe_seeded_ciphertext_vector::*;
+pub use gsw_ciphertext::*;
 pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
 pub use lwe_ciphertext_vector::*;
@@ -47,5 +60,6 @@ pub use lwe_seeded_bootstrap_key::*;
 pub use lwe_seeded_ciphertext::*;
 pub use lwe_seeded_ciphertext_vector::*;
 pub use lwe_seeded_keyswitch_key::*;
+pub use lwe_seeded_public_key::*;
 pub use plaintext::*;
 pub use plaintext_vector::*;
@@ -116,3 +116,54 @@ mod backend_fft {
         fn destroy_ggsw_ciphertext(&mut self, _entity: FftFourierGgswCiphertext64) {}
     }
 }
+
+#[cfg(feature = "backend_ntt")]
+mod backend_ntt {
+    use crate::generation::synthesizing::SynthesizesGgswCiphertext;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        GgswCiphertextConversionEngine, NttGgswCiphertext32, NttGgswCiphertext64,
+    };
+
+    impl SynthesizesGgswCiphertext<Precision32, BinaryKeyDistribution, NttGgswCiphertext32> for Maker {
+        fn synthesize_ggsw_ciphertext(
+            &mut self,
+            prototype: &Self::GgswCiphertextProto,
+        ) -> NttGgswCiphertext32 {
+            self.ntt_engine
+                .convert_ggsw_ciphertext(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_ggsw_ciphertext(
+            &mut self,
+            _entity: NttGgswCiphertext32,
+        ) -> Self::GgswCiphertextProto {
+            // FIXME:
+            unimplemented!("The backward NTT conversion was not yet implemented");
+        }
+
+        fn destroy_ggsw_ciphertext(&mut self, _entity: NttGgswCiphertext32) {}
+    }
+
+    impl SynthesizesGgswCiphertext<Precision64, BinaryKeyDistribution, NttGgswCiphertext64> for Maker {
+        fn synthesize_ggsw_ciphertext(
+            &mut self,
+            prototype: &Self::GgswCiphertextProto,
+        ) -> NttGgswCiphertext64 {
+            self.ntt_engine
+                .convert_ggsw_ciphertext(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_ggsw_ciphertext(
+            &mut self,
+            _entity: NttGgswCiphertext64,
+        ) -> Self::GgswCiphertextProto {
+            // FIXME:
+            unimplemented!("The backward NTT conversion was not yet implemented");
+        }
+
+        fn destroy_ggsw_ciphertext(&mut self, _entity: NttGgswCiphertext64) {}
+    }
+}
@@ -0,0 +1,135 @@
+use crate::generation::prototyping::PrototypesGgswCiphertextVector;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::GgswCiphertextVectorEntity;
+
+/// A trait allowing to synthesize an actual ggsw ciphertext vector entity from a prototype.
+pub trait SynthesizesGgswCiphertextVector<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    GgswCiphertextVector,
+>: PrototypesGgswCiphertextVector<Precision, KeyDistribution> where
+    GgswCiphertextVector: GgswCiphertextVectorEntity,
+{
+    fn synthesize_ggsw_ciphertext_vector(
+        &mut self,
+        prototype: &Self::GgswCiphertextVectorProto,
+    ) -> GgswCiphertextVector;
+    fn unsynthesize_ggsw_ciphertext_vector(
+        &mut self,
+        entity: GgswCiphertextVector,
+    ) -> Self::GgswCiphertextVectorProto;
+    fn destroy_ggsw_ciphertext_vector(&mut self, entity: GgswCiphertextVector);
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryGgswCiphertextVector32, ProtoBinaryGgswCiphertextVector64,
+    };
+    use crate::generation::synthesizing::SynthesizesGgswCiphertextVector;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{GgswCiphertextVector32, GgswCiphertextVector64};
+
+    impl SynthesizesGgswCiphertextVector<Precision32, BinaryKeyDistribution, GgswCiphertextVector32>
+        for Maker
+    {
+        fn synthesize_ggsw_ciphertext_vector(
+            &mut self,
+            prototype: &Self::GgswCiphertextVectorProto,
+        ) -> GgswCiphertextVector32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_ggsw_ciphertext_vector(
+            &mut self,
+            entity: GgswCiphertextVector32,
+        ) -> Self::GgswCiphertextVectorProto {
+            ProtoBinaryGgswCiphertextVector32(entity)
+        }
+
+        fn destroy_ggsw_ciphertext_vector(&mut self, _entity: GgswCiphertextVector32) {}
+    }
+
+    impl SynthesizesGgswCiphertextVector<Precision64, BinaryKeyDistribution, GgswCiphertextVector64>
+        for Maker
+    {
+        fn synthesize_ggsw_ciphertext_vector(
+            &mut self,
+            prototype: &Self::GgswCiphertextVectorProto,
+        ) -> GgswCiphertextVector64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_ggsw_ciphertext_vector(
+            &mut self,
+            entity: GgswCiphertextVector64,
+        ) -> Self::GgswCiphertextVectorProto {
+            ProtoBinaryGgswCiphertextVector64(entity)
+        }
+
+        fn destroy_ggsw_ciphertext_vector(&mut self, _entity: GgswCiphertextVector64) {}
+    }
+}
+
+#[cfg(any(feature = "backend_fft", feature = "backend_fft_parallel"))]
+mod backend_fft {
+    use crate::generation::synthesizing::SynthesizesGgswCiphertextVector;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        FftFourierGgswCiphertextVector32, FftFourierGgswCiphertextVector64,
+        GgswCiphertextVectorConversionEngine,
+    };
+
+    impl
+        SynthesizesGgswCiphertextVector<
+            Precision32,
+            BinaryKeyDistribution,
+            FftFourierGgswCiphertextVector32,
+        > for Maker
+    {
+        fn synthesize_ggsw_ciphertext_vector(
+            &mut self,
+            prototype: &Self::GgswCiphertextVectorProto,
+        ) -> FftFourierGgswCiphertextVector32 {
+            self.fft_engine
+                .convert_ggsw_ciphertext_vector(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_ggsw_ciphertext_vector(
+            &mut self,
+            _entity: FftFourierGgswCiphertextVector32,
+        ) -> Self::GgswCiphertextVectorProto {
+            // FIXME:
+            unimplemented!("The backward fourier conversion was not yet implemented");
+        }
+
+        fn destroy_ggsw_ciphertext_vector(&mut self, _entity: FftFourierGgswCiphertextVector32) {}
+    }
+
+    impl
+        SynthesizesGgswCiphertextVector<
+            Precision64,
+            BinaryKeyDistribution,
+            FftFourierGgswCiphertextVector64,
+        > for Maker
+    {
+        fn synthesize_ggsw_ciphertext_vector(
+            &mut self,
+            prototype: &Self::GgswCiphertextVectorProto,
+        ) -> FftFourierGgswCiphertextVector64 {
+            self.fft_engine
+                .convert_ggsw_ciphertext_vector(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_ggsw_ciphertext_vector(
+            &mut self,
+            _entity: FftFourierGgswCiphertextVector64,
+        ) -> Self::GgswCiphertextVectorProto {
+            // FIXME:
+            unimplemented!("The backward fourier conversion was not yet implemented");
+        }
+
+        fn destroy_ggsw_ciphertext_vector(&mut self, _entity: FftFourierGgswCiphertextVector64) {}
+    }
+}
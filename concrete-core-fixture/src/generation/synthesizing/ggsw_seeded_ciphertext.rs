@@ -0,0 +1,71 @@
+use crate::generation::prototyping::PrototypesGgswSeededCiphertext;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::GgswSeededCiphertextEntity;
+
+/// A trait allowing to synthesize an actual GgswSeededCiphertextEntity from a prototype.
+pub trait SynthesizesGgswSeededCiphertext<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    GgswSeededCiphertext,
+>: PrototypesGgswSeededCiphertext<Precision, KeyDistribution> where
+    GgswSeededCiphertext: GgswSeededCiphertextEntity,
+{
+    fn synthesize_ggsw_seeded_ciphertext(
+        &mut self,
+        prototype: &Self::GgswSeededCiphertextProto,
+    ) -> GgswSeededCiphertext;
+    fn unsynthesize_ggsw_seeded_ciphertext(
+        &mut self,
+        entity: GgswSeededCiphertext,
+    ) -> Self::GgswSeededCiphertextProto;
+    fn destroy_ggsw_seeded_ciphertext(&mut self, entity: GgswSeededCiphertext);
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryGgswSeededCiphertext32, ProtoBinaryGgswSeededCiphertext64,
+    };
+    use crate::generation::synthesizing::SynthesizesGgswSeededCiphertext;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{GgswSeededCiphertext32, GgswSeededCiphertext64};
+
+    impl SynthesizesGgswSeededCiphertext<Precision32, BinaryKeyDistribution, GgswSeededCiphertext32>
+        for Maker
+    {
+        fn synthesize_ggsw_seeded_ciphertext(
+            &mut self,
+            prototype: &Self::GgswSeededCiphertextProto,
+        ) -> GgswSeededCiphertext32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_ggsw_seeded_ciphertext(
+            &mut self,
+            entity: GgswSeededCiphertext32,
+        ) -> Self::GgswSeededCiphertextProto {
+            ProtoBinaryGgswSeededCiphertext32(entity)
+        }
+
+        fn destroy_ggsw_seeded_ciphertext(&mut self, _entity: GgswSeededCiphertext32) {}
+    }
+
+    impl SynthesizesGgswSeededCiphertext<Precision64, BinaryKeyDistribution, GgswSeededCiphertext64>
+        for Maker
+    {
+        fn synthesize_ggsw_seeded_ciphertext(
+            &mut self,
+            prototype: &Self::GgswSeededCiphertextProto,
+        ) -> GgswSeededCiphertext64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_ggsw_seeded_ciphertext(
+            &mut self,
+            entity: GgswSeededCiphertext64,
+        ) -> Self::GgswSeededCiphertextProto {
+            ProtoBinaryGgswSeededCiphertext64(entity)
+        }
+
+        fn destroy_ggsw_seeded_ciphertext(&mut self, _entity: GgswSeededCiphertext64) {}
+    }
+}
@@ -0,0 +1,133 @@
+use crate::generation::prototyping::PrototypesGlweAutomorphismKey;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::GlweAutomorphismKeyEntity;
+
+/// A trait allowing to synthesize an actual glwe automorphism key entity from a prototype.
+pub trait SynthesizesGlweAutomorphismKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    GlweAutomorphismKey,
+>: PrototypesGlweAutomorphismKey<Precision, KeyDistribution> where
+    GlweAutomorphismKey: GlweAutomorphismKeyEntity,
+{
+    fn synthesize_glwe_automorphism_key(
+        &mut self,
+        prototype: &Self::GlweAutomorphismKeyProto,
+    ) -> GlweAutomorphismKey;
+    fn unsynthesize_glwe_automorphism_key(
+        &mut self,
+        entity: GlweAutomorphismKey,
+    ) -> Self::GlweAutomorphismKeyProto;
+    fn destroy_glwe_automorphism_key(&mut self, entity: GlweAutomorphismKey);
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryGlweAutomorphismKey32, ProtoBinaryGlweAutomorphismKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesGlweAutomorphismKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{GlweAutomorphismKey32, GlweAutomorphismKey64};
+
+    impl SynthesizesGlweAutomorphismKey<Precision32, BinaryKeyDistribution, GlweAutomorphismKey32>
+        for Maker
+    {
+        fn synthesize_glwe_automorphism_key(
+            &mut self,
+            prototype: &Self::GlweAutomorphismKeyProto,
+        ) -> GlweAutomorphismKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_automorphism_key(
+            &mut self,
+            entity: GlweAutomorphismKey32,
+        ) -> Self::GlweAutomorphismKeyProto {
+            ProtoBinaryGlweAutomorphismKey32(entity)
+        }
+
+        fn destroy_glwe_automorphism_key(&mut self, _entity: GlweAutomorphismKey32) {}
+    }
+
+    impl SynthesizesGlweAutomorphismKey<Precision64, BinaryKeyDistribution, GlweAutomorphismKey64>
+        for Maker
+    {
+        fn synthesize_glwe_automorphism_key(
+            &mut self,
+            prototype: &Self::GlweAutomorphismKeyProto,
+        ) -> GlweAutomorphismKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_automorphism_key(
+            &mut self,
+            entity: GlweAutomorphismKey64,
+        ) -> Self::GlweAutomorphismKeyProto {
+            ProtoBinaryGlweAutomorphismKey64(entity)
+        }
+
+        fn destroy_glwe_automorphism_key(&mut self, _entity: GlweAutomorphismKey64) {}
+    }
+}
+
+#[cfg(any(feature = "backend_fft", feature = "backend_fft_parallel"))]
+mod backend_fft {
+    use crate::generation::synthesizing::SynthesizesGlweAutomorphismKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        FftFourierGlweAutomorphismKey32, FftFourierGlweAutomorphismKey64,
+        GlweAutomorphismKeyConversionEngine,
+    };
+
+    impl
+        SynthesizesGlweAutomorphismKey<
+            Precision32,
+            BinaryKeyDistribution,
+            FftFourierGlweAutomorphismKey32,
+        > for Maker
+    {
+        fn synthesize_glwe_automorphism_key(
+            &mut self,
+            prototype: &Self::GlweAutomorphismKeyProto,
+        ) -> FftFourierGlweAutomorphismKey32 {
+            self.fft_engine
+                .convert_glwe_automorphism_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_automorphism_key(
+            &mut self,
+            _entity: FftFourierGlweAutomorphismKey32,
+        ) -> Self::GlweAutomorphismKeyProto {
+            todo!()
+        }
+
+        fn destroy_glwe_automorphism_key(&mut self, _entity: FftFourierGlweAutomorphismKey32) {}
+    }
+
+    impl
+        SynthesizesGlweAutomorphismKey<
+            Precision64,
+            BinaryKeyDistribution,
+            FftFourierGlweAutomorphismKey64,
+        > for Maker
+    {
+        fn synthesize_glwe_automorphism_key(
+            &mut self,
+            prototype: &Self::GlweAutomorphismKeyProto,
+        ) -> FftFourierGlweAutomorphismKey64 {
+            self.fft_engine
+                .convert_glwe_automorphism_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_automorphism_key(
+            &mut self,
+            _entity: FftFourierGlweAutomorphismKey64,
+        ) -> Self::GlweAutomorphismKeyProto {
+            todo!()
+        }
+
+        fn destroy_glwe_automorphism_key(&mut self, _entity: FftFourierGlweAutomorphismKey64) {}
+    }
+}
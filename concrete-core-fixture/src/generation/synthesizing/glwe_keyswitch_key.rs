@@ -0,0 +1,146 @@
+use crate::generation::prototyping::PrototypesGlweKeyswitchKey;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::GlweKeyswitchKeyEntity;
+
+/// A trait allowing to synthesize an actual glwe keyswitch key entity from a prototype.
+pub trait SynthesizesGlweKeyswitchKey<
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    GlweKeyswitchKey,
+>: PrototypesGlweKeyswitchKey<Precision, InputKeyDistribution, OutputKeyDistribution> where
+    GlweKeyswitchKey: GlweKeyswitchKeyEntity,
+{
+    fn synthesize_glwe_keyswitch_key(
+        &mut self,
+        prototype: &Self::GlweKeyswitchKeyProto,
+    ) -> GlweKeyswitchKey;
+    fn unsynthesize_glwe_keyswitch_key(
+        &mut self,
+        entity: GlweKeyswitchKey,
+    ) -> Self::GlweKeyswitchKeyProto;
+    fn destroy_glwe_keyswitch_key(&mut self, entity: GlweKeyswitchKey);
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryBinaryGlweKeyswitchKey32, ProtoBinaryBinaryGlweKeyswitchKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesGlweKeyswitchKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{GlweKeyswitchKey32, GlweKeyswitchKey64};
+
+    impl
+        SynthesizesGlweKeyswitchKey<
+            Precision32,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            GlweKeyswitchKey32,
+        > for Maker
+    {
+        fn synthesize_glwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::GlweKeyswitchKeyProto,
+        ) -> GlweKeyswitchKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_keyswitch_key(
+            &mut self,
+            entity: GlweKeyswitchKey32,
+        ) -> Self::GlweKeyswitchKeyProto {
+            ProtoBinaryBinaryGlweKeyswitchKey32(entity)
+        }
+
+        fn destroy_glwe_keyswitch_key(&mut self, _entity: GlweKeyswitchKey32) {}
+    }
+
+    impl
+        SynthesizesGlweKeyswitchKey<
+            Precision64,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            GlweKeyswitchKey64,
+        > for Maker
+    {
+        fn synthesize_glwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::GlweKeyswitchKeyProto,
+        ) -> GlweKeyswitchKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_keyswitch_key(
+            &mut self,
+            entity: GlweKeyswitchKey64,
+        ) -> Self::GlweKeyswitchKeyProto {
+            ProtoBinaryBinaryGlweKeyswitchKey64(entity)
+        }
+
+        fn destroy_glwe_keyswitch_key(&mut self, _entity: GlweKeyswitchKey64) {}
+    }
+}
+
+#[cfg(any(feature = "backend_fft", feature = "backend_fft_parallel"))]
+mod backend_fft {
+    use crate::generation::synthesizing::SynthesizesGlweKeyswitchKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        FftFourierGlweKeyswitchKey32, FftFourierGlweKeyswitchKey64,
+        GlweKeyswitchKeyConversionEngine,
+    };
+
+    impl
+        SynthesizesGlweKeyswitchKey<
+            Precision32,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            FftFourierGlweKeyswitchKey32,
+        > for Maker
+    {
+        fn synthesize_glwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::GlweKeyswitchKeyProto,
+        ) -> FftFourierGlweKeyswitchKey32 {
+            self.fft_engine
+                .convert_glwe_keyswitch_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_keyswitch_key(
+            &mut self,
+            _entity: FftFourierGlweKeyswitchKey32,
+        ) -> Self::GlweKeyswitchKeyProto {
+            todo!()
+        }
+
+        fn destroy_glwe_keyswitch_key(&mut self, _entity: FftFourierGlweKeyswitchKey32) {}
+    }
+
+    impl
+        SynthesizesGlweKeyswitchKey<
+            Precision64,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            FftFourierGlweKeyswitchKey64,
+        > for Maker
+    {
+        fn synthesize_glwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::GlweKeyswitchKeyProto,
+        ) -> FftFourierGlweKeyswitchKey64 {
+            self.fft_engine
+                .convert_glwe_keyswitch_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_keyswitch_key(
+            &mut self,
+            _entity: FftFourierGlweKeyswitchKey64,
+        ) -> Self::GlweKeyswitchKeyProto {
+            todo!()
+        }
+
+        fn destroy_glwe_keyswitch_key(&mut self, _entity: FftFourierGlweKeyswitchKey64) {}
+    }
+}
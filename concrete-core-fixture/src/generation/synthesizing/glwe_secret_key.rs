@@ -17,10 +17,19 @@ pub trait SynthesizesGlweSecretKey<
 }
 
 mod backend_default {
-    use crate::generation::prototypes::{ProtoBinaryGlweSecretKey32, ProtoBinaryGlweSecretKey64};
+    use crate::generation::prototypes::{
+        ProtoBinaryGlweSecretKey32, ProtoBinaryGlweSecretKey64, ProtoGaussianGlweSecretKey32,
+        ProtoGaussianGlweSecretKey64, ProtoTernaryGlweSecretKey32, ProtoTernaryGlweSecretKey64,
+    };
     use crate::generation::synthesizing::SynthesizesGlweSecretKey;
-    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
-    use concrete_core::prelude::{GlweSecretKey32, GlweSecretKey64};
+    use crate::generation::{
+        BinaryKeyDistribution, GaussianKeyDistribution, Maker, Precision32, Precision64,
+        TernaryKeyDistribution,
+    };
+    use concrete_core::prelude::{
+        GlweGaussianSecretKey32, GlweGaussianSecretKey64, GlweSecretKey32, GlweSecretKey64,
+        GlweTernarySecretKey32, GlweTernarySecretKey64,
+    };
 
     impl SynthesizesGlweSecretKey<Precision32, BinaryKeyDistribution, GlweSecretKey32> for Maker {
         fn synthesize_glwe_secret_key(
@@ -57,4 +66,84 @@ mod backend_default {
 
         fn destroy_glwe_secret_key(&mut self, _entity: GlweSecretKey64) {}
     }
+
+    impl SynthesizesGlweSecretKey<Precision32, TernaryKeyDistribution, GlweTernarySecretKey32>
+        for Maker
+    {
+        fn synthesize_glwe_secret_key(
+            &mut self,
+            prototype: &Self::GlweSecretKeyProto,
+        ) -> GlweTernarySecretKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_secret_key(
+            &mut self,
+            entity: GlweTernarySecretKey32,
+        ) -> Self::GlweSecretKeyProto {
+            ProtoTernaryGlweSecretKey32(entity)
+        }
+
+        fn destroy_glwe_secret_key(&mut self, _entity: GlweTernarySecretKey32) {}
+    }
+
+    impl SynthesizesGlweSecretKey<Precision64, TernaryKeyDistribution, GlweTernarySecretKey64>
+        for Maker
+    {
+        fn synthesize_glwe_secret_key(
+            &mut self,
+            prototype: &Self::GlweSecretKeyProto,
+        ) -> GlweTernarySecretKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_secret_key(
+            &mut self,
+            entity: GlweTernarySecretKey64,
+        ) -> Self::GlweSecretKeyProto {
+            ProtoTernaryGlweSecretKey64(entity)
+        }
+
+        fn destroy_glwe_secret_key(&mut self, _entity: GlweTernarySecretKey64) {}
+    }
+
+    impl SynthesizesGlweSecretKey<Precision32, GaussianKeyDistribution, GlweGaussianSecretKey32>
+        for Maker
+    {
+        fn synthesize_glwe_secret_key(
+            &mut self,
+            prototype: &Self::GlweSecretKeyProto,
+        ) -> GlweGaussianSecretKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_secret_key(
+            &mut self,
+            entity: GlweGaussianSecretKey32,
+        ) -> Self::GlweSecretKeyProto {
+            ProtoGaussianGlweSecretKey32(entity)
+        }
+
+        fn destroy_glwe_secret_key(&mut self, _entity: GlweGaussianSecretKey32) {}
+    }
+
+    impl SynthesizesGlweSecretKey<Precision64, GaussianKeyDistribution, GlweGaussianSecretKey64>
+        for Maker
+    {
+        fn synthesize_glwe_secret_key(
+            &mut self,
+            prototype: &Self::GlweSecretKeyProto,
+        ) -> GlweGaussianSecretKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_secret_key(
+            &mut self,
+            entity: GlweGaussianSecretKey64,
+        ) -> Self::GlweSecretKeyProto {
+            ProtoGaussianGlweSecretKey64(entity)
+        }
+
+        fn destroy_glwe_secret_key(&mut self, _entity: GlweGaussianSecretKey64) {}
+    }
 }
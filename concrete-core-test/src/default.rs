@@ -1,7 +1,10 @@
 use crate::{REPETITIONS, SAMPLE_SIZE};
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
-use concrete_core_fixture::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+use concrete_core_fixture::generation::{
+    BinaryKeyDistribution, GaussianKeyDistribution, Maker, Precision32, Precision64,
+    TernaryKeyDistribution,
+};
 use concrete_csprng::seeders::UnixSeeder;
 use paste::paste;
 
@@ -50,12 +53,27 @@ test! {
     ((), CleartextVectorDiscardingRetrievalFixture, (CleartextVector)),
     ((), CleartextVectorRetrievalFixture, (CleartextVector)),
     ((BinaryKeyDistribution), GlweCiphertextDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+    ((TernaryKeyDistribution), GlweCiphertextDecryptionFixture, (PlaintextVector, GlweTernarySecretKey, GlweCiphertext)),
+    ((GaussianKeyDistribution), GlweCiphertextDecryptionFixture, (PlaintextVector, GlweGaussianSecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextDiscardingDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextDiscardingEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextDiscardingTrivialEncryptionFixture, (PlaintextVector, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextDiscardingTrivialEncryptionFixture, (PlaintextVector, GlweCiphertextMutView)),
     ((BinaryKeyDistribution), GlweCiphertextEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+    ((TernaryKeyDistribution), GlweCiphertextEncryptionFixture, (PlaintextVector, GlweTernarySecretKey, GlweCiphertext)),
+    ((GaussianKeyDistribution), GlweCiphertextEncryptionFixture, (PlaintextVector, GlweGaussianSecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextTrivialEncryptionFixture, (PlaintextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextAccumulatorGenerationFixture, (GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingAdditionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingAdditionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingSubtractionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingSubtractionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingOppositeFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingOppositeFixture, (GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorDiscardingAdditionFixture, (GlweCiphertext, PlaintextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorFusingAdditionFixture, (GlweCiphertext, PlaintextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextCleartextVectorDiscardingMultiplicationFixture, (GlweCiphertext, CleartextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextCleartextVectorFusingMultiplicationFixture, (GlweCiphertext, CleartextVector)),
     ((BinaryKeyDistribution), GlweCiphertextZeroEncryptionFixture, (GlweSecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextVectorEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
     ((BinaryKeyDistribution), GlweCiphertextVectorDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
@@ -77,6 +95,8 @@ test! {
     ((BinaryKeyDistribution), GlweSeededCiphertextVectorEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweSeededCiphertextVector)),
     ((BinaryKeyDistribution), GlweSeededCiphertextVectorToGlweCiphertextVectorTransformationFixture, (PlaintextVector, GlweSecretKey, GlweSeededCiphertextVector, GlweCiphertextVector)),
     ((BinaryKeyDistribution), GlweToLweSecretKeyTransformationFixture, (GlweSecretKey, LweSecretKey)),
+    ((TernaryKeyDistribution), GlweToLweSecretKeyTransformationFixture, (GlweTernarySecretKey, LweTernarySecretKey)),
+    ((GaussianKeyDistribution), GlweToLweSecretKeyTransformationFixture, (GlweGaussianSecretKey, LweGaussianSecretKey)),
     ((BinaryKeyDistribution, BinaryKeyDistribution),
         LweBootstrapKeyCreationFixture, (Vec, LweBootstrapKey)),
     ((BinaryKeyDistribution, BinaryKeyDistribution),
@@ -90,16 +110,22 @@ test! {
     ((BinaryKeyDistribution, BinaryKeyDistribution),
         LweBootstrapKeyConsumingRetrievalFixture, (LweBootstrapKeyMutView, MutSlice)),
     ((BinaryKeyDistribution), LweCiphertextEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+    ((TernaryKeyDistribution), LweCiphertextEncryptionFixture, (Plaintext, LweTernarySecretKey, LweCiphertext)),
+    ((GaussianKeyDistribution), LweCiphertextEncryptionFixture, (Plaintext, LweGaussianSecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextZeroEncryptionFixture, (LweSecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextTrivialEncryptionFixture, (Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextTrivialDecryptionFixture, (Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector)),
     ((BinaryKeyDistribution), LweCiphertextDecryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+    ((TernaryKeyDistribution), LweCiphertextDecryptionFixture, (Plaintext, LweTernarySecretKey, LweCiphertext)),
+    ((GaussianKeyDistribution), LweCiphertextDecryptionFixture, (Plaintext, LweGaussianSecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDecryptionFixture, (Plaintext, LweSecretKey, LweCiphertextView)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertextMutView)),
     ((BinaryKeyDistribution), LweCiphertextVectorDecryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
     ((BinaryKeyDistribution), LweCiphertextVectorEncryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorPublicKeyEncryptionFixture, (PlaintextVector, LwePublicKey, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDiscardingPublicKeyEncryptionFixture, (PlaintextVector, LwePublicKey, LweCiphertextVector)),
     ((BinaryKeyDistribution), LweCiphertextVectorDiscardingEncryptionFixture, (PlaintextVector, LweSecretKey,
         LweCiphertextVector)),
     ((BinaryKeyDistribution), LweCiphertextVectorDiscardingDecryptionFixture, (PlaintextVector, LweSecretKey,
@@ -118,6 +144,12 @@ test! {
     ((BinaryKeyDistribution), LweCiphertextVectorDiscardingAffineTransformationFixture, (LweCiphertextVector, CleartextVector, Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertextView, LweCiphertextMutView)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweKeyswitchKeyGenerationKeyswitchFixture,
+        (LweSecretKey, LweSecretKey, LweKeyswitchKey, LweCiphertext, LweCiphertext)),
+    ((TernaryKeyDistribution, TernaryKeyDistribution), LweKeyswitchKeyGenerationKeyswitchFixture,
+        (LweTernarySecretKey, LweTernarySecretKey, LweKeyswitchKey, LweCiphertext, LweCiphertext)),
+    ((GaussianKeyDistribution, GaussianKeyDistribution), LweKeyswitchKeyGenerationKeyswitchFixture,
+        (LweGaussianSecretKey, LweGaussianSecretKey, LweKeyswitchKey, LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingAdditionFixture, (LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingAdditionFixture, (LweCiphertextView, LweCiphertextMutView)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingOppositeFixture, (LweCiphertext, LweCiphertext)),
@@ -135,6 +167,11 @@ test! {
     ((BinaryKeyDistribution), LweCiphertextPlaintextDiscardingSubtractionFixture, (LweCiphertext, Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextPlaintextFusingSubtractionFixture, (Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingExtractionFixture, (GlweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDiscardingExtractionFixture, (GlweCiphertext, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), GlweCiphertextDiscardingKeyswitchFixture, (GlweKeyswitchKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingTraceFixture, (GlweAutomorphismKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingTracePackingFixture, (GlweAutomorphismKey,
+        LweCiphertextVector, GlweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchFixture, (LweCiphertextVector, LwePackingKeyswitchKey, GlweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchFixture,
         (LweCiphertextVector, LwePrivateFunctionalPackingKeyswitchKey, GlweCiphertext)),
@@ -160,7 +197,14 @@ test! {
     ((BinaryKeyDistribution), LweSeededCiphertextToLweCiphertextTransformationFixture, (Plaintext, LweSecretKey, LweSeededCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution), LweSeededCiphertextVectorEncryptionFixture, (PlaintextVector, LweSecretKey, LweSeededCiphertextVector)),
     ((BinaryKeyDistribution), LweSeededCiphertextVectorToLweCiphertextVectorTransformationFixture, (PlaintextVector, LweSecretKey, LweSeededCiphertextVector, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweSeededPublicKeyGenerationFixture, (LweSecretKey, LweSeededPublicKey)),
+    ((BinaryKeyDistribution), LweSeededPublicKeyToLwePublicKeyTransformationFixture, (LweSeededPublicKey, LwePublicKey)),
     ((BinaryKeyDistribution), LweToGlweSecretKeyTransformationFixture, (LweSecretKey, GlweSecretKey)),
+    ((BinaryKeyDistribution), LweCiphertextGswCiphertextExternalProductFixture, (LweCiphertext, GswCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextsGswCiphertextFusingCmuxFixture, (LweCiphertext, LweCiphertext, GswCiphertext)),
+    ((BinaryKeyDistribution), GgswCiphertextVectorScalarEncryptionFixture, (GlweSecretKey, PlaintextVector, GgswCiphertextVector)),
+    ((BinaryKeyDistribution), GgswSeededCiphertextToGgswCiphertextTransformationFixture, (GgswSeededCiphertext,
+        GgswCiphertext)),
     ((), PlaintextCreationFixture, (Plaintext)),
     ((), PlaintextDiscardingRetrievalFixture, (Plaintext)),
     ((), PlaintextRetrievalFixture, (Plaintext)),
@@ -200,5 +244,7 @@ macro_rules! test_parallel {
 
 #[cfg(feature = "backend_default_parallel")]
 test_parallel! {
-    ((BinaryKeyDistribution), LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector))
+    ((BinaryKeyDistribution), LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDiscardingExtractionFixture, (GlweCiphertext, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorPublicKeyEncryptionFixture, (PlaintextVector, LwePublicKey, LweCiphertextVector))
 }
@@ -1,7 +1,9 @@
 use crate::{REPETITIONS, SAMPLE_SIZE};
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
-use concrete_core_fixture::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+use concrete_core_fixture::generation::{
+    BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+};
 use paste::paste;
 
 macro_rules! test {
@@ -34,13 +36,139 @@ macro_rules! test {
 
 test! {
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture1, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((TernaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture1, (FftFourierLweTernaryBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextMultiValueBootstrapFixture, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBlindRotationFixture, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture2, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FftFourierGgswCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextsGgswCiphertextFusingCmuxFixture, (GlweCiphertext,
         GlweCiphertext, FftFourierGgswCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextTensorProductFixture, (GlweCiphertext, GlweCiphertext,
+        GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextMultiplicationFixture, (GlweCiphertext, GlweCiphertext,
+        FftFourierGlweRelinearizationKey, GlweCiphertext)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), GlweCiphertextDiscardingKeyswitchFixture,
+        (FftFourierGlweKeyswitchKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingTraceFixture,
+        (FftFourierGlweAutomorphismKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingTracePackingFixture,
+        (FftFourierGlweAutomorphismKey, LweCiphertextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), GgswCiphertextVectorConversionFixture, (GgswCiphertextVector,
+        FftFourierGgswCiphertextVector)),
+    ((BinaryKeyDistribution), GgswSeededCiphertextConversionFixture, (GgswSeededCiphertext,
+        FftFourierGgswCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingCmuxTreeFixture, (FftFourierGgswCiphertextVector,
+        PlaintextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextDiscardingVerticalPackingFixture, (FftFourierGgswCiphertextVector,
+        PlaintextVector, LweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBitExtractFixture,
         (FftFourierLweBootstrapKey, LweKeyswitchKey, LweCiphertext, LweCiphertextVector)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingFixture,
         (FftFourierLweBootstrapKey, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
-        PlaintextVector, LweCiphertextVectorView, LweCiphertextVectorMutView))
+        PlaintextVector, LweCiphertextVectorView, LweCiphertextVectorMutView)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingAndFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingNandFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingNorFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingOrFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingXorFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingXnorFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingAndKeyMismatchFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingNandKeyMismatchFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingNorKeyMismatchFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingOrKeyMismatchFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingXorKeyMismatchFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingXnorKeyMismatchFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDiscardingNotFixture,
+        (LweCiphertextVector, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingAndFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingNandFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingNorFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingOrFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingXorFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingXnorFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingMuxFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingMuxKeyMismatchFixture,
+        (LweCiphertext, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution), LweCiphertextDiscardingNotFixture,
+        (LweCiphertext, LweCiphertext))
+}
+
+#[cfg(feature = "backend_fft_parallel")]
+macro_rules! test_parallel {
+    (($($key_dist:ident),*), $fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            fn [< test_parallel_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                let mut maker = Maker::default();
+                let mut engine = FftParallelEngine::new(()).unwrap();
+                let test_result =
+                    <$fixture as Fixture<
+                        $precision,
+                        ($($key_dist,)*),
+                        FftParallelEngine,
+                        ($($types,)+),
+                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
+                assert!(test_result);
+            }
+        }
+    };
+    ($((($($key_dist:ident),*), $fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                test_parallel!{($($key_dist),*), $fixture, Precision32, ($([< $types 32 >]),+)}
+                test_parallel!{($($key_dist),*), $fixture, Precision64, ($([< $types 64 >]),+)}
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "backend_fft_parallel")]
+test_parallel! {
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingBootstrapFixture1,
+        (FftFourierLweBootstrapKey, GlweCiphertextVector, LweCiphertextVector, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingAndFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingNandFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingNorFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingOrFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingXorFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingXnorFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingAndKeyMismatchFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingNandKeyMismatchFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingNorKeyMismatchFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingOrKeyMismatchFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingXorKeyMismatchFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingXnorKeyMismatchFixture,
+        (LweCiphertextVector, FftFourierLweBootstrapKey, LweKeyswitchKey)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDiscardingNotFixture,
+        (LweCiphertextVector, LweCiphertextVector))
 }
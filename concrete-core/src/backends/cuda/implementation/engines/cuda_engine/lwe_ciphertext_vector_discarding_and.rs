@@ -23,13 +23,12 @@ impl
         input_2: &CudaLweCiphertextVector32,
         bsk: &CudaFourierLweBootstrapKey32,
         ksk: &CudaLweKeyswitchKey32,
-        stream_idx: usize,
     ) -> Result<(), LweCiphertextVectorDiscardingAndError<CudaError>> {
-        LweCiphertextVectorDiscardingAndError::perform_generic_checks(output, input_1, input_2)?;
+        LweCiphertextVectorDiscardingAndError::perform_generic_checks(
+            output, input_1, input_2, bsk, ksk,
+        )?;
         unsafe {
-            self.discard_and_lwe_ciphertext_vector_unchecked(
-                output, input_1, input_2, bsk, ksk, stream_idx,
-            )
+            self.discard_and_lwe_ciphertext_vector_unchecked(output, input_1, input_2, bsk, ksk)
         };
         Ok(())
     }
@@ -41,9 +40,8 @@ impl
         input_2: &CudaLweCiphertextVector32,
         bsk: &CudaFourierLweBootstrapKey32,
         ksk: &CudaLweKeyswitchKey32,
-        stream_idx: usize,
     ) {
-        self.streams[stream_idx]
+        self.streams[self.active_stream]
             .write()
             .unwrap()
             .discard_and_amortized_lwe_ciphertext_vector::<u32>(
@@ -13,10 +13,9 @@ impl LweCiphertextVectorDiscardingNotEngine<CudaLweCiphertextVector32, CudaLweCi
         &self,
         output: &mut CudaLweCiphertextVector32,
         input: &CudaLweCiphertextVector32,
-        stream_idx: usize,
     ) -> Result<(), LweCiphertextVectorDiscardingNotError<CudaError>> {
         LweCiphertextVectorDiscardingNotError::perform_generic_checks(output, input)?;
-        unsafe { self.discard_not_lwe_ciphertext_vector_unchecked(output, input, stream_idx) };
+        unsafe { self.discard_not_lwe_ciphertext_vector_unchecked(output, input) };
         Ok(())
     }
 
@@ -24,9 +23,8 @@ impl LweCiphertextVectorDiscardingNotEngine<CudaLweCiphertextVector32, CudaLweCi
         &self,
         output: &mut CudaLweCiphertextVector32,
         input: &CudaLweCiphertextVector32,
-        stream_idx: usize,
     ) {
-        self.streams[stream_idx]
+        self.streams[self.active_stream]
             .write()
             .unwrap()
             .discard_not_amortized_lwe_ciphertext_vector::<u32>(
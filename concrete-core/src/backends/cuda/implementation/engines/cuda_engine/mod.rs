@@ -11,6 +11,11 @@ use std::sync::{Arc, RwLock};
 /// number of input ciphertexts). This engine does not give control over the streams, nor the GPU
 /// load balancing. In this way, we can overlap computations done on different GPUs, but not
 /// computations done on a given GPU, which are executed in a sequence.
+///
+/// Operations which are not split over the GPUs, such as the boolean gates, are executed on the
+/// _active stream_ of the engine, which is the first stream by default. It can be changed with
+/// [`CudaEngine::set_active_stream`], for instance on a clone of the engine, so that independent
+/// gates can be executed concurrently on different streams.
 // A finer access to streams could allow for more overlapping of computations
 // on a given device. We'll probably want to support it in the future, in an AdvancedCudaEngine
 // for example.
@@ -20,6 +25,7 @@ pub struct CudaEngine {
     streams: Vec<Arc<RwLock<CudaStream>>>,
     max_shared_memory: usize,
     number_of_gpus: usize,
+    active_stream: usize,
 }
 
 impl AbstractEngineSeal for CudaEngine {}
@@ -46,6 +52,7 @@ impl AbstractEngine for CudaEngine {
                 streams,
                 max_shared_memory: max_shared_memory as usize,
                 number_of_gpus: number_of_gpus as usize,
+                active_stream: 0,
             })
         }
     }
@@ -64,6 +71,18 @@ impl CudaEngine {
     pub fn get_cuda_shared_memory(&self) -> SharedMemoryAmount {
         SharedMemoryAmount(self.max_shared_memory)
     }
+    /// Get the index of the stream on which the single-stream operations are executed
+    pub fn get_active_stream(&self) -> usize {
+        self.active_stream
+    }
+    /// Set the index of the stream on which the single-stream operations are executed
+    pub fn set_active_stream(&mut self, stream_idx: usize) -> Result<(), CudaError> {
+        if stream_idx >= self.streams.len() {
+            return Err(CudaError::InvalidStreamIndex(stream_idx));
+        }
+        self.active_stream = stream_idx;
+        Ok(())
+    }
 }
 
 macro_rules! check_poly_size {
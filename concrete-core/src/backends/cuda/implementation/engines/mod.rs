@@ -21,6 +21,7 @@ pub enum CudaError {
     SharedMemoryNotFound(GpuIndex),
     NotEnoughDeviceMemory(GpuIndex),
     InvalidDeviceIndex(GpuIndex),
+    InvalidStreamIndex(usize),
     UnspecifiedDeviceError(GpuIndex),
     PolynomialSizeNotSupported,
     GlweDimensionNotSupported,
@@ -49,6 +50,13 @@ impl Display for CudaError {
                     gpu_index.0
                 )
             }
+            CudaError::InvalidStreamIndex(stream_idx) => {
+                write!(
+                    f,
+                    "The specified stream index, {}, does not exist.",
+                    stream_idx
+                )
+            }
             CudaError::PolynomialSizeNotSupported => {
                 write!(
                     f,
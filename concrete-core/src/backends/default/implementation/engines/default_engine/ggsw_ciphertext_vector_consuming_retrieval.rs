@@ -0,0 +1,376 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GgswCiphertextVector32, GgswCiphertextVector64, GgswCiphertextVectorMutView32,
+    GgswCiphertextVectorMutView64, GgswCiphertextVectorView32, GgswCiphertextVectorView64,
+};
+use crate::specification::engines::{
+    GgswCiphertextVectorConsumingRetrievalEngine, GgswCiphertextVectorConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorConsumingRetrievalEngine`] for [`DefaultEngine`] that
+/// returns the underlying container of a [`GgswCiphertextVector32`] consuming it in the process
+impl GgswCiphertextVectorConsumingRetrievalEngine<GgswCiphertextVector32, Vec<u32>>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ggsw_count = GgswCiphertextCount(3);
+    /// let glwe_size = glwe_dimension.to_glwe_size();
+    ///
+    /// // You have to make sure you size the container properly
+    /// let owned_container =
+    ///     vec![0_u32; level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 * ggsw_count.0];
+    /// let original_vec_ptr = owned_container.as_ptr();
+    ///
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVector32 = engine.create_ggsw_ciphertext_vector_from(
+    ///     owned_container,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let retrieved = engine.consume_retrieve_ggsw_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(original_vec_ptr, retrieved.as_ptr());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_ggsw_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: GgswCiphertextVector32,
+    ) -> Result<Vec<u32>, GgswCiphertextVectorConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_ggsw_ciphertext_vector_unchecked(ciphertext_vector) })
+    }
+
+    unsafe fn consume_retrieve_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        ciphertext_vector: GgswCiphertextVector32,
+    ) -> Vec<u32> {
+        ciphertext_vector.0.into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorConsumingRetrievalEngine`] for [`DefaultEngine`] that
+/// returns the underlying container of a [`GgswCiphertextVectorView32`] consuming it in the process
+impl<'data>
+    GgswCiphertextVectorConsumingRetrievalEngine<GgswCiphertextVectorView32<'data>, &'data [u32]>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ggsw_count = GgswCiphertextCount(3);
+    /// let glwe_size = glwe_dimension.to_glwe_size();
+    ///
+    /// // You have to make sure you size the container properly
+    /// let owned_container =
+    ///     vec![0_u32; level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 * ggsw_count.0];
+    /// let slice = &owned_container[..];
+    ///
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVectorView32 = engine
+    ///     .create_ggsw_ciphertext_vector_from(
+    ///         slice,
+    ///         glwe_dimension,
+    ///         polynomial_size,
+    ///         level,
+    ///         base_log,
+    ///     )?;
+    /// let retrieved = engine.consume_retrieve_ggsw_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(slice, retrieved);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_ggsw_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: GgswCiphertextVectorView32<'data>,
+    ) -> Result<&'data [u32], GgswCiphertextVectorConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_ggsw_ciphertext_vector_unchecked(ciphertext_vector) })
+    }
+
+    unsafe fn consume_retrieve_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        ciphertext_vector: GgswCiphertextVectorView32<'data>,
+    ) -> &'data [u32] {
+        ciphertext_vector.0.into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorConsumingRetrievalEngine`] for [`DefaultEngine`] that
+/// returns the underlying container of a [`GgswCiphertextVectorMutView32`] consuming it in the
+/// process
+impl<'data>
+    GgswCiphertextVectorConsumingRetrievalEngine<
+        GgswCiphertextVectorMutView32<'data>,
+        &'data mut [u32],
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ggsw_count = GgswCiphertextCount(3);
+    /// let glwe_size = glwe_dimension.to_glwe_size();
+    ///
+    /// // You have to make sure you size the container properly
+    /// let mut owned_container =
+    ///     vec![0_u32; level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 * ggsw_count.0];
+    /// let slice = &mut owned_container[..];
+    /// // Required as we can't borrow a mut slice more than once
+    /// let original_vec_ptr = slice.as_ptr();
+    ///
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVectorMutView32 = engine
+    ///     .create_ggsw_ciphertext_vector_from(
+    ///         slice,
+    ///         glwe_dimension,
+    ///         polynomial_size,
+    ///         level,
+    ///         base_log,
+    ///     )?;
+    /// let retrieved = engine.consume_retrieve_ggsw_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(original_vec_ptr, retrieved.as_ptr());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_ggsw_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: GgswCiphertextVectorMutView32<'data>,
+    ) -> Result<&'data mut [u32], GgswCiphertextVectorConsumingRetrievalError<Self::EngineError>>
+    {
+        Ok(unsafe { self.consume_retrieve_ggsw_ciphertext_vector_unchecked(ciphertext_vector) })
+    }
+
+    unsafe fn consume_retrieve_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        ciphertext_vector: GgswCiphertextVectorMutView32<'data>,
+    ) -> &'data mut [u32] {
+        ciphertext_vector.0.into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorConsumingRetrievalEngine`] for [`DefaultEngine`] that
+/// returns the underlying container of a [`GgswCiphertextVector64`] consuming it in the process
+impl GgswCiphertextVectorConsumingRetrievalEngine<GgswCiphertextVector64, Vec<u64>>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ggsw_count = GgswCiphertextCount(3);
+    /// let glwe_size = glwe_dimension.to_glwe_size();
+    ///
+    /// // You have to make sure you size the container properly
+    /// let owned_container =
+    ///     vec![0_u64; level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 * ggsw_count.0];
+    /// let original_vec_ptr = owned_container.as_ptr();
+    ///
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVector64 = engine.create_ggsw_ciphertext_vector_from(
+    ///     owned_container,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let retrieved = engine.consume_retrieve_ggsw_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(original_vec_ptr, retrieved.as_ptr());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_ggsw_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: GgswCiphertextVector64,
+    ) -> Result<Vec<u64>, GgswCiphertextVectorConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_ggsw_ciphertext_vector_unchecked(ciphertext_vector) })
+    }
+
+    unsafe fn consume_retrieve_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        ciphertext_vector: GgswCiphertextVector64,
+    ) -> Vec<u64> {
+        ciphertext_vector.0.into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorConsumingRetrievalEngine`] for [`DefaultEngine`] that
+/// returns the underlying container of a [`GgswCiphertextVectorView64`] consuming it in the process
+impl<'data>
+    GgswCiphertextVectorConsumingRetrievalEngine<GgswCiphertextVectorView64<'data>, &'data [u64]>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ggsw_count = GgswCiphertextCount(3);
+    /// let glwe_size = glwe_dimension.to_glwe_size();
+    ///
+    /// // You have to make sure you size the container properly
+    /// let owned_container =
+    ///     vec![0_u64; level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 * ggsw_count.0];
+    /// let slice = &owned_container[..];
+    ///
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVectorView64 = engine
+    ///     .create_ggsw_ciphertext_vector_from(
+    ///         slice,
+    ///         glwe_dimension,
+    ///         polynomial_size,
+    ///         level,
+    ///         base_log,
+    ///     )?;
+    /// let retrieved = engine.consume_retrieve_ggsw_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(slice, retrieved);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_ggsw_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: GgswCiphertextVectorView64<'data>,
+    ) -> Result<&'data [u64], GgswCiphertextVectorConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_ggsw_ciphertext_vector_unchecked(ciphertext_vector) })
+    }
+
+    unsafe fn consume_retrieve_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        ciphertext_vector: GgswCiphertextVectorView64<'data>,
+    ) -> &'data [u64] {
+        ciphertext_vector.0.into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorConsumingRetrievalEngine`] for [`DefaultEngine`] that
+/// returns the underlying container of a [`GgswCiphertextVectorMutView64`] consuming it in the
+/// process
+impl<'data>
+    GgswCiphertextVectorConsumingRetrievalEngine<
+        GgswCiphertextVectorMutView64<'data>,
+        &'data mut [u64],
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ggsw_count = GgswCiphertextCount(3);
+    /// let glwe_size = glwe_dimension.to_glwe_size();
+    ///
+    /// // You have to make sure you size the container properly
+    /// let mut owned_container =
+    ///     vec![0_u64; level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 * ggsw_count.0];
+    /// let slice = &mut owned_container[..];
+    /// // Required as we can't borrow a mut slice more than once
+    /// let original_vec_ptr = slice.as_ptr();
+    ///
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVectorMutView64 = engine
+    ///     .create_ggsw_ciphertext_vector_from(
+    ///         slice,
+    ///         glwe_dimension,
+    ///         polynomial_size,
+    ///         level,
+    ///         base_log,
+    ///     )?;
+    /// let retrieved = engine.consume_retrieve_ggsw_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(original_vec_ptr, retrieved.as_ptr());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_ggsw_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: GgswCiphertextVectorMutView64<'data>,
+    ) -> Result<&'data mut [u64], GgswCiphertextVectorConsumingRetrievalError<Self::EngineError>>
+    {
+        Ok(unsafe { self.consume_retrieve_ggsw_ciphertext_vector_unchecked(ciphertext_vector) })
+    }
+
+    unsafe fn consume_retrieve_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        ciphertext_vector: GgswCiphertextVectorMutView64<'data>,
+    ) -> &'data mut [u64] {
+        ciphertext_vector.0.into_container()
+    }
+}
@@ -0,0 +1,542 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GgswCiphertextVector32, GgswCiphertextVector64, GgswCiphertextVectorMutView32,
+    GgswCiphertextVectorMutView64, GgswCiphertextVectorView32, GgswCiphertextVectorView64,
+};
+use crate::commons::crypto::ggsw::GgswList as ImplGgswList;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use crate::specification::engines::{
+    GgswCiphertextVectorCreationEngine, GgswCiphertextVectorCreationError,
+};
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorCreationEngine`] for [`DefaultEngine`] which returns
+/// an [`GgswCiphertextVector32`].
+impl GgswCiphertextVectorCreationEngine<Vec<u32>, GgswCiphertextVector32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ggsw_count = GgswCiphertextCount(3);
+    /// let glwe_size = glwe_dimension.to_glwe_size();
+    ///
+    /// // You have to make sure you size the container properly
+    /// let owned_container =
+    ///     vec![0_u32; level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 * ggsw_count.0];
+    ///
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVector32 = engine.create_ggsw_ciphertext_vector_from(
+    ///     owned_container,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.ggsw_ciphertext_count(), ggsw_count);
+    /// assert_eq!(ciphertext_vector.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_ggsw_ciphertext_vector_from(
+        &mut self,
+        container: Vec<u32>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<GgswCiphertextVector32, GgswCiphertextVectorCreationError<Self::EngineError>> {
+        GgswCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+            decomposition_level_count,
+        )?;
+        Ok(unsafe {
+            self.create_ggsw_ciphertext_vector_from_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn create_ggsw_ciphertext_vector_from_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswCiphertextVector32 {
+        GgswCiphertextVector32(ImplGgswList::from_container(
+            container,
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorCreationEngine`] for [`DefaultEngine`] which returns
+/// an immutable [`GgswCiphertextVectorView32`] that does not own its memory.
+impl<'data> GgswCiphertextVectorCreationEngine<&'data [u32], GgswCiphertextVectorView32<'data>>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ggsw_count = GgswCiphertextCount(3);
+    /// let glwe_size = glwe_dimension.to_glwe_size();
+    ///
+    /// // You have to make sure you size the container properly
+    /// let owned_container =
+    ///     vec![0_u32; level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 * ggsw_count.0];
+    ///
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVectorView32 = engine
+    ///     .create_ggsw_ciphertext_vector_from(
+    ///         &owned_container[..],
+    ///         glwe_dimension,
+    ///         polynomial_size,
+    ///         level,
+    ///         base_log,
+    ///     )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.ggsw_ciphertext_count(), ggsw_count);
+    /// assert_eq!(ciphertext_vector.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_ggsw_ciphertext_vector_from(
+        &mut self,
+        container: &'data [u32],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<
+        GgswCiphertextVectorView32<'data>,
+        GgswCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        GgswCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+            decomposition_level_count,
+        )?;
+        Ok(unsafe {
+            self.create_ggsw_ciphertext_vector_from_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn create_ggsw_ciphertext_vector_from_unchecked(
+        &mut self,
+        container: &'data [u32],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswCiphertextVectorView32<'data> {
+        GgswCiphertextVectorView32(ImplGgswList::from_container(
+            container,
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorCreationEngine`] for [`DefaultEngine`] which returns
+/// a mutable [`GgswCiphertextVectorMutView32`] that does not own its memory.
+impl<'data>
+    GgswCiphertextVectorCreationEngine<&'data mut [u32], GgswCiphertextVectorMutView32<'data>>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ggsw_count = GgswCiphertextCount(3);
+    /// let glwe_size = glwe_dimension.to_glwe_size();
+    ///
+    /// // You have to make sure you size the container properly
+    /// let mut owned_container =
+    ///     vec![0_u32; level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 * ggsw_count.0];
+    ///
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVectorMutView32 = engine
+    ///     .create_ggsw_ciphertext_vector_from(
+    ///         &mut owned_container[..],
+    ///         glwe_dimension,
+    ///         polynomial_size,
+    ///         level,
+    ///         base_log,
+    ///     )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.ggsw_ciphertext_count(), ggsw_count);
+    /// assert_eq!(ciphertext_vector.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_ggsw_ciphertext_vector_from(
+        &mut self,
+        container: &'data mut [u32],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<
+        GgswCiphertextVectorMutView32<'data>,
+        GgswCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        GgswCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+            decomposition_level_count,
+        )?;
+        Ok(unsafe {
+            self.create_ggsw_ciphertext_vector_from_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn create_ggsw_ciphertext_vector_from_unchecked(
+        &mut self,
+        container: &'data mut [u32],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswCiphertextVectorMutView32<'data> {
+        GgswCiphertextVectorMutView32(ImplGgswList::from_container(
+            container,
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorCreationEngine`] for [`DefaultEngine`] which returns
+/// an [`GgswCiphertextVector64`].
+impl GgswCiphertextVectorCreationEngine<Vec<u64>, GgswCiphertextVector64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ggsw_count = GgswCiphertextCount(3);
+    /// let glwe_size = glwe_dimension.to_glwe_size();
+    ///
+    /// // You have to make sure you size the container properly
+    /// let owned_container =
+    ///     vec![0_u64; level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 * ggsw_count.0];
+    ///
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVector64 = engine.create_ggsw_ciphertext_vector_from(
+    ///     owned_container,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.ggsw_ciphertext_count(), ggsw_count);
+    /// assert_eq!(ciphertext_vector.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_ggsw_ciphertext_vector_from(
+        &mut self,
+        container: Vec<u64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<GgswCiphertextVector64, GgswCiphertextVectorCreationError<Self::EngineError>> {
+        GgswCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+            decomposition_level_count,
+        )?;
+        Ok(unsafe {
+            self.create_ggsw_ciphertext_vector_from_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn create_ggsw_ciphertext_vector_from_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswCiphertextVector64 {
+        GgswCiphertextVector64(ImplGgswList::from_container(
+            container,
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorCreationEngine`] for [`DefaultEngine`] which returns
+/// an immutable [`GgswCiphertextVectorView64`] that does not own its memory.
+impl<'data> GgswCiphertextVectorCreationEngine<&'data [u64], GgswCiphertextVectorView64<'data>>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ggsw_count = GgswCiphertextCount(3);
+    /// let glwe_size = glwe_dimension.to_glwe_size();
+    ///
+    /// // You have to make sure you size the container properly
+    /// let owned_container =
+    ///     vec![0_u64; level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 * ggsw_count.0];
+    ///
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVectorView64 = engine
+    ///     .create_ggsw_ciphertext_vector_from(
+    ///         &owned_container[..],
+    ///         glwe_dimension,
+    ///         polynomial_size,
+    ///         level,
+    ///         base_log,
+    ///     )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.ggsw_ciphertext_count(), ggsw_count);
+    /// assert_eq!(ciphertext_vector.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_ggsw_ciphertext_vector_from(
+        &mut self,
+        container: &'data [u64],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<
+        GgswCiphertextVectorView64<'data>,
+        GgswCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        GgswCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+            decomposition_level_count,
+        )?;
+        Ok(unsafe {
+            self.create_ggsw_ciphertext_vector_from_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn create_ggsw_ciphertext_vector_from_unchecked(
+        &mut self,
+        container: &'data [u64],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswCiphertextVectorView64<'data> {
+        GgswCiphertextVectorView64(ImplGgswList::from_container(
+            container,
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorCreationEngine`] for [`DefaultEngine`] which returns
+/// a mutable [`GgswCiphertextVectorMutView64`] that does not own its memory.
+impl<'data>
+    GgswCiphertextVectorCreationEngine<&'data mut [u64], GgswCiphertextVectorMutView64<'data>>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ggsw_count = GgswCiphertextCount(3);
+    /// let glwe_size = glwe_dimension.to_glwe_size();
+    ///
+    /// // You have to make sure you size the container properly
+    /// let mut owned_container =
+    ///     vec![0_u64; level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 * ggsw_count.0];
+    ///
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVectorMutView64 = engine
+    ///     .create_ggsw_ciphertext_vector_from(
+    ///         &mut owned_container[..],
+    ///         glwe_dimension,
+    ///         polynomial_size,
+    ///         level,
+    ///         base_log,
+    ///     )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.ggsw_ciphertext_count(), ggsw_count);
+    /// assert_eq!(ciphertext_vector.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_ggsw_ciphertext_vector_from(
+        &mut self,
+        container: &'data mut [u64],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<
+        GgswCiphertextVectorMutView64<'data>,
+        GgswCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        GgswCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+            decomposition_level_count,
+        )?;
+        Ok(unsafe {
+            self.create_ggsw_ciphertext_vector_from_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn create_ggsw_ciphertext_vector_from_unchecked(
+        &mut self,
+        container: &'data mut [u64],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswCiphertextVectorMutView64<'data> {
+        GgswCiphertextVectorMutView64(ImplGgswList::from_container(
+            container,
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
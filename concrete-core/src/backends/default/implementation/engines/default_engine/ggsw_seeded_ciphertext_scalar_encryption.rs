@@ -0,0 +1,197 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    GgswSeededCiphertext32, GgswSeededCiphertext64, GlweSecretKey32, GlweSecretKey64, Plaintext32,
+    Plaintext64,
+};
+use crate::commons::crypto::ggsw::StandardGgswSeededCiphertext as ImplGgswSeededCiphertext;
+use crate::commons::math::random::{CompressionSeed, Seeder};
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, Variance};
+use crate::specification::engines::{
+    GgswSeededCiphertextScalarEncryptionEngine, GgswSeededCiphertextScalarEncryptionError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GgswSeededCiphertextScalarEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    GgswSeededCiphertextScalarEncryptionEngine<GlweSecretKey32, Plaintext32, GgswSeededCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext: GgswSeededCiphertext32 =
+    ///     engine.encrypt_scalar_ggsw_seeded_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_ggsw_seeded_ciphertext(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<GgswSeededCiphertext32, GgswSeededCiphertextScalarEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe {
+            self.encrypt_scalar_ggsw_seeded_ciphertext_unchecked(
+                key,
+                input,
+                noise,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_ggsw_seeded_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswSeededCiphertext32 {
+        let mut ciphertext = ImplGgswSeededCiphertext::allocate(
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            CompressionSeed {
+                seed: self.seeder.seed(),
+            },
+        );
+        key.0
+            .encrypt_constant_seeded_ggsw::<_, _, _, _, ActivatedRandomGenerator>(
+                &mut ciphertext,
+                &input.0,
+                noise,
+                &mut self.seeder,
+            );
+        GgswSeededCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswSeededCiphertextScalarEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    GgswSeededCiphertextScalarEncryptionEngine<GlweSecretKey64, Plaintext64, GgswSeededCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext: GgswSeededCiphertext64 =
+    ///     engine.encrypt_scalar_ggsw_seeded_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_ggsw_seeded_ciphertext(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<GgswSeededCiphertext64, GgswSeededCiphertextScalarEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe {
+            self.encrypt_scalar_ggsw_seeded_ciphertext_unchecked(
+                key,
+                input,
+                noise,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_ggsw_seeded_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswSeededCiphertext64 {
+        let mut ciphertext = ImplGgswSeededCiphertext::allocate(
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            CompressionSeed {
+                seed: self.seeder.seed(),
+            },
+        );
+        key.0
+            .encrypt_constant_seeded_ggsw::<_, _, _, _, ActivatedRandomGenerator>(
+                &mut ciphertext,
+                &input.0,
+                noise,
+                &mut self.seeder,
+            );
+        GgswSeededCiphertext64(ciphertext)
+    }
+}
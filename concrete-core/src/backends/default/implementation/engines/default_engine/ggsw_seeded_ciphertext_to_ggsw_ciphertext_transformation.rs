@@ -0,0 +1,177 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    GgswCiphertext32, GgswCiphertext64, GgswSeededCiphertext32, GgswSeededCiphertext64,
+};
+use crate::commons::crypto::ggsw::StandardGgswCiphertext as ImplGgswCiphertext;
+use crate::specification::engines::{
+    GgswSeededCiphertextToGgswCiphertextTransformationEngine,
+    GgswSeededCiphertextToGgswCiphertextTransformationError,
+};
+use crate::specification::entities::GgswSeededCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`GgswSeededCiphertextToGgswCiphertextTransformationEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    GgswSeededCiphertextToGgswCiphertextTransformationEngine<
+        GgswSeededCiphertext32,
+        GgswCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let seeded_ciphertext: GgswSeededCiphertext32 =
+    ///     engine.encrypt_scalar_ggsw_seeded_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let ciphertext =
+    ///     engine.transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext(seeded_ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext(
+        &mut self,
+        ggsw_seeded_ciphertext: GgswSeededCiphertext32,
+    ) -> Result<
+        GgswCiphertext32,
+        GgswSeededCiphertextToGgswCiphertextTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext_unchecked(
+                ggsw_seeded_ciphertext,
+            )
+        })
+    }
+
+    unsafe fn transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext_unchecked(
+        &mut self,
+        ggsw_seeded_ciphertext: GgswSeededCiphertext32,
+    ) -> GgswCiphertext32 {
+        let mut output = ImplGgswCiphertext::allocate(
+            0,
+            ggsw_seeded_ciphertext.polynomial_size(),
+            ggsw_seeded_ciphertext.glwe_dimension().to_glwe_size(),
+            ggsw_seeded_ciphertext.decomposition_level_count(),
+            ggsw_seeded_ciphertext.decomposition_base_log(),
+        );
+
+        ggsw_seeded_ciphertext
+            .0
+            .expand_into::<_, _, ActivatedRandomGenerator>(&mut output);
+
+        GgswCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswSeededCiphertextToGgswCiphertextTransformationEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    GgswSeededCiphertextToGgswCiphertextTransformationEngine<
+        GgswSeededCiphertext64,
+        GgswCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let seeded_ciphertext: GgswSeededCiphertext64 =
+    ///     engine.encrypt_scalar_ggsw_seeded_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let ciphertext =
+    ///     engine.transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext(seeded_ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext(
+        &mut self,
+        ggsw_seeded_ciphertext: GgswSeededCiphertext64,
+    ) -> Result<
+        GgswCiphertext64,
+        GgswSeededCiphertextToGgswCiphertextTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext_unchecked(
+                ggsw_seeded_ciphertext,
+            )
+        })
+    }
+
+    unsafe fn transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext_unchecked(
+        &mut self,
+        ggsw_seeded_ciphertext: GgswSeededCiphertext64,
+    ) -> GgswCiphertext64 {
+        let mut output = ImplGgswCiphertext::allocate(
+            0,
+            ggsw_seeded_ciphertext.polynomial_size(),
+            ggsw_seeded_ciphertext.glwe_dimension().to_glwe_size(),
+            ggsw_seeded_ciphertext.decomposition_level_count(),
+            ggsw_seeded_ciphertext.decomposition_base_log(),
+        );
+
+        ggsw_seeded_ciphertext
+            .0
+            .expand_into::<_, _, ActivatedRandomGenerator>(&mut output);
+
+        GgswCiphertext64(output)
+    }
+}
@@ -0,0 +1,193 @@
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, Variance};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweAutomorphismKey32, GlweAutomorphismKey64, GlweSecretKey32, GlweSecretKey64,
+};
+use crate::commons::crypto::glwe::GlweAutomorphismKey as ImplGlweAutomorphismKey;
+use crate::specification::engines::{
+    GlweAutomorphismKeyGenerationEngine, GlweAutomorphismKeyGenerationError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GlweAutomorphismKeyGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers. It outputs a GLWE automorphism key in the standard domain.
+impl GlweAutomorphismKeyGenerationEngine<GlweSecretKey32, GlweAutomorphismKey32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let automorphism_key: GlweAutomorphismKey32 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     automorphism_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     automorphism_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_key.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_automorphism_key(
+        &mut self,
+        key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweAutomorphismKey32, GlweAutomorphismKeyGenerationError<Self::EngineError>> {
+        GlweAutomorphismKeyGenerationError::perform_generic_checks(
+            key,
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_automorphism_key_unchecked(
+                key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_automorphism_key_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweAutomorphismKey32 {
+        let mut ak = ImplGlweAutomorphismKey::allocate(
+            0,
+            key.glwe_dimension(),
+            key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        ak.fill_with_automorphism_key(&key.0, noise, &mut self.encryption_generator);
+        GlweAutomorphismKey32(ak)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweAutomorphismKeyGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers. It outputs a GLWE automorphism key in the standard domain.
+impl GlweAutomorphismKeyGenerationEngine<GlweSecretKey64, GlweAutomorphismKey64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let decomposition_base_log = DecompositionBaseLog(10);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let automorphism_key: GlweAutomorphismKey64 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     automorphism_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     automorphism_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_key.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_automorphism_key(
+        &mut self,
+        key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweAutomorphismKey64, GlweAutomorphismKeyGenerationError<Self::EngineError>> {
+        GlweAutomorphismKeyGenerationError::perform_generic_checks(
+            key,
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_automorphism_key_unchecked(
+                key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_automorphism_key_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweAutomorphismKey64 {
+        let mut ak = ImplGlweAutomorphismKey::allocate(
+            0,
+            key.glwe_dimension(),
+            key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        ak.fill_with_automorphism_key(&key.0, noise, &mut self.encryption_generator);
+        GlweAutomorphismKey64(ak)
+    }
+}
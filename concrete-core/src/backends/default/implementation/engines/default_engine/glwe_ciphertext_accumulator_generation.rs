@@ -0,0 +1,203 @@
+use crate::prelude::{GlweDimension, MessageBitsCount, PaddingBitsCount, PolynomialSize};
+
+use crate::backends::default::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::commons::crypto::bootstrap::create_accumulator;
+use crate::specification::engines::{
+    GlweCiphertextAccumulatorGenerationEngine, GlweCiphertextAccumulatorGenerationError,
+};
+
+use crate::backends::default::engines::DefaultEngine;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextAccumulatorGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl GlweCiphertextAccumulatorGenerationEngine<GlweCiphertext32, u32> for DefaultEngine {
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(512);
+    /// let message_bits = MessageBitsCount(3);
+    /// let padding_bits = PaddingBitsCount(1);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// // DISCLAIMER: the accumulator is a trivial encryption, which DOES NOT hide the function.
+    /// let accumulator: GlweCiphertext32 = engine.generate_new_glwe_ciphertext_accumulator(
+    ///     &|m: u32| (m * m) % 8,
+    ///     message_bits,
+    ///     padding_bits,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    /// )?;
+    ///
+    /// assert_eq!(accumulator.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(accumulator.polynomial_size(), polynomial_size);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_ciphertext_accumulator(
+        &mut self,
+        f: &dyn Fn(u32) -> u32,
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweCiphertext32, GlweCiphertextAccumulatorGenerationError<Self::EngineError>> {
+        GlweCiphertextAccumulatorGenerationError::perform_generic_checks(
+            message_bits,
+            padding_bits,
+            polynomial_size,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_ciphertext_accumulator_unchecked(
+                f,
+                message_bits,
+                padding_bits,
+                glwe_dimension,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_ciphertext_accumulator_unchecked(
+        &mut self,
+        f: &dyn Fn(u32) -> u32,
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertext32 {
+        GlweCiphertext32(create_accumulator(
+            f,
+            message_bits,
+            padding_bits,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextAccumulatorGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl GlweCiphertextAccumulatorGenerationEngine<GlweCiphertext64, u64> for DefaultEngine {
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(512);
+    /// let message_bits = MessageBitsCount(3);
+    /// let padding_bits = PaddingBitsCount(1);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// // DISCLAIMER: the accumulator is a trivial encryption, which DOES NOT hide the function.
+    /// let accumulator: GlweCiphertext64 = engine.generate_new_glwe_ciphertext_accumulator(
+    ///     &|m: u64| (m * m) % 8,
+    ///     message_bits,
+    ///     padding_bits,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    /// )?;
+    ///
+    /// assert_eq!(accumulator.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(accumulator.polynomial_size(), polynomial_size);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_ciphertext_accumulator(
+        &mut self,
+        f: &dyn Fn(u64) -> u64,
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweCiphertext64, GlweCiphertextAccumulatorGenerationError<Self::EngineError>> {
+        GlweCiphertextAccumulatorGenerationError::perform_generic_checks(
+            message_bits,
+            padding_bits,
+            polynomial_size,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_ciphertext_accumulator_unchecked(
+                f,
+                message_bits,
+                padding_bits,
+                glwe_dimension,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_ciphertext_accumulator_unchecked(
+        &mut self,
+        f: &dyn Fn(u64) -> u64,
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertext64 {
+        GlweCiphertext64(create_accumulator(
+            f,
+            message_bits,
+            padding_bits,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod unit_test_accumulator_generation {
+    use crate::prelude::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_non_power_of_two_polynomial_size() -> Result<(), Box<dyn Error>> {
+        const UNSAFE_SECRET: u128 = 0;
+        let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+
+        // 768 is large enough to hold the 8 boxes of the lookup table, but is not a power of two.
+        let polynomial_size = PolynomialSize(768);
+        let result: Result<GlweCiphertext32, _> = engine.generate_new_glwe_ciphertext_accumulator(
+            &|m: u32| m,
+            MessageBitsCount(3),
+            PaddingBitsCount(1),
+            GlweDimension(1),
+            polynomial_size,
+        );
+        assert!(matches!(
+            result,
+            Err(GlweCiphertextAccumulatorGenerationError::PolynomialSizeNotPowerOfTwo)
+        ));
+        let result: Result<GlweCiphertext64, _> = engine.generate_new_glwe_ciphertext_accumulator(
+            &|m: u64| m,
+            MessageBitsCount(3),
+            PaddingBitsCount(1),
+            GlweDimension(1),
+            polynomial_size,
+        );
+        assert!(matches!(
+            result,
+            Err(GlweCiphertextAccumulatorGenerationError::PolynomialSizeNotPowerOfTwo)
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    CleartextVector32, CleartextVector64, GlweCiphertext32, GlweCiphertext64,
+};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    GlweCiphertextCleartextVectorFusingMultiplicationEngine,
+    GlweCiphertextCleartextVectorFusingMultiplicationError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCleartextVectorFusingMultiplicationEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl GlweCiphertextCleartextVectorFusingMultiplicationEngine<GlweCiphertext32, CleartextVector32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// // The cleartext polynomial 2 + X
+    /// let cleartext_input = vec![2_u32, 1, 0, 0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let cleartext_vector: CleartextVector32 =
+    ///     engine.create_cleartext_vector_from(&cleartext_input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.fuse_mul_glwe_ciphertext_cleartext_vector(&mut ciphertext, &cleartext_vector)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_glwe_ciphertext_cleartext_vector(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &CleartextVector32,
+    ) -> Result<(), GlweCiphertextCleartextVectorFusingMultiplicationError<Self::EngineError>> {
+        GlweCiphertextCleartextVectorFusingMultiplicationError::perform_generic_checks(
+            output, input,
+        )?;
+        unsafe { self.fuse_mul_glwe_ciphertext_cleartext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_glwe_ciphertext_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &CleartextVector32,
+    ) {
+        let cleartext_polynomial = Polynomial::from_container(input.0.as_tensor().as_slice());
+        // The product can not be computed in place, so it goes through a buffer.
+        let mut product = Polynomial::allocate(0u32, output.0.polynomial_size());
+        for mut output_polynomial in output.0.as_mut_polynomial_list().into_polynomial_iter() {
+            product.fill_with_wrapping_mul(&output_polynomial, &cleartext_polynomial);
+            output_polynomial
+                .as_mut_tensor()
+                .fill_with_copy(product.as_tensor());
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCleartextVectorFusingMultiplicationEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl GlweCiphertextCleartextVectorFusingMultiplicationEngine<GlweCiphertext64, CleartextVector64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u64 << 20; polynomial_size.0];
+    /// // The cleartext polynomial 2 + X
+    /// let cleartext_input = vec![2_u64, 1, 0, 0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let cleartext_vector: CleartextVector64 =
+    ///     engine.create_cleartext_vector_from(&cleartext_input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.fuse_mul_glwe_ciphertext_cleartext_vector(&mut ciphertext, &cleartext_vector)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_glwe_ciphertext_cleartext_vector(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &CleartextVector64,
+    ) -> Result<(), GlweCiphertextCleartextVectorFusingMultiplicationError<Self::EngineError>> {
+        GlweCiphertextCleartextVectorFusingMultiplicationError::perform_generic_checks(
+            output, input,
+        )?;
+        unsafe { self.fuse_mul_glwe_ciphertext_cleartext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_glwe_ciphertext_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &CleartextVector64,
+    ) {
+        let cleartext_polynomial = Polynomial::from_container(input.0.as_tensor().as_slice());
+        // The product can not be computed in place, so it goes through a buffer.
+        let mut product = Polynomial::allocate(0u64, output.0.polynomial_size());
+        for mut output_polynomial in output.0.as_mut_polynomial_list().into_polynomial_iter() {
+            product.fill_with_wrapping_mul(&output_polynomial, &cleartext_polynomial);
+            output_polynomial
+                .as_mut_tensor()
+                .fill_with_copy(product.as_tensor());
+        }
+    }
+}
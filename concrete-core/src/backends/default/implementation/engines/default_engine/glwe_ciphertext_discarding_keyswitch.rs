@@ -0,0 +1,155 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweKeyswitchKey32, GlweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingKeyswitchEngine, GlweCiphertextDiscardingKeyswitchError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingKeyswitchEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl GlweCiphertextDiscardingKeyswitchEngine<GlweKeyswitchKey32, GlweCiphertext32, GlweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(2);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    /// let keyswitch_key = engine.generate_new_glwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&input_key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_glwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        ksk: &GlweKeyswitchKey32,
+    ) -> Result<(), GlweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        GlweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_glwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        ksk: &GlweKeyswitchKey32,
+    ) {
+        ksk.0.keyswitch_ciphertext(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingKeyswitchEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl GlweCiphertextDiscardingKeyswitchEngine<GlweKeyswitchKey64, GlweCiphertext64, GlweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(2);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    /// let keyswitch_key = engine.generate_new_glwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&input_key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_glwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        ksk: &GlweKeyswitchKey64,
+    ) -> Result<(), GlweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        GlweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_glwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        ksk: &GlweKeyswitchKey64,
+    ) {
+        ksk.0.keyswitch_ciphertext(&mut output.0, &input.0);
+    }
+}
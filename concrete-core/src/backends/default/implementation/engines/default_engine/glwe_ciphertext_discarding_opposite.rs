@@ -0,0 +1,122 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingOppositeEngine, GlweCiphertextDiscardingOppositeError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingOppositeEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl GlweCiphertextDiscardingOppositeEngine<GlweCiphertext32, GlweCiphertext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_opp_glwe_ciphertext(&mut ciphertext_2, &ciphertext_1)?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_opp_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+    ) -> Result<(), GlweCiphertextDiscardingOppositeError<Self::EngineError>> {
+        GlweCiphertextDiscardingOppositeError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_opp_glwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_opp_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+    ) {
+        output.0.as_mut_tensor().fill_with_copy(input.0.as_tensor());
+        for mut output_polynomial in output.0.as_mut_polynomial_list().into_polynomial_iter() {
+            output_polynomial.update_with_wrapping_neg();
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingOppositeEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl GlweCiphertextDiscardingOppositeEngine<GlweCiphertext64, GlweCiphertext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u64 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_opp_glwe_ciphertext(&mut ciphertext_2, &ciphertext_1)?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_opp_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+    ) -> Result<(), GlweCiphertextDiscardingOppositeError<Self::EngineError>> {
+        GlweCiphertextDiscardingOppositeError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_opp_glwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_opp_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+    ) {
+        output.0.as_mut_tensor().fill_with_copy(input.0.as_tensor());
+        for mut output_polynomial in output.0.as_mut_polynomial_list().into_polynomial_iter() {
+            output_polynomial.update_with_wrapping_neg();
+        }
+    }
+}
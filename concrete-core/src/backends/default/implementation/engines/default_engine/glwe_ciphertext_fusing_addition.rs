@@ -0,0 +1,133 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::specification::engines::{
+    GlweCiphertextFusingAdditionEngine, GlweCiphertextFusingAdditionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextFusingAdditionEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl GlweCiphertextFusingAdditionEngine<GlweCiphertext32, GlweCiphertext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![7_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let mut ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// engine.fuse_add_glwe_ciphertext(&mut ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_1.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_1.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+    ) -> Result<(), GlweCiphertextFusingAdditionError<Self::EngineError>> {
+        GlweCiphertextFusingAdditionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_add_glwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+    ) {
+        for (mut output_polynomial, input_polynomial) in output
+            .0
+            .as_mut_polynomial_list()
+            .into_polynomial_iter()
+            .zip(input.0.as_polynomial_list().into_polynomial_iter())
+        {
+            output_polynomial.update_with_wrapping_add(&input_polynomial);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextFusingAdditionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl GlweCiphertextFusingAdditionEngine<GlweCiphertext64, GlweCiphertext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u64 << 20; polynomial_size.0];
+    /// let input_2 = vec![7_u64 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let mut ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// engine.fuse_add_glwe_ciphertext(&mut ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_1.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_1.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+    ) -> Result<(), GlweCiphertextFusingAdditionError<Self::EngineError>> {
+        GlweCiphertextFusingAdditionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_add_glwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+    ) {
+        for (mut output_polynomial, input_polynomial) in output
+            .0
+            .as_mut_polynomial_list()
+            .into_polynomial_iter()
+            .zip(input.0.as_polynomial_list().into_polynomial_iter())
+        {
+            output_polynomial.update_with_wrapping_add(&input_polynomial);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::specification::engines::{
+    GlweCiphertextFusingOppositeEngine, GlweCiphertextFusingOppositeError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextFusingOppositeEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl GlweCiphertextFusingOppositeEngine<GlweCiphertext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.fuse_opp_glwe_ciphertext(&mut ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_opp_glwe_ciphertext(
+        &mut self,
+        input: &mut GlweCiphertext32,
+    ) -> Result<(), GlweCiphertextFusingOppositeError<Self::EngineError>> {
+        unsafe { self.fuse_opp_glwe_ciphertext_unchecked(input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_opp_glwe_ciphertext_unchecked(&mut self, input: &mut GlweCiphertext32) {
+        for mut polynomial in input.0.as_mut_polynomial_list().into_polynomial_iter() {
+            polynomial.update_with_wrapping_neg();
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextFusingOppositeEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl GlweCiphertextFusingOppositeEngine<GlweCiphertext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u64 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.fuse_opp_glwe_ciphertext(&mut ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_opp_glwe_ciphertext(
+        &mut self,
+        input: &mut GlweCiphertext64,
+    ) -> Result<(), GlweCiphertextFusingOppositeError<Self::EngineError>> {
+        unsafe { self.fuse_opp_glwe_ciphertext_unchecked(input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_opp_glwe_ciphertext_unchecked(&mut self, input: &mut GlweCiphertext64) {
+        for mut polynomial in input.0.as_mut_polynomial_list().into_polynomial_iter() {
+            polynomial.update_with_wrapping_neg();
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use crate::prelude::{GlweDimension, MessageBitsCount, PaddingBitsCount, PolynomialSize};
+
+use crate::backends::default::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::commons::crypto::bootstrap::create_multi_function_accumulator;
+use crate::specification::engines::{
+    GlweCiphertextMultiFunctionAccumulatorGenerationEngine,
+    GlweCiphertextMultiFunctionAccumulatorGenerationError,
+};
+
+use crate::backends::default::engines::DefaultEngine;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextMultiFunctionAccumulatorGenerationEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl GlweCiphertextMultiFunctionAccumulatorGenerationEngine<GlweCiphertext32, u32>
+    for DefaultEngine
+{
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(512);
+    /// let message_bits = MessageBitsCount(3);
+    /// let padding_bits = PaddingBitsCount(1);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// // DISCLAIMER: the accumulator is a trivial encryption, which DOES NOT hide the function.
+    /// // The j-th function adds j to the message, the four lookup tables being evaluated by a
+    /// // multi-value bootstrap with a `LutCountLog(2)`.
+    /// let accumulator: GlweCiphertext32 = engine
+    ///     .generate_new_glwe_ciphertext_multi_function_accumulator(
+    ///         &[
+    ///             &|m: u32| m,
+    ///             &|m: u32| (m + 1) % 8,
+    ///             &|m: u32| (m + 2) % 8,
+    ///             &|m: u32| (m + 3) % 8,
+    ///         ],
+    ///         message_bits,
+    ///         padding_bits,
+    ///         glwe_dimension,
+    ///         polynomial_size,
+    ///     )?;
+    ///
+    /// assert_eq!(accumulator.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(accumulator.polynomial_size(), polynomial_size);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_ciphertext_multi_function_accumulator(
+        &mut self,
+        functions: &[&dyn Fn(u32) -> u32],
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<
+        GlweCiphertext32,
+        GlweCiphertextMultiFunctionAccumulatorGenerationError<Self::EngineError>,
+    > {
+        GlweCiphertextMultiFunctionAccumulatorGenerationError::perform_generic_checks(
+            functions.len(),
+            message_bits,
+            padding_bits,
+            polynomial_size,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_ciphertext_multi_function_accumulator_unchecked(
+                functions,
+                message_bits,
+                padding_bits,
+                glwe_dimension,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_ciphertext_multi_function_accumulator_unchecked(
+        &mut self,
+        functions: &[&dyn Fn(u32) -> u32],
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertext32 {
+        GlweCiphertext32(create_multi_function_accumulator(
+            functions,
+            message_bits,
+            padding_bits,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextMultiFunctionAccumulatorGenerationEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl GlweCiphertextMultiFunctionAccumulatorGenerationEngine<GlweCiphertext64, u64>
+    for DefaultEngine
+{
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(512);
+    /// let message_bits = MessageBitsCount(3);
+    /// let padding_bits = PaddingBitsCount(1);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// // DISCLAIMER: the accumulator is a trivial encryption, which DOES NOT hide the function.
+    /// // The j-th function adds j to the message, the four lookup tables being evaluated by a
+    /// // multi-value bootstrap with a `LutCountLog(2)`.
+    /// let accumulator: GlweCiphertext64 = engine
+    ///     .generate_new_glwe_ciphertext_multi_function_accumulator(
+    ///         &[
+    ///             &|m: u64| m,
+    ///             &|m: u64| (m + 1) % 8,
+    ///             &|m: u64| (m + 2) % 8,
+    ///             &|m: u64| (m + 3) % 8,
+    ///         ],
+    ///         message_bits,
+    ///         padding_bits,
+    ///         glwe_dimension,
+    ///         polynomial_size,
+    ///     )?;
+    ///
+    /// assert_eq!(accumulator.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(accumulator.polynomial_size(), polynomial_size);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_ciphertext_multi_function_accumulator(
+        &mut self,
+        functions: &[&dyn Fn(u64) -> u64],
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<
+        GlweCiphertext64,
+        GlweCiphertextMultiFunctionAccumulatorGenerationError<Self::EngineError>,
+    > {
+        GlweCiphertextMultiFunctionAccumulatorGenerationError::perform_generic_checks(
+            functions.len(),
+            message_bits,
+            padding_bits,
+            polynomial_size,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_ciphertext_multi_function_accumulator_unchecked(
+                functions,
+                message_bits,
+                padding_bits,
+                glwe_dimension,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_ciphertext_multi_function_accumulator_unchecked(
+        &mut self,
+        functions: &[&dyn Fn(u64) -> u64],
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertext64 {
+        GlweCiphertext64(create_multi_function_accumulator(
+            functions,
+            message_bits,
+            padding_bits,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod unit_test_multi_function_accumulator_generation {
+    use crate::prelude::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_lookup_tables_are_interleaved() -> Result<(), Box<dyn Error>> {
+        const UNSAFE_SECRET: u128 = 0;
+        let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let message_bits = MessageBitsCount(3);
+        let padding_bits = PaddingBitsCount(1);
+        let glwe_dimension = GlweDimension(1);
+        let polynomial_size = PolynomialSize(512);
+        let functions: [&dyn Fn(u64) -> u64; 4] =
+            [&|m| m, &|m| (m * m) % 8, &|m| (m + 5) % 8, &|m| (7 - m) % 8];
+
+        let accumulator: GlweCiphertext64 = engine
+            .generate_new_glwe_ciphertext_multi_function_accumulator(
+                &functions,
+                message_bits,
+                padding_bits,
+                glwe_dimension,
+                polynomial_size,
+            )?;
+        let plaintext_vector: PlaintextVector64 =
+            engine.trivially_decrypt_glwe_ciphertext(&accumulator)?;
+        let lookup_tables: Vec<u64> = engine.retrieve_plaintext_vector(&plaintext_vector)?;
+
+        // The coefficients of index j modulo 4 must be the ones of the accumulator of the j-th
+        // function alone.
+        for (j, function) in functions.iter().enumerate() {
+            let accumulator: GlweCiphertext64 = engine.generate_new_glwe_ciphertext_accumulator(
+                *function,
+                message_bits,
+                padding_bits,
+                glwe_dimension,
+                polynomial_size,
+            )?;
+            let plaintext_vector: PlaintextVector64 =
+                engine.trivially_decrypt_glwe_ciphertext(&accumulator)?;
+            let lookup_table: Vec<u64> = engine.retrieve_plaintext_vector(&plaintext_vector)?;
+            for index in (j..polynomial_size.0).step_by(functions.len()) {
+                assert_eq!(lookup_tables[index], lookup_table[index]);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_boxes_too_small_for_the_functions() -> Result<(), Box<dyn Error>> {
+        const UNSAFE_SECRET: u128 = 0;
+        let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+
+        // The 8 boxes of 4 coefficients can hold a single lookup table, but not 4 interleaved
+        // ones, since half a box is not a multiple of 4.
+        let result: Result<GlweCiphertext32, _> = engine
+            .generate_new_glwe_ciphertext_multi_function_accumulator(
+                &[&|m: u32| m, &|m: u32| m, &|m: u32| m, &|m: u32| m],
+                MessageBitsCount(3),
+                PaddingBitsCount(1),
+                GlweDimension(1),
+                PolynomialSize(32),
+            );
+        assert!(matches!(
+            result,
+            Err(GlweCiphertextMultiFunctionAccumulatorGenerationError::PolynomialSizeTooSmall)
+        ));
+        let result: Result<GlweCiphertext64, _> = engine
+            .generate_new_glwe_ciphertext_multi_function_accumulator(
+                &[&|m: u64| m, &|m: u64| m, &|m: u64| m],
+                MessageBitsCount(3),
+                PaddingBitsCount(1),
+                GlweDimension(1),
+                PolynomialSize(512),
+            );
+        assert!(matches!(
+            result,
+            Err(GlweCiphertextMultiFunctionAccumulatorGenerationError::FunctionCountNotPowerOfTwo)
+        ));
+        Ok(())
+    }
+}
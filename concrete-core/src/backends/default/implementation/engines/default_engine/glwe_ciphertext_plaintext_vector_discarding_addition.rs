@@ -0,0 +1,176 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, PlaintextVector32, PlaintextVector64,
+};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    GlweCiphertextPlaintextVectorDiscardingAdditionEngine,
+    GlweCiphertextPlaintextVectorDiscardingAdditionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextPlaintextVectorDiscardingAdditionEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    GlweCiphertextPlaintextVectorDiscardingAdditionEngine<
+        GlweCiphertext32,
+        PlaintextVector32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![7_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_add_glwe_ciphertext_plaintext_vector(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &plaintext_vector_2,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_glwe_ciphertext_plaintext_vector(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &PlaintextVector32,
+    ) -> Result<(), GlweCiphertextPlaintextVectorDiscardingAdditionError<Self::EngineError>> {
+        GlweCiphertextPlaintextVectorDiscardingAdditionError::perform_generic_checks(
+            output, input_1, input_2,
+        )?;
+        unsafe {
+            self.discard_add_glwe_ciphertext_plaintext_vector_unchecked(output, input_1, input_2)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_add_glwe_ciphertext_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &PlaintextVector32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        output
+            .0
+            .get_mut_body()
+            .into_polynomial()
+            .update_with_wrapping_add(&Polynomial::from_container(
+                input_2.0.as_tensor().as_slice(),
+            ));
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextPlaintextVectorDiscardingAdditionEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    GlweCiphertextPlaintextVectorDiscardingAdditionEngine<
+        GlweCiphertext64,
+        PlaintextVector64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u64 << 20; polynomial_size.0];
+    /// let input_2 = vec![7_u64 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_add_glwe_ciphertext_plaintext_vector(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &plaintext_vector_2,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_glwe_ciphertext_plaintext_vector(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &PlaintextVector64,
+    ) -> Result<(), GlweCiphertextPlaintextVectorDiscardingAdditionError<Self::EngineError>> {
+        GlweCiphertextPlaintextVectorDiscardingAdditionError::perform_generic_checks(
+            output, input_1, input_2,
+        )?;
+        unsafe {
+            self.discard_add_glwe_ciphertext_plaintext_vector_unchecked(output, input_1, input_2)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_add_glwe_ciphertext_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &PlaintextVector64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        output
+            .0
+            .get_mut_body()
+            .into_polynomial()
+            .update_with_wrapping_add(&Polynomial::from_container(
+                input_2.0.as_tensor().as_slice(),
+            ));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, PlaintextVector32, PlaintextVector64,
+};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    GlweCiphertextPlaintextVectorFusingAdditionEngine,
+    GlweCiphertextPlaintextVectorFusingAdditionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextPlaintextVectorFusingAdditionEngine`] for [`DefaultEngine`]
+/// that operates on 32 bits integers.
+impl GlweCiphertextPlaintextVectorFusingAdditionEngine<GlweCiphertext32, PlaintextVector32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![7_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    ///
+    /// engine.fuse_add_glwe_ciphertext_plaintext_vector(&mut ciphertext, &plaintext_vector_2)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_glwe_ciphertext_plaintext_vector(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &PlaintextVector32,
+    ) -> Result<(), GlweCiphertextPlaintextVectorFusingAdditionError<Self::EngineError>> {
+        GlweCiphertextPlaintextVectorFusingAdditionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_add_glwe_ciphertext_plaintext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_glwe_ciphertext_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &PlaintextVector32,
+    ) {
+        output
+            .0
+            .get_mut_body()
+            .into_polynomial()
+            .update_with_wrapping_add(&Polynomial::from_container(input.0.as_tensor().as_slice()));
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextPlaintextVectorFusingAdditionEngine`] for [`DefaultEngine`]
+/// that operates on 64 bits integers.
+impl GlweCiphertextPlaintextVectorFusingAdditionEngine<GlweCiphertext64, PlaintextVector64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u64 << 20; polynomial_size.0];
+    /// let input_2 = vec![7_u64 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    ///
+    /// engine.fuse_add_glwe_ciphertext_plaintext_vector(&mut ciphertext, &plaintext_vector_2)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_glwe_ciphertext_plaintext_vector(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &PlaintextVector64,
+    ) -> Result<(), GlweCiphertextPlaintextVectorFusingAdditionError<Self::EngineError>> {
+        GlweCiphertextPlaintextVectorFusingAdditionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_add_glwe_ciphertext_plaintext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_glwe_ciphertext_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &PlaintextVector64,
+    ) {
+        output
+            .0
+            .get_mut_body()
+            .into_polynomial()
+            .update_with_wrapping_add(&Polynomial::from_container(input.0.as_tensor().as_slice()));
+    }
+}
@@ -0,0 +1,171 @@
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, Variance};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweRelinearizationKey32, GlweRelinearizationKey64, GlweSecretKey32, GlweSecretKey64,
+};
+use crate::commons::crypto::glwe::GlweRelinearizationKey as ImplGlweRelinearizationKey;
+use crate::prelude::GlweSecretKeyEntity;
+use crate::specification::engines::{
+    GlweRelinearizationKeyGenerationEngine, GlweRelinearizationKeyGenerationError,
+};
+
+/// # Description:
+/// Implementation of [`GlweRelinearizationKeyGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers. It outputs a relinearization key in the standard domain.
+impl GlweRelinearizationKeyGenerationEngine<GlweSecretKey32, GlweRelinearizationKey32>
+    for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dim, poly_size) = (GlweDimension(2), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let rlk: GlweRelinearizationKey32 =
+    ///     engine.generate_new_glwe_relinearization_key(&glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(rlk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(rlk.polynomial_size(), poly_size);
+    /// assert_eq!(rlk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(rlk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &GlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<GlweRelinearizationKey32, GlweRelinearizationKeyGenerationError<Self::EngineError>>
+    {
+        GlweRelinearizationKeyGenerationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_relinearization_key_unchecked(
+                secret_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_relinearization_key_unchecked(
+        &mut self,
+        secret_key: &GlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> GlweRelinearizationKey32 {
+        let mut key = ImplGlweRelinearizationKey::allocate(
+            0,
+            secret_key.glwe_dimension(),
+            secret_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        key.fill_with_new_key(&secret_key.0, noise, &mut self.encryption_generator);
+        GlweRelinearizationKey32(key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweRelinearizationKeyGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers. It outputs a relinearization key in the standard domain.
+impl GlweRelinearizationKeyGenerationEngine<GlweSecretKey64, GlweRelinearizationKey64>
+    for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dim, poly_size) = (GlweDimension(2), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let rlk: GlweRelinearizationKey64 =
+    ///     engine.generate_new_glwe_relinearization_key(&glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(rlk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(rlk.polynomial_size(), poly_size);
+    /// assert_eq!(rlk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(rlk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<GlweRelinearizationKey64, GlweRelinearizationKeyGenerationError<Self::EngineError>>
+    {
+        GlweRelinearizationKeyGenerationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_relinearization_key_unchecked(
+                secret_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_relinearization_key_unchecked(
+        &mut self,
+        secret_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> GlweRelinearizationKey64 {
+        let mut key = ImplGlweRelinearizationKey::allocate(
+            0,
+            secret_key.glwe_dimension(),
+            secret_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        key.fill_with_new_key(&secret_key.0, noise, &mut self.encryption_generator);
+        GlweRelinearizationKey64(key)
+    }
+}
@@ -1,7 +1,10 @@
 use crate::prelude::{GlweDimension, PolynomialSize};
 
 use crate::backends::default::implementation::engines::DefaultEngine;
-use crate::backends::default::implementation::entities::{GlweSecretKey32, GlweSecretKey64};
+use crate::backends::default::implementation::entities::{
+    GlweGaussianSecretKey32, GlweGaussianSecretKey64, GlweSecretKey32, GlweSecretKey64,
+    GlweTernarySecretKey32, GlweTernarySecretKey64,
+};
 use crate::commons::crypto::secret::GlweSecretKey as ImplGlweSecretKey;
 use crate::specification::engines::{GlweSecretKeyGenerationEngine, GlweSecretKeyGenerationError};
 
@@ -104,3 +107,203 @@ impl GlweSecretKeyGenerationEngine<GlweSecretKey64> for DefaultEngine {
         ))
     }
 }
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyGenerationEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers and ternary secret keys.
+impl GlweSecretKeyGenerationEngine<GlweTernarySecretKey32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweTernarySecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweTernarySecretKey32, GlweSecretKeyGenerationError<Self::EngineError>> {
+        GlweSecretKeyGenerationError::perform_generic_checks(glwe_dimension, polynomial_size)?;
+        Ok(unsafe { self.generate_new_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn generate_new_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweTernarySecretKey32 {
+        GlweTernarySecretKey32(ImplGlweSecretKey::generate_ternary(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyGenerationEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers and ternary secret keys.
+impl GlweSecretKeyGenerationEngine<GlweTernarySecretKey64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweTernarySecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweTernarySecretKey64, GlweSecretKeyGenerationError<Self::EngineError>> {
+        GlweSecretKeyGenerationError::perform_generic_checks(glwe_dimension, polynomial_size)?;
+        Ok(unsafe { self.generate_new_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn generate_new_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweTernarySecretKey64 {
+        GlweTernarySecretKey64(ImplGlweSecretKey::generate_ternary(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyGenerationEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers and gaussian secret keys.
+impl GlweSecretKeyGenerationEngine<GlweGaussianSecretKey32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweGaussianSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweGaussianSecretKey32, GlweSecretKeyGenerationError<Self::EngineError>> {
+        GlweSecretKeyGenerationError::perform_generic_checks(glwe_dimension, polynomial_size)?;
+        Ok(unsafe { self.generate_new_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn generate_new_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweGaussianSecretKey32 {
+        GlweGaussianSecretKey32(ImplGlweSecretKey::generate_gaussian(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyGenerationEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers and gaussian secret keys.
+impl GlweSecretKeyGenerationEngine<GlweGaussianSecretKey64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweGaussianSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweGaussianSecretKey64, GlweSecretKeyGenerationError<Self::EngineError>> {
+        GlweSecretKeyGenerationError::perform_generic_checks(glwe_dimension, polynomial_size)?;
+        Ok(unsafe { self.generate_new_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn generate_new_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweGaussianSecretKey64 {
+        GlweGaussianSecretKey64(ImplGlweSecretKey::generate_gaussian(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
@@ -0,0 +1,177 @@
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, Variance};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GswCiphertext32, GswCiphertext64, LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64,
+};
+use crate::commons::crypto::gsw::GswCiphertext as ImplGswCiphertext;
+use crate::specification::engines::{
+    GswCiphertextScalarEncryptionEngine, GswCiphertextScalarEncryptionError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GswCiphertextScalarEncryptionEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl GswCiphertextScalarEncryptionEngine<LweSecretKey32, Plaintext32, GswCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_gsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_gsw_ciphertext(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<GswCiphertext32, GswCiphertextScalarEncryptionError<Self::EngineError>> {
+        Ok(unsafe {
+            self.encrypt_scalar_gsw_ciphertext_unchecked(
+                key,
+                input,
+                noise,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_gsw_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GswCiphertext32 {
+        let mut ciphertext = ImplGswCiphertext::allocate(
+            0u32,
+            key.lwe_dimension().to_lwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        key.0.encrypt_constant_gsw(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GswCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GswCiphertextScalarEncryptionEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl GswCiphertextScalarEncryptionEngine<LweSecretKey64, Plaintext64, GswCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_gsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_gsw_ciphertext(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<GswCiphertext64, GswCiphertextScalarEncryptionError<Self::EngineError>> {
+        Ok(unsafe {
+            self.encrypt_scalar_gsw_ciphertext_unchecked(
+                key,
+                input,
+                noise,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_gsw_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GswCiphertext64 {
+        let mut ciphertext = ImplGswCiphertext::allocate(
+            0u64,
+            key.lwe_dimension().to_lwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        key.0.encrypt_constant_gsw(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GswCiphertext64(ciphertext)
+    }
+}
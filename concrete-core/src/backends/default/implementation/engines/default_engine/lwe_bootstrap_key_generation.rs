@@ -1,9 +1,11 @@
-use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, Variance};
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance};
 
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    GlweSecretKey32, GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweSecretKey32,
-    LweSecretKey64,
+    GlweGaussianSecretKey32, GlweGaussianSecretKey64, GlweSecretKey32, GlweSecretKey64,
+    GlweTernarySecretKey32, GlweTernarySecretKey64, LweBootstrapKey32, LweBootstrapKey64,
+    LweSecretKey32, LweSecretKey64, LweTernaryBootstrapKey32, LweTernaryBootstrapKey64,
+    LweTernarySecretKey32, LweTernarySecretKey64,
 };
 use crate::commons::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
 use crate::prelude::{GlweSecretKeyEntity, LweSecretKeyEntity};
@@ -190,3 +192,561 @@ impl LweBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKey64, LweBootstr
         LweBootstrapKey64(key)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyGenerationEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers, with a binary input secret key and a ternary output secret key. It outputs a
+/// bootstrap key in the standard domain.
+impl LweBootstrapKeyGenerationEngine<LweSecretKey32, GlweTernarySecretKey32, LweBootstrapKey32>
+    for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweTernarySecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &GlweTernarySecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweBootstrapKey32, LweBootstrapKeyGenerationError<Self::EngineError>> {
+        LweBootstrapKeyGenerationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &GlweTernarySecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweBootstrapKey32 {
+        let mut key = ImplStandardBootstrapKey::allocate(
+            0,
+            output_key.glwe_dimension().to_glwe_size(),
+            output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+        );
+        key.fill_with_new_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweBootstrapKey32(key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyGenerationEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers, with a binary input secret key and a ternary output secret key. It outputs a
+/// bootstrap key in the standard domain.
+impl LweBootstrapKeyGenerationEngine<LweSecretKey64, GlweTernarySecretKey64, LweBootstrapKey64>
+    for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweTernarySecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweTernarySecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweBootstrapKey64, LweBootstrapKeyGenerationError<Self::EngineError>> {
+        LweBootstrapKeyGenerationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweTernarySecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweBootstrapKey64 {
+        let mut key = ImplStandardBootstrapKey::allocate(
+            0,
+            output_key.glwe_dimension().to_glwe_size(),
+            output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+        );
+        key.fill_with_new_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweBootstrapKey64(key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyGenerationEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers, with a binary input secret key and a gaussian output secret key. It outputs a
+/// bootstrap key in the standard domain.
+impl LweBootstrapKeyGenerationEngine<LweSecretKey32, GlweGaussianSecretKey32, LweBootstrapKey32>
+    for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweGaussianSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &GlweGaussianSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweBootstrapKey32, LweBootstrapKeyGenerationError<Self::EngineError>> {
+        LweBootstrapKeyGenerationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &GlweGaussianSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweBootstrapKey32 {
+        let mut key = ImplStandardBootstrapKey::allocate(
+            0,
+            output_key.glwe_dimension().to_glwe_size(),
+            output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+        );
+        key.fill_with_new_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweBootstrapKey32(key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyGenerationEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers, with a binary input secret key and a gaussian output secret key. It outputs a
+/// bootstrap key in the standard domain.
+impl LweBootstrapKeyGenerationEngine<LweSecretKey64, GlweGaussianSecretKey64, LweBootstrapKey64>
+    for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweGaussianSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweGaussianSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweBootstrapKey64, LweBootstrapKeyGenerationError<Self::EngineError>> {
+        LweBootstrapKeyGenerationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweGaussianSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweBootstrapKey64 {
+        let mut key = ImplStandardBootstrapKey::allocate(
+            0,
+            output_key.glwe_dimension().to_glwe_size(),
+            output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+        );
+        key.fill_with_new_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweBootstrapKey64(key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyGenerationEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers, with a ternary input secret key. It outputs a bootstrap key in the standard
+/// domain, which encrypts each coefficient of the input key in two GGSW ciphertexts.
+impl
+    LweBootstrapKeyGenerationEngine<
+        LweTernarySecretKey32,
+        GlweSecretKey32,
+        LweTernaryBootstrapKey32,
+    > for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweTernarySecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweTernarySecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweTernaryBootstrapKey32, LweBootstrapKeyGenerationError<Self::EngineError>> {
+        LweBootstrapKeyGenerationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweTernarySecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweTernaryBootstrapKey32 {
+        let mut key = ImplStandardBootstrapKey::allocate(
+            0,
+            output_key.glwe_dimension().to_glwe_size(),
+            output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            LweDimension(2 * input_key.lwe_dimension().0),
+        );
+        key.fill_with_new_ternary_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweTernaryBootstrapKey32(key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyGenerationEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers, with a ternary input secret key. It outputs a bootstrap key in the standard
+/// domain, which encrypts each coefficient of the input key in two GGSW ciphertexts.
+impl
+    LweBootstrapKeyGenerationEngine<
+        LweTernarySecretKey64,
+        GlweSecretKey64,
+        LweTernaryBootstrapKey64,
+    > for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweTernarySecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweTernarySecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweTernaryBootstrapKey64, LweBootstrapKeyGenerationError<Self::EngineError>> {
+        LweBootstrapKeyGenerationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweTernarySecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweTernaryBootstrapKey64 {
+        let mut key = ImplStandardBootstrapKey::allocate(
+            0,
+            output_key.glwe_dimension().to_glwe_size(),
+            output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            LweDimension(2 * input_key.lwe_dimension().0),
+        );
+        key.fill_with_new_ternary_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweTernaryBootstrapKey64(key)
+    }
+}
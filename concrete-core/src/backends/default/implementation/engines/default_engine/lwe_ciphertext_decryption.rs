@@ -1,7 +1,8 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweCiphertextView32, LweCiphertextView64, LweSecretKey32,
-    LweSecretKey64, Plaintext32, Plaintext64,
+    LweCiphertext32, LweCiphertext64, LweCiphertextView32, LweCiphertextView64,
+    LweGaussianSecretKey32, LweGaussianSecretKey64, LweSecretKey32, LweSecretKey64,
+    LweTernarySecretKey32, LweTernarySecretKey64, Plaintext32, Plaintext64,
 };
 use crate::commons::crypto::encoding::Plaintext as ImplPlaintext;
 use crate::specification::engines::{LweCiphertextDecryptionEngine, LweCiphertextDecryptionError};
@@ -223,3 +224,207 @@ impl LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertextView64<'_>, Plai
         Plaintext64(plaintext)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers and ternary secret keys.
+impl LweCiphertextDecryptionEngine<LweTernarySecretKey32, LweCiphertext32, Plaintext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweTernarySecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweTernarySecretKey32,
+        input: &LweCiphertext32,
+    ) -> Result<Plaintext32, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweTernarySecretKey32,
+        input: &LweCiphertext32,
+    ) -> Plaintext32 {
+        let mut plaintext = ImplPlaintext(0u32);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext32(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers and ternary secret keys.
+impl LweCiphertextDecryptionEngine<LweTernarySecretKey64, LweCiphertext64, Plaintext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweTernarySecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweTernarySecretKey64,
+        input: &LweCiphertext64,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweTernarySecretKey64,
+        input: &LweCiphertext64,
+    ) -> Plaintext64 {
+        let mut plaintext = ImplPlaintext(0u64);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext64(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers and gaussian secret keys.
+impl LweCiphertextDecryptionEngine<LweGaussianSecretKey32, LweCiphertext32, Plaintext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweGaussianSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweGaussianSecretKey32,
+        input: &LweCiphertext32,
+    ) -> Result<Plaintext32, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweGaussianSecretKey32,
+        input: &LweCiphertext32,
+    ) -> Plaintext32 {
+        let mut plaintext = ImplPlaintext(0u32);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext32(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers and gaussian secret keys.
+impl LweCiphertextDecryptionEngine<LweGaussianSecretKey64, LweCiphertext64, Plaintext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweGaussianSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweGaussianSecretKey64,
+        input: &LweCiphertext64,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweGaussianSecretKey64,
+        input: &LweCiphertext64,
+    ) -> Plaintext64 {
+        let mut plaintext = ImplPlaintext(0u64);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext64(plaintext)
+    }
+}
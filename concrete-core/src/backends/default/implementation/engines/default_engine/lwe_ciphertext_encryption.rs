@@ -2,7 +2,9 @@ use crate::prelude::Variance;
 
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64,
+    LweCiphertext32, LweCiphertext64, LweGaussianSecretKey32, LweGaussianSecretKey64,
+    LweSecretKey32, LweSecretKey64, LweTernarySecretKey32, LweTernarySecretKey64, Plaintext32,
+    Plaintext64,
 };
 use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
 use crate::specification::engines::{LweCiphertextEncryptionEngine, LweCiphertextEncryptionError};
@@ -121,3 +123,239 @@ impl LweCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LweCiphertext64>
         LweCiphertext64(ciphertext)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers and ternary secret keys.
+impl LweCiphertextEncryptionEngine<LweTernarySecretKey32, Plaintext32, LweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweTernarySecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweTernarySecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<LweCiphertext32, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweTernarySecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> LweCiphertext32 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u32, key.lwe_dimension().to_lwe_size());
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers and ternary secret keys.
+impl LweCiphertextEncryptionEngine<LweTernarySecretKey64, Plaintext64, LweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweTernarySecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweTernarySecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<LweCiphertext64, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweTernarySecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> LweCiphertext64 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u64, key.lwe_dimension().to_lwe_size());
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCiphertext64(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers and gaussian secret keys.
+impl LweCiphertextEncryptionEngine<LweGaussianSecretKey32, Plaintext32, LweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweGaussianSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweGaussianSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<LweCiphertext32, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweGaussianSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> LweCiphertext32 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u32, key.lwe_dimension().to_lwe_size());
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers and gaussian secret keys.
+impl LweCiphertextEncryptionEngine<LweGaussianSecretKey64, Plaintext64, LweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweGaussianSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweGaussianSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<LweCiphertext64, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweGaussianSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> LweCiphertext64 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u64, key.lwe_dimension().to_lwe_size());
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCiphertext64(ciphertext)
+    }
+}
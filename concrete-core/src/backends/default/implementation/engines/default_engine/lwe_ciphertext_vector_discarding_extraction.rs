@@ -0,0 +1,162 @@
+#[allow(deprecated)]
+use crate::prelude::{MonomialDegree, MonomialIndex};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingExtractionEngine, LweCiphertextVectorDiscardingExtractionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingExtractionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl LweCiphertextVectorDiscardingExtractionEngine<GlweCiphertext32, LweCiphertextVector32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let expected_lwe_dimension = LweDimension(glwe_dimension.0 * polynomial_size.0);
+    /// // We're going to extract every coefficient of the GLWE ciphertext
+    /// let lwe_count = LweCiphertextCount(polynomial_size.0);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// // Transform the original GLWE key to an LWE key to be able to decrypt the extracted
+    /// // ciphertexts
+    /// let lwe_key: LweSecretKey32 =
+    ///     engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let glwe_ciphertext = engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
+    /// // We first create an LWE ciphertext vector encrypting zeros
+    /// let mut lwe_ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&lwe_key, noise, lwe_count)?;
+    ///
+    /// // Then we extract all the samples from the GLWE ciphertext to store them into the vector
+    /// engine.discard_extract_lwe_ciphertext_vector(
+    ///     &mut lwe_ciphertext_vector,
+    ///     &glwe_ciphertext,
+    ///     MonomialIndex(0),
+    /// )?;
+    /// #
+    /// assert_eq!(lwe_ciphertext_vector.lwe_dimension(), expected_lwe_dimension);
+    /// assert_eq!(lwe_ciphertext_vector.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_extract_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &GlweCiphertext32,
+        first: MonomialIndex,
+    ) -> Result<(), LweCiphertextVectorDiscardingExtractionError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingExtractionError::perform_generic_checks(output, input, first)?;
+        unsafe { self.discard_extract_lwe_ciphertext_vector_unchecked(output, input, first) };
+        Ok(())
+    }
+
+    unsafe fn discard_extract_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &GlweCiphertext32,
+        first: MonomialIndex,
+    ) {
+        for (i, mut lwe) in output.0.ciphertext_iter_mut().enumerate() {
+            #[allow(deprecated)]
+            lwe.fill_with_glwe_sample_extraction(&input.0, MonomialDegree(first.0 + i));
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingExtractionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextVectorDiscardingExtractionEngine<GlweCiphertext64, LweCiphertextVector64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let expected_lwe_dimension = LweDimension(glwe_dimension.0 * polynomial_size.0);
+    /// // We're going to extract the last two coefficients of the GLWE ciphertext
+    /// let lwe_count = LweCiphertextCount(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// // Transform the original GLWE key to an LWE key to be able to decrypt the extracted
+    /// // ciphertexts
+    /// let lwe_key: LweSecretKey64 =
+    ///     engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let glwe_ciphertext = engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
+    /// // We first create an LWE ciphertext vector encrypting zeros
+    /// let mut lwe_ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&lwe_key, noise, lwe_count)?;
+    ///
+    /// // Then we extract the samples of index 2 and 3 from the GLWE ciphertext
+    /// engine.discard_extract_lwe_ciphertext_vector(
+    ///     &mut lwe_ciphertext_vector,
+    ///     &glwe_ciphertext,
+    ///     MonomialIndex(2),
+    /// )?;
+    /// #
+    /// assert_eq!(lwe_ciphertext_vector.lwe_dimension(), expected_lwe_dimension);
+    /// assert_eq!(lwe_ciphertext_vector.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_extract_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &GlweCiphertext64,
+        first: MonomialIndex,
+    ) -> Result<(), LweCiphertextVectorDiscardingExtractionError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingExtractionError::perform_generic_checks(output, input, first)?;
+        unsafe { self.discard_extract_lwe_ciphertext_vector_unchecked(output, input, first) };
+        Ok(())
+    }
+
+    unsafe fn discard_extract_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &GlweCiphertext64,
+        first: MonomialIndex,
+    ) {
+        for (i, mut lwe) in output.0.ciphertext_iter_mut().enumerate() {
+            #[allow(deprecated)]
+            lwe.fill_with_glwe_sample_extraction(&input.0, MonomialDegree(first.0 + i));
+        }
+    }
+}
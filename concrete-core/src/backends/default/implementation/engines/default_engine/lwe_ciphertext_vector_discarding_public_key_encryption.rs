@@ -0,0 +1,218 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LwePublicKey32, LwePublicKey64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingPublicKeyEncryptionEngine,
+    LweCiphertextVectorDiscardingPublicKeyEncryptionError,
+};
+use crate::specification::entities::{LwePublicKeyEntity, PlaintextVectorEntity};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingPublicKeyEncryptionEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextVectorDiscardingPublicKeyEncryptionEngine<
+        LwePublicKey32,
+        PlaintextVector32,
+        LweCiphertextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(7);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LwePublicKey32 = engine.generate_new_lwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext_vector: LweCiphertextVector32 = engine.create_lwe_ciphertext_vector_from(
+    ///     vec![0u32; lwe_dimension.to_lwe_size().0 * 3],
+    ///     lwe_dimension.to_lwe_size(),
+    /// )?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext_vector_with_public_key(
+    ///     &public_key,
+    ///     &mut ciphertext_vector,
+    ///     &plaintext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector(&secret_key, &ciphertext_vector)?;
+    /// let output: Vec<u32> = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// for value in output {
+    ///     assert_eq!((value.wrapping_add(1 << 19)) >> 20, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_vector_with_public_key(
+        &mut self,
+        key: &LwePublicKey32,
+        output: &mut LweCiphertextVector32,
+        input: &PlaintextVector32,
+    ) -> Result<(), LweCiphertextVectorDiscardingPublicKeyEncryptionError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingPublicKeyEncryptionError::perform_generic_checks(
+            key, output, input,
+        )?;
+        unsafe {
+            self.discard_encrypt_lwe_ciphertext_vector_with_public_key_unchecked(key, output, input)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_vector_with_public_key_unchecked(
+        &mut self,
+        key: &LwePublicKey32,
+        output: &mut LweCiphertextVector32,
+        input: &PlaintextVector32,
+    ) {
+        let zero_encryption_count = key.lwe_zero_encryption_count().0;
+        let ct_choices = self
+            .secret_generator
+            .random_binary_tensor::<u32>(zero_encryption_count * input.plaintext_count().0);
+
+        for ((mut ciphertext, plaintext), choices) in output
+            .0
+            .ciphertext_iter_mut()
+            .zip(input.0.plaintext_iter())
+            .zip(ct_choices.as_container().chunks(zero_encryption_count))
+        {
+            // Fills the mask with zeros, store the plaintext in the body
+            ciphertext.fill_with_trivial_encryption(plaintext);
+
+            // Add the public encryption of zeros to get the encryption
+            for (&chosen, public_encryption_of_zero) in choices.iter().zip(key.0.ciphertext_iter())
+            {
+                if chosen == 1 {
+                    ciphertext.update_with_add(&public_encryption_of_zero);
+                }
+            }
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingPublicKeyEncryptionEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingPublicKeyEncryptionEngine<
+        LwePublicKey64,
+        PlaintextVector64,
+        LweCiphertextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(7);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LwePublicKey64 = engine.generate_new_lwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext_vector: LweCiphertextVector64 = engine.create_lwe_ciphertext_vector_from(
+    ///     vec![0u64; lwe_dimension.to_lwe_size().0 * 3],
+    ///     lwe_dimension.to_lwe_size(),
+    /// )?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext_vector_with_public_key(
+    ///     &public_key,
+    ///     &mut ciphertext_vector,
+    ///     &plaintext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector(&secret_key, &ciphertext_vector)?;
+    /// let output: Vec<u64> = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// for value in output {
+    ///     assert_eq!((value.wrapping_add(1 << 49)) >> 50, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_vector_with_public_key(
+        &mut self,
+        key: &LwePublicKey64,
+        output: &mut LweCiphertextVector64,
+        input: &PlaintextVector64,
+    ) -> Result<(), LweCiphertextVectorDiscardingPublicKeyEncryptionError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingPublicKeyEncryptionError::perform_generic_checks(
+            key, output, input,
+        )?;
+        unsafe {
+            self.discard_encrypt_lwe_ciphertext_vector_with_public_key_unchecked(key, output, input)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_vector_with_public_key_unchecked(
+        &mut self,
+        key: &LwePublicKey64,
+        output: &mut LweCiphertextVector64,
+        input: &PlaintextVector64,
+    ) {
+        let zero_encryption_count = key.lwe_zero_encryption_count().0;
+        let ct_choices = self
+            .secret_generator
+            .random_binary_tensor::<u64>(zero_encryption_count * input.plaintext_count().0);
+
+        for ((mut ciphertext, plaintext), choices) in output
+            .0
+            .ciphertext_iter_mut()
+            .zip(input.0.plaintext_iter())
+            .zip(ct_choices.as_container().chunks(zero_encryption_count))
+        {
+            // Fills the mask with zeros, store the plaintext in the body
+            ciphertext.fill_with_trivial_encryption(plaintext);
+
+            // Add the public encryption of zeros to get the encryption
+            for (&chosen, public_encryption_of_zero) in choices.iter().zip(key.0.ciphertext_iter())
+            {
+                if chosen == 1 {
+                    ciphertext.update_with_add(&public_encryption_of_zero);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweAutomorphismKey32, GlweAutomorphismKey64, GlweCiphertext32, GlweCiphertext64,
+    LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine,
+    LweCiphertextVectorGlweCiphertextDiscardingTracePackingError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine<
+        GlweAutomorphismKey32,
+        LweCiphertextVector32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits), which leaves room for the
+    /// // multiplication by the polynomial size performed by the packing.
+    /// let input = vec![3_u32 << 20; 16];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey32 = engine.generate_new_glwe_automorphism_key(
+    ///     &glwe_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let lwe_key: LweSecretKey32 =
+    ///     engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)?;
+    /// let mut output_glwe = engine.zero_encrypt_glwe_ciphertext(&glwe_key, noise)?;
+    ///
+    /// engine.discard_trace_packing_lwe_ciphertext_vector(
+    ///     &mut output_glwe,
+    ///     &ciphertext_vector,
+    ///     &automorphism_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_glwe.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output_glwe.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_trace_packing_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &LweCiphertextVector32,
+        ak: &GlweAutomorphismKey32,
+    ) -> Result<(), LweCiphertextVectorGlweCiphertextDiscardingTracePackingError<Self::EngineError>>
+    {
+        LweCiphertextVectorGlweCiphertextDiscardingTracePackingError::perform_generic_checks(
+            output, input, ak,
+        )?;
+        unsafe { self.discard_trace_packing_lwe_ciphertext_vector_unchecked(output, input, ak) };
+        Ok(())
+    }
+
+    unsafe fn discard_trace_packing_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &LweCiphertextVector32,
+        ak: &GlweAutomorphismKey32,
+    ) {
+        ak.0.pack_lwe_ciphertexts(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine<
+        GlweAutomorphismKey64,
+        LweCiphertextVector64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let decomposition_base_log = DecompositionBaseLog(10);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits), which leaves room for the
+    /// // multiplication by the polynomial size performed by the packing.
+    /// let input = vec![3_u64 << 50; 16];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey64 = engine.generate_new_glwe_automorphism_key(
+    ///     &glwe_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let lwe_key: LweSecretKey64 =
+    ///     engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)?;
+    /// let mut output_glwe = engine.zero_encrypt_glwe_ciphertext(&glwe_key, noise)?;
+    ///
+    /// engine.discard_trace_packing_lwe_ciphertext_vector(
+    ///     &mut output_glwe,
+    ///     &ciphertext_vector,
+    ///     &automorphism_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_glwe.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output_glwe.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_trace_packing_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &LweCiphertextVector64,
+        ak: &GlweAutomorphismKey64,
+    ) -> Result<(), LweCiphertextVectorGlweCiphertextDiscardingTracePackingError<Self::EngineError>>
+    {
+        LweCiphertextVectorGlweCiphertextDiscardingTracePackingError::perform_generic_checks(
+            output, input, ak,
+        )?;
+        unsafe { self.discard_trace_packing_lwe_ciphertext_vector_unchecked(output, input, ak) };
+        Ok(())
+    }
+
+    unsafe fn discard_trace_packing_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &LweCiphertextVector64,
+        ak: &GlweAutomorphismKey64,
+    ) {
+        ak.0.pack_lwe_ciphertexts(&mut output.0, &input.0);
+    }
+}
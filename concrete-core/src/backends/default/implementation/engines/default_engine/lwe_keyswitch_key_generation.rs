@@ -2,7 +2,8 @@ use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, Variance};
 
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey32, LweSecretKey64,
+    LweGaussianSecretKey32, LweGaussianSecretKey64, LweKeyswitchKey32, LweKeyswitchKey64,
+    LweSecretKey32, LweSecretKey64, LweTernarySecretKey32, LweTernarySecretKey64,
 };
 use crate::commons::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
 use crate::specification::engines::{
@@ -213,3 +214,1253 @@ impl LweKeyswitchKeyGenerationEngine<LweSecretKey64, LweSecretKey64, LweKeyswitc
         LweKeyswitchKey64(ksk)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers and ternary secret keys.
+impl
+    LweKeyswitchKeyGenerationEngine<LweTernarySecretKey32, LweTernarySecretKey32, LweKeyswitchKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::Variance;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweTernarySecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweTernarySecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweTernarySecretKey32,
+        output_key: &LweTernarySecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey32, LweKeyswitchKeyGenerationError<Self::EngineError>> {
+        LweKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweTernarySecretKey32,
+        output_key: &LweTernarySecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey32 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers and ternary secret keys.
+impl
+    LweKeyswitchKeyGenerationEngine<LweTernarySecretKey64, LweTernarySecretKey64, LweKeyswitchKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::Variance;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweTernarySecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweTernarySecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweTernarySecretKey64,
+        output_key: &LweTernarySecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyGenerationError<Self::EngineError>> {
+        LweKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweTernarySecretKey64,
+        output_key: &LweTernarySecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey64 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweKeyswitchKey64(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers, with a binary input secret key and a ternary output secret key.
+impl LweKeyswitchKeyGenerationEngine<LweSecretKey32, LweTernarySecretKey32, LweKeyswitchKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::Variance;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweTernarySecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &LweTernarySecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey32, LweKeyswitchKeyGenerationError<Self::EngineError>> {
+        LweKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &LweTernarySecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey32 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers, with a binary input secret key and a ternary output secret key.
+impl LweKeyswitchKeyGenerationEngine<LweSecretKey64, LweTernarySecretKey64, LweKeyswitchKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::Variance;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweTernarySecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &LweTernarySecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyGenerationError<Self::EngineError>> {
+        LweKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &LweTernarySecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey64 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweKeyswitchKey64(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers, with a ternary input secret key and a binary output secret key.
+impl LweKeyswitchKeyGenerationEngine<LweTernarySecretKey32, LweSecretKey32, LweKeyswitchKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::Variance;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweTernarySecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweTernarySecretKey32,
+        output_key: &LweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey32, LweKeyswitchKeyGenerationError<Self::EngineError>> {
+        LweKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweTernarySecretKey32,
+        output_key: &LweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey32 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers, with a ternary input secret key and a binary output secret key.
+impl LweKeyswitchKeyGenerationEngine<LweTernarySecretKey64, LweSecretKey64, LweKeyswitchKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::Variance;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweTernarySecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweTernarySecretKey64,
+        output_key: &LweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyGenerationError<Self::EngineError>> {
+        LweKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweTernarySecretKey64,
+        output_key: &LweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey64 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweKeyswitchKey64(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers and gaussian secret keys.
+impl
+    LweKeyswitchKeyGenerationEngine<
+        LweGaussianSecretKey32,
+        LweGaussianSecretKey32,
+        LweKeyswitchKey32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::Variance;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweGaussianSecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweGaussianSecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweGaussianSecretKey32,
+        output_key: &LweGaussianSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey32, LweKeyswitchKeyGenerationError<Self::EngineError>> {
+        LweKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweGaussianSecretKey32,
+        output_key: &LweGaussianSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey32 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers and gaussian secret keys.
+impl
+    LweKeyswitchKeyGenerationEngine<
+        LweGaussianSecretKey64,
+        LweGaussianSecretKey64,
+        LweKeyswitchKey64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::Variance;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweGaussianSecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweGaussianSecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweGaussianSecretKey64,
+        output_key: &LweGaussianSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyGenerationError<Self::EngineError>> {
+        LweKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweGaussianSecretKey64,
+        output_key: &LweGaussianSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey64 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweKeyswitchKey64(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers, with a binary input secret key and a gaussian output secret key.
+impl LweKeyswitchKeyGenerationEngine<LweSecretKey32, LweGaussianSecretKey32, LweKeyswitchKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::Variance;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweGaussianSecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &LweGaussianSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey32, LweKeyswitchKeyGenerationError<Self::EngineError>> {
+        LweKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &LweGaussianSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey32 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers, with a binary input secret key and a gaussian output secret key.
+impl LweKeyswitchKeyGenerationEngine<LweSecretKey64, LweGaussianSecretKey64, LweKeyswitchKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::Variance;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweGaussianSecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &LweGaussianSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyGenerationError<Self::EngineError>> {
+        LweKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &LweGaussianSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey64 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweKeyswitchKey64(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on 32
+/// bits integers, with a gaussian input secret key and a binary output secret key.
+impl LweKeyswitchKeyGenerationEngine<LweGaussianSecretKey32, LweSecretKey32, LweKeyswitchKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::Variance;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweGaussianSecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweGaussianSecretKey32,
+        output_key: &LweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey32, LweKeyswitchKeyGenerationError<Self::EngineError>> {
+        LweKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweGaussianSecretKey32,
+        output_key: &LweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey32 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on 64
+/// bits integers, with a gaussian input secret key and a binary output secret key.
+impl LweKeyswitchKeyGenerationEngine<LweGaussianSecretKey64, LweSecretKey64, LweKeyswitchKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::Variance;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweGaussianSecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweGaussianSecretKey64,
+        output_key: &LweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyGenerationError<Self::EngineError>> {
+        LweKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweGaussianSecretKey64,
+        output_key: &LweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey64 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweKeyswitchKey64(ksk)
+    }
+}
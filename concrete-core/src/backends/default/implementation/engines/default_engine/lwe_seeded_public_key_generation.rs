@@ -0,0 +1,181 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    LweSecretKey32, LweSecretKey64, LweSeededPublicKey32, LweSeededPublicKey64,
+};
+use crate::commons::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::commons::crypto::lwe::LweSeededList as ImplLweSeededList;
+use crate::commons::math::random::{CompressionSeed, Seeder};
+use crate::prelude::{CiphertextCount, LwePublicKeyZeroEncryptionCount, PlaintextCount, Variance};
+use crate::specification::engines::{
+    LweSeededPublicKeyGenerationEngine, LweSeededPublicKeyGenerationError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweSeededPublicKeyGenerationEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl LweSeededPublicKeyGenerationEngine<LweSecretKey32, LweSeededPublicKey32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let seeded_public_key: LweSeededPublicKey32 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    /// #
+    /// assert_eq!(seeded_public_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     seeded_public_key.lwe_zero_encryption_count(),
+    ///     lwe_public_key_zero_encryption_count
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_seeded_public_key(
+        &mut self,
+        lwe_secret_key: &LweSecretKey32,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<LweSeededPublicKey32, LweSeededPublicKeyGenerationError<Self::EngineError>> {
+        LweSeededPublicKeyGenerationError::perform_generic_checks(
+            lwe_public_key_zero_encryption_count,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_seeded_public_key_unchecked(
+                lwe_secret_key,
+                noise,
+                lwe_public_key_zero_encryption_count,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_seeded_public_key_unchecked(
+        &mut self,
+        lwe_secret_key: &LweSecretKey32,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> LweSeededPublicKey32 {
+        let mut encrypted_zeros = ImplLweSeededList::allocate(
+            lwe_secret_key.lwe_dimension(),
+            CiphertextCount(lwe_public_key_zero_encryption_count.0),
+            CompressionSeed {
+                seed: self.seeder.seed(),
+            },
+        );
+        let zeros = ImplPlaintextList::allocate(
+            0u32,
+            PlaintextCount(lwe_public_key_zero_encryption_count.0),
+        );
+        lwe_secret_key
+            .0
+            .encrypt_seeded_lwe_list::<_, _, _, _, _, ActivatedRandomGenerator>(
+                &mut encrypted_zeros,
+                &zeros,
+                noise,
+                &mut self.seeder,
+            );
+        LweSeededPublicKey32(encrypted_zeros)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededPublicKeyGenerationEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl LweSeededPublicKeyGenerationEngine<LweSecretKey64, LweSeededPublicKey64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let seeded_public_key: LweSeededPublicKey64 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    /// #
+    /// assert_eq!(seeded_public_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     seeded_public_key.lwe_zero_encryption_count(),
+    ///     lwe_public_key_zero_encryption_count
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_seeded_public_key(
+        &mut self,
+        lwe_secret_key: &LweSecretKey64,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<LweSeededPublicKey64, LweSeededPublicKeyGenerationError<Self::EngineError>> {
+        LweSeededPublicKeyGenerationError::perform_generic_checks(
+            lwe_public_key_zero_encryption_count,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_seeded_public_key_unchecked(
+                lwe_secret_key,
+                noise,
+                lwe_public_key_zero_encryption_count,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_seeded_public_key_unchecked(
+        &mut self,
+        lwe_secret_key: &LweSecretKey64,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> LweSeededPublicKey64 {
+        let mut encrypted_zeros = ImplLweSeededList::allocate(
+            lwe_secret_key.lwe_dimension(),
+            CiphertextCount(lwe_public_key_zero_encryption_count.0),
+            CompressionSeed {
+                seed: self.seeder.seed(),
+            },
+        );
+        let zeros = ImplPlaintextList::allocate(
+            0u64,
+            PlaintextCount(lwe_public_key_zero_encryption_count.0),
+        );
+        lwe_secret_key
+            .0
+            .encrypt_seeded_lwe_list::<_, _, _, _, _, ActivatedRandomGenerator>(
+                &mut encrypted_zeros,
+                &zeros,
+                noise,
+                &mut self.seeder,
+            );
+        LweSeededPublicKey64(encrypted_zeros)
+    }
+}
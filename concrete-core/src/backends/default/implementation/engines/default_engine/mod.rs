@@ -101,13 +101,34 @@ mod encoder_vector_creation;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod ggsw_ciphertext_vector_consuming_retrieval;
+mod ggsw_ciphertext_vector_creation;
+mod ggsw_ciphertext_vector_scalar_encryption;
+mod ggsw_seeded_ciphertext_scalar_encryption;
+mod ggsw_seeded_ciphertext_to_ggsw_ciphertext_transformation;
+mod glwe_automorphism_key_generation;
+mod glwe_ciphertext_accumulator_generation;
+mod glwe_ciphertext_cleartext_vector_discarding_multiplication;
+mod glwe_ciphertext_cleartext_vector_fusing_multiplication;
 mod glwe_ciphertext_consuming_retrieval;
 mod glwe_ciphertext_creation;
 mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_discarding_addition;
+mod glwe_ciphertext_discarding_automorphism;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_keyswitch;
+mod glwe_ciphertext_discarding_opposite;
+mod glwe_ciphertext_discarding_subtraction;
+mod glwe_ciphertext_discarding_trace;
 mod glwe_ciphertext_discarding_trivial_encryption;
 mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_fusing_addition;
+mod glwe_ciphertext_fusing_opposite;
+mod glwe_ciphertext_fusing_subtraction;
+mod glwe_ciphertext_multi_function_accumulator_generation;
+mod glwe_ciphertext_plaintext_vector_discarding_addition;
+mod glwe_ciphertext_plaintext_vector_fusing_addition;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_consuming_retrieval;
@@ -120,12 +141,17 @@ mod glwe_ciphertext_vector_trivial_decryption;
 mod glwe_ciphertext_vector_trivial_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
+mod glwe_keyswitch_key_generation;
+mod glwe_relinearization_key_generation;
 mod glwe_secret_key_generation;
 mod glwe_seeded_ciphertext_encryption;
 mod glwe_seeded_ciphertext_to_glwe_ciphertext_transformation;
 mod glwe_seeded_ciphertext_vector_encryption;
+mod glwe_seeded_keyswitch_key_generation;
+mod glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_transformation;
 mod glwe_seeded_vector_to_glwe_ciphertext_vector_transformation;
 mod glwe_to_lwe_secret_key_transformation;
+mod gsw_ciphertext_scalar_encryption;
 mod lwe_bootstrap_key_consuming_retrieval;
 mod lwe_bootstrap_key_creation;
 mod lwe_bootstrap_key_discarding_conversion;
@@ -149,10 +175,12 @@ mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_opposite;
 mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_gsw_ciphertext_external_product;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
 mod lwe_ciphertext_plaintext_fusing_subtraction;
+mod lwe_ciphertext_public_key_encryption;
 mod lwe_ciphertext_trivial_decryption;
 mod lwe_ciphertext_trivial_encryption;
 mod lwe_ciphertext_vector_cleartext_vector_discarding_multiplication;
@@ -163,17 +191,22 @@ mod lwe_ciphertext_vector_discarding_addition;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
 mod lwe_ciphertext_vector_discarding_decryption;
 mod lwe_ciphertext_vector_discarding_encryption;
+mod lwe_ciphertext_vector_discarding_extraction;
+mod lwe_ciphertext_vector_discarding_public_key_encryption;
 mod lwe_ciphertext_vector_discarding_subtraction;
 mod lwe_ciphertext_vector_encryption;
 mod lwe_ciphertext_vector_fusing_addition;
 mod lwe_ciphertext_vector_fusing_subtraction;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_trace_packing;
 mod lwe_ciphertext_vector_plaintext_vector_discarding_addition;
+mod lwe_ciphertext_vector_public_key_encryption;
 mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
+mod lwe_ciphertexts_gsw_ciphertext_fusing_cmux;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
 mod lwe_keyswitch_key_consuming_retrieval;
 mod lwe_keyswitch_key_creation;
@@ -190,6 +223,8 @@ mod lwe_seeded_ciphertext_vector_encryption;
 mod lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation;
 mod lwe_seeded_keyswitch_key_generation;
 mod lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation;
+mod lwe_seeded_public_key_generation;
+mod lwe_seeded_public_key_to_lwe_public_key_transformation;
 mod lwe_seeded_to_lwe_ciphertext_transformation;
 mod lwe_to_glwe_secret_key_transformation;
 mod plaintext_creation;
@@ -0,0 +1,216 @@
+use crate::backends::default::implementation::engines::DefaultParallelEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LwePublicKey32, LwePublicKey64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::commons::crypto::encoding::Plaintext as ImplPlaintext;
+use crate::commons::crypto::lwe::LweList as ImplLweList;
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::prelude::CiphertextCount;
+use crate::specification::engines::{
+    LweCiphertextVectorPublicKeyEncryptionEngine, LweCiphertextVectorPublicKeyEncryptionError,
+};
+use crate::specification::entities::{LwePublicKeyEntity, PlaintextVectorEntity};
+use rayon::prelude::*;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorPublicKeyEncryptionEngine`] for [`DefaultParallelEngine`]
+/// that operates on 32 bits integers.
+impl
+    LweCiphertextVectorPublicKeyEncryptionEngine<
+        LwePublicKey32,
+        PlaintextVector32,
+        LweCiphertextVector32,
+    > for DefaultParallelEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(7);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut parallel_engine =
+    ///     DefaultParallelEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LwePublicKey32 = engine.generate_new_lwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     parallel_engine.encrypt_lwe_ciphertext_vector_with_public_key(
+    ///         &public_key,
+    ///         &plaintext_vector,
+    ///     )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector(&secret_key, &ciphertext_vector)?;
+    /// let output: Vec<u32> = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// for value in output {
+    ///     assert_eq!((value.wrapping_add(1 << 19)) >> 20, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector_with_public_key(
+        &mut self,
+        key: &LwePublicKey32,
+        input: &PlaintextVector32,
+    ) -> Result<LweCiphertextVector32, LweCiphertextVectorPublicKeyEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_with_public_key_unchecked(key, input) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_with_public_key_unchecked(
+        &mut self,
+        key: &LwePublicKey32,
+        input: &PlaintextVector32,
+    ) -> LweCiphertextVector32 {
+        let zero_encryption_count = key.lwe_zero_encryption_count().0;
+        let ct_choices = self
+            .secret_generator
+            .random_binary_tensor::<u32>(zero_encryption_count * input.plaintext_count().0);
+        let mut vector = ImplLweList::allocate(
+            0u32,
+            key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(input.plaintext_count().0),
+        );
+
+        vector
+            .par_ciphertext_iter_mut()
+            .zip(input.0.as_tensor().as_slice().par_iter())
+            .zip(ct_choices.as_container().par_chunks(zero_encryption_count))
+            .for_each(|((mut ciphertext, plaintext), choices)| {
+                // Fills the mask with zeros, store the plaintext in the body
+                ciphertext.fill_with_trivial_encryption(&ImplPlaintext(*plaintext));
+
+                // Add the public encryption of zeros to get the encryption
+                for (&chosen, public_encryption_of_zero) in
+                    choices.iter().zip(key.0.ciphertext_iter())
+                {
+                    if chosen == 1 {
+                        ciphertext.update_with_add(&public_encryption_of_zero);
+                    }
+                }
+            });
+        LweCiphertextVector32(vector)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorPublicKeyEncryptionEngine`] for [`DefaultParallelEngine`]
+/// that operates on 64 bits integers.
+impl
+    LweCiphertextVectorPublicKeyEncryptionEngine<
+        LwePublicKey64,
+        PlaintextVector64,
+        LweCiphertextVector64,
+    > for DefaultParallelEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(7);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut parallel_engine =
+    ///     DefaultParallelEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LwePublicKey64 = engine.generate_new_lwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     parallel_engine.encrypt_lwe_ciphertext_vector_with_public_key(
+    ///         &public_key,
+    ///         &plaintext_vector,
+    ///     )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector(&secret_key, &ciphertext_vector)?;
+    /// let output: Vec<u64> = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// for value in output {
+    ///     assert_eq!((value.wrapping_add(1 << 49)) >> 50, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector_with_public_key(
+        &mut self,
+        key: &LwePublicKey64,
+        input: &PlaintextVector64,
+    ) -> Result<LweCiphertextVector64, LweCiphertextVectorPublicKeyEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_with_public_key_unchecked(key, input) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_with_public_key_unchecked(
+        &mut self,
+        key: &LwePublicKey64,
+        input: &PlaintextVector64,
+    ) -> LweCiphertextVector64 {
+        let zero_encryption_count = key.lwe_zero_encryption_count().0;
+        let ct_choices = self
+            .secret_generator
+            .random_binary_tensor::<u64>(zero_encryption_count * input.plaintext_count().0);
+        let mut vector = ImplLweList::allocate(
+            0u64,
+            key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(input.plaintext_count().0),
+        );
+
+        vector
+            .par_ciphertext_iter_mut()
+            .zip(input.0.as_tensor().as_slice().par_iter())
+            .zip(ct_choices.as_container().par_chunks(zero_encryption_count))
+            .for_each(|((mut ciphertext, plaintext), choices)| {
+                // Fills the mask with zeros, store the plaintext in the body
+                ciphertext.fill_with_trivial_encryption(&ImplPlaintext(*plaintext));
+
+                // Add the public encryption of zeros to get the encryption
+                for (&chosen, public_encryption_of_zero) in
+                    choices.iter().zip(key.0.ciphertext_iter())
+                {
+                    if chosen == 1 {
+                        ciphertext.update_with_add(&public_encryption_of_zero);
+                    }
+                }
+            });
+        LweCiphertextVector64(vector)
+    }
+}
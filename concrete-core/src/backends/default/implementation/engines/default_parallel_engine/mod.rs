@@ -2,6 +2,7 @@ use super::ActivatedRandomGenerator;
 use crate::commons::crypto::secret::generators::{
     DeterministicSeeder as ImplDeterministicSeeder,
     EncryptionRandomGenerator as ImplEncryptionRandomGenerator,
+    SecretRandomGenerator as ImplSecretRandomGenerator,
 };
 use crate::specification::engines::sealed::AbstractEngineSeal;
 use crate::specification::engines::AbstractEngine;
@@ -35,6 +36,7 @@ pub struct DefaultParallelEngine {
     /// generate mask coefficients and one privately seeded used to generate errors during
     /// encryption.
     pub(crate) encryption_generator: ImplEncryptionRandomGenerator<ActivatedRandomGenerator>,
+    secret_generator: ImplSecretRandomGenerator<ActivatedRandomGenerator>,
     /// A seeder that can be called to generate 128 bits seeds, useful to create new
     /// [`ImplEncryptionRandomGenerator`] to encrypt seeded types.
     seeder: ImplDeterministicSeeder<ActivatedRandomGenerator>,
@@ -56,12 +58,17 @@ impl AbstractEngine for DefaultParallelEngine {
                 deterministic_seeder.seed(),
                 &mut deterministic_seeder,
             ),
+            // The secret generator is seeded after the encryption generator, so that the latter
+            // keeps the seed it was given before the secret generator was introduced.
+            secret_generator: ImplSecretRandomGenerator::new(deterministic_seeder.seed()),
             seeder: deterministic_seeder,
         })
     }
 }
 
 mod lwe_bootstrap_key_generation;
+mod lwe_ciphertext_vector_discarding_extraction;
+mod lwe_ciphertext_vector_public_key_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
 mod lwe_public_key_generation;
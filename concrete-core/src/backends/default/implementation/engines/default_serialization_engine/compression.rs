@@ -0,0 +1,227 @@
+//! The payloads of the entities serialized in a compressed form by the
+//! `DefaultSerializationEngine`.
+//!
+//! The seeded entities only store the bodies of their ciphertexts, which are serialized with their
+//! least significant bits truncated. The ciphertext vectors are bit-packed without loss.
+//!
+//! The payloads do not depend on the precision of the entity, and are shared by its 32 and 64 bits
+//! versions. When an entity is deserialized, the number of integers expected from its dimensions
+//! is checked against the number of packed integers before allocating it.
+
+use crate::commons::crypto::bootstrap::StandardSeededBootstrapKey as ImplStandardSeededBootstrapKey;
+use crate::commons::crypto::glwe::GlweSeededList as ImplGlweSeededList;
+use crate::commons::crypto::lwe::{
+    LweList as ImplLweList, LweSeededKeyswitchKey as ImplLweSeededKeyswitchKey,
+};
+use crate::commons::math::random::CompressionSeed;
+use crate::commons::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::{CastFrom, CastInto};
+use crate::commons::serialization::PackedIntegers;
+use crate::prelude::{
+    CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweCiphertextCount,
+    GlweDimension, GlweSize, LweCiphertextCount, LweDimension, LweSize, PolynomialSize,
+    TruncatedBitCount,
+};
+use serde::{Deserialize, Serialize};
+
+/// The payload of a seeded LWE keyswitch key serialized with truncated bodies.
+#[derive(Serialize, Deserialize)]
+pub(super) struct TruncatedLweSeededKeyswitchKey {
+    input_lwe_dimension: LweDimension,
+    output_lwe_dimension: LweDimension,
+    decomposition_level_count: DecompositionLevelCount,
+    decomposition_base_log: DecompositionBaseLog,
+    compression_seed: CompressionSeed,
+    bodies: PackedIntegers,
+}
+
+impl TruncatedLweSeededKeyswitchKey {
+    pub(super) fn new<Scalar>(
+        key: &ImplLweSeededKeyswitchKey<Vec<Scalar>>,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Self
+    where
+        Scalar: UnsignedTorus + CastInto<u64>,
+    {
+        TruncatedLweSeededKeyswitchKey {
+            input_lwe_dimension: key.input_lwe_dimension(),
+            output_lwe_dimension: key.output_lwe_dimension(),
+            decomposition_level_count: key.decomposition_level_count(),
+            decomposition_base_log: key.decomposition_base_log(),
+            compression_seed: key.compression_seed(),
+            bodies: PackedIntegers::pack_rounded(key.as_tensor().as_slice(), truncated_bit_count.0),
+        }
+    }
+
+    pub(super) fn into_inner<Scalar>(
+        self,
+    ) -> bincode::Result<ImplLweSeededKeyswitchKey<Vec<Scalar>>>
+    where
+        Scalar: UnsignedTorus + CastFrom<u64>,
+    {
+        self.bodies.check_len(
+            self.input_lwe_dimension
+                .0
+                .checked_mul(self.decomposition_level_count.0),
+        )?;
+        let mut inner = ImplLweSeededKeyswitchKey::allocate(
+            self.decomposition_level_count,
+            self.decomposition_base_log,
+            self.input_lwe_dimension,
+            self.output_lwe_dimension,
+            self.compression_seed,
+        );
+        self.bodies
+            .unpack_into(inner.as_mut_tensor().as_mut_slice())?;
+        Ok(inner)
+    }
+}
+
+/// The payload of a seeded LWE bootstrap key serialized with truncated bodies.
+#[derive(Serialize, Deserialize)]
+pub(super) struct TruncatedLweSeededBootstrapKey {
+    input_lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+    decomposition_base_log: DecompositionBaseLog,
+    compression_seed: CompressionSeed,
+    bodies: PackedIntegers,
+}
+
+impl TruncatedLweSeededBootstrapKey {
+    pub(super) fn new<Scalar>(
+        key: &ImplStandardSeededBootstrapKey<Vec<Scalar>>,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Self
+    where
+        Scalar: UnsignedTorus + CastInto<u64>,
+    {
+        TruncatedLweSeededBootstrapKey {
+            input_lwe_dimension: key.key_size(),
+            glwe_dimension: key.glwe_size().to_glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+            decomposition_level_count: key.level_count(),
+            decomposition_base_log: key.base_log(),
+            compression_seed: key.compression_seed(),
+            bodies: PackedIntegers::pack_rounded(key.as_tensor().as_slice(), truncated_bit_count.0),
+        }
+    }
+
+    pub(super) fn into_inner<Scalar>(
+        self,
+    ) -> bincode::Result<ImplStandardSeededBootstrapKey<Vec<Scalar>>>
+    where
+        Scalar: UnsignedTorus + CastFrom<u64>,
+    {
+        let glwe_size = self.glwe_dimension.0.checked_add(1);
+        self.bodies.check_len(
+            glwe_size
+                .and_then(|glwe_size| glwe_size.checked_mul(self.input_lwe_dimension.0))
+                .and_then(|len| len.checked_mul(self.decomposition_level_count.0))
+                .and_then(|len| len.checked_mul(self.polynomial_size.0)),
+        )?;
+        let mut inner = ImplStandardSeededBootstrapKey::allocate(
+            GlweSize(glwe_size.unwrap()),
+            self.polynomial_size,
+            self.decomposition_level_count,
+            self.decomposition_base_log,
+            self.input_lwe_dimension,
+            self.compression_seed,
+        );
+        self.bodies
+            .unpack_into(inner.as_mut_tensor().as_mut_slice())?;
+        Ok(inner)
+    }
+}
+
+/// The payload of a seeded GLWE ciphertext vector serialized with truncated bodies.
+#[derive(Serialize, Deserialize)]
+pub(super) struct TruncatedGlweSeededCiphertextVector {
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    glwe_ciphertext_count: GlweCiphertextCount,
+    compression_seed: CompressionSeed,
+    bodies: PackedIntegers,
+}
+
+impl TruncatedGlweSeededCiphertextVector {
+    pub(super) fn new<Scalar>(
+        list: &ImplGlweSeededList<Vec<Scalar>>,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Self
+    where
+        Scalar: UnsignedTorus + CastInto<u64>,
+    {
+        TruncatedGlweSeededCiphertextVector {
+            glwe_dimension: list.glwe_dimension(),
+            polynomial_size: list.polynomial_size(),
+            glwe_ciphertext_count: GlweCiphertextCount(list.ciphertext_count().0),
+            compression_seed: list.compression_seed(),
+            bodies: PackedIntegers::pack_rounded(
+                list.as_tensor().as_slice(),
+                truncated_bit_count.0,
+            ),
+        }
+    }
+
+    pub(super) fn into_inner<Scalar>(self) -> bincode::Result<ImplGlweSeededList<Vec<Scalar>>>
+    where
+        Scalar: UnsignedTorus + CastFrom<u64>,
+    {
+        self.bodies.check_len(
+            self.polynomial_size
+                .0
+                .checked_mul(self.glwe_ciphertext_count.0),
+        )?;
+        let mut inner = ImplGlweSeededList::allocate(
+            self.polynomial_size,
+            self.glwe_dimension,
+            CiphertextCount(self.glwe_ciphertext_count.0),
+            self.compression_seed,
+        );
+        self.bodies
+            .unpack_into(inner.as_mut_tensor().as_mut_slice())?;
+        Ok(inner)
+    }
+}
+
+/// The payload of a LWE ciphertext vector serialized with bit-packed coefficients.
+#[derive(Serialize, Deserialize)]
+pub(super) struct PackedLweCiphertextVector {
+    lwe_dimension: LweDimension,
+    lwe_ciphertext_count: LweCiphertextCount,
+    coefficients: PackedIntegers,
+}
+
+impl PackedLweCiphertextVector {
+    pub(super) fn new<Scalar>(list: &ImplLweList<Vec<Scalar>>) -> Self
+    where
+        Scalar: UnsignedTorus + CastInto<u64>,
+    {
+        PackedLweCiphertextVector {
+            lwe_dimension: list.lwe_size().to_lwe_dimension(),
+            lwe_ciphertext_count: LweCiphertextCount(list.count().0),
+            coefficients: PackedIntegers::pack_lossless(list.as_tensor().as_slice()),
+        }
+    }
+
+    pub(super) fn into_inner<Scalar>(self) -> bincode::Result<ImplLweList<Vec<Scalar>>>
+    where
+        Scalar: UnsignedTorus + CastFrom<u64>,
+    {
+        let lwe_size = self.lwe_dimension.0.checked_add(1);
+        self.coefficients.check_len(
+            lwe_size.and_then(|lwe_size| lwe_size.checked_mul(self.lwe_ciphertext_count.0)),
+        )?;
+        let mut inner = ImplLweList::allocate(
+            Scalar::ZERO,
+            LweSize(lwe_size.unwrap()),
+            CiphertextCount(self.lwe_ciphertext_count.0),
+        );
+        self.coefficients
+            .unpack_into(inner.as_mut_tensor().as_mut_slice())?;
+        Ok(inner)
+    }
+}
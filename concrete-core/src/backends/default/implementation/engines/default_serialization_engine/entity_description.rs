@@ -0,0 +1,193 @@
+//! The descriptions stored in the headers of the entities serialized by the
+//! `DefaultSerializationEngine`.
+
+use crate::commons::serialization::describe_entities;
+use crate::prelude::{
+    Cleartext32, Cleartext64, CleartextF64, CleartextVector32, CleartextVector64,
+    CleartextVectorEntity, CleartextVectorF64, EncoderVectorEntity, FloatEncoder,
+    FloatEncoderVector, GgswCiphertext32, GgswCiphertext64, GgswCiphertextEntity,
+    GgswSeededCiphertext32, GgswSeededCiphertext64, GgswSeededCiphertextEntity, GlweCiphertext32,
+    GlweCiphertext64, GlweCiphertextEntity, GlweCiphertextMutView32, GlweCiphertextMutView64,
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweCiphertextVectorEntity,
+    GlweCiphertextVectorMutView32, GlweCiphertextVectorMutView64, GlweCiphertextVectorView32,
+    GlweCiphertextVectorView64, GlweCiphertextView32, GlweCiphertextView64,
+    GlweGaussianSecretKey32, GlweGaussianSecretKey64, GlweSecretKey32, GlweSecretKey64,
+    GlweSecretKeyEntity, GlweSeededCiphertext32, GlweSeededCiphertext64,
+    GlweSeededCiphertextEntity, GlweSeededCiphertextVector32, GlweSeededCiphertextVector64,
+    GlweSeededCiphertextVectorEntity, GlweTernarySecretKey32, GlweTernarySecretKey64,
+    LweBootstrapKey32, LweBootstrapKey64, LweBootstrapKeyEntity, LweCiphertext32, LweCiphertext64,
+    LweCiphertextEntity, LweCiphertextMutView32, LweCiphertextMutView64, LweCiphertextVector32,
+    LweCiphertextVector64, LweCiphertextVectorEntity, LweCiphertextVectorMutView32,
+    LweCiphertextVectorMutView64, LweCiphertextVectorView32, LweCiphertextVectorView64,
+    LweCiphertextView32, LweCiphertextView64,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, LweGaussianSecretKey32,
+    LweGaussianSecretKey64, LweKeyswitchKey32, LweKeyswitchKey64, LweKeyswitchKeyEntity,
+    LwePackingKeyswitchKey32, LwePackingKeyswitchKey64, LwePackingKeyswitchKeyEntity,
+    LwePublicKey32, LwePublicKey64, LwePublicKeyEntity, LweSecretKey32, LweSecretKey64,
+    LweSecretKeyEntity, LweSeededBootstrapKey32, LweSeededBootstrapKey64,
+    LweSeededBootstrapKeyEntity, LweSeededCiphertext32, LweSeededCiphertext64,
+    LweSeededCiphertextEntity, LweSeededCiphertextVector32, LweSeededCiphertextVector64,
+    LweSeededCiphertextVectorEntity, LweSeededKeyswitchKey32, LweSeededKeyswitchKey64,
+    LweSeededKeyswitchKeyEntity, LweSeededPublicKey32, LweSeededPublicKey64,
+    LweSeededPublicKeyEntity, LweTernaryBootstrapKey32, LweTernaryBootstrapKey64,
+    LweTernarySecretKey32, LweTernarySecretKey64, Plaintext32, Plaintext64, PlaintextVector32,
+    PlaintextVector64, PlaintextVectorEntity,
+};
+
+describe_entities! {
+    "Cleartext" => [Cleartext32: 32, Cleartext64: 64, CleartextF64: 64] |_e| vec![];
+    "CleartextVector" => [CleartextVector32: 32, CleartextVector64: 64, CleartextVectorF64: 64]
+    |e| vec![e.cleartext_count().0];
+    "FloatEncoder" => [FloatEncoder: 64] |_e| vec![];
+    "FloatEncoderVector" => [FloatEncoderVector: 64] |e| vec![e.encoder_count().0];
+    "GgswCiphertext" => [GgswCiphertext32: 32, GgswCiphertext64: 64]
+    |e| vec![
+        e.glwe_dimension().0,
+        e.polynomial_size().0,
+        e.decomposition_level_count().0,
+        e.decomposition_base_log().0,
+    ];
+    "GgswSeededCiphertext" => [GgswSeededCiphertext32: 32, GgswSeededCiphertext64: 64]
+    |e| vec![
+        e.glwe_dimension().0,
+        e.polynomial_size().0,
+        e.decomposition_level_count().0,
+        e.decomposition_base_log().0,
+    ];
+    "GlweCiphertext" => [
+        GlweCiphertext32: 32,
+        GlweCiphertext64: 64,
+        GlweCiphertextView32<'_>: 32,
+        GlweCiphertextView64<'_>: 64,
+        GlweCiphertextMutView32<'_>: 32,
+        GlweCiphertextMutView64<'_>: 64
+    ]
+    |e| vec![e.glwe_dimension().0, e.polynomial_size().0];
+    "GlweCiphertextVector" => [
+        GlweCiphertextVector32: 32,
+        GlweCiphertextVector64: 64,
+        GlweCiphertextVectorView32<'_>: 32,
+        GlweCiphertextVectorView64<'_>: 64,
+        GlweCiphertextVectorMutView32<'_>: 32,
+        GlweCiphertextVectorMutView64<'_>: 64
+    ]
+    |e| vec![
+        e.glwe_dimension().0,
+        e.polynomial_size().0,
+        e.glwe_ciphertext_count().0,
+    ];
+    "GlweSecretKey" => [GlweSecretKey32: 32, GlweSecretKey64: 64]
+    |e| vec![e.glwe_dimension().0, e.polynomial_size().0];
+    "GlweTernarySecretKey" => [GlweTernarySecretKey32: 32, GlweTernarySecretKey64: 64]
+    |e| vec![e.glwe_dimension().0, e.polynomial_size().0];
+    "GlweGaussianSecretKey" => [GlweGaussianSecretKey32: 32, GlweGaussianSecretKey64: 64]
+    |e| vec![e.glwe_dimension().0, e.polynomial_size().0];
+    "GlweSeededCiphertext" => [GlweSeededCiphertext32: 32, GlweSeededCiphertext64: 64]
+    |e| vec![e.glwe_dimension().0, e.polynomial_size().0];
+    "GlweSeededCiphertextVector" => [
+        GlweSeededCiphertextVector32: 32,
+        GlweSeededCiphertextVector64: 64
+    ]
+    |e| vec![
+        e.glwe_dimension().0,
+        e.polynomial_size().0,
+        e.glwe_ciphertext_count().0,
+    ];
+    "LweBootstrapKey" => [LweBootstrapKey32: 32, LweBootstrapKey64: 64]
+    |e| vec![
+        e.input_lwe_dimension().0,
+        e.glwe_dimension().0,
+        e.polynomial_size().0,
+        e.decomposition_level_count().0,
+        e.decomposition_base_log().0,
+    ];
+    "LweTernaryBootstrapKey" => [LweTernaryBootstrapKey32: 32, LweTernaryBootstrapKey64: 64]
+    |e| vec![
+        e.input_lwe_dimension().0,
+        e.glwe_dimension().0,
+        e.polynomial_size().0,
+        e.decomposition_level_count().0,
+        e.decomposition_base_log().0,
+    ];
+    "LweSeededBootstrapKey" => [LweSeededBootstrapKey32: 32, LweSeededBootstrapKey64: 64]
+    |e| vec![
+        e.input_lwe_dimension().0,
+        e.glwe_dimension().0,
+        e.polynomial_size().0,
+        e.decomposition_level_count().0,
+        e.decomposition_base_log().0,
+    ];
+    "LweCiphertext" => [
+        LweCiphertext32: 32,
+        LweCiphertext64: 64,
+        LweCiphertextView32<'_>: 32,
+        LweCiphertextView64<'_>: 64,
+        LweCiphertextMutView32<'_>: 32,
+        LweCiphertextMutView64<'_>: 64
+    ]
+    |e| vec![e.lwe_dimension().0];
+    "LweCiphertextVector" => [
+        LweCiphertextVector32: 32,
+        LweCiphertextVector64: 64,
+        LweCiphertextVectorView32<'_>: 32,
+        LweCiphertextVectorView64<'_>: 64,
+        LweCiphertextVectorMutView32<'_>: 32,
+        LweCiphertextVectorMutView64<'_>: 64
+    ]
+    |e| vec![e.lwe_dimension().0, e.lwe_ciphertext_count().0];
+    "LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys" => [
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32: 32,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64: 64
+    ]
+    |e| vec![
+        e.input_lwe_dimension().0,
+        e.output_glwe_dimension().0,
+        e.output_polynomial_size().0,
+        e.decomposition_level_count().0,
+        e.decomposition_base_log().0,
+        e.key_count().0,
+    ];
+    "LweKeyswitchKey" => [LweKeyswitchKey32: 32, LweKeyswitchKey64: 64]
+    |e| vec![
+        e.input_lwe_dimension().0,
+        e.output_lwe_dimension().0,
+        e.decomposition_level_count().0,
+        e.decomposition_base_log().0,
+    ];
+    "LweSeededKeyswitchKey" => [LweSeededKeyswitchKey32: 32, LweSeededKeyswitchKey64: 64]
+    |e| vec![
+        e.input_lwe_dimension().0,
+        e.output_lwe_dimension().0,
+        e.decomposition_level_count().0,
+        e.decomposition_base_log().0,
+    ];
+    "LwePackingKeyswitchKey" => [LwePackingKeyswitchKey32: 32, LwePackingKeyswitchKey64: 64]
+    |e| vec![
+        e.input_lwe_dimension().0,
+        e.output_glwe_dimension().0,
+        e.output_polynomial_size().0,
+        e.decomposition_level_count().0,
+        e.decomposition_base_log().0,
+    ];
+    "LwePublicKey" => [LwePublicKey32: 32, LwePublicKey64: 64]
+    |e| vec![e.lwe_dimension().0, e.lwe_zero_encryption_count().0];
+    "LweSecretKey" => [LweSecretKey32: 32, LweSecretKey64: 64] |e| vec![e.lwe_dimension().0];
+    "LweTernarySecretKey" => [LweTernarySecretKey32: 32, LweTernarySecretKey64: 64]
+    |e| vec![e.lwe_dimension().0];
+    "LweGaussianSecretKey" => [LweGaussianSecretKey32: 32, LweGaussianSecretKey64: 64]
+    |e| vec![e.lwe_dimension().0];
+    "LweSeededCiphertext" => [LweSeededCiphertext32: 32, LweSeededCiphertext64: 64]
+    |e| vec![e.lwe_dimension().0];
+    "LweSeededPublicKey" => [LweSeededPublicKey32: 32, LweSeededPublicKey64: 64]
+    |e| vec![e.lwe_dimension().0, e.lwe_zero_encryption_count().0];
+    "LweSeededCiphertextVector" => [
+        LweSeededCiphertextVector32: 32,
+        LweSeededCiphertextVector64: 64
+    ]
+    |e| vec![e.lwe_dimension().0, e.lwe_ciphertext_count().0];
+    "Plaintext" => [Plaintext32: 32, Plaintext64: 64] |_e| vec![];
+    "PlaintextVector" => [PlaintextVector32: 32, PlaintextVector64: 64]
+    |e| vec![e.plaintext_count().0];
+}
@@ -0,0 +1,227 @@
+use super::compression::PackedLweCiphertextVector;
+use crate::commons::serialization::serialize_with_header;
+use crate::prelude::{
+    DefaultSerializationEngine, DefaultSerializationError, EntityPackedSerializationEngine,
+    EntityPackedSerializationError, LweCiphertextVector32, LweCiphertextVector32Version,
+    LweCiphertextVector64, LweCiphertextVector64Version,
+};
+use serde::Serialize;
+
+/// # Description:
+/// Implementation of [`EntityPackedSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes a LWE ciphertext vector entity with bit-packed
+/// coefficients.
+impl EntityPackedSerializationEngine<LweCiphertextVector32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The coefficients live in a modulus of 2^12, stored in the most significant bits.
+    /// let lwe_size = LweSize(8);
+    /// let container: Vec<u32> = (0..lwe_size.0 as u32 * 64)
+    ///     .map(|i| (i.wrapping_mul(2_654_435_761) % 4096) << 20)
+    ///     .collect();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.create_lwe_ciphertext_vector_from(container, lwe_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let packed = serialization_engine.serialize_packed(&ciphertext_vector)?;
+    /// assert!(packed.len() < serialized.len() / 2);
+    ///
+    /// let recovered = serialization_engine.deserialize(packed.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_packed(
+        &mut self,
+        entity: &LweCiphertextVector32,
+    ) -> Result<Vec<u8>, EntityPackedSerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVector32 {
+            version: LweCiphertextVector32Version,
+            inner: PackedLweCiphertextVector,
+        }
+        let serializable = SerializableLweCiphertextVector32 {
+            version: LweCiphertextVector32Version::PackedV0,
+            inner: PackedLweCiphertextVector::new(&entity.0),
+        };
+        serialize_with_header(entity, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntityPackedSerializationError::Engine)
+    }
+
+    unsafe fn serialize_packed_unchecked(&mut self, entity: &LweCiphertextVector32) -> Vec<u8> {
+        self.serialize_packed(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityPackedSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes a LWE ciphertext vector entity with bit-packed
+/// coefficients.
+impl EntityPackedSerializationEngine<LweCiphertextVector64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The coefficients live in a modulus of 2^12, stored in the most significant bits.
+    /// let lwe_size = LweSize(8);
+    /// let container: Vec<u64> = (0..lwe_size.0 as u64 * 64)
+    ///     .map(|i| (i.wrapping_mul(2_654_435_761) % 4096) << 52)
+    ///     .collect();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.create_lwe_ciphertext_vector_from(container, lwe_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let packed = serialization_engine.serialize_packed(&ciphertext_vector)?;
+    /// assert!(packed.len() < serialized.len() / 2);
+    ///
+    /// let recovered = serialization_engine.deserialize(packed.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_packed(
+        &mut self,
+        entity: &LweCiphertextVector64,
+    ) -> Result<Vec<u8>, EntityPackedSerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVector64 {
+            version: LweCiphertextVector64Version,
+            inner: PackedLweCiphertextVector,
+        }
+        let serializable = SerializableLweCiphertextVector64 {
+            version: LweCiphertextVector64Version::PackedV0,
+            inner: PackedLweCiphertextVector::new(&entity.0),
+        };
+        serialize_with_header(entity, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntityPackedSerializationError::Engine)
+    }
+
+    unsafe fn serialize_packed_unchecked(&mut self, entity: &LweCiphertextVector64) -> Vec<u8> {
+        self.serialize_packed(entity).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod unit_test_packed_serialization {
+    use crate::commons::utils::Checksum;
+    use crate::prelude::*;
+    use std::error::Error;
+
+    // The offsets of the fields of a packed LWE ciphertext vector payload, made of the version tag,
+    // the LWE dimension, the ciphertext count, the shift and the bit width of the packed integers,
+    // their number, and the length of the vector of words followed by the words.
+    const CIPHERTEXT_COUNT_OFFSET: usize = 12;
+    const BIT_WIDTH_OFFSET: usize = 24;
+    const INTEGER_COUNT_OFFSET: usize = 28;
+    const WORD_COUNT_OFFSET: usize = 36;
+
+    // Returns the offset of the payload of a serialized entity.
+    fn payload_offset(serialized: &[u8]) -> usize {
+        let header_size = u64::from_le_bytes(serialized[8..16].try_into().unwrap()) as usize;
+        16 + header_size + 8
+    }
+
+    // Updates the payload size and the checksum of a serialized entity whose payload was
+    // modified, so that the corrupted payload is decoded.
+    fn reseal(serialized: &mut [u8]) {
+        let payload_offset = payload_offset(serialized);
+        let checksum_offset = serialized.len() - 8;
+        let payload_size = (checksum_offset - payload_offset) as u64;
+        serialized[payload_offset - 8..payload_offset].copy_from_slice(&payload_size.to_le_bytes());
+        let mut checksum = Checksum::new();
+        checksum.update(&serialized[..checksum_offset]);
+        serialized[checksum_offset..].copy_from_slice(&checksum.finish().to_le_bytes());
+    }
+
+    // Returns a copy of the serialized entity in which `field` is set to `value`.
+    fn with_field<const N: usize>(serialized: &[u8], field: usize, value: [u8; N]) -> Vec<u8> {
+        let mut corrupted = serialized.to_vec();
+        let offset = payload_offset(serialized) + field;
+        corrupted[offset..offset + N].copy_from_slice(&value);
+        reseal(&mut corrupted);
+        corrupted
+    }
+
+    #[test]
+    fn test_corrupted_packed_payload_is_rejected() -> Result<(), Box<dyn Error>> {
+        let lwe_size = LweSize(8);
+        let ciphertext_count = 64_u64;
+        let container: Vec<u32> = (0..lwe_size.0 as u32 * ciphertext_count as u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) % 4096) << 20)
+            .collect();
+
+        const UNSAFE_SECRET: u128 = 0;
+        let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let mut serialization_engine = DefaultSerializationEngine::new(())?;
+        let ciphertext_vector: LweCiphertextVector32 =
+            engine.create_lwe_ciphertext_vector_from(container, lwe_size)?;
+        let packed = serialization_engine.serialize_packed(&ciphertext_vector)?;
+        let recovered: LweCiphertextVector32 =
+            serialization_engine.deserialize(packed.as_slice())?;
+        assert_eq!(recovered, ciphertext_vector);
+
+        // The last word of the packed integers is dropped.
+        let mut short = packed.clone();
+        let checksum_offset = short.len() - 8;
+        short.drain(checksum_offset - 8..checksum_offset);
+        let offset = payload_offset(&short) + WORD_COUNT_OFFSET;
+        let word_count = u64::from_le_bytes(short[offset..offset + 8].try_into().unwrap());
+        short[offset..offset + 8].copy_from_slice(&(word_count - 1).to_le_bytes());
+        reseal(&mut short);
+
+        let corrupted = [
+            with_field(&packed, CIPHERTEXT_COUNT_OFFSET, u64::MAX.to_le_bytes()),
+            with_field(
+                &packed,
+                CIPHERTEXT_COUNT_OFFSET,
+                (ciphertext_count + 1).to_le_bytes(),
+            ),
+            with_field(&packed, BIT_WIDTH_OFFSET, 0_u32.to_le_bytes()),
+            with_field(&packed, BIT_WIDTH_OFFSET, 13_u32.to_le_bytes()),
+            with_field(&packed, BIT_WIDTH_OFFSET, u32::MAX.to_le_bytes()),
+            with_field(&packed, INTEGER_COUNT_OFFSET, u64::MAX.to_le_bytes()),
+            with_field(&packed, WORD_COUNT_OFFSET, u64::MAX.to_le_bytes()),
+            short,
+        ];
+        for corrupted in corrupted.iter() {
+            let result: Result<LweCiphertextVector32, _> =
+                serialization_engine.deserialize(corrupted.as_slice());
+            assert!(matches!(result, Err(EntityDeserializationError::Engine(_))));
+        }
+
+        for len in 0..packed.len() {
+            let result: Result<LweCiphertextVector32, _> =
+                serialization_engine.deserialize(&packed[..len]);
+            assert!(result.is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,4410 @@
+#![allow(clippy::missing_safety_doc)]
+use super::compression::{
+    PackedLweCiphertextVector, TruncatedGlweSeededCiphertextVector, TruncatedLweSeededBootstrapKey,
+    TruncatedLweSeededKeyswitchKey,
+};
+use crate::commons::crypto::bootstrap::{
+    StandardBootstrapKey as ImplStandardBootstrapKey,
+    StandardSeededBootstrapKey as ImplStandardSeededBootstrapKey,
+};
+use crate::commons::crypto::encoding::{
+    Cleartext as ImplCleartext, CleartextList as ImplCleartextList,
+    FloatEncoder as ImplFloatEncoder, Plaintext as ImplPlaintext,
+    PlaintextList as ImplPlaintextList,
+};
+use crate::commons::crypto::ggsw::{
+    StandardGgswCiphertext as ImplStandardGgswCiphertext,
+    StandardGgswSeededCiphertext as ImplStandardGgswSeededCiphertext,
+};
+use crate::commons::crypto::glwe::{
+    GlweCiphertext as ImplGlweCiphertext, GlweList as ImplGlweList,
+    GlweSeededCiphertext as ImplGlweSeededCiphertext, GlweSeededList as ImplGlweSeededList,
+    LwePackingKeyswitchKey as ImplLwePackingKeyswitchKey,
+    LwePrivateFunctionalPackingKeyswitchKeyList as ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys,
+};
+use crate::commons::crypto::lwe::{
+    LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
+    LweList as ImplLweList, LweSeededCiphertext as ImplLweSeededCiphertext,
+    LweSeededKeyswitchKey as ImplLweSeededKeyswitchKey, LweSeededList as ImplLweSeededList,
+};
+use crate::commons::crypto::secret::{
+    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
+};
+use crate::commons::serialization::deserialize_from_with_header;
+use crate::prelude::{
+    BinaryKeyKind, Cleartext32, Cleartext32Version, Cleartext64, Cleartext64Version, CleartextF64,
+    CleartextF64Version, CleartextVector32, CleartextVector32Version, CleartextVector64,
+    CleartextVector64Version, CleartextVectorF64, CleartextVectorF64Version,
+    DefaultSerializationEngine, DefaultSerializationError, EntityStreamDeserializationEngine,
+    EntityStreamDeserializationError, FloatEncoder, FloatEncoderVector, FloatEncoderVectorVersion,
+    FloatEncoderVersion, GaussianKeyKind, GgswCiphertext32, GgswCiphertext32Version,
+    GgswCiphertext64, GgswCiphertext64Version, GgswSeededCiphertext32,
+    GgswSeededCiphertext32Version, GgswSeededCiphertext64, GgswSeededCiphertext64Version,
+    GlweCiphertext32, GlweCiphertext32Version, GlweCiphertext64, GlweCiphertext64Version,
+    GlweCiphertextVector32, GlweCiphertextVector32Version, GlweCiphertextVector64,
+    GlweCiphertextVector64Version, GlweGaussianSecretKey32, GlweGaussianSecretKey32Version,
+    GlweGaussianSecretKey64, GlweGaussianSecretKey64Version, GlweSecretKey32,
+    GlweSecretKey32Version, GlweSecretKey64, GlweSecretKey64Version, GlweSeededCiphertext32,
+    GlweSeededCiphertext32Version, GlweSeededCiphertext64, GlweSeededCiphertext64Version,
+    GlweSeededCiphertextVector32, GlweSeededCiphertextVector64, GlweTernarySecretKey32,
+    GlweTernarySecretKey32Version, GlweTernarySecretKey64, GlweTernarySecretKey64Version,
+    LweBootstrapKey32, LweBootstrapKey32Version, LweBootstrapKey64, LweBootstrapKey64Version,
+    LweCiphertext32, LweCiphertext32Version, LweCiphertext64, LweCiphertext64Version,
+    LweCiphertextVector32, LweCiphertextVector64,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version, LweGaussianSecretKey32,
+    LweGaussianSecretKey32Version, LweGaussianSecretKey64, LweGaussianSecretKey64Version,
+    LweKeyswitchKey32, LweKeyswitchKey32Version, LweKeyswitchKey64, LweKeyswitchKey64Version,
+    LwePackingKeyswitchKey32, LwePackingKeyswitchKey32Version, LwePackingKeyswitchKey64,
+    LwePackingKeyswitchKey64Version, LwePublicKey32, LwePublicKey32Version, LwePublicKey64,
+    LwePublicKey64Version, LweSecretKey32, LweSecretKey32Version, LweSecretKey64,
+    LweSecretKey64Version, LweSeededBootstrapKey32, LweSeededBootstrapKey64, LweSeededCiphertext32,
+    LweSeededCiphertext32Version, LweSeededCiphertext64, LweSeededCiphertext64Version,
+    LweSeededCiphertextVector32, LweSeededCiphertextVector32Version, LweSeededCiphertextVector64,
+    LweSeededCiphertextVector64Version, LweSeededKeyswitchKey32, LweSeededKeyswitchKey64,
+    LweSeededPublicKey32, LweSeededPublicKey32Version, LweSeededPublicKey64,
+    LweSeededPublicKey64Version, LweTernaryBootstrapKey32, LweTernaryBootstrapKey32Version,
+    LweTernaryBootstrapKey64, LweTernaryBootstrapKey64Version, LweTernarySecretKey32,
+    LweTernarySecretKey32Version, LweTernarySecretKey64, LweTernarySecretKey64Version, Plaintext32,
+    Plaintext32Version, Plaintext64, Plaintext64Version, PlaintextVector32,
+    PlaintextVector32Version, PlaintextVector64, PlaintextVector64Version, TernaryKeyKind,
+};
+use serde::Deserialize;
+use std::io::Read;
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a cleartext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, Cleartext32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u32 = 3;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&cleartext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(cleartext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<Cleartext32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableCleartext32 {
+            version: Cleartext32Version,
+            inner: ImplCleartext<u32>,
+        }
+        let (deserialized, parameters): (DeserializableCleartext32, _) =
+            deserialize_from_with_header::<Cleartext32, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableCleartext32 {
+                version: Cleartext32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableCleartext32 {
+                version: Cleartext32Version::V0,
+                inner,
+            } => parameters.verify(Cleartext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> Cleartext32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a cleartext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, Cleartext64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u64 = 3;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&cleartext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(cleartext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<Cleartext64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableCleartext64 {
+            version: Cleartext64Version,
+            inner: ImplCleartext<u64>,
+        }
+        let (deserialized, parameters): (DeserializableCleartext64, _) =
+            deserialize_from_with_header::<Cleartext64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableCleartext64 {
+                version: Cleartext64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableCleartext64 {
+                version: Cleartext64Version::V0,
+                inner,
+            } => parameters.verify(Cleartext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> Cleartext64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a floating point cleartext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, CleartextF64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: f64 = 3.;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&cleartext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(cleartext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<CleartextF64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableCleartextF64 {
+            version: CleartextF64Version,
+            inner: ImplCleartext<f64>,
+        }
+        let (deserialized, parameters): (DeserializableCleartextF64, _) =
+            deserialize_from_with_header::<CleartextF64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableCleartextF64 {
+                version: CleartextF64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableCleartextF64 {
+                version: CleartextF64Version::V0,
+                inner,
+            } => parameters.verify(CleartextF64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> CleartextF64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a cleartext vector entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, CleartextVector32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{CleartextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_u32; 100];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext_vector: CleartextVector32 = engine.create_cleartext_vector_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&cleartext_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(cleartext_vector, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<CleartextVector32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableCleartextVector32 {
+            version: CleartextVector32Version,
+            inner: ImplCleartextList<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableCleartextVector32, _) =
+            deserialize_from_with_header::<CleartextVector32, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableCleartextVector32 {
+                version: CleartextVector32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableCleartextVector32 {
+                version: CleartextVector32Version::V0,
+                inner,
+            } => parameters.verify(CleartextVector32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> CleartextVector32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a cleartext vector entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, CleartextVector64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{CleartextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_u64; 100];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext_vector: CleartextVector64 = engine.create_cleartext_vector_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&cleartext_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(cleartext_vector, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<CleartextVector64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableCleartextVector64 {
+            version: CleartextVector64Version,
+            inner: ImplCleartextList<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableCleartextVector64, _) =
+            deserialize_from_with_header::<CleartextVector64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableCleartextVector64 {
+                version: CleartextVector64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableCleartextVector64 {
+                version: CleartextVector64Version::V0,
+                inner,
+            } => parameters.verify(CleartextVector64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> CleartextVector64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a floating point cleartext vector entity from a
+/// reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, CleartextVectorF64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{CleartextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3.0_f64; 100];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&cleartext_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(cleartext_vector, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<CleartextVectorF64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableCleartextVectorF64 {
+            version: CleartextVectorF64Version,
+            inner: ImplCleartextList<Vec<f64>>,
+        }
+        let (deserialized, parameters): (DeserializableCleartextVectorF64, _) =
+            deserialize_from_with_header::<CleartextVectorF64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableCleartextVectorF64 {
+                version: CleartextVectorF64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableCleartextVectorF64 {
+                version: CleartextVectorF64Version::V0,
+                inner,
+            } => parameters.verify(CleartextVectorF64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> CleartextVectorF64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GGSW ciphertext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GgswCiphertext32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GgswCiphertext32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGgswCiphertext32 {
+            version: GgswCiphertext32Version,
+            inner: ImplStandardGgswCiphertext<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableGgswCiphertext32, _) =
+            deserialize_from_with_header::<GgswCiphertext32, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableGgswCiphertext32 {
+                version: GgswCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGgswCiphertext32 {
+                version: GgswCiphertext32Version::V0,
+                inner,
+            } => parameters.verify(GgswCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GgswCiphertext32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GGSW ciphertext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GgswCiphertext64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GgswCiphertext64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGgswCiphertext64 {
+            version: GgswCiphertext64Version,
+            inner: ImplStandardGgswCiphertext<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableGgswCiphertext64, _) =
+            deserialize_from_with_header::<GgswCiphertext64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableGgswCiphertext64 {
+                version: GgswCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGgswCiphertext64 {
+                version: GgswCiphertext64Version::V0,
+                inner,
+            } => parameters.verify(GgswCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GgswCiphertext64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded GGSW ciphertext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GgswSeededCiphertext32>
+    for DefaultSerializationEngine
+{
+    /// TODO
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GgswSeededCiphertext32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGgswSeededCiphertext32 {
+            version: GgswSeededCiphertext32Version,
+            inner: ImplStandardGgswSeededCiphertext<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableGgswSeededCiphertext32, _) =
+            deserialize_from_with_header::<GgswSeededCiphertext32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableGgswSeededCiphertext32 {
+                version: GgswSeededCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGgswSeededCiphertext32 {
+                version: GgswSeededCiphertext32Version::V0,
+                inner,
+            } => parameters.verify(GgswSeededCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GgswSeededCiphertext32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded GGSW ciphertext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GgswSeededCiphertext64>
+    for DefaultSerializationEngine
+{
+    /// TODO
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GgswSeededCiphertext64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGgswSeededCiphertext64 {
+            version: GgswSeededCiphertext64Version,
+            inner: ImplStandardGgswSeededCiphertext<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableGgswSeededCiphertext64, _) =
+            deserialize_from_with_header::<GgswSeededCiphertext64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableGgswSeededCiphertext64 {
+                version: GgswSeededCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGgswSeededCiphertext64 {
+                version: GgswSeededCiphertext64Version::V0,
+                inner,
+            } => parameters.verify(GgswSeededCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GgswSeededCiphertext64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE ciphertext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweCiphertext32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweCiphertext32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweCiphertext32 {
+            version: GlweCiphertext32Version,
+            inner: ImplGlweCiphertext<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableGlweCiphertext32, _) =
+            deserialize_from_with_header::<GlweCiphertext32, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableGlweCiphertext32 {
+                version: GlweCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweCiphertext32 {
+                version: GlweCiphertext32Version::V0,
+                inner,
+            } => parameters.verify(GlweCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GlweCiphertext32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE ciphertext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweCiphertext64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweCiphertext64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweCiphertext64 {
+            version: GlweCiphertext64Version,
+            inner: ImplGlweCiphertext<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableGlweCiphertext64, _) =
+            deserialize_from_with_header::<GlweCiphertext64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableGlweCiphertext64 {
+                version: GlweCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweCiphertext64 {
+                version: GlweCiphertext64Version::V0,
+                inner,
+            } => parameters.verify(GlweCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GlweCiphertext64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE ciphertext vector entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweCiphertextVector32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweCiphertextVector32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweCiphertextVector32 {
+            version: GlweCiphertextVector32Version,
+            inner: ImplGlweList<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableGlweCiphertextVector32, _) =
+            deserialize_from_with_header::<GlweCiphertextVector32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableGlweCiphertextVector32 {
+                version: GlweCiphertextVector32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweCiphertextVector32 {
+                version: GlweCiphertextVector32Version::V0,
+                inner,
+            } => parameters.verify(GlweCiphertextVector32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GlweCiphertextVector32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE ciphertext vector entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweCiphertextVector64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweCiphertextVector64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweCiphertextVector64 {
+            version: GlweCiphertextVector64Version,
+            inner: ImplGlweList<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableGlweCiphertextVector64, _) =
+            deserialize_from_with_header::<GlweCiphertextVector64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableGlweCiphertextVector64 {
+                version: GlweCiphertextVector64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweCiphertextVector64 {
+                version: GlweCiphertextVector64Version::V0,
+                inner,
+            } => parameters.verify(GlweCiphertextVector64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GlweCiphertextVector64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE secret key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweSecretKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&glwe_secret_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweSecretKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSecretKey32 {
+            version: GlweSecretKey32Version,
+            inner: ImplGlweSecretKey<BinaryKeyKind, Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableGlweSecretKey32, _) =
+            deserialize_from_with_header::<GlweSecretKey32, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableGlweSecretKey32 {
+                version: GlweSecretKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSecretKey32 {
+                version: GlweSecretKey32Version::V0,
+                inner,
+            } => parameters.verify(GlweSecretKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GlweSecretKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE secret key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweSecretKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&glwe_secret_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweSecretKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSecretKey64 {
+            version: GlweSecretKey64Version,
+            inner: ImplGlweSecretKey<BinaryKeyKind, Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableGlweSecretKey64, _) =
+            deserialize_from_with_header::<GlweSecretKey64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableGlweSecretKey64 {
+                version: GlweSecretKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSecretKey64 {
+                version: GlweSecretKey64Version::V0,
+                inner,
+            } => parameters.verify(GlweSecretKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GlweSecretKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE ternary secret key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweTernarySecretKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweTernarySecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&glwe_secret_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweTernarySecretKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweTernarySecretKey32 {
+            version: GlweTernarySecretKey32Version,
+            inner: ImplGlweSecretKey<TernaryKeyKind, Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableGlweTernarySecretKey32, _) =
+            deserialize_from_with_header::<GlweTernarySecretKey32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableGlweTernarySecretKey32 {
+                version: GlweTernarySecretKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweTernarySecretKey32 {
+                version: GlweTernarySecretKey32Version::V0,
+                inner,
+            } => parameters.verify(GlweTernarySecretKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GlweTernarySecretKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE ternary secret key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweTernarySecretKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweTernarySecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&glwe_secret_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweTernarySecretKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweTernarySecretKey64 {
+            version: GlweTernarySecretKey64Version,
+            inner: ImplGlweSecretKey<TernaryKeyKind, Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableGlweTernarySecretKey64, _) =
+            deserialize_from_with_header::<GlweTernarySecretKey64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableGlweTernarySecretKey64 {
+                version: GlweTernarySecretKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweTernarySecretKey64 {
+                version: GlweTernarySecretKey64Version::V0,
+                inner,
+            } => parameters.verify(GlweTernarySecretKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GlweTernarySecretKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE gaussian secret key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweGaussianSecretKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweGaussianSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&glwe_secret_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweGaussianSecretKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweGaussianSecretKey32 {
+            version: GlweGaussianSecretKey32Version,
+            inner: ImplGlweSecretKey<GaussianKeyKind, Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableGlweGaussianSecretKey32, _) =
+            deserialize_from_with_header::<GlweGaussianSecretKey32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableGlweGaussianSecretKey32 {
+                version: GlweGaussianSecretKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweGaussianSecretKey32 {
+                version: GlweGaussianSecretKey32Version::V0,
+                inner,
+            } => parameters.verify(GlweGaussianSecretKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GlweGaussianSecretKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE gaussian secret key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweGaussianSecretKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweGaussianSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&glwe_secret_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweGaussianSecretKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweGaussianSecretKey64 {
+            version: GlweGaussianSecretKey64Version,
+            inner: ImplGlweSecretKey<GaussianKeyKind, Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableGlweGaussianSecretKey64, _) =
+            deserialize_from_with_header::<GlweGaussianSecretKey64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableGlweGaussianSecretKey64 {
+                version: GlweGaussianSecretKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweGaussianSecretKey64 {
+                version: GlweGaussianSecretKey64Version::V0,
+                inner,
+            } => parameters.verify(GlweGaussianSecretKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GlweGaussianSecretKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE seeded ciphertext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweSeededCiphertext32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&seeded_ciphertext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweSeededCiphertext32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSeededCiphertext32 {
+            version: GlweSeededCiphertext32Version,
+            inner: ImplGlweSeededCiphertext<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableGlweSeededCiphertext32, _) =
+            deserialize_from_with_header::<GlweSeededCiphertext32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableGlweSeededCiphertext32 {
+                version: GlweSeededCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSeededCiphertext32 {
+                version: GlweSeededCiphertext32Version::V0,
+                inner,
+            } => parameters.verify(GlweSeededCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GlweSeededCiphertext32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE seeded ciphertext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweSeededCiphertext64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&seeded_ciphertext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweSeededCiphertext64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSeededCiphertext64 {
+            version: GlweSeededCiphertext64Version,
+            inner: ImplGlweSeededCiphertext<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableGlweSeededCiphertext64, _) =
+            deserialize_from_with_header::<GlweSeededCiphertext64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableGlweSeededCiphertext64 {
+                version: GlweSeededCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSeededCiphertext64 {
+                version: GlweSeededCiphertext64Version::V0,
+                inner,
+            } => parameters.verify(GlweSeededCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> GlweSeededCiphertext64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE seeded ciphertext vector entity from a
+/// reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweSeededCiphertextVector32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&seeded_ciphertext_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweSeededCiphertextVector32, EntityStreamDeserializationError<Self::EngineError>>
+    {
+        // The payload is decoded as an enum, laid out like the versioned structures written by
+        // the regular and the compressed serializations.
+        #[derive(Deserialize)]
+        enum DeserializableGlweSeededCiphertextVector32 {
+            V0(ImplGlweSeededList<Vec<u32>>),
+            TruncatedV0(TruncatedGlweSeededCiphertextVector),
+            #[serde(other)]
+            Unsupported,
+        }
+        let (deserialized, parameters): (DeserializableGlweSeededCiphertextVector32, _) =
+            deserialize_from_with_header::<GlweSeededCiphertextVector32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableGlweSeededCiphertextVector32::Unsupported => {
+                Err(EntityStreamDeserializationError::Engine(
+                    DefaultSerializationError::UnsupportedVersion,
+                ))
+            }
+            DeserializableGlweSeededCiphertextVector32::V0(inner) => {
+                parameters.verify(GlweSeededCiphertextVector32(inner))
+            }
+            DeserializableGlweSeededCiphertextVector32::TruncatedV0(compressed) => {
+                let inner = compressed
+                    .into_inner()
+                    .map_err(DefaultSerializationError::Deserialization)
+                    .map_err(EntityStreamDeserializationError::Engine)?;
+                parameters.verify(GlweSeededCiphertextVector32(inner))
+            }
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(
+        &mut self,
+        reader: Reader,
+    ) -> GlweSeededCiphertextVector32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE seeded ciphertext vector entity from a
+/// reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, GlweSeededCiphertextVector64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&seeded_ciphertext_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<GlweSeededCiphertextVector64, EntityStreamDeserializationError<Self::EngineError>>
+    {
+        // The payload is decoded as an enum, laid out like the versioned structures written by
+        // the regular and the compressed serializations.
+        #[derive(Deserialize)]
+        enum DeserializableGlweSeededCiphertextVector64 {
+            V0(ImplGlweSeededList<Vec<u64>>),
+            TruncatedV0(TruncatedGlweSeededCiphertextVector),
+            #[serde(other)]
+            Unsupported,
+        }
+        let (deserialized, parameters): (DeserializableGlweSeededCiphertextVector64, _) =
+            deserialize_from_with_header::<GlweSeededCiphertextVector64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableGlweSeededCiphertextVector64::Unsupported => {
+                Err(EntityStreamDeserializationError::Engine(
+                    DefaultSerializationError::UnsupportedVersion,
+                ))
+            }
+            DeserializableGlweSeededCiphertextVector64::V0(inner) => {
+                parameters.verify(GlweSeededCiphertextVector64(inner))
+            }
+            DeserializableGlweSeededCiphertextVector64::TruncatedV0(truncated) => {
+                let inner = truncated
+                    .into_inner()
+                    .map_err(DefaultSerializationError::Deserialization)
+                    .map_err(EntityStreamDeserializationError::Engine)?;
+                parameters.verify(GlweSeededCiphertextVector64(inner))
+            }
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(
+        &mut self,
+        reader: Reader,
+    ) -> GlweSeededCiphertextVector64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE bootstrap key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweBootstrapKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&bsk, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweBootstrapKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweBootstrapKey32 {
+            version: LweBootstrapKey32Version,
+            inner: ImplStandardBootstrapKey<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableLweBootstrapKey32, _) =
+            deserialize_from_with_header::<LweBootstrapKey32, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLweBootstrapKey32 {
+                version: LweBootstrapKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweBootstrapKey32 {
+                version: LweBootstrapKey32Version::V0,
+                inner,
+            } => parameters.verify(LweBootstrapKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweBootstrapKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE bootstrap key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweBootstrapKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&bsk, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweBootstrapKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweBootstrapKey64 {
+            version: LweBootstrapKey64Version,
+            inner: ImplStandardBootstrapKey<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableLweBootstrapKey64, _) =
+            deserialize_from_with_header::<LweBootstrapKey64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLweBootstrapKey64 {
+                version: LweBootstrapKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweBootstrapKey64 {
+                version: LweBootstrapKey64Version::V0,
+                inner,
+            } => parameters.verify(LweBootstrapKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweBootstrapKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE bootstrap key entity for a ternary input key
+/// from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweTernaryBootstrapKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweTernarySecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&bsk, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweTernaryBootstrapKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweTernaryBootstrapKey32 {
+            version: LweTernaryBootstrapKey32Version,
+            inner: ImplStandardBootstrapKey<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableLweTernaryBootstrapKey32, _) =
+            deserialize_from_with_header::<LweTernaryBootstrapKey32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweTernaryBootstrapKey32 {
+                version: LweTernaryBootstrapKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweTernaryBootstrapKey32 {
+                version: LweTernaryBootstrapKey32Version::V0,
+                inner,
+            } => parameters.verify(LweTernaryBootstrapKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweTernaryBootstrapKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE bootstrap key entity for a ternary input key
+/// from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweTernaryBootstrapKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweTernarySecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&bsk, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweTernaryBootstrapKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweTernaryBootstrapKey64 {
+            version: LweTernaryBootstrapKey64Version,
+            inner: ImplStandardBootstrapKey<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableLweTernaryBootstrapKey64, _) =
+            deserialize_from_with_header::<LweTernaryBootstrapKey64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweTernaryBootstrapKey64 {
+                version: LweTernaryBootstrapKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweTernaryBootstrapKey64 {
+                version: LweTernaryBootstrapKey64Version::V0,
+                inner,
+            } => parameters.verify(LweTernaryBootstrapKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweTernaryBootstrapKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE ciphertext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweCiphertext32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweCiphertext32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweCiphertext32 {
+            version: LweCiphertext32Version,
+            inner: ImplLweCiphertext<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableLweCiphertext32, _) =
+            deserialize_from_with_header::<LweCiphertext32, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLweCiphertext32 {
+                version: LweCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCiphertext32 {
+                version: LweCiphertext32Version::V0,
+                inner,
+            } => parameters.verify(LweCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweCiphertext32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE ciphertext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweCiphertext64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::Variance;
+    /// use concrete_core::prelude::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweCiphertext64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweCiphertext64 {
+            version: LweCiphertext64Version,
+            inner: ImplLweCiphertext<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableLweCiphertext64, _) =
+            deserialize_from_with_header::<LweCiphertext64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLweCiphertext64 {
+                version: LweCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCiphertext64 {
+                version: LweCiphertext64Version::V0,
+                inner,
+            } => parameters.verify(LweCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweCiphertext64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE ciphertext vector entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweCiphertextVector32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweCiphertextVector32, EntityStreamDeserializationError<Self::EngineError>> {
+        // The payload is decoded as an enum, laid out like the versioned structures written by
+        // the regular and the compressed serializations.
+        #[derive(Deserialize)]
+        enum DeserializableLweCiphertextVector32 {
+            V0(ImplLweList<Vec<u32>>),
+            PackedV0(PackedLweCiphertextVector),
+            #[serde(other)]
+            Unsupported,
+        }
+        let (deserialized, parameters): (DeserializableLweCiphertextVector32, _) =
+            deserialize_from_with_header::<LweCiphertextVector32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweCiphertextVector32::Unsupported => {
+                Err(EntityStreamDeserializationError::Engine(
+                    DefaultSerializationError::UnsupportedVersion,
+                ))
+            }
+            DeserializableLweCiphertextVector32::V0(inner) => {
+                parameters.verify(LweCiphertextVector32(inner))
+            }
+            DeserializableLweCiphertextVector32::PackedV0(packed) => {
+                let inner = packed
+                    .into_inner()
+                    .map_err(DefaultSerializationError::Deserialization)
+                    .map_err(EntityStreamDeserializationError::Engine)?;
+                parameters.verify(LweCiphertextVector32(inner))
+            }
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweCiphertextVector32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE ciphertext vector entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweCiphertextVector64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweCiphertextVector64, EntityStreamDeserializationError<Self::EngineError>> {
+        // The payload is decoded as an enum, laid out like the versioned structures written by
+        // the regular and the compressed serializations.
+        #[derive(Deserialize)]
+        enum DeserializableLweCiphertextVector64 {
+            V0(ImplLweList<Vec<u64>>),
+            PackedV0(PackedLweCiphertextVector),
+            #[serde(other)]
+            Unsupported,
+        }
+        let (deserialized, parameters): (DeserializableLweCiphertextVector64, _) =
+            deserialize_from_with_header::<LweCiphertextVector64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweCiphertextVector64::Unsupported => {
+                Err(EntityStreamDeserializationError::Engine(
+                    DefaultSerializationError::UnsupportedVersion,
+                ))
+            }
+            DeserializableLweCiphertextVector64::V0(inner) => {
+                parameters.verify(LweCiphertextVector64(inner))
+            }
+            DeserializableLweCiphertextVector64::PackedV0(compressed) => {
+                let inner = compressed
+                    .into_inner()
+                    .map_err(DefaultSerializationError::Deserialization)
+                    .map_err(EntityStreamDeserializationError::Engine)?;
+                parameters.verify(LweCiphertextVector64(inner))
+            }
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweCiphertextVector64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes an LWE circuit bootstrap private functional
+/// packing keyswitch vector from a reader.
+impl<Reader: Read>
+    EntityStreamDeserializationEngine<
+        Reader,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    > for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let cbs_private_functional_packing_keyswitch_key:
+    ///     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 =
+    ///     engine
+    ///     .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&cbs_private_functional_packing_keyswitch_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(cbs_private_functional_packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        EntityStreamDeserializationError<Self::EngineError>,
+    > {
+        #[derive(Deserialize)]
+        struct DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+            version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
+            inner: ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys<Vec<u32>>,
+        }
+        let (deserialized, parameters): (
+            DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+            _,
+        ) = deserialize_from_with_header::<
+            LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+            _,
+            _,
+        >(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+                version:
+                    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+                version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version::V0,
+                inner,
+            } => parameters.verify(LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(
+                inner,
+            )),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(
+        &mut self,
+        reader: Reader,
+    ) -> LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes an LWE circuit bootstrap private functional
+/// packing keyswitch vector from a reader.
+impl<Reader: Read>
+    EntityStreamDeserializationEngine<
+        Reader,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    > for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let cbs_private_functional_packing_keyswitch_key:
+    ///     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 =
+    ///     engine
+    ///     .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&cbs_private_functional_packing_keyswitch_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(cbs_private_functional_packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        EntityStreamDeserializationError<Self::EngineError>,
+    > {
+        #[derive(Deserialize)]
+        struct DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+            version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version,
+            inner: ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys<Vec<u64>>,
+        }
+        let (deserialized, parameters): (
+            DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+            _,
+        ) = deserialize_from_with_header::<
+            LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+            _,
+            _,
+        >(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+                version:
+                    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+                version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version::V0,
+                inner,
+            } => parameters.verify(LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(
+                inner,
+            )),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(
+        &mut self,
+        reader: Reader,
+    ) -> LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE keyswitch key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweKeyswitchKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&keyswitch_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweKeyswitchKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweKeyswitchKey32 {
+            version: LweKeyswitchKey32Version,
+            inner: ImplLweKeyswitchKey<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableLweKeyswitchKey32, _) =
+            deserialize_from_with_header::<LweKeyswitchKey32, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLweKeyswitchKey32 {
+                version: LweKeyswitchKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweKeyswitchKey32 {
+                version: LweKeyswitchKey32Version::V0,
+                inner,
+            } => parameters.verify(LweKeyswitchKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweKeyswitchKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE keyswitch key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweKeyswitchKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&keyswitch_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweKeyswitchKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweKeyswitchKey64 {
+            version: LweKeyswitchKey64Version,
+            inner: ImplLweKeyswitchKey<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableLweKeyswitchKey64, _) =
+            deserialize_from_with_header::<LweKeyswitchKey64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLweKeyswitchKey64 {
+                version: LweKeyswitchKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweKeyswitchKey64 {
+                version: LweKeyswitchKey64Version::V0,
+                inner,
+            } => parameters.verify(LweKeyswitchKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweKeyswitchKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE secret key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweSecretKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&lwe_secret_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweSecretKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSecretKey32 {
+            version: LweSecretKey32Version,
+            inner: ImplLweSecretKey<BinaryKeyKind, Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableLweSecretKey32, _) =
+            deserialize_from_with_header::<LweSecretKey32, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLweSecretKey32 {
+                version: LweSecretKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSecretKey32 {
+                version: LweSecretKey32Version::V0,
+                inner,
+            } => parameters.verify(LweSecretKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweSecretKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE secret key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweSecretKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&lwe_secret_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweSecretKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSecretKey64 {
+            version: LweSecretKey64Version,
+            inner: ImplLweSecretKey<BinaryKeyKind, Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableLweSecretKey64, _) =
+            deserialize_from_with_header::<LweSecretKey64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLweSecretKey64 {
+                version: LweSecretKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSecretKey64 {
+                version: LweSecretKey64Version::V0,
+                inner,
+            } => parameters.verify(LweSecretKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweSecretKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE ternary secret key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweTernarySecretKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweTernarySecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&lwe_secret_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweTernarySecretKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweTernarySecretKey32 {
+            version: LweTernarySecretKey32Version,
+            inner: ImplLweSecretKey<TernaryKeyKind, Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableLweTernarySecretKey32, _) =
+            deserialize_from_with_header::<LweTernarySecretKey32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweTernarySecretKey32 {
+                version: LweTernarySecretKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweTernarySecretKey32 {
+                version: LweTernarySecretKey32Version::V0,
+                inner,
+            } => parameters.verify(LweTernarySecretKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweTernarySecretKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE ternary secret key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweTernarySecretKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweTernarySecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&lwe_secret_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweTernarySecretKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweTernarySecretKey64 {
+            version: LweTernarySecretKey64Version,
+            inner: ImplLweSecretKey<TernaryKeyKind, Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableLweTernarySecretKey64, _) =
+            deserialize_from_with_header::<LweTernarySecretKey64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweTernarySecretKey64 {
+                version: LweTernarySecretKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweTernarySecretKey64 {
+                version: LweTernarySecretKey64Version::V0,
+                inner,
+            } => parameters.verify(LweTernarySecretKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweTernarySecretKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE gaussian secret key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweGaussianSecretKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweGaussianSecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&lwe_secret_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweGaussianSecretKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweGaussianSecretKey32 {
+            version: LweGaussianSecretKey32Version,
+            inner: ImplLweSecretKey<GaussianKeyKind, Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableLweGaussianSecretKey32, _) =
+            deserialize_from_with_header::<LweGaussianSecretKey32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweGaussianSecretKey32 {
+                version: LweGaussianSecretKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweGaussianSecretKey32 {
+                version: LweGaussianSecretKey32Version::V0,
+                inner,
+            } => parameters.verify(LweGaussianSecretKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweGaussianSecretKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE gaussian secret key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweGaussianSecretKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweGaussianSecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&lwe_secret_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweGaussianSecretKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweGaussianSecretKey64 {
+            version: LweGaussianSecretKey64Version,
+            inner: ImplLweSecretKey<GaussianKeyKind, Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableLweGaussianSecretKey64, _) =
+            deserialize_from_with_header::<LweGaussianSecretKey64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweGaussianSecretKey64 {
+                version: LweGaussianSecretKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweGaussianSecretKey64 {
+                version: LweGaussianSecretKey64Version::V0,
+                inner,
+            } => parameters.verify(LweGaussianSecretKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweGaussianSecretKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded LWE bootstrap key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweSeededBootstrapKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweSeededBootstrapKey32 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&bsk, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    ///
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweSeededBootstrapKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        // The payload is decoded as an enum, laid out like the versioned structures written by
+        // the regular and the compressed serializations.
+        #[derive(Deserialize)]
+        enum DeserializableLweSeededBootstrapKey32 {
+            V0(ImplStandardSeededBootstrapKey<Vec<u32>>),
+            TruncatedV0(TruncatedLweSeededBootstrapKey),
+            #[serde(other)]
+            Unsupported,
+        }
+        let (deserialized, parameters): (DeserializableLweSeededBootstrapKey32, _) =
+            deserialize_from_with_header::<LweSeededBootstrapKey32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweSeededBootstrapKey32::Unsupported => {
+                Err(EntityStreamDeserializationError::Engine(
+                    DefaultSerializationError::UnsupportedVersion,
+                ))
+            }
+            DeserializableLweSeededBootstrapKey32::V0(inner) => {
+                parameters.verify(LweSeededBootstrapKey32(inner))
+            }
+            DeserializableLweSeededBootstrapKey32::TruncatedV0(compressed) => {
+                let inner = compressed
+                    .into_inner()
+                    .map_err(DefaultSerializationError::Deserialization)
+                    .map_err(EntityStreamDeserializationError::Engine)?;
+                parameters.verify(LweSeededBootstrapKey32(inner))
+            }
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweSeededBootstrapKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded LWE bootstrap key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweSeededBootstrapKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweSeededBootstrapKey64 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&bsk, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    ///
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweSeededBootstrapKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        // The payload is decoded as an enum, laid out like the versioned structures written by
+        // the regular and the compressed serializations.
+        #[derive(Deserialize)]
+        enum DeserializableLweSeededBootstrapKey64 {
+            V0(ImplStandardSeededBootstrapKey<Vec<u64>>),
+            TruncatedV0(TruncatedLweSeededBootstrapKey),
+            #[serde(other)]
+            Unsupported,
+        }
+        let (deserialized, parameters): (DeserializableLweSeededBootstrapKey64, _) =
+            deserialize_from_with_header::<LweSeededBootstrapKey64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweSeededBootstrapKey64::Unsupported => {
+                Err(EntityStreamDeserializationError::Engine(
+                    DefaultSerializationError::UnsupportedVersion,
+                ))
+            }
+            DeserializableLweSeededBootstrapKey64::V0(inner) => {
+                parameters.verify(LweSeededBootstrapKey64(inner))
+            }
+            DeserializableLweSeededBootstrapKey64::TruncatedV0(truncated) => {
+                let inner = truncated
+                    .into_inner()
+                    .map_err(DefaultSerializationError::Deserialization)
+                    .map_err(EntityStreamDeserializationError::Engine)?;
+                parameters.verify(LweSeededBootstrapKey64(inner))
+            }
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweSeededBootstrapKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded LWE ciphertext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweSeededCiphertext32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext: LweSeededCiphertext32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweSeededCiphertext32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededCiphertext32 {
+            version: LweSeededCiphertext32Version,
+            inner: ImplLweSeededCiphertext<u32>,
+        }
+        let (deserialized, parameters): (DeserializableLweSeededCiphertext32, _) =
+            deserialize_from_with_header::<LweSeededCiphertext32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweSeededCiphertext32 {
+                version: LweSeededCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededCiphertext32 {
+                version: LweSeededCiphertext32Version::V0,
+                inner,
+            } => parameters.verify(LweSeededCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweSeededCiphertext32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded LWE ciphertext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweSeededCiphertext64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext: LweSeededCiphertext64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweSeededCiphertext64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededCiphertext64 {
+            version: LweSeededCiphertext64Version,
+            inner: ImplLweSeededCiphertext<u64>,
+        }
+        let (deserialized, parameters): (DeserializableLweSeededCiphertext64, _) =
+            deserialize_from_with_header::<LweSeededCiphertext64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweSeededCiphertext64 {
+                version: LweSeededCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededCiphertext64 {
+                version: LweSeededCiphertext64Version::V0,
+                inner,
+            } => parameters.verify(LweSeededCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweSeededCiphertext64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded LWE ciphertext vector entity from a
+/// reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweSeededCiphertextVector32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweSeededCiphertextVector32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweSeededCiphertextVector32, EntityStreamDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededCiphertextVector32 {
+            version: LweSeededCiphertextVector32Version,
+            inner: ImplLweSeededList<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableLweSeededCiphertextVector32, _) =
+            deserialize_from_with_header::<LweSeededCiphertextVector32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweSeededCiphertextVector32 {
+                version: LweSeededCiphertextVector32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededCiphertextVector32 {
+                version: LweSeededCiphertextVector32Version::V0,
+                inner,
+            } => parameters.verify(LweSeededCiphertextVector32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweSeededCiphertextVector32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded LWE ciphertext vector entity from a
+/// reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweSeededCiphertextVector64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweSeededCiphertextVector64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweSeededCiphertextVector64, EntityStreamDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededCiphertextVector64 {
+            version: LweSeededCiphertextVector64Version,
+            inner: ImplLweSeededList<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableLweSeededCiphertextVector64, _) =
+            deserialize_from_with_header::<LweSeededCiphertextVector64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweSeededCiphertextVector64 {
+                version: LweSeededCiphertextVector64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededCiphertextVector64 {
+                version: LweSeededCiphertextVector64Version::V0,
+                inner,
+            } => parameters.verify(LweSeededCiphertextVector64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweSeededCiphertextVector64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded LWE ciphertext keyswitch key entity from
+/// a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweSeededKeyswitchKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&seeded_keyswitch_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(seeded_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweSeededKeyswitchKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        // The payload is decoded as an enum, laid out like the versioned structures written by
+        // the regular and the compressed serializations.
+        #[derive(Deserialize)]
+        enum DeserializableLweSeededKeyswitchKey32 {
+            V0(ImplLweSeededKeyswitchKey<Vec<u32>>),
+            TruncatedV0(TruncatedLweSeededKeyswitchKey),
+            #[serde(other)]
+            Unsupported,
+        }
+        let (deserialized, parameters): (DeserializableLweSeededKeyswitchKey32, _) =
+            deserialize_from_with_header::<LweSeededKeyswitchKey32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweSeededKeyswitchKey32::Unsupported => {
+                Err(EntityStreamDeserializationError::Engine(
+                    DefaultSerializationError::UnsupportedVersion,
+                ))
+            }
+            DeserializableLweSeededKeyswitchKey32::V0(inner) => {
+                parameters.verify(LweSeededKeyswitchKey32(inner))
+            }
+            DeserializableLweSeededKeyswitchKey32::TruncatedV0(compressed) => {
+                let inner = compressed
+                    .into_inner()
+                    .map_err(DefaultSerializationError::Deserialization)
+                    .map_err(EntityStreamDeserializationError::Engine)?;
+                parameters.verify(LweSeededKeyswitchKey32(inner))
+            }
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweSeededKeyswitchKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded LWE ciphertext keyswitch key entity from
+/// a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweSeededKeyswitchKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&seeded_keyswitch_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(seeded_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweSeededKeyswitchKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        // The payload is decoded as an enum, laid out like the versioned structures written by
+        // the regular and the compressed serializations.
+        #[derive(Deserialize)]
+        enum DeserializableLweSeededKeyswitchKey64 {
+            V0(ImplLweSeededKeyswitchKey<Vec<u64>>),
+            TruncatedV0(TruncatedLweSeededKeyswitchKey),
+            #[serde(other)]
+            Unsupported,
+        }
+        let (deserialized, parameters): (DeserializableLweSeededKeyswitchKey64, _) =
+            deserialize_from_with_header::<LweSeededKeyswitchKey64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializableLweSeededKeyswitchKey64::Unsupported => {
+                Err(EntityStreamDeserializationError::Engine(
+                    DefaultSerializationError::UnsupportedVersion,
+                ))
+            }
+            DeserializableLweSeededKeyswitchKey64::V0(inner) => {
+                parameters.verify(LweSeededKeyswitchKey64(inner))
+            }
+            DeserializableLweSeededKeyswitchKey64::TruncatedV0(truncated) => {
+                let inner = truncated
+                    .into_inner()
+                    .map_err(DefaultSerializationError::Deserialization)
+                    .map_err(EntityStreamDeserializationError::Engine)?;
+                parameters.verify(LweSeededKeyswitchKey64(inner))
+            }
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweSeededKeyswitchKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes a packing keyswitch key entity.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LwePackingKeyswitchKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&packing_keyswitch_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LwePackingKeyswitchKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializablePackingKeyswitchKey32 {
+            version: LwePackingKeyswitchKey32Version,
+            inner: ImplLwePackingKeyswitchKey<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializablePackingKeyswitchKey32, _) =
+            deserialize_from_with_header::<LwePackingKeyswitchKey32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializablePackingKeyswitchKey32 {
+                version: LwePackingKeyswitchKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializablePackingKeyswitchKey32 {
+                version: LwePackingKeyswitchKey32Version::V0,
+                inner,
+            } => parameters.verify(LwePackingKeyswitchKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LwePackingKeyswitchKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a packing keyswitch key entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LwePackingKeyswitchKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&packing_keyswitch_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LwePackingKeyswitchKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializablePackingKeyswitchKey64 {
+            version: LwePackingKeyswitchKey64Version,
+            inner: ImplLwePackingKeyswitchKey<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializablePackingKeyswitchKey64, _) =
+            deserialize_from_with_header::<LwePackingKeyswitchKey64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            DeserializablePackingKeyswitchKey64 {
+                version: LwePackingKeyswitchKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializablePackingKeyswitchKey64 {
+                version: LwePackingKeyswitchKey64Version::V0,
+                inner,
+            } => parameters.verify(LwePackingKeyswitchKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LwePackingKeyswitchKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes an LWE public key from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LwePublicKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LwePublicKey32 = engine.generate_new_lwe_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&public_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(public_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LwePublicKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLwePublicKey32 {
+            version: LwePublicKey32Version,
+            inner: ImplLweList<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableLwePublicKey32, _) =
+            deserialize_from_with_header::<LwePublicKey32, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLwePublicKey32 {
+                version: LwePublicKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLwePublicKey32 {
+                version: LwePublicKey32Version::V0,
+                inner,
+            } => parameters.verify(LwePublicKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LwePublicKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes an LWE public key from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LwePublicKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LwePublicKey64 = engine.generate_new_lwe_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&public_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(public_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LwePublicKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLwePublicKey64 {
+            version: LwePublicKey64Version,
+            inner: ImplLweList<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableLwePublicKey64, _) =
+            deserialize_from_with_header::<LwePublicKey64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLwePublicKey64 {
+                version: LwePublicKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLwePublicKey64 {
+                version: LwePublicKey64Version::V0,
+                inner,
+            } => parameters.verify(LwePublicKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LwePublicKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded LWE public key from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweSeededPublicKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let seeded_public_key: LweSeededPublicKey32 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&seeded_public_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(seeded_public_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweSeededPublicKey32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededPublicKey32 {
+            version: LweSeededPublicKey32Version,
+            inner: ImplLweSeededList<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializableLweSeededPublicKey32, _) =
+            deserialize_from_with_header::<LweSeededPublicKey32, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLweSeededPublicKey32 {
+                version: LweSeededPublicKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededPublicKey32 {
+                version: LweSeededPublicKey32Version::V0,
+                inner,
+            } => parameters.verify(LweSeededPublicKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweSeededPublicKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded LWE public key from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, LweSeededPublicKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let seeded_public_key: LweSeededPublicKey64 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&seeded_public_key, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(seeded_public_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<LweSeededPublicKey64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededPublicKey64 {
+            version: LweSeededPublicKey64Version,
+            inner: ImplLweSeededList<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializableLweSeededPublicKey64, _) =
+            deserialize_from_with_header::<LweSeededPublicKey64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableLweSeededPublicKey64 {
+                version: LweSeededPublicKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededPublicKey64 {
+                version: LweSeededPublicKey64Version::V0,
+                inner,
+            } => parameters.verify(LweSeededPublicKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> LweSeededPublicKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a plaintext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, Plaintext32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext32 = engine.create_plaintext_from(&input)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&plaintext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(plaintext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<Plaintext32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializablePlaintext32 {
+            version: Plaintext32Version,
+            inner: ImplPlaintext<u32>,
+        }
+        let (deserialized, parameters): (DeserializablePlaintext32, _) =
+            deserialize_from_with_header::<Plaintext32, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializablePlaintext32 {
+                version: Plaintext32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializablePlaintext32 {
+                version: Plaintext32Version::V0,
+                inner,
+            } => parameters.verify(Plaintext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> Plaintext32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a plaintext entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, Plaintext64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext64 = engine.create_plaintext_from(&input)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&plaintext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(plaintext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<Plaintext64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializablePlaintext64 {
+            version: Plaintext64Version,
+            inner: ImplPlaintext<u64>,
+        }
+        let (deserialized, parameters): (DeserializablePlaintext64, _) =
+            deserialize_from_with_header::<Plaintext64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializablePlaintext64 {
+                version: Plaintext64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializablePlaintext64 {
+                version: Plaintext64Version::V0,
+                inner,
+            } => parameters.verify(Plaintext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> Plaintext64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a plaintext vector entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, PlaintextVector32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{PlaintextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&plaintext_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(plaintext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<PlaintextVector32, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializablePlaintextVector32 {
+            version: PlaintextVector32Version,
+            inner: ImplPlaintextList<Vec<u32>>,
+        }
+        let (deserialized, parameters): (DeserializablePlaintextVector32, _) =
+            deserialize_from_with_header::<PlaintextVector32, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializablePlaintextVector32 {
+                version: PlaintextVector32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializablePlaintextVector32 {
+                version: PlaintextVector32Version::V0,
+                inner,
+            } => parameters.verify(PlaintextVector32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> PlaintextVector32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a plaintext vector entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, PlaintextVector64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{PlaintextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&plaintext_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(plaintext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<PlaintextVector64, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializablePlaintextVector64 {
+            version: PlaintextVector64Version,
+            inner: ImplPlaintextList<Vec<u64>>,
+        }
+        let (deserialized, parameters): (DeserializablePlaintextVector64, _) =
+            deserialize_from_with_header::<PlaintextVector64, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializablePlaintextVector64 {
+                version: PlaintextVector64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializablePlaintextVector64 {
+                version: PlaintextVector64Version::V0,
+                inner,
+            } => parameters.verify(PlaintextVector64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> PlaintextVector64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a float encoder entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, FloatEncoder>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{PlaintextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder = engine.create_encoder_from(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 10.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&encoder, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(encoder, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<FloatEncoder, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableFloatEncoder {
+            version: FloatEncoderVersion,
+            inner: ImplFloatEncoder,
+        }
+        let (deserialized, parameters): (DeserializableFloatEncoder, _) =
+            deserialize_from_with_header::<FloatEncoder, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableFloatEncoder {
+                version: FloatEncoderVersion::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableFloatEncoder {
+                version: FloatEncoderVersion::V0,
+                inner,
+            } => parameters.verify(FloatEncoder(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> FloatEncoder {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a float encoder vector entity from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, FloatEncoderVector>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder_vector = engine.create_encoder_vector_from(&vec![
+    ///     FloatEncoderCenterRadiusConfig {
+    ///         center: 10.,
+    ///         radius: 5.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     1
+    /// ])?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&encoder_vector, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(encoder_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<FloatEncoderVector, EntityStreamDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableFloatEncoderVector {
+            version: FloatEncoderVectorVersion,
+            inner: Vec<ImplFloatEncoder>,
+        }
+        let (deserialized, parameters): (DeserializableFloatEncoderVector, _) =
+            deserialize_from_with_header::<FloatEncoderVector, _, _>(reader, self.accept_legacy)?;
+        match deserialized {
+            DeserializableFloatEncoderVector {
+                version: FloatEncoderVectorVersion::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableFloatEncoderVector {
+                version: FloatEncoderVectorVersion::V0,
+                inner,
+            } => parameters.verify(FloatEncoderVector(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> FloatEncoderVector {
+        self.deserialize_from(reader).unwrap()
+    }
+}
@@ -0,0 +1,681 @@
+use super::compression::{
+    TruncatedGlweSeededCiphertextVector, TruncatedLweSeededBootstrapKey,
+    TruncatedLweSeededKeyswitchKey,
+};
+use crate::commons::serialization::serialize_with_header;
+use crate::prelude::{
+    DefaultSerializationEngine, DefaultSerializationError, EntityTruncatedSerializationEngine,
+    EntityTruncatedSerializationError, GlweSeededCiphertextVector32,
+    GlweSeededCiphertextVector32Version, GlweSeededCiphertextVector64,
+    GlweSeededCiphertextVector64Version, LweSeededBootstrapKey32, LweSeededBootstrapKey32Version,
+    LweSeededBootstrapKey64, LweSeededBootstrapKey64Version, LweSeededKeyswitchKey32,
+    LweSeededKeyswitchKey32Version, LweSeededKeyswitchKey64, LweSeededKeyswitchKey64Version,
+    TruncatedBitCount,
+};
+use serde::Serialize;
+
+/// # Description:
+/// Implementation of [`EntityTruncatedSerializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It serializes a seeded LWE bootstrap key entity with
+/// truncated bodies.
+impl EntityTruncatedSerializationEngine<LweSeededBootstrapKey32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     TruncatedBitCount, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweSeededBootstrapKey32 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let truncated = serialization_engine.serialize_truncated(&bsk, TruncatedBitCount(12))?;
+    /// assert!(truncated.len() < serialized.len() * 3 / 4);
+    ///
+    /// let recovered: LweSeededBootstrapKey32 =
+    ///     serialization_engine.deserialize(truncated.as_slice())?;
+    /// assert_eq!(recovered.decomposition_level_count(), dec_lc);
+    /// assert_eq!(recovered.compression_seed(), bsk.compression_seed());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_truncated(
+        &mut self,
+        entity: &LweSeededBootstrapKey32,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Result<Vec<u8>, EntityTruncatedSerializationError<Self::EngineError>> {
+        EntityTruncatedSerializationError::perform_generic_checks(truncated_bit_count, 32)?;
+        #[derive(Serialize)]
+        struct SerializableLweSeededBootstrapKey32 {
+            version: LweSeededBootstrapKey32Version,
+            inner: TruncatedLweSeededBootstrapKey,
+        }
+        let serializable = SerializableLweSeededBootstrapKey32 {
+            version: LweSeededBootstrapKey32Version::TruncatedV0,
+            inner: TruncatedLweSeededBootstrapKey::new(&entity.0, truncated_bit_count),
+        };
+        serialize_with_header(entity, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntityTruncatedSerializationError::Engine)
+    }
+
+    unsafe fn serialize_truncated_unchecked(
+        &mut self,
+        entity: &LweSeededBootstrapKey32,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Vec<u8> {
+        self.serialize_truncated(entity, truncated_bit_count)
+            .unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityTruncatedSerializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It serializes a seeded LWE bootstrap key entity with
+/// truncated bodies.
+impl EntityTruncatedSerializationEngine<LweSeededBootstrapKey64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     TruncatedBitCount, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweSeededBootstrapKey64 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let truncated = serialization_engine.serialize_truncated(&bsk, TruncatedBitCount(24))?;
+    /// assert!(truncated.len() < serialized.len() * 3 / 4);
+    ///
+    /// let recovered: LweSeededBootstrapKey64 =
+    ///     serialization_engine.deserialize(truncated.as_slice())?;
+    /// assert_eq!(recovered.decomposition_level_count(), dec_lc);
+    /// assert_eq!(recovered.compression_seed(), bsk.compression_seed());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_truncated(
+        &mut self,
+        entity: &LweSeededBootstrapKey64,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Result<Vec<u8>, EntityTruncatedSerializationError<Self::EngineError>> {
+        EntityTruncatedSerializationError::perform_generic_checks(truncated_bit_count, 64)?;
+        #[derive(Serialize)]
+        struct SerializableLweSeededBootstrapKey64 {
+            version: LweSeededBootstrapKey64Version,
+            inner: TruncatedLweSeededBootstrapKey,
+        }
+        let serializable = SerializableLweSeededBootstrapKey64 {
+            version: LweSeededBootstrapKey64Version::TruncatedV0,
+            inner: TruncatedLweSeededBootstrapKey::new(&entity.0, truncated_bit_count),
+        };
+        serialize_with_header(entity, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntityTruncatedSerializationError::Engine)
+    }
+
+    unsafe fn serialize_truncated_unchecked(
+        &mut self,
+        entity: &LweSeededBootstrapKey64,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Vec<u8> {
+        self.serialize_truncated(entity, truncated_bit_count)
+            .unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityTruncatedSerializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It serializes a seeded LWE keyswitch key entity with
+/// truncated bodies.
+impl EntityTruncatedSerializationEngine<LweSeededKeyswitchKey32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, TruncatedBitCount, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let truncated =
+    ///     serialization_engine.serialize_truncated(&seeded_keyswitch_key, TruncatedBitCount(16))?;
+    /// let recovered: LweSeededKeyswitchKey32 =
+    ///     serialization_engine.deserialize(truncated.as_slice())?;
+    /// assert_eq!(recovered.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(recovered.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// // The number of truncated bits must be smaller than the scalar bit width.
+    /// assert!(serialization_engine
+    ///     .serialize_truncated(&seeded_keyswitch_key, TruncatedBitCount(32))
+    ///     .is_err());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_truncated(
+        &mut self,
+        entity: &LweSeededKeyswitchKey32,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Result<Vec<u8>, EntityTruncatedSerializationError<Self::EngineError>> {
+        EntityTruncatedSerializationError::perform_generic_checks(truncated_bit_count, 32)?;
+        #[derive(Serialize)]
+        struct SerializableLweSeededKeyswitchKey32 {
+            version: LweSeededKeyswitchKey32Version,
+            inner: TruncatedLweSeededKeyswitchKey,
+        }
+        let serializable = SerializableLweSeededKeyswitchKey32 {
+            version: LweSeededKeyswitchKey32Version::TruncatedV0,
+            inner: TruncatedLweSeededKeyswitchKey::new(&entity.0, truncated_bit_count),
+        };
+        serialize_with_header(entity, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntityTruncatedSerializationError::Engine)
+    }
+
+    unsafe fn serialize_truncated_unchecked(
+        &mut self,
+        entity: &LweSeededKeyswitchKey32,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Vec<u8> {
+        self.serialize_truncated(entity, truncated_bit_count)
+            .unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityTruncatedSerializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It serializes a seeded LWE keyswitch key entity with
+/// truncated bodies.
+impl EntityTruncatedSerializationEngine<LweSeededKeyswitchKey64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, TruncatedBitCount, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let truncated =
+    ///     serialization_engine.serialize_truncated(&seeded_keyswitch_key, TruncatedBitCount(16))?;
+    /// let recovered: LweSeededKeyswitchKey64 =
+    ///     serialization_engine.deserialize(truncated.as_slice())?;
+    /// assert_eq!(recovered.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(recovered.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// // The number of truncated bits must be smaller than the scalar bit width.
+    /// assert!(serialization_engine
+    ///     .serialize_truncated(&seeded_keyswitch_key, TruncatedBitCount(64))
+    ///     .is_err());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_truncated(
+        &mut self,
+        entity: &LweSeededKeyswitchKey64,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Result<Vec<u8>, EntityTruncatedSerializationError<Self::EngineError>> {
+        EntityTruncatedSerializationError::perform_generic_checks(truncated_bit_count, 64)?;
+        #[derive(Serialize)]
+        struct SerializableLweSeededKeyswitchKey64 {
+            version: LweSeededKeyswitchKey64Version,
+            inner: TruncatedLweSeededKeyswitchKey,
+        }
+        let serializable = SerializableLweSeededKeyswitchKey64 {
+            version: LweSeededKeyswitchKey64Version::TruncatedV0,
+            inner: TruncatedLweSeededKeyswitchKey::new(&entity.0, truncated_bit_count),
+        };
+        serialize_with_header(entity, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntityTruncatedSerializationError::Engine)
+    }
+
+    unsafe fn serialize_truncated_unchecked(
+        &mut self,
+        entity: &LweSeededKeyswitchKey64,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Vec<u8> {
+        self.serialize_truncated(entity, truncated_bit_count)
+            .unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityTruncatedSerializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It serializes a seeded GLWE ciphertext vector entity with
+/// truncated bodies.
+impl EntityTruncatedSerializationEngine<GlweSeededCiphertextVector32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     GlweCiphertextCount, GlweDimension, PolynomialSize, TruncatedBitCount, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-40.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// // The noise lives far above the 8 least significant bits, which can be dropped.
+    /// let truncated =
+    ///     serialization_engine.serialize_truncated(&seeded_ciphertext_vector, TruncatedBitCount(8))?;
+    /// let recovered: GlweSeededCiphertextVector32 =
+    ///     serialization_engine.deserialize(truncated.as_slice())?;
+    /// assert_eq!(recovered.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    ///
+    /// let ciphertext_vector: GlweCiphertextVector32 =
+    ///     engine.transform_glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector(recovered)?;
+    /// let decrypted: PlaintextVector32 =
+    ///     engine.decrypt_glwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// let decoded: Vec<u32> = engine
+    ///     .retrieve_plaintext_vector(&decrypted)?
+    ///     .into_iter()
+    ///     .map(|value| (value.wrapping_add(1 << 19)) >> 20)
+    ///     .collect();
+    /// assert_eq!(decoded, vec![3; 8]);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_truncated(
+        &mut self,
+        entity: &GlweSeededCiphertextVector32,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Result<Vec<u8>, EntityTruncatedSerializationError<Self::EngineError>> {
+        EntityTruncatedSerializationError::perform_generic_checks(truncated_bit_count, 32)?;
+        #[derive(Serialize)]
+        struct SerializableGlweSeededCiphertextVector32 {
+            version: GlweSeededCiphertextVector32Version,
+            inner: TruncatedGlweSeededCiphertextVector,
+        }
+        let serializable = SerializableGlweSeededCiphertextVector32 {
+            version: GlweSeededCiphertextVector32Version::TruncatedV0,
+            inner: TruncatedGlweSeededCiphertextVector::new(&entity.0, truncated_bit_count),
+        };
+        serialize_with_header(entity, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntityTruncatedSerializationError::Engine)
+    }
+
+    unsafe fn serialize_truncated_unchecked(
+        &mut self,
+        entity: &GlweSeededCiphertextVector32,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Vec<u8> {
+        self.serialize_truncated(entity, truncated_bit_count)
+            .unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityTruncatedSerializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It serializes a seeded GLWE ciphertext vector entity with
+/// truncated bodies.
+impl EntityTruncatedSerializationEngine<GlweSeededCiphertextVector64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     GlweCiphertextCount, GlweDimension, PolynomialSize, TruncatedBitCount, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// // The noise lives far above the 32 least significant bits, which can be dropped.
+    /// let truncated =
+    ///     serialization_engine.serialize_truncated(&seeded_ciphertext_vector, TruncatedBitCount(32))?;
+    /// let recovered: GlweSeededCiphertextVector64 =
+    ///     serialization_engine.deserialize(truncated.as_slice())?;
+    /// assert_eq!(recovered.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    ///
+    /// let ciphertext_vector: GlweCiphertextVector64 =
+    ///     engine.transform_glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector(recovered)?;
+    /// let decrypted: PlaintextVector64 =
+    ///     engine.decrypt_glwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// let decoded: Vec<u64> = engine
+    ///     .retrieve_plaintext_vector(&decrypted)?
+    ///     .into_iter()
+    ///     .map(|value| (value.wrapping_add(1 << 49)) >> 50)
+    ///     .collect();
+    /// assert_eq!(decoded, vec![3; 8]);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_truncated(
+        &mut self,
+        entity: &GlweSeededCiphertextVector64,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Result<Vec<u8>, EntityTruncatedSerializationError<Self::EngineError>> {
+        EntityTruncatedSerializationError::perform_generic_checks(truncated_bit_count, 64)?;
+        #[derive(Serialize)]
+        struct SerializableGlweSeededCiphertextVector64 {
+            version: GlweSeededCiphertextVector64Version,
+            inner: TruncatedGlweSeededCiphertextVector,
+        }
+        let serializable = SerializableGlweSeededCiphertextVector64 {
+            version: GlweSeededCiphertextVector64Version::TruncatedV0,
+            inner: TruncatedGlweSeededCiphertextVector::new(&entity.0, truncated_bit_count),
+        };
+        serialize_with_header(entity, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntityTruncatedSerializationError::Engine)
+    }
+
+    unsafe fn serialize_truncated_unchecked(
+        &mut self,
+        entity: &GlweSeededCiphertextVector64,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Vec<u8> {
+        self.serialize_truncated(entity, truncated_bit_count)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod unit_test_truncated_serialization {
+    use crate::commons::test_tools::new_random_generator;
+    use crate::prelude::*;
+    use std::error::Error;
+
+    // Returns the distance between two torus elements.
+    fn torus_distance(a: u64, b: u64) -> u64 {
+        a.wrapping_sub(b).min(b.wrapping_sub(a))
+    }
+
+    // Returns the largest change of a body coefficient caused by the truncation, which rounds it
+    // to the closest multiple of 2^t.
+    fn truncation_error(truncated_bit_count: TruncatedBitCount) -> u64 {
+        1 << (truncated_bit_count.0 - 1)
+    }
+
+    #[test]
+    fn test_truncated_keyswitch_key_keyswitches() -> Result<(), Box<dyn Error>> {
+        // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+        let input_lwe_dimension = LweDimension(600);
+        let output_lwe_dimension = LweDimension(300);
+        let level = DecompositionLevelCount(5);
+        let base_log = DecompositionBaseLog(3);
+        let noise = Variance(2_f64.powf(-80.));
+        let truncated_bit_count = TruncatedBitCount(24);
+        let delta_log = 60;
+
+        const UNSAFE_SECRET: u128 = 0;
+        let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let mut serialization_engine = DefaultSerializationEngine::new(())?;
+        let mut generator = new_random_generator();
+
+        let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+        let output_key: LweSecretKey64 =
+            engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+        let seeded_ksk: LweSeededKeyswitchKey64 = engine.generate_new_lwe_seeded_keyswitch_key(
+            &input_key,
+            &output_key,
+            level,
+            base_log,
+            noise,
+        )?;
+        let serialized =
+            serialization_engine.serialize_truncated(&seeded_ksk, truncated_bit_count)?;
+        let recovered: LweSeededKeyswitchKey64 =
+            serialization_engine.deserialize(serialized.as_slice())?;
+        let ksk: LweKeyswitchKey64 =
+            engine.transform_lwe_seeded_keyswitch_key_to_lwe_keyswitch_key(seeded_ksk)?;
+        let recovered_ksk: LweKeyswitchKey64 =
+            engine.transform_lwe_seeded_keyswitch_key_to_lwe_keyswitch_key(recovered)?;
+
+        // Only the bodies of the key are truncated, so the outputs of both keys differ by the sum
+        // of the decomposed input coefficients, multiplied by the errors of the truncated bodies.
+        let extra_noise_bound = input_lwe_dimension.0 as u64
+            * level.0 as u64
+            * (1 << (base_log.0 - 1))
+            * truncation_error(truncated_bit_count);
+
+        for _ in 0..100 {
+            let message = generator.random_uniform::<u64>() % 16;
+            let plaintext = engine.create_plaintext_from(&(message << delta_log))?;
+            let input = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+            let mut output = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+            let mut recovered_output = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+            engine.discard_keyswitch_lwe_ciphertext(&mut output, &input, &ksk)?;
+            engine.discard_keyswitch_lwe_ciphertext(
+                &mut recovered_output,
+                &input,
+                &recovered_ksk,
+            )?;
+
+            let decrypted = engine.decrypt_lwe_ciphertext(&output_key, &output)?;
+            let decrypted = engine.retrieve_plaintext(&decrypted)?;
+            let recovered_decrypted =
+                engine.decrypt_lwe_ciphertext(&output_key, &recovered_output)?;
+            let recovered_decrypted = engine.retrieve_plaintext(&recovered_decrypted)?;
+            assert!(torus_distance(decrypted, recovered_decrypted) <= extra_noise_bound);
+
+            let decoded =
+                (recovered_decrypted.wrapping_add(1 << (delta_log - 1)) >> delta_log) % 16;
+            assert_eq!(decoded, message);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "backend_fft")]
+    #[test]
+    fn test_truncated_bootstrap_key_bootstraps() -> Result<(), Box<dyn Error>> {
+        // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+        let lwe_dimension = LweDimension(10);
+        let glwe_dimension = GlweDimension(1);
+        let polynomial_size = PolynomialSize(512);
+        let level = DecompositionLevelCount(3);
+        let base_log = DecompositionBaseLog(7);
+        let lwe_noise = Variance(2_f64.powf(-80.));
+        let glwe_noise = Variance(2_f64.powf(-100.));
+        let truncated_bit_count = TruncatedBitCount(24);
+        let message_bits = MessageBitsCount(2);
+        let padding_bits = PaddingBitsCount(1);
+        let delta_log = 64 - message_bits.0 - padding_bits.0;
+
+        const UNSAFE_SECRET: u128 = 0;
+        let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let mut fft_engine = FftEngine::new(())?;
+        let mut serialization_engine = DefaultSerializationEngine::new(())?;
+        let mut generator = new_random_generator();
+
+        let lwe_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+        let glwe_key: GlweSecretKey64 =
+            engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+        let large_lwe_key = engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+        let seeded_bsk: LweSeededBootstrapKey64 = engine.generate_new_lwe_seeded_bootstrap_key(
+            &lwe_key, &glwe_key, base_log, level, glwe_noise,
+        )?;
+        let serialized =
+            serialization_engine.serialize_truncated(&seeded_bsk, truncated_bit_count)?;
+        let recovered: LweSeededBootstrapKey64 =
+            serialization_engine.deserialize(serialized.as_slice())?;
+        let bsk: LweBootstrapKey64 =
+            engine.transform_lwe_seeded_bootstrap_key_to_lwe_bootstrap_key(seeded_bsk)?;
+        let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+        let recovered_bsk: LweBootstrapKey64 =
+            engine.transform_lwe_seeded_bootstrap_key_to_lwe_bootstrap_key(recovered)?;
+        let recovered_bsk: FftFourierLweBootstrapKey64 =
+            fft_engine.convert_lwe_bootstrap_key(&recovered_bsk)?;
+        let accumulator: GlweCiphertext64 = engine.generate_new_glwe_ciphertext_accumulator(
+            &|message: u64| message,
+            message_bits,
+            padding_bits,
+            glwe_dimension,
+            polynomial_size,
+        )?;
+
+        // Each of the n external products of the blind rotation multiplies (k + 1) * l decomposed
+        // polynomials by the errors of the truncated bodies of a GGSW ciphertext.
+        let extra_noise_bound = lwe_dimension.0 as u64
+            * glwe_dimension.to_glwe_size().0 as u64
+            * level.0 as u64
+            * polynomial_size.0 as u64
+            * (1 << (base_log.0 - 1))
+            * truncation_error(truncated_bit_count);
+
+        let mut errors = Vec::new();
+        let mut recovered_errors = Vec::new();
+        for _ in 0..100 {
+            let message = generator.random_uniform::<u64>() % (1 << message_bits.0);
+            let plaintext = engine.create_plaintext_from(&(message << delta_log))?;
+            let input = engine.encrypt_lwe_ciphertext(&lwe_key, &plaintext, lwe_noise)?;
+            for (key, errors) in [(&bsk, &mut errors), (&recovered_bsk, &mut recovered_errors)] {
+                let mut output = engine.zero_encrypt_lwe_ciphertext(&large_lwe_key, lwe_noise)?;
+                fft_engine.discard_bootstrap_lwe_ciphertext(
+                    &mut output,
+                    &input,
+                    &accumulator,
+                    key,
+                )?;
+                let decrypted = engine.decrypt_lwe_ciphertext(&large_lwe_key, &output)?;
+                let decrypted = engine.retrieve_plaintext(&decrypted)?;
+                errors.push(torus_distance(decrypted, message << delta_log));
+            }
+        }
+
+        // The rounding errors of the FFT differ between both keys, hence the slack on the error
+        // of the original key.
+        let max_error = *errors.iter().max().unwrap();
+        for error in recovered_errors {
+            assert!(error <= 2 * max_error + extra_noise_bound);
+            assert!(error < 1 << (delta_log - 1));
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 use crate::prelude::sealed::AbstractEngineSeal;
-use crate::prelude::AbstractEngine;
+use crate::prelude::{
+    AbstractEngine, EntityDeserializationError, EntityStreamDeserializationError,
+};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
@@ -34,7 +36,83 @@ impl Display for DefaultSerializationError {
 
 impl Error for DefaultSerializationError {}
 
-pub struct DefaultSerializationEngine;
+impl From<EntityDeserializationError<bincode::Error>>
+    for EntityStreamDeserializationError<DefaultSerializationError>
+{
+    fn from(error: EntityDeserializationError<bincode::Error>) -> Self {
+        match error {
+            EntityDeserializationError::InvalidHeader => Self::InvalidHeader,
+            EntityDeserializationError::ChecksumMismatch => Self::ChecksumMismatch,
+            EntityDeserializationError::EntityKindMismatch => Self::EntityKindMismatch,
+            EntityDeserializationError::ParameterMismatch => Self::ParameterMismatch,
+            EntityDeserializationError::Engine(error) => {
+                Self::Engine(DefaultSerializationError::Deserialization(error))
+            }
+        }
+    }
+}
+
+/// The serialization engine of the default backend.
+///
+/// Entities are serialized along with a header storing their kind, their precision and their
+/// parameters, followed by a checksum of the serialized bytes. This allows the deserialization
+/// engines to detect corrupted data, as well as data which was not serialized from the requested
+/// entity, and to report it through the generic variants of [`EntityDeserializationError`] and
+/// [`EntityStreamDeserializationError`]. Truncated data is reported as an `UnexpectedEof` I/O
+/// error.
+///
+/// Some entities can also be serialized in a compressed form, through the
+/// [`EntityTruncatedSerializationEngine`](crate::prelude::EntityTruncatedSerializationEngine) and
+/// [`EntityPackedSerializationEngine`](crate::prelude::EntityPackedSerializationEngine) traits.
+/// The regular deserialization engines transparently decode them.
+///
+/// # Legacy entities
+///
+/// Entities serialized by the versions of the library preceding the introduction of the header
+/// only contain the versioned entity, and are rejected by default as
+/// [`EntityDeserializationError::InvalidHeader`]. They can still be loaded after calling
+/// [`accept_legacy_entities`](DefaultSerializationEngine::accept_legacy_entities), in which case
+/// the input which does not start with the header is decoded without any check, and can be
+/// serialized again with a header:
+///
+/// ```
+/// use concrete_core::prelude::*;
+/// # use std::error::Error;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// // A 32 bits cleartext holding 3, as serialized by earlier versions of the library.
+/// let legacy: [u8; 8] = [0, 0, 0, 0, 3, 0, 0, 0];
+///
+/// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+/// let rejected: Result<Cleartext32, _> = serialization_engine.deserialize(&legacy[..]);
+/// assert!(matches!(
+///     rejected,
+///     Err(EntityDeserializationError::InvalidHeader)
+/// ));
+///
+/// serialization_engine.accept_legacy_entities(true);
+/// let cleartext: Cleartext32 = serialization_engine.deserialize(&legacy[..])?;
+/// let migrated = serialization_engine.serialize(&cleartext)?;
+///
+/// serialization_engine.accept_legacy_entities(false);
+/// let recovered: Cleartext32 = serialization_engine.deserialize(migrated.as_slice())?;
+/// assert_eq!(recovered, cleartext);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct DefaultSerializationEngine {
+    accept_legacy: bool,
+}
+
+impl DefaultSerializationEngine {
+    /// Sets whether the deserialization engines accept legacy entities, serialized without
+    /// header. Since such entities carry neither a checksum nor a description, corrupted data or
+    /// data serialized from another entity may not be detected.
+    pub fn accept_legacy_entities(&mut self, accept: bool) {
+        self.accept_legacy = accept;
+    }
+}
 
 impl AbstractEngineSeal for DefaultSerializationEngine {}
 
@@ -46,9 +124,17 @@ impl AbstractEngine for DefaultSerializationEngine {
     where
         Self: Sized,
     {
-        Ok(DefaultSerializationEngine)
+        Ok(DefaultSerializationEngine {
+            accept_legacy: false,
+        })
     }
 }
 
+mod compression;
+mod entity_description;
 mod entity_deserialization;
+mod entity_packed_serialization;
 mod entity_serialization;
+mod entity_stream_deserialization;
+mod entity_stream_serialization;
+mod entity_truncated_serialization;
@@ -0,0 +1,236 @@
+use crate::commons::crypto::ggsw::GgswList as ImplGgswList;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    PolynomialSize,
+};
+use crate::specification::entities::markers::GgswCiphertextVectorKind;
+use crate::specification::entities::{AbstractEntity, GgswCiphertextVectorEntity};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a vector of GGSW ciphertexts with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GgswCiphertextVector32(pub(crate) ImplGgswList<Vec<u32>>);
+
+impl AbstractEntity for GgswCiphertextVector32 {
+    type Kind = GgswCiphertextVectorKind;
+}
+
+impl GgswCiphertextVectorEntity for GgswCiphertextVector32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        GgswCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GgswCiphertextVector32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a vector of GGSW ciphertexts with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GgswCiphertextVector64(pub(crate) ImplGgswList<Vec<u64>>);
+
+impl AbstractEntity for GgswCiphertextVector64 {
+    type Kind = GgswCiphertextVectorKind;
+}
+
+impl GgswCiphertextVectorEntity for GgswCiphertextVector64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        GgswCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GgswCiphertextVector64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+// GgswCiphertextVectorViews are just GgswCiphertextVector entities that do not own their memory,
+// they use a slice as a container as opposed to Vec for the standard GgswCiphertextVector
+
+/// A structure representing a vector of GGSW ciphertext views, with 32 bits of precision.
+///
+/// By _view_ here, we mean that the entity does not own the data, but immutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Immutable variant.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GgswCiphertextVectorView32<'a>(pub(crate) ImplGgswList<&'a [u32]>);
+
+impl AbstractEntity for GgswCiphertextVectorView32<'_> {
+    type Kind = GgswCiphertextVectorKind;
+}
+
+impl GgswCiphertextVectorEntity for GgswCiphertextVectorView32<'_> {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        GgswCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+/// A structure representing a vector of GGSW ciphertext views, with 32 bits of precision.
+///
+/// By _view_ here, we mean that the entity does not own the data, but mutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Mutable variant.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GgswCiphertextVectorMutView32<'a>(pub(crate) ImplGgswList<&'a mut [u32]>);
+
+impl AbstractEntity for GgswCiphertextVectorMutView32<'_> {
+    type Kind = GgswCiphertextVectorKind;
+}
+
+impl GgswCiphertextVectorEntity for GgswCiphertextVectorMutView32<'_> {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        GgswCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+/// A structure representing a vector of GGSW ciphertext views, with 64 bits of precision.
+///
+/// By _view_ here, we mean that the entity does not own the data, but immutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Immutable variant.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GgswCiphertextVectorView64<'a>(pub(crate) ImplGgswList<&'a [u64]>);
+
+impl AbstractEntity for GgswCiphertextVectorView64<'_> {
+    type Kind = GgswCiphertextVectorKind;
+}
+
+impl GgswCiphertextVectorEntity for GgswCiphertextVectorView64<'_> {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        GgswCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+/// A structure representing a vector of GGSW ciphertext views, with 64 bits of precision.
+///
+/// By _view_ here, we mean that the entity does not own the data, but mutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Mutable variant.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GgswCiphertextVectorMutView64<'a>(pub(crate) ImplGgswList<&'a mut [u64]>);
+
+impl AbstractEntity for GgswCiphertextVectorMutView64<'_> {
+    type Kind = GgswCiphertextVectorKind;
+}
+
+impl GgswCiphertextVectorEntity for GgswCiphertextVectorMutView64<'_> {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        GgswCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
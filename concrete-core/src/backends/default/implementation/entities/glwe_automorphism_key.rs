@@ -0,0 +1,54 @@
+use crate::commons::crypto::glwe::GlweAutomorphismKey as ImplGlweAutomorphismKey;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::GlweAutomorphismKeyKind;
+use crate::specification::entities::{AbstractEntity, GlweAutomorphismKeyEntity};
+
+/// A structure representing a GLWE automorphism key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweAutomorphismKey32(pub(crate) ImplGlweAutomorphismKey<Vec<u32>>);
+impl AbstractEntity for GlweAutomorphismKey32 {
+    type Kind = GlweAutomorphismKeyKind;
+}
+impl GlweAutomorphismKeyEntity for GlweAutomorphismKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+}
+
+/// A structure representing a GLWE automorphism key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweAutomorphismKey64(pub(crate) ImplGlweAutomorphismKey<Vec<u64>>);
+impl AbstractEntity for GlweAutomorphismKey64 {
+    type Kind = GlweAutomorphismKeyKind;
+}
+impl GlweAutomorphismKeyEntity for GlweAutomorphismKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+}
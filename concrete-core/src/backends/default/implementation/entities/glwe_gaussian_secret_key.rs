@@ -0,0 +1,54 @@
+use crate::commons::crypto::secret::GlweSecretKey as ImpGlweSecretKey;
+use crate::prelude::{GaussianKeyKind, GlweDimension, PolynomialSize};
+use crate::specification::entities::markers::GlweSecretKeyKind;
+use crate::specification::entities::{AbstractEntity, GlweSecretKeyEntity};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a GLWE gaussian secret key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweGaussianSecretKey32(pub(crate) ImpGlweSecretKey<GaussianKeyKind, Vec<u32>>);
+impl AbstractEntity for GlweGaussianSecretKey32 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweGaussianSecretKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweGaussianSecretKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a GLWE gaussian secret key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweGaussianSecretKey64(pub(crate) ImpGlweSecretKey<GaussianKeyKind, Vec<u64>>);
+impl AbstractEntity for GlweGaussianSecretKey64 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweGaussianSecretKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweGaussianSecretKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -0,0 +1,54 @@
+use crate::commons::crypto::glwe::GlweRelinearizationKey as ImplGlweRelinearizationKey;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::GlweRelinearizationKeyKind;
+use crate::specification::entities::{AbstractEntity, GlweRelinearizationKeyEntity};
+
+/// A structure representing a GLWE relinearization key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweRelinearizationKey32(pub(crate) ImplGlweRelinearizationKey<Vec<u32>>);
+impl AbstractEntity for GlweRelinearizationKey32 {
+    type Kind = GlweRelinearizationKeyKind;
+}
+impl GlweRelinearizationKeyEntity for GlweRelinearizationKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+/// A structure representing a GLWE relinearization key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweRelinearizationKey64(pub(crate) ImplGlweRelinearizationKey<Vec<u64>>);
+impl AbstractEntity for GlweRelinearizationKey64 {
+    type Kind = GlweRelinearizationKeyKind;
+}
+impl GlweRelinearizationKeyEntity for GlweRelinearizationKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
@@ -34,6 +34,7 @@ impl GlweSeededCiphertextVectorEntity for GlweSeededCiphertextVector32 {
 #[derive(Serialize, Deserialize)]
 pub(crate) enum GlweSeededCiphertextVector32Version {
     V0,
+    TruncatedV0,
     #[serde(other)]
     Unsupported,
 }
@@ -66,6 +67,7 @@ impl GlweSeededCiphertextVectorEntity for GlweSeededCiphertextVector64 {
 #[derive(Serialize, Deserialize)]
 pub(crate) enum GlweSeededCiphertextVector64Version {
     V0,
+    TruncatedV0,
     #[serde(other)]
     Unsupported,
 }
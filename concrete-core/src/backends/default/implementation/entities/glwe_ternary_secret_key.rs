@@ -0,0 +1,54 @@
+use crate::commons::crypto::secret::GlweSecretKey as ImpGlweSecretKey;
+use crate::prelude::{GlweDimension, PolynomialSize, TernaryKeyKind};
+use crate::specification::entities::markers::GlweSecretKeyKind;
+use crate::specification::entities::{AbstractEntity, GlweSecretKeyEntity};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a GLWE ternary secret key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweTernarySecretKey32(pub(crate) ImpGlweSecretKey<TernaryKeyKind, Vec<u32>>);
+impl AbstractEntity for GlweTernarySecretKey32 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweTernarySecretKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweTernarySecretKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a GLWE ternary secret key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweTernarySecretKey64(pub(crate) ImpGlweSecretKey<TernaryKeyKind, Vec<u64>>);
+impl AbstractEntity for GlweTernarySecretKey64 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweTernarySecretKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweTernarySecretKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -27,6 +27,7 @@ impl LweCiphertextVectorEntity for LweCiphertextVector32 {
 #[derive(Serialize, Deserialize)]
 pub(crate) enum LweCiphertextVector32Version {
     V0,
+    PackedV0,
     #[serde(other)]
     Unsupported,
 }
@@ -53,6 +54,7 @@ impl LweCiphertextVectorEntity for LweCiphertextVector64 {
 #[derive(Serialize, Deserialize)]
 pub(crate) enum LweCiphertextVector64Version {
     V0,
+    PackedV0,
     #[serde(other)]
     Unsupported,
 }
@@ -0,0 +1,46 @@
+use crate::commons::crypto::secret::LweSecretKey as ImpLweSecretKey;
+use crate::prelude::{GaussianKeyKind, LweDimension};
+use crate::specification::entities::markers::LweSecretKeyKind;
+use crate::specification::entities::{AbstractEntity, LweSecretKeyEntity};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing an LWE gaussian secret key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweGaussianSecretKey32(pub(crate) ImpLweSecretKey<GaussianKeyKind, Vec<u32>>);
+impl AbstractEntity for LweGaussianSecretKey32 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweGaussianSecretKey32 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweGaussianSecretKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing an LWE gaussian secret key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweGaussianSecretKey64(pub(crate) ImpLweSecretKey<GaussianKeyKind, Vec<u64>>);
+impl AbstractEntity for LweGaussianSecretKey64 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweGaussianSecretKey64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweGaussianSecretKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -44,6 +44,7 @@ impl LweSeededBootstrapKeyEntity for LweSeededBootstrapKey32 {
 #[derive(Serialize, Deserialize)]
 pub(crate) enum LweSeededBootstrapKey32Version {
     V0,
+    TruncatedV0,
     #[serde(other)]
     Unsupported,
 }
@@ -84,6 +85,7 @@ impl LweSeededBootstrapKeyEntity for LweSeededBootstrapKey64 {
 #[derive(Serialize, Deserialize)]
 pub(crate) enum LweSeededBootstrapKey64Version {
     V0,
+    TruncatedV0,
     #[serde(other)]
     Unsupported,
 }
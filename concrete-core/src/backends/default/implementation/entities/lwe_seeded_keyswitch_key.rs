@@ -38,6 +38,7 @@ impl LweSeededKeyswitchKeyEntity for LweSeededKeyswitchKey32 {
 #[derive(Serialize, Deserialize)]
 pub(crate) enum LweSeededKeyswitchKey32Version {
     V0,
+    TruncatedV0,
     #[serde(other)]
     Unsupported,
 }
@@ -74,6 +75,7 @@ impl LweSeededKeyswitchKeyEntity for LweSeededKeyswitchKey64 {
 #[derive(Serialize, Deserialize)]
 pub(crate) enum LweSeededKeyswitchKey64Version {
     V0,
+    TruncatedV0,
     #[serde(other)]
     Unsupported,
 }
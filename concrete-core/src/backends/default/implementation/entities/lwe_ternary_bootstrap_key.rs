@@ -0,0 +1,244 @@
+use crate::commons::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::LweBootstrapKeyKind;
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing an LWE bootstrap key for a ternary input key, with 32 bits of
+/// precision.
+///
+/// Each coefficient of the input key is encrypted in two GGSW ciphertexts, the first one encrypting
+/// whether it is equal to one, and the second one whether it is equal to minus one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernaryBootstrapKey32(pub(crate) ImplStandardBootstrapKey<Vec<u32>>);
+impl AbstractEntity for LweTernaryBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweTernaryBootstrapKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweTernaryBootstrapKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing an LWE bootstrap key for a ternary input key, with 64 bits of
+/// precision.
+///
+/// Each coefficient of the input key is encrypted in two GGSW ciphertexts, the first one encrypting
+/// whether it is equal to one, and the second one whether it is equal to minus one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernaryBootstrapKey64(pub(crate) ImplStandardBootstrapKey<Vec<u64>>);
+impl AbstractEntity for LweTernaryBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweTernaryBootstrapKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweTernaryBootstrapKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing an LWE bootstrap key for a ternary input key, with 32 bits of
+/// precision.
+///
+/// Each coefficient of the input key is encrypted in two GGSW ciphertexts, the first one encrypting
+/// whether it is equal to one, and the second one whether it is equal to minus one.
+///
+/// By _view_ here, we mean that the entity does not own the data, but mutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Mutable variant.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LweBootstrapKeyMutView32<'a>(pub(crate) ImplStandardBootstrapKey<&'a mut [u32]>);
+impl AbstractEntity for LweBootstrapKeyMutView32<'_> {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweBootstrapKeyMutView32<'_> {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key for a ternary input key, with 64 bits of
+/// precision.
+///
+/// Each coefficient of the input key is encrypted in two GGSW ciphertexts, the first one encrypting
+/// whether it is equal to one, and the second one whether it is equal to minus one.
+///
+/// By _view_ here, we mean that the entity does not own the data, but mutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Mutable variant.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LweBootstrapKeyMutView64<'a>(pub(crate) ImplStandardBootstrapKey<&'a mut [u64]>);
+impl AbstractEntity for LweBootstrapKeyMutView64<'_> {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweBootstrapKeyMutView64<'_> {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key for a ternary input key, with 32 bits of
+/// precision.
+///
+/// Each coefficient of the input key is encrypted in two GGSW ciphertexts, the first one encrypting
+/// whether it is equal to one, and the second one whether it is equal to minus one.
+///
+/// By _view_ here, we mean that the entity does not own the data, but immutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Immutable variant.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LweBootstrapKeyView32<'a>(pub(crate) ImplStandardBootstrapKey<&'a [u32]>);
+impl AbstractEntity for LweBootstrapKeyView32<'_> {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweBootstrapKeyView32<'_> {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key for a ternary input key, with 64 bits of
+/// precision.
+///
+/// Each coefficient of the input key is encrypted in two GGSW ciphertexts, the first one encrypting
+/// whether it is equal to one, and the second one whether it is equal to minus one.
+///
+/// By _view_ here, we mean that the entity does not own the data, but immutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Immutable variant.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LweBootstrapKeyView64<'a>(pub(crate) ImplStandardBootstrapKey<&'a [u64]>);
+impl AbstractEntity for LweBootstrapKeyView64<'_> {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweBootstrapKeyView64<'_> {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
@@ -0,0 +1,46 @@
+use crate::commons::crypto::secret::LweSecretKey as ImpLweSecretKey;
+use crate::prelude::{LweDimension, TernaryKeyKind};
+use crate::specification::entities::markers::LweSecretKeyKind;
+use crate::specification::entities::{AbstractEntity, LweSecretKeyEntity};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing an LWE ternary secret key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernarySecretKey32(pub(crate) ImpLweSecretKey<TernaryKeyKind, Vec<u32>>);
+impl AbstractEntity for LweTernarySecretKey32 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweTernarySecretKey32 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweTernarySecretKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing an LWE ternary secret key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernarySecretKey64(pub(crate) ImpLweSecretKey<TernaryKeyKind, Vec<u64>>);
+impl AbstractEntity for LweTernarySecretKey64 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweTernarySecretKey64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweTernarySecretKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -6,17 +6,25 @@ mod cleartext_vector;
 mod encoder;
 mod encoder_vector;
 mod ggsw_ciphertext;
+mod ggsw_ciphertext_vector;
 mod ggsw_seeded_ciphertext;
+mod glwe_automorphism_key;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
+mod glwe_gaussian_secret_key;
+mod glwe_keyswitch_key;
+mod glwe_relinearization_key;
 mod glwe_secret_key;
 mod glwe_seeded_ciphertext;
 mod glwe_seeded_ciphertext_vector;
+mod glwe_seeded_keyswitch_key;
+mod glwe_ternary_secret_key;
 mod gsw_ciphertext;
 mod lwe_bootstrap_key;
 mod lwe_ciphertext;
 mod lwe_ciphertext_vector;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys;
+mod lwe_gaussian_secret_key;
 mod lwe_keyswitch_key;
 mod lwe_packing_keyswitch_key;
 mod lwe_private_functional_packing_keyswitch_key;
@@ -26,6 +34,9 @@ mod lwe_seeded_bootstrap_key;
 mod lwe_seeded_ciphertext;
 mod lwe_seeded_ciphertext_vector;
 mod lwe_seeded_keyswitch_key;
+mod lwe_seeded_public_key;
+mod lwe_ternary_bootstrap_key;
+mod lwe_ternary_secret_key;
 mod plaintext;
 mod plaintext_vector;
 
@@ -34,17 +45,25 @@ pub use cleartext_vector::*;
 pub use encoder::*;
 pub use encoder_vector::*;
 pub use ggsw_ciphertext::*;
+pub use ggsw_ciphertext_vector::*;
 pub use ggsw_seeded_ciphertext::*;
+pub use glwe_automorphism_key::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
+pub use glwe_gaussian_secret_key::*;
+pub use glwe_keyswitch_key::*;
+pub use glwe_relinearization_key::*;
 pub use glwe_secret_key::*;
 pub use glwe_seeded_ciphertext::*;
 pub use glwe_seeded_ciphertext_vector::*;
+pub use glwe_seeded_keyswitch_key::*;
+pub use glwe_ternary_secret_key::*;
 pub use gsw_ciphertext::*;
 pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
 pub use lwe_ciphertext_vector::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys::*;
+pub use lwe_gaussian_secret_key::*;
 pub use lwe_keyswitch_key::*;
 pub use lwe_packing_keyswitch_key::*;
 pub use lwe_private_functional_packing_keyswitch_key::*;
@@ -54,5 +73,8 @@ pub use lwe_seeded_bootstrap_key::*;
 pub use lwe_seeded_ciphertext::*;
 pub use lwe_seeded_ciphertext_vector::*;
 pub use lwe_seeded_keyswitch_key::*;
+pub use lwe_seeded_public_key::*;
+pub use lwe_ternary_bootstrap_key::*;
+pub use lwe_ternary_secret_key::*;
 pub use plaintext::*;
 pub use plaintext_vector::*;
@@ -0,0 +1,220 @@
+use super::{FftEngine, FftError};
+use crate::backends::default::engines::ActivatedRandomGenerator;
+use crate::backends::fft::entities::{FftFourierGgswCiphertext32, FftFourierGgswCiphertext64};
+use crate::backends::fft::private::crypto::ggsw::{
+    fill_with_forward_fourier_from_seeded_scratch, FourierGgswCiphertext,
+};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::prelude::{GgswSeededCiphertext32, GgswSeededCiphertext64};
+use crate::specification::engines::{
+    GgswSeededCiphertextConversionEngine, GgswSeededCiphertextConversionError,
+};
+use crate::specification::entities::GgswSeededCiphertextEntity;
+use aligned_vec::avec;
+use concrete_fft::c64;
+
+impl From<FftError> for GgswSeededCiphertextConversionError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GgswSeededCiphertextConversionEngine`] for [`FftEngine`] that operates on
+/// 32 bit integers. It expands a seeded GGSW ciphertext directly into the Fourier domain, one
+/// GLWE row at a time, without allocating the whole standard GGSW ciphertext.
+impl GgswSeededCiphertextConversionEngine<GgswSeededCiphertext32, FftFourierGgswCiphertext32>
+    for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a seeded GGSW ciphertext in the standard domain
+    /// let seeded_ciphertext: GgswSeededCiphertext32 = default_engine
+    ///     .encrypt_scalar_ggsw_seeded_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we expand it directly in the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext32 =
+    ///     fft_engine.convert_ggsw_seeded_ciphertext(&seeded_ciphertext)?;
+    ///
+    /// assert_eq!(fourier_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(fourier_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(fourier_ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(fourier_ciphertext.decomposition_level_count(), level);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_seeded_ciphertext(
+        &mut self,
+        input: &GgswSeededCiphertext32,
+    ) -> Result<FftFourierGgswCiphertext32, GgswSeededCiphertextConversionError<Self::EngineError>>
+    {
+        FftError::perform_fft_checks(input.polynomial_size())?;
+        Ok(unsafe { self.convert_ggsw_seeded_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_seeded_ciphertext_unchecked(
+        &mut self,
+        input: &GgswSeededCiphertext32,
+    ) -> FftFourierGgswCiphertext32 {
+        let glwe_size = input.glwe_dimension().to_glwe_size();
+        let polynomial_size = input.polynomial_size();
+        let mut output = FourierGgswCiphertext::new(
+            avec![
+                c64::default();
+                (polynomial_size.0
+                    * glwe_size.0
+                    * glwe_size.0
+                    * input.decomposition_level_count().0)
+                    / 2
+            ]
+            .into_boxed_slice(),
+            polynomial_size,
+            glwe_size,
+            input.decomposition_base_log(),
+            input.decomposition_level_count(),
+        );
+
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_from_seeded_scratch::<u32>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier_from_seeded::<_, ActivatedRandomGenerator>(
+                input.0.as_view(),
+                fft,
+                self.stack(),
+            );
+        FftFourierGgswCiphertext32(output)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GgswSeededCiphertextConversionEngine`] for [`FftEngine`] that operates on
+/// 64 bit integers. It expands a seeded GGSW ciphertext directly into the Fourier domain, one
+/// GLWE row at a time, without allocating the whole standard GGSW ciphertext.
+impl GgswSeededCiphertextConversionEngine<GgswSeededCiphertext64, FftFourierGgswCiphertext64>
+    for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a seeded GGSW ciphertext in the standard domain
+    /// let seeded_ciphertext: GgswSeededCiphertext64 = default_engine
+    ///     .encrypt_scalar_ggsw_seeded_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we expand it directly in the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext64 =
+    ///     fft_engine.convert_ggsw_seeded_ciphertext(&seeded_ciphertext)?;
+    ///
+    /// assert_eq!(fourier_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(fourier_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(fourier_ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(fourier_ciphertext.decomposition_level_count(), level);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_seeded_ciphertext(
+        &mut self,
+        input: &GgswSeededCiphertext64,
+    ) -> Result<FftFourierGgswCiphertext64, GgswSeededCiphertextConversionError<Self::EngineError>>
+    {
+        FftError::perform_fft_checks(input.polynomial_size())?;
+        Ok(unsafe { self.convert_ggsw_seeded_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_seeded_ciphertext_unchecked(
+        &mut self,
+        input: &GgswSeededCiphertext64,
+    ) -> FftFourierGgswCiphertext64 {
+        let glwe_size = input.glwe_dimension().to_glwe_size();
+        let polynomial_size = input.polynomial_size();
+        let mut output = FourierGgswCiphertext::new(
+            avec![
+                c64::default();
+                (polynomial_size.0
+                    * glwe_size.0
+                    * glwe_size.0
+                    * input.decomposition_level_count().0)
+                    / 2
+            ]
+            .into_boxed_slice(),
+            polynomial_size,
+            glwe_size,
+            input.decomposition_base_log(),
+            input.decomposition_level_count(),
+        );
+
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_from_seeded_scratch::<u64>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier_from_seeded::<_, ActivatedRandomGenerator>(
+                input.0.as_view(),
+                fft,
+                self.stack(),
+            );
+        FftFourierGgswCiphertext64(output)
+    }
+}
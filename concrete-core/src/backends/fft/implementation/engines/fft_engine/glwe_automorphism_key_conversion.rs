@@ -0,0 +1,225 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::glwe_automorphism::{
+    fill_with_forward_fourier_scratch, FourierGlweAutomorphismKey,
+};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::prelude::{
+    FftFourierGlweAutomorphismKey32, FftFourierGlweAutomorphismKey64, GlweAutomorphismKey32,
+    GlweAutomorphismKey64, GlweAutomorphismKeyConversionEngine, GlweAutomorphismKeyConversionError,
+};
+use aligned_vec::avec;
+use concrete_fft::c64;
+
+impl From<FftError> for GlweAutomorphismKeyConversionError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweAutomorphismKeyConversionEngine`] for [`FftEngine`] that operates on
+/// 32 bit integers. It converts a GLWE automorphism key from the standard to the Fourier domain.
+impl GlweAutomorphismKeyConversionEngine<GlweAutomorphismKey32, FftFourierGlweAutomorphismKey32>
+    for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey32 = default_engine
+    ///     .generate_new_glwe_automorphism_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let fourier_automorphism_key: FftFourierGlweAutomorphismKey32 =
+    ///     fft_engine.convert_glwe_automorphism_key(&automorphism_key)?;
+    /// #
+    /// assert_eq!(fourier_automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(fourier_automorphism_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     fourier_automorphism_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    /// assert_eq!(
+    ///     fourier_automorphism_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_automorphism_key(
+        &mut self,
+        input: &GlweAutomorphismKey32,
+    ) -> Result<
+        FftFourierGlweAutomorphismKey32,
+        GlweAutomorphismKeyConversionError<Self::EngineError>,
+    > {
+        FftError::perform_fft_checks(input.0.polynomial_size())?;
+        Ok(unsafe { self.convert_glwe_automorphism_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_automorphism_key_unchecked(
+        &mut self,
+        input: &GlweAutomorphismKey32,
+    ) -> FftFourierGlweAutomorphismKey32 {
+        let glwe_size = input.0.glwe_dimension().to_glwe_size();
+
+        let boxed = avec![
+            c64::default();
+            input.0.keyswitch_key_count()
+                * input.0.polynomial_size().0 / 2
+                * input.0.glwe_dimension().0
+                * input.0.level_count().0
+                * glwe_size.0
+        ]
+        .into_boxed_slice();
+        let fft = Fft::new(input.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+
+        let mut output = FourierGlweAutomorphismKey::new(
+            boxed,
+            input.0.polynomial_size(),
+            glwe_size,
+            input.0.base_log(),
+            input.0.level_count(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier(input.0.as_view(), fft, stack);
+        FftFourierGlweAutomorphismKey32(output)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweAutomorphismKeyConversionEngine`] for [`FftEngine`] that operates on
+/// 64 bit integers. It converts a GLWE automorphism key from the standard to the Fourier domain.
+impl GlweAutomorphismKeyConversionEngine<GlweAutomorphismKey64, FftFourierGlweAutomorphismKey64>
+    for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let decomposition_base_log = DecompositionBaseLog(10);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey64 = default_engine
+    ///     .generate_new_glwe_automorphism_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let fourier_automorphism_key: FftFourierGlweAutomorphismKey64 =
+    ///     fft_engine.convert_glwe_automorphism_key(&automorphism_key)?;
+    /// #
+    /// assert_eq!(fourier_automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(fourier_automorphism_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     fourier_automorphism_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    /// assert_eq!(
+    ///     fourier_automorphism_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_automorphism_key(
+        &mut self,
+        input: &GlweAutomorphismKey64,
+    ) -> Result<
+        FftFourierGlweAutomorphismKey64,
+        GlweAutomorphismKeyConversionError<Self::EngineError>,
+    > {
+        FftError::perform_fft_checks(input.0.polynomial_size())?;
+        Ok(unsafe { self.convert_glwe_automorphism_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_automorphism_key_unchecked(
+        &mut self,
+        input: &GlweAutomorphismKey64,
+    ) -> FftFourierGlweAutomorphismKey64 {
+        let glwe_size = input.0.glwe_dimension().to_glwe_size();
+
+        let boxed = avec![
+            c64::default();
+            input.0.keyswitch_key_count()
+                * input.0.polynomial_size().0 / 2
+                * input.0.glwe_dimension().0
+                * input.0.level_count().0
+                * glwe_size.0
+        ]
+        .into_boxed_slice();
+        let fft = Fft::new(input.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+
+        let mut output = FourierGlweAutomorphismKey::new(
+            boxed,
+            input.0.polynomial_size(),
+            glwe_size,
+            input.0.base_log(),
+            input.0.level_count(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier(input.0.as_view(), fft, stack);
+        FftFourierGlweAutomorphismKey64(output)
+    }
+}
@@ -0,0 +1,217 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::entities::{
+    FftFourierGgswCiphertextVector32, FftFourierGgswCiphertextVector64,
+};
+use crate::backends::fft::private::crypto::wop_pbs::{
+    cmux_tree_memory_optimized, cmux_tree_memory_optimized_scratch,
+};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::math::polynomial::PolynomialList;
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::prelude::{GlweCiphertext32, GlweCiphertext64, PlaintextVector32, PlaintextVector64};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingCmuxTreeEngine, GlweCiphertextDiscardingCmuxTreeError,
+};
+use crate::specification::entities::GgswCiphertextVectorEntity;
+
+impl From<FftError> for GlweCiphertextDiscardingCmuxTreeError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextDiscardingCmuxTreeEngine`] for [`FftEngine`] that operates
+/// on 32 bit integers.
+impl
+    GlweCiphertextDiscardingCmuxTreeEngine<
+        FftFourierGgswCiphertextVector32,
+        PlaintextVector32,
+        GlweCiphertext32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(6);
+    /// // The selector bits, from the most significant to the least significant one
+    /// let bits = vec![1_u32, 0];
+    /// // Four constant look-up tables, with a hard-set encoding (shift by 20 bits)
+    /// let luts: Vec<u32> = (0..4_u32)
+    ///     .flat_map(|i| vec![i << 20; polynomial_size.0])
+    ///     .collect();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let bits = default_engine.create_plaintext_vector_from(&bits)?;
+    /// let luts = default_engine.create_plaintext_vector_from(&luts)?;
+    /// let ggsw_vector: GgswCiphertextVector32 = default_engine
+    ///     .encrypt_scalar_ggsw_ciphertext_vector(&key, &bits, noise, level, base_log)?;
+    /// let ggsw_vector: FftFourierGgswCiphertextVector32 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&ggsw_vector)?;
+    /// let mut output = default_engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// fft_engine.discard_cmux_tree_glwe_ciphertext(&mut output, &luts, &ggsw_vector)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_cmux_tree_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        luts: &PlaintextVector32,
+        ggsw_vector: &FftFourierGgswCiphertextVector32,
+    ) -> Result<(), GlweCiphertextDiscardingCmuxTreeError<Self::EngineError>> {
+        FftError::perform_fft_checks(ggsw_vector.polynomial_size())?;
+        GlweCiphertextDiscardingCmuxTreeError::perform_generic_checks(output, luts, ggsw_vector)?;
+        unsafe { self.discard_cmux_tree_glwe_ciphertext_unchecked(output, luts, ggsw_vector) };
+        Ok(())
+    }
+
+    unsafe fn discard_cmux_tree_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        luts: &PlaintextVector32,
+        ggsw_vector: &FftFourierGgswCiphertextVector32,
+    ) {
+        let polynomial_size = ggsw_vector.0.polynomial_size();
+        let luts = PolynomialList::from_container(luts.0.as_tensor().as_slice(), polynomial_size);
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            cmux_tree_memory_optimized_scratch::<u32>(
+                polynomial_size,
+                ggsw_vector.0.glwe_size(),
+                ggsw_vector.0.count(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        cmux_tree_memory_optimized(
+            output.0.as_mut_view(),
+            luts,
+            ggsw_vector.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextDiscardingCmuxTreeEngine`] for [`FftEngine`] that operates
+/// on 64 bit integers.
+impl
+    GlweCiphertextDiscardingCmuxTreeEngine<
+        FftFourierGgswCiphertextVector64,
+        PlaintextVector64,
+        GlweCiphertext64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(6);
+    /// // The selector bits, from the most significant to the least significant one
+    /// let bits = vec![1_u64, 0];
+    /// // Four constant look-up tables, with a hard-set encoding (shift by 50 bits)
+    /// let luts: Vec<u64> = (0..4_u64)
+    ///     .flat_map(|i| vec![i << 50; polynomial_size.0])
+    ///     .collect();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let bits = default_engine.create_plaintext_vector_from(&bits)?;
+    /// let luts = default_engine.create_plaintext_vector_from(&luts)?;
+    /// let ggsw_vector: GgswCiphertextVector64 = default_engine
+    ///     .encrypt_scalar_ggsw_ciphertext_vector(&key, &bits, noise, level, base_log)?;
+    /// let ggsw_vector: FftFourierGgswCiphertextVector64 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&ggsw_vector)?;
+    /// let mut output = default_engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// fft_engine.discard_cmux_tree_glwe_ciphertext(&mut output, &luts, &ggsw_vector)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_cmux_tree_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        luts: &PlaintextVector64,
+        ggsw_vector: &FftFourierGgswCiphertextVector64,
+    ) -> Result<(), GlweCiphertextDiscardingCmuxTreeError<Self::EngineError>> {
+        FftError::perform_fft_checks(ggsw_vector.polynomial_size())?;
+        GlweCiphertextDiscardingCmuxTreeError::perform_generic_checks(output, luts, ggsw_vector)?;
+        unsafe { self.discard_cmux_tree_glwe_ciphertext_unchecked(output, luts, ggsw_vector) };
+        Ok(())
+    }
+
+    unsafe fn discard_cmux_tree_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        luts: &PlaintextVector64,
+        ggsw_vector: &FftFourierGgswCiphertextVector64,
+    ) {
+        let polynomial_size = ggsw_vector.0.polynomial_size();
+        let luts = PolynomialList::from_container(luts.0.as_tensor().as_slice(), polynomial_size);
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            cmux_tree_memory_optimized_scratch::<u64>(
+                polynomial_size,
+                ggsw_vector.0.glwe_size(),
+                ggsw_vector.0.count(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        cmux_tree_memory_optimized(
+            output.0.as_mut_view(),
+            luts,
+            ggsw_vector.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
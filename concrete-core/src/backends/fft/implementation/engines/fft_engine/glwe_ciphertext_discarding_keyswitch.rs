@@ -0,0 +1,222 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::glwe_keyswitch::{keyswitch, keyswitch_scratch};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::prelude::{
+    FftFourierGlweKeyswitchKey32, FftFourierGlweKeyswitchKey64, GlweCiphertext32, GlweCiphertext64,
+    GlweCiphertextDiscardingKeyswitchEngine, GlweCiphertextDiscardingKeyswitchError,
+    GlweKeyswitchKeyEntity,
+};
+
+impl From<FftError> for GlweCiphertextDiscardingKeyswitchError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextDiscardingKeyswitchEngine`] for [`FftEngine`] that operates
+/// on 32 bit integers.
+impl
+    GlweCiphertextDiscardingKeyswitchEngine<
+        FftFourierGlweKeyswitchKey32,
+        GlweCiphertext32,
+        GlweCiphertext32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (input_glwe_dim, output_glwe_dim) = (GlweDimension(2), GlweDimension(1));
+    /// let poly_size = PolynomialSize(256);
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; poly_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let input_key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(input_glwe_dim, poly_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(output_glwe_dim, poly_size)?;
+    /// let ksk: GlweKeyswitchKey32 = default_engine.generate_new_glwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     noise,
+    /// )?;
+    /// let fourier_ksk: FftFourierGlweKeyswitchKey32 =
+    ///     fft_engine.convert_glwe_keyswitch_key(&ksk)?;
+    ///
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let input_ciphertext =
+    ///     default_engine.encrypt_glwe_ciphertext(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext =
+    ///     default_engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// fft_engine.discard_keyswitch_glwe_ciphertext(
+    ///     &mut output_ciphertext,
+    ///     &input_ciphertext,
+    ///     &fourier_ksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), output_glwe_dim);
+    /// assert_eq!(output_ciphertext.polynomial_size(), poly_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        ksk: &FftFourierGlweKeyswitchKey32,
+    ) -> Result<(), GlweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        FftError::perform_fft_checks(ksk.polynomial_size())?;
+        GlweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_glwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        ksk: &FftFourierGlweKeyswitchKey32,
+    ) {
+        let output_glwe_size = output.0.size();
+        let polynomial_size = output.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            keyswitch_scratch::<u32>(output_glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        keyswitch(
+            output.0.as_mut_view(),
+            input.0.as_view(),
+            ksk.0.as_view(),
+            fft,
+            stack,
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextDiscardingKeyswitchEngine`] for [`FftEngine`] that operates
+/// on 64 bit integers.
+impl
+    GlweCiphertextDiscardingKeyswitchEngine<
+        FftFourierGlweKeyswitchKey64,
+        GlweCiphertext64,
+        GlweCiphertext64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (input_glwe_dim, output_glwe_dim) = (GlweDimension(2), GlweDimension(1));
+    /// let poly_size = PolynomialSize(256);
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; poly_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let input_key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(input_glwe_dim, poly_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(output_glwe_dim, poly_size)?;
+    /// let ksk: GlweKeyswitchKey64 = default_engine.generate_new_glwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     noise,
+    /// )?;
+    /// let fourier_ksk: FftFourierGlweKeyswitchKey64 =
+    ///     fft_engine.convert_glwe_keyswitch_key(&ksk)?;
+    ///
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let input_ciphertext =
+    ///     default_engine.encrypt_glwe_ciphertext(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext =
+    ///     default_engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// fft_engine.discard_keyswitch_glwe_ciphertext(
+    ///     &mut output_ciphertext,
+    ///     &input_ciphertext,
+    ///     &fourier_ksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), output_glwe_dim);
+    /// assert_eq!(output_ciphertext.polynomial_size(), poly_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        ksk: &FftFourierGlweKeyswitchKey64,
+    ) -> Result<(), GlweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        FftError::perform_fft_checks(ksk.polynomial_size())?;
+        GlweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_glwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        ksk: &FftFourierGlweKeyswitchKey64,
+    ) {
+        let output_glwe_size = output.0.size();
+        let polynomial_size = output.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            keyswitch_scratch::<u64>(output_glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        keyswitch(
+            output.0.as_mut_view(),
+            input.0.as_view(),
+            ksk.0.as_view(),
+            fft,
+            stack,
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::glwe_automorphism::{trace, trace_scratch};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::prelude::{
+    FftFourierGlweAutomorphismKey32, FftFourierGlweAutomorphismKey64, GlweAutomorphismKeyEntity,
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextDiscardingTraceEngine,
+    GlweCiphertextDiscardingTraceError,
+};
+
+impl From<FftError> for GlweCiphertextDiscardingTraceError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextDiscardingTraceEngine`] for [`FftEngine`] that operates on
+/// 32 bit integers.
+impl
+    GlweCiphertextDiscardingTraceEngine<
+        FftFourierGlweAutomorphismKey32,
+        GlweCiphertext32,
+        GlweCiphertext32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits), which leaves room for the
+    /// // multiplication by the polynomial size performed by the trace.
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey32 = default_engine
+    ///     .generate_new_glwe_automorphism_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let fourier_automorphism_key: FftFourierGlweAutomorphismKey32 =
+    ///     fft_engine.convert_glwe_automorphism_key(&automorphism_key)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_1 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_2 = default_engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// fft_engine.discard_trace_glwe_ciphertext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &fourier_automorphism_key,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_trace_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        ak: &FftFourierGlweAutomorphismKey32,
+    ) -> Result<(), GlweCiphertextDiscardingTraceError<Self::EngineError>> {
+        FftError::perform_fft_checks(ak.polynomial_size())?;
+        GlweCiphertextDiscardingTraceError::perform_generic_checks(output, input, ak)?;
+        unsafe { self.discard_trace_glwe_ciphertext_unchecked(output, input, ak) };
+        Ok(())
+    }
+
+    unsafe fn discard_trace_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        ak: &FftFourierGlweAutomorphismKey32,
+    ) {
+        let glwe_size = output.0.size();
+        let polynomial_size = output.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            trace_scratch::<u32>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        trace(
+            output.0.as_mut_view(),
+            input.0.as_view(),
+            ak.0.as_view(),
+            fft,
+            stack,
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextDiscardingTraceEngine`] for [`FftEngine`] that operates on
+/// 64 bit integers.
+impl
+    GlweCiphertextDiscardingTraceEngine<
+        FftFourierGlweAutomorphismKey64,
+        GlweCiphertext64,
+        GlweCiphertext64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let decomposition_base_log = DecompositionBaseLog(10);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits), which leaves room for the
+    /// // multiplication by the polynomial size performed by the trace.
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey64 = default_engine
+    ///     .generate_new_glwe_automorphism_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let fourier_automorphism_key: FftFourierGlweAutomorphismKey64 =
+    ///     fft_engine.convert_glwe_automorphism_key(&automorphism_key)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_1 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_2 = default_engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// fft_engine.discard_trace_glwe_ciphertext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &fourier_automorphism_key,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_trace_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        ak: &FftFourierGlweAutomorphismKey64,
+    ) -> Result<(), GlweCiphertextDiscardingTraceError<Self::EngineError>> {
+        FftError::perform_fft_checks(ak.polynomial_size())?;
+        GlweCiphertextDiscardingTraceError::perform_generic_checks(output, input, ak)?;
+        unsafe { self.discard_trace_glwe_ciphertext_unchecked(output, input, ak) };
+        Ok(())
+    }
+
+    unsafe fn discard_trace_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        ak: &FftFourierGlweAutomorphismKey64,
+    ) {
+        let glwe_size = output.0.size();
+        let polynomial_size = output.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            trace_scratch::<u64>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        trace(
+            output.0.as_mut_view(),
+            input.0.as_view(),
+            ak.0.as_view(),
+            fft,
+            stack,
+        );
+    }
+}
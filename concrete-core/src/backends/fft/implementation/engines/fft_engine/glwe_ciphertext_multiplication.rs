@@ -0,0 +1,243 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::relinearization::{multiply, multiply_scratch};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::prelude::{
+    DeltaLog, FftFourierGlweRelinearizationKey32, FftFourierGlweRelinearizationKey64,
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextEntity, GlweCiphertextMultiplicationEngine,
+    GlweCiphertextMultiplicationError,
+};
+
+impl From<FftError> for GlweCiphertextMultiplicationError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextMultiplicationEngine`] for [`FftEngine`] that operates on
+/// 32 bit integers.
+impl
+    GlweCiphertextMultiplicationEngine<
+        GlweCiphertext32,
+        GlweCiphertext32,
+        FftFourierGlweRelinearizationKey32,
+        GlweCiphertext32,
+    > for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let delta_log = DeltaLog(20);
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![2_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let rlk: GlweRelinearizationKey32 =
+    ///     default_engine.generate_new_glwe_relinearization_key(&key, dec_bl, dec_lc, noise)?;
+    /// let fourier_rlk: FftFourierGlweRelinearizationKey32 =
+    ///     fft_engine.convert_glwe_relinearization_key(&rlk)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let product = fft_engine.mul_glwe_ciphertext(
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &fourier_rlk,
+    ///     delta_log,
+    /// )?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn mul_glwe_ciphertext(
+        &mut self,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        relinearization_key: &FftFourierGlweRelinearizationKey32,
+        delta_log: DeltaLog,
+    ) -> Result<GlweCiphertext32, GlweCiphertextMultiplicationError<Self::EngineError>> {
+        FftError::perform_fft_checks(input_1.polynomial_size())?;
+        GlweCiphertextMultiplicationError::perform_generic_checks(
+            input_1,
+            input_2,
+            relinearization_key,
+            delta_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.mul_glwe_ciphertext_unchecked(input_1, input_2, relinearization_key, delta_log)
+        })
+    }
+
+    unsafe fn mul_glwe_ciphertext_unchecked(
+        &mut self,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        relinearization_key: &FftFourierGlweRelinearizationKey32,
+        delta_log: DeltaLog,
+    ) -> GlweCiphertext32 {
+        let glwe_size = input_1.0.size();
+        let polynomial_size = input_1.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            multiply_scratch::<u32>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        let mut output = ImplGlweCiphertext::allocate(0_u32, polynomial_size, glwe_size);
+        multiply(
+            output.as_mut_view(),
+            input_1.0.as_view(),
+            input_2.0.as_view(),
+            relinearization_key.0.as_view(),
+            delta_log,
+            fft,
+            stack,
+        );
+        GlweCiphertext32(output)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextMultiplicationEngine`] for [`FftEngine`] that operates on
+/// 64 bit integers.
+impl
+    GlweCiphertextMultiplicationEngine<
+        GlweCiphertext64,
+        GlweCiphertext64,
+        FftFourierGlweRelinearizationKey64,
+        GlweCiphertext64,
+    > for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(4), DecompositionBaseLog(10));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let delta_log = DeltaLog(50);
+    /// let input_1 = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_2 = vec![2_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let rlk: GlweRelinearizationKey64 =
+    ///     default_engine.generate_new_glwe_relinearization_key(&key, dec_bl, dec_lc, noise)?;
+    /// let fourier_rlk: FftFourierGlweRelinearizationKey64 =
+    ///     fft_engine.convert_glwe_relinearization_key(&rlk)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let product = fft_engine.mul_glwe_ciphertext(
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &fourier_rlk,
+    ///     delta_log,
+    /// )?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn mul_glwe_ciphertext(
+        &mut self,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        relinearization_key: &FftFourierGlweRelinearizationKey64,
+        delta_log: DeltaLog,
+    ) -> Result<GlweCiphertext64, GlweCiphertextMultiplicationError<Self::EngineError>> {
+        FftError::perform_fft_checks(input_1.polynomial_size())?;
+        GlweCiphertextMultiplicationError::perform_generic_checks(
+            input_1,
+            input_2,
+            relinearization_key,
+            delta_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.mul_glwe_ciphertext_unchecked(input_1, input_2, relinearization_key, delta_log)
+        })
+    }
+
+    unsafe fn mul_glwe_ciphertext_unchecked(
+        &mut self,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        relinearization_key: &FftFourierGlweRelinearizationKey64,
+        delta_log: DeltaLog,
+    ) -> GlweCiphertext64 {
+        let glwe_size = input_1.0.size();
+        let polynomial_size = input_1.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            multiply_scratch::<u64>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        let mut output = ImplGlweCiphertext::allocate(0_u64, polynomial_size, glwe_size);
+        multiply(
+            output.as_mut_view(),
+            input_1.0.as_view(),
+            input_2.0.as_view(),
+            relinearization_key.0.as_view(),
+            delta_log,
+            fft,
+            stack,
+        );
+        GlweCiphertext64(output)
+    }
+}
@@ -0,0 +1,196 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::relinearization::{
+    tensor_product, tensor_product_glwe_size, tensor_product_scratch,
+};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::prelude::{
+    DeltaLog, GlweCiphertext32, GlweCiphertext64, GlweCiphertextEntity,
+    GlweCiphertextTensorProductEngine, GlweCiphertextTensorProductError,
+};
+
+impl From<FftError> for GlweCiphertextTensorProductError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextTensorProductEngine`] for [`FftEngine`] that operates on
+/// 32 bit integers.
+impl GlweCiphertextTensorProductEngine<GlweCiphertext32, GlweCiphertext32, GlweCiphertext32>
+    for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{DeltaLog, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let delta_log = DeltaLog(20);
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![2_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let tensor =
+    ///     fft_engine.tensor_product_glwe_ciphertext(&ciphertext_1, &ciphertext_2, delta_log)?;
+    /// #
+    /// assert_eq!(tensor.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(tensor.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn tensor_product_glwe_ciphertext(
+        &mut self,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        delta_log: DeltaLog,
+    ) -> Result<GlweCiphertext32, GlweCiphertextTensorProductError<Self::EngineError>> {
+        FftError::perform_fft_checks(input_1.polynomial_size())?;
+        GlweCiphertextTensorProductError::perform_generic_checks(input_1, input_2, delta_log, 32)?;
+        Ok(unsafe { self.tensor_product_glwe_ciphertext_unchecked(input_1, input_2, delta_log) })
+    }
+
+    unsafe fn tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        delta_log: DeltaLog,
+    ) -> GlweCiphertext32 {
+        let glwe_size = input_1.0.size();
+        let polynomial_size = input_1.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            tensor_product_scratch::<u32>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        let mut output = ImplGlweCiphertext::allocate(
+            0_u32,
+            polynomial_size,
+            tensor_product_glwe_size(glwe_size),
+        );
+        tensor_product(
+            output.as_mut_view(),
+            input_1.0.as_view(),
+            input_2.0.as_view(),
+            delta_log,
+            fft,
+            stack,
+        );
+        GlweCiphertext32(output)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextTensorProductEngine`] for [`FftEngine`] that operates on
+/// 64 bit integers.
+impl GlweCiphertextTensorProductEngine<GlweCiphertext64, GlweCiphertext64, GlweCiphertext64>
+    for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{DeltaLog, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let delta_log = DeltaLog(50);
+    /// let input_1 = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_2 = vec![2_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let tensor =
+    ///     fft_engine.tensor_product_glwe_ciphertext(&ciphertext_1, &ciphertext_2, delta_log)?;
+    /// #
+    /// assert_eq!(tensor.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(tensor.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn tensor_product_glwe_ciphertext(
+        &mut self,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        delta_log: DeltaLog,
+    ) -> Result<GlweCiphertext64, GlweCiphertextTensorProductError<Self::EngineError>> {
+        FftError::perform_fft_checks(input_1.polynomial_size())?;
+        GlweCiphertextTensorProductError::perform_generic_checks(input_1, input_2, delta_log, 64)?;
+        Ok(unsafe { self.tensor_product_glwe_ciphertext_unchecked(input_1, input_2, delta_log) })
+    }
+
+    unsafe fn tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        delta_log: DeltaLog,
+    ) -> GlweCiphertext64 {
+        let glwe_size = input_1.0.size();
+        let polynomial_size = input_1.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            tensor_product_scratch::<u64>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        let mut output = ImplGlweCiphertext::allocate(
+            0_u64,
+            polynomial_size,
+            tensor_product_glwe_size(glwe_size),
+        );
+        tensor_product(
+            output.as_mut_view(),
+            input_1.0.as_view(),
+            input_2.0.as_view(),
+            delta_log,
+            fft,
+            stack,
+        );
+        GlweCiphertext64(output)
+    }
+}
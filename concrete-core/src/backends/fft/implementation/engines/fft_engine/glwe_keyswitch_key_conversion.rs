@@ -0,0 +1,215 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::glwe_keyswitch::{
+    fill_with_forward_fourier_scratch, FourierGlweKeyswitchKey,
+};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::prelude::{
+    FftFourierGlweKeyswitchKey32, FftFourierGlweKeyswitchKey64, GlweKeyswitchKey32,
+    GlweKeyswitchKey64, GlweKeyswitchKeyConversionEngine, GlweKeyswitchKeyConversionError,
+};
+use aligned_vec::avec;
+use concrete_fft::c64;
+
+impl From<FftError> for GlweKeyswitchKeyConversionError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweKeyswitchKeyConversionEngine`] for [`FftEngine`] that operates on
+/// 32 bit integers. It converts a GLWE keyswitch key from the standard to the Fourier domain.
+impl GlweKeyswitchKeyConversionEngine<GlweKeyswitchKey32, FftFourierGlweKeyswitchKey32>
+    for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (input_glwe_dim, output_glwe_dim) = (GlweDimension(2), GlweDimension(1));
+    /// let poly_size = PolynomialSize(256);
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let input_key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(input_glwe_dim, poly_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(output_glwe_dim, poly_size)?;
+    /// let ksk: GlweKeyswitchKey32 = default_engine.generate_new_glwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     noise,
+    /// )?;
+    ///
+    /// let fourier_ksk: FftFourierGlweKeyswitchKey32 =
+    ///     fft_engine.convert_glwe_keyswitch_key(&ksk)?;
+    /// #
+    /// assert_eq!(fourier_ksk.input_glwe_dimension(), input_glwe_dim);
+    /// assert_eq!(fourier_ksk.output_glwe_dimension(), output_glwe_dim);
+    /// assert_eq!(fourier_ksk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_ksk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_ksk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_keyswitch_key(
+        &mut self,
+        input: &GlweKeyswitchKey32,
+    ) -> Result<FftFourierGlweKeyswitchKey32, GlweKeyswitchKeyConversionError<Self::EngineError>>
+    {
+        FftError::perform_fft_checks(input.0.polynomial_size())?;
+        Ok(unsafe { self.convert_glwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &GlweKeyswitchKey32,
+    ) -> FftFourierGlweKeyswitchKey32 {
+        let output_glwe_size = input.0.output_glwe_dimension().to_glwe_size();
+
+        let boxed = avec![
+            c64::default();
+            input.0.polynomial_size().0 / 2
+                * input.0.input_glwe_dimension().0
+                * input.0.level_count().0
+                * output_glwe_size.0
+        ]
+        .into_boxed_slice();
+        let fft = Fft::new(input.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+
+        let mut output = FourierGlweKeyswitchKey::new(
+            boxed,
+            input.0.polynomial_size(),
+            input.0.input_glwe_dimension(),
+            output_glwe_size,
+            input.0.base_log(),
+            input.0.level_count(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier(input.0.as_view(), fft, stack);
+        FftFourierGlweKeyswitchKey32(output)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweKeyswitchKeyConversionEngine`] for [`FftEngine`] that operates on
+/// 64 bit integers. It converts a GLWE keyswitch key from the standard to the Fourier domain.
+impl GlweKeyswitchKeyConversionEngine<GlweKeyswitchKey64, FftFourierGlweKeyswitchKey64>
+    for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (input_glwe_dim, output_glwe_dim) = (GlweDimension(2), GlweDimension(1));
+    /// let poly_size = PolynomialSize(256);
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let input_key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(input_glwe_dim, poly_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(output_glwe_dim, poly_size)?;
+    /// let ksk: GlweKeyswitchKey64 = default_engine.generate_new_glwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     noise,
+    /// )?;
+    ///
+    /// let fourier_ksk: FftFourierGlweKeyswitchKey64 =
+    ///     fft_engine.convert_glwe_keyswitch_key(&ksk)?;
+    /// #
+    /// assert_eq!(fourier_ksk.input_glwe_dimension(), input_glwe_dim);
+    /// assert_eq!(fourier_ksk.output_glwe_dimension(), output_glwe_dim);
+    /// assert_eq!(fourier_ksk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_ksk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_ksk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_keyswitch_key(
+        &mut self,
+        input: &GlweKeyswitchKey64,
+    ) -> Result<FftFourierGlweKeyswitchKey64, GlweKeyswitchKeyConversionError<Self::EngineError>>
+    {
+        FftError::perform_fft_checks(input.0.polynomial_size())?;
+        Ok(unsafe { self.convert_glwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &GlweKeyswitchKey64,
+    ) -> FftFourierGlweKeyswitchKey64 {
+        let output_glwe_size = input.0.output_glwe_dimension().to_glwe_size();
+
+        let boxed = avec![
+            c64::default();
+            input.0.polynomial_size().0 / 2
+                * input.0.input_glwe_dimension().0
+                * input.0.level_count().0
+                * output_glwe_size.0
+        ]
+        .into_boxed_slice();
+        let fft = Fft::new(input.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+
+        let mut output = FourierGlweKeyswitchKey::new(
+            boxed,
+            input.0.polynomial_size(),
+            input.0.input_glwe_dimension(),
+            output_glwe_size,
+            input.0.base_log(),
+            input.0.level_count(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier(input.0.as_view(), fft, stack);
+        FftFourierGlweKeyswitchKey64(output)
+    }
+}
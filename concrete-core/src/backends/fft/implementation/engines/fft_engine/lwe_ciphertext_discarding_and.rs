@@ -0,0 +1,244 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::gates::{binary_gate, binary_gate_scratch, BinaryGate};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::prelude::{
+    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, LweBootstrapKeyEntity,
+    LweCiphertext32, LweCiphertext64, LweCiphertextEntity, LweKeyswitchKey32, LweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingAndEngine, LweCiphertextDiscardingAndError,
+};
+
+impl From<FftError> for LweCiphertextDiscardingAndError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAndEngine`] for [`FftEngine`] that operates on
+/// 32 bits integers.
+impl
+    LweCiphertextDiscardingAndEngine<
+        LweCiphertext32,
+        LweCiphertext32,
+        FftFourierLweBootstrapKey32,
+        LweKeyswitchKey32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Booleans are encoded as 1/8 (true) and -1/8 (false)
+    /// let true_plaintext = 1_u32 << 29;
+    /// let false_plaintext = true_plaintext.wrapping_neg();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, and rely on /dev/random only for tests.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let large_lwe_sk: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let ksk: LweKeyswitchKey32 = default_engine.generate_new_lwe_keyswitch_key(
+    ///     &large_lwe_sk,
+    ///     &lwe_sk,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     noise,
+    /// )?;
+    /// let plaintext_1 = default_engine.create_plaintext_from(&true_plaintext)?;
+    /// let plaintext_2 = default_engine.create_plaintext_from(&false_plaintext)?;
+    /// let ciphertext_1 = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext_1, noise)?;
+    /// let ciphertext_2 = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext_2, noise)?;
+    /// let mut output_ciphertext = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk, noise)?;
+    ///
+    /// fft_engine.discard_and_lwe_ciphertext(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &bsk,
+    ///     &ksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), lwe_dim);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_and_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input_1: &LweCiphertext32,
+        input_2: &LweCiphertext32,
+        bsk: &FftFourierLweBootstrapKey32,
+        ksk: &LweKeyswitchKey32,
+    ) -> Result<(), LweCiphertextDiscardingAndError<Self::EngineError>> {
+        FftError::perform_fft_checks(bsk.polynomial_size())?;
+        LweCiphertextDiscardingAndError::perform_generic_checks(
+            output, input_1, input_2, bsk, ksk,
+        )?;
+        unsafe { self.discard_and_lwe_ciphertext_unchecked(output, input_1, input_2, bsk, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_and_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input_1: &LweCiphertext32,
+        input_2: &LweCiphertext32,
+        bsk: &FftFourierLweBootstrapKey32,
+        ksk: &LweKeyswitchKey32,
+    ) {
+        let fft = Fft::new(bsk.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            binary_gate_scratch::<u32>(
+                input_1.lwe_dimension(),
+                bsk.glwe_dimension().to_glwe_size(),
+                bsk.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        binary_gate(
+            BinaryGate::And,
+            output.0.as_mut_view(),
+            input_1.0.as_view(),
+            input_2.0.as_view(),
+            bsk.0.as_view(),
+            ksk.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAndEngine`] for [`FftEngine`] that operates on
+/// 64 bits integers.
+impl
+    LweCiphertextDiscardingAndEngine<
+        LweCiphertext64,
+        LweCiphertext64,
+        FftFourierLweBootstrapKey64,
+        LweKeyswitchKey64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Booleans are encoded as 1/8 (true) and -1/8 (false)
+    /// let true_plaintext = 1_u64 << 61;
+    /// let false_plaintext = true_plaintext.wrapping_neg();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, and rely on /dev/random only for tests.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let large_lwe_sk: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let ksk: LweKeyswitchKey64 = default_engine.generate_new_lwe_keyswitch_key(
+    ///     &large_lwe_sk,
+    ///     &lwe_sk,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     noise,
+    /// )?;
+    /// let plaintext_1 = default_engine.create_plaintext_from(&true_plaintext)?;
+    /// let plaintext_2 = default_engine.create_plaintext_from(&false_plaintext)?;
+    /// let ciphertext_1 = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext_1, noise)?;
+    /// let ciphertext_2 = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext_2, noise)?;
+    /// let mut output_ciphertext = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk, noise)?;
+    ///
+    /// fft_engine.discard_and_lwe_ciphertext(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &bsk,
+    ///     &ksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), lwe_dim);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_and_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input_1: &LweCiphertext64,
+        input_2: &LweCiphertext64,
+        bsk: &FftFourierLweBootstrapKey64,
+        ksk: &LweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingAndError<Self::EngineError>> {
+        FftError::perform_fft_checks(bsk.polynomial_size())?;
+        LweCiphertextDiscardingAndError::perform_generic_checks(
+            output, input_1, input_2, bsk, ksk,
+        )?;
+        unsafe { self.discard_and_lwe_ciphertext_unchecked(output, input_1, input_2, bsk, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_and_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input_1: &LweCiphertext64,
+        input_2: &LweCiphertext64,
+        bsk: &FftFourierLweBootstrapKey64,
+        ksk: &LweKeyswitchKey64,
+    ) {
+        let fft = Fft::new(bsk.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            binary_gate_scratch::<u64>(
+                input_1.lwe_dimension(),
+                bsk.glwe_dimension().to_glwe_size(),
+                bsk.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        binary_gate(
+            BinaryGate::And,
+            output.0.as_mut_view(),
+            input_1.0.as_view(),
+            input_2.0.as_view(),
+            bsk.0.as_view(),
+            ksk.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
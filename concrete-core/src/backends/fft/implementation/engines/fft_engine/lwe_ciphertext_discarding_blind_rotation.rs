@@ -0,0 +1,213 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::bootstrap::blind_rotate_scratch;
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::prelude::{
+    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, GlweCiphertext32, GlweCiphertext64,
+    GlweCiphertextEntity, LutCountLog, LweCiphertext32, LweCiphertext64,
+    LweCiphertextDiscardingBlindRotationEngine, LweCiphertextDiscardingBlindRotationError,
+};
+
+impl From<FftError> for LweCiphertextDiscardingBlindRotationError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingBlindRotationEngine`] for [`FftEngine`] that
+/// operates on 32 bit integers.
+impl
+    LweCiphertextDiscardingBlindRotationEngine<
+        FftFourierLweBootstrapKey32,
+        GlweCiphertext32,
+        LweCiphertext32,
+        GlweCiphertext32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is stored in the accumulator
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_glwe_ciphertext(&glwe_sk, noise)?;
+    ///
+    /// fft_engine.discard_blind_rotate_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_blind_rotate_lwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBlindRotationError<Self::EngineError>> {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingBlindRotationError::perform_generic_checks(output, input, acc, bsk)?;
+        unsafe { self.discard_blind_rotate_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_blind_rotate_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftFourierLweBootstrapKey32,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            blind_rotate_scratch::<u32>(acc.0.size(), acc.0.polynomial_size(), fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        output.0.as_mut_tensor().fill_with_copy(acc.0.as_tensor());
+        bsk.0.as_view().blind_rotate(
+            output.0.as_mut_view(),
+            input.0.tensor.as_slice(),
+            LutCountLog(0),
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingBlindRotationEngine`] for [`FftEngine`] that
+/// operates on 64 bit integers.
+impl
+    LweCiphertextDiscardingBlindRotationEngine<
+        FftFourierLweBootstrapKey64,
+        GlweCiphertext64,
+        LweCiphertext64,
+        GlweCiphertext64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is stored in the accumulator
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_glwe_ciphertext(&glwe_sk, noise)?;
+    ///
+    /// fft_engine.discard_blind_rotate_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_blind_rotate_lwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBlindRotationError<Self::EngineError>> {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingBlindRotationError::perform_generic_checks(output, input, acc, bsk)?;
+        unsafe { self.discard_blind_rotate_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_blind_rotate_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            blind_rotate_scratch::<u64>(acc.0.size(), acc.0.polynomial_size(), fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        output.0.as_mut_tensor().fill_with_copy(acc.0.as_tensor());
+        bsk.0.as_view().blind_rotate(
+            output.0.as_mut_view(),
+            input.0.tensor.as_slice(),
+            LutCountLog(0),
+            fft,
+            self.stack(),
+        );
+    }
+}
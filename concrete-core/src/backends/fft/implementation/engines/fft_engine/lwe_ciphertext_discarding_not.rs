@@ -0,0 +1,118 @@
+use super::FftEngine;
+use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::prelude::{LweCiphertext32, LweCiphertext64};
+use crate::specification::engines::{
+    LweCiphertextDiscardingNotEngine, LweCiphertextDiscardingNotError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingNotEngine`] for [`FftEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextDiscardingNotEngine<LweCiphertext32, LweCiphertext32> for FftEngine {
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dim = LweDimension(4);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Booleans are encoded as 1/8 (true) and -1/8 (false)
+    /// let true_plaintext = 1_u32 << 29;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, and rely on /dev/random only for tests.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let plaintext = default_engine.create_plaintext_from(&true_plaintext)?;
+    /// let ciphertext = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output_ciphertext = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk, noise)?;
+    ///
+    /// fft_engine.discard_not_lwe_ciphertext(&mut output_ciphertext, &ciphertext)?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), lwe_dim);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_not_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+    ) -> Result<(), LweCiphertextDiscardingNotError<Self::EngineError>> {
+        LweCiphertextDiscardingNotError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_not_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_not_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |element| element.wrapping_neg());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingNotEngine`] for [`FftEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextDiscardingNotEngine<LweCiphertext64, LweCiphertext64> for FftEngine {
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dim = LweDimension(4);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Booleans are encoded as 1/8 (true) and -1/8 (false)
+    /// let true_plaintext = 1_u64 << 61;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, and rely on /dev/random only for tests.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let plaintext = default_engine.create_plaintext_from(&true_plaintext)?;
+    /// let ciphertext = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output_ciphertext = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk, noise)?;
+    ///
+    /// fft_engine.discard_not_lwe_ciphertext(&mut output_ciphertext, &ciphertext)?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), lwe_dim);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_not_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+    ) -> Result<(), LweCiphertextDiscardingNotError<Self::EngineError>> {
+        LweCiphertextDiscardingNotError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_not_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_not_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |element| element.wrapping_neg());
+    }
+}
@@ -0,0 +1,231 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::entities::{
+    FftFourierGgswCiphertextVector32, FftFourierGgswCiphertextVector64,
+};
+use crate::backends::fft::private::crypto::wop_pbs::{vertical_packing, vertical_packing_scratch};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::math::polynomial::PolynomialList;
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::prelude::{LweCiphertext32, LweCiphertext64, PlaintextVector32, PlaintextVector64};
+use crate::specification::engines::{
+    LweCiphertextDiscardingVerticalPackingEngine, LweCiphertextDiscardingVerticalPackingError,
+};
+use crate::specification::entities::GgswCiphertextVectorEntity;
+
+impl From<FftError> for LweCiphertextDiscardingVerticalPackingError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingVerticalPackingEngine`] for [`FftEngine`] that
+/// operates on 32 bit integers.
+impl
+    LweCiphertextDiscardingVerticalPackingEngine<
+        FftFourierGgswCiphertextVector32,
+        PlaintextVector32,
+        LweCiphertext32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(6);
+    /// // The bits of the index, from the most significant to the least significant one
+    /// let bits = vec![1_u32, 0, 1];
+    /// // An identity look-up table, with a hard-set encoding (shift by 20 bits)
+    /// let lut: Vec<u32> = (0..polynomial_size.0 as u32).map(|i| (i % 8) << 20).collect();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let glwe_key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let lwe_key: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+    /// let bits = default_engine.create_plaintext_vector_from(&bits)?;
+    /// let lut = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let ggsw_vector: GgswCiphertextVector32 = default_engine
+    ///     .encrypt_scalar_ggsw_ciphertext_vector(&glwe_key, &bits, noise, level, base_log)?;
+    /// let ggsw_vector: FftFourierGgswCiphertextVector32 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&ggsw_vector)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_key, noise)?;
+    ///
+    /// fft_engine.discard_vertical_packing_lwe_ciphertext(&mut output, &lut, &ggsw_vector)?;
+    /// #
+    /// assert_eq!(
+    ///     output.lwe_dimension(),
+    ///     LweDimension(glwe_dimension.0 * polynomial_size.0)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_vertical_packing_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        lut: &PlaintextVector32,
+        ggsw_vector: &FftFourierGgswCiphertextVector32,
+    ) -> Result<(), LweCiphertextDiscardingVerticalPackingError<Self::EngineError>> {
+        FftError::perform_fft_checks(ggsw_vector.polynomial_size())?;
+        LweCiphertextDiscardingVerticalPackingError::perform_generic_checks(
+            output,
+            lut,
+            ggsw_vector,
+        )?;
+        unsafe { self.discard_vertical_packing_lwe_ciphertext_unchecked(output, lut, ggsw_vector) };
+        Ok(())
+    }
+
+    unsafe fn discard_vertical_packing_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        lut: &PlaintextVector32,
+        ggsw_vector: &FftFourierGgswCiphertextVector32,
+    ) {
+        let polynomial_size = ggsw_vector.0.polynomial_size();
+        let lut = PolynomialList::from_container(lut.0.as_tensor().as_slice(), polynomial_size);
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            vertical_packing_scratch::<u32>(
+                ggsw_vector.0.glwe_size(),
+                polynomial_size,
+                lut.polynomial_count(),
+                ggsw_vector.0.count(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        vertical_packing(
+            lut,
+            output.0.as_mut_view(),
+            ggsw_vector.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingVerticalPackingEngine`] for [`FftEngine`] that
+/// operates on 64 bit integers.
+impl
+    LweCiphertextDiscardingVerticalPackingEngine<
+        FftFourierGgswCiphertextVector64,
+        PlaintextVector64,
+        LweCiphertext64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(6);
+    /// // The bits of the index, from the most significant to the least significant one
+    /// let bits = vec![1_u64, 0, 1];
+    /// // An identity look-up table, with a hard-set encoding (shift by 50 bits)
+    /// let lut: Vec<u64> = (0..polynomial_size.0 as u64).map(|i| (i % 8) << 50).collect();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let glwe_key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let lwe_key: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+    /// let bits = default_engine.create_plaintext_vector_from(&bits)?;
+    /// let lut = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let ggsw_vector: GgswCiphertextVector64 = default_engine
+    ///     .encrypt_scalar_ggsw_ciphertext_vector(&glwe_key, &bits, noise, level, base_log)?;
+    /// let ggsw_vector: FftFourierGgswCiphertextVector64 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&ggsw_vector)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_key, noise)?;
+    ///
+    /// fft_engine.discard_vertical_packing_lwe_ciphertext(&mut output, &lut, &ggsw_vector)?;
+    /// #
+    /// assert_eq!(
+    ///     output.lwe_dimension(),
+    ///     LweDimension(glwe_dimension.0 * polynomial_size.0)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_vertical_packing_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        lut: &PlaintextVector64,
+        ggsw_vector: &FftFourierGgswCiphertextVector64,
+    ) -> Result<(), LweCiphertextDiscardingVerticalPackingError<Self::EngineError>> {
+        FftError::perform_fft_checks(ggsw_vector.polynomial_size())?;
+        LweCiphertextDiscardingVerticalPackingError::perform_generic_checks(
+            output,
+            lut,
+            ggsw_vector,
+        )?;
+        unsafe { self.discard_vertical_packing_lwe_ciphertext_unchecked(output, lut, ggsw_vector) };
+        Ok(())
+    }
+
+    unsafe fn discard_vertical_packing_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        lut: &PlaintextVector64,
+        ggsw_vector: &FftFourierGgswCiphertextVector64,
+    ) {
+        let polynomial_size = ggsw_vector.0.polynomial_size();
+        let lut = PolynomialList::from_container(lut.0.as_tensor().as_slice(), polynomial_size);
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            vertical_packing_scratch::<u64>(
+                ggsw_vector.0.glwe_size(),
+                polynomial_size,
+                lut.polynomial_count(),
+                ggsw_vector.0.count(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        vertical_packing(
+            lut,
+            output.0.as_mut_view(),
+            ggsw_vector.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::bootstrap::bootstrap_scratch;
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::crypto::lwe::LweList as ImplLweList;
+use crate::commons::math::tensor::{AsMutSlice, AsRefSlice};
+use crate::prelude::{
+    CiphertextCount, FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, GlweCiphertext32,
+    GlweCiphertext64, GlweCiphertextEntity, LutCountLog, LweBootstrapKeyEntity, LweCiphertext32,
+    LweCiphertext64, LweCiphertextMultiValueBootstrapEngine, LweCiphertextMultiValueBootstrapError,
+    LweCiphertextVector32, LweCiphertextVector64,
+};
+
+impl From<FftError> for LweCiphertextMultiValueBootstrapError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextMultiValueBootstrapEngine`] for [`FftEngine`] that operates
+/// on 32 bit integers.
+impl
+    LweCiphertextMultiValueBootstrapEngine<
+        FftFourierLweBootstrapKey32,
+        GlweCiphertext32,
+        LweCiphertext32,
+        LweCiphertextVector32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LutCountLog, LweDimension,
+    ///     PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let lut_count_log = LutCountLog(1);
+    /// // Two constant functions are packed in the accumulator, the first one in the even
+    /// // coefficients and the second one in the odd coefficients.
+    /// let lut = (0..poly_size.0)
+    ///     .map(|i| if i % 2 == 0 { 8_u32 << 20 } else { 2_u32 << 20 })
+    ///     .collect::<Vec<_>>();
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    ///
+    /// let output: LweCiphertextVector32 =
+    ///     fft_engine.multi_value_bootstrap_lwe_ciphertext(&input, &acc, &bsk, lut_count_log)?;
+    /// #
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(2));
+    /// assert_eq!(output.lwe_dimension(), bsk.output_lwe_dimension());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn multi_value_bootstrap_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftFourierLweBootstrapKey32,
+        lut_count_log: LutCountLog,
+    ) -> Result<LweCiphertextVector32, LweCiphertextMultiValueBootstrapError<Self::EngineError>>
+    {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextMultiValueBootstrapError::perform_generic_checks(
+            input,
+            acc,
+            bsk,
+            lut_count_log,
+        )?;
+        Ok(unsafe {
+            self.multi_value_bootstrap_lwe_ciphertext_unchecked(input, acc, bsk, lut_count_log)
+        })
+    }
+
+    unsafe fn multi_value_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftFourierLweBootstrapKey32,
+        lut_count_log: LutCountLog,
+    ) -> LweCiphertextVector32 {
+        let mut output = ImplLweList::allocate(
+            0u32,
+            bsk.output_lwe_dimension().to_lwe_size(),
+            CiphertextCount(1 << lut_count_log.0),
+        );
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            bootstrap_scratch::<u32>(acc.0.size(), acc.0.polynomial_size(), fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        bsk.0.as_view().multi_value_bootstrap(
+            output.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc.0.as_view(),
+            lut_count_log,
+            fft,
+            self.stack(),
+        );
+        LweCiphertextVector32(output)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextMultiValueBootstrapEngine`] for [`FftEngine`] that operates
+/// on 64 bit integers.
+impl
+    LweCiphertextMultiValueBootstrapEngine<
+        FftFourierLweBootstrapKey64,
+        GlweCiphertext64,
+        LweCiphertext64,
+        LweCiphertextVector64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LutCountLog, LweDimension,
+    ///     PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let lut_count_log = LutCountLog(1);
+    /// // Two constant functions are packed in the accumulator, the first one in the even
+    /// // coefficients and the second one in the odd coefficients.
+    /// let lut = (0..poly_size.0)
+    ///     .map(|i| if i % 2 == 0 { 8_u64 << 50 } else { 2_u64 << 50 })
+    ///     .collect::<Vec<_>>();
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    ///
+    /// let output: LweCiphertextVector64 =
+    ///     fft_engine.multi_value_bootstrap_lwe_ciphertext(&input, &acc, &bsk, lut_count_log)?;
+    /// #
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(2));
+    /// assert_eq!(output.lwe_dimension(), bsk.output_lwe_dimension());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn multi_value_bootstrap_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftFourierLweBootstrapKey64,
+        lut_count_log: LutCountLog,
+    ) -> Result<LweCiphertextVector64, LweCiphertextMultiValueBootstrapError<Self::EngineError>>
+    {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextMultiValueBootstrapError::perform_generic_checks(
+            input,
+            acc,
+            bsk,
+            lut_count_log,
+        )?;
+        Ok(unsafe {
+            self.multi_value_bootstrap_lwe_ciphertext_unchecked(input, acc, bsk, lut_count_log)
+        })
+    }
+
+    unsafe fn multi_value_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftFourierLweBootstrapKey64,
+        lut_count_log: LutCountLog,
+    ) -> LweCiphertextVector64 {
+        let mut output = ImplLweList::allocate(
+            0u64,
+            bsk.output_lwe_dimension().to_lwe_size(),
+            CiphertextCount(1 << lut_count_log.0),
+        );
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            bootstrap_scratch::<u64>(acc.0.size(), acc.0.polynomial_size(), fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        bsk.0.as_view().multi_value_bootstrap(
+            output.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc.0.as_view(),
+            lut_count_log,
+            fft,
+            self.stack(),
+        );
+        LweCiphertextVector64(output)
+    }
+}
@@ -0,0 +1,269 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::gates::{
+    binary_gate_list, binary_gate_scratch, BinaryGate,
+};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::prelude::{
+    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, LweBootstrapKeyEntity,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorEntity, LweKeyswitchKey32,
+    LweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingNandEngine, LweCiphertextVectorDiscardingNandError,
+};
+
+impl From<FftError> for LweCiphertextVectorDiscardingNandError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingNandEngine`] for [`FftEngine`] that operates
+/// on 32 bits integers.
+impl
+    LweCiphertextVectorDiscardingNandEngine<
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+        FftFourierLweBootstrapKey32,
+        LweKeyswitchKey32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Booleans are encoded as 1/8 (true) and -1/8 (false)
+    /// let true_plaintext = 1_u32 << 29;
+    /// let false_plaintext = true_plaintext.wrapping_neg();
+    /// let input_1 = vec![true_plaintext, true_plaintext, false_plaintext, false_plaintext];
+    /// let input_2 = vec![true_plaintext, false_plaintext, true_plaintext, false_plaintext];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, and rely on /dev/random only for tests.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let large_lwe_sk: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let ksk: LweKeyswitchKey32 = default_engine.generate_new_lwe_keyswitch_key(
+    ///     &large_lwe_sk,
+    ///     &lwe_sk,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_vector_1 =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector_1, noise)?;
+    /// let ciphertext_vector_2 =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector_2, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     default_engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk, noise, LweCiphertextCount(4))?;
+    ///
+    /// fft_engine.discard_nand_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector_1,
+    ///     &ciphertext_vector_2,
+    ///     &bsk,
+    ///     &ksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_dimension(), lwe_dim);
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(4)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_nand_lwe_ciphertext_vector(
+        &self,
+        output: &mut LweCiphertextVector32,
+        input_1: &LweCiphertextVector32,
+        input_2: &LweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+        ksk: &LweKeyswitchKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingNandError<Self::EngineError>> {
+        FftError::perform_fft_checks(bsk.polynomial_size())?;
+        LweCiphertextVectorDiscardingNandError::perform_generic_checks(
+            output, input_1, input_2, bsk, ksk,
+        )?;
+        unsafe {
+            self.discard_nand_lwe_ciphertext_vector_unchecked(output, input_1, input_2, bsk, ksk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_nand_lwe_ciphertext_vector_unchecked(
+        &self,
+        output: &mut LweCiphertextVector32,
+        input_1: &LweCiphertextVector32,
+        input_2: &LweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+        ksk: &LweKeyswitchKey32,
+    ) {
+        let fft = Fft::new(bsk.polynomial_size());
+        let fft = fft.as_view();
+        let capacity = binary_gate_scratch::<u32>(
+            input_1.lwe_dimension(),
+            bsk.glwe_dimension().to_glwe_size(),
+            bsk.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required();
+        self.with_gate_stack(capacity, |stack| {
+            binary_gate_list(
+                BinaryGate::Nand,
+                output.0.as_mut_view(),
+                input_1.0.as_view(),
+                input_2.0.as_view(),
+                bsk.0.as_view(),
+                ksk.0.as_view(),
+                fft,
+                stack,
+            )
+        });
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingNandEngine`] for [`FftEngine`] that operates
+/// on 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingNandEngine<
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+        FftFourierLweBootstrapKey64,
+        LweKeyswitchKey64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Booleans are encoded as 1/8 (true) and -1/8 (false)
+    /// let true_plaintext = 1_u64 << 61;
+    /// let false_plaintext = true_plaintext.wrapping_neg();
+    /// let input_1 = vec![true_plaintext, true_plaintext, false_plaintext, false_plaintext];
+    /// let input_2 = vec![true_plaintext, false_plaintext, true_plaintext, false_plaintext];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, and rely on /dev/random only for tests.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let large_lwe_sk: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let ksk: LweKeyswitchKey64 = default_engine.generate_new_lwe_keyswitch_key(
+    ///     &large_lwe_sk,
+    ///     &lwe_sk,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_vector_1 =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector_1, noise)?;
+    /// let ciphertext_vector_2 =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector_2, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     default_engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk, noise, LweCiphertextCount(4))?;
+    ///
+    /// fft_engine.discard_nand_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector_1,
+    ///     &ciphertext_vector_2,
+    ///     &bsk,
+    ///     &ksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_dimension(), lwe_dim);
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(4)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_nand_lwe_ciphertext_vector(
+        &self,
+        output: &mut LweCiphertextVector64,
+        input_1: &LweCiphertextVector64,
+        input_2: &LweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+        ksk: &LweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingNandError<Self::EngineError>> {
+        FftError::perform_fft_checks(bsk.polynomial_size())?;
+        LweCiphertextVectorDiscardingNandError::perform_generic_checks(
+            output, input_1, input_2, bsk, ksk,
+        )?;
+        unsafe {
+            self.discard_nand_lwe_ciphertext_vector_unchecked(output, input_1, input_2, bsk, ksk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_nand_lwe_ciphertext_vector_unchecked(
+        &self,
+        output: &mut LweCiphertextVector64,
+        input_1: &LweCiphertextVector64,
+        input_2: &LweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+        ksk: &LweKeyswitchKey64,
+    ) {
+        let fft = Fft::new(bsk.polynomial_size());
+        let fft = fft.as_view();
+        let capacity = binary_gate_scratch::<u64>(
+            input_1.lwe_dimension(),
+            bsk.glwe_dimension().to_glwe_size(),
+            bsk.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required();
+        self.with_gate_stack(capacity, |stack| {
+            binary_gate_list(
+                BinaryGate::Nand,
+                output.0.as_mut_view(),
+                input_1.0.as_view(),
+                input_2.0.as_view(),
+                bsk.0.as_view(),
+                ksk.0.as_view(),
+                fft,
+                stack,
+            )
+        });
+    }
+}
@@ -0,0 +1,144 @@
+use super::FftEngine;
+use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::prelude::{LweCiphertextVector32, LweCiphertextVector64};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingNotEngine, LweCiphertextVectorDiscardingNotError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingNotEngine`] for [`FftEngine`] that operates
+/// on 32 bits integers.
+impl LweCiphertextVectorDiscardingNotEngine<LweCiphertextVector32, LweCiphertextVector32>
+    for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dim = LweDimension(4);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Booleans are encoded as 1/8 (true) and -1/8 (false)
+    /// let true_plaintext = 1_u32 << 29;
+    /// let false_plaintext = true_plaintext.wrapping_neg();
+    /// let input = vec![true_plaintext, false_plaintext];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, and rely on /dev/random only for tests.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     default_engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk, noise, LweCiphertextCount(2))?;
+    ///
+    /// fft_engine.discard_not_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_dimension(), lwe_dim);
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(2)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_not_lwe_ciphertext_vector(
+        &self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+    ) -> Result<(), LweCiphertextVectorDiscardingNotError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingNotError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_not_lwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_not_lwe_ciphertext_vector_unchecked(
+        &self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |element| element.wrapping_neg());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingNotEngine`] for [`FftEngine`] that operates
+/// on 64 bits integers.
+impl LweCiphertextVectorDiscardingNotEngine<LweCiphertextVector64, LweCiphertextVector64>
+    for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dim = LweDimension(4);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Booleans are encoded as 1/8 (true) and -1/8 (false)
+    /// let true_plaintext = 1_u64 << 61;
+    /// let false_plaintext = true_plaintext.wrapping_neg();
+    /// let input = vec![true_plaintext, false_plaintext];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, and rely on /dev/random only for tests.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     default_engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk, noise, LweCiphertextCount(2))?;
+    ///
+    /// fft_engine.discard_not_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_dimension(), lwe_dim);
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(2)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_not_lwe_ciphertext_vector(
+        &self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+    ) -> Result<(), LweCiphertextVectorDiscardingNotError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingNotError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_not_lwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_not_lwe_ciphertext_vector_unchecked(
+        &self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |element| element.wrapping_neg());
+    }
+}
@@ -0,0 +1,231 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::glwe_automorphism::{
+    pack_lwe_ciphertexts, pack_lwe_ciphertexts_scratch,
+};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::prelude::{
+    FftFourierGlweAutomorphismKey32, FftFourierGlweAutomorphismKey64, GlweAutomorphismKeyEntity,
+    GlweCiphertext32, GlweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine,
+    LweCiphertextVectorGlweCiphertextDiscardingTracePackingError,
+};
+
+impl From<FftError> for LweCiphertextVectorGlweCiphertextDiscardingTracePackingError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine`] for
+/// [`FftEngine`] that operates on 32 bit integers.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine<
+        FftFourierGlweAutomorphismKey32,
+        LweCiphertextVector32,
+        GlweCiphertext32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits), which leaves room for the
+    /// // multiplication by the polynomial size performed by the packing.
+    /// let input = vec![3_u32 << 20; 16];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let glwe_key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey32 = default_engine
+    ///     .generate_new_glwe_automorphism_key(
+    ///         &glwe_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let fourier_automorphism_key: FftFourierGlweAutomorphismKey32 =
+    ///     fft_engine.convert_glwe_automorphism_key(&automorphism_key)?;
+    /// let lwe_key: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)?;
+    /// let mut output_glwe = default_engine.zero_encrypt_glwe_ciphertext(&glwe_key, noise)?;
+    ///
+    /// fft_engine.discard_trace_packing_lwe_ciphertext_vector(
+    ///     &mut output_glwe,
+    ///     &ciphertext_vector,
+    ///     &fourier_automorphism_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_glwe.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output_glwe.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_trace_packing_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &LweCiphertextVector32,
+        ak: &FftFourierGlweAutomorphismKey32,
+    ) -> Result<(), LweCiphertextVectorGlweCiphertextDiscardingTracePackingError<Self::EngineError>>
+    {
+        FftError::perform_fft_checks(ak.polynomial_size())?;
+        LweCiphertextVectorGlweCiphertextDiscardingTracePackingError::perform_generic_checks(
+            output, input, ak,
+        )?;
+        unsafe { self.discard_trace_packing_lwe_ciphertext_vector_unchecked(output, input, ak) };
+        Ok(())
+    }
+
+    unsafe fn discard_trace_packing_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &LweCiphertextVector32,
+        ak: &FftFourierGlweAutomorphismKey32,
+    ) {
+        let glwe_size = output.0.size();
+        let polynomial_size = output.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            pack_lwe_ciphertexts_scratch::<u32>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        pack_lwe_ciphertexts(
+            output.0.as_mut_view(),
+            input.0.as_view(),
+            ak.0.as_view(),
+            fft,
+            stack,
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine`] for
+/// [`FftEngine`] that operates on 64 bit integers.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine<
+        FftFourierGlweAutomorphismKey64,
+        LweCiphertextVector64,
+        GlweCiphertext64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let decomposition_base_log = DecompositionBaseLog(10);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits), which leaves room for the
+    /// // multiplication by the polynomial size performed by the packing.
+    /// let input = vec![3_u64 << 50; 16];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let glwe_key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey64 = default_engine
+    ///     .generate_new_glwe_automorphism_key(
+    ///         &glwe_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let fourier_automorphism_key: FftFourierGlweAutomorphismKey64 =
+    ///     fft_engine.convert_glwe_automorphism_key(&automorphism_key)?;
+    /// let lwe_key: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)?;
+    /// let mut output_glwe = default_engine.zero_encrypt_glwe_ciphertext(&glwe_key, noise)?;
+    ///
+    /// fft_engine.discard_trace_packing_lwe_ciphertext_vector(
+    ///     &mut output_glwe,
+    ///     &ciphertext_vector,
+    ///     &fourier_automorphism_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_glwe.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output_glwe.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_trace_packing_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &LweCiphertextVector64,
+        ak: &FftFourierGlweAutomorphismKey64,
+    ) -> Result<(), LweCiphertextVectorGlweCiphertextDiscardingTracePackingError<Self::EngineError>>
+    {
+        FftError::perform_fft_checks(ak.polynomial_size())?;
+        LweCiphertextVectorGlweCiphertextDiscardingTracePackingError::perform_generic_checks(
+            output, input, ak,
+        )?;
+        unsafe { self.discard_trace_packing_lwe_ciphertext_vector_unchecked(output, input, ak) };
+        Ok(())
+    }
+
+    unsafe fn discard_trace_packing_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &LweCiphertextVector64,
+        ak: &FftFourierGlweAutomorphismKey64,
+    ) {
+        let glwe_size = output.0.size();
+        let polynomial_size = output.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            pack_lwe_ciphertexts_scratch::<u64>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        pack_lwe_ciphertexts(
+            output.0.as_mut_view(),
+            input.0.as_view(),
+            ak.0.as_view(),
+            fft,
+            stack,
+        );
+    }
+}
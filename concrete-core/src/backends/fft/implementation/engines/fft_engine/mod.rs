@@ -4,6 +4,7 @@ use dyn_stack::DynStack;
 use crate::specification::engines::sealed::AbstractEngineSeal;
 use crate::specification::engines::AbstractEngine;
 use core::mem::MaybeUninit;
+use std::sync::Mutex;
 
 /// Error that can occur in the execution of FHE operations by the [`FftEngine`].
 #[derive(Debug)]
@@ -38,6 +39,8 @@ impl FftError {
 /// The main engine exposed by the Concrete-FFT backend.
 pub struct FftEngine {
     memory: Vec<MaybeUninit<u8>>,
+    // The gate engines only borrow the engine immutably, so they use a separate buffer.
+    gate_memory: Mutex<Vec<MaybeUninit<u8>>>,
 }
 
 impl FftEngine {
@@ -48,6 +51,23 @@ impl FftEngine {
     pub(crate) fn stack(&mut self) -> DynStack<'_> {
         DynStack::new(&mut self.memory)
     }
+
+    /// Runs `f` on a stack of at least `capacity` bytes, taken from the buffer of the gate
+    /// engines.
+    pub(crate) fn with_gate_stack<R>(
+        &self,
+        capacity: usize,
+        f: impl FnOnce(DynStack<'_>) -> R,
+    ) -> R {
+        // The buffer only contains uninitialized memory, so it can be reused even if a previous
+        // gate panicked while holding the lock.
+        let mut memory = self
+            .gate_memory
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        memory.resize_with(capacity, MaybeUninit::uninit);
+        f(DynStack::new(&mut memory))
+    }
 }
 
 impl AbstractEngineSeal for FftEngine {}
@@ -56,16 +76,49 @@ impl AbstractEngine for FftEngine {
     type Parameters = ();
 
     fn new(_parameter: Self::Parameters) -> Result<Self, Self::EngineError> {
-        Ok(FftEngine { memory: Vec::new() })
+        Ok(FftEngine {
+            memory: Vec::new(),
+            gate_memory: Mutex::new(Vec::new()),
+        })
     }
 }
 
 mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_discarding_conversion;
+mod ggsw_ciphertext_vector_conversion;
+mod ggsw_seeded_ciphertext_conversion;
+mod glwe_automorphism_key_conversion;
+mod glwe_ciphertext_discarding_automorphism;
+mod glwe_ciphertext_discarding_cmux_tree;
+mod glwe_ciphertext_discarding_keyswitch;
+mod glwe_ciphertext_discarding_trace;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+mod glwe_ciphertext_multiplication;
+mod glwe_ciphertext_tensor_product;
 mod glwe_ciphertexts_ggsw_ciphertext_fusing_cmux;
+mod glwe_keyswitch_key_conversion;
+mod glwe_relinearization_key_conversion;
 mod lwe_bootstrap_key_conversion;
+mod lwe_ciphertext_discarding_and;
 mod lwe_ciphertext_discarding_bit_extraction;
+mod lwe_ciphertext_discarding_blind_rotation;
 mod lwe_ciphertext_discarding_bootstrap;
 mod lwe_ciphertext_discarding_circuit_bootstrap_boolean;
+mod lwe_ciphertext_discarding_mux;
+mod lwe_ciphertext_discarding_nand;
+mod lwe_ciphertext_discarding_nor;
+mod lwe_ciphertext_discarding_not;
+mod lwe_ciphertext_discarding_or;
+mod lwe_ciphertext_discarding_vertical_packing;
+mod lwe_ciphertext_discarding_xnor;
+mod lwe_ciphertext_discarding_xor;
+mod lwe_ciphertext_multi_value_bootstrap;
+mod lwe_ciphertext_vector_discarding_and;
 mod lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing;
+mod lwe_ciphertext_vector_discarding_nand;
+mod lwe_ciphertext_vector_discarding_nor;
+mod lwe_ciphertext_vector_discarding_not;
+mod lwe_ciphertext_vector_discarding_or;
+mod lwe_ciphertext_vector_discarding_xnor;
+mod lwe_ciphertext_vector_discarding_xor;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_trace_packing;
@@ -0,0 +1,62 @@
+use crate::commons::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::prelude::{
+    FftParallelEngine, FftParallelError, LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVectorDiscardingNotEngine, LweCiphertextVectorDiscardingNotError,
+};
+use rayon::prelude::*;
+
+impl LweCiphertextVectorDiscardingNotEngine<LweCiphertextVector32, LweCiphertextVector32>
+    for FftParallelEngine
+{
+    fn discard_not_lwe_ciphertext_vector(
+        &self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+    ) -> Result<(), LweCiphertextVectorDiscardingNotError<FftParallelError>> {
+        LweCiphertextVectorDiscardingNotError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_not_lwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_not_lwe_ciphertext_vector_unchecked(
+        &self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .as_mut_slice()
+            .par_iter_mut()
+            .zip(input.0.as_tensor().as_slice().par_iter())
+            .for_each(|(o, i)| *o = i.wrapping_neg());
+    }
+}
+
+impl LweCiphertextVectorDiscardingNotEngine<LweCiphertextVector64, LweCiphertextVector64>
+    for FftParallelEngine
+{
+    fn discard_not_lwe_ciphertext_vector(
+        &self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+    ) -> Result<(), LweCiphertextVectorDiscardingNotError<FftParallelError>> {
+        LweCiphertextVectorDiscardingNotError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_not_lwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_not_lwe_ciphertext_vector_unchecked(
+        &self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .as_mut_slice()
+            .par_iter_mut()
+            .zip(input.0.as_tensor().as_slice().par_iter())
+            .for_each(|(o, i)| *o = i.wrapping_neg());
+    }
+}
@@ -0,0 +1,153 @@
+use crate::backends::fft::private::crypto::gates::{binary_gate, binary_gate_scratch, BinaryGate};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::prelude::{
+    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, FftParallelEngine, FftParallelError,
+    LweBootstrapKeyEntity, LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVectorDiscardingOrEngine, LweCiphertextVectorDiscardingOrError,
+    LweCiphertextVectorEntity, LweKeyswitchKey32, LweKeyswitchKey64, FFT_ENGINE,
+};
+use rayon::prelude::*;
+
+impl From<FftParallelError> for LweCiphertextVectorDiscardingOrError<FftParallelError> {
+    fn from(err: FftParallelError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+impl
+    LweCiphertextVectorDiscardingOrEngine<
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+        FftFourierLweBootstrapKey32,
+        LweKeyswitchKey32,
+    > for FftParallelEngine
+{
+    fn discard_or_lwe_ciphertext_vector(
+        &self,
+        output: &mut LweCiphertextVector32,
+        input_1: &LweCiphertextVector32,
+        input_2: &LweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+        ksk: &LweKeyswitchKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingOrError<FftParallelError>> {
+        FftParallelError::perform_fft_checks(bsk.polynomial_size())?;
+        LweCiphertextVectorDiscardingOrError::perform_generic_checks(
+            output, input_1, input_2, bsk, ksk,
+        )?;
+        unsafe {
+            self.discard_or_lwe_ciphertext_vector_unchecked(output, input_1, input_2, bsk, ksk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_or_lwe_ciphertext_vector_unchecked(
+        &self,
+        output: &mut LweCiphertextVector32,
+        input_1: &LweCiphertextVector32,
+        input_2: &LweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+        ksk: &LweKeyswitchKey32,
+    ) {
+        output
+            .0
+            .par_ciphertext_iter_mut()
+            .zip(input_1.0.par_ciphertext_iter())
+            .zip(input_2.0.par_ciphertext_iter())
+            .for_each(|((o, c_1), c_2)| {
+                let fft = Fft::new(bsk.polynomial_size());
+                let fft = fft.as_view();
+                FFT_ENGINE.with(|e| {
+                    let mut engine = e.borrow_mut();
+                    engine.resize(
+                        binary_gate_scratch::<u32>(
+                            input_1.lwe_dimension(),
+                            bsk.glwe_dimension().to_glwe_size(),
+                            bsk.polynomial_size(),
+                            fft,
+                        )
+                        .unwrap()
+                        .unaligned_bytes_required(),
+                    );
+                    binary_gate(
+                        BinaryGate::Or,
+                        o,
+                        c_1,
+                        c_2,
+                        bsk.0.as_view(),
+                        ksk.0.as_view(),
+                        fft,
+                        engine.stack(),
+                    );
+                });
+            });
+    }
+}
+
+impl
+    LweCiphertextVectorDiscardingOrEngine<
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+        FftFourierLweBootstrapKey64,
+        LweKeyswitchKey64,
+    > for FftParallelEngine
+{
+    fn discard_or_lwe_ciphertext_vector(
+        &self,
+        output: &mut LweCiphertextVector64,
+        input_1: &LweCiphertextVector64,
+        input_2: &LweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+        ksk: &LweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingOrError<FftParallelError>> {
+        FftParallelError::perform_fft_checks(bsk.polynomial_size())?;
+        LweCiphertextVectorDiscardingOrError::perform_generic_checks(
+            output, input_1, input_2, bsk, ksk,
+        )?;
+        unsafe {
+            self.discard_or_lwe_ciphertext_vector_unchecked(output, input_1, input_2, bsk, ksk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_or_lwe_ciphertext_vector_unchecked(
+        &self,
+        output: &mut LweCiphertextVector64,
+        input_1: &LweCiphertextVector64,
+        input_2: &LweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+        ksk: &LweKeyswitchKey64,
+    ) {
+        output
+            .0
+            .par_ciphertext_iter_mut()
+            .zip(input_1.0.par_ciphertext_iter())
+            .zip(input_2.0.par_ciphertext_iter())
+            .for_each(|((o, c_1), c_2)| {
+                let fft = Fft::new(bsk.polynomial_size());
+                let fft = fft.as_view();
+                FFT_ENGINE.with(|e| {
+                    let mut engine = e.borrow_mut();
+                    engine.resize(
+                        binary_gate_scratch::<u64>(
+                            input_1.lwe_dimension(),
+                            bsk.glwe_dimension().to_glwe_size(),
+                            bsk.polynomial_size(),
+                            fft,
+                        )
+                        .unwrap()
+                        .unaligned_bytes_required(),
+                    );
+                    binary_gate(
+                        BinaryGate::Or,
+                        o,
+                        c_1,
+                        c_2,
+                        bsk.0.as_view(),
+                        ksk.0.as_view(),
+                        fft,
+                        engine.stack(),
+                    );
+                });
+            });
+    }
+}
@@ -52,4 +52,11 @@ impl AbstractEngine for FftParallelEngine {
     }
 }
 
+mod lwe_ciphertext_vector_discarding_and;
 mod lwe_ciphertext_vector_discarding_bootstrap;
+mod lwe_ciphertext_vector_discarding_nand;
+mod lwe_ciphertext_vector_discarding_nor;
+mod lwe_ciphertext_vector_discarding_not;
+mod lwe_ciphertext_vector_discarding_or;
+mod lwe_ciphertext_vector_discarding_xnor;
+mod lwe_ciphertext_vector_discarding_xor;
@@ -0,0 +1,630 @@
+#![allow(clippy::missing_safety_doc)]
+
+use super::aligned_layout::{deserialize_aligned, AlignedEntityKind};
+use super::FftSerializationEngine;
+use crate::backends::fft::private::crypto::bootstrap::FourierLweBootstrapKey;
+use crate::backends::fft::private::crypto::ggsw::FourierGgswCiphertext;
+use crate::prelude::{
+    ChecksumVerification, EntityAlignedDeserializationEngine, EntityAlignedDeserializationError,
+    EntityDeserializationEngine, EntityDeserializationError, FftFourierGgswCiphertextView32,
+    FftFourierGgswCiphertextView64, FftFourierLweBootstrapKeyView32,
+    FftFourierLweBootstrapKeyView64,
+};
+
+/// # Description:
+/// Implementation of [`EntityAlignedDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GGSW ciphertext in the Fourier domain,
+/// serialized with the aligned layout described in the [`FftSerializationEngine`] documentation,
+/// into a view borrowing the serialized bytes.
+impl<'a> EntityAlignedDeserializationEngine<&'a [u8], FftFourierGgswCiphertextView32<'a>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext32 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_aligned(&fourier_ciphertext)?;
+    ///
+    /// // The view borrows the serialized buffer, which could as well be a memory-mapped file. The
+    /// // checksum is not verified here, to avoid reading the whole buffer.
+    /// let view: FftFourierGgswCiphertextView32 = serialization_engine
+    ///     .deserialize_aligned(&serialized[..], ChecksumVerification(false))?;
+    /// assert_eq!(view.polynomial_size(), polynomial_size);
+    /// assert_eq!(view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(view.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_aligned(
+        &mut self,
+        serialized: &'a [u8],
+        checksum_verification: ChecksumVerification,
+    ) -> Result<
+        FftFourierGgswCiphertextView32<'a>,
+        EntityAlignedDeserializationError<Self::EngineError>,
+    > {
+        let (header, data) = deserialize_aligned(
+            serialized,
+            AlignedEntityKind::GgswCiphertext32,
+            checksum_verification,
+        )?;
+        Ok(FftFourierGgswCiphertextView32(FourierGgswCiphertext::new(
+            data,
+            header.polynomial_size,
+            header.glwe_size,
+            header.decomposition_base_log,
+            header.decomposition_level_count,
+        )))
+    }
+
+    unsafe fn deserialize_aligned_unchecked(
+        &mut self,
+        serialized: &'a [u8],
+        checksum_verification: ChecksumVerification,
+    ) -> FftFourierGgswCiphertextView32<'a> {
+        self.deserialize_aligned(serialized, checksum_verification)
+            .unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`FftSerializationEngine`] that operates
+/// on 32 bits integers. It deserializes a GGSW ciphertext in the Fourier domain, serialized with
+/// the aligned layout described in the [`FftSerializationEngine`] documentation, into a view
+/// borrowing the serialized bytes. The checksum of the serialized entity is always verified, see
+/// [`EntityAlignedDeserializationEngine`] to skip it.
+impl<'a> EntityDeserializationEngine<&'a [u8], FftFourierGgswCiphertextView32<'a>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext32 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_aligned(&fourier_ciphertext)?;
+    ///
+    /// // The view borrows the serialized buffer, which could as well be a memory-mapped file.
+    /// let view: FftFourierGgswCiphertextView32 = serialization_engine.deserialize(&serialized[..])?;
+    /// assert_eq!(view.polynomial_size(), polynomial_size);
+    /// assert_eq!(view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(view.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &'a [u8],
+    ) -> Result<FftFourierGgswCiphertextView32<'a>, EntityDeserializationError<Self::EngineError>>
+    {
+        self.deserialize_aligned(serialized, ChecksumVerification(true))
+            .map_err(EntityDeserializationError::from)
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &'a [u8],
+    ) -> FftFourierGgswCiphertextView32<'a> {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityAlignedDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GGSW ciphertext in the Fourier domain,
+/// serialized with the aligned layout described in the [`FftSerializationEngine`] documentation,
+/// into a view borrowing the serialized bytes.
+impl<'a> EntityAlignedDeserializationEngine<&'a [u8], FftFourierGgswCiphertextView64<'a>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext64 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_aligned(&fourier_ciphertext)?;
+    ///
+    /// // The view borrows the serialized buffer, which could as well be a memory-mapped file. The
+    /// // checksum is not verified here, to avoid reading the whole buffer.
+    /// let view: FftFourierGgswCiphertextView64 = serialization_engine
+    ///     .deserialize_aligned(&serialized[..], ChecksumVerification(false))?;
+    /// assert_eq!(view.polynomial_size(), polynomial_size);
+    /// assert_eq!(view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(view.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_aligned(
+        &mut self,
+        serialized: &'a [u8],
+        checksum_verification: ChecksumVerification,
+    ) -> Result<
+        FftFourierGgswCiphertextView64<'a>,
+        EntityAlignedDeserializationError<Self::EngineError>,
+    > {
+        let (header, data) = deserialize_aligned(
+            serialized,
+            AlignedEntityKind::GgswCiphertext64,
+            checksum_verification,
+        )?;
+        Ok(FftFourierGgswCiphertextView64(FourierGgswCiphertext::new(
+            data,
+            header.polynomial_size,
+            header.glwe_size,
+            header.decomposition_base_log,
+            header.decomposition_level_count,
+        )))
+    }
+
+    unsafe fn deserialize_aligned_unchecked(
+        &mut self,
+        serialized: &'a [u8],
+        checksum_verification: ChecksumVerification,
+    ) -> FftFourierGgswCiphertextView64<'a> {
+        self.deserialize_aligned(serialized, checksum_verification)
+            .unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`FftSerializationEngine`] that operates
+/// on 64 bits integers. It deserializes a GGSW ciphertext in the Fourier domain, serialized with
+/// the aligned layout described in the [`FftSerializationEngine`] documentation, into a view
+/// borrowing the serialized bytes. The checksum of the serialized entity is always verified, see
+/// [`EntityAlignedDeserializationEngine`] to skip it.
+impl<'a> EntityDeserializationEngine<&'a [u8], FftFourierGgswCiphertextView64<'a>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext64 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_aligned(&fourier_ciphertext)?;
+    ///
+    /// // The view borrows the serialized buffer, which could as well be a memory-mapped file.
+    /// let view: FftFourierGgswCiphertextView64 = serialization_engine.deserialize(&serialized[..])?;
+    /// assert_eq!(view.polynomial_size(), polynomial_size);
+    /// assert_eq!(view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(view.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &'a [u8],
+    ) -> Result<FftFourierGgswCiphertextView64<'a>, EntityDeserializationError<Self::EngineError>>
+    {
+        self.deserialize_aligned(serialized, ChecksumVerification(true))
+            .map_err(EntityDeserializationError::from)
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &'a [u8],
+    ) -> FftFourierGgswCiphertextView64<'a> {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityAlignedDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes an LWE bootstrap key in the Fourier domain,
+/// serialized with the aligned layout described in the [`FftSerializationEngine`] documentation,
+/// into a view borrowing the serialized bytes.
+impl<'a> EntityAlignedDeserializationEngine<&'a [u8], FftFourierLweBootstrapKeyView32<'a>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_aligned(&fourier_bsk)?;
+    ///
+    /// // The view borrows the serialized buffer, which could as well be a memory-mapped file. The
+    /// // checksum is not verified here, to avoid reading the whole buffer.
+    /// let view: FftFourierLweBootstrapKeyView32 = serialization_engine
+    ///     .deserialize_aligned(&serialized[..], ChecksumVerification(false))?;
+    /// assert_eq!(view.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(view.glwe_dimension(), glwe_dim);
+    /// assert_eq!(view.polynomial_size(), poly_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_aligned(
+        &mut self,
+        serialized: &'a [u8],
+        checksum_verification: ChecksumVerification,
+    ) -> Result<
+        FftFourierLweBootstrapKeyView32<'a>,
+        EntityAlignedDeserializationError<Self::EngineError>,
+    > {
+        let (header, data) = deserialize_aligned(
+            serialized,
+            AlignedEntityKind::LweBootstrapKey32,
+            checksum_verification,
+        )?;
+        Ok(FftFourierLweBootstrapKeyView32(
+            FourierLweBootstrapKey::new(
+                data,
+                header.key_size,
+                header.polynomial_size,
+                header.glwe_size,
+                header.decomposition_base_log,
+                header.decomposition_level_count,
+            ),
+        ))
+    }
+
+    unsafe fn deserialize_aligned_unchecked(
+        &mut self,
+        serialized: &'a [u8],
+        checksum_verification: ChecksumVerification,
+    ) -> FftFourierLweBootstrapKeyView32<'a> {
+        self.deserialize_aligned(serialized, checksum_verification)
+            .unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`FftSerializationEngine`] that operates
+/// on 32 bits integers. It deserializes an LWE bootstrap key in the Fourier domain, serialized with
+/// the aligned layout described in the [`FftSerializationEngine`] documentation, into a view
+/// borrowing the serialized bytes. The checksum of the serialized entity is always verified, see
+/// [`EntityAlignedDeserializationEngine`] to skip it.
+impl<'a> EntityDeserializationEngine<&'a [u8], FftFourierLweBootstrapKeyView32<'a>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_aligned(&fourier_bsk)?;
+    ///
+    /// // The view borrows the serialized buffer, which could as well be a memory-mapped file.
+    /// let view: FftFourierLweBootstrapKeyView32 = serialization_engine.deserialize(&serialized[..])?;
+    /// assert_eq!(view.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(view.glwe_dimension(), glwe_dim);
+    /// assert_eq!(view.polynomial_size(), poly_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &'a [u8],
+    ) -> Result<FftFourierLweBootstrapKeyView32<'a>, EntityDeserializationError<Self::EngineError>>
+    {
+        self.deserialize_aligned(serialized, ChecksumVerification(true))
+            .map_err(EntityDeserializationError::from)
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &'a [u8],
+    ) -> FftFourierLweBootstrapKeyView32<'a> {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityAlignedDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes an LWE bootstrap key in the Fourier domain,
+/// serialized with the aligned layout described in the [`FftSerializationEngine`] documentation,
+/// into a view borrowing the serialized bytes.
+impl<'a> EntityAlignedDeserializationEngine<&'a [u8], FftFourierLweBootstrapKeyView64<'a>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_aligned(&fourier_bsk)?;
+    ///
+    /// // The view borrows the serialized buffer, which could as well be a memory-mapped file. The
+    /// // checksum is not verified here, to avoid reading the whole buffer.
+    /// let view: FftFourierLweBootstrapKeyView64 = serialization_engine
+    ///     .deserialize_aligned(&serialized[..], ChecksumVerification(false))?;
+    /// assert_eq!(view.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(view.glwe_dimension(), glwe_dim);
+    /// assert_eq!(view.polynomial_size(), poly_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_aligned(
+        &mut self,
+        serialized: &'a [u8],
+        checksum_verification: ChecksumVerification,
+    ) -> Result<
+        FftFourierLweBootstrapKeyView64<'a>,
+        EntityAlignedDeserializationError<Self::EngineError>,
+    > {
+        let (header, data) = deserialize_aligned(
+            serialized,
+            AlignedEntityKind::LweBootstrapKey64,
+            checksum_verification,
+        )?;
+        Ok(FftFourierLweBootstrapKeyView64(
+            FourierLweBootstrapKey::new(
+                data,
+                header.key_size,
+                header.polynomial_size,
+                header.glwe_size,
+                header.decomposition_base_log,
+                header.decomposition_level_count,
+            ),
+        ))
+    }
+
+    unsafe fn deserialize_aligned_unchecked(
+        &mut self,
+        serialized: &'a [u8],
+        checksum_verification: ChecksumVerification,
+    ) -> FftFourierLweBootstrapKeyView64<'a> {
+        self.deserialize_aligned(serialized, checksum_verification)
+            .unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`FftSerializationEngine`] that operates
+/// on 64 bits integers. It deserializes an LWE bootstrap key in the Fourier domain, serialized with
+/// the aligned layout described in the [`FftSerializationEngine`] documentation, into a view
+/// borrowing the serialized bytes. The checksum of the serialized entity is always verified, see
+/// [`EntityAlignedDeserializationEngine`] to skip it.
+impl<'a> EntityDeserializationEngine<&'a [u8], FftFourierLweBootstrapKeyView64<'a>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_aligned(&fourier_bsk)?;
+    ///
+    /// // The view borrows the serialized buffer, which could as well be a memory-mapped file.
+    /// let view: FftFourierLweBootstrapKeyView64 = serialization_engine.deserialize(&serialized[..])?;
+    /// assert_eq!(view.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(view.glwe_dimension(), glwe_dim);
+    /// assert_eq!(view.polynomial_size(), poly_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &'a [u8],
+    ) -> Result<FftFourierLweBootstrapKeyView64<'a>, EntityDeserializationError<Self::EngineError>>
+    {
+        self.deserialize_aligned(serialized, ChecksumVerification(true))
+            .map_err(EntityDeserializationError::from)
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &'a [u8],
+    ) -> FftFourierLweBootstrapKeyView64<'a> {
+        self.deserialize(serialized).unwrap()
+    }
+}
@@ -0,0 +1,380 @@
+//! The aligned layout used to serialize Fourier entities, so that they can be loaded without copy.
+//!
+//! See the documentation of [`FftSerializationEngine`](super::FftSerializationEngine) for a
+//! description of the layout.
+
+use super::FftSerializationError;
+use crate::backends::fft::private::math::fft::{Fft, FftView};
+use crate::commons::utils::Checksum;
+#[cfg(target_endian = "little")]
+use crate::prelude::ChecksumVerification;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+};
+#[cfg(target_endian = "little")]
+use crate::specification::engines::EntityAlignedDeserializationError;
+use aligned_vec::{avec, ABox};
+use concrete_fft::c64;
+
+/// The magic bytes starting every entity serialized with the aligned layout.
+const MAGIC: [u8; 8] = *b"CCFOURIE";
+
+/// The current version of the aligned layout.
+const LAYOUT_VERSION: u64 = 1;
+
+/// The size of the header, in bytes.
+pub(super) const HEADER_SIZE: usize = 128;
+
+/// The range of the header bytes storing the checksum, which are the only ones it does not cover.
+const CHECKSUM_BYTES: std::ops::Range<usize> = 72..80;
+
+/// The size of a serialized complex number, in bytes.
+const C64_SIZE: usize = 16;
+
+/// The kind of entity stored in an aligned buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum AlignedEntityKind {
+    GgswCiphertext32 = 0,
+    GgswCiphertext64 = 1,
+    LweBootstrapKey32 = 2,
+    LweBootstrapKey64 = 3,
+}
+
+/// The parameters stored in the header of an aligned buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct AlignedHeader {
+    pub kind: AlignedEntityKind,
+    pub polynomial_size: PolynomialSize,
+    pub glwe_size: GlweSize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+    /// The input LWE dimension of a bootstrap key, or zero for a GGSW ciphertext.
+    pub key_size: LweDimension,
+}
+
+impl AlignedHeader {
+    /// Returns the number of complex numbers stored in the body of the buffer.
+    fn body_len(&self) -> Option<usize> {
+        let ggsw_len = (self.polynomial_size.0 / 2)
+            .checked_mul(self.glwe_size.0)?
+            .checked_mul(self.glwe_size.0)?
+            .checked_mul(self.decomposition_level_count.0)?;
+        match self.kind {
+            AlignedEntityKind::GgswCiphertext32 | AlignedEntityKind::GgswCiphertext64 => {
+                Some(ggsw_len)
+            }
+            AlignedEntityKind::LweBootstrapKey32 | AlignedEntityKind::LweBootstrapKey64 => {
+                ggsw_len.checked_mul(self.key_size.0)
+            }
+        }
+    }
+
+    /// Returns the bytes of the header, the checksum being left to zero.
+    fn to_bytes(self) -> [u8; HEADER_SIZE] {
+        let words = [
+            u64::from_le_bytes(MAGIC),
+            LAYOUT_VERSION,
+            self.kind as u64,
+            self.polynomial_size.0 as u64,
+            self.glwe_size.0 as u64,
+            self.decomposition_base_log.0 as u64,
+            self.decomposition_level_count.0 as u64,
+            self.key_size.0 as u64,
+            self.body_len().unwrap() as u64,
+            0,
+            fourier_ordering(self.polynomial_size),
+        ];
+        let mut bytes = [0u8; HEADER_SIZE];
+        for (chunk, word) in bytes.chunks_exact_mut(8).zip(words.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Parses and validates a header, and returns it along with the checksum and the Fourier
+    /// ordering it contains.
+    #[cfg(target_endian = "little")]
+    fn from_bytes(
+        bytes: &[u8],
+        kind: AlignedEntityKind,
+    ) -> Result<(Self, u64, u64), EntityAlignedDeserializationError<FftSerializationError>> {
+        let invalid_header =
+            || EntityAlignedDeserializationError::Engine(FftSerializationError::InvalidHeader);
+        if bytes.len() < HEADER_SIZE {
+            return Err(invalid_header());
+        }
+        let word = |i: usize| {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&bytes[8 * i..8 * (i + 1)]);
+            u64::from_le_bytes(word)
+        };
+        let as_usize = |i: usize| usize::try_from(word(i)).map_err(|_| invalid_header());
+        if word(0).to_le_bytes() != MAGIC {
+            return Err(invalid_header());
+        }
+        if word(1) != LAYOUT_VERSION {
+            return Err(EntityAlignedDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            ));
+        }
+        EntityAlignedDeserializationError::<FftSerializationError>::perform_generic_checks(
+            word(2),
+            kind as u64,
+        )?;
+        let polynomial_size = as_usize(3)?;
+        let glwe_size = as_usize(4)?;
+        let decomposition_base_log = as_usize(5)?;
+        let decomposition_level_count = as_usize(6)?;
+        let key_size = as_usize(7)?;
+        let body_len = as_usize(8)?;
+        let header = AlignedHeader {
+            kind,
+            polynomial_size: PolynomialSize(polynomial_size),
+            glwe_size: GlweSize(glwe_size),
+            decomposition_base_log: DecompositionBaseLog(decomposition_base_log),
+            decomposition_level_count: DecompositionLevelCount(decomposition_level_count),
+            key_size: LweDimension(key_size),
+        };
+        if polynomial_size < 2
+            || !polynomial_size.is_power_of_two()
+            || glwe_size == 0
+            || decomposition_base_log == 0
+            || decomposition_level_count == 0
+            || header.body_len() != Some(body_len)
+            || (matches!(
+                kind,
+                AlignedEntityKind::GgswCiphertext32 | AlignedEntityKind::GgswCiphertext64
+            ) && key_size != 0)
+        {
+            return Err(invalid_header());
+        }
+        Ok((header, word(9), word(10)))
+    }
+}
+
+/// Returns an identifier of the order in which the FFT plan of `polynomial_size` stores the
+/// coefficients of a polynomial in the Fourier domain.
+///
+/// The plans are created with a fixed base FFT, so that this order does not depend on the process
+/// creating them, but it can still change with the version or the configuration of the FFT
+/// library. The identifier is the checksum of a polynomial whose coefficients are their index in the order
+/// of the plan, serialized in the canonical order.
+fn fourier_ordering(polynomial_size: PolynomialSize) -> u64 {
+    struct CanonicalOrder<'a> {
+        fft: FftView<'a>,
+        buf: &'a [c64],
+    }
+
+    impl<'a> serde::Serialize for CanonicalOrder<'a> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.fft.serialize_fourier_buffer(serializer, self.buf)
+        }
+    }
+
+    let fft = Fft::new(polynomial_size);
+    let indices: Vec<c64> = (0..polynomial_size.0 / 2)
+        .map(|i| c64::new(i as f64, 0.0))
+        .collect();
+    let canonical = bincode::serialize(&CanonicalOrder {
+        fft: fft.as_view(),
+        buf: &indices,
+    })
+    .unwrap();
+    let mut checksum = Checksum::new();
+    checksum.update(&canonical);
+    checksum.finish()
+}
+
+/// Computes the checksum of a serialized entity, which covers the header, except for the checksum
+/// itself, and the body.
+fn checksum(bytes: &[u8]) -> u64 {
+    let mut checksum = Checksum::new();
+    checksum.update(&bytes[..CHECKSUM_BYTES.start]);
+    checksum.update(&bytes[CHECKSUM_BYTES.end..]);
+    checksum.finish()
+}
+
+/// Serializes an entity with the aligned layout, in a cache-line aligned buffer.
+pub(super) fn serialize_aligned(header: AlignedHeader, data: &[c64]) -> ABox<[u8]> {
+    debug_assert_eq!(header.body_len(), Some(data.len()));
+    let mut buffer = avec![0u8; HEADER_SIZE + data.len() * C64_SIZE];
+    buffer[..HEADER_SIZE].copy_from_slice(&header.to_bytes());
+    for (chunk, value) in buffer[HEADER_SIZE..]
+        .chunks_exact_mut(C64_SIZE)
+        .zip(data.iter())
+    {
+        chunk[..8].copy_from_slice(&value.re.to_le_bytes());
+        chunk[8..].copy_from_slice(&value.im.to_le_bytes());
+    }
+    let checksum = checksum(&buffer);
+    buffer[CHECKSUM_BYTES].copy_from_slice(&checksum.to_le_bytes());
+    buffer.into_boxed_slice()
+}
+
+/// Validates a buffer containing an entity serialized with the aligned layout, and returns its
+/// header along with a view to its body.
+///
+/// The checksum of the entity is only verified if `checksum_verification` is set, as this
+/// requires to read the whole buffer. The Fourier coefficients being used in place, the buffer is
+/// rejected if they were not stored in the order of the FFT plan of the current process.
+#[cfg(target_endian = "little")]
+pub(super) fn deserialize_aligned(
+    bytes: &[u8],
+    kind: AlignedEntityKind,
+    checksum_verification: ChecksumVerification,
+) -> Result<(AlignedHeader, &[c64]), EntityAlignedDeserializationError<FftSerializationError>> {
+    let (header, expected_checksum, ordering) = AlignedHeader::from_bytes(bytes, kind)?;
+    let body_len = header.body_len().unwrap();
+    let body_bytes = &bytes[HEADER_SIZE..];
+    if body_len.checked_mul(C64_SIZE) != Some(body_bytes.len()) {
+        return Err(EntityAlignedDeserializationError::Engine(
+            FftSerializationError::InvalidHeader,
+        ));
+    }
+    if body_bytes.as_ptr() as usize % core::mem::align_of::<c64>() != 0 {
+        return Err(EntityAlignedDeserializationError::Engine(
+            FftSerializationError::MisalignedBuffer,
+        ));
+    }
+    if checksum_verification.0 && checksum(bytes) != expected_checksum {
+        return Err(EntityAlignedDeserializationError::ChecksumMismatch);
+    }
+    if ordering != fourier_ordering(header.polynomial_size) {
+        return Err(EntityAlignedDeserializationError::Engine(
+            FftSerializationError::FourierOrderingMismatch,
+        ));
+    }
+    // SAFETY: the body is correctly aligned for `c64` and contains exactly `body_len` complex
+    // numbers, each one stored as two little endian `f64`, which is the in-memory representation
+    // of `c64` on little endian targets. Any bit pattern is a valid `f64`.
+    let body = unsafe { core::slice::from_raw_parts(body_bytes.as_ptr() as *const c64, body_len) };
+    Ok((header, body))
+}
+
+#[cfg(all(test, target_endian = "little"))]
+mod test {
+    use super::*;
+
+    fn header() -> AlignedHeader {
+        AlignedHeader {
+            kind: AlignedEntityKind::LweBootstrapKey64,
+            polynomial_size: PolynomialSize(4),
+            glwe_size: GlweSize(2),
+            decomposition_base_log: DecompositionBaseLog(5),
+            decomposition_level_count: DecompositionLevelCount(3),
+            key_size: LweDimension(2),
+        }
+    }
+
+    fn data() -> Vec<c64> {
+        (0..header().body_len().unwrap())
+            .map(|i| c64::new(i as f64, -(i as f64)))
+            .collect()
+    }
+
+    #[test]
+    fn test_aligned_layout_roundtrip() {
+        let serialized = serialize_aligned(header(), &data());
+        let (recovered_header, recovered_data) = deserialize_aligned(
+            &serialized,
+            AlignedEntityKind::LweBootstrapKey64,
+            ChecksumVerification(true),
+        )
+        .unwrap();
+        assert_eq!(recovered_header, header());
+        assert_eq!(recovered_data, data().as_slice());
+        assert_eq!(
+            recovered_data.as_ptr() as usize,
+            serialized.as_ptr() as usize + HEADER_SIZE
+        );
+    }
+
+    #[test]
+    fn test_aligned_layout_errors() {
+        let serialized = serialize_aligned(header(), &data());
+        assert!(matches!(
+            deserialize_aligned(
+                &serialized,
+                AlignedEntityKind::LweBootstrapKey32,
+                ChecksumVerification(true)
+            ),
+            Err(EntityAlignedDeserializationError::EntityKindMismatch)
+        ));
+        assert!(matches!(
+            deserialize_aligned(
+                &serialized[..HEADER_SIZE + 16],
+                AlignedEntityKind::LweBootstrapKey64,
+                ChecksumVerification(true)
+            ),
+            Err(EntityAlignedDeserializationError::Engine(
+                FftSerializationError::InvalidHeader
+            ))
+        ));
+
+        let mut corrupted = avec![0u8; serialized.len()];
+        corrupted.copy_from_slice(&serialized);
+        *corrupted.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            deserialize_aligned(
+                &corrupted,
+                AlignedEntityKind::LweBootstrapKey64,
+                ChecksumVerification(true)
+            ),
+            Err(EntityAlignedDeserializationError::ChecksumMismatch)
+        ));
+        // The checksum is not verified on request, to avoid reading the whole buffer.
+        assert!(deserialize_aligned(
+            &corrupted,
+            AlignedEntityKind::LweBootstrapKey64,
+            ChecksumVerification(false)
+        )
+        .is_ok());
+
+        let mut shifted = avec![0u8; serialized.len() + 1];
+        shifted[1..].copy_from_slice(&serialized);
+        assert!(matches!(
+            deserialize_aligned(
+                &shifted[1..],
+                AlignedEntityKind::LweBootstrapKey64,
+                ChecksumVerification(true)
+            ),
+            Err(EntityAlignedDeserializationError::Engine(
+                FftSerializationError::MisalignedBuffer
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_aligned_layout_other_fourier_ordering() {
+        // Simulates a buffer serialized with a version of the FFT library whose plan stores the
+        // Fourier coefficients in another order, which can not be used in place.
+        let serialized = serialize_aligned(header(), &data());
+        let mut other_plan = avec![0u8; serialized.len()];
+        other_plan.copy_from_slice(&serialized);
+        let ordering = u64::from_le_bytes(other_plan[80..88].try_into().unwrap());
+        assert_eq!(ordering, fourier_ordering(header().polynomial_size));
+        other_plan[80..88].copy_from_slice(&(!ordering).to_le_bytes());
+        let checksum = checksum(&other_plan);
+        other_plan[CHECKSUM_BYTES].copy_from_slice(&checksum.to_le_bytes());
+        assert!(matches!(
+            deserialize_aligned(
+                &other_plan,
+                AlignedEntityKind::LweBootstrapKey64,
+                ChecksumVerification(true)
+            ),
+            Err(EntityAlignedDeserializationError::Engine(
+                FftSerializationError::FourierOrderingMismatch
+            ))
+        ));
+        assert!(matches!(
+            deserialize_aligned(
+                &other_plan,
+                AlignedEntityKind::LweBootstrapKey64,
+                ChecksumVerification(false)
+            ),
+            Err(EntityAlignedDeserializationError::Engine(
+                FftSerializationError::FourierOrderingMismatch
+            ))
+        ));
+    }
+}
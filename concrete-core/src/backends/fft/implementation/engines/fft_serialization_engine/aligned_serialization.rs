@@ -0,0 +1,300 @@
+#![allow(clippy::missing_safety_doc)]
+
+use super::aligned_layout::{serialize_aligned, AlignedEntityKind, AlignedHeader};
+use super::FftSerializationEngine;
+use crate::prelude::{
+    EntityAlignedSerializationEngine, EntityAlignedSerializationError, FftFourierGgswCiphertext32,
+    FftFourierGgswCiphertext64, FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64,
+    LweDimension,
+};
+use aligned_vec::ABox;
+
+/// # Description:
+/// Implementation of [`EntityAlignedSerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It serializes a GGSW ciphertext in the Fourier domain with the
+/// aligned layout described in the [`FftSerializationEngine`] documentation.
+impl EntityAlignedSerializationEngine<FftFourierGgswCiphertext32, ABox<[u8]>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext32 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_aligned(&fourier_ciphertext)?;
+    ///
+    /// // The view borrows the serialized buffer, which could as well be a memory-mapped file.
+    /// let view: FftFourierGgswCiphertextView32 = serialization_engine.deserialize(&serialized[..])?;
+    /// assert_eq!(view.polynomial_size(), polynomial_size);
+    /// assert_eq!(view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(view.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_aligned(
+        &mut self,
+        entity: &FftFourierGgswCiphertext32,
+    ) -> Result<ABox<[u8]>, EntityAlignedSerializationError<Self::EngineError>> {
+        Ok(unsafe { self.serialize_aligned_unchecked(entity) })
+    }
+
+    unsafe fn serialize_aligned_unchecked(
+        &mut self,
+        entity: &FftFourierGgswCiphertext32,
+    ) -> ABox<[u8]> {
+        let header = AlignedHeader {
+            kind: AlignedEntityKind::GgswCiphertext32,
+            polynomial_size: entity.0.polynomial_size(),
+            glwe_size: entity.0.glwe_size(),
+            decomposition_base_log: entity.0.decomposition_base_log(),
+            decomposition_level_count: entity.0.decomposition_level_count(),
+            key_size: LweDimension(0),
+        };
+        serialize_aligned(header, entity.0.as_view().data())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityAlignedSerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It serializes a GGSW ciphertext in the Fourier domain with the
+/// aligned layout described in the [`FftSerializationEngine`] documentation.
+impl EntityAlignedSerializationEngine<FftFourierGgswCiphertext64, ABox<[u8]>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext64 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_aligned(&fourier_ciphertext)?;
+    ///
+    /// // The view borrows the serialized buffer, which could as well be a memory-mapped file.
+    /// let view: FftFourierGgswCiphertextView64 = serialization_engine.deserialize(&serialized[..])?;
+    /// assert_eq!(view.polynomial_size(), polynomial_size);
+    /// assert_eq!(view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(view.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_aligned(
+        &mut self,
+        entity: &FftFourierGgswCiphertext64,
+    ) -> Result<ABox<[u8]>, EntityAlignedSerializationError<Self::EngineError>> {
+        Ok(unsafe { self.serialize_aligned_unchecked(entity) })
+    }
+
+    unsafe fn serialize_aligned_unchecked(
+        &mut self,
+        entity: &FftFourierGgswCiphertext64,
+    ) -> ABox<[u8]> {
+        let header = AlignedHeader {
+            kind: AlignedEntityKind::GgswCiphertext64,
+            polynomial_size: entity.0.polynomial_size(),
+            glwe_size: entity.0.glwe_size(),
+            decomposition_base_log: entity.0.decomposition_base_log(),
+            decomposition_level_count: entity.0.decomposition_level_count(),
+            key_size: LweDimension(0),
+        };
+        serialize_aligned(header, entity.0.as_view().data())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityAlignedSerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It serializes an LWE bootstrap key in the Fourier domain with the
+/// aligned layout described in the [`FftSerializationEngine`] documentation.
+impl EntityAlignedSerializationEngine<FftFourierLweBootstrapKey32, ABox<[u8]>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_aligned(&fourier_bsk)?;
+    ///
+    /// // The view borrows the serialized buffer, which could as well be a memory-mapped file.
+    /// let view: FftFourierLweBootstrapKeyView32 = serialization_engine.deserialize(&serialized[..])?;
+    /// assert_eq!(view.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(view.glwe_dimension(), glwe_dim);
+    /// assert_eq!(view.polynomial_size(), poly_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_aligned(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey32,
+    ) -> Result<ABox<[u8]>, EntityAlignedSerializationError<Self::EngineError>> {
+        Ok(unsafe { self.serialize_aligned_unchecked(entity) })
+    }
+
+    unsafe fn serialize_aligned_unchecked(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey32,
+    ) -> ABox<[u8]> {
+        let header = AlignedHeader {
+            kind: AlignedEntityKind::LweBootstrapKey32,
+            polynomial_size: entity.0.polynomial_size(),
+            glwe_size: entity.0.glwe_size(),
+            decomposition_base_log: entity.0.decomposition_base_log(),
+            decomposition_level_count: entity.0.decomposition_level_count(),
+            key_size: entity.0.key_size(),
+        };
+        serialize_aligned(header, entity.0.as_view().data())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityAlignedSerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It serializes an LWE bootstrap key in the Fourier domain with the
+/// aligned layout described in the [`FftSerializationEngine`] documentation.
+impl EntityAlignedSerializationEngine<FftFourierLweBootstrapKey64, ABox<[u8]>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_aligned(&fourier_bsk)?;
+    ///
+    /// // The view borrows the serialized buffer, which could as well be a memory-mapped file.
+    /// let view: FftFourierLweBootstrapKeyView64 = serialization_engine.deserialize(&serialized[..])?;
+    /// assert_eq!(view.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(view.glwe_dimension(), glwe_dim);
+    /// assert_eq!(view.polynomial_size(), poly_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_aligned(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey64,
+    ) -> Result<ABox<[u8]>, EntityAlignedSerializationError<Self::EngineError>> {
+        Ok(unsafe { self.serialize_aligned_unchecked(entity) })
+    }
+
+    unsafe fn serialize_aligned_unchecked(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey64,
+    ) -> ABox<[u8]> {
+        let header = AlignedHeader {
+            kind: AlignedEntityKind::LweBootstrapKey64,
+            polynomial_size: entity.0.polynomial_size(),
+            glwe_size: entity.0.glwe_size(),
+            decomposition_base_log: entity.0.decomposition_base_log(),
+            decomposition_level_count: entity.0.decomposition_level_count(),
+            key_size: entity.0.key_size(),
+        };
+        serialize_aligned(header, entity.0.as_view().data())
+    }
+}
@@ -3,12 +3,16 @@
 use super::{FftSerializationEngine, FftSerializationError};
 use crate::backends::fft::private::crypto::bootstrap::FourierLweBootstrapKey;
 use crate::backends::fft::private::crypto::ggsw::FourierGgswCiphertext;
+use crate::backends::fft::private::crypto::ternary_bootstrap::FourierLweTernaryBootstrapKey;
+use crate::commons::serialization::deserialize_with_header;
 use crate::prelude::{
     EntityDeserializationEngine, EntityDeserializationError, FftFourierGgswCiphertext32,
     FftFourierGgswCiphertext32Version, FftFourierGgswCiphertext64,
     FftFourierGgswCiphertext64Version, FftFourierLweBootstrapKey32,
     FftFourierLweBootstrapKey32Version, FftFourierLweBootstrapKey64,
-    FftFourierLweBootstrapKey64Version,
+    FftFourierLweBootstrapKey64Version, FftFourierLweTernaryBootstrapKey32,
+    FftFourierLweTernaryBootstrapKey32Version, FftFourierLweTernaryBootstrapKey64,
+    FftFourierLweTernaryBootstrapKey64Version,
 };
 use aligned_vec::ABox;
 use concrete_fft::c64;
@@ -70,9 +74,11 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext32> for FftSeria
             version: FftFourierGgswCiphertext32Version,
             inner: FourierGgswCiphertext<ABox<[c64]>>,
         }
-        let deserialized: SerializableFftFourierGgswCiphertext32 = bincode::deserialize(serialized)
-            .map_err(FftSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let (deserialized, parameters): (SerializableFftFourierGgswCiphertext32, _) =
+            deserialize_with_header::<FftFourierGgswCiphertext32, _>(
+                serialized,
+                self.accept_legacy,
+            )?;
         match deserialized {
             SerializableFftFourierGgswCiphertext32 {
                 version: FftFourierGgswCiphertext32Version::Unsupported,
@@ -83,7 +89,7 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext32> for FftSeria
             SerializableFftFourierGgswCiphertext32 {
                 version: FftFourierGgswCiphertext32Version::V0,
                 inner,
-            } => Ok(FftFourierGgswCiphertext32(inner)),
+            } => parameters.verify(FftFourierGgswCiphertext32(inner)),
         }
     }
 
@@ -149,9 +155,11 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext64> for FftSeria
             version: FftFourierGgswCiphertext64Version,
             inner: FourierGgswCiphertext<ABox<[c64]>>,
         }
-        let deserialized: SerializableFftFourierGgswCiphertext64 = bincode::deserialize(serialized)
-            .map_err(FftSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let (deserialized, parameters): (SerializableFftFourierGgswCiphertext64, _) =
+            deserialize_with_header::<FftFourierGgswCiphertext64, _>(
+                serialized,
+                self.accept_legacy,
+            )?;
         match deserialized {
             SerializableFftFourierGgswCiphertext64 {
                 version: FftFourierGgswCiphertext64Version::Unsupported,
@@ -162,7 +170,7 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext64> for FftSeria
             SerializableFftFourierGgswCiphertext64 {
                 version: FftFourierGgswCiphertext64Version::V0,
                 inner,
-            } => Ok(FftFourierGgswCiphertext64(inner)),
+            } => parameters.verify(FftFourierGgswCiphertext64(inner)),
         }
     }
 
@@ -220,10 +228,11 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey32> for FftSeri
             version: FftFourierLweBootstrapKey32Version,
             inner: FourierLweBootstrapKey<ABox<[c64]>>,
         }
-        let deserialized: SerializableFftFourierLweBootstrapKey32 =
-            bincode::deserialize(serialized)
-                .map_err(FftSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+        let (deserialized, parameters): (SerializableFftFourierLweBootstrapKey32, _) =
+            deserialize_with_header::<FftFourierLweBootstrapKey32, _>(
+                serialized,
+                self.accept_legacy,
+            )?;
         match deserialized {
             SerializableFftFourierLweBootstrapKey32 {
                 version: FftFourierLweBootstrapKey32Version::Unsupported,
@@ -234,7 +243,7 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey32> for FftSeri
             SerializableFftFourierLweBootstrapKey32 {
                 version: FftFourierLweBootstrapKey32Version::V0,
                 inner,
-            } => Ok(FftFourierLweBootstrapKey32(inner)),
+            } => parameters.verify(FftFourierLweBootstrapKey32(inner)),
         }
     }
 
@@ -292,10 +301,11 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey64> for FftSeri
             version: FftFourierLweBootstrapKey64Version,
             inner: FourierLweBootstrapKey<ABox<[c64]>>,
         }
-        let deserialized: SerializableFftFourierLweBootstrapKey64 =
-            bincode::deserialize(serialized)
-                .map_err(FftSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+        let (deserialized, parameters): (SerializableFftFourierLweBootstrapKey64, _) =
+            deserialize_with_header::<FftFourierLweBootstrapKey64, _>(
+                serialized,
+                self.accept_legacy,
+            )?;
         match deserialized {
             SerializableFftFourierLweBootstrapKey64 {
                 version: FftFourierLweBootstrapKey64Version::Unsupported,
@@ -306,7 +316,7 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey64> for FftSeri
             SerializableFftFourierLweBootstrapKey64 {
                 version: FftFourierLweBootstrapKey64Version::V0,
                 inner,
-            } => Ok(FftFourierLweBootstrapKey64(inner)),
+            } => parameters.verify(FftFourierLweBootstrapKey64(inner)),
         }
     }
 
@@ -314,3 +324,165 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey64> for FftSeri
         self.deserialize(serialized).unwrap()
     }
 }
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`FftSerializationEngine`] that operates
+/// on 32 bits integers. It deserializes an LWE bootstrap key for a ternary input key in the
+/// Fourier domain.
+impl EntityDeserializationEngine<&[u8], FftFourierLweTernaryBootstrapKey32>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweTernarySecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey32 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<FftFourierLweTernaryBootstrapKey32, EntityDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierLweTernaryBootstrapKey32 {
+            version: FftFourierLweTernaryBootstrapKey32Version,
+            inner: FourierLweTernaryBootstrapKey<ABox<[c64]>>,
+        }
+        let (deserialized, parameters): (SerializableFftFourierLweTernaryBootstrapKey32, _) =
+            deserialize_with_header::<FftFourierLweTernaryBootstrapKey32, _>(
+                serialized,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            SerializableFftFourierLweTernaryBootstrapKey32 {
+                version: FftFourierLweTernaryBootstrapKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierLweTernaryBootstrapKey32 {
+                version: FftFourierLweTernaryBootstrapKey32Version::V0,
+                inner,
+            } => parameters.verify(FftFourierLweTernaryBootstrapKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> FftFourierLweTernaryBootstrapKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`FftSerializationEngine`] that operates
+/// on 64 bits integers. It deserializes an LWE bootstrap key for a ternary input key in the
+/// Fourier domain.
+impl EntityDeserializationEngine<&[u8], FftFourierLweTernaryBootstrapKey64>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweTernarySecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey64 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<FftFourierLweTernaryBootstrapKey64, EntityDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierLweTernaryBootstrapKey64 {
+            version: FftFourierLweTernaryBootstrapKey64Version,
+            inner: FourierLweTernaryBootstrapKey<ABox<[c64]>>,
+        }
+        let (deserialized, parameters): (SerializableFftFourierLweTernaryBootstrapKey64, _) =
+            deserialize_with_header::<FftFourierLweTernaryBootstrapKey64, _>(
+                serialized,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            SerializableFftFourierLweTernaryBootstrapKey64 {
+                version: FftFourierLweTernaryBootstrapKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierLweTernaryBootstrapKey64 {
+                version: FftFourierLweTernaryBootstrapKey64Version::V0,
+                inner,
+            } => parameters.verify(FftFourierLweTernaryBootstrapKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> FftFourierLweTernaryBootstrapKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
@@ -0,0 +1,41 @@
+//! The descriptions stored in the headers of the entities serialized by the
+//! `FftSerializationEngine`.
+
+use crate::commons::serialization::describe_entities;
+use crate::prelude::{
+    FftFourierGgswCiphertext32, FftFourierGgswCiphertext64, FftFourierLweBootstrapKey32,
+    FftFourierLweBootstrapKey64, FftFourierLweTernaryBootstrapKey32,
+    FftFourierLweTernaryBootstrapKey64, GgswCiphertextEntity, LweBootstrapKeyEntity,
+};
+
+describe_entities! {
+    "FftFourierGgswCiphertext" => [FftFourierGgswCiphertext32: 32, FftFourierGgswCiphertext64: 64]
+    |e| vec![
+        e.glwe_dimension().0,
+        e.polynomial_size().0,
+        e.decomposition_level_count().0,
+        e.decomposition_base_log().0,
+    ];
+    "FftFourierLweBootstrapKey" => [
+        FftFourierLweBootstrapKey32: 32,
+        FftFourierLweBootstrapKey64: 64
+    ]
+    |e| vec![
+        e.input_lwe_dimension().0,
+        e.glwe_dimension().0,
+        e.polynomial_size().0,
+        e.decomposition_level_count().0,
+        e.decomposition_base_log().0,
+    ];
+    "FftFourierLweTernaryBootstrapKey" => [
+        FftFourierLweTernaryBootstrapKey32: 32,
+        FftFourierLweTernaryBootstrapKey64: 64
+    ]
+    |e| vec![
+        e.input_lwe_dimension().0,
+        e.glwe_dimension().0,
+        e.polynomial_size().0,
+        e.decomposition_level_count().0,
+        e.decomposition_base_log().0,
+    ];
+}
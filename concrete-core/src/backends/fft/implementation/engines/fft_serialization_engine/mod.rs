@@ -1,5 +1,8 @@
 use crate::specification::engines::sealed::AbstractEngineSeal;
-use crate::specification::engines::AbstractEngine;
+use crate::specification::engines::{
+    AbstractEngine, EntityAlignedDeserializationError, EntityDeserializationError,
+    EntityStreamDeserializationError,
+};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
@@ -9,6 +12,9 @@ pub enum FftSerializationError {
     Serialization(bincode::Error),
     Deserialization(bincode::Error),
     UnsupportedVersion,
+    InvalidHeader,
+    MisalignedBuffer,
+    FourierOrderingMismatch,
 }
 
 impl Display for FftSerializationError {
@@ -26,14 +32,145 @@ impl Display for FftSerializationError {
                     "The version used to serialize the entity is not supported."
                 )
             }
+            FftSerializationError::InvalidHeader => {
+                write!(f, "The header of the serialized entity is invalid.")
+            }
+            FftSerializationError::MisalignedBuffer => {
+                write!(
+                    f,
+                    "The buffer containing the serialized entity is not correctly aligned."
+                )
+            }
+            FftSerializationError::FourierOrderingMismatch => {
+                write!(
+                    f,
+                    "The Fourier coefficients of the serialized entity are not stored in the \
+                    order used by the FFT plans of this process."
+                )
+            }
         }
     }
 }
 
 impl Error for FftSerializationError {}
 
+impl From<EntityDeserializationError<bincode::Error>>
+    for EntityDeserializationError<FftSerializationError>
+{
+    fn from(error: EntityDeserializationError<bincode::Error>) -> Self {
+        match error {
+            EntityDeserializationError::InvalidHeader => Self::InvalidHeader,
+            EntityDeserializationError::ChecksumMismatch => Self::ChecksumMismatch,
+            EntityDeserializationError::EntityKindMismatch => Self::EntityKindMismatch,
+            EntityDeserializationError::ParameterMismatch => Self::ParameterMismatch,
+            EntityDeserializationError::Engine(error) => {
+                Self::Engine(FftSerializationError::Deserialization(error))
+            }
+        }
+    }
+}
+
+impl From<EntityDeserializationError<bincode::Error>>
+    for EntityStreamDeserializationError<FftSerializationError>
+{
+    fn from(error: EntityDeserializationError<bincode::Error>) -> Self {
+        match error {
+            EntityDeserializationError::InvalidHeader => Self::InvalidHeader,
+            EntityDeserializationError::ChecksumMismatch => Self::ChecksumMismatch,
+            EntityDeserializationError::EntityKindMismatch => Self::EntityKindMismatch,
+            EntityDeserializationError::ParameterMismatch => Self::ParameterMismatch,
+            EntityDeserializationError::Engine(error) => {
+                Self::Engine(FftSerializationError::Deserialization(error))
+            }
+        }
+    }
+}
+
+impl From<EntityAlignedDeserializationError<FftSerializationError>>
+    for EntityDeserializationError<FftSerializationError>
+{
+    fn from(error: EntityAlignedDeserializationError<FftSerializationError>) -> Self {
+        match error {
+            EntityAlignedDeserializationError::ChecksumMismatch => Self::ChecksumMismatch,
+            EntityAlignedDeserializationError::EntityKindMismatch => Self::EntityKindMismatch,
+            EntityAlignedDeserializationError::Engine(error) => Self::Engine(error),
+        }
+    }
+}
+
 /// The serialization engine exposed by the fft backend.
-pub struct FftSerializationEngine;
+///
+/// Entities are serialized with bincode, along with a header storing their kind, their precision
+/// and their parameters, followed by a checksum of the serialized bytes. Corrupted data, as well
+/// as data which was not serialized from the requested entity, are reported by the
+/// deserialization engines through the generic variants of [`EntityDeserializationError`] and
+/// [`EntityStreamDeserializationError`]. Truncated data is reported as an `UnexpectedEof` I/O
+/// error.
+///
+/// # Aligned layout
+///
+/// On top of the bincode serialization, the Fourier LWE bootstrap keys and GGSW ciphertexts can be
+/// serialized into an [`ABox<[u8]>`](aligned_vec::ABox) using an aligned layout, through the
+/// [`EntityAlignedSerializationEngine`](crate::prelude::EntityAlignedSerializationEngine). A buffer
+/// containing an entity serialized this way can then be deserialized into a view entity (for
+/// instance [`FftFourierLweBootstrapKeyView64`](crate::prelude::FftFourierLweBootstrapKeyView64))
+/// without copying the Fourier coefficients. This allows to use keys stored in a memory-mapped
+/// file directly. The
+/// [`EntityAlignedDeserializationEngine`](crate::prelude::EntityAlignedDeserializationEngine)
+/// allows to skip the verification of the checksum, which requires to read the whole buffer,
+/// while the header is still validated.
+///
+/// The layout is made of a header of 128 bytes, followed by the body. The header is made of
+/// sixteen little-endian `u64` words:
+///
+/// + word 0: the magic bytes `CCFOURIE`;
+/// + word 1: the version of the layout, currently 1;
+/// + word 2: the kind of the entity, 0 for a 32 bits GGSW ciphertext, 1 for a 64 bits GGSW
+///   ciphertext, 2 for a 32 bits LWE bootstrap key and 3 for a 64 bits LWE bootstrap key;
+/// + word 3: the polynomial size;
+/// + word 4: the GLWE size;
+/// + word 5: the decomposition base log;
+/// + word 6: the decomposition level count;
+/// + word 7: the input LWE dimension of an LWE bootstrap key, 0 for a GGSW ciphertext;
+/// + word 8: the number of complex numbers in the body;
+/// + word 9: the 64 bits FNV-1a checksum of the bytes of all the other words of the header,
+///   followed by the bytes of the body, whose mismatch is reported as
+///   [`EntityDeserializationError::ChecksumMismatch`];
+/// + word 10: an identifier of the order of the Fourier coefficients;
+/// + words 11 to 15: reserved, set to 0.
+///
+/// The body contains the Fourier coefficients of the entity, in the order used in memory by the
+/// owned entity. Each coefficient is stored as two little-endian `f64`, the real part first. This
+/// order depends on the FFT plan. The plans are created with a fixed base FFT instead of a measured
+/// one, so that every process uses the same order, but it can still change with the version of
+/// the FFT library. Since the body is used in place, a buffer whose coefficients were not stored in
+/// the order of the current plan is rejected with
+/// [`FftSerializationError::FourierOrderingMismatch`]. Such an entity can still be transferred with
+/// the regular serialization engines, which store the Fourier coefficients in a canonical order.
+///
+/// Since the header size is a multiple of the cache line size, the body of an entity serialized
+/// into a cache-line aligned buffer, or at the start of a memory-mapped file, is aligned as well.
+/// Deserializing into a view requires the body to be at least aligned for `f64`, and is only
+/// available on little-endian targets.
+///
+/// # Legacy entities
+///
+/// Entities serialized with bincode by the versions of the library preceding the introduction of
+/// the header are rejected by default. They can still be loaded after calling
+/// [`accept_legacy_entities`](FftSerializationEngine::accept_legacy_entities), in which case the
+/// input which does not start with the header is decoded without any check.
+pub struct FftSerializationEngine {
+    accept_legacy: bool,
+}
+
+impl FftSerializationEngine {
+    /// Sets whether the deserialization engines accept legacy entities, serialized without
+    /// header. Since such entities carry neither a checksum nor a description, corrupted data or
+    /// data serialized from another entity may not be detected.
+    pub fn accept_legacy_entities(&mut self, accept: bool) {
+        self.accept_legacy = accept;
+    }
+}
 
 impl AbstractEngineSeal for FftSerializationEngine {}
 
@@ -42,9 +179,18 @@ impl AbstractEngine for FftSerializationEngine {
     type Parameters = ();
 
     fn new(_parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
-        Ok(FftSerializationEngine)
+        Ok(FftSerializationEngine {
+            accept_legacy: false,
+        })
     }
 }
 
+#[cfg(target_endian = "little")]
+mod aligned_deserialization;
+mod aligned_layout;
+mod aligned_serialization;
 mod deserialization;
+mod entity_description;
 mod serialization;
+mod stream_deserialization;
+mod stream_serialization;
@@ -3,12 +3,16 @@
 use super::{FftSerializationEngine, FftSerializationError};
 use crate::backends::fft::private::crypto::bootstrap::FourierLweBootstrapKeyView;
 use crate::backends::fft::private::crypto::ggsw::FourierGgswCiphertextView;
+use crate::backends::fft::private::crypto::ternary_bootstrap::FourierLweTernaryBootstrapKeyView;
+use crate::commons::serialization::serialize_with_header;
 use crate::prelude::{
     EntitySerializationEngine, EntitySerializationError, FftFourierGgswCiphertext32,
     FftFourierGgswCiphertext32Version, FftFourierGgswCiphertext64,
     FftFourierGgswCiphertext64Version, FftFourierLweBootstrapKey32,
     FftFourierLweBootstrapKey32Version, FftFourierLweBootstrapKey64,
-    FftFourierLweBootstrapKey64Version,
+    FftFourierLweBootstrapKey64Version, FftFourierLweTernaryBootstrapKey32,
+    FftFourierLweTernaryBootstrapKey32Version, FftFourierLweTernaryBootstrapKey64,
+    FftFourierLweTernaryBootstrapKey64Version,
 };
 use serde::Serialize;
 
@@ -74,7 +78,7 @@ impl EntitySerializationEngine<FftFourierGgswCiphertext32, Vec<u8>> for FftSeria
             version: FftFourierGgswCiphertext32Version::V0,
             inner: entity,
         };
-        bincode::serialize(&value)
+        serialize_with_header(entity, &value)
             .map_err(FftSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
@@ -146,7 +150,7 @@ impl EntitySerializationEngine<FftFourierGgswCiphertext64, Vec<u8>> for FftSeria
             version: FftFourierGgswCiphertext64Version::V0,
             inner: entity,
         };
-        bincode::serialize(&value)
+        serialize_with_header(entity, &value)
             .map_err(FftSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
@@ -210,7 +214,7 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey32, Vec<u8>> for FftSeri
             version: FftFourierLweBootstrapKey32Version::V0,
             inner: entity,
         };
-        bincode::serialize(&value)
+        serialize_with_header(entity, &value)
             .map_err(FftSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
@@ -274,7 +278,7 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey64, Vec<u8>> for FftSeri
             version: FftFourierLweBootstrapKey64Version::V0,
             inner: entity,
         };
-        bincode::serialize(&value)
+        serialize_with_header(entity, &value)
             .map_err(FftSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
@@ -283,3 +287,145 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey64, Vec<u8>> for FftSeri
         self.serialize(entity).unwrap()
     }
 }
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`FftSerializationEngine`] that operates on
+/// 32 bits integers. It serializes an LWE bootstrap key for a ternary input key in the
+/// Fourier domain.
+impl EntitySerializationEngine<FftFourierLweTernaryBootstrapKey32, Vec<u8>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweTernarySecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey32 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &FftFourierLweTernaryBootstrapKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let entity = entity.0.as_view();
+        #[derive(Serialize)]
+        struct SerializableFftFourierLweTernaryBootstrapKey32<'a> {
+            version: FftFourierLweTernaryBootstrapKey32Version,
+            inner: FourierLweTernaryBootstrapKeyView<'a>,
+        }
+        let value = SerializableFftFourierLweTernaryBootstrapKey32 {
+            version: FftFourierLweTernaryBootstrapKey32Version::V0,
+            inner: entity,
+        };
+        serialize_with_header(entity, &value)
+            .map_err(FftSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(
+        &mut self,
+        entity: &FftFourierLweTernaryBootstrapKey32,
+    ) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`FftSerializationEngine`] that operates on
+/// 64 bits integers. It serializes an LWE bootstrap key for a ternary input key in the
+/// Fourier domain.
+impl EntitySerializationEngine<FftFourierLweTernaryBootstrapKey64, Vec<u8>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweTernarySecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey64 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &FftFourierLweTernaryBootstrapKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let entity = entity.0.as_view();
+        #[derive(Serialize)]
+        struct SerializableFftFourierLweTernaryBootstrapKey64<'a> {
+            version: FftFourierLweTernaryBootstrapKey64Version,
+            inner: FourierLweTernaryBootstrapKeyView<'a>,
+        }
+        let value = SerializableFftFourierLweTernaryBootstrapKey64 {
+            version: FftFourierLweTernaryBootstrapKey64Version::V0,
+            inner: entity,
+        };
+        serialize_with_header(entity, &value)
+            .map_err(FftSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(
+        &mut self,
+        entity: &FftFourierLweTernaryBootstrapKey64,
+    ) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
@@ -0,0 +1,515 @@
+#![allow(clippy::missing_safety_doc)]
+
+use super::{FftSerializationEngine, FftSerializationError};
+use crate::backends::fft::private::crypto::bootstrap::FourierLweBootstrapKey;
+use crate::backends::fft::private::crypto::ggsw::FourierGgswCiphertext;
+use crate::backends::fft::private::crypto::ternary_bootstrap::FourierLweTernaryBootstrapKey;
+use crate::commons::serialization::deserialize_from_with_header;
+use crate::prelude::{
+    EntityStreamDeserializationEngine, EntityStreamDeserializationError,
+    FftFourierGgswCiphertext32, FftFourierGgswCiphertext32Version, FftFourierGgswCiphertext64,
+    FftFourierGgswCiphertext64Version, FftFourierLweBootstrapKey32,
+    FftFourierLweBootstrapKey32Version, FftFourierLweBootstrapKey64,
+    FftFourierLweBootstrapKey64Version, FftFourierLweTernaryBootstrapKey32,
+    FftFourierLweTernaryBootstrapKey32Version, FftFourierLweTernaryBootstrapKey64,
+    FftFourierLweTernaryBootstrapKey64Version,
+};
+use aligned_vec::ABox;
+use concrete_fft::c64;
+use serde::Deserialize;
+use std::io::Read;
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GGSW ciphertext in the Fourier domain from a
+/// reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, FftFourierGgswCiphertext32>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext32 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&fourier_ciphertext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(fourier_ciphertext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<FftFourierGgswCiphertext32, EntityStreamDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierGgswCiphertext32 {
+            version: FftFourierGgswCiphertext32Version,
+            inner: FourierGgswCiphertext<ABox<[c64]>>,
+        }
+        let (deserialized, parameters): (SerializableFftFourierGgswCiphertext32, _) =
+            deserialize_from_with_header::<FftFourierGgswCiphertext32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            SerializableFftFourierGgswCiphertext32 {
+                version: FftFourierGgswCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierGgswCiphertext32 {
+                version: FftFourierGgswCiphertext32Version::V0,
+                inner,
+            } => parameters.verify(FftFourierGgswCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> FftFourierGgswCiphertext32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GGSW ciphertext in the Fourier domain from a
+/// reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, FftFourierGgswCiphertext64>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext64 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&fourier_ciphertext, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(fourier_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<FftFourierGgswCiphertext64, EntityStreamDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierGgswCiphertext64 {
+            version: FftFourierGgswCiphertext64Version,
+            inner: FourierGgswCiphertext<ABox<[c64]>>,
+        }
+        let (deserialized, parameters): (SerializableFftFourierGgswCiphertext64, _) =
+            deserialize_from_with_header::<FftFourierGgswCiphertext64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            SerializableFftFourierGgswCiphertext64 {
+                version: FftFourierGgswCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierGgswCiphertext64 {
+                version: FftFourierGgswCiphertext64Version::V0,
+                inner,
+            } => parameters.verify(FftFourierGgswCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> FftFourierGgswCiphertext64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes an LWE bootstrap key in the Fourier domain from a
+/// reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, FftFourierLweBootstrapKey32>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&fourier_bsk, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<FftFourierLweBootstrapKey32, EntityStreamDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierLweBootstrapKey32 {
+            version: FftFourierLweBootstrapKey32Version,
+            inner: FourierLweBootstrapKey<ABox<[c64]>>,
+        }
+        let (deserialized, parameters): (SerializableFftFourierLweBootstrapKey32, _) =
+            deserialize_from_with_header::<FftFourierLweBootstrapKey32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            SerializableFftFourierLweBootstrapKey32 {
+                version: FftFourierLweBootstrapKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierLweBootstrapKey32 {
+                version: FftFourierLweBootstrapKey32Version::V0,
+                inner,
+            } => parameters.verify(FftFourierLweBootstrapKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> FftFourierLweBootstrapKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes an LWE bootstrap key in the Fourier domain from a
+/// reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, FftFourierLweBootstrapKey64>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&fourier_bsk, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<FftFourierLweBootstrapKey64, EntityStreamDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierLweBootstrapKey64 {
+            version: FftFourierLweBootstrapKey64Version,
+            inner: FourierLweBootstrapKey<ABox<[c64]>>,
+        }
+        let (deserialized, parameters): (SerializableFftFourierLweBootstrapKey64, _) =
+            deserialize_from_with_header::<FftFourierLweBootstrapKey64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            SerializableFftFourierLweBootstrapKey64 {
+                version: FftFourierLweBootstrapKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierLweBootstrapKey64 {
+                version: FftFourierLweBootstrapKey64Version::V0,
+                inner,
+            } => parameters.verify(FftFourierLweBootstrapKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> FftFourierLweBootstrapKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes an LWE bootstrap key for a ternary input key in
+/// the Fourier domain from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, FftFourierLweTernaryBootstrapKey32>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweTernarySecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey32 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&fourier_bsk, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<
+        FftFourierLweTernaryBootstrapKey32,
+        EntityStreamDeserializationError<Self::EngineError>,
+    > {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierLweTernaryBootstrapKey32 {
+            version: FftFourierLweTernaryBootstrapKey32Version,
+            inner: FourierLweTernaryBootstrapKey<ABox<[c64]>>,
+        }
+        let (deserialized, parameters): (SerializableFftFourierLweTernaryBootstrapKey32, _) =
+            deserialize_from_with_header::<FftFourierLweTernaryBootstrapKey32, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            SerializableFftFourierLweTernaryBootstrapKey32 {
+                version: FftFourierLweTernaryBootstrapKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierLweTernaryBootstrapKey32 {
+                version: FftFourierLweTernaryBootstrapKey32Version::V0,
+                inner,
+            } => parameters.verify(FftFourierLweTernaryBootstrapKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(
+        &mut self,
+        reader: Reader,
+    ) -> FftFourierLweTernaryBootstrapKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes an LWE bootstrap key for a ternary input key in
+/// the Fourier domain from a reader.
+impl<Reader: Read> EntityStreamDeserializationEngine<Reader, FftFourierLweTernaryBootstrapKey64>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweTernarySecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey64 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&fourier_bsk, &mut serialized)?;
+    /// let recovered = serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<
+        FftFourierLweTernaryBootstrapKey64,
+        EntityStreamDeserializationError<Self::EngineError>,
+    > {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierLweTernaryBootstrapKey64 {
+            version: FftFourierLweTernaryBootstrapKey64Version,
+            inner: FourierLweTernaryBootstrapKey<ABox<[c64]>>,
+        }
+        let (deserialized, parameters): (SerializableFftFourierLweTernaryBootstrapKey64, _) =
+            deserialize_from_with_header::<FftFourierLweTernaryBootstrapKey64, _, _>(
+                reader,
+                self.accept_legacy,
+            )?;
+        match deserialized {
+            SerializableFftFourierLweTernaryBootstrapKey64 {
+                version: FftFourierLweTernaryBootstrapKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierLweTernaryBootstrapKey64 {
+                version: FftFourierLweTernaryBootstrapKey64Version::V0,
+                inner,
+            } => parameters.verify(FftFourierLweTernaryBootstrapKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(
+        &mut self,
+        reader: Reader,
+    ) -> FftFourierLweTernaryBootstrapKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
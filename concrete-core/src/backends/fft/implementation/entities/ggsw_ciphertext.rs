@@ -1,4 +1,6 @@
-use super::super::super::private::crypto::ggsw::FourierGgswCiphertext;
+use super::super::super::private::crypto::ggsw::{
+    FourierGgswCiphertext, FourierGgswCiphertextView,
+};
 use crate::prelude::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
 };
@@ -60,6 +62,67 @@ impl GgswCiphertextEntity for FftFourierGgswCiphertext64 {
     }
 }
 
+/// A structure representing a view to a GGSW ciphertext with 32 bits of precision in the Fourier
+/// domain.
+///
+/// This view borrows its data, and can be built without copy over a buffer containing a
+/// ciphertext serialized with the aligned layout of the
+/// [`FftSerializationEngine`](crate::prelude::FftSerializationEngine).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierGgswCiphertextView32<'a>(pub(crate) FourierGgswCiphertextView<'a>);
+
+/// A structure representing a view to a GGSW ciphertext with 64 bits of precision in the Fourier
+/// domain.
+///
+/// This view borrows its data, and can be built without copy over a buffer containing a
+/// ciphertext serialized with the aligned layout of the
+/// [`FftSerializationEngine`](crate::prelude::FftSerializationEngine).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierGgswCiphertextView64<'a>(pub(crate) FourierGgswCiphertextView<'a>);
+
+impl AbstractEntity for FftFourierGgswCiphertextView32<'_> {
+    type Kind = GgswCiphertextKind;
+}
+impl AbstractEntity for FftFourierGgswCiphertextView64<'_> {
+    type Kind = GgswCiphertextKind;
+}
+
+impl GgswCiphertextEntity for FftFourierGgswCiphertextView32<'_> {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+impl GgswCiphertextEntity for FftFourierGgswCiphertextView64<'_> {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
 #[cfg(feature = "backend_fft_serialization")]
 #[derive(Serialize, Deserialize)]
 pub(crate) enum FftFourierGgswCiphertext32Version {
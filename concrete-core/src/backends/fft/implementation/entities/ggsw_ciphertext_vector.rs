@@ -0,0 +1,89 @@
+use super::super::super::private::crypto::wop_pbs::FourierGgswCiphertextList;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    PolynomialSize,
+};
+use crate::specification::entities::markers::GgswCiphertextVectorKind;
+use crate::specification::entities::{AbstractEntity, GgswCiphertextVectorEntity};
+use aligned_vec::ABox;
+use concrete_fft::c64;
+#[cfg(feature = "backend_fft_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a vector of GGSW ciphertexts with 32 bits of precision in the
+/// Fourier domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierGgswCiphertextVector32(pub(crate) FourierGgswCiphertextList<ABox<[c64]>>);
+
+/// A structure representing a vector of GGSW ciphertexts with 64 bits of precision in the
+/// Fourier domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierGgswCiphertextVector64(pub(crate) FourierGgswCiphertextList<ABox<[c64]>>);
+
+impl AbstractEntity for FftFourierGgswCiphertextVector32 {
+    type Kind = GgswCiphertextVectorKind;
+}
+
+impl AbstractEntity for FftFourierGgswCiphertextVector64 {
+    type Kind = GgswCiphertextVectorKind;
+}
+
+impl GgswCiphertextVectorEntity for FftFourierGgswCiphertextVector32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        GgswCiphertextCount(self.0.count())
+    }
+}
+
+impl GgswCiphertextVectorEntity for FftFourierGgswCiphertextVector64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        GgswCiphertextCount(self.0.count())
+    }
+}
+
+#[cfg(feature = "backend_fft_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum FftFourierGgswCiphertextVector32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+#[cfg(feature = "backend_fft_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum FftFourierGgswCiphertextVector64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -0,0 +1,60 @@
+use super::super::super::private::crypto::glwe_automorphism::FourierGlweAutomorphismKey;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::GlweAutomorphismKeyKind;
+use crate::specification::entities::{AbstractEntity, GlweAutomorphismKeyEntity};
+use aligned_vec::ABox;
+use concrete_fft::c64;
+
+/// A structure representing a GLWE automorphism key with 32 bits of precision, in the Fourier
+/// domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierGlweAutomorphismKey32(pub(crate) FourierGlweAutomorphismKey<ABox<[c64]>>);
+
+/// A structure representing a GLWE automorphism key with 64 bits of precision, in the Fourier
+/// domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierGlweAutomorphismKey64(pub(crate) FourierGlweAutomorphismKey<ABox<[c64]>>);
+
+impl AbstractEntity for FftFourierGlweAutomorphismKey32 {
+    type Kind = GlweAutomorphismKeyKind;
+}
+impl AbstractEntity for FftFourierGlweAutomorphismKey64 {
+    type Kind = GlweAutomorphismKeyKind;
+}
+
+impl GlweAutomorphismKeyEntity for FftFourierGlweAutomorphismKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+impl GlweAutomorphismKeyEntity for FftFourierGlweAutomorphismKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
@@ -0,0 +1,63 @@
+use super::super::super::private::crypto::glwe_keyswitch::FourierGlweKeyswitchKey;
+use crate::specification::entities::markers::GlweKeyswitchKeyKind;
+use crate::specification::entities::{AbstractEntity, GlweKeyswitchKeyEntity};
+use aligned_vec::ABox;
+use concrete_fft::c64;
+
+/// A structure representing a GLWE keyswitch key with 32 bits of precision, in the Fourier domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierGlweKeyswitchKey32(pub(crate) FourierGlweKeyswitchKey<ABox<[c64]>>);
+
+/// A structure representing a GLWE keyswitch key with 64 bits of precision, in the Fourier domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierGlweKeyswitchKey64(pub(crate) FourierGlweKeyswitchKey<ABox<[c64]>>);
+
+impl AbstractEntity for FftFourierGlweKeyswitchKey32 {
+    type Kind = GlweKeyswitchKeyKind;
+}
+impl AbstractEntity for FftFourierGlweKeyswitchKey64 {
+    type Kind = GlweKeyswitchKeyKind;
+}
+
+impl GlweKeyswitchKeyEntity for FftFourierGlweKeyswitchKey32 {
+    fn input_glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.input_glwe_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.output_glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> crate::prelude::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> crate::prelude::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> crate::prelude::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+impl GlweKeyswitchKeyEntity for FftFourierGlweKeyswitchKey64 {
+    fn input_glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.input_glwe_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.output_glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> crate::prelude::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> crate::prelude::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> crate::prelude::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
@@ -1,4 +1,6 @@
-use super::super::super::private::crypto::bootstrap::FourierLweBootstrapKey;
+use super::super::super::private::crypto::bootstrap::{
+    FourierLweBootstrapKey, FourierLweBootstrapKeyView,
+};
 use crate::specification::entities::markers::LweBootstrapKeyKind;
 use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
 use aligned_vec::ABox;
@@ -64,6 +66,76 @@ impl LweBootstrapKeyEntity for FftFourierLweBootstrapKey64 {
     }
 }
 
+/// A structure representing a view to an LWE bootstrap key with 32 bits of precision, in the
+/// Fourier domain.
+///
+/// This view borrows its data, and can be built without copy over a buffer containing a key
+/// serialized with the aligned layout of the
+/// [`FftSerializationEngine`](crate::prelude::FftSerializationEngine), for instance a memory-mapped
+/// file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierLweBootstrapKeyView32<'a>(pub(crate) FourierLweBootstrapKeyView<'a>);
+
+/// A structure representing a view to an LWE bootstrap key with 64 bits of precision, in the
+/// Fourier domain.
+///
+/// This view borrows its data, and can be built without copy over a buffer containing a key
+/// serialized with the aligned layout of the
+/// [`FftSerializationEngine`](crate::prelude::FftSerializationEngine), for instance a memory-mapped
+/// file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierLweBootstrapKeyView64<'a>(pub(crate) FourierLweBootstrapKeyView<'a>);
+
+impl AbstractEntity for FftFourierLweBootstrapKeyView32<'_> {
+    type Kind = LweBootstrapKeyKind;
+}
+impl AbstractEntity for FftFourierLweBootstrapKeyView64<'_> {
+    type Kind = LweBootstrapKeyKind;
+}
+
+impl LweBootstrapKeyEntity for FftFourierLweBootstrapKeyView32<'_> {
+    fn glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> crate::prelude::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> crate::prelude::LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> crate::prelude::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> crate::prelude::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
+impl LweBootstrapKeyEntity for FftFourierLweBootstrapKeyView64<'_> {
+    fn glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> crate::prelude::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> crate::prelude::LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> crate::prelude::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> crate::prelude::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
+
 #[cfg(feature = "backend_fft_serialization")]
 #[derive(Serialize, Deserialize)]
 pub(crate) enum FftFourierLweBootstrapKey32Version {
@@ -0,0 +1,86 @@
+use super::super::super::private::crypto::ternary_bootstrap::FourierLweTernaryBootstrapKey;
+use crate::specification::entities::markers::LweBootstrapKeyKind;
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+use aligned_vec::ABox;
+use concrete_fft::c64;
+#[cfg(feature = "backend_fft_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing an LWE bootstrap key for a ternary input key, with 32 bits of
+/// precision, in the Fourier domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierLweTernaryBootstrapKey32(
+    pub(crate) FourierLweTernaryBootstrapKey<ABox<[c64]>>,
+);
+
+/// A structure representing an LWE bootstrap key for a ternary input key, with 64 bits of
+/// precision, in the Fourier domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierLweTernaryBootstrapKey64(
+    pub(crate) FourierLweTernaryBootstrapKey<ABox<[c64]>>,
+);
+
+impl AbstractEntity for FftFourierLweTernaryBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl AbstractEntity for FftFourierLweTernaryBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+
+impl LweBootstrapKeyEntity for FftFourierLweTernaryBootstrapKey32 {
+    fn glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> crate::prelude::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> crate::prelude::LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> crate::prelude::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> crate::prelude::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
+impl LweBootstrapKeyEntity for FftFourierLweTernaryBootstrapKey64 {
+    fn glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> crate::prelude::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> crate::prelude::LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> crate::prelude::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> crate::prelude::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
+
+#[cfg(feature = "backend_fft_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum FftFourierLweTernaryBootstrapKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+#[cfg(feature = "backend_fft_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum FftFourierLweTernaryBootstrapKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
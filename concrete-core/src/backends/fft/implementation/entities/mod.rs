@@ -2,7 +2,17 @@
 //! Concrete-FFT backend.
 
 mod ggsw_ciphertext;
+mod ggsw_ciphertext_vector;
+mod glwe_automorphism_key;
+mod glwe_keyswitch_key;
+mod glwe_relinearization_key;
 mod lwe_bootstrap_key;
+mod lwe_ternary_bootstrap_key;
 
 pub use ggsw_ciphertext::*;
+pub use ggsw_ciphertext_vector::*;
+pub use glwe_automorphism_key::*;
+pub use glwe_keyswitch_key::*;
+pub use glwe_relinearization_key::*;
 pub use lwe_bootstrap_key::*;
+pub use lwe_ternary_bootstrap_key::*;
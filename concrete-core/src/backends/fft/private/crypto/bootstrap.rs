@@ -185,10 +185,15 @@ pub fn bootstrap_scratch<Scalar>(
 }
 
 impl<'a> FourierLweBootstrapKeyView<'a> {
+    /// Rotates the `lut` accumulator by the phase of the `lwe` ciphertext.
+    ///
+    /// The phase is switched to a multiple of `2^lut_count_log` in $\mathbb{Z}\_{2N}$, so that the
+    /// `2^lut_count_log` lookup tables interleaved in the accumulator are rotated together.
     pub fn blind_rotate<Scalar: UnsignedTorus + CastInto<usize>>(
         self,
         mut lut: GlweCiphertext<&'_ mut [Scalar]>,
         lwe: &[Scalar],
+        lut_count_log: LutCountLog,
         fft: FftView<'_>,
         mut stack: DynStack<'_>,
     ) {
@@ -199,7 +204,7 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             *lwe_body,
             lut_poly_size,
             ModulusSwitchOffset(0),
-            LutCountLog(0),
+            lut_count_log,
         );
         lut.as_mut_view()
             .into_polynomial_list()
@@ -233,7 +238,7 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
                             *lwe_mask_element,
                             lut_poly_size,
                             ModulusSwitchOffset(0),
-                            LutCountLog(0),
+                            lut_count_log,
                         ),
                     ));
                 }
@@ -265,12 +270,58 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             &mut *local_accumulator_data,
             accumulator.polynomial_size(),
         );
-        self.blind_rotate(local_accumulator.as_mut_view(), lwe_in, fft, stack);
+        self.blind_rotate(
+            local_accumulator.as_mut_view(),
+            lwe_in,
+            LutCountLog(0),
+            fft,
+            stack,
+        );
         local_accumulator.as_view().fill_lwe_with_sample_extraction(
             &mut LweCiphertext::from_container(&mut *lwe_out),
             MonomialDegree(0),
         );
     }
+
+    /// Bootstraps `lwe_in` once, and evaluates the `2^lut_count_log` lookup tables interleaved in
+    /// the accumulator. The `lwe_out` buffer contains the output ciphertexts one after the other,
+    /// the `j`-th one being the evaluation of the lookup table stored in the coefficients of
+    /// index `j` modulo `2^lut_count_log`.
+    pub fn multi_value_bootstrap<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        lwe_out: &mut [Scalar],
+        lwe_in: &[Scalar],
+        accumulator: GlweCiphertext<&'_ [Scalar]>,
+        lut_count_log: LutCountLog,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        let (mut local_accumulator_data, stack) = stack.collect_aligned(
+            CACHELINE_ALIGN,
+            accumulator.as_view().into_container().iter().copied(),
+        );
+        let mut local_accumulator = GlweCiphertext::from_container(
+            &mut *local_accumulator_data,
+            accumulator.polynomial_size(),
+        );
+        self.blind_rotate(
+            local_accumulator.as_mut_view(),
+            lwe_in,
+            lut_count_log,
+            fft,
+            stack,
+        );
+
+        let lut_count = 1 << lut_count_log.0;
+        debug_assert_eq!(lwe_out.len() % lut_count, 0);
+        let lwe_size = lwe_out.len() / lut_count;
+        for (lut_index, lwe_out) in lwe_out.chunks_exact_mut(lwe_size).enumerate() {
+            local_accumulator.as_view().fill_lwe_with_sample_extraction(
+                &mut LweCiphertext::from_container(lwe_out),
+                MonomialDegree(lut_index),
+            );
+        }
+    }
 }
 
 /// This function switches modulus for a single coefficient of a ciphertext,
@@ -0,0 +1,362 @@
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
+
+use super::super::math::fft::FftView;
+use super::bootstrap::{bootstrap_scratch, FourierLweBootstrapKeyView};
+use crate::commons::crypto::glwe::GlweCiphertext;
+use crate::commons::crypto::lwe::{LweCiphertext, LweKeyswitchKey, LweList};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::CastInto;
+use crate::commons::utils::izip;
+use crate::prelude::{GlweSize, LweDimension, PolynomialSize};
+
+/// The binary boolean gates which can be evaluated with [`binary_gate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryGate {
+    And,
+    Nand,
+    Nor,
+    Or,
+    Xor,
+    Xnor,
+}
+
+impl BinaryGate {
+    /// Returns the `(sign, offset, factor)` triplet such that the ciphertext fed to the bootstrap
+    /// is `factor * (sign * (c_1 + c_2) + offset)`.
+    fn linear_combination<Scalar: UnsignedTorus>(self) -> (Scalar, Scalar, Scalar) {
+        let plus = Scalar::ONE;
+        let minus = Scalar::ZERO.wrapping_sub(Scalar::ONE);
+        let true_plaintext = boolean_true::<Scalar>();
+        let false_plaintext = Scalar::ZERO.wrapping_sub(true_plaintext);
+        let two = Scalar::ONE << 1;
+        match self {
+            BinaryGate::And => (plus, false_plaintext, Scalar::ONE),
+            BinaryGate::Nand => (minus, true_plaintext, Scalar::ONE),
+            BinaryGate::Nor => (minus, false_plaintext, Scalar::ONE),
+            BinaryGate::Or => (plus, true_plaintext, Scalar::ONE),
+            BinaryGate::Xor => (plus, true_plaintext, two),
+            BinaryGate::Xnor => (minus, false_plaintext, two),
+        }
+    }
+}
+
+/// Returns the encoding of the `true` boolean value, that is `q/8`. The `false` value is encoded
+/// as `-q/8`.
+pub fn boolean_true<Scalar: UnsignedTorus>() -> Scalar {
+    Scalar::ONE << (Scalar::BITS - 3)
+}
+
+/// Returns the required memory for [`boolean_bootstrap`].
+fn boolean_bootstrap_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let pbs_accumulator =
+        StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?;
+    pbs_accumulator.try_and(bootstrap_scratch::<Scalar>(
+        glwe_size,
+        polynomial_size,
+        fft,
+    )?)
+}
+
+/// Bootstraps `lwe_in` into `lwe_out`, with an accumulator encoding the constant `q/8`.
+///
+/// The output encrypts `q/8` for inputs in the positive half of the torus, and `-q/8` otherwise.
+fn boolean_bootstrap<Scalar: UnsignedTorus + CastInto<usize>>(
+    lwe_out: &mut [Scalar],
+    lwe_in: &[Scalar],
+    fourier_bsk: FourierLweBootstrapKeyView<'_>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    let polynomial_size = fourier_bsk.polynomial_size();
+    let glwe_size = fourier_bsk.glwe_size();
+    let glwe_mask_len = glwe_size.to_glwe_dimension().0 * polynomial_size.0;
+
+    // The accumulator is a trivial encryption of the constant polynomial q/8.
+    let (pbs_accumulator_data, stack) =
+        stack.make_aligned_with(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN, |i| {
+            if i < glwe_mask_len {
+                Scalar::ZERO
+            } else {
+                boolean_true()
+            }
+        });
+    let pbs_accumulator = GlweCiphertext::from_container(&*pbs_accumulator_data, polynomial_size);
+
+    fourier_bsk.bootstrap(lwe_out, lwe_in, pbs_accumulator, fft, stack);
+}
+
+/// Returns the required memory for [`binary_gate`].
+pub fn binary_gate_scratch<Scalar>(
+    lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+
+    let lwe_in_buffer = StackReq::try_new_aligned::<Scalar>(lwe_dimension.to_lwe_size().0, align)?;
+    let lwe_out_pbs_buffer = StackReq::try_new_aligned::<Scalar>(
+        glwe_size.to_glwe_dimension().0 * polynomial_size.0 + 1,
+        align,
+    )?;
+    let bootstrap_scratch = boolean_bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, fft)?;
+
+    lwe_in_buffer
+        .try_and(lwe_out_pbs_buffer)?
+        .try_and(bootstrap_scratch)
+}
+
+/// Returns the required memory for [`mux`].
+pub fn mux_scratch<Scalar>(
+    lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+
+    let lwe_in_buffer = StackReq::try_new_aligned::<Scalar>(lwe_dimension.to_lwe_size().0, align)?;
+    let lwe_out_pbs_buffer = StackReq::try_new_aligned::<Scalar>(
+        glwe_size.to_glwe_dimension().0 * polynomial_size.0 + 1,
+        align,
+    )?;
+    let bootstrap_scratch = boolean_bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, fft)?;
+
+    StackReq::try_all_of([
+        lwe_in_buffer,
+        lwe_in_buffer,
+        lwe_out_pbs_buffer,
+        lwe_out_pbs_buffer,
+        bootstrap_scratch,
+    ])
+}
+
+/// Evaluates a binary boolean gate on two LWE ciphertexts encrypting booleans.
+///
+/// The inputs are linearly combined, bootstrapped with an accumulator encoding the constant `q/8`,
+/// and the result is keyswitched back to the input key. This is the same sequence of operations
+/// as the one used by the Cuda backend, so that the two produce the same decryptions.
+#[allow(clippy::too_many_arguments)]
+pub fn binary_gate<Scalar: UnsignedTorus + CastInto<usize>>(
+    gate: BinaryGate,
+    mut lwe_out: LweCiphertext<&'_ mut [Scalar]>,
+    lwe_in_1: LweCiphertext<&'_ [Scalar]>,
+    lwe_in_2: LweCiphertext<&'_ [Scalar]>,
+    fourier_bsk: FourierLweBootstrapKeyView<'_>,
+    ksk: LweKeyswitchKey<&'_ [Scalar]>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    debug_assert!(
+        lwe_in_1.lwe_size() == fourier_bsk.key_size().to_lwe_size(),
+        "lwe_in_1 needs to have an LWE dimension of {}, got {}",
+        fourier_bsk.key_size().0,
+        lwe_in_1.lwe_size().to_lwe_dimension().0,
+    );
+    debug_assert!(
+        lwe_in_2.lwe_size() == fourier_bsk.key_size().to_lwe_size(),
+        "lwe_in_2 needs to have an LWE dimension of {}, got {}",
+        fourier_bsk.key_size().0,
+        lwe_in_2.lwe_size().to_lwe_dimension().0,
+    );
+    debug_assert!(
+        ksk.before_key_size() == fourier_bsk.output_lwe_dimension(),
+        "ksk needs to have an input LWE dimension of {}, got {}",
+        fourier_bsk.output_lwe_dimension().0,
+        ksk.before_key_size().0,
+    );
+    debug_assert!(
+        ksk.after_key_size() == fourier_bsk.key_size(),
+        "ksk needs to have an output LWE dimension of {}, got {}",
+        fourier_bsk.key_size().0,
+        ksk.after_key_size().0,
+    );
+
+    let glwe_mask_len =
+        fourier_bsk.glwe_size().to_glwe_dimension().0 * fourier_bsk.polynomial_size().0;
+
+    let (sign, offset, factor) = gate.linear_combination::<Scalar>();
+    let (mut lwe_in_buffer_data, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        izip!(lwe_in_1.into_container(), lwe_in_2.into_container())
+            .map(|(c_1, c_2)| factor.wrapping_mul(sign.wrapping_mul(c_1.wrapping_add(*c_2)))),
+    );
+    let body = lwe_in_buffer_data.last_mut().unwrap();
+    *body = body.wrapping_add(factor.wrapping_mul(offset));
+
+    let (mut lwe_out_pbs_buffer_data, stack) =
+        stack.make_aligned_with(glwe_mask_len + 1, CACHELINE_ALIGN, |_| Scalar::ZERO);
+
+    boolean_bootstrap(
+        &mut lwe_out_pbs_buffer_data,
+        &lwe_in_buffer_data,
+        fourier_bsk,
+        fft,
+        stack,
+    );
+
+    ksk.keyswitch_ciphertext(
+        &mut lwe_out,
+        &LweCiphertext::from_container(&*lwe_out_pbs_buffer_data),
+    );
+}
+
+/// Evaluates a binary boolean gate on each pair of ciphertexts of two LWE lists.
+///
+/// See [`binary_gate`] for details.
+#[allow(clippy::too_many_arguments)]
+pub fn binary_gate_list<Scalar: UnsignedTorus + CastInto<usize>>(
+    gate: BinaryGate,
+    mut lwe_list_out: LweList<&'_ mut [Scalar]>,
+    lwe_list_in_1: LweList<&'_ [Scalar]>,
+    lwe_list_in_2: LweList<&'_ [Scalar]>,
+    fourier_bsk: FourierLweBootstrapKeyView<'_>,
+    ksk: LweKeyswitchKey<&'_ [Scalar]>,
+    fft: FftView<'_>,
+    mut stack: DynStack<'_>,
+) {
+    debug_assert!(
+        lwe_list_out.count() == lwe_list_in_1.count(),
+        "lwe_list_out needs to have a ciphertext count of {}, got {}",
+        lwe_list_in_1.count().0,
+        lwe_list_out.count().0,
+    );
+    debug_assert!(
+        lwe_list_out.count() == lwe_list_in_2.count(),
+        "lwe_list_out needs to have a ciphertext count of {}, got {}",
+        lwe_list_in_2.count().0,
+        lwe_list_out.count().0,
+    );
+
+    for (lwe_out, lwe_in_1, lwe_in_2) in izip!(
+        lwe_list_out.ciphertext_iter_mut(),
+        lwe_list_in_1.ciphertext_iter(),
+        lwe_list_in_2.ciphertext_iter()
+    ) {
+        binary_gate(
+            gate,
+            lwe_out,
+            lwe_in_1,
+            lwe_in_2,
+            fourier_bsk,
+            ksk.as_view(),
+            fft,
+            stack.rb_mut(),
+        );
+    }
+}
+
+/// Evaluates the MUX gate on three LWE ciphertexts encrypting booleans, that is `lwe_in_1` if
+/// `lwe_select` encrypts `true`, and `lwe_in_2` otherwise.
+///
+/// The `select AND in_1` and `NOT(select) AND in_2` terms are bootstrapped separately, and their
+/// OR is computed before the keyswitch, which saves one bootstrap and one keyswitch compared to
+/// the evaluation of three gates.
+#[allow(clippy::too_many_arguments)]
+pub fn mux<Scalar: UnsignedTorus + CastInto<usize>>(
+    mut lwe_out: LweCiphertext<&'_ mut [Scalar]>,
+    lwe_select: LweCiphertext<&'_ [Scalar]>,
+    lwe_in_1: LweCiphertext<&'_ [Scalar]>,
+    lwe_in_2: LweCiphertext<&'_ [Scalar]>,
+    fourier_bsk: FourierLweBootstrapKeyView<'_>,
+    ksk: LweKeyswitchKey<&'_ [Scalar]>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    debug_assert!(
+        lwe_select.lwe_size() == fourier_bsk.key_size().to_lwe_size(),
+        "lwe_select needs to have an LWE dimension of {}, got {}",
+        fourier_bsk.key_size().0,
+        lwe_select.lwe_size().to_lwe_dimension().0,
+    );
+    debug_assert!(
+        lwe_in_1.lwe_size() == fourier_bsk.key_size().to_lwe_size(),
+        "lwe_in_1 needs to have an LWE dimension of {}, got {}",
+        fourier_bsk.key_size().0,
+        lwe_in_1.lwe_size().to_lwe_dimension().0,
+    );
+    debug_assert!(
+        lwe_in_2.lwe_size() == fourier_bsk.key_size().to_lwe_size(),
+        "lwe_in_2 needs to have an LWE dimension of {}, got {}",
+        fourier_bsk.key_size().0,
+        lwe_in_2.lwe_size().to_lwe_dimension().0,
+    );
+    debug_assert!(
+        ksk.before_key_size() == fourier_bsk.output_lwe_dimension(),
+        "ksk needs to have an input LWE dimension of {}, got {}",
+        fourier_bsk.output_lwe_dimension().0,
+        ksk.before_key_size().0,
+    );
+    debug_assert!(
+        ksk.after_key_size() == fourier_bsk.key_size(),
+        "ksk needs to have an output LWE dimension of {}, got {}",
+        fourier_bsk.key_size().0,
+        ksk.after_key_size().0,
+    );
+
+    let glwe_mask_len =
+        fourier_bsk.glwe_size().to_glwe_dimension().0 * fourier_bsk.polynomial_size().0;
+    let true_plaintext = boolean_true::<Scalar>();
+    let false_plaintext = Scalar::ZERO.wrapping_sub(true_plaintext);
+
+    // select + in_1 - 1/8, which bootstraps to select AND in_1.
+    let (mut lwe_in_buffer_data_1, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        izip!(
+            lwe_select.as_view().into_container(),
+            lwe_in_1.into_container()
+        )
+        .map(|(s, c)| s.wrapping_add(*c)),
+    );
+    let body = lwe_in_buffer_data_1.last_mut().unwrap();
+    *body = body.wrapping_add(false_plaintext);
+
+    // -select + in_2 - 1/8, which bootstraps to NOT(select) AND in_2.
+    let (mut lwe_in_buffer_data_2, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        izip!(lwe_select.into_container(), lwe_in_2.into_container())
+            .map(|(s, c)| c.wrapping_sub(*s)),
+    );
+    let body = lwe_in_buffer_data_2.last_mut().unwrap();
+    *body = body.wrapping_add(false_plaintext);
+
+    let (mut lwe_out_pbs_buffer_data_1, stack) =
+        stack.make_aligned_with(glwe_mask_len + 1, CACHELINE_ALIGN, |_| Scalar::ZERO);
+    let (mut lwe_out_pbs_buffer_data_2, mut stack) =
+        stack.make_aligned_with(glwe_mask_len + 1, CACHELINE_ALIGN, |_| Scalar::ZERO);
+
+    boolean_bootstrap(
+        &mut lwe_out_pbs_buffer_data_1,
+        &lwe_in_buffer_data_1,
+        fourier_bsk,
+        fft,
+        stack.rb_mut(),
+    );
+    boolean_bootstrap(
+        &mut lwe_out_pbs_buffer_data_2,
+        &lwe_in_buffer_data_2,
+        fourier_bsk,
+        fft,
+        stack.rb_mut(),
+    );
+
+    // The two terms can not both be true, so their OR is their sum, shifted by 1/8.
+    for (c_1, c_2) in izip!(
+        lwe_out_pbs_buffer_data_1.iter_mut(),
+        lwe_out_pbs_buffer_data_2.iter()
+    ) {
+        *c_1 = c_1.wrapping_add(*c_2);
+    }
+    let body = lwe_out_pbs_buffer_data_1.last_mut().unwrap();
+    *body = body.wrapping_add(true_plaintext);
+
+    ksk.keyswitch_ciphertext(
+        &mut lwe_out,
+        &LweCiphertext::from_container(&*lwe_out_pbs_buffer_data_1),
+    );
+}
@@ -4,10 +4,11 @@ use super::super::math::decomposition::TensorSignedDecompositionLendingIter;
 use super::super::math::fft::{FftView, FourierPolynomialList};
 use super::super::math::polynomial::{FourierPolynomialUninitMutView, FourierPolynomialView};
 use super::super::{as_mut_uninit, assume_init_mut};
-use crate::commons::crypto::ggsw::StandardGgswCiphertext;
+use crate::commons::crypto::ggsw::{StandardGgswCiphertext, StandardGgswSeededCiphertext};
 use crate::commons::crypto::glwe::GlweCiphertext;
 use crate::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
 use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::random::{ByteRandomGenerator, RandomGenerator};
 #[cfg(feature = "backend_fft_serialization")]
 use crate::commons::math::tensor::ContainerOwned;
 use crate::commons::math::tensor::{AsMutSlice, Container, IntoTensor, Split};
@@ -284,6 +285,70 @@ impl<'a> FourierGgswCiphertextMutView<'a> {
     }
 }
 
+/// Returns the required memory for
+/// [`FourierGgswCiphertextMutView::fill_with_forward_fourier_from_seeded`].
+pub fn fill_with_forward_fourier_from_seeded_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?
+        .try_and(fft.forward_scratch()?)
+}
+
+impl<'a> FourierGgswCiphertextMutView<'a> {
+    /// Fills a GGSW ciphertext with the Fourier transform of a seeded GGSW ciphertext.
+    ///
+    /// The rows of the seeded ciphertext are expanded one at a time in a buffer taken from the
+    /// stack, so that the standard GGSW ciphertext is never materialized in full. The generator
+    /// type must be the one that was used to encrypt the seeded ciphertext.
+    pub fn fill_with_forward_fourier_from_seeded<Scalar, Gen>(
+        self,
+        seeded_ggsw: StandardGgswSeededCiphertext<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) where
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        debug_assert_eq!(seeded_ggsw.polynomial_size(), self.polynomial_size());
+        debug_assert_eq!(seeded_ggsw.glwe_size(), self.glwe_size());
+        let polynomial_size = seeded_ggsw.polynomial_size();
+        let poly_size = polynomial_size.0;
+        let row_len = seeded_ggsw.glwe_size().0 * poly_size;
+
+        let (mut row_buffer, mut stack) =
+            stack.make_aligned_with(row_len, CACHELINE_ALIGN, |_| Scalar::ZERO);
+        let mut generator = RandomGenerator::<Gen>::new(seeded_ggsw.compression_seed().seed);
+        let mut fourier_rows = self.data().into_chunks(row_len / 2);
+
+        for seeded_matrix in seeded_ggsw.level_matrix_iter() {
+            for (seeded_row, fourier_row) in izip!(seeded_matrix.row_iter(), fourier_rows.by_ref())
+            {
+                let mut glwe = GlweCiphertext::from_container(&mut *row_buffer, polynomial_size);
+                seeded_row
+                    .into_seeded_glwe()
+                    .expand_into_with_existing_generator::<_, _, Gen>(&mut glwe, &mut generator);
+
+                for (fourier_poly, coef_poly) in izip!(
+                    fourier_row.into_chunks(poly_size / 2),
+                    row_buffer.chunks_exact(poly_size)
+                ) {
+                    // SAFETY: forward_as_torus doesn't write any uninitialized values into its
+                    // output
+                    fft.forward_as_torus(
+                        FourierPolynomialUninitMutView {
+                            data: unsafe { as_mut_uninit(fourier_poly) },
+                        },
+                        Polynomial::from_container(coef_poly),
+                        stack.rb_mut(),
+                    );
+                }
+            }
+        }
+    }
+}
+
 /// Returns the required memory for [`FourierGgswCiphertextMutView::fill_with_backward_fourier`].
 pub fn fill_with_backward_fourier_scratch(fft: FftView<'_>) -> Result<StackReq, SizeOverflow> {
     fft.backward_scratch()
@@ -470,7 +535,7 @@ pub fn external_product<Scalar: UnsignedTorus>(
 }
 
 #[cfg_attr(__profiling, inline(never))]
-fn collect_next_term<'a, Scalar: UnsignedTorus>(
+pub(crate) fn collect_next_term<'a, Scalar: UnsignedTorus>(
     decomposition: &mut TensorSignedDecompositionLendingIter<'_, Scalar>,
     substack1: &'a mut DynStack,
     align: usize,
@@ -645,7 +710,7 @@ unsafe fn update_with_fmadd_scalar(
 ///
 ///  - if `is_output_uninit` is false, `output_fourier` must not hold any uninitialized values.
 #[cfg_attr(__profiling, inline(never))]
-unsafe fn update_with_fmadd(
+pub(crate) unsafe fn update_with_fmadd(
     output_fft_buffer: &mut [MaybeUninit<c64>],
     ggsw_row: FourierGgswLevelRowView,
     fourier: &[c64],
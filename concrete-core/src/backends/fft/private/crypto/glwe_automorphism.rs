@@ -0,0 +1,286 @@
+use super::super::as_mut_uninit;
+use super::super::math::fft::{FftView, FourierPolynomialList};
+use super::super::math::polynomial::FourierPolynomialUninitMutView;
+use super::glwe_keyswitch::{keyswitch, keyswitch_scratch, FourierGlweKeyswitchKeyView};
+use crate::commons::crypto::glwe::{
+    automorphism_key_count, automorphism_key_index, fill_with_ciphertext_automorphism,
+    pack_lwe_ciphertexts_with_automorphism, trace_with_automorphism, GlweAutomorphismKey,
+    GlweCiphertext,
+};
+use crate::commons::crypto::lwe::LweList;
+use crate::commons::math::polynomial::Polynomial;
+#[cfg(feature = "backend_fft_serialization")]
+use crate::commons::math::tensor::ContainerOwned;
+use crate::commons::math::tensor::{AsMutTensor, AsRefTensor, Container, Split};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::utils::izip;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GaloisElement, GlweSize, PolynomialSize,
+};
+use aligned_vec::CACHELINE_ALIGN;
+use concrete_fft::c64;
+use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// A GLWE automorphism key in the Fourier domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "backend_fft_serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "C: ContainerOwned"))
+)]
+pub struct FourierGlweAutomorphismKey<C: Container<Element = c64>> {
+    fourier: FourierPolynomialList<C>,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+pub type FourierGlweAutomorphismKeyView<'a> = FourierGlweAutomorphismKey<&'a [c64]>;
+pub type FourierGlweAutomorphismKeyMutView<'a> = FourierGlweAutomorphismKey<&'a mut [c64]>;
+
+impl<C: Container<Element = c64>> FourierGlweAutomorphismKey<C> {
+    pub fn new(
+        data: C,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        assert!(polynomial_size.0.is_power_of_two());
+        assert_eq!(
+            data.container_len(),
+            automorphism_key_count(polynomial_size)
+                * glwe_size.to_glwe_dimension().0
+                * polynomial_size.0
+                / 2
+                * decomposition_level_count.0
+                * glwe_size.0
+        );
+        Self {
+            fourier: FourierPolynomialList {
+                data,
+                polynomial_size,
+            },
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn data(self) -> C {
+        self.fourier.data
+    }
+
+    pub fn as_view(&self) -> FourierGlweAutomorphismKeyView<'_>
+    where
+        C: AsRef<[c64]>,
+    {
+        FourierGlweAutomorphismKeyView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_ref(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierGlweAutomorphismKeyMutView<'_>
+    where
+        C: AsMut<[c64]>,
+    {
+        FourierGlweAutomorphismKeyMutView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_mut(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+}
+
+impl<'a> FourierGlweAutomorphismKeyView<'a> {
+    /// Returns the keyswitch key associated with a Galois element, if the automorphism key
+    /// contains one.
+    pub fn keyswitch_key(
+        self,
+        galois_element: GaloisElement,
+    ) -> Option<FourierGlweKeyswitchKeyView<'a>> {
+        let polynomial_size = self.fourier.polynomial_size;
+        let index = automorphism_key_index(polynomial_size, galois_element)?;
+        let glwe_dimension = self.glwe_size.to_glwe_dimension();
+        let size = glwe_dimension.0 * polynomial_size.0 / 2
+            * self.decomposition_level_count.0
+            * self.glwe_size.0;
+        Some(FourierGlweKeyswitchKeyView::new(
+            &self.fourier.data[index * size..(index + 1) * size],
+            polynomial_size,
+            glwe_dimension,
+            self.glwe_size,
+            self.decomposition_base_log,
+            self.decomposition_level_count,
+        ))
+    }
+}
+
+/// Returns the required memory for [`FourierGlweAutomorphismKeyMutView::fill_with_forward_fourier`].
+pub fn fill_with_forward_fourier_scratch(fft: FftView<'_>) -> Result<StackReq, SizeOverflow> {
+    fft.forward_scratch()
+}
+
+impl<'a> FourierGlweAutomorphismKeyMutView<'a> {
+    /// Fills a GLWE automorphism key with the Fourier transform of a GLWE automorphism key in the
+    /// standard domain.
+    pub fn fill_with_forward_fourier<Scalar: UnsignedTorus>(
+        self,
+        coef_ak: GlweAutomorphismKey<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        debug_assert_eq!(coef_ak.polynomial_size(), self.polynomial_size());
+        let poly_size = coef_ak.polynomial_size().0;
+
+        for (fourier_poly, coef_poly) in izip!(
+            self.data().into_chunks(poly_size / 2),
+            coef_ak.into_container().into_chunks(poly_size)
+        ) {
+            // SAFETY: forward_as_torus doesn't write any uninitialized values into its output
+            fft.forward_as_torus(
+                FourierPolynomialUninitMutView {
+                    data: unsafe { as_mut_uninit(fourier_poly) },
+                },
+                Polynomial::from_container(coef_poly),
+                stack.rb_mut(),
+            );
+        }
+    }
+}
+
+/// Returns the required memory for [`automorphism`].
+pub fn automorphism_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?.try_and(
+        keyswitch_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+    )
+}
+
+/// Applies the automorphism $X \mapsto X^k$ of the given Galois element to the `input` GLWE
+/// ciphertext, and writes the result, encrypted under the original key, to `out`.
+///
+/// # Panics
+///
+/// Panics if the automorphism key does not contain the Galois element.
+pub fn automorphism<Scalar: UnsignedTorus>(
+    out: GlweCiphertext<&'_ mut [Scalar]>,
+    input: GlweCiphertext<&'_ [Scalar]>,
+    ak: FourierGlweAutomorphismKeyView<'_>,
+    galois_element: GaloisElement,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    debug_assert_eq!(ak.polynomial_size(), input.polynomial_size());
+    debug_assert_eq!(ak.glwe_size(), input.size());
+
+    let ksk = ak
+        .keyswitch_key(galois_element)
+        .expect("The automorphism key does not contain the Galois element.");
+    let (mut permuted, stack) = stack.make_aligned_with(
+        ak.glwe_size().0 * ak.polynomial_size().0,
+        CACHELINE_ALIGN,
+        |_| Scalar::ZERO,
+    );
+    let mut permuted = GlweCiphertext::from_container(&mut *permuted, ak.polynomial_size());
+    fill_with_ciphertext_automorphism(&mut permuted, &input, galois_element);
+    keyswitch(out, permuted.as_view(), ksk, fft, stack);
+}
+
+/// Returns the required memory for [`trace`].
+pub fn trace_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    automorphism_scratch::<Scalar>(glwe_size, polynomial_size, fft)
+}
+
+/// Computes the homomorphic trace of the `input` GLWE ciphertext, and writes the result to `out`.
+pub fn trace<Scalar: UnsignedTorus>(
+    mut out: GlweCiphertext<&'_ mut [Scalar]>,
+    input: GlweCiphertext<&'_ [Scalar]>,
+    ak: FourierGlweAutomorphismKeyView<'_>,
+    fft: FftView<'_>,
+    mut stack: DynStack<'_>,
+) {
+    let mut accumulator =
+        GlweCiphertext::allocate(Scalar::ZERO, ak.polynomial_size(), ak.glwe_size());
+    accumulator
+        .as_mut_tensor()
+        .fill_with_copy(input.as_tensor());
+    trace_with_automorphism(&mut accumulator, 0, |image, ciphertext, galois_element| {
+        automorphism(
+            image.as_mut_view(),
+            ciphertext.as_view(),
+            ak,
+            galois_element,
+            fft,
+            stack.rb_mut(),
+        )
+    });
+    out.as_mut_tensor().fill_with_copy(accumulator.as_tensor());
+}
+
+/// Returns the required memory for [`pack_lwe_ciphertexts`].
+pub fn pack_lwe_ciphertexts_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    automorphism_scratch::<Scalar>(glwe_size, polynomial_size, fft)
+}
+
+/// Packs the `input` LWE ciphertexts into the `out` GLWE ciphertext using homomorphic traces.
+pub fn pack_lwe_ciphertexts<Scalar: UnsignedTorus>(
+    mut out: GlweCiphertext<&'_ mut [Scalar]>,
+    input: LweList<&'_ [Scalar]>,
+    ak: FourierGlweAutomorphismKeyView<'_>,
+    fft: FftView<'_>,
+    mut stack: DynStack<'_>,
+) {
+    pack_lwe_ciphertexts_with_automorphism(
+        &mut out,
+        &input,
+        ak.glwe_size(),
+        |image, ciphertext, galois_element| {
+            automorphism(
+                image.as_mut_view(),
+                ciphertext.as_view(),
+                ak,
+                galois_element,
+                fft,
+                stack.rb_mut(),
+            )
+        },
+    );
+}
@@ -0,0 +1,309 @@
+use super::super::math::decomposition::TensorSignedDecompositionLendingIter;
+use super::super::math::fft::{FftView, FourierPolynomialList};
+use super::super::math::polynomial::{FourierPolynomialUninitMutView, FourierPolynomialView};
+use super::super::{as_mut_uninit, assume_init_mut};
+use super::ggsw::{collect_next_term, update_with_fmadd, FourierGgswLevelRowView};
+use crate::commons::crypto::glwe::{GlweCiphertext, GlweKeyswitchKey};
+use crate::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
+use crate::commons::math::polynomial::Polynomial;
+#[cfg(feature = "backend_fft_serialization")]
+use crate::commons::math::tensor::ContainerOwned;
+use crate::commons::math::tensor::{Container, IntoTensor, Split};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::utils::izip;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+};
+use aligned_vec::CACHELINE_ALIGN;
+use concrete_fft::c64;
+use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// A GLWE keyswitch key in the Fourier domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "backend_fft_serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "C: ContainerOwned"))
+)]
+pub struct FourierGlweKeyswitchKey<C: Container<Element = c64>> {
+    fourier: FourierPolynomialList<C>,
+    input_glwe_dimension: GlweDimension,
+    output_glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+pub type FourierGlweKeyswitchKeyView<'a> = FourierGlweKeyswitchKey<&'a [c64]>;
+pub type FourierGlweKeyswitchKeyMutView<'a> = FourierGlweKeyswitchKey<&'a mut [c64]>;
+
+impl<C: Container<Element = c64>> FourierGlweKeyswitchKey<C> {
+    pub fn new(
+        data: C,
+        polynomial_size: PolynomialSize,
+        input_glwe_dimension: GlweDimension,
+        output_glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        assert_eq!(polynomial_size.0 % 2, 0);
+        assert_eq!(
+            data.container_len(),
+            input_glwe_dimension.0 * polynomial_size.0 / 2
+                * decomposition_level_count.0
+                * output_glwe_size.0
+        );
+        Self {
+            fourier: FourierPolynomialList {
+                data,
+                polynomial_size,
+            },
+            input_glwe_dimension,
+            output_glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier.polynomial_size
+    }
+
+    pub fn input_glwe_dimension(&self) -> GlweDimension {
+        self.input_glwe_dimension
+    }
+
+    pub fn output_glwe_size(&self) -> GlweSize {
+        self.output_glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn data(self) -> C {
+        self.fourier.data
+    }
+
+    pub fn as_view(&self) -> FourierGlweKeyswitchKeyView<'_>
+    where
+        C: AsRef<[c64]>,
+    {
+        FourierGlweKeyswitchKeyView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_ref(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            input_glwe_dimension: self.input_glwe_dimension,
+            output_glwe_size: self.output_glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierGlweKeyswitchKeyMutView<'_>
+    where
+        C: AsMut<[c64]>,
+    {
+        FourierGlweKeyswitchKeyMutView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_mut(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            input_glwe_dimension: self.input_glwe_dimension,
+            output_glwe_size: self.output_glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+}
+
+impl<'a> FourierGlweKeyswitchKeyView<'a> {
+    /// Returns an iterator over the GLev ciphertexts of the key, one per polynomial of the input
+    /// key, each one being an iterator over its levels.
+    pub fn into_glev_iter(
+        self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = FourierGgswLevelRowView<'a>>> {
+        let polynomial_size = self.fourier.polynomial_size;
+        let output_glwe_size = self.output_glwe_size;
+        let level_count = self.decomposition_level_count.0;
+        self.fourier
+            .data
+            .split_into(self.input_glwe_dimension.0)
+            .map(move |glev| {
+                glev.split_into(level_count)
+                    .enumerate()
+                    .map(move |(i, slice)| {
+                        FourierGgswLevelRowView::new(
+                            slice,
+                            polynomial_size,
+                            output_glwe_size,
+                            DecompositionLevel(i + 1),
+                        )
+                    })
+            })
+    }
+}
+
+/// Returns the required memory for [`FourierGlweKeyswitchKeyMutView::fill_with_forward_fourier`].
+pub fn fill_with_forward_fourier_scratch(fft: FftView<'_>) -> Result<StackReq, SizeOverflow> {
+    fft.forward_scratch()
+}
+
+impl<'a> FourierGlweKeyswitchKeyMutView<'a> {
+    /// Fills a GLWE keyswitch key with the Fourier transform of a GLWE keyswitch key in the
+    /// standard domain.
+    pub fn fill_with_forward_fourier<Scalar: UnsignedTorus>(
+        self,
+        coef_ksk: GlweKeyswitchKey<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        debug_assert_eq!(coef_ksk.polynomial_size(), self.polynomial_size());
+        let poly_size = coef_ksk.polynomial_size().0;
+
+        for (fourier_poly, coef_poly) in izip!(
+            self.data().into_chunks(poly_size / 2),
+            coef_ksk.into_container().into_chunks(poly_size)
+        ) {
+            // SAFETY: forward_as_torus doesn't write any uninitialized values into its output
+            fft.forward_as_torus(
+                FourierPolynomialUninitMutView {
+                    data: unsafe { as_mut_uninit(fourier_poly) },
+                },
+                Polynomial::from_container(coef_poly),
+                stack.rb_mut(),
+            );
+        }
+    }
+}
+
+/// Returns the required memory for [`keyswitch`].
+pub fn keyswitch_scratch<Scalar>(
+    output_glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let standard_scratch = StackReq::try_new_aligned::<Scalar>(polynomial_size.0, align)?;
+    let fourier_scratch =
+        StackReq::try_new_aligned::<c64>(output_glwe_size.0 * polynomial_size.0 / 2, align)?;
+    let fourier_scratch_single = StackReq::try_new_aligned::<c64>(polynomial_size.0 / 2, align)?;
+
+    let substack3 = fft.forward_scratch()?;
+    let substack2 = substack3.try_and(fourier_scratch_single)?;
+    let substack1 = substack2.try_and(standard_scratch)?;
+    let substack0 = StackReq::try_any_of([
+        substack1.try_and(standard_scratch)?,
+        fft.backward_scratch()?,
+    ])?;
+    substack0.try_and(fourier_scratch)
+}
+
+/// Switches the `input` GLWE ciphertext to the output key of `ksk`, and writes the result to
+/// `out`.
+#[cfg_attr(__profiling, inline(never))]
+pub fn keyswitch<Scalar: UnsignedTorus>(
+    mut out: GlweCiphertext<&'_ mut [Scalar]>,
+    input: GlweCiphertext<&'_ [Scalar]>,
+    ksk: FourierGlweKeyswitchKeyView<'_>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    // we check that the polynomial sizes match
+    debug_assert_eq!(ksk.polynomial_size(), input.polynomial_size());
+    debug_assert_eq!(ksk.polynomial_size(), out.polynomial_size());
+    // we check that the glwe sizes match
+    debug_assert_eq!(ksk.input_glwe_dimension(), input.size().to_glwe_dimension());
+    debug_assert_eq!(ksk.output_glwe_size(), out.size());
+
+    let align = CACHELINE_ALIGN;
+    let poly_size = ksk.polynomial_size().0;
+
+    // The output mask starts at zero, and the output body at the input body.
+    let (input_mask, input_body) = input
+        .into_container()
+        .split_at(ksk.input_glwe_dimension().0 * poly_size);
+    let (out_mask, out_body) = out
+        .as_mut_view()
+        .into_tensor()
+        .into_container()
+        .split_at_mut(ksk.output_glwe_size().to_glwe_dimension().0 * poly_size);
+    out_mask.fill(Scalar::ZERO);
+    out_body.copy_from_slice(input_body);
+
+    let decomposer = SignedDecomposer::<Scalar>::new(
+        ksk.decomposition_base_log(),
+        ksk.decomposition_level_count(),
+    );
+
+    let (mut output_fft_buffer, mut substack0) =
+        stack.make_aligned_uninit::<c64>(poly_size / 2 * ksk.output_glwe_size().0, align);
+    // output_fft_buffer is initially uninitialized, considered to be implicitly zero, to avoid
+    // the cost of filling it up with zeros. `is_output_uninit` is set to `false` once
+    // it has been fully initialized for the first time.
+    let output_fft_buffer = &mut *output_fft_buffer;
+    let mut is_output_uninit = true;
+
+    for (glev, mask_poly) in izip!(ksk.into_glev_iter(), input_mask.into_chunks(poly_size)) {
+        // The mask polynomial multiplies the input key polynomial with a minus sign in the phase,
+        // hence we decompose its opposite.
+        let (mut decomposition, mut substack1) = TensorSignedDecompositionLendingIter::new(
+            mask_poly
+                .iter()
+                .map(|s| decomposer.closest_representable(s.wrapping_neg())),
+            DecompositionBaseLog(decomposer.base_log),
+            DecompositionLevelCount(decomposer.level_count),
+            substack0.rb_mut(),
+        );
+
+        // We loop through the levels (we reverse to match the order of the decomposition iterator.)
+        glev.rev().for_each(|glev_level| {
+            let (level, decomp_term, mut substack2) =
+                collect_next_term(&mut decomposition, &mut substack1, align);
+            debug_assert_eq!(glev_level.decomposition_level(), level);
+
+            let (mut fourier, substack3) = substack2
+                .rb_mut()
+                .make_aligned_uninit::<c64>(poly_size / 2, align);
+            let fourier = fft
+                .forward_as_integer(
+                    FourierPolynomialUninitMutView { data: &mut fourier },
+                    Polynomial::from_container(&*decomp_term),
+                    substack3,
+                )
+                .data;
+
+            // SAFETY: see comment above definition of `output_fft_buffer`
+            unsafe {
+                update_with_fmadd(
+                    output_fft_buffer,
+                    glev_level,
+                    fourier,
+                    is_output_uninit,
+                    poly_size,
+                )
+            };
+
+            // we initialized `output_fft_buffer, so we can set this to false
+            is_output_uninit = false;
+        });
+    }
+
+    if !is_output_uninit {
+        // SAFETY: output_fft_buffer is initialized, since `is_output_uninit` is false
+        let output_fft_buffer = &*unsafe { assume_init_mut(output_fft_buffer) };
+        izip!(
+            out.into_polynomial_list().into_polynomial_iter(),
+            output_fft_buffer
+                .into_chunks(poly_size / 2)
+                .map(|slice| FourierPolynomialView { data: slice }),
+        )
+        .for_each(|(out, fourier)| {
+            fft.add_backward_as_torus(out, fourier, substack0.rb_mut());
+        });
+    }
+}
@@ -1,3 +1,8 @@
 pub mod bootstrap;
+pub mod gates;
 pub mod ggsw;
+pub mod glwe_automorphism;
+pub mod glwe_keyswitch;
+pub mod relinearization;
+pub mod ternary_bootstrap;
 pub mod wop_pbs;
@@ -0,0 +1,492 @@
+use core::mem::MaybeUninit;
+
+use super::super::math::decomposition::TensorSignedDecompositionLendingIter;
+use super::super::math::fft::{FftView, FourierPolynomialList};
+use super::super::math::polynomial::{FourierPolynomialUninitMutView, FourierPolynomialView};
+use super::super::{as_mut_uninit, assume_init_mut};
+use super::ggsw::{collect_next_term, update_with_fmadd, FourierGgswLevelRowView};
+use crate::commons::crypto::glwe::{GlweCiphertext, GlweRelinearizationKey};
+use crate::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
+use crate::commons::math::polynomial::Polynomial;
+#[cfg(feature = "backend_fft_serialization")]
+use crate::commons::math::tensor::ContainerOwned;
+use crate::commons::math::tensor::{Container, IntoTensor, Split};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::utils::izip;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweSize, PolynomialSize,
+};
+use aligned_vec::CACHELINE_ALIGN;
+use concrete_fft::c64;
+use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// A GLWE relinearization key in the Fourier domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "backend_fft_serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "C: ContainerOwned"))
+)]
+pub struct FourierGlweRelinearizationKey<C: Container<Element = c64>> {
+    fourier: FourierPolynomialList<C>,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+pub type FourierGlweRelinearizationKeyView<'a> = FourierGlweRelinearizationKey<&'a [c64]>;
+pub type FourierGlweRelinearizationKeyMutView<'a> = FourierGlweRelinearizationKey<&'a mut [c64]>;
+
+/// Returns the number of products $S\_i \cdot S\_j$, $i \le j$, for a GLWE size $k + 1$.
+fn pair_count(glwe_size: GlweSize) -> usize {
+    let glwe_dimension = glwe_size.to_glwe_dimension().0;
+    glwe_dimension * (glwe_dimension + 1) / 2
+}
+
+/// Returns the GLWE size of the tensor product of two ciphertexts of GLWE size `glwe_size`.
+pub fn tensor_product_glwe_size(glwe_size: GlweSize) -> GlweSize {
+    let glwe_dimension = glwe_size.to_glwe_dimension().0;
+    GlweSize(glwe_dimension * (glwe_dimension + 3) / 2 + 1)
+}
+
+impl<C: Container<Element = c64>> FourierGlweRelinearizationKey<C> {
+    pub fn new(
+        data: C,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        assert_eq!(polynomial_size.0 % 2, 0);
+        assert_eq!(
+            data.container_len(),
+            pair_count(glwe_size) * polynomial_size.0 / 2
+                * decomposition_level_count.0
+                * glwe_size.0
+        );
+        Self {
+            fourier: FourierPolynomialList {
+                data,
+                polynomial_size,
+            },
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn data(self) -> C {
+        self.fourier.data
+    }
+
+    pub fn as_view(&self) -> FourierGlweRelinearizationKeyView<'_>
+    where
+        C: AsRef<[c64]>,
+    {
+        FourierGlweRelinearizationKeyView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_ref(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierGlweRelinearizationKeyMutView<'_>
+    where
+        C: AsMut<[c64]>,
+    {
+        FourierGlweRelinearizationKeyMutView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_mut(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+}
+
+impl<'a> FourierGlweRelinearizationKeyView<'a> {
+    /// Returns an iterator over the GLev ciphertexts of the key, each one being an iterator over
+    /// its levels.
+    pub fn into_glev_iter(
+        self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = FourierGgswLevelRowView<'a>>> {
+        let polynomial_size = self.fourier.polynomial_size;
+        let glwe_size = self.glwe_size;
+        let level_count = self.decomposition_level_count.0;
+        self.fourier
+            .data
+            .split_into(pair_count(glwe_size))
+            .map(move |glev| {
+                glev.split_into(level_count)
+                    .enumerate()
+                    .map(move |(i, slice)| {
+                        FourierGgswLevelRowView::new(
+                            slice,
+                            polynomial_size,
+                            glwe_size,
+                            DecompositionLevel(i + 1),
+                        )
+                    })
+            })
+    }
+}
+
+/// Returns the required memory for
+/// [`FourierGlweRelinearizationKeyMutView::fill_with_forward_fourier`].
+pub fn fill_with_forward_fourier_scratch(fft: FftView<'_>) -> Result<StackReq, SizeOverflow> {
+    fft.forward_scratch()
+}
+
+impl<'a> FourierGlweRelinearizationKeyMutView<'a> {
+    /// Fills a relinearization key with the Fourier transform of a relinearization key in the
+    /// standard domain.
+    pub fn fill_with_forward_fourier<Scalar: UnsignedTorus>(
+        self,
+        coef_rlk: GlweRelinearizationKey<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        debug_assert_eq!(coef_rlk.polynomial_size(), self.polynomial_size());
+        let poly_size = coef_rlk.polynomial_size().0;
+
+        for (fourier_poly, coef_poly) in izip!(
+            self.data().into_chunks(poly_size / 2),
+            coef_rlk.into_container().into_chunks(poly_size)
+        ) {
+            // SAFETY: forward_as_torus doesn't write any uninitialized values into its output
+            fft.forward_as_torus(
+                FourierPolynomialUninitMutView {
+                    data: unsafe { as_mut_uninit(fourier_poly) },
+                },
+                Polynomial::from_container(coef_poly),
+                stack.rb_mut(),
+            );
+        }
+    }
+}
+
+/// Returns the required memory for [`tensor_product`].
+pub fn tensor_product_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let fourier_scratch =
+        StackReq::try_new_aligned::<c64>(glwe_size.0 * polynomial_size.0 / 2, align)?;
+    let fourier_scratch_single = StackReq::try_new_aligned::<c64>(polynomial_size.0 / 2, align)?;
+    fourier_scratch
+        .try_and(fourier_scratch)?
+        .try_and(fourier_scratch_single)?
+        .try_and(StackReq::try_any_of([
+            fft.forward_scratch()?,
+            fft.backward_scratch()?,
+        ])?)
+}
+
+/// Performs the tensor product of `lhs` and `rhs` divided by $2^{\mathsf{delta\\_log}}$, and
+/// writes the result to `out`.
+///
+/// The output mask contains the quadratic terms (in lexicographic order of the pairs $(i, j)$,
+/// $i \le j$), followed by the linear terms.
+#[cfg_attr(__profiling, inline(never))]
+pub fn tensor_product<Scalar: UnsignedTorus>(
+    out: GlweCiphertext<&'_ mut [Scalar]>,
+    lhs: GlweCiphertext<&'_ [Scalar]>,
+    rhs: GlweCiphertext<&'_ [Scalar]>,
+    delta_log: DeltaLog,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    // we check that the polynomial sizes match
+    debug_assert_eq!(lhs.polynomial_size(), rhs.polynomial_size());
+    debug_assert_eq!(lhs.polynomial_size(), out.polynomial_size());
+    // we check that the glwe sizes match
+    debug_assert_eq!(lhs.size(), rhs.size());
+    debug_assert_eq!(tensor_product_glwe_size(lhs.size()), out.size());
+
+    let align = CACHELINE_ALIGN;
+    let poly_size = lhs.polynomial_size().0;
+    let glwe_dimension = lhs.size().to_glwe_dimension().0;
+
+    let (mut lhs_fourier, stack) =
+        stack.make_aligned_uninit::<c64>(poly_size / 2 * lhs.size().0, align);
+    let (mut rhs_fourier, stack) =
+        stack.make_aligned_uninit::<c64>(poly_size / 2 * rhs.size().0, align);
+    let (mut acc, mut stack) = stack.make_aligned_uninit::<c64>(poly_size / 2, align);
+    let lhs_fourier = &*fill_with_forward_glwe(&mut lhs_fourier, lhs, fft, stack.rb_mut());
+    let rhs_fourier = &*fill_with_forward_glwe(&mut rhs_fourier, rhs, fft, stack.rb_mut());
+    let acc = &mut *acc;
+
+    let lhs_poly = |i: usize| &lhs_fourier[i * poly_size / 2..(i + 1) * poly_size / 2];
+    let rhs_poly = |i: usize| &rhs_fourier[i * poly_size / 2..(i + 1) * poly_size / 2];
+    let body = glwe_dimension;
+
+    // The torus polynomials are mapped to [-1/2, 1/2[ by the forward transform, so that their
+    // product must be multiplied by q / delta to be brought back to the torus.
+    let scale = 2.0_f64.powi((Scalar::BITS - delta_log.0) as i32);
+    let mut out_polys = out.into_polynomial_list().into_polynomial_iter();
+    let mut write_next = |terms: &[(&[c64], &[c64])], scale: f64| {
+        let fourier = fill_with_scaled_product_sum(acc, terms, scale);
+        // SAFETY: backward_as_torus doesn't write any uninitialized values into its output
+        fft.backward_as_torus(
+            unsafe { out_polys.next().unwrap().into_uninit() },
+            FourierPolynomialView { data: fourier },
+            stack.rb_mut(),
+        );
+    };
+
+    // The quadratic terms, to be relinearized with the keys encrypting S_i * S_j.
+    for i in 0..glwe_dimension {
+        write_next(&[(lhs_poly(i), rhs_poly(i))], -scale);
+        for j in i + 1..glwe_dimension {
+            write_next(
+                &[(lhs_poly(i), rhs_poly(j)), (lhs_poly(j), rhs_poly(i))],
+                -scale,
+            );
+        }
+    }
+    // The linear terms, encrypted under S_i.
+    for i in 0..glwe_dimension {
+        write_next(
+            &[(lhs_poly(body), rhs_poly(i)), (lhs_poly(i), rhs_poly(body))],
+            scale,
+        );
+    }
+    // The body.
+    write_next(&[(lhs_poly(body), rhs_poly(body))], scale);
+}
+
+/// Fills `fourier` with the forward Fourier transforms of the polynomials of `glwe`.
+fn fill_with_forward_glwe<'out, Scalar: UnsignedTorus>(
+    fourier: &'out mut [MaybeUninit<c64>],
+    glwe: GlweCiphertext<&'_ [Scalar]>,
+    fft: FftView<'_>,
+    mut stack: DynStack<'_>,
+) -> &'out mut [c64] {
+    let poly_size = glwe.polynomial_size().0;
+    for (fourier_poly, poly) in izip!(
+        (&mut *fourier).into_chunks(poly_size / 2),
+        glwe.into_polynomial_list().into_polynomial_iter()
+    ) {
+        fft.forward_as_torus(
+            FourierPolynomialUninitMutView { data: fourier_poly },
+            poly,
+            stack.rb_mut(),
+        );
+    }
+    // SAFETY: forward_as_torus initialized all the elements of `fourier`
+    unsafe { assume_init_mut(fourier) }
+}
+
+/// Fills `out` with the sum of the pointwise products of the `terms`, multiplied by `scale`.
+fn fill_with_scaled_product_sum<'out>(
+    out: &'out mut [MaybeUninit<c64>],
+    terms: &[(&[c64], &[c64])],
+    scale: f64,
+) -> &'out [c64] {
+    for (k, out) in out.iter_mut().enumerate() {
+        let sum = terms
+            .iter()
+            .fold(c64::default(), |sum, (lhs, rhs)| sum + lhs[k] * rhs[k]);
+        out.write(sum * scale);
+    }
+    // SAFETY: all the elements of `out` were initialized above
+    unsafe { assume_init_mut(out) }
+}
+
+/// Returns the required memory for [`relinearize`].
+pub fn relinearize_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let standard_scratch = StackReq::try_new_aligned::<Scalar>(polynomial_size.0, align)?;
+    let fourier_scratch =
+        StackReq::try_new_aligned::<c64>(glwe_size.0 * polynomial_size.0 / 2, align)?;
+    let fourier_scratch_single = StackReq::try_new_aligned::<c64>(polynomial_size.0 / 2, align)?;
+
+    let substack3 = fft.forward_scratch()?;
+    let substack2 = substack3.try_and(fourier_scratch_single)?;
+    let substack1 = substack2.try_and(standard_scratch)?;
+    let substack0 = StackReq::try_any_of([
+        substack1.try_and(standard_scratch)?,
+        fft.backward_scratch()?,
+    ])?;
+    substack0.try_and(fourier_scratch)
+}
+
+/// Relinearizes the output `tensor` of a [`tensor_product`] using `rlk`, and writes the result
+/// to `out`.
+#[cfg_attr(__profiling, inline(never))]
+pub fn relinearize<Scalar: UnsignedTorus>(
+    mut out: GlweCiphertext<&'_ mut [Scalar]>,
+    tensor: GlweCiphertext<&'_ [Scalar]>,
+    rlk: FourierGlweRelinearizationKeyView<'_>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    // we check that the polynomial sizes match
+    debug_assert_eq!(rlk.polynomial_size(), tensor.polynomial_size());
+    debug_assert_eq!(rlk.polynomial_size(), out.polynomial_size());
+    // we check that the glwe sizes match
+    debug_assert_eq!(rlk.glwe_size(), out.size());
+    debug_assert_eq!(tensor_product_glwe_size(rlk.glwe_size()), tensor.size());
+
+    let align = CACHELINE_ALIGN;
+    let poly_size = rlk.polynomial_size().0;
+
+    // The linear terms and the body of the tensor product are already encrypted under the
+    // secret key, so they are copied to the output as is.
+    let (quadratic, linear) = tensor
+        .into_container()
+        .split_at(pair_count(rlk.glwe_size()) * poly_size);
+    out.as_mut_view()
+        .into_tensor()
+        .into_container()
+        .copy_from_slice(linear);
+
+    let decomposer = SignedDecomposer::<Scalar>::new(
+        rlk.decomposition_base_log(),
+        rlk.decomposition_level_count(),
+    );
+
+    let (mut output_fft_buffer, mut substack0) =
+        stack.make_aligned_uninit::<c64>(poly_size / 2 * rlk.glwe_size().0, align);
+    // output_fft_buffer is initially uninitialized, considered to be implicitly zero, to avoid
+    // the cost of filling it up with zeros. `is_output_uninit` is set to `false` once
+    // it has been fully initialized for the first time.
+    let output_fft_buffer = &mut *output_fft_buffer;
+    let mut is_output_uninit = true;
+
+    for (glev, quadratic_poly) in izip!(rlk.into_glev_iter(), quadratic.into_chunks(poly_size)) {
+        // The quadratic term multiplies S_i * S_j with a minus sign in the phase, hence we
+        // decompose its opposite.
+        let (mut decomposition, mut substack1) = TensorSignedDecompositionLendingIter::new(
+            quadratic_poly
+                .iter()
+                .map(|s| decomposer.closest_representable(s.wrapping_neg())),
+            DecompositionBaseLog(decomposer.base_log),
+            DecompositionLevelCount(decomposer.level_count),
+            substack0.rb_mut(),
+        );
+
+        // We loop through the levels (we reverse to match the order of the decomposition iterator.)
+        glev.rev().for_each(|glev_level| {
+            let (level, decomp_term, mut substack2) =
+                collect_next_term(&mut decomposition, &mut substack1, align);
+            debug_assert_eq!(glev_level.decomposition_level(), level);
+
+            let (mut fourier, substack3) = substack2
+                .rb_mut()
+                .make_aligned_uninit::<c64>(poly_size / 2, align);
+            let fourier = fft
+                .forward_as_integer(
+                    FourierPolynomialUninitMutView { data: &mut fourier },
+                    Polynomial::from_container(&*decomp_term),
+                    substack3,
+                )
+                .data;
+
+            // SAFETY: see comment above definition of `output_fft_buffer`
+            unsafe {
+                update_with_fmadd(
+                    output_fft_buffer,
+                    glev_level,
+                    fourier,
+                    is_output_uninit,
+                    poly_size,
+                )
+            };
+
+            // we initialized `output_fft_buffer, so we can set this to false
+            is_output_uninit = false;
+        });
+    }
+
+    if !is_output_uninit {
+        // SAFETY: output_fft_buffer is initialized, since `is_output_uninit` is false
+        let output_fft_buffer = &*unsafe { assume_init_mut(output_fft_buffer) };
+        izip!(
+            out.into_polynomial_list().into_polynomial_iter(),
+            output_fft_buffer
+                .into_chunks(poly_size / 2)
+                .map(|slice| FourierPolynomialView { data: slice }),
+        )
+        .for_each(|(out, fourier)| {
+            fft.add_backward_as_torus(out, fourier, substack0.rb_mut());
+        });
+    }
+}
+
+/// Returns the required memory for [`multiply`].
+pub fn multiply_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let tensor_glwe_size = tensor_product_glwe_size(glwe_size);
+    StackReq::try_new_aligned::<Scalar>(tensor_glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?
+        .try_and(StackReq::try_any_of([
+            tensor_product_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+            relinearize_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+        ])?)
+}
+
+/// Multiplies `lhs` and `rhs`, divides the result by $2^{\mathsf{delta\\_log}}$, and writes it
+/// to `out` after a relinearization with `rlk`.
+pub fn multiply<Scalar: UnsignedTorus>(
+    out: GlweCiphertext<&'_ mut [Scalar]>,
+    lhs: GlweCiphertext<&'_ [Scalar]>,
+    rhs: GlweCiphertext<&'_ [Scalar]>,
+    rlk: FourierGlweRelinearizationKeyView<'_>,
+    delta_log: DeltaLog,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    let polynomial_size = lhs.polynomial_size();
+    let tensor_glwe_size = tensor_product_glwe_size(lhs.size());
+    let (mut tensor, mut stack) = stack.make_aligned_with(
+        tensor_glwe_size.0 * polynomial_size.0,
+        CACHELINE_ALIGN,
+        |_| Scalar::ZERO,
+    );
+    tensor_product(
+        GlweCiphertext::from_container(&mut *tensor, polynomial_size),
+        lhs,
+        rhs,
+        delta_log,
+        fft,
+        stack.rb_mut(),
+    );
+    relinearize(
+        out,
+        GlweCiphertext::from_container(&*tensor, polynomial_size),
+        rlk,
+        fft,
+        stack,
+    );
+}
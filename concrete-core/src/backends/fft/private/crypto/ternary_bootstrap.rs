@@ -0,0 +1,306 @@
+use super::super::math::fft::FftView;
+use super::bootstrap::{pbs_modulus_switch, FourierLweBootstrapKey};
+use super::ggsw::{external_product, external_product_scratch, FourierGgswCiphertext};
+use crate::commons::crypto::bootstrap::StandardBootstrapKey;
+use crate::commons::crypto::glwe::GlweCiphertext;
+use crate::commons::crypto::lwe::LweCiphertext;
+#[cfg(feature = "backend_fft_serialization")]
+use crate::commons::math::tensor::ContainerOwned;
+use crate::commons::math::tensor::{Container, Split};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::CastInto;
+use crate::commons::utils::izip;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweDimension,
+    ModulusSwitchOffset, MonomialDegree, PolynomialSize,
+};
+use aligned_vec::CACHELINE_ALIGN;
+use concrete_fft::c64;
+use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// A bootstrap key for a ternary input key, in the Fourier domain.
+///
+/// Each coefficient $s\_i$ of the input key is encrypted in two consecutive GGSW ciphertexts, the
+/// first one encrypting $\[s\_i = 1\]$ and the second one encrypting $\[s\_i = -1\]$.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "backend_fft_serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "C: ContainerOwned"))
+)]
+pub struct FourierLweTernaryBootstrapKey<C: Container<Element = c64>> {
+    // The GGSW ciphertexts of the key, two for each coefficient of the input key.
+    inner: FourierLweBootstrapKey<C>,
+}
+
+pub type FourierLweTernaryBootstrapKeyView<'a> = FourierLweTernaryBootstrapKey<&'a [c64]>;
+pub type FourierLweTernaryBootstrapKeyMutView<'a> = FourierLweTernaryBootstrapKey<&'a mut [c64]>;
+
+impl<C: Container<Element = c64>> FourierLweTernaryBootstrapKey<C> {
+    pub fn new(
+        data: C,
+        key_size: LweDimension,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        Self {
+            inner: FourierLweBootstrapKey::new(
+                data,
+                LweDimension(2 * key_size.0),
+                polynomial_size,
+                glwe_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            ),
+        }
+    }
+
+    /// Returns an iterator over the pairs of GGSW ciphertexts composing the key, the first one
+    /// encrypting whether the key coefficient is one, and the second one whether it is minus one.
+    pub fn into_ggsw_pair_iter(
+        self,
+    ) -> impl DoubleEndedIterator<Item = (FourierGgswCiphertext<C>, FourierGgswCiphertext<C>)>
+    where
+        C: Split,
+    {
+        let key_size = self.key_size();
+        let polynomial_size = self.polynomial_size();
+        let glwe_size = self.glwe_size();
+        let decomposition_base_log = self.decomposition_base_log();
+        let decomposition_level_count = self.decomposition_level_count();
+        self.inner.data().split_into(key_size.0).map(move |slice| {
+            let mid = slice.container_len() / 2;
+            let (plus, minus) = slice.split_at(mid);
+            let new_ggsw = |data| {
+                FourierGgswCiphertext::new(
+                    data,
+                    polynomial_size,
+                    glwe_size,
+                    decomposition_base_log,
+                    decomposition_level_count,
+                )
+            };
+            (new_ggsw(plus), new_ggsw(minus))
+        })
+    }
+
+    pub fn key_size(&self) -> LweDimension {
+        LweDimension(self.inner.key_size().0 / 2)
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.inner.polynomial_size()
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.inner.glwe_size()
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.inner.decomposition_base_log()
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.inner.decomposition_level_count()
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        self.inner.output_lwe_dimension()
+    }
+
+    pub fn data(self) -> C {
+        self.inner.data()
+    }
+
+    pub fn as_view(&self) -> FourierLweTernaryBootstrapKeyView<'_> {
+        FourierLweTernaryBootstrapKeyView {
+            inner: self.inner.as_view(),
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierLweTernaryBootstrapKeyMutView<'_>
+    where
+        C: AsMut<[c64]>,
+    {
+        FourierLweTernaryBootstrapKeyMutView {
+            inner: self.inner.as_mut_view(),
+        }
+    }
+}
+
+/// Returns the required memory for
+/// [`FourierLweTernaryBootstrapKeyMutView::fill_with_forward_fourier`].
+pub fn fill_with_forward_fourier_scratch(fft: FftView<'_>) -> Result<StackReq, SizeOverflow> {
+    fft.forward_scratch()
+}
+
+impl<'a> FourierLweTernaryBootstrapKeyMutView<'a> {
+    /// Fills a ternary bootstrapping key with the Fourier transform of a ternary bootstrapping key
+    /// in the standard domain.
+    pub fn fill_with_forward_fourier<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        coef_bsk: StandardBootstrapKey<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        // Both keys store the pairs of GGSW ciphertexts one after the other.
+        self.inner.fill_with_forward_fourier(coef_bsk, fft, stack);
+    }
+}
+
+/// Returns the required memory for [`FourierLweTernaryBootstrapKeyView::blind_rotate`].
+pub fn blind_rotate_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let glwe_scratch =
+        StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?;
+    glwe_scratch
+        .try_and(glwe_scratch)?
+        .try_and(external_product_scratch::<Scalar>(
+            glwe_size,
+            polynomial_size,
+            fft,
+        )?)
+}
+
+/// Returns the required memory for [`FourierLweTernaryBootstrapKeyView::bootstrap`].
+pub fn bootstrap_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    blind_rotate_scratch::<Scalar>(glwe_size, polynomial_size, fft)?.try_and(
+        StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?,
+    )
+}
+
+impl<'a> FourierLweTernaryBootstrapKeyView<'a> {
+    /// Rotates the `lut` accumulator by the phase of the `lwe` ciphertext.
+    ///
+    /// For each mask element $\hat{a}\_i$, the accumulator is updated with two external products:
+    /// $\mathsf{ACC} \leftarrow \mathsf{ACC} + \mathsf{CT}^{+}\_i \boxdot \left(X^{\hat{a}\_i}
+    /// \mathsf{ACC} - \mathsf{ACC}\right) + \mathsf{CT}^{-}\_i \boxdot \left(X^{-\hat{a}\_i}
+    /// \mathsf{ACC} - \mathsf{ACC}\right)$, which rotates it by $X^{\hat{a}\_i s\_i}$.
+    pub fn blind_rotate<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        mut lut: GlweCiphertext<&'_ mut [Scalar]>,
+        lwe: &[Scalar],
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
+
+        let lut_poly_size = lut.polynomial_size();
+        let monomial_degree = pbs_modulus_switch(
+            *lwe_body,
+            lut_poly_size,
+            ModulusSwitchOffset(0),
+            LutCountLog(0),
+        );
+        lut.as_mut_view()
+            .into_polynomial_list()
+            .into_polynomial_iter()
+            .for_each(|mut poly| {
+                poly.update_with_wrapping_unit_monomial_div(MonomialDegree(monomial_degree));
+            });
+
+        let mut acc = lut;
+
+        for (lwe_mask_element, (ggsw_plus, ggsw_minus)) in
+            izip!(lwe_mask.iter(), self.into_ggsw_pair_iter())
+        {
+            if *lwe_mask_element != Scalar::ZERO {
+                let monomial_degree = MonomialDegree(pbs_modulus_switch(
+                    *lwe_mask_element,
+                    lut_poly_size,
+                    ModulusSwitchOffset(0),
+                    LutCountLog(0),
+                ));
+
+                // We copy the accumulator twice, to rotate it in both directions
+                let stack = stack.rb_mut();
+                let (mut acc_plus, stack) = stack.collect_aligned(
+                    CACHELINE_ALIGN,
+                    acc.as_view().into_container().iter().copied(),
+                );
+                let (mut acc_minus, mut stack) = stack.collect_aligned(
+                    CACHELINE_ALIGN,
+                    acc.as_view().into_container().iter().copied(),
+                );
+                let mut acc_plus = GlweCiphertext::from_container(&mut *acc_plus, lut_poly_size);
+                let mut acc_minus = GlweCiphertext::from_container(&mut *acc_minus, lut_poly_size);
+
+                // We compute acc_plus <- acc * X^{a_hat} and acc_minus <- acc * X^{-a_hat}
+                for mut poly in acc_plus
+                    .as_mut_view()
+                    .into_polynomial_list()
+                    .into_polynomial_iter()
+                {
+                    poly.update_with_wrapping_monic_monomial_mul(monomial_degree);
+                }
+                for mut poly in acc_minus
+                    .as_mut_view()
+                    .into_polynomial_list()
+                    .into_polynomial_iter()
+                {
+                    poly.update_with_wrapping_unit_monomial_div(monomial_degree);
+                }
+
+                // We subtract the accumulator to both rotations
+                izip!(
+                    acc_plus.as_mut_view().into_tensor().into_container(),
+                    acc_minus.as_mut_view().into_tensor().into_container(),
+                    acc.as_view().into_tensor().into_container(),
+                )
+                .for_each(|(plus, minus, a)| {
+                    *plus = plus.wrapping_sub(*a);
+                    *minus = minus.wrapping_sub(*a);
+                });
+
+                // At most one of the two GGSW ciphertexts encrypts one, the accumulator is left
+                // untouched when the key coefficient is zero.
+                external_product(
+                    acc.as_mut_view(),
+                    ggsw_plus,
+                    acc_plus.as_view(),
+                    fft,
+                    stack.rb_mut(),
+                );
+                external_product(
+                    acc.as_mut_view(),
+                    ggsw_minus,
+                    acc_minus.as_view(),
+                    fft,
+                    stack,
+                );
+            }
+        }
+    }
+
+    pub fn bootstrap<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        lwe_out: &mut [Scalar],
+        lwe_in: &[Scalar],
+        accumulator: GlweCiphertext<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        let (mut local_accumulator_data, stack) = stack.collect_aligned(
+            CACHELINE_ALIGN,
+            accumulator.as_view().into_container().iter().copied(),
+        );
+        let mut local_accumulator = GlweCiphertext::from_container(
+            &mut *local_accumulator_data,
+            accumulator.polynomial_size(),
+        );
+        self.blind_rotate(local_accumulator.as_mut_view(), lwe_in, fft, stack);
+        local_accumulator.as_view().fill_lwe_with_sample_extraction(
+            &mut LweCiphertext::from_container(&mut *lwe_out),
+            MonomialDegree(0),
+        );
+    }
+}
@@ -7,7 +7,7 @@ use super::super::math::fft::FftView;
 use super::bootstrap::{bootstrap_scratch, FourierLweBootstrapKeyView};
 use super::ggsw::{
     cmux, cmux_scratch, external_product, external_product_scratch,
-    fill_with_forward_fourier_scratch, FourierGgswCiphertext,
+    fill_with_forward_fourier_scratch, FourierGgswCiphertext, FourierGgswCiphertextMutView,
 };
 use crate::backends::fft::private::math::fft::FourierPolynomialList;
 use crate::commons::crypto::encoding::Cleartext;
@@ -313,6 +313,73 @@ pub fn circuit_bootstrap_boolean<Scalar: UnsignedTorus + CastInto<usize>>(
     }
 }
 
+pub fn circuit_bootstrap_boolean_fourier_scratch<Scalar>(
+    lwe_in_size: LweSize,
+    bsk_output_lwe_size: LweSize,
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    level_cbs: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(
+        polynomial_size.0 * glwe_size.0 * glwe_size.0 * level_cbs.0,
+        CACHELINE_ALIGN,
+    )?
+    .try_and(StackReq::try_any_of([
+        circuit_bootstrap_boolean_scratch::<Scalar>(
+            lwe_in_size,
+            bsk_output_lwe_size,
+            polynomial_size,
+            glwe_size,
+            fft,
+        )?,
+        fill_with_forward_fourier_scratch(fft)?,
+    ])?)
+}
+
+/// Circuit bootstrapping for boolean messages, with an output GGSW ciphertext in the Fourier
+/// domain.
+///
+/// The output GGSW ciphertext `ggsw_out` decomposition base log and level count are used as the
+/// circuit_bootstrap_boolean decomposition base log and level count.
+pub fn circuit_bootstrap_boolean_fourier<Scalar: UnsignedTorus + CastInto<usize>>(
+    fourier_bsk: FourierLweBootstrapKeyView<'_>,
+    lwe_in: LweCiphertext<&[Scalar]>,
+    ggsw_out: FourierGgswCiphertextMutView<'_>,
+    delta_log: DeltaLog,
+    fpksk_list: LwePrivateFunctionalPackingKeyswitchKeyList<&[Scalar]>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    let polynomial_size = ggsw_out.polynomial_size();
+    let glwe_size = ggsw_out.glwe_size();
+    let level_cbs = ggsw_out.decomposition_level_count();
+
+    let (mut ggsw_res_data, mut stack) = stack.make_aligned_with(
+        polynomial_size.0 * glwe_size.0 * glwe_size.0 * level_cbs.0,
+        CACHELINE_ALIGN,
+        |_| Scalar::ZERO,
+    );
+    let mut ggsw_res = StandardGgswCiphertext::from_container(
+        &mut *ggsw_res_data,
+        glwe_size,
+        polynomial_size,
+        ggsw_out.decomposition_base_log(),
+    );
+
+    circuit_bootstrap_boolean(
+        fourier_bsk,
+        lwe_in,
+        ggsw_res.as_mut_view(),
+        delta_log,
+        fpksk_list,
+        fft,
+        stack.rb_mut(),
+    );
+
+    ggsw_out.fill_with_forward_fourier(ggsw_res.as_view(), fft, stack);
+}
+
 pub fn homomorphic_shift_boolean_scratch<Scalar>(
     lwe_in_size: LweSize,
     polynomial_size: PolynomialSize,
@@ -13,6 +13,7 @@ use crate::commons::utils::izip;
 use crate::prelude::PolynomialSize;
 use aligned_vec::{avec, ABox};
 use concrete_fft::c64;
+use concrete_fft::ordered::FftAlgo;
 use concrete_fft::unordered::{Method, Plan};
 use dyn_stack::{DynStack, SizeOverflow, StackReq};
 use once_cell::sync::OnceCell;
@@ -21,7 +22,6 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::mem::{align_of, size_of, MaybeUninit};
 use std::sync::{Arc, RwLock};
-use std::time::Duration;
 
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 mod x86;
@@ -100,6 +100,27 @@ impl Fft {
     }
 }
 
+/// The size of the base FFT of the plans, for the sizes larger than it.
+const PLAN_BASE_SIZE: usize = 256;
+
+/// Returns the method used to create the FFT plan of size `n`.
+///
+/// The order in which a plan stores the coefficients in the Fourier domain depends on the base FFT
+/// it uses. The base FFT is fixed instead of being measured, so that every process creates the
+/// same plans, and Fourier entities serialized with the aligned layout can be used in place by
+/// another process.
+fn plan_method(n: usize) -> Method {
+    let base_n = Ord::min(n, PLAN_BASE_SIZE);
+    Method::UserProvided {
+        base_algo: if base_n < 4 {
+            FftAlgo::Dif2
+        } else {
+            FftAlgo::Dif4
+        },
+        base_n,
+    }
+}
+
 type PlanMap = RwLock<HashMap<usize, Arc<OnceCell<Arc<(Twisties, Plan)>>>>>;
 static PLANS: OnceCell<PlanMap> = OnceCell::new();
 fn plans() -> &'static PlanMap {
@@ -161,7 +182,7 @@ impl Fft {
                 p.get_or_init(|| {
                     Arc::new((
                         Twisties::new(n / 2),
-                        Plan::new(n / 2, Method::Measure(Duration::from_millis(10))),
+                        Plan::new(n / 2, plan_method(n / 2)),
                     ))
                 })
                 .clone()
@@ -0,0 +1,223 @@
+use super::{NttEngine, NttError};
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::commons::math::tensor::AsMutTensor;
+use crate::prelude::{
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextEntity,
+    GlweCiphertextGgswCiphertextDiscardingExternalProductEngine,
+    GlweCiphertextGgswCiphertextDiscardingExternalProductError, NttGgswCiphertext32,
+    NttGgswCiphertext64,
+};
+
+impl From<NttError> for GlweCiphertextGgswCiphertextDiscardingExternalProductError<NttError> {
+    fn from(err: NttError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextGgswCiphertextDiscardingExternalProductEngine`] for
+/// [`NttEngine`] that operates on 32 bit integers.
+impl
+    GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+        GlweCiphertext32,
+        NttGgswCiphertext32,
+        GlweCiphertext32,
+    > for NttEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_ggsw = 3_u32;
+    /// let input_glwe = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut ntt_engine = NttEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = default_engine.create_plaintext_from(&input_ggsw)?;
+    /// let plaintext_glwe = default_engine.create_plaintext_vector_from(&input_glwe)?;
+    ///
+    /// let ggsw = default_engine.encrypt_scalar_ggsw_ciphertext(
+    ///     &key,
+    ///     &plaintext_ggsw,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let ntt_ggsw: NttGgswCiphertext32 = ntt_engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    ///
+    /// // We allocate an output ciphertext simply by cloning the input.
+    /// // The content of this output ciphertext will by wiped by the external product.
+    /// let mut product = glwe.clone();
+    /// ntt_engine.discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+    ///     &glwe,
+    ///     &ntt_ggsw,
+    ///     &mut product,
+    /// )?;
+    /// #
+    /// # assert_eq!(
+    /// #     product.polynomial_size(),
+    /// #     glwe.polynomial_size(),
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &GlweCiphertext32,
+        ggsw_input: &NttGgswCiphertext32,
+        output: &mut GlweCiphertext32,
+    ) -> Result<(), GlweCiphertextGgswCiphertextDiscardingExternalProductError<Self::EngineError>>
+    {
+        NttError::perform_ntt_checks(glwe_input.polynomial_size())?;
+        GlweCiphertextGgswCiphertextDiscardingExternalProductError::perform_generic_checks(
+            glwe_input, ggsw_input, output,
+        )?;
+        unsafe {
+            self.discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                glwe_input, ggsw_input, output,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &GlweCiphertext32,
+        ggsw_input: &NttGgswCiphertext32,
+        output: &mut GlweCiphertext32,
+    ) {
+        let polynomial_size = glwe_input.0.polynomial_size();
+        let mut rounded_buffer =
+            ImplGlweCiphertext::allocate(0u32, polynomial_size, glwe_input.0.size());
+        // The external product accumulates in the output, so we have to clear it first.
+        output.0.as_mut_tensor().fill_with_element(0);
+        let ntt = self.ntt_32(polynomial_size);
+        ggsw_input
+            .0
+            .external_product(&mut output.0, &glwe_input.0, &mut rounded_buffer, ntt);
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextGgswCiphertextDiscardingExternalProductEngine`] for
+/// [`NttEngine`] that operates on 64 bit integers.
+impl
+    GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+        GlweCiphertext64,
+        NttGgswCiphertext64,
+        GlweCiphertext64,
+    > for NttEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_ggsw = 3_u64;
+    /// let input_glwe = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut ntt_engine = NttEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = default_engine.create_plaintext_from(&input_ggsw)?;
+    /// let plaintext_glwe = default_engine.create_plaintext_vector_from(&input_glwe)?;
+    ///
+    /// let ggsw = default_engine.encrypt_scalar_ggsw_ciphertext(
+    ///     &key,
+    ///     &plaintext_ggsw,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let ntt_ggsw: NttGgswCiphertext64 = ntt_engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    ///
+    /// // We allocate an output ciphertext simply by cloning the input.
+    /// // The content of this output ciphertext will by wiped by the external product.
+    /// let mut product = glwe.clone();
+    /// ntt_engine.discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+    ///     &glwe,
+    ///     &ntt_ggsw,
+    ///     &mut product,
+    /// )?;
+    /// #
+    /// # assert_eq!(
+    /// #     product.polynomial_size(),
+    /// #     glwe.polynomial_size(),
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &GlweCiphertext64,
+        ggsw_input: &NttGgswCiphertext64,
+        output: &mut GlweCiphertext64,
+    ) -> Result<(), GlweCiphertextGgswCiphertextDiscardingExternalProductError<Self::EngineError>>
+    {
+        NttError::perform_ntt_checks(glwe_input.polynomial_size())?;
+        GlweCiphertextGgswCiphertextDiscardingExternalProductError::perform_generic_checks(
+            glwe_input, ggsw_input, output,
+        )?;
+        unsafe {
+            self.discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                glwe_input, ggsw_input, output,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &GlweCiphertext64,
+        ggsw_input: &NttGgswCiphertext64,
+        output: &mut GlweCiphertext64,
+    ) {
+        let polynomial_size = glwe_input.0.polynomial_size();
+        let mut rounded_buffer =
+            ImplGlweCiphertext::allocate(0u64, polynomial_size, glwe_input.0.size());
+        // The external product accumulates in the output, so we have to clear it first.
+        output.0.as_mut_tensor().fill_with_element(0);
+        let ntt = self.ntt_64(polynomial_size);
+        ggsw_input
+            .0
+            .external_product(&mut output.0, &glwe_input.0, &mut rounded_buffer, ntt);
+    }
+}
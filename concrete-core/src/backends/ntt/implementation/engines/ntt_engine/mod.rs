@@ -0,0 +1,105 @@
+use crate::backends::ntt::private::math::transform::Ntt;
+use crate::prelude::PolynomialSize;
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+use std::collections::BTreeMap;
+
+/// The largest polynomial size supported by the NTT backend.
+///
+/// The transform used for 32 bits inputs works modulo a prime $q < 2^{32}$ such that
+/// $q \equiv 1 \mod 2N$. For $2N = 2^{30}$, $3 \cdot 2^{30} + 1$ is such a prime, but none exists
+/// for $2N = 2^{31}$.
+const MAX_POLYNOMIAL_SIZE: PolynomialSize = PolynomialSize(1 << 29);
+
+/// Error that can occur in the execution of FHE operations by the [`NttEngine`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum NttError {
+    UnsupportedPolynomialSize,
+}
+
+impl core::fmt::Display for NttError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NttError::UnsupportedPolynomialSize => f.write_str(
+                "The NTT backend only supports polynomial sizes that are powers of two between 1 \
+                    and 2^29.",
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NttError {}
+
+impl NttError {
+    pub fn perform_ntt_checks(polynomial_size: PolynomialSize) -> Result<(), NttError> {
+        if polynomial_size.0.is_power_of_two() && polynomial_size <= MAX_POLYNOMIAL_SIZE {
+            Ok(())
+        } else {
+            Err(NttError::UnsupportedPolynomialSize)
+        }
+    }
+}
+
+/// The main engine exposed by the NTT backend.
+///
+/// This engine keeps one NTT transformer per polynomial size and precision it encountered, so that
+/// their buffers only have to be allocated once.
+pub struct NttEngine {
+    ntts_32: BTreeMap<PolynomialSize, Ntt<u64>>,
+    ntts_64: BTreeMap<PolynomialSize, Ntt<u128>>,
+}
+
+impl NttEngine {
+    pub(crate) fn ntt_32(&mut self, poly_size: PolynomialSize) -> &mut Ntt<u64> {
+        self.ntts_32
+            .entry(poly_size)
+            .or_insert_with(|| Ntt::new(poly_size))
+    }
+
+    pub(crate) fn ntt_64(&mut self, poly_size: PolynomialSize) -> &mut Ntt<u128> {
+        self.ntts_64
+            .entry(poly_size)
+            .or_insert_with(|| Ntt::new(poly_size))
+    }
+
+    // The bootstrap buffers need to own the transformer, so we take it out of the engine for the
+    // duration of the operation, and give it back with `release_ntt_*`.
+    pub(crate) fn take_ntt_32(&mut self, poly_size: PolynomialSize) -> Ntt<u64> {
+        self.ntts_32
+            .remove(&poly_size)
+            .unwrap_or_else(|| Ntt::new(poly_size))
+    }
+
+    pub(crate) fn take_ntt_64(&mut self, poly_size: PolynomialSize) -> Ntt<u128> {
+        self.ntts_64
+            .remove(&poly_size)
+            .unwrap_or_else(|| Ntt::new(poly_size))
+    }
+
+    pub(crate) fn release_ntt_32(&mut self, poly_size: PolynomialSize, ntt: Ntt<u64>) {
+        self.ntts_32.insert(poly_size, ntt);
+    }
+
+    pub(crate) fn release_ntt_64(&mut self, poly_size: PolynomialSize, ntt: Ntt<u128>) {
+        self.ntts_64.insert(poly_size, ntt);
+    }
+}
+
+impl AbstractEngineSeal for NttEngine {}
+impl AbstractEngine for NttEngine {
+    type EngineError = NttError;
+    type Parameters = ();
+
+    fn new(_parameter: Self::Parameters) -> Result<Self, Self::EngineError> {
+        Ok(NttEngine {
+            ntts_32: BTreeMap::new(),
+            ntts_64: BTreeMap::new(),
+        })
+    }
+}
+
+mod ggsw_ciphertext_conversion;
+mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+mod lwe_bootstrap_key_conversion;
+mod lwe_ciphertext_discarding_bootstrap;
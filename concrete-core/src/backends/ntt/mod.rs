@@ -1,4 +1,6 @@
 //! An accelerated backend using the NTT.
 
+mod implementation;
 #[doc(hidden)]
 pub mod private;
+pub use implementation::{engines, entities};
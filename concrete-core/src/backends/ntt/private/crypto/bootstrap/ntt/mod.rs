@@ -94,11 +94,10 @@ impl<Cont> NttBootstrapKey<Cont> {
     /// ```
     /// use concrete_core::backends::ntt::private::crypto::bootstrap::NttBootstrapKey;
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_256::MOD_32_256;
     /// use concrete_core::prelude::{
     ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
     /// };
-    /// let vector = vec![ModQ::new(0u64, MOD_32_256); 256 * 5 * 4 * 4 * 15];
+    /// let vector = vec![ModQ::new(0u64); 256 * 5 * 4 * 4 * 15];
     /// let bsk = NttBootstrapKey::from_container(
     ///     vector.as_slice(),
     ///     GlweSize(4),
@@ -144,15 +143,10 @@ impl<Cont> NttBootstrapKey<Cont> {
     ///
     /// ```
     /// use concrete_core::backends::ntt::private::crypto::bootstrap::NttBootstrapKey;
-    /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_256::{
-    ///     INVROOTS_32_256, MOD_32_256, NINV_32_256, ROOTS_32_256,
-    /// };
     /// use concrete_core::backends::ntt::private::math::transform::Ntt;
     /// use concrete_core::commons::crypto::bootstrap::StandardBootstrapKey;
     /// use concrete_core::prelude::{
     ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
-    ///     PolynomialSizeLog,
     /// };
     /// let bsk = StandardBootstrapKey::allocate(
     ///     9u32,
@@ -170,20 +164,7 @@ impl<Cont> NttBootstrapKey<Cont> {
     ///     LweDimension(4),
     /// );
     /// let poly_size = PolynomialSize(256);
-    /// let log_size = PolynomialSizeLog(8);
-    /// let q: u64 = MOD_32_256;
-    /// let roots: Vec<ModQ<u64>> = ROOTS_32_256
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let roots_inv: Vec<ModQ<u64>> = INVROOTS_32_256
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let n_inv = ModQ::new(NINV_32_256, q);
-    /// let mut ntt = Ntt::new(poly_size, log_size, roots, roots_inv, n_inv);
+    /// let mut ntt: Ntt<u64> = Ntt::new(poly_size);
     /// ntt_bsk.fill_with_forward_ntt(&bsk, &mut ntt);
     /// ```
     pub fn fill_with_forward_ntt<InputCont, Scalar, NttScalar>(
@@ -406,7 +387,6 @@ impl<Cont> NttBootstrapKey<Cont> {
     /// ```
     /// use concrete_core::backends::ntt::private::crypto::bootstrap::NttBootstrapKey;
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_256::MOD_32_256;
     /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
     /// use concrete_core::prelude::{
     ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
@@ -420,12 +400,12 @@ impl<Cont> NttBootstrapKey<Cont> {
     /// );
     /// for mut ggsw in bsk.ggsw_iter_mut() {
     ///     ggsw.as_mut_tensor()
-    ///         .fill_with_element(ModQ::new(1u64, MOD_32_256));
+    ///         .fill_with_element(ModQ::new(1u64));
     /// }
     /// assert!(bsk
     ///     .as_tensor()
     ///     .iter()
-    ///     .all(|a| *a == ModQ::new(1u64, MOD_32_256)));
+    ///     .all(|a| *a == ModQ::new(1u64)));
     /// assert_eq!(bsk.ggsw_iter_mut().count(), 4);
     /// ```
     pub fn ggsw_iter_mut<NttScalar: UnsignedInteger>(
@@ -552,9 +532,6 @@ where
     ///     BootstrapBuffers, NttBootstrapKey,
     /// };
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_1024::{
-    ///     INVROOTS_32_1024, MOD_32_1024, NINV_32_1024, ROOTS_32_1024,
-    /// };
     /// use concrete_core::backends::ntt::private::math::transform::Ntt;
     /// use concrete_core::commons::crypto::bootstrap::StandardBootstrapKey;
     /// use concrete_core::commons::crypto::encoding::Plaintext;
@@ -568,7 +545,7 @@ where
     /// use concrete_core::commons::numeric::CastInto;
     /// use concrete_core::prelude::{
     ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev, LweDimension,
-    ///     LweSize, PolynomialSize, PolynomialSizeLog,
+    ///     LweSize, PolynomialSize,
     /// };
     /// use concrete_csprng::generators::SoftwareRandomGenerator;
     /// use concrete_csprng::seeders::{Seed, UnixSeeder};
@@ -611,20 +588,7 @@ where
     /// );
     ///
     /// let poly_size = PolynomialSize(1024);
-    /// let log_size = PolynomialSizeLog(10);
-    /// let q: u64 = MOD_32_1024;
-    /// let roots: Vec<ModQ<u64>> = ROOTS_32_1024
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let roots_inv: Vec<ModQ<u64>> = INVROOTS_32_1024
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let n_inv = ModQ::new(NINV_32_1024, q);
-    /// let mut ntt = Ntt::new(poly_size, log_size, roots, roots_inv, n_inv);
+    /// let mut ntt: Ntt<u64> = Ntt::new(poly_size);
     ///
     /// ntt_bsk.fill_with_forward_ntt(&coef_bsk, &mut ntt);
     ///
@@ -75,13 +75,12 @@ impl<Cont> NttGgswCiphertext<Cont> {
     /// ```
     /// use concrete_core::backends::ntt::private::crypto::ggsw::NttGgswCiphertext;
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_128::MOD_32_128;
     /// use concrete_core::prelude::{
     ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
     /// };
     ///
     /// let ggsw: NttGgswCiphertext<Vec<ModQ<u64>>> = NttGgswCiphertext::from_container(
-    ///     vec![ModQ::new(0, MOD_32_128); 7 * 7 * 10 * 3],
+    ///     vec![ModQ::new(0u64); 7 * 7 * 10 * 3],
     ///     GlweSize(7),
     ///     PolynomialSize(10),
     ///     DecompositionBaseLog(4),
@@ -231,7 +230,6 @@ impl<Cont> NttGgswCiphertext<Cont> {
     /// ```
     /// use concrete_core::backends::ntt::private::crypto::ggsw::NttGgswCiphertext;
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_128::MOD_32_128;
     /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
     /// use concrete_core::prelude::{
     ///     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize,
@@ -246,12 +244,12 @@ impl<Cont> NttGgswCiphertext<Cont> {
     /// );
     /// let mut list = ggsw.as_mut_glwe_list();
     /// list.as_mut_tensor()
-    ///     .fill_with_element(ModQ::new(1u64, MOD_32_128));
+    ///     .fill_with_element(ModQ::new(1u64));
     /// assert_eq!(list.glwe_dimension(), GlweDimension(6));
     /// assert_eq!(list.ciphertext_count(), CiphertextCount(3 * 7));
     /// ggsw.as_tensor()
     ///     .iter()
-    ///     .for_each(|a| assert_eq!(*a, ModQ::new(1u64, MOD_32_128)));
+    ///     .for_each(|a| assert_eq!(*a, ModQ::new(1u64)));
     /// ```
     pub fn as_mut_glwe_list<E>(&mut self) -> GlweList<&mut [E]>
     where
@@ -351,7 +349,6 @@ impl<Cont> NttGgswCiphertext<Cont> {
     /// ```
     /// use concrete_core::backends::ntt::private::crypto::ggsw::NttGgswCiphertext;
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_128::MOD_32_128;
     /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
     /// use concrete_core::prelude::{
     ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
@@ -366,13 +363,13 @@ impl<Cont> NttGgswCiphertext<Cont> {
     /// for mut level_matrix in ggsw.level_matrix_iter_mut() {
     ///     for mut glwe in level_matrix.row_iter_mut() {
     ///         glwe.as_mut_tensor()
-    ///             .fill_with_element(ModQ::new(1u64, MOD_32_128));
+    ///             .fill_with_element(ModQ::new(1u64));
     ///     }
     /// }
     /// assert!(ggsw
     ///     .as_tensor()
     ///     .iter()
-    ///     .all(|a| *a == ModQ::new(1u64, MOD_32_128)));
+    ///     .all(|a| *a == ModQ::new(1u64)));
     /// assert_eq!(ggsw.level_matrix_iter_mut().count(), 3);
     /// ```
     pub fn level_matrix_iter_mut(
@@ -463,9 +460,9 @@ impl<Cont> NttGgswCiphertext<Cont> {
         let mut decomposition = decomposer.decompose_tensor(rounded_input_glwe);
 
         let mut fft_buffer: NttPolynomial<Vec<ModQ<NttScalar>>> =
-            NttPolynomial::allocate(ntt.get_zero_mod_q(), self.poly_size);
+            NttPolynomial::allocate(ModQ::empty(), self.poly_size);
         let mut ntt_glwe_buffer: NttGlweCiphertext<Vec<ModQ<NttScalar>>> =
-            NttGlweCiphertext::allocate(ntt.get_zero_mod_q(), self.poly_size, self.glwe_size);
+            NttGlweCiphertext::allocate(ModQ::empty(), self.poly_size, self.glwe_size);
 
         // We loop through the levels (we reverse to match the order of the decomposition iterator.)
         for ggsw_decomp_matrix in self.level_matrix_iter().rev() {
@@ -506,7 +503,11 @@ impl<Cont> NttGgswCiphertext<Cont> {
                     ntt_glwe_buffer.polynomial_iter_mut()
                 );
                 for zip_args!(ggsw_poly, mut output_poly) in iterator {
-                    output_poly.update_with_multiply_accumulate(&ggsw_poly, &fft_buffer);
+                    output_poly.update_with_multiply_accumulate(
+                        &ggsw_poly,
+                        &fft_buffer,
+                        ntt.modulus(),
+                    );
                 }
             }
         }
@@ -27,10 +27,9 @@ impl<NttScalar: UnsignedInteger> NttGlweCiphertext<Vec<ModQ<NttScalar>>> {
     /// ```
     /// use concrete_core::backends::ntt::private::crypto::glwe::NttGlweCiphertext;
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_128::MOD_32_128;
     /// use concrete_core::prelude::{GlweSize, PolynomialSize};
     /// let glwe: NttGlweCiphertext<Vec<ModQ<u64>>> =
-    ///     NttGlweCiphertext::allocate(ModQ::new(0u64, MOD_32_128), PolynomialSize(10), GlweSize(7));
+    ///     NttGlweCiphertext::allocate(ModQ::new(0u64), PolynomialSize(10), GlweSize(7));
     /// assert_eq!(glwe.glwe_size(), GlweSize(7));
     /// assert_eq!(glwe.polynomial_size(), PolynomialSize(10));
     /// ```
@@ -56,11 +55,10 @@ impl<Cont> NttGlweCiphertext<Cont> {
     /// ```
     /// use concrete_core::backends::ntt::private::crypto::glwe::NttGlweCiphertext;
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_128::MOD_32_128;
     /// use concrete_core::prelude::{GlweSize, PolynomialSize};
     ///
     /// let glwe: NttGlweCiphertext<Vec<ModQ<u64>>> = NttGlweCiphertext::from_container(
-    ///     vec![ModQ::new(0u64, MOD_32_128); 7 * 10],
+    ///     vec![ModQ::new(0u64); 7 * 10],
     ///     GlweSize(7),
     ///     PolynomialSize(10),
     /// );
@@ -87,11 +85,10 @@ impl<Cont> NttGlweCiphertext<Cont> {
     /// ```
     /// use concrete_core::backends::ntt::private::crypto::glwe::NttGlweCiphertext;
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_128::MOD_32_128;
     /// use concrete_core::prelude::{GlweSize, PolynomialSize};
     ///
     /// let glwe: NttGlweCiphertext<Vec<ModQ<u64>>> =
-    ///     NttGlweCiphertext::allocate(ModQ::new(0u64, MOD_32_128), PolynomialSize(10), GlweSize(7));
+    ///     NttGlweCiphertext::allocate(ModQ::new(0u64), PolynomialSize(10), GlweSize(7));
     /// assert_eq!(glwe.glwe_size(), GlweSize(7));
     /// ```
     pub fn glwe_size(&self) -> GlweSize
@@ -108,11 +105,10 @@ impl<Cont> NttGlweCiphertext<Cont> {
     /// ```
     /// use concrete_core::backends::ntt::private::crypto::glwe::NttGlweCiphertext;
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_128::MOD_32_128;
     /// use concrete_core::prelude::{GlweSize, PolynomialSize};
     ///
     /// let glwe: NttGlweCiphertext<Vec<ModQ<u64>>> =
-    ///     NttGlweCiphertext::allocate(ModQ::new(0u64, MOD_32_128), PolynomialSize(10), GlweSize(7));
+    ///     NttGlweCiphertext::allocate(ModQ::new(0u64), PolynomialSize(10), GlweSize(7));
     /// assert_eq!(glwe.polynomial_size(), PolynomialSize(10));
     /// ```
     pub fn polynomial_size(&self) -> PolynomialSize {
@@ -125,34 +121,18 @@ impl<Cont> NttGlweCiphertext<Cont> {
     /// ```
     /// use concrete_core::backends::ntt::private::crypto::glwe::NttGlweCiphertext;
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_128::{
-    ///     INVROOTS_32_128, MOD_32_128, NINV_32_128, ROOTS_32_128,
-    /// };
     /// use concrete_core::backends::ntt::private::math::transform::Ntt;
     /// use concrete_core::commons::crypto::glwe::GlweCiphertext;
-    /// use concrete_core::prelude::{GlweSize, PolynomialSize, PolynomialSizeLog};
+    /// use concrete_core::prelude::{GlweSize, PolynomialSize};
     /// let mut ntt_glwe: NttGlweCiphertext<Vec<ModQ<u64>>> = NttGlweCiphertext::allocate(
-    ///     ModQ::new(0u64, MOD_32_128),
+    ///     ModQ::new(0u64),
     ///     PolynomialSize(128),
     ///     GlweSize(7),
     /// );
     ///
     /// let glwe = GlweCiphertext::allocate(0 as u32, PolynomialSize(128), GlweSize(7));
     /// let poly_size = PolynomialSize(128);
-    /// let log_size = PolynomialSizeLog(7);
-    /// let q: u64 = MOD_32_128;
-    /// let roots: Vec<ModQ<u64>> = ROOTS_32_128
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let roots_inv: Vec<ModQ<u64>> = INVROOTS_32_128
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let n_inv = ModQ::new(NINV_32_128, q);
-    /// let mut ntt = Ntt::new(poly_size, log_size, roots, roots_inv, n_inv);
+    /// let mut ntt: Ntt<u64> = Ntt::new(poly_size);
     ///
     /// ntt_glwe.fill_with_forward_ntt(&glwe, &mut ntt);
     /// ```
@@ -177,34 +157,18 @@ impl<Cont> NttGlweCiphertext<Cont> {
     /// ```
     /// use concrete_core::backends::ntt::private::crypto::glwe::NttGlweCiphertext;
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_128::{
-    ///     INVROOTS_32_128, MOD_32_128, NINV_32_128, ROOTS_32_128,
-    /// };
     /// use concrete_core::backends::ntt::private::math::transform::Ntt;
     /// use concrete_core::commons::crypto::glwe::GlweCiphertext;
-    /// use concrete_core::prelude::{GlweSize, PolynomialSize, PolynomialSizeLog};
+    /// use concrete_core::prelude::{GlweSize, PolynomialSize};
     /// let mut ntt_glwe: NttGlweCiphertext<Vec<ModQ<u64>>> = NttGlweCiphertext::allocate(
-    ///     ModQ::new(0u64, MOD_32_128),
+    ///     ModQ::new(0u64),
     ///     PolynomialSize(128),
     ///     GlweSize(7),
     /// );
     ///
     /// let glwe = GlweCiphertext::allocate(0 as u32, PolynomialSize(128), GlweSize(7));
     /// let poly_size = PolynomialSize(128);
-    /// let log_size = PolynomialSizeLog(7);
-    /// let q: u64 = MOD_32_128;
-    /// let roots: Vec<ModQ<u64>> = ROOTS_32_128
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let roots_inv: Vec<ModQ<u64>> = INVROOTS_32_128
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let n_inv = ModQ::new(NINV_32_128, q);
-    /// let mut ntt = Ntt::new(poly_size, log_size, roots, roots_inv, n_inv);
+    /// let mut ntt: Ntt<u64> = Ntt::new(poly_size);
     ///
     /// ntt_glwe.fill_with_forward_ntt(&glwe, &mut ntt);
     ///
@@ -1,139 +1,232 @@
 use crate::commons::numeric::{CastFrom, CastInto, UnsignedInteger};
-use std::fmt::Debug;
-use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// A structure to represent values in Z_q
+///
+/// The value is always kept in the canonical range `[0, q)`. The modulus is not stored with the
+/// value; the arithmetic is performed through a [`Modulus`], which is held once per transform.
 #[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug, Default)]
+#[repr(transparent)]
 pub struct ModQ<N: UnsignedInteger> {
     val: N,
-    q: N,
 }
 
-/// Implement the ring operations in Z_q for ModQ
-impl<N: UnsignedInteger> Add<Self> for ModQ<N> {
-    type Output = ModQ<N>;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        debug_assert_eq!(self.q.cast_into(), rhs.q.cast_into(), "Different Moduli!");
-        debug_assert_ne!(self.q.cast_into(), N::ZERO.cast_into(), "Modulus is 0!");
-        ModQ {
-            val: (self.val + rhs.val) % self.q,
-            q: self.q,
-        }
+impl<Element: UnsignedInteger> ModQ<Element> {
+    /// Creates a new value. The input is expected to already be reduced modulo q.
+    pub fn new(val: Element) -> Self {
+        ModQ { val }
     }
-}
 
-impl<N: UnsignedInteger> AddAssign<Self> for ModQ<N> {
-    fn add_assign(&mut self, rhs: Self) {
-        *self = *self + rhs;
+    pub fn empty() -> Self {
+        ModQ { val: Element::ZERO }
     }
-}
 
-impl<N: UnsignedInteger> Mul<Self> for ModQ<N> {
-    type Output = ModQ<N>;
+    pub fn set(&mut self, input: Element) {
+        self.val = input;
+    }
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        debug_assert_eq!(self.q.cast_into(), rhs.q.cast_into(), "Different Moduli!");
-        debug_assert_ne!(self.q.cast_into(), N::ZERO.cast_into(), "Modulus is 0!");
-        ModQ {
-            val: (self.val * rhs.val) % self.q,
-            q: self.q,
-        }
+    pub fn get(self) -> Element {
+        self.val
     }
 }
 
-impl<N: UnsignedInteger> MulAssign<Self> for ModQ<N> {
-    fn mul_assign(&mut self, rhs: Self) {
-        *self = *self * rhs;
+/// A constant operand of a modular multiplication, along with its precomputed Shoup quotient
+/// `floor(value * 2^(N::BITS / 2) / q)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShoupMultiplier<N: UnsignedInteger> {
+    value: N,
+    quotient: N,
+}
+
+impl<N: UnsignedInteger> ShoupMultiplier<N> {
+    pub fn value(&self) -> ModQ<N> {
+        ModQ::new(self.value)
     }
 }
 
-impl<N: UnsignedInteger> Neg for ModQ<N> {
-    type Output = ModQ<N>;
+/// An odd modulus q, along with the constants used to perform fast modular arithmetic.
+///
+/// The modulus must fit in the lower half of the bits of `N`, such that the product of two
+/// values mod q can be computed in `N` without overflowing. Multiplications by arbitrary values
+/// use Montgomery reductions, and multiplications by constants use Shoup's precomputed
+/// quotients. No division is performed outside of the constructor and the modulus switches.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Modulus<N: UnsignedInteger> {
+    q: N,
+    // -q^(-1) mod 2^(N::BITS / 2)
+    q_neg_inv: N,
+    // 2^N::BITS mod q
+    r2: N,
+}
 
-    fn neg(self) -> Self::Output {
-        debug_assert_ne!(self.q.cast_into(), N::ZERO.cast_into(), "Modulus is 0!");
-        ModQ {
-            val: (self.q - self.val) % self.q,
-            q: self.q,
+impl<N: UnsignedInteger> Modulus<N> {
+    const HALF_BITS: usize = N::BITS / 2;
+
+    /// Creates a new modulus.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::backends::ntt::private::math::mod_q::Modulus;
+    /// let modulus = Modulus::new(257u64);
+    /// assert_eq!(modulus.get(), 257u64);
+    /// ```
+    pub fn new(q: N) -> Self {
+        assert!(q & N::ONE == N::ONE, "The modulus must be odd.");
+        assert!(
+            q >> Self::HALF_BITS == N::ZERO,
+            "The modulus must fit in {} bits.",
+            Self::HALF_BITS
+        );
+        // Newton iteration for the inverse of q mod 2^N::BITS: every odd q is its own inverse mod
+        // 8, and each iteration doubles the number of correct bits.
+        let mut inv = q;
+        let mut correct_bits = 3;
+        while correct_bits < N::BITS {
+            inv = inv.wrapping_mul(N::TWO.wrapping_sub(q.wrapping_mul(inv)));
+            correct_bits *= 2;
         }
+        let q_neg_inv = inv.wrapping_neg() & Self::low_mask();
+        let r = (N::ONE << Self::HALF_BITS) % q;
+        let r2 = (r * r) % q;
+        Modulus { q, q_neg_inv, r2 }
+    }
+
+    pub fn get(&self) -> N {
+        self.q
     }
-}
 
-impl<N: UnsignedInteger> Sub<Self> for ModQ<N> {
-    type Output = ModQ<N>;
+    fn low_mask() -> N {
+        (N::ONE << Self::HALF_BITS) - N::ONE
+    }
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        ModQ {
-            val: (self + (-rhs)).val % self.q,
-            q: self.q,
+    /// Reduces an arbitrary value mod q.
+    pub fn reduce(&self, val: N) -> ModQ<N> {
+        ModQ::new(val % self.q)
+    }
+
+    /// Subtracts `bound` from `val` if `val` is greater or equal to `bound`.
+    #[inline]
+    pub(crate) fn reduce_once(val: N, bound: N) -> N {
+        if val >= bound {
+            val - bound
+        } else {
+            val
         }
     }
-}
 
-impl<N: UnsignedInteger> SubAssign<Self> for ModQ<N> {
-    fn sub_assign(&mut self, rhs: Self) {
-        *self = *self - rhs;
+    #[inline]
+    pub fn add(&self, a: ModQ<N>, b: ModQ<N>) -> ModQ<N> {
+        ModQ::new(Self::reduce_once(a.val + b.val, self.q))
     }
-}
 
-impl<Element: UnsignedInteger> ModQ<Element> {
-    /// scales the stored value to a new modulus (a power of two)
-    /// implicitly specified by the type N
-    pub fn mod_switch_from<N: UnsignedInteger + CastFrom<Element>>(self) -> N {
-        let new_val = ((self.val << N::BITS) | (self.q >> 1)) / self.q;
-        N::cast_from(new_val)
+    #[inline]
+    pub fn sub(&self, a: ModQ<N>, b: ModQ<N>) -> ModQ<N> {
+        ModQ::new(Self::reduce_once(a.val + self.q - b.val, self.q))
     }
 
-    /// scales the given value, interpreted as a value mod a power of two
-    /// implicitly specified by the type N, to the modulus self.q
-    pub fn mod_switch_into<N: UnsignedInteger + CastInto<Element>>(&mut self, input: N) {
-        let new_val: Element = <N as CastInto<Element>>::cast_into(input);
-        self.set((new_val * self.q + (Element::ONE << (N::BITS - 1))) >> N::BITS);
+    #[inline]
+    pub fn neg(&self, a: ModQ<N>) -> ModQ<N> {
+        ModQ::new(Self::reduce_once(self.q - a.val, self.q))
     }
 
-    pub fn set(&mut self, input: Element) {
-        debug_assert_ne!(
-            self.q.cast_into(),
-            Element::ZERO.cast_into(),
-            "Modulus is 0!"
-        );
-        self.val = input % self.q;
+    /// Montgomery reduction: returns `t * 2^(-N::BITS / 2) mod q` for `t < q * 2^(N::BITS / 2)`.
+    #[inline]
+    fn redc(&self, t: N) -> N {
+        let m = ((t & Self::low_mask()) * self.q_neg_inv) & Self::low_mask();
+        // t + m * q is a multiple of 2^(N::BITS / 2), and its low halves add up to exactly
+        // 2^(N::BITS / 2) unless both are zero. We only compute the high halves to avoid
+        // overflowing.
+        let mq = m * self.q;
+        let carry = if t & Self::low_mask() == N::ZERO {
+            N::ZERO
+        } else {
+            N::ONE
+        };
+        let res = (t >> Self::HALF_BITS) + (mq >> Self::HALF_BITS) + carry;
+        Self::reduce_once(res, self.q)
     }
 
-    pub fn get(self) -> Element {
-        self.val
+    #[inline]
+    pub fn mul(&self, a: ModQ<N>, b: ModQ<N>) -> ModQ<N> {
+        // The first reduction yields a * b * 2^(-N::BITS / 2), and the second one multiplies it
+        // back by 2^(N::BITS / 2).
+        ModQ::new(self.redc(self.redc(a.val * b.val) * self.r2))
     }
 
-    pub fn get_mod(self) -> Element {
-        self.q
+    /// Computes `a^exp` mod q.
+    pub fn pow(&self, a: ModQ<N>, mut exp: N) -> ModQ<N> {
+        let mut result = ModQ::new(Self::reduce_once(N::ONE, self.q));
+        let mut base = a;
+        while exp != N::ZERO {
+            if exp & N::ONE == N::ONE {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+        result
     }
 
-    pub fn new(val: Element, q: Element) -> Self {
-        // for 0 modulus use empty
-        debug_assert_ne!(q.cast_into(), Element::ZERO.cast_into(), "Modulus is 0!");
-        ModQ { val: val % q, q }
+    /// Computes the inverse of `a` mod q. The modulus must be prime.
+    pub fn inv(&self, a: ModQ<N>) -> ModQ<N> {
+        self.pow(a, self.q - N::TWO)
     }
 
-    pub fn empty() -> Self {
-        ModQ {
-            val: Element::ZERO,
-            q: Element::ZERO,
+    /// Precomputes the Shoup quotient of a constant operand.
+    pub fn shoup(&self, w: ModQ<N>) -> ShoupMultiplier<N> {
+        ShoupMultiplier {
+            value: w.val,
+            quotient: (w.val << Self::HALF_BITS) / self.q,
         }
     }
+
+    /// Returns a value congruent to `a * w` mod q, in the range `[0, 2q)`.
+    ///
+    /// The input `a` must be smaller than `2^(N::BITS / 2)`, which is guaranteed as soon as it
+    /// is smaller than q.
+    #[inline]
+    pub(crate) fn mul_shoup_lazy(&self, a: N, w: &ShoupMultiplier<N>) -> N {
+        let q_hat = (a * w.quotient) >> Self::HALF_BITS;
+        a * w.value - q_hat * self.q
+    }
+
+    #[inline]
+    pub fn mul_shoup(&self, a: ModQ<N>, w: &ShoupMultiplier<N>) -> ModQ<N> {
+        ModQ::new(Self::reduce_once(self.mul_shoup_lazy(a.val, w), self.q))
+    }
+
+    /// scales the given value, from a modulus q to a new modulus (a power of two)
+    /// implicitly specified by the type C
+    #[inline]
+    pub fn mod_switch_from<C: UnsignedInteger + CastFrom<N>>(&self, a: ModQ<N>) -> C {
+        let new_val = ((a.val << C::BITS) | (self.q >> 1)) / self.q;
+        C::cast_from(new_val)
+    }
+
+    /// scales the given value, interpreted as a value mod a power of two
+    /// implicitly specified by the type C, to the modulus q
+    #[inline]
+    pub fn mod_switch_into<C: UnsignedInteger + CastInto<N>>(&self, input: C) -> ModQ<N> {
+        let new_val: N = <C as CastInto<N>>::cast_into(input);
+        let scaled = (new_val * self.q + (N::ONE << (C::BITS - 1))) >> C::BITS;
+        ModQ::new(Self::reduce_once(scaled, self.q))
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::backends::ntt::private::math::params::params_64_128::MOD_64_128;
+    use std::fmt::Debug;
+
+    const MOD_32: u64 = 4294828033;
+    const MOD_64: u128 = 18446744073709436929;
 
     fn test_add_modq<N: UnsignedInteger + Debug>() {
-        let q: N = N::cast_from(255.);
-        let a: ModQ<N> = ModQ::new(N::cast_from(100.), q);
-        let b: ModQ<N> = ModQ::new(N::cast_from(200.), q);
+        let modulus = Modulus::new(N::cast_from(255.));
+        let a: ModQ<N> = ModQ::new(N::cast_from(100.));
+        let b: ModQ<N> = ModQ::new(N::cast_from(200.));
 
-        assert_eq!((a + b).val, N::cast_from(45.));
+        assert_eq!(modulus.add(a, b).val, N::cast_from(45.));
     }
 
     #[test]
@@ -147,11 +240,11 @@ mod test {
     }
 
     fn test_sub_modq<N: UnsignedInteger + Debug>() {
-        let q: N = N::cast_from(255.);
-        let a: ModQ<N> = ModQ::new(N::cast_from(100.), q);
-        let b: ModQ<N> = ModQ::new(N::cast_from(200.), q);
+        let modulus = Modulus::new(N::cast_from(255.));
+        let a: ModQ<N> = ModQ::new(N::cast_from(100.));
+        let b: ModQ<N> = ModQ::new(N::cast_from(200.));
 
-        assert_eq!((a - b).val, N::cast_from(155.));
+        assert_eq!(modulus.sub(a, b).val, N::cast_from(155.));
     }
 
     #[test]
@@ -165,11 +258,33 @@ mod test {
     }
 
     fn test_mul_modq<N: UnsignedInteger + Debug>() {
-        let q: N = N::cast_from(255.);
-        let a: ModQ<N> = ModQ::new(N::cast_from(100.), q);
-        let b: ModQ<N> = ModQ::new(N::cast_from(200.), q);
+        let modulus = Modulus::new(N::cast_from(255.));
+        let a: ModQ<N> = ModQ::new(N::cast_from(100.));
+        let b: ModQ<N> = ModQ::new(N::cast_from(200.));
+
+        assert_eq!(modulus.mul(a, b).val, N::cast_from(110.));
+        assert_eq!(
+            modulus.mul_shoup(a, &modulus.shoup(b)).val,
+            N::cast_from(110.)
+        );
+    }
+
+    fn test_pow_inv_modq<N: UnsignedInteger + Debug>() {
+        let modulus = Modulus::new(N::cast_from(257.));
+        let a: ModQ<N> = ModQ::new(N::cast_from(3.));
+
+        assert_eq!(modulus.pow(a, N::cast_from(5.)).val, N::cast_from(243.));
+        assert_eq!(modulus.mul(a, modulus.inv(a)).val, N::ONE);
+    }
 
-        assert_eq!((a * b).val, N::cast_from(110.));
+    #[test]
+    fn test_pow_inv_modq_64() {
+        test_pow_inv_modq::<u64>();
+    }
+
+    #[test]
+    fn test_pow_inv_modq_128() {
+        test_pow_inv_modq::<u128>();
     }
 
     #[test]
@@ -182,15 +297,34 @@ mod test {
         test_mul_modq::<u128>();
     }
 
+    #[test]
+    fn test_mul_modq_large_modulus_64() {
+        let modulus = Modulus::new(MOD_32);
+        let a = ModQ::new(MOD_32 - 1);
+        let b = ModQ::new(MOD_32 - 2);
+        // (-1) * (-2) = 2
+        assert_eq!(modulus.mul(a, b).val, 2);
+        assert_eq!(modulus.mul_shoup(a, &modulus.shoup(b)).val, 2);
+    }
+
+    #[test]
+    fn test_mul_modq_large_modulus_128() {
+        let modulus = Modulus::new(MOD_64);
+        let a = ModQ::new(MOD_64 - 1);
+        let b = ModQ::new(MOD_64 - 2);
+        // (-1) * (-2) = 2
+        assert_eq!(modulus.mul(a, b).val, 2);
+        assert_eq!(modulus.mul_shoup(a, &modulus.shoup(b)).val, 2);
+    }
+
     fn test_switch_modq<
         N: UnsignedInteger + Debug,
         N1: UnsignedInteger + CastFrom<N> + CastInto<N>,
     >() {
-        let q: N = N::cast_from(256.);
-        let a: ModQ<N> = ModQ::new(N::cast_from(128.), q);
-        let b: N1 = a.mod_switch_from();
-        let mut c: ModQ<N> = ModQ::new(N::cast_from(0.), q);
-        c.mod_switch_into(b);
+        let modulus = Modulus::new(N::cast_from(257.));
+        let a: ModQ<N> = ModQ::new(N::cast_from(128.));
+        let b: N1 = modulus.mod_switch_from(a);
+        let c: ModQ<N> = modulus.mod_switch_into(b);
         assert_eq!(a, c);
     }
 
@@ -206,18 +340,17 @@ mod test {
 
     #[test]
     fn test_switch_modq_64_2() {
-        let q: u128 = MOD_64_128;
+        let modulus = Modulus::new(MOD_64);
         let a: u64 = 17723707332261611143;
-        let mut b: ModQ<u128> = ModQ::new(0u128, q);
-        b.mod_switch_into(a);
+        let b: ModQ<u128> = modulus.mod_switch_into(a);
         assert_eq!(b.get(), 17723707332261500951u128);
     }
 
     #[test]
     fn test_switch_modq_64_3() {
-        let q: u128 = MOD_64_128;
-        let a: ModQ<u128> = ModQ::new(17723707332261611143u128, q);
-        let b: u64 = a.mod_switch_from();
+        let modulus = Modulus::new(MOD_64);
+        let a: ModQ<u128> = ModQ::new(17723707332261611143u128);
+        let b: u64 = modulus.mod_switch_from(a);
         assert_eq!(b, 17723707332261721335u64);
     }
 }
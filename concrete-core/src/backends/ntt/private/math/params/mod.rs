@@ -1,13 +1,366 @@
-pub mod params_32_1024;
-pub mod params_32_128;
-pub mod params_32_2048;
-pub mod params_32_256;
-pub mod params_32_4096;
-pub mod params_32_512;
-
-pub mod params_64_1024;
-pub mod params_64_128;
-pub mod params_64_2048;
-pub mod params_64_256;
-pub mod params_64_4096;
-pub mod params_64_512;
+//! Generation of the parameters of the NTT.
+//!
+//! For a polynomial size $N$ and a bit width $b$, we look for the largest prime $q < 2^b$ such
+//! that $q \equiv 1 \mod 2N$, and for a primitive $2N$-th root of unity $\psi$ modulo $q$. The
+//! negacyclic transform then uses the powers of $\psi$, stored in bit-reversed order.
+use crate::backends::ntt::private::math::mod_q::{ModQ, Modulus, ShoupMultiplier};
+use crate::commons::numeric::{CastFrom, UnsignedInteger};
+use crate::prelude::PolynomialSize;
+use once_cell::sync::OnceCell;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+// The bases of the Miller-Rabin test. Using the first twelve primes makes the test deterministic
+// for every integer smaller than 2^64.
+const MILLER_RABIN_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// The parameters of an NTT over polynomials of a given size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NttParameters<N: UnsignedInteger> {
+    poly_size: PolynomialSize,
+    modulus: Modulus<N>,
+    roots: Vec<ShoupMultiplier<N>>,
+    roots_inv: Vec<ShoupMultiplier<N>>,
+    n_inv: ShoupMultiplier<N>,
+}
+
+impl<N: UnsignedInteger> NttParameters<N> {
+    /// Generates the parameters for polynomials of size `poly_size`, using a prime modulus of at
+    /// most `bit_width` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `poly_size` is not a power of two, if `bit_width` is larger than half the bits
+    /// of `N`, or if there is no suitable prime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::backends::ntt::private::math::params::NttParameters;
+    /// use concrete_core::prelude::PolynomialSize;
+    /// let params = NttParameters::<u64>::generate(PolynomialSize(16384), 32);
+    /// assert_eq!(params.polynomial_size(), PolynomialSize(16384));
+    /// assert!(params.modulus().get() < 1 << 32);
+    /// assert_eq!(params.modulus().get() % (2 * 16384), 1);
+    /// ```
+    pub fn generate(poly_size: PolynomialSize, bit_width: usize) -> Self
+    where
+        N: CastFrom<u64>,
+    {
+        assert!(
+            poly_size.0.is_power_of_two(),
+            "The polynomial size must be a power of two, got {}.",
+            poly_size.0
+        );
+        assert!(
+            bit_width <= N::BITS / 2,
+            "The bit width must be at most {}, got {}.",
+            N::BITS / 2,
+            bit_width
+        );
+        let log_two_n = poly_size.log2().0 + 1;
+        assert!(
+            log_two_n < bit_width,
+            "The polynomial size is too large for a modulus of {} bits.",
+            bit_width
+        );
+
+        // We look for the largest prime below 2^bit_width which is equal to 1 mod 2N.
+        let two_n = N::ONE << log_two_n;
+        let mut q = ((((N::ONE << bit_width) - N::ONE) >> log_two_n) << log_two_n) + N::ONE;
+        while !is_prime(q) {
+            assert!(q > two_n, "No NTT-friendly prime of {} bits.", bit_width);
+            q -= two_n;
+        }
+        let modulus = Modulus::new(q);
+
+        // For any quadratic non-residue g, g^((q - 1) / 2N) is a primitive 2N-th root of unity.
+        let minus_one = ModQ::new(q - N::ONE);
+        let n = N::ONE << poly_size.log2().0;
+        let psi = (2..)
+            .map(|g: u64| modulus.pow(ModQ::new(N::cast_from(g)), (q - N::ONE) >> log_two_n))
+            .find(|psi| modulus.pow(*psi, n) == minus_one)
+            .unwrap();
+        let psi_inv = modulus.inv(psi);
+
+        let roots = bit_reversed_powers(&modulus, psi, poly_size);
+        let roots_inv = bit_reversed_powers(&modulus, psi_inv, poly_size);
+        let n_inv = modulus.inv(ModQ::new(n));
+        let params = NttParameters {
+            poly_size,
+            roots: roots.into_iter().map(|w| modulus.shoup(w)).collect(),
+            roots_inv: roots_inv.into_iter().map(|w| modulus.shoup(w)).collect(),
+            n_inv: modulus.shoup(n_inv),
+            modulus,
+        };
+        assert!(params.is_valid(), "Generated invalid NTT parameters.");
+        params
+    }
+
+    /// Checks that the parameters describe a valid negacyclic NTT.
+    pub fn is_valid(&self) -> bool
+    where
+        N: CastFrom<u64>,
+    {
+        let n = self.poly_size.0;
+        let modulus = &self.modulus;
+        let q = modulus.get();
+        let log_two_n = self.poly_size.log2().0 + 1;
+        let one = ModQ::new(N::ONE);
+
+        if !is_prime(q) || (q - N::ONE) & ((N::ONE << log_two_n) - N::ONE) != N::ZERO {
+            return false;
+        }
+        if self.roots.len() != n || self.roots_inv.len() != n {
+            return false;
+        }
+        let psi = if n == 1 {
+            ModQ::new(q - N::ONE)
+        } else {
+            self.roots[n / 2].value()
+        };
+        if modulus.pow(psi, N::ONE << self.poly_size.log2().0) != ModQ::new(q - N::ONE) {
+            return false;
+        }
+        let expected = bit_reversed_powers(modulus, psi, self.poly_size);
+        let roots_match = self
+            .roots
+            .iter()
+            .zip(self.roots_inv.iter())
+            .zip(expected.iter())
+            .all(|((root, root_inv), expected)| {
+                root.value() == *expected && modulus.mul(root.value(), root_inv.value()) == one
+            });
+        let n_inv_match = modulus.mul(
+            self.n_inv.value(),
+            ModQ::new(N::ONE << self.poly_size.log2().0),
+        ) == one;
+        roots_match && n_inv_match
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    pub fn modulus(&self) -> &Modulus<N> {
+        &self.modulus
+    }
+
+    /// Returns the powers of the root of unity in bit-reversed order, along with their Shoup
+    /// quotients.
+    pub fn roots(&self) -> &[ShoupMultiplier<N>] {
+        &self.roots
+    }
+
+    /// Returns the inverses of the powers of the root of unity in bit-reversed order, along with
+    /// their Shoup quotients.
+    pub fn roots_inv(&self) -> &[ShoupMultiplier<N>] {
+        &self.roots_inv
+    }
+
+    pub fn n_inv(&self) -> &ShoupMultiplier<N> {
+        &self.n_inv
+    }
+}
+
+type ParametersMap = RwLock<HashMap<(TypeId, usize), Arc<dyn Any + Send + Sync>>>;
+static PARAMETERS: OnceCell<ParametersMap> = OnceCell::new();
+fn parameters() -> &'static ParametersMap {
+    PARAMETERS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Returns the parameters for polynomials of size `poly_size`, using a modulus of half the bits
+/// of `N`.
+///
+/// The parameters are only generated once per process, and shared afterwards.
+pub fn cached_parameters<N>(poly_size: PolynomialSize) -> Arc<NttParameters<N>>
+where
+    N: UnsignedInteger + CastFrom<u64> + Send + Sync,
+{
+    let key = (TypeId::of::<N>(), poly_size.0);
+    let cached = parameters().read().unwrap().get(&key).cloned();
+    let params = match cached {
+        Some(params) => params,
+        None => {
+            // We generate the parameters while holding the lock, so that they are not generated
+            // twice by concurrent threads.
+            let mut map = parameters().write().unwrap();
+            map.entry(key)
+                .or_insert_with(|| Arc::new(NttParameters::<N>::generate(poly_size, N::BITS / 2)))
+                .clone()
+        }
+    };
+    params.downcast().unwrap()
+}
+
+// Returns the powers of `root`, in bit-reversed order.
+fn bit_reversed_powers<N: UnsignedInteger>(
+    modulus: &Modulus<N>,
+    root: ModQ<N>,
+    poly_size: PolynomialSize,
+) -> Vec<ModQ<N>> {
+    let n = poly_size.0;
+    let log_n = poly_size.log2().0;
+    let mut powers = Vec::with_capacity(n);
+    let mut power = ModQ::new(N::ONE);
+    for _ in 0..n {
+        powers.push(power);
+        power = modulus.mul(power, root);
+    }
+    (0..n)
+        .map(|k| {
+            let reversed = if log_n == 0 {
+                0
+            } else {
+                k.reverse_bits() >> (usize::BITS as usize - log_n)
+            };
+            powers[reversed]
+        })
+        .collect()
+}
+
+// Deterministic Miller-Rabin test, valid for odd integers smaller than 2^64.
+fn is_prime<N: UnsignedInteger + CastFrom<u64>>(candidate: N) -> bool {
+    if candidate & N::ONE == N::ZERO || candidate == N::ONE {
+        return false;
+    }
+    let bases = MILLER_RABIN_BASES.map(N::cast_from);
+    if let Some(base) = bases.iter().find(|b| candidate % **b == N::ZERO) {
+        return candidate == *base;
+    }
+    let modulus = Modulus::new(candidate);
+    let minus_one = ModQ::new(candidate - N::ONE);
+    let mut d = candidate - N::ONE;
+    let mut s = 0;
+    while d & N::ONE == N::ZERO {
+        d >>= 1;
+        s += 1;
+    }
+    bases.iter().all(|base| {
+        let mut x = modulus.pow(ModQ::new(*base), d);
+        if x == ModQ::new(N::ONE) || x == minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = modulus.mul(x, x);
+            if x == minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::backends::ntt::private::math::polynomial::{ModQPolynomial, NttPolynomial};
+    use crate::backends::ntt::private::math::transform::Ntt;
+    use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    use rand::Rng;
+
+    #[test]
+    fn test_is_prime() {
+        let primes: Vec<u64> = (1..200u64).filter(|a| is_prime(*a)).collect();
+        let expected: Vec<u64> = (1..200u64)
+            .filter(|a| *a > 1 && (2..*a).all(|d| a % d != 0))
+            .collect();
+        assert_eq!(primes, expected);
+        assert!(is_prime(4294828033u64));
+        assert!(is_prime(18446744073709436929u128));
+        assert!(!is_prime(4294828033u64 * 3));
+    }
+
+    #[test]
+    fn test_generated_moduli() {
+        for size in [1, 2, 128, 256, 512, 1024, 2048, 4096, 8192, 16384] {
+            let params_32 = NttParameters::<u64>::generate(PolynomialSize(size), 32);
+            let params_64 = NttParameters::<u128>::generate(PolynomialSize(size), 64);
+            assert!(params_32.is_valid());
+            assert!(params_64.is_valid());
+            assert_eq!(params_32.modulus().get() % (2 * size as u64), 1);
+            assert_eq!(params_64.modulus().get() % (2 * size as u128), 1);
+        }
+        // For the largest size of the former hard-coded tables, we find the same moduli.
+        assert_eq!(
+            NttParameters::<u64>::generate(PolynomialSize(4096), 32)
+                .modulus()
+                .get(),
+            4294828033
+        );
+        assert_eq!(
+            NttParameters::<u128>::generate(PolynomialSize(4096), 64)
+                .modulus()
+                .get(),
+            18446744073709436929
+        );
+    }
+
+    #[test]
+    fn test_cached_parameters() {
+        let params_1 = cached_parameters::<u64>(PolynomialSize(1024));
+        let params_2 = cached_parameters::<u64>(PolynomialSize(1024));
+        assert!(Arc::ptr_eq(&params_1, &params_2));
+        let params_3 = cached_parameters::<u128>(PolynomialSize(1024));
+        assert_eq!(params_3.polynomial_size(), PolynomialSize(1024));
+    }
+
+    // Multiplies a random polynomial by a monomial X^k in the NTT domain, and checks that the
+    // result is the negacyclic rotation of the input.
+    fn test_monomial_product<N>(size: usize)
+    where
+        N: UnsignedInteger
+            + CastFrom<u64>
+            + Send
+            + Sync
+            + rand::distributions::uniform::SampleUniform,
+    {
+        let poly_size = PolynomialSize(size);
+        let mut ntt = Ntt::<N>::new(poly_size);
+        let modulus = *ntt.modulus();
+        let q = modulus.get();
+        let mut rng = rand::thread_rng();
+
+        let mut poly = ModQPolynomial::allocate(ModQ::empty(), poly_size);
+        for coef in poly.coefficient_iter_mut() {
+            *coef = ModQ::new(rng.gen_range(N::ZERO, q));
+        }
+        let k = rng.gen_range(0, size);
+        let mut monomial = ModQPolynomial::allocate(ModQ::empty(), poly_size);
+        monomial.as_mut_tensor().set_element(k, ModQ::new(N::ONE));
+
+        let mut ntt_poly = NttPolynomial::allocate(ModQ::empty(), poly_size);
+        let mut ntt_monomial = NttPolynomial::allocate(ModQ::empty(), poly_size);
+        let mut ntt_product = NttPolynomial::allocate(ModQ::empty(), poly_size);
+        ntt.forward(&mut ntt_poly, &poly);
+        ntt.forward(&mut ntt_monomial, &monomial);
+        ntt_product.update_with_multiply_accumulate(&ntt_poly, &ntt_monomial, &modulus);
+        let mut product = ModQPolynomial::allocate(ModQ::empty(), poly_size);
+        ntt.backward(&mut product, &ntt_product);
+
+        let input: Vec<ModQ<N>> = poly.coefficient_iter().copied().collect();
+        for (i, coef) in product.as_tensor().iter().enumerate() {
+            let expected = if i >= k {
+                input[i - k]
+            } else {
+                modulus.neg(input[size + i - k])
+            };
+            assert_eq!(*coef, expected);
+        }
+    }
+
+    #[test]
+    fn test_monomial_product_32() {
+        for size in [1, 2, 128, 1024, 8192, 16384] {
+            test_monomial_product::<u64>(size);
+        }
+    }
+
+    #[test]
+    fn test_monomial_product_64() {
+        for size in [1, 2, 128, 1024, 8192, 16384] {
+            test_monomial_product::<u128>(size);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::backends::ntt::private::math::mod_q::ModQ;
+use crate::backends::ntt::private::math::mod_q::{ModQ, Modulus};
 use crate::commons::math::polynomial::Polynomial;
 use crate::commons::math::tensor::{
     ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
@@ -26,7 +26,7 @@ impl<N: UnsignedInteger> ModQPolynomial<Vec<ModQ<N>>> {
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
     /// use concrete_core::backends::ntt::private::math::polynomial::ModQPolynomial;
     /// use concrete_core::prelude::PolynomialSize;
-    /// let modq_poly = ModQPolynomial::allocate(ModQ::new(0, 257u64), PolynomialSize(128));
+    /// let modq_poly = ModQPolynomial::allocate(ModQ::new(0u64), PolynomialSize(128));
     /// assert_eq!(modq_poly.polynomial_size(), PolynomialSize(128));
     /// ```
     pub fn allocate(value: ModQ<N>, coef_count: PolynomialSize) -> Self {
@@ -66,7 +66,7 @@ impl<Cont> ModQPolynomial<Cont> {
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
     /// use concrete_core::backends::ntt::private::math::polynomial::ModQPolynomial;
     /// use concrete_core::prelude::PolynomialSize;
-    /// let modq_poly = ModQPolynomial::allocate(ModQ::new(0, 257u64), PolynomialSize(128));
+    /// let modq_poly = ModQPolynomial::allocate(ModQ::new(0u64), PolynomialSize(128));
     /// assert_eq!(modq_poly.polynomial_size(), PolynomialSize(128));
     /// ```
     pub fn polynomial_size(&self) -> PolynomialSize
@@ -88,9 +88,9 @@ impl<Cont> ModQPolynomial<Cont> {
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
     /// use concrete_core::backends::ntt::private::math::polynomial::ModQPolynomial;
     /// use concrete_core::prelude::PolynomialSize;
-    /// let modq_poly = ModQPolynomial::allocate(ModQ::new(0, 257u64), PolynomialSize(128));
+    /// let modq_poly = ModQPolynomial::allocate(ModQ::new(0u64), PolynomialSize(128));
     /// for coef in modq_poly.coefficient_iter() {
-    ///     assert_eq!(*coef, ModQ::new(0, 257u64));
+    ///     assert_eq!(*coef, ModQ::new(0u64));
     /// }
     /// assert_eq!(modq_poly.coefficient_iter().count(), 128);
     /// ```
@@ -114,9 +114,9 @@ impl<Cont> ModQPolynomial<Cont> {
     /// use concrete_core::backends::ntt::private::math::polynomial::ModQPolynomial;
     /// use concrete_core::commons::math::tensor::AsRefTensor;
     /// use concrete_core::prelude::PolynomialSize;
-    /// let mut modq_poly = ModQPolynomial::allocate(ModQ::new(0, 257u64), PolynomialSize(128));
+    /// let mut modq_poly = ModQPolynomial::allocate(ModQ::new(0u64), PolynomialSize(128));
     /// for mut coef in modq_poly.coefficient_iter_mut() {
-    ///     coef.set(259u64);
+    ///     coef.set(2u64);
     /// }
     /// assert!(modq_poly.as_tensor().iter().all(|a| a.get() == 2u64));
     /// assert_eq!(modq_poly.coefficient_iter_mut().count(), 128);
@@ -134,12 +134,12 @@ where
     N: UnsignedInteger,
     Cont: AsMutSlice<Element = ModQ<N>>,
 {
-    pub fn from_polynomial<InCont>(&mut self, poly: &Polynomial<InCont>)
+    pub fn from_polynomial<InCont>(&mut self, poly: &Polynomial<InCont>, modulus: &Modulus<N>)
     where
         InCont: AsRefSlice<Element = N>,
     {
         for (modq, n) in self.coefficient_iter_mut().zip(poly.coefficient_iter()) {
-            modq.set(*n);
+            *modq = modulus.reduce(*n);
         }
     }
 }
@@ -156,7 +156,7 @@ impl<N: UnsignedInteger> NttPolynomial<Vec<ModQ<N>>> {
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
     /// use concrete_core::backends::ntt::private::math::polynomial::NttPolynomial;
     /// use concrete_core::prelude::PolynomialSize;
-    /// let ntt_poly = NttPolynomial::allocate(ModQ::new(0, 257u64), PolynomialSize(128));
+    /// let ntt_poly = NttPolynomial::allocate(ModQ::new(0u64), PolynomialSize(128));
     /// assert_eq!(ntt_poly.polynomial_size(), PolynomialSize(128));
     /// ```
     pub fn allocate(value: ModQ<N>, coef_count: PolynomialSize) -> Self {
@@ -174,7 +174,7 @@ impl<Cont> NttPolynomial<Cont> {
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
     /// use concrete_core::backends::ntt::private::math::polynomial::NttPolynomial;
     /// use concrete_core::prelude::PolynomialSize;
-    /// let mut vec: Vec<ModQ<u64>> = vec![ModQ::new(0, 257u64); 128];
+    /// let mut vec: Vec<ModQ<u64>> = vec![ModQ::new(0u64); 128];
     /// let ntt_poly = NttPolynomial::from_container(vec.as_mut_slice());
     /// assert_eq!(ntt_poly.polynomial_size(), PolynomialSize(128));
     /// ```
@@ -194,7 +194,7 @@ impl<Cont> NttPolynomial<Cont> {
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
     /// use concrete_core::backends::ntt::private::math::polynomial::NttPolynomial;
     /// use concrete_core::prelude::PolynomialSize;
-    /// let ntt_poly = NttPolynomial::allocate(ModQ::new(0, 257u64), PolynomialSize(128));
+    /// let ntt_poly = NttPolynomial::allocate(ModQ::new(0u64), PolynomialSize(128));
     /// assert_eq!(ntt_poly.polynomial_size(), PolynomialSize(128));
     /// ```
     pub fn polynomial_size(&self) -> PolynomialSize
@@ -216,9 +216,9 @@ impl<Cont> NttPolynomial<Cont> {
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
     /// use concrete_core::backends::ntt::private::math::polynomial::NttPolynomial;
     /// use concrete_core::prelude::PolynomialSize;
-    /// let ntt_poly = NttPolynomial::allocate(ModQ::new(0, 257u64), PolynomialSize(128));
+    /// let ntt_poly = NttPolynomial::allocate(ModQ::new(0u64), PolynomialSize(128));
     /// for coef in ntt_poly.coefficient_iter() {
-    ///     assert_eq!(*coef, ModQ::new(0, 257u64));
+    ///     assert_eq!(*coef, ModQ::new(0u64));
     /// }
     /// assert_eq!(ntt_poly.coefficient_iter().count(), 128);
     /// ```
@@ -242,9 +242,9 @@ impl<Cont> NttPolynomial<Cont> {
     /// use concrete_core::backends::ntt::private::math::polynomial::NttPolynomial;
     /// use concrete_core::commons::math::tensor::AsRefTensor;
     /// use concrete_core::prelude::PolynomialSize;
-    /// let mut ntt_poly = NttPolynomial::allocate(ModQ::new(0, 257u64), PolynomialSize(128));
+    /// let mut ntt_poly = NttPolynomial::allocate(ModQ::new(0u64), PolynomialSize(128));
     /// for mut coef in ntt_poly.coefficient_iter_mut() {
-    ///     coef.set(259u64);
+    ///     coef.set(2u64);
     /// }
     /// assert!(ntt_poly.as_tensor().iter().all(|a| a.get() == 2u64));
     /// assert_eq!(ntt_poly.coefficient_iter_mut().count(), 128);
@@ -264,20 +264,21 @@ impl<Cont> NttPolynomial<Cont> {
     /// # Example
     ///
     /// ```rust
-    /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
+    /// use concrete_core::backends::ntt::private::math::mod_q::{ModQ, Modulus};
     /// use concrete_core::backends::ntt::private::math::polynomial::NttPolynomial;
     /// use concrete_core::prelude::PolynomialSize;
-    /// let q = 257u64;
-    /// let mut npoly1 = NttPolynomial::allocate(ModQ::new(250, q), PolynomialSize(128));
-    /// let npoly2 = NttPolynomial::allocate(ModQ::new(3, q), PolynomialSize(128));
-    /// let npoly3 = NttPolynomial::allocate(ModQ::new(5, q), PolynomialSize(128));
-    /// npoly1.update_with_multiply_accumulate(&npoly2, &npoly3);
+    /// let modulus = Modulus::new(257u64);
+    /// let mut npoly1 = NttPolynomial::allocate(ModQ::new(250), PolynomialSize(128));
+    /// let npoly2 = NttPolynomial::allocate(ModQ::new(3), PolynomialSize(128));
+    /// let npoly3 = NttPolynomial::allocate(ModQ::new(5), PolynomialSize(128));
+    /// npoly1.update_with_multiply_accumulate(&npoly2, &npoly3, &modulus);
     /// assert!(npoly1.coefficient_iter().all(|a| a.get() == 8u64));
     /// ```
     pub fn update_with_multiply_accumulate<PolyCont1, PolyCont2, N>(
         &mut self,
         poly_1: &NttPolynomial<PolyCont1>,
         poly_2: &NttPolynomial<PolyCont2>,
+        modulus: &Modulus<N>,
     ) where
         Self: AsMutTensor<Element = ModQ<N>>,
         NttPolynomial<PolyCont1>: AsRefTensor<Element = ModQ<N>>,
@@ -290,7 +291,7 @@ impl<Cont> NttPolynomial<Cont> {
             poly_1.as_tensor().iter(),
             poly_2.as_tensor().iter()
         ) {
-            *res += *coef_1 * *coef_2;
+            *res = modulus.add(*res, modulus.mul(*coef_1, *coef_2));
         }
     }
 }
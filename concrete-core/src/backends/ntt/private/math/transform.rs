@@ -1,13 +1,13 @@
 use crate::commons::numeric::{CastFrom, CastInto, UnsignedInteger};
 
-use crate::backends::ntt::private::math::mod_q::ModQ;
+use crate::backends::ntt::private::math::mod_q::{ModQ, Modulus};
+use crate::backends::ntt::private::math::params::{cached_parameters, NttParameters};
 use crate::backends::ntt::private::math::polynomial::{ModQPolynomial, NttPolynomial};
 use crate::commons::math::polynomial::Polynomial;
-use crate::commons::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::commons::math::tensor::{AsMutSlice, AsRefSlice};
 use crate::commons::math::torus::UnsignedTorus;
 use crate::prelude::{PolynomialSize, PolynomialSizeLog};
-
-use super::ALLOWED_POLY_SIZE;
+use std::sync::Arc;
 
 /// A fast NTT transformer.
 ///
@@ -16,60 +16,62 @@ use super::ALLOWED_POLY_SIZE;
 #[derive(Clone)]
 pub struct Ntt<N: UnsignedInteger> {
     log_size: PolynomialSizeLog,
-    roots: Vec<ModQ<N>>,
-    roots_inv: Vec<ModQ<N>>,
-    n_inv: ModQ<N>,
-    buffer: ModQPolynomial<Vec<ModQ<N>>>,
+    params: Arc<NttParameters<N>>,
+    // The values in the buffer are only partially reduced during the transforms, and lie in
+    // [0, 2q).
+    buffer: Vec<N>,
+}
+
+impl<N: UnsignedInteger + CastFrom<u64> + Send + Sync> Ntt<N> {
+    /// Generates a new transformer for polynomials of a given size.
+    ///
+    /// The modulus is a prime of half the bits of `N`, and the parameters of the transform are
+    /// generated on the first call for a given size, and cached afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::backends::ntt::private::math::transform::Ntt;
+    /// use concrete_core::prelude::PolynomialSize;
+    /// let ntt: Ntt<u64> = Ntt::new(PolynomialSize(8192));
+    /// assert_eq!(ntt.polynomial_size(), PolynomialSize(8192));
+    /// ```
+    pub fn new(poly_size: PolynomialSize) -> Ntt<N> {
+        Self::from_parameters(cached_parameters(poly_size))
+    }
 }
 
 impl<N: UnsignedInteger> Ntt<N> {
-    /// Generates a new transformer for polynomials with given size and parameters.
+    /// Generates a new transformer from a given set of parameters.
     ///
     /// # Example
     ///
     /// ```
-    /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_128::{
-    ///     INVROOTS_32_128, MOD_32_128, NINV_32_128, ROOTS_32_128,
-    /// };
+    /// use concrete_core::backends::ntt::private::math::params::NttParameters;
     /// use concrete_core::backends::ntt::private::math::transform::Ntt;
-    /// use concrete_core::prelude::{PolynomialSize, PolynomialSizeLog};
-    /// let poly_size = PolynomialSize(128);
-    /// let log_size = PolynomialSizeLog(7);
-    /// let q: u64 = MOD_32_128;
-    /// let roots: Vec<ModQ<u64>> = ROOTS_32_128
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let roots_inv: Vec<ModQ<u64>> = INVROOTS_32_128
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let n_inv = ModQ::new(NINV_32_128, q);
-    /// let ntt = Ntt::new(poly_size, log_size, roots, roots_inv, n_inv);
+    /// use concrete_core::prelude::PolynomialSize;
+    /// use std::sync::Arc;
+    /// let params = NttParameters::<u64>::generate(PolynomialSize(1024), 30);
+    /// let ntt = Ntt::from_parameters(Arc::new(params));
+    /// assert!(ntt.modulus().get() < 1 << 30);
     /// ```
-    pub fn new(
-        poly_size: PolynomialSize,
-        log_size: PolynomialSizeLog,
-        roots: Vec<ModQ<N>>,
-        roots_inv: Vec<ModQ<N>>,
-        n_inv: ModQ<N>,
-    ) -> Ntt<N> {
-        let modulus = n_inv.get_mod();
-        let buffer = ModQPolynomial::allocate(<ModQ<N>>::new(N::ZERO, modulus), poly_size);
+    pub fn from_parameters(params: Arc<NttParameters<N>>) -> Ntt<N> {
+        let poly_size = params.polynomial_size();
         Ntt {
-            log_size,
-            roots,
-            roots_inv,
-            n_inv,
-            buffer,
+            log_size: poly_size.log2(),
+            buffer: vec![N::ZERO; poly_size.0],
+            params,
         }
     }
 
-    pub fn get_zero_mod_q(&self) -> ModQ<N> {
-        ModQ::new(N::ZERO, self.n_inv.get_mod())
+    /// Returns the modulus used by the transformer.
+    pub fn modulus(&self) -> &Modulus<N> {
+        self.params.modulus()
+    }
+
+    /// Returns the size of the polynomials handled by the transformer.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.params.polynomial_size()
     }
 
     /// First applies a modulus switch to the internal modulus to the `in_poly` polynomial,
@@ -80,33 +82,17 @@ impl<N: UnsignedInteger> Ntt<N> {
     ///
     /// ```
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_256::{
-    ///     INVROOTS_32_256, MOD_32_256, NINV_32_256, ROOTS_32_256,
-    /// };
     /// use concrete_core::backends::ntt::private::math::polynomial::NttPolynomial;
     /// use concrete_core::backends::ntt::private::math::transform::Ntt;
     /// use concrete_core::commons::math::polynomial::Polynomial;
     /// use concrete_core::commons::math::random::RandomGenerator;
     /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
-    /// use concrete_core::prelude::{PolynomialSize, PolynomialSizeLog};
+    /// use concrete_core::prelude::PolynomialSize;
     /// use concrete_csprng::generators::SoftwareRandomGenerator;
     /// use concrete_csprng::seeders::Seed;
     /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
     /// let poly_size = PolynomialSize(256);
-    /// let log_size = PolynomialSizeLog(8);
-    /// let q: u64 = MOD_32_256;
-    /// let roots: Vec<ModQ<u64>> = ROOTS_32_256
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let roots_inv: Vec<ModQ<u64>> = INVROOTS_32_256
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let n_inv = ModQ::new(NINV_32_256, q);
-    /// let mut ntt = Ntt::new(poly_size, log_size, roots, roots_inv, n_inv);
+    /// let mut ntt: Ntt<u64> = Ntt::new(poly_size);
     /// let mut ntt_poly = NttPolynomial::allocate(ModQ::empty(), PolynomialSize(256));
     /// let mut poly = Polynomial::allocate(0u32, PolynomialSize(256));
     /// generator.fill_tensor_with_random_uniform(&mut poly);
@@ -125,20 +111,13 @@ impl<N: UnsignedInteger> Ntt<N> {
         OutCont: AsMutSlice<Element = ModQ<N>>,
         InCoef: UnsignedTorus + CastInto<N>,
     {
-        debug_assert!(
-            ALLOWED_POLY_SIZE.contains(&out_poly.polynomial_size().0),
-            "The size chosen is not valid ({}). Check ALLOWED_POLY_SIZE.",
-            out_poly.polynomial_size().0
-        );
+        assert_eq!(out_poly.polynomial_size(), self.polynomial_size());
         assert_eq!(out_poly.polynomial_size().0, in_poly.polynomial_size().0);
-        let buffer = &mut self.buffer;
+        let modulus = self.params.modulus();
 
         // copy in_poly into buffer while applying modulus switch
-        for (buf, pol) in buffer
-            .coefficient_iter_mut()
-            .zip(in_poly.coefficient_iter())
-        {
-            buf.mod_switch_into(*pol);
+        for (buf, pol) in self.buffer.iter_mut().zip(in_poly.coefficient_iter()) {
+            *buf = modulus.mod_switch_into(*pol).get();
         }
 
         self.forward_from_buffer(out_poly);
@@ -151,40 +130,24 @@ impl<N: UnsignedInteger> Ntt<N> {
     ///
     /// ```
     /// use concrete_core::backends::ntt::private::math::mod_q::ModQ;
-    /// use concrete_core::backends::ntt::private::math::params::params_32_256::{
-    ///     INVROOTS_32_256, MOD_32_256, NINV_32_256, ROOTS_32_256,
-    /// };
     /// use concrete_core::backends::ntt::private::math::polynomial::{ModQPolynomial, NttPolynomial};
     /// use concrete_core::backends::ntt::private::math::transform::Ntt;
     /// use concrete_core::commons::math::polynomial::Polynomial;
     /// use concrete_core::commons::math::random::RandomGenerator;
     /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
-    /// use concrete_core::prelude::{PolynomialSize, PolynomialSizeLog};
+    /// use concrete_core::prelude::PolynomialSize;
     /// use concrete_csprng::generators::SoftwareRandomGenerator;
     /// use concrete_csprng::seeders::Seed;
     /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
     /// let poly_size = PolynomialSize(256);
-    /// let log_size = PolynomialSizeLog(8);
-    /// let q: u64 = MOD_32_256;
-    /// let roots: Vec<ModQ<u64>> = ROOTS_32_256
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let roots_inv: Vec<ModQ<u64>> = INVROOTS_32_256
-    ///     .to_vec()
-    ///     .iter()
-    ///     .map(|a| <ModQ<u64>>::new(*a as u64, q))
-    ///     .collect();
-    /// let n_inv = ModQ::new(NINV_32_256, q);
-    /// let mut ntt = Ntt::new(poly_size, log_size, roots, roots_inv, n_inv);
+    /// let mut ntt: Ntt<u64> = Ntt::new(poly_size);
     /// let mut ntt_poly = NttPolynomial::allocate(ModQ::empty(), PolynomialSize(256));
     /// let mut poly = Polynomial::allocate(0u64, PolynomialSize(256));
     /// generator.fill_tensor_with_random_uniform(&mut poly);
-    /// let mut modq_poly = ModQPolynomial::allocate(ntt.get_zero_mod_q(), PolynomialSize(256));
-    /// modq_poly.from_polynomial(&poly);
+    /// let mut modq_poly = ModQPolynomial::allocate(ModQ::empty(), PolynomialSize(256));
+    /// modq_poly.from_polynomial(&poly, ntt.modulus());
     /// ntt.forward(&mut ntt_poly, &modq_poly);
-    /// let mut out = ModQPolynomial::allocate(ntt.get_zero_mod_q(), PolynomialSize(256));
+    /// let mut out = ModQPolynomial::allocate(ModQ::empty(), PolynomialSize(256));
     /// ntt.backward(&mut out, &mut ntt_poly);
     ///
     /// out.as_tensor()
@@ -200,20 +163,12 @@ impl<N: UnsignedInteger> Ntt<N> {
         InCont: AsRefSlice<Element = ModQ<N>>,
         OutCont: AsMutSlice<Element = ModQ<N>>,
     {
-        debug_assert!(
-            ALLOWED_POLY_SIZE.contains(&out_poly.polynomial_size().0),
-            "The size chosen is not valid ({}). Check ALLOWED_POLY_SIZE.",
-            out_poly.polynomial_size().0
-        );
+        assert_eq!(out_poly.polynomial_size(), self.polynomial_size());
         assert_eq!(out_poly.polynomial_size().0, in_poly.polynomial_size().0);
-        let buffer = &mut self.buffer;
 
         // copy in_poly into buffer
-        for (buf, pol) in buffer
-            .coefficient_iter_mut()
-            .zip(in_poly.coefficient_iter())
-        {
-            *buf = *pol;
+        for (buf, pol) in self.buffer.iter_mut().zip(in_poly.coefficient_iter()) {
+            *buf = pol.get();
         }
 
         self.forward_from_buffer(out_poly);
@@ -225,37 +180,48 @@ impl<N: UnsignedInteger> Ntt<N> {
         OutCont: AsMutSlice<Element = ModQ<N>>,
     {
         let buffer = &mut self.buffer;
-        let roots = &self.roots;
+        let roots = self.params.roots();
+        let modulus = self.params.modulus();
         let log_size = self.log_size.0;
+        let q = modulus.get();
+        let two_q = q + q;
 
-        // We perform the forward ntt
+        // We perform the forward ntt, with Harvey's lazy butterflies: the values stay in [0, 2q)
+        // between the stages, and are only fully reduced at the end.
         for scale in 0..log_size {
             let t: usize = 1 << (log_size - 1 - scale);
             let m: usize = 1 << scale;
 
-            for i in 0..m {
-                let j1: usize = 2 * i * t;
-                let j2: usize = j1 + t - 1;
+            for (i, chunk) in buffer.chunks_exact_mut(2 * t).enumerate() {
                 let s = &roots[m + i];
+                let (lo, hi) = chunk.split_at_mut(t);
 
-                for j in j1..(j2 + 1) {
-                    let u = *buffer.as_tensor().get_element(j);
-                    let v = *buffer.as_tensor().get_element(j + t) * (*s);
-                    buffer.as_mut_tensor().set_element(j, u + v);
-                    buffer.as_mut_tensor().set_element(j + t, u - v);
+                for (x, y) in lo.iter_mut().zip(hi.iter_mut()) {
+                    let u = *x;
+                    let v = modulus.mul_shoup_lazy(Modulus::reduce_once(*y, q), s);
+                    *x = Modulus::reduce_once(u + v, two_q);
+                    *y = Modulus::reduce_once(u + two_q - v, two_q);
                 }
             }
         }
 
         // copy result from buffer to out_poly
-        for (out_coef, buf_coef) in out_poly
-            .coefficient_iter_mut()
-            .zip(buffer.coefficient_iter())
-        {
-            *out_coef = *buf_coef;
+        for (out_coef, buf_coef) in out_poly.coefficient_iter_mut().zip(buffer.iter()) {
+            *out_coef = ModQ::new(Modulus::reduce_once(*buf_coef, q));
         }
     }
 
+    /// Multiplies a value of the buffer by n^(-1), and fully reduces it.
+    #[inline]
+    fn scale_buffer_value(&self, value: N) -> ModQ<N> {
+        let modulus = self.params.modulus();
+        let q = modulus.get();
+        ModQ::new(Modulus::reduce_once(
+            modulus.mul_shoup_lazy(Modulus::reduce_once(value, q), self.params.n_inv()),
+            q,
+        ))
+    }
+
     /// Performs the backward NTT transform of the `in_poly` polynomial, viewed as a
     /// polynomial of integer coefficients mod the internal modulus and then applies a modulus
     /// switch to `Coef::MAX`.
@@ -274,13 +240,11 @@ impl<N: UnsignedInteger> Ntt<N> {
 
         self.backwards_into_buffer(in_poly);
 
-        let buffer = &mut self.buffer;
         // copy result from buffer to out_poly while applying the modulus switch
-        for (out_coef, buf_coef) in out_poly
-            .coefficient_iter_mut()
-            .zip(buffer.coefficient_iter())
-        {
-            *out_coef = (*buf_coef * self.n_inv).mod_switch_from();
+        for (out_coef, buf_coef) in out_poly.coefficient_iter_mut().zip(self.buffer.iter()) {
+            *out_coef = self
+                .modulus()
+                .mod_switch_from(self.scale_buffer_value(*buf_coef));
         }
     }
 
@@ -297,14 +261,12 @@ impl<N: UnsignedInteger> Ntt<N> {
 
         self.backwards_into_buffer(in_poly);
 
-        let buffer = &mut self.buffer;
         // add result from buffer to out_poly while applying the modulus switch
-        for (out_coef, buf_coef) in out_poly
-            .coefficient_iter_mut()
-            .zip(buffer.coefficient_iter())
-        {
-            // *out_coef += (*buf_coef * self.n_inv).mod_switch_from();
-            *out_coef = out_coef.wrapping_add((*buf_coef * self.n_inv).mod_switch_from());
+        for (out_coef, buf_coef) in out_poly.coefficient_iter_mut().zip(self.buffer.iter()) {
+            let switched: OutCoef = self
+                .modulus()
+                .mod_switch_from(self.scale_buffer_value(*buf_coef));
+            *out_coef = out_coef.wrapping_add(switched);
         }
     }
 
@@ -321,56 +283,51 @@ impl<N: UnsignedInteger> Ntt<N> {
         OutCont: AsMutSlice<Element = ModQ<N>>,
     {
         assert_eq!(out_poly.polynomial_size().0, in_poly.polynomial_size().0);
-        // let params = self.params.get_mut(&in_poly.polynomial_size()).unwrap();
 
         self.backwards_into_buffer(in_poly);
 
-        let buffer = &mut self.buffer;
-
         // copy result from buffer to out_poly
-        for (out_coef, buf_coef) in out_poly
-            .coefficient_iter_mut()
-            .zip(buffer.coefficient_iter())
-        {
-            *out_coef = *buf_coef * self.n_inv;
+        for (out_coef, buf_coef) in out_poly.coefficient_iter_mut().zip(self.buffer.iter()) {
+            *out_coef = self.scale_buffer_value(*buf_coef);
         }
     }
 
     /// Copies `in_poly` to the buffer and performs the inverse NTT on it.
+    ///
+    /// The values left in the buffer lie in [0, 2q), and still have to be multiplied by n^(-1).
     fn backwards_into_buffer<InCont>(&mut self, in_poly: &NttPolynomial<InCont>)
     where
         InCont: AsRefSlice<Element = ModQ<N>>,
     {
         let buffer = &mut self.buffer;
-        let roots_inv = &self.roots_inv;
+        let roots_inv = self.params.roots_inv();
+        let modulus = self.params.modulus();
         let log_size = self.log_size.0;
+        let q = modulus.get();
+        let two_q = q + q;
 
-        for (buf, pol) in buffer
-            .coefficient_iter_mut()
-            .zip(in_poly.coefficient_iter())
-        {
-            *buf = *pol;
+        for (buf, pol) in buffer.iter_mut().zip(in_poly.coefficient_iter()) {
+            *buf = pol.get();
         }
 
-        // We perform the backward ntt
+        // We perform the backward ntt, with lazy Gentleman-Sande butterflies: the values stay in
+        // [0, 2q) between the stages.
         for scale in 0..log_size {
             let h: usize = 1 << (log_size - 1 - scale);
             let t: usize = 1 << scale;
 
-            let mut j1: usize = 0;
-
-            for i in 0..h {
-                let j2: usize = j1 + t - 1;
+            for (i, chunk) in buffer.chunks_exact_mut(2 * t).enumerate() {
                 let s = &roots_inv[h + i];
-
-                for j in j1..(j2 + 1) {
-                    let u = *buffer.as_tensor().get_element(j);
-                    let v = *buffer.as_tensor().get_element(j + t);
-                    buffer.as_mut_tensor().set_element(j, u + v);
-                    buffer.as_mut_tensor().set_element(j + t, (u - v) * (*s));
+                let (lo, hi) = chunk.split_at_mut(t);
+
+                for (x, y) in lo.iter_mut().zip(hi.iter_mut()) {
+                    let u = *x;
+                    let v = *y;
+                    *x = Modulus::reduce_once(u + v, two_q);
+                    // The operand of the multiplication must be fully reduced.
+                    let diff = Modulus::reduce_once(Modulus::reduce_once(u + two_q - v, two_q), q);
+                    *y = modulus.mul_shoup_lazy(diff, s);
                 }
-
-                j1 += 2 * t;
             }
         }
     }
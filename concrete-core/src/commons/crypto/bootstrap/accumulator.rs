@@ -0,0 +1,131 @@
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::glwe::GlweCiphertext;
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::CastFrom;
+use crate::prelude::{GlweSize, MessageBitsCount, PaddingBitsCount, PolynomialSize};
+
+/// Returns the trivial GLWE encryption of the lookup table of `f`, to be used as an accumulator in
+/// a programmable bootstrap.
+///
+/// The messages are assumed to be encoded on the `message_bits` bits below the `padding_bits`
+/// most significant bits of the plaintexts, and the outputs of `f` are encoded the same way.
+///
+/// The positive half of the torus is split in one box of coefficients per possible value of the
+/// message and of the padding bits below the first one. Each box is filled with the encoding of
+/// the image of its message, and the lookup table is rotated by half a box, so that noisy inputs
+/// land in the middle of the box of their message. The boxes corresponding to a non-zero padding
+/// bit are filled with the image of the message bits only.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::commons::crypto::bootstrap::create_accumulator;
+/// use concrete_core::prelude::{GlweSize, MessageBitsCount, PaddingBitsCount, PolynomialSize};
+/// let accumulator = create_accumulator::<u64, _>(
+///     |m| (m + 1) % 4,
+///     MessageBitsCount(2),
+///     PaddingBitsCount(1),
+///     GlweSize(2),
+///     PolynomialSize(16),
+/// );
+/// let body = accumulator.get_body().as_polynomial().coefficient_iter().copied().collect::<Vec<_>>();
+/// // Each box holds 4 coefficients, and the table is rotated by half a box.
+/// assert_eq!(body[0], 1 << 61);
+/// assert_eq!(body[2], 2 << 61);
+/// assert_eq!(body[14], (1_u64 << 61).wrapping_neg());
+/// ```
+pub fn create_accumulator<Scalar, F>(
+    f: F,
+    message_bits: MessageBitsCount,
+    padding_bits: PaddingBitsCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> GlweCiphertext<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus + CastFrom<usize>,
+    F: Fn(Scalar) -> Scalar,
+{
+    create_multi_function_accumulator(
+        &[&f],
+        message_bits,
+        padding_bits,
+        glwe_size,
+        polynomial_size,
+    )
+}
+
+/// Returns the trivial GLWE encryption of the lookup tables of the `functions`, interleaved in a
+/// single accumulator to be used in a multi-value bootstrap.
+///
+/// The number of functions `k` must be a power of two. The lookup tables are built as in
+/// [`create_accumulator`], except that the coefficients of index `j` modulo `k` of every box are
+/// filled with the image of the `j`-th function. Since the lookup table is rotated by half a box,
+/// the boxes must hold at least `2k` coefficients when `k > 1` for the interleaving to be
+/// preserved.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::commons::crypto::bootstrap::create_multi_function_accumulator;
+/// use concrete_core::prelude::{GlweSize, MessageBitsCount, PaddingBitsCount, PolynomialSize};
+/// let accumulator = create_multi_function_accumulator::<u64>(
+///     &[&|m: u64| m, &|m: u64| (m + 1) % 4],
+///     MessageBitsCount(2),
+///     PaddingBitsCount(1),
+///     GlweSize(2),
+///     PolynomialSize(16),
+/// );
+/// let body = accumulator.get_body().as_polynomial().coefficient_iter().copied().collect::<Vec<_>>();
+/// // Each box holds 4 coefficients, alternating between the two lookup tables.
+/// assert_eq!(body[0], 0);
+/// assert_eq!(body[1], 1 << 61);
+/// assert_eq!(body[2], 1 << 61);
+/// assert_eq!(body[3], 2 << 61);
+/// ```
+pub fn create_multi_function_accumulator<Scalar>(
+    functions: &[&dyn Fn(Scalar) -> Scalar],
+    message_bits: MessageBitsCount,
+    padding_bits: PaddingBitsCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> GlweCiphertext<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus + CastFrom<usize>,
+{
+    debug_assert!(padding_bits.0 >= 1);
+    debug_assert!(message_bits.0 + padding_bits.0 <= Scalar::BITS);
+    debug_assert!(functions.len().is_power_of_two());
+
+    let function_count = functions.len();
+    let delta_log = Scalar::BITS - message_bits.0 - padding_bits.0;
+    let message_modulus = 1 << message_bits.0;
+    let box_count = 1 << (message_bits.0 + padding_bits.0 - 1);
+    debug_assert!(polynomial_size.0 >= box_count);
+    let box_size = polynomial_size.0 / box_count;
+
+    let images: Vec<Vec<Scalar>> = functions
+        .iter()
+        .map(|f| {
+            (0..message_modulus)
+                .map(|message| f(Scalar::cast_from(message)) << delta_log)
+                .collect()
+        })
+        .collect();
+    let mut lut: Vec<Scalar> = (0..polynomial_size.0)
+        .map(|index| {
+            images[index % function_count]
+                [((index * box_count) / polynomial_size.0) % message_modulus]
+        })
+        .collect();
+
+    // We rotate the lookup table by -box_size/2, in the negacyclic ring. The rotation must be a
+    // multiple of the number of functions, to keep each lookup table on its own coefficients.
+    let half_box_size = box_size / 2;
+    debug_assert_eq!(half_box_size % function_count, 0);
+    for coefficient in lut[..half_box_size].iter_mut() {
+        *coefficient = coefficient.wrapping_neg();
+    }
+    lut.rotate_left(half_box_size);
+
+    GlweCiphertext::new_trivial_encryption(glwe_size, &PlaintextList::from_container(lut))
+}
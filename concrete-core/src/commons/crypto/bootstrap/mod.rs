@@ -3,9 +3,11 @@
 //! The bootstrapping operation allows to reduce the level of noise in an LWE ciphertext, while
 //! evaluating an univariate function.
 
+mod accumulator;
 mod seeded_standard;
 mod standard;
 
+pub use accumulator::{create_accumulator, create_multi_function_accumulator};
 pub use seeded_standard::StandardSeededBootstrapKey;
 pub use standard::StandardBootstrapKey;
 
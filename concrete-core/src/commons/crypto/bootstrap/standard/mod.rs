@@ -14,7 +14,7 @@ use crate::commons::numeric::Numeric;
 use crate::commons::utils::{zip, zip_args};
 use crate::prelude::{
     BinaryKeyKind, DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweSize,
-    LweDimension, PolynomialSize,
+    KeyKind, LweDimension, PolynomialSize, TernaryKeyKind,
 };
 #[cfg(feature = "__commons_parallel")]
 use rayon::{iter::IndexedParallelIterator, prelude::*};
@@ -207,16 +207,17 @@ impl<Cont> StandardBootstrapKey<Cont> {
     ///     &mut encryption_generator,
     /// );
     /// ```
-    pub fn fill_with_new_key<LweCont, RlweCont, Scalar, Gen>(
+    pub fn fill_with_new_key<GlweKind, LweCont, RlweCont, Scalar, Gen>(
         &mut self,
         lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
-        glwe_secret_key: &GlweSecretKey<BinaryKeyKind, RlweCont>,
+        glwe_secret_key: &GlweSecretKey<GlweKind, RlweCont>,
         noise_parameters: impl DispersionParameter,
         generator: &mut EncryptionRandomGenerator<Gen>,
     ) where
         Self: AsMutTensor<Element = Scalar>,
+        GlweKind: KeyKind,
         LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
-        GlweSecretKey<BinaryKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<GlweKind, RlweCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
         Gen: ByteRandomGenerator,
     {
@@ -248,6 +249,102 @@ impl<Cont> StandardBootstrapKey<Cont> {
         }
     }
 
+    /// Generate a new bootstrap key for a ternary input key from the input parameters, and fills
+    /// the current container with it.
+    ///
+    /// Each coefficient $s\_i$ of the ternary LWE secret key is encrypted in two consecutive GGSW
+    /// ciphertexts, the first one encrypting $\[s\_i = 1\]$ and the second one encrypting
+    /// $\[s\_i = -1\]$. The key must then be allocated with twice the size of the LWE secret key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::bootstrap::StandardBootstrapKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    ///
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(9));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let mut bsk = StandardBootstrapKey::allocate(
+    ///     9u32,
+    ///     glwe_dim.to_glwe_size(),
+    ///     poly_size,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     LweDimension(2 * lwe_dim.0),
+    /// );
+    /// let lwe_sk = LweSecretKey::generate_ternary(lwe_dim, &mut secret_generator);
+    /// let glwe_sk = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    /// bsk.fill_with_new_ternary_key(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    /// assert_eq!(bsk.ggsw_iter().count(), 8);
+    /// ```
+    pub fn fill_with_new_ternary_key<GlweKind, LweCont, RlweCont, Scalar, Gen>(
+        &mut self,
+        lwe_secret_key: &LweSecretKey<TernaryKeyKind, LweCont>,
+        glwe_secret_key: &GlweSecretKey<GlweKind, RlweCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweKind: KeyKind,
+        LweSecretKey<TernaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<GlweKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.key_size().0 => 2 * lwe_secret_key.key_size().0);
+        self.as_mut_tensor()
+            .fill_with_element(<Scalar as Numeric>::ZERO);
+
+        let gen_iter = generator
+            .fork_bsk_to_ggsw::<Scalar>(
+                self.key_size(),
+                self.decomp_level,
+                glwe_secret_key.key_size().to_glwe_size(),
+                self.poly_size,
+            )
+            .unwrap();
+
+        // A ternary coefficient equal to -1 is stored as `Scalar::MAX`.
+        let encoded_iter = lwe_secret_key.as_tensor().iter().flat_map(|sk_scalar| {
+            let (plus, minus) = if *sk_scalar == Scalar::ONE {
+                (Scalar::ONE, Scalar::ZERO)
+            } else if *sk_scalar == Scalar::MAX {
+                (Scalar::ZERO, Scalar::ONE)
+            } else {
+                (Scalar::ZERO, Scalar::ZERO)
+            };
+            [Plaintext(plus), Plaintext(minus)]
+        });
+
+        for zip_args!(mut rgsw, encoded, mut generator) in
+            zip!(self.ggsw_iter_mut(), encoded_iter, gen_iter)
+        {
+            glwe_secret_key.encrypt_constant_ggsw(
+                &mut rgsw,
+                &encoded,
+                noise_parameters,
+                &mut generator,
+            );
+        }
+    }
+
     /// Generate a new bootstrap key from the input parameters, and fills the current container
     /// with it, using all the available threads.
     ///
@@ -296,16 +393,17 @@ impl<Cont> StandardBootstrapKey<Cont> {
     /// );
     /// ```
     #[cfg(feature = "__commons_parallel")]
-    pub fn par_fill_with_new_key<LweCont, RlweCont, Scalar, Gen>(
+    pub fn par_fill_with_new_key<GlweKind, LweCont, RlweCont, Scalar, Gen>(
         &mut self,
         lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
-        glwe_secret_key: &GlweSecretKey<BinaryKeyKind, RlweCont>,
+        glwe_secret_key: &GlweSecretKey<GlweKind, RlweCont>,
         noise_parameters: impl DispersionParameter + Sync + Send,
         generator: &mut EncryptionRandomGenerator<Gen>,
     ) where
         Self: AsMutTensor<Element = Scalar>,
+        GlweKind: KeyKind,
         LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
-        GlweSecretKey<BinaryKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<GlweKind, RlweCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus + Sync + Send,
         RlweCont: Sync,
         Gen: ParallelByteRandomGenerator,
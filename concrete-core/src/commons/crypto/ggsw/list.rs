@@ -0,0 +1,268 @@
+use super::StandardGgswCiphertext;
+use crate::commons::math::tensor::{
+    ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::prelude::{
+    CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A list of ciphertexts encoded with the GGSW scheme.
+///
+/// All the ciphertexts of the list share the same polynomial size, GLWE size and decomposition
+/// parameters, and are stored contiguously in memory.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GgswList<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    pub(crate) poly_size: PolynomialSize,
+    pub(crate) rlwe_size: GlweSize,
+    pub(crate) decomp_level: DecompositionLevelCount,
+    pub(crate) decomp_base_log: DecompositionBaseLog,
+}
+
+tensor_traits!(GgswList);
+
+impl<Scalar> GgswList<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates storage for an owned [`GgswList`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::ggsw::GgswList;
+    /// use concrete_core::prelude::{
+    ///     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweSize,
+    ///     PolynomialSize,
+    /// };
+    /// let list = GgswList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     CiphertextCount(5),
+    /// );
+    /// assert_eq!(list.ciphertext_count(), CiphertextCount(5));
+    /// assert_eq!(list.polynomial_size(), PolynomialSize(10));
+    /// assert_eq!(list.glwe_size(), GlweSize(7));
+    /// assert_eq!(list.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(list.decomposition_base_log(), DecompositionBaseLog(4));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        rlwe_size: GlweSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        ciphertext_count: CiphertextCount,
+    ) -> Self {
+        GgswList {
+            tensor: Tensor::from_container(vec![
+                value;
+                ciphertext_count.0
+                    * decomp_level.0
+                    * rlwe_size.0
+                    * rlwe_size.0
+                    * poly_size.0
+            ]),
+            poly_size,
+            rlwe_size,
+            decomp_level,
+            decomp_base_log,
+        }
+    }
+}
+
+impl<Cont> GgswList<Cont> {
+    /// Creates a list from a container of values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::ggsw::GgswList;
+    /// use concrete_core::prelude::{
+    ///     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweSize,
+    ///     PolynomialSize,
+    /// };
+    /// let list = GgswList::from_container(
+    ///     vec![0 as u8; 10 * 7 * 7 * 3 * 5],
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(list.ciphertext_count(), CiphertextCount(5));
+    /// assert_eq!(list.glwe_size(), GlweSize(7));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        poly_size: PolynomialSize,
+        rlwe_size: GlweSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => poly_size.0, rlwe_size.0 * rlwe_size.0, decomp_level.0);
+        GgswList {
+            tensor,
+            poly_size,
+            rlwe_size,
+            decomp_level,
+            decomp_base_log,
+        }
+    }
+
+    /// Consumes the list and returns its container.
+    pub fn into_container(self) -> Cont {
+        self.tensor.into_container()
+    }
+
+    /// Returns the number of ciphertexts in the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::ggsw::GgswList;
+    /// use concrete_core::prelude::{
+    ///     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweSize,
+    ///     PolynomialSize,
+    /// };
+    /// let list = GgswList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     CiphertextCount(5),
+    /// );
+    /// assert_eq!(list.ciphertext_count(), CiphertextCount(5));
+    /// ```
+    pub fn ciphertext_count(&self) -> CiphertextCount
+    where
+        Self: AsRefTensor,
+    {
+        CiphertextCount(self.as_tensor().len() / self.ciphertext_size())
+    }
+
+    /// Returns the size of the glwe ciphertexts composing the ggsw ciphertexts of the list.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.rlwe_size
+    }
+
+    /// Returns the number of coefficients of the polynomials used in the list ciphertexts.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of decomposition levels used in the list ciphertexts.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level
+    }
+
+    /// Returns the logarithm of the base used for the decomposition in the list ciphertexts.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns an iterator over ciphertexts borrowed from the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::ggsw::GgswList;
+    /// use concrete_core::prelude::{
+    ///     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweSize,
+    ///     PolynomialSize,
+    /// };
+    /// let list = GgswList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     CiphertextCount(5),
+    /// );
+    /// for ggsw in list.ciphertext_iter() {
+    ///     assert_eq!(ggsw.decomposition_level_count(), DecompositionLevelCount(3));
+    /// }
+    /// assert_eq!(list.ciphertext_iter().count(), 5);
+    /// ```
+    pub fn ciphertext_iter(
+        &self,
+    ) -> impl Iterator<Item = StandardGgswCiphertext<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        let poly_size = self.poly_size;
+        let rlwe_size = self.rlwe_size;
+        let base_log = self.decomp_base_log;
+        self.as_tensor()
+            .subtensor_iter(self.ciphertext_size())
+            .map(move |sub| {
+                StandardGgswCiphertext::from_container(
+                    sub.into_container(),
+                    rlwe_size,
+                    poly_size,
+                    base_log,
+                )
+            })
+    }
+
+    /// Returns an iterator over ciphertexts mutably borrowed from the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::ggsw::GgswList;
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// use concrete_core::prelude::{
+    ///     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweSize,
+    ///     PolynomialSize,
+    /// };
+    /// let mut list = GgswList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     CiphertextCount(5),
+    /// );
+    /// for mut ggsw in list.ciphertext_iter_mut() {
+    ///     ggsw.as_mut_tensor().fill_with_element(9);
+    /// }
+    /// assert!(list.as_tensor().iter().all(|a| *a == 9));
+    /// assert_eq!(list.ciphertext_iter_mut().count(), 5);
+    /// ```
+    pub fn ciphertext_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = StandardGgswCiphertext<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        let poly_size = self.poly_size;
+        let rlwe_size = self.rlwe_size;
+        let base_log = self.decomp_base_log;
+        let chunks_size = self.ciphertext_size();
+        self.as_mut_tensor()
+            .subtensor_iter_mut(chunks_size)
+            .map(move |sub| {
+                StandardGgswCiphertext::from_container(
+                    sub.into_container(),
+                    rlwe_size,
+                    poly_size,
+                    base_log,
+                )
+            })
+    }
+
+    fn ciphertext_size(&self) -> usize {
+        self.decomp_level.0 * self.rlwe_size.0 * self.rlwe_size.0 * self.poly_size.0
+    }
+}
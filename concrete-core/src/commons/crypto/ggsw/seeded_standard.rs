@@ -4,7 +4,7 @@ use crate::commons::math::random::{
     ByteRandomGenerator, CompressionSeed, RandomGenerable, RandomGenerator, Uniform,
 };
 use crate::commons::math::tensor::{
-    ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+    ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
 };
 use crate::commons::numeric::Numeric;
 use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize};
@@ -133,6 +133,20 @@ impl<Cont> StandardGgswSeededCiphertext<Cont> {
         }
     }
 
+    /// Returns a view of the ciphertext, borrowing its container.
+    pub fn as_view(&self) -> StandardGgswSeededCiphertext<&'_ [Cont::Element]>
+    where
+        Cont: Container,
+    {
+        StandardGgswSeededCiphertext {
+            tensor: Tensor::from_container(self.tensor.as_container().as_ref()),
+            poly_size: self.poly_size,
+            glwe_size: self.glwe_size,
+            decomp_base_log: self.decomp_base_log,
+            compression_seed: self.compression_seed,
+        }
+    }
+
     /// Returns the size of the glwe ciphertexts composing the ggsw ciphertext.
     ///
     /// # Example
@@ -0,0 +1,705 @@
+use super::{GlweCiphertext, GlweKeyswitchKey};
+use crate::commons::crypto::lwe::LweList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::polynomial::MonomialDegree;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::Numeric;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GaloisElement,
+    GlweDimension, GlweSize, KeyKind, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A GLWE automorphism key, used to evaluate Galois automorphisms $X \mapsto X^k$ on GLWE
+/// ciphertexts.
+///
+/// For a polynomial size $N = 2^n$, the key contains one GLWE keyswitching key for each Galois
+/// element $k = 2^j + 1$ with $1 \le j \le n$. The keyswitching key of element $k$ switches from
+/// the key $\vec{S}(X^k)$ back to the key $\vec{S}(X)$. These are exactly the elements needed to
+/// compute the homomorphic trace of a GLWE ciphertext.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweAutomorphismKey<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomp_level: DecompositionLevelCount,
+    decomp_base_log: DecompositionBaseLog,
+}
+
+tensor_traits!(GlweAutomorphismKey);
+
+/// Returns the number of keyswitching keys stored in an automorphism key, that is
+/// $\log\_2(N)$.
+pub(crate) fn automorphism_key_count(poly_size: PolynomialSize) -> usize {
+    debug_assert!(poly_size.0.is_power_of_two());
+    poly_size.0.trailing_zeros() as usize
+}
+
+/// Returns the Galois element $2^j + 1$ whose keyswitching key is stored at index $j - 1$ in an
+/// automorphism key.
+pub(crate) fn automorphism_key_galois_element(index: usize) -> GaloisElement {
+    GaloisElement((1 << (index + 1)) + 1)
+}
+
+/// Returns the index of the keyswitching key associated with a Galois element in an automorphism
+/// key, if the key contains one.
+pub(crate) fn automorphism_key_index(
+    poly_size: PolynomialSize,
+    galois_element: GaloisElement,
+) -> Option<usize> {
+    (0..automorphism_key_count(poly_size))
+        .find(|index| automorphism_key_galois_element(*index) == galois_element)
+}
+
+impl<Scalar> GlweAutomorphismKey<Vec<Scalar>> {
+    /// Allocates a new GLWE automorphism key whose polynomials coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let ak = GlweAutomorphismKey::allocate(
+    ///     9u32,
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    /// );
+    /// assert_eq!(ak.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(ak.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(ak.keyswitch_key_count(), 8);
+    /// assert_eq!(ak.level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(ak.base_log(), DecompositionBaseLog(5));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> GlweAutomorphismKey<Vec<Scalar>>
+    where
+        Scalar: UnsignedTorus,
+    {
+        let glwe_size = glwe_dimension.to_glwe_size();
+        GlweAutomorphismKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                automorphism_key_count(poly_size)
+                    * glwe_dimension.0
+                    * decomp_level.0
+                    * glwe_size.0
+                    * poly_size.0
+            ]),
+            poly_size,
+            glwe_size,
+            decomp_level,
+            decomp_base_log,
+        }
+    }
+}
+
+impl<Cont> GlweAutomorphismKey<Cont> {
+    /// Creates a GLWE automorphism key from an existing container of values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let vector = vec![0u32; 8 * 2 * 2 * 3 * 256];
+    /// let ak = GlweAutomorphismKey::from_container(
+    ///     vector.as_slice(),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(2),
+    ///     DecompositionBaseLog(5),
+    /// );
+    /// assert_eq!(ak.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(ak.level_count(), DecompositionLevelCount(2));
+    /// ```
+    pub fn from_container<Coef>(
+        cont: Cont,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> GlweAutomorphismKey<Cont>
+    where
+        Cont: AsRefSlice<Element = Coef>,
+    {
+        let tensor = Tensor::from_container(cont);
+        let glwe_size = glwe_dimension.to_glwe_size();
+        ck_dim_eq!(
+            tensor.len() =>
+            automorphism_key_count(poly_size)
+                * glwe_dimension.0
+                * decomp_level.0
+                * glwe_size.0
+                * poly_size.0
+        );
+        GlweAutomorphismKey {
+            tensor,
+            poly_size,
+            glwe_size,
+            decomp_level,
+            decomp_base_log,
+        }
+    }
+
+    pub fn into_container(self) -> Cont {
+        self.tensor.into_container()
+    }
+
+    pub fn as_view(&self) -> GlweAutomorphismKey<&'_ [Cont::Element]>
+    where
+        Cont: Container,
+    {
+        GlweAutomorphismKey {
+            tensor: Tensor::from_container(self.tensor.as_container().as_ref()),
+            poly_size: self.poly_size,
+            glwe_size: self.glwe_size,
+            decomp_level: self.decomp_level,
+            decomp_base_log: self.decomp_base_log,
+        }
+    }
+
+    /// Returns the size of the polynomials used in the automorphism key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the dimension of the GLWE secret key the automorphism key was generated with.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the number of levels used to decompose the input masks.
+    pub fn level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level
+    }
+
+    /// Returns the logarithm of the base used to decompose the input masks.
+    pub fn base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the number of keyswitching keys contained in the automorphism key.
+    pub fn keyswitch_key_count(&self) -> usize {
+        automorphism_key_count(self.poly_size)
+    }
+
+    fn keyswitch_key_size(&self) -> usize {
+        self.glwe_dimension().0 * self.decomp_level.0 * self.glwe_size.0 * self.poly_size.0
+    }
+
+    /// Returns the keyswitching key associated with a Galois element, if the automorphism key
+    /// contains one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GaloisElement, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// let ak = GlweAutomorphismKey::allocate(
+    ///     0u32,
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    /// );
+    /// assert!(ak.keyswitch_key(GaloisElement(3)).is_some());
+    /// assert!(ak.keyswitch_key(GaloisElement(257)).is_some());
+    /// assert!(ak.keyswitch_key(GaloisElement(7)).is_none());
+    /// ```
+    pub fn keyswitch_key(
+        &self,
+        galois_element: GaloisElement,
+    ) -> Option<GlweKeyswitchKey<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        let index = automorphism_key_index(self.poly_size, galois_element)?;
+        let size = self.keyswitch_key_size();
+        Some(GlweKeyswitchKey::from_container(
+            &self.as_tensor().as_slice()[index * size..(index + 1) * size],
+            self.glwe_dimension(),
+            self.glwe_dimension(),
+            self.poly_size,
+            self.decomp_level,
+            self.decomp_base_log,
+        ))
+    }
+
+    /// Generates a new GLWE automorphism key from the input parameters, and fills the current
+    /// container with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    ///
+    /// let (glwe_dim, poly_size) = (GlweDimension(1), PolynomialSize(256));
+    /// let key = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    /// let mut ak = GlweAutomorphismKey::allocate(
+    ///     0u64,
+    ///     glwe_dim,
+    ///     poly_size,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// ak.fill_with_automorphism_key(
+    ///     &key,
+    ///     LogStandardDev::from_log_standard_dev(-50.),
+    ///     &mut encryption_generator,
+    /// );
+    /// ```
+    pub fn fill_with_automorphism_key<Kind, KeyCont, Scalar, Gen>(
+        &mut self,
+        key: &GlweSecretKey<Kind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<Kind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Kind: KeyKind,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.glwe_dimension() => key.key_size());
+        ck_dim_eq!(self.polynomial_size() => key.polynomial_size());
+
+        let glwe_dimension = self.glwe_dimension();
+        let poly_size = self.poly_size;
+        let decomp_level = self.decomp_level;
+        let decomp_base_log = self.decomp_base_log;
+        let size = self.keyswitch_key_size();
+        for (index, ksk_slice) in self
+            .as_mut_tensor()
+            .as_mut_slice()
+            .chunks_exact_mut(size)
+            .enumerate()
+        {
+            let permuted_key = key.to_automorphism_image(automorphism_key_galois_element(index));
+            let mut ksk = GlweKeyswitchKey::from_container(
+                ksk_slice,
+                glwe_dimension,
+                glwe_dimension,
+                poly_size,
+                decomp_level,
+                decomp_base_log,
+            );
+            ksk.fill_with_keyswitch_key(&permuted_key, key, noise_parameters, generator);
+        }
+    }
+
+    /// Applies the automorphism $X \mapsto X^k$ to the input GLWE ciphertext, and writes the
+    /// result, encrypted under the original key, in `output`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key does not contain a keyswitching key for the Galois element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweAutomorphismKey, GlweCiphertext};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::polynomial::Polynomial;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GaloisElement, GlweDimension,
+    ///     LogStandardDev, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    ///
+    /// let (glwe_dim, poly_size) = (GlweDimension(1), PolynomialSize(256));
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    /// let key = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    /// let mut ak = GlweAutomorphismKey::allocate(
+    ///     0u64,
+    ///     glwe_dim,
+    ///     poly_size,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(10),
+    /// );
+    /// ak.fill_with_automorphism_key(&key, noise, &mut encryption_generator);
+    ///
+    /// let messages = PlaintextList::from_container(
+    ///     (0..poly_size.0 as u64)
+    ///         .map(|i| i << 52)
+    ///         .collect::<Vec<_>>(),
+    /// );
+    /// let mut input = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// key.encrypt_glwe(&mut input, &messages, noise, &mut encryption_generator);
+    /// let mut output = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// ak.automorphism_ciphertext(&mut output, &input, GaloisElement(5));
+    ///
+    /// let mut expected = Polynomial::allocate(0u64, poly_size);
+    /// expected.fill_with_automorphism(&messages.as_polynomial(), GaloisElement(5));
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// key.decrypt_glwe(&mut decrypted, &output);
+    /// for (dec, exp) in decrypted.plaintext_iter().zip(expected.coefficient_iter()) {
+    ///     let error = dec.0.wrapping_sub(*exp) as i64;
+    ///     assert!(error.abs() < 1 << 40);
+    /// }
+    /// ```
+    pub fn automorphism_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &GlweCiphertext<InCont>,
+        galois_element: GaloisElement,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.glwe_dimension() => input.mask_size(), output.mask_size());
+        ck_dim_eq!(self.polynomial_size() => input.polynomial_size(), output.polynomial_size());
+
+        let ksk = self
+            .keyswitch_key(galois_element)
+            .expect("The automorphism key does not contain the Galois element.");
+        let mut permuted = GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.glwe_size);
+        fill_with_ciphertext_automorphism(&mut permuted, input, galois_element);
+        ksk.keyswitch_ciphertext(output, &permuted);
+    }
+
+    /// Computes the homomorphic trace of the input GLWE ciphertext, and writes the result in
+    /// `output`.
+    ///
+    /// If the input encrypts the polynomial $M$, the output encrypts the constant polynomial
+    /// $N \cdot M\_0$. Since $N$ is not invertible modulo a power of two, the $N$ factor cannot be
+    /// removed homomorphically, and must be accounted for in the encoding of the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweAutomorphismKey, GlweCiphertext};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    ///
+    /// let (glwe_dim, poly_size) = (GlweDimension(1), PolynomialSize(256));
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    /// let key = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    /// let mut ak = GlweAutomorphismKey::allocate(
+    ///     0u64,
+    ///     glwe_dim,
+    ///     poly_size,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(10),
+    /// );
+    /// ak.fill_with_automorphism_key(&key, noise, &mut encryption_generator);
+    ///
+    /// // The message is encoded with a scaling factor divided by N = 2^8.
+    /// let messages = PlaintextList::from_container(vec![3u64 << 52; poly_size.0]);
+    /// let mut input = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// key.encrypt_glwe(&mut input, &messages, noise, &mut encryption_generator);
+    /// let mut output = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// ak.trace_ciphertext(&mut output, &input);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// key.decrypt_glwe(&mut decrypted, &output);
+    /// for (i, dec) in decrypted.plaintext_iter().enumerate() {
+    ///     let expected = if i == 0 { 3u64 << 60 } else { 0 };
+    ///     let error = dec.0.wrapping_sub(expected) as i64;
+    ///     assert!(error.abs() < 1 << 50);
+    /// }
+    /// ```
+    pub fn trace_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &GlweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.glwe_dimension() => input.mask_size(), output.mask_size());
+        ck_dim_eq!(self.polynomial_size() => input.polynomial_size(), output.polynomial_size());
+
+        let mut accumulator =
+            GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.glwe_size);
+        accumulator
+            .as_mut_tensor()
+            .fill_with_copy(input.as_tensor());
+        trace_with_automorphism(&mut accumulator, 0, |out, inp, galois_element| {
+            self.automorphism_ciphertext(out, inp, galois_element)
+        });
+        output
+            .as_mut_tensor()
+            .fill_with_copy(accumulator.as_tensor());
+    }
+
+    /// Packs the input LWE ciphertexts into the output GLWE ciphertext using homomorphic traces.
+    ///
+    /// The input ciphertexts must be encrypted under the LWE key obtained by flattening the GLWE
+    /// key of the automorphism key. Denoting $2^l$ the smallest power of two greater than or equal
+    /// to the number of inputs, the $i$-th input message $m\_i$ ends up multiplied by $N$ in the
+    /// coefficient of degree $i \cdot N / 2^l$ of the output, every other coefficient being an
+    /// encryption of zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweAutomorphismKey, GlweCiphertext};
+    /// use concrete_core::commons::crypto::lwe::LweList;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_core::prelude::{
+    ///     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     LogStandardDev, LweSize, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    ///
+    /// let (glwe_dim, poly_size) = (GlweDimension(1), PolynomialSize(256));
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    /// let key = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    /// let mut ak = GlweAutomorphismKey::allocate(
+    ///     0u64,
+    ///     glwe_dim,
+    ///     poly_size,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(10),
+    /// );
+    /// ak.fill_with_automorphism_key(&key, noise, &mut encryption_generator);
+    ///
+    /// // Four messages, encoded with a scaling factor divided by N = 2^8.
+    /// let messages = PlaintextList::from_container(vec![1u64 << 52, 2 << 52, 3 << 52, 4 << 52]);
+    /// let lwe_key = key.clone().into_lwe_secret_key();
+    /// let mut input = LweList::allocate(0u64, LweSize(poly_size.0 + 1), CiphertextCount(4));
+    /// lwe_key.encrypt_lwe_list(&mut input, &messages, noise, &mut encryption_generator);
+    /// let mut output = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// ak.pack_lwe_ciphertexts(&mut output, &input);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// key.decrypt_glwe(&mut decrypted, &output);
+    /// for (i, message) in messages.plaintext_iter().enumerate() {
+    ///     let dec = *decrypted.as_tensor().get_element(i * poly_size.0 / 4);
+    ///     let error = dec.wrapping_sub(message.0 << 8) as i64;
+    ///     assert!(error.abs() < 1 << 50);
+    /// }
+    /// ```
+    pub fn pack_lwe_ciphertexts<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &LweList<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.glwe_dimension() => output.mask_size());
+        ck_dim_eq!(self.polynomial_size() => output.polynomial_size());
+
+        pack_lwe_ciphertexts_with_automorphism(
+            output,
+            input,
+            self.glwe_size,
+            |out, inp, galois_element| self.automorphism_ciphertext(out, inp, galois_element),
+        );
+    }
+}
+
+/// Fills the output GLWE ciphertext with the image of the input GLWE ciphertext by the
+/// automorphism $X \mapsto X^k$. The result is encrypted under the key $\vec{S}(X^k)$.
+pub(crate) fn fill_with_ciphertext_automorphism<InCont, OutCont, Scalar>(
+    output: &mut GlweCiphertext<OutCont>,
+    input: &GlweCiphertext<InCont>,
+    galois_element: GaloisElement,
+) where
+    GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+    GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus,
+{
+    for (mut output_poly, input_poly) in output
+        .as_mut_polynomial_list()
+        .polynomial_iter_mut()
+        .zip(input.as_polynomial_list().polynomial_iter())
+    {
+        output_poly.fill_with_automorphism(&input_poly, galois_element);
+    }
+}
+
+/// Computes in place the trace of a GLWE ciphertext from the ring of polynomials of size $N$ down
+/// to the subring of polynomials in $X^{N / 2^l}$, `subring_log` being $l$.
+///
+/// The `automorphism` closure is expected to fill its first argument with the image of its second
+/// argument by the automorphism of the given Galois element, encrypted under the original key.
+pub(crate) fn trace_with_automorphism<Scalar, F>(
+    ciphertext: &mut GlweCiphertext<Vec<Scalar>>,
+    subring_log: usize,
+    mut automorphism: F,
+) where
+    Scalar: UnsignedTorus,
+    F: FnMut(&mut GlweCiphertext<Vec<Scalar>>, &GlweCiphertext<Vec<Scalar>>, GaloisElement),
+{
+    let poly_size = ciphertext.polynomial_size();
+    let mut image = GlweCiphertext::allocate(Scalar::ZERO, poly_size, ciphertext.size());
+    for index in (subring_log..automorphism_key_count(poly_size)).rev() {
+        automorphism(
+            &mut image,
+            ciphertext,
+            automorphism_key_galois_element(index),
+        );
+        ciphertext
+            .as_mut_tensor()
+            .update_with_wrapping_add(image.as_tensor());
+    }
+}
+
+/// Packs LWE ciphertexts into a GLWE ciphertext, following the tree-based algorithm of Chen, Dai,
+/// Kim and Song, "Efficient Homomorphic Conversion Between (Ring) LWE Ciphertexts".
+///
+/// The `automorphism` closure has the same semantics as in [`trace_with_automorphism`].
+pub(crate) fn pack_lwe_ciphertexts_with_automorphism<InCont, OutCont, Scalar, F>(
+    output: &mut GlweCiphertext<OutCont>,
+    input: &LweList<InCont>,
+    glwe_size: GlweSize,
+    mut automorphism: F,
+) where
+    GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+    LweList<InCont>: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus,
+    F: FnMut(&mut GlweCiphertext<Vec<Scalar>>, &GlweCiphertext<Vec<Scalar>>, GaloisElement),
+{
+    let poly_size = output.polynomial_size();
+    ck_dim_eq!(input.mask_size().0 => glwe_size.to_glwe_dimension().0 * poly_size.0);
+    debug_assert!(input.count().0 <= poly_size.0);
+
+    let packed_log = input.count().0.next_power_of_two().trailing_zeros() as usize;
+
+    // We start with the embeddings of the input ciphertexts, completed with empty slots up to a
+    // power of two.
+    let mut nodes: Vec<Option<GlweCiphertext<Vec<Scalar>>>> = input
+        .ciphertext_iter()
+        .map(|lwe| {
+            let mut glwe = GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe_size);
+            let (body, mask) = lwe.get_body_and_mask();
+            for (mut glwe_mask_poly, lwe_mask_poly) in glwe
+                .get_mut_mask()
+                .as_mut_polynomial_list()
+                .polynomial_iter_mut()
+                .zip(mask.as_tensor().as_slice().chunks(poly_size.0))
+            {
+                // The constant coefficient of the product of the mask and key polynomials must
+                // match the LWE mask-key product, hence the reversed and negated coefficients.
+                let glwe_mask_coefs = glwe_mask_poly.as_mut_tensor().as_mut_slice();
+                glwe_mask_coefs[0] = lwe_mask_poly[0];
+                for (glwe_coef, lwe_coef) in glwe_mask_coefs[1..]
+                    .iter_mut()
+                    .zip(lwe_mask_poly[1..].iter().rev())
+                {
+                    *glwe_coef = lwe_coef.wrapping_neg();
+                }
+            }
+            glwe.get_mut_body().as_mut_tensor().as_mut_slice()[0] = body.0;
+            Some(glwe)
+        })
+        .collect();
+    nodes.resize(1 << packed_log, None);
+
+    // At level m, the ciphertexts of index r and r + 2^(l - m) are merged.
+    let mut image = GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe_size);
+    for level in 1..=packed_log {
+        let half = nodes.len() / 2;
+        let odd_nodes = nodes.split_off(half);
+        for (even, odd) in nodes.iter_mut().zip(odd_nodes.into_iter()) {
+            let (mut sum, difference) = match (even.take(), odd) {
+                (None, None) => continue,
+                (Some(even), None) => (even.clone(), even),
+                (even, Some(mut odd)) => {
+                    for mut poly in odd.as_mut_polynomial_list().polynomial_iter_mut() {
+                        poly.update_with_wrapping_monic_monomial_mul(MonomialDegree(
+                            poly_size.0 >> level,
+                        ));
+                    }
+                    let mut sum = even.unwrap_or_else(|| {
+                        GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe_size)
+                    });
+                    let mut difference = sum.clone();
+                    sum.as_mut_tensor()
+                        .update_with_wrapping_add(odd.as_tensor());
+                    difference
+                        .as_mut_tensor()
+                        .update_with_wrapping_sub(odd.as_tensor());
+                    (sum, difference)
+                }
+            };
+            automorphism(
+                &mut image,
+                &difference,
+                automorphism_key_galois_element(level - 1),
+            );
+            sum.as_mut_tensor()
+                .update_with_wrapping_add(image.as_tensor());
+            *even = Some(sum);
+        }
+    }
+
+    let mut packed = nodes
+        .pop()
+        .flatten()
+        .unwrap_or_else(|| GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe_size));
+    trace_with_automorphism(&mut packed, packed_log, automorphism);
+    output.as_mut_tensor().fill_with_copy(packed.as_tensor());
+}
@@ -0,0 +1,424 @@
+use super::{GlweCiphertext, GlweList};
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::decomposition::SignedDecomposer;
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::Numeric;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension, GlweSize,
+    KeyKind, PlaintextCount, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A GLWE keyswitching key, used to switch a GLWE ciphertext from an input GLWE secret key to an
+/// output GLWE secret key with the same polynomial size.
+///
+/// For every polynomial $S\_{\mathsf{in},i}$ of the input key, the key contains `level_count`
+/// GLWE ciphertexts under the output key, the ciphertext of level $l$ encrypting
+/// $S\_{\mathsf{in},i} \cdot \frac{q}{\beta^l}$.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweKeyswitchKey<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    input_glwe_dimension: GlweDimension,
+    output_glwe_size: GlweSize,
+    decomp_level: DecompositionLevelCount,
+    decomp_base_log: DecompositionBaseLog,
+}
+
+tensor_traits!(GlweKeyswitchKey);
+
+/// Returns the plaintexts encrypted in a GLWE keyswitching key, that is, the input key
+/// polynomials scaled by the decomposition factor of every level.
+pub(crate) fn encode_keyswitch_key_plaintexts<Kind, KeyCont, Scalar>(
+    input_key: &GlweSecretKey<Kind, KeyCont>,
+    level_count: DecompositionLevelCount,
+    base_log: DecompositionBaseLog,
+) -> PlaintextList<Vec<Scalar>>
+where
+    GlweSecretKey<Kind, KeyCont>: AsRefTensor<Element = Scalar>,
+    Kind: KeyKind,
+    Scalar: UnsignedTorus,
+{
+    let poly_size = input_key.polynomial_size();
+    let mut encoded = PlaintextList::allocate(
+        Scalar::ZERO,
+        PlaintextCount(input_key.key_size().0 * level_count.0 * poly_size.0),
+    );
+    for (key_poly, mut levels) in input_key
+        .as_polynomial_list()
+        .polynomial_iter()
+        .zip(encoded.sublist_iter_mut(PlaintextCount(level_count.0 * poly_size.0)))
+    {
+        for (level, mut level_plaintexts) in levels
+            .sublist_iter_mut(PlaintextCount(poly_size.0))
+            .enumerate()
+        {
+            let factor = Scalar::ONE << (Scalar::BITS - (base_log.0 * (level + 1)));
+            level_plaintexts
+                .as_mut_tensor()
+                .fill_with_one(key_poly.as_tensor(), |s| s.wrapping_mul(factor));
+        }
+    }
+    encoded
+}
+
+impl<Scalar> GlweKeyswitchKey<Vec<Scalar>> {
+    /// Allocates a new GLWE keyswitching key whose polynomials coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     9u32,
+    ///     GlweDimension(2),
+    ///     GlweDimension(3),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    /// );
+    /// assert_eq!(ksk.input_glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(ksk.output_glwe_dimension(), GlweDimension(3));
+    /// assert_eq!(ksk.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(ksk.level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(ksk.base_log(), DecompositionBaseLog(5));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        input_glwe_dimension: GlweDimension,
+        output_glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> GlweKeyswitchKey<Vec<Scalar>>
+    where
+        Scalar: UnsignedTorus,
+    {
+        let output_glwe_size = output_glwe_dimension.to_glwe_size();
+        GlweKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                input_glwe_dimension.0
+                    * decomp_level.0
+                    * output_glwe_size.0
+                    * poly_size.0
+            ]),
+            poly_size,
+            input_glwe_dimension,
+            output_glwe_size,
+            decomp_level,
+            decomp_base_log,
+        }
+    }
+}
+
+impl<Cont> GlweKeyswitchKey<Cont> {
+    /// Creates a GLWE keyswitching key from an existing container of values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let vector = vec![0u32; 2 * 2 * 4 * 256];
+    /// let ksk = GlweKeyswitchKey::from_container(
+    ///     vector.as_slice(),
+    ///     GlweDimension(2),
+    ///     GlweDimension(3),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(2),
+    ///     DecompositionBaseLog(5),
+    /// );
+    /// assert_eq!(ksk.input_glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(ksk.output_glwe_dimension(), GlweDimension(3));
+    /// assert_eq!(ksk.level_count(), DecompositionLevelCount(2));
+    /// ```
+    pub fn from_container<Coef>(
+        cont: Cont,
+        input_glwe_dimension: GlweDimension,
+        output_glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> GlweKeyswitchKey<Cont>
+    where
+        Cont: AsRefSlice<Element = Coef>,
+    {
+        let tensor = Tensor::from_container(cont);
+        let output_glwe_size = output_glwe_dimension.to_glwe_size();
+        ck_dim_eq!(
+            tensor.len() =>
+            input_glwe_dimension.0 * decomp_level.0 * output_glwe_size.0 * poly_size.0
+        );
+        GlweKeyswitchKey {
+            tensor,
+            poly_size,
+            input_glwe_dimension,
+            output_glwe_size,
+            decomp_level,
+            decomp_base_log,
+        }
+    }
+
+    pub fn into_container(self) -> Cont {
+        self.tensor.into_container()
+    }
+
+    pub fn as_view(&self) -> GlweKeyswitchKey<&'_ [Cont::Element]>
+    where
+        Cont: Container,
+    {
+        GlweKeyswitchKey {
+            tensor: Tensor::from_container(self.tensor.as_container().as_ref()),
+            poly_size: self.poly_size,
+            input_glwe_dimension: self.input_glwe_dimension,
+            output_glwe_size: self.output_glwe_size,
+            decomp_level: self.decomp_level,
+            decomp_base_log: self.decomp_base_log,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> GlweKeyswitchKey<&'_ mut [Cont::Element]>
+    where
+        Cont: Container,
+        Cont: AsMut<[Cont::Element]>,
+    {
+        GlweKeyswitchKey {
+            tensor: Tensor::from_container(self.tensor.as_mut_container().as_mut()),
+            poly_size: self.poly_size,
+            input_glwe_dimension: self.input_glwe_dimension,
+            output_glwe_size: self.output_glwe_size,
+            decomp_level: self.decomp_level,
+            decomp_base_log: self.decomp_base_log,
+        }
+    }
+
+    /// Returns the size of the polynomials used in the keyswitching key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the dimension of the GLWE secret key the key switches from.
+    pub fn input_glwe_dimension(&self) -> GlweDimension {
+        self.input_glwe_dimension
+    }
+
+    /// Returns the dimension of the GLWE secret key the key switches to.
+    pub fn output_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the number of levels used to decompose the input masks.
+    pub fn level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level
+    }
+
+    /// Returns the logarithm of the base used to decompose the input masks.
+    pub fn base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns a borrowed list of all the GLWE ciphertexts of the key.
+    ///
+    /// The ciphertexts are ordered by input key polynomial, then by increasing level.
+    pub fn as_glwe_list(&self) -> GlweList<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        GlweList::from_container(
+            self.as_tensor().as_slice(),
+            self.output_glwe_dimension(),
+            self.poly_size,
+        )
+    }
+
+    /// Generates a new GLWE keyswitching key from the input parameters, and fills the current
+    /// container with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    ///
+    /// let poly_size = PolynomialSize(256);
+    /// let (input_glwe_dim, output_glwe_dim) = (GlweDimension(2), GlweDimension(1));
+    /// let mut ksk = GlweKeyswitchKey::allocate(
+    ///     0u64,
+    ///     input_glwe_dim,
+    ///     output_glwe_dim,
+    ///     poly_size,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// let input_key =
+    ///     GlweSecretKey::generate_binary(input_glwe_dim, poly_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_glwe_dim, poly_size, &mut secret_generator);
+    /// ksk.fill_with_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     LogStandardDev::from_log_standard_dev(-50.),
+    ///     &mut encryption_generator,
+    /// );
+    /// ```
+    pub fn fill_with_keyswitch_key<InKind, OutKind, InKeyCont, OutKeyCont, Scalar, Gen>(
+        &mut self,
+        input_key: &GlweSecretKey<InKind, InKeyCont>,
+        output_key: &GlweSecretKey<OutKind, OutKeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<InKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<OutKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        InKind: KeyKind,
+        OutKind: KeyKind,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_glwe_dimension() => input_key.key_size());
+        ck_dim_eq!(self.output_glwe_dimension() => output_key.key_size());
+        ck_dim_eq!(self.polynomial_size() => input_key.polynomial_size());
+        ck_dim_eq!(self.polynomial_size() => output_key.polynomial_size());
+
+        let encoded =
+            encode_keyswitch_key_plaintexts(input_key, self.decomp_level, self.decomp_base_log);
+        let output_glwe_dimension = self.output_glwe_dimension();
+        let poly_size = self.poly_size;
+        let mut glwe_list = GlweList::from_container(
+            self.as_mut_tensor().as_mut_slice(),
+            output_glwe_dimension,
+            poly_size,
+        );
+        output_key.encrypt_glwe_list(&mut glwe_list, &encoded, noise_parameters, generator);
+    }
+
+    /// Switches the input GLWE ciphertext to the output key, and writes the result in `output`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweCiphertext, GlweKeyswitchKey};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    ///
+    /// let poly_size = PolynomialSize(256);
+    /// let (input_glwe_dim, output_glwe_dim) = (GlweDimension(2), GlweDimension(1));
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    /// let input_key =
+    ///     GlweSecretKey::generate_binary(input_glwe_dim, poly_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_glwe_dim, poly_size, &mut secret_generator);
+    /// let mut ksk = GlweKeyswitchKey::allocate(
+    ///     0u64,
+    ///     input_glwe_dim,
+    ///     output_glwe_dim,
+    ///     poly_size,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(10),
+    /// );
+    /// ksk.fill_with_keyswitch_key(&input_key, &output_key, noise, &mut encryption_generator);
+    ///
+    /// let messages = PlaintextList::from_container(vec![1u64 << 60; poly_size.0]);
+    /// let mut input = GlweCiphertext::allocate(0u64, poly_size, input_glwe_dim.to_glwe_size());
+    /// input_key.encrypt_glwe(&mut input, &messages, noise, &mut encryption_generator);
+    /// let mut output = GlweCiphertext::allocate(0u64, poly_size, output_glwe_dim.to_glwe_size());
+    /// ksk.keyswitch_ciphertext(&mut output, &input);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// output_key.decrypt_glwe(&mut decrypted, &output);
+    /// for (dec, msg) in decrypted.plaintext_iter().zip(messages.plaintext_iter()) {
+    ///     let error = dec.0.wrapping_sub(msg.0) as i64;
+    ///     assert!(error.abs() < 1 << 40);
+    /// }
+    /// ```
+    pub fn keyswitch_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &GlweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.input_glwe_dimension() => input.mask_size());
+        ck_dim_eq!(self.output_glwe_dimension() => output.mask_size());
+        ck_dim_eq!(self.polynomial_size() => input.polynomial_size(), output.polynomial_size());
+
+        let poly_size = self.poly_size;
+        let ciphertext_size = self.output_glwe_size.0 * poly_size.0;
+
+        // We reset the output masks and copy the input body.
+        output.as_mut_tensor().fill_with_element(Scalar::ZERO);
+        output
+            .get_mut_body()
+            .as_mut_tensor()
+            .fill_with_copy(input.get_body().as_tensor());
+
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level);
+        let mut product = Polynomial::allocate(Scalar::ZERO, poly_size);
+        for (mask_poly, levels) in input.get_mask().as_polynomial_list().polynomial_iter().zip(
+            self.as_tensor()
+                .subtensor_iter(self.decomp_level.0 * ciphertext_size),
+        ) {
+            let mut decomposition = decomposer.decompose_tensor(&mask_poly);
+            // The decomposition iterator outputs the levels in decreasing order.
+            while let Some(term) = decomposition.next_term() {
+                let term_poly = Polynomial::from_container(term.as_tensor().as_slice());
+                let level_start = (term.level().0 - 1) * ciphertext_size;
+                let key_ciphertext = GlweCiphertext::from_container(
+                    &levels.as_slice()[level_start..level_start + ciphertext_size],
+                    poly_size,
+                );
+                for (mut output_poly, key_poly) in output
+                    .as_mut_polynomial_list()
+                    .polynomial_iter_mut()
+                    .zip(key_ciphertext.as_polynomial_list().polynomial_iter())
+                {
+                    product.fill_with_wrapping_mul(&term_poly, &key_poly);
+                    output_poly.update_with_wrapping_sub(&product);
+                }
+            }
+        }
+    }
+}
@@ -1,17 +1,133 @@
 //! GLWE encryption scheme
 
+mod automorphism_key;
 mod body;
 mod ciphertext;
 mod keyswitch;
+mod keyswitch_key;
 mod list;
 mod mask;
+mod relinearization_key;
 mod seeded_ciphertext;
+mod seeded_keyswitch_key;
 mod seeded_list;
 
+pub use automorphism_key::*;
 pub use body::*;
 pub use ciphertext::*;
 pub use keyswitch::*;
+pub use keyswitch_key::*;
 pub use list::*;
 pub use mask::*;
+pub use relinearization_key::*;
 pub use seeded_ciphertext::*;
+pub use seeded_keyswitch_key::*;
 pub use seeded_list::*;
+
+#[cfg(test)]
+mod test {
+    use crate::commons::crypto::glwe::{GlweKeyswitchKey, GlweSeededKeyswitchKey};
+    use crate::commons::crypto::secret::generators::{
+        DeterministicSeeder, EncryptionRandomGenerator,
+    };
+    use crate::commons::crypto::secret::GlweSecretKey;
+    use crate::commons::math::random::CompressionSeed;
+    use crate::commons::math::torus::UnsignedTorus;
+    use crate::commons::test_tools::new_secret_random_generator;
+    use crate::prelude::{
+        DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, StandardDev,
+    };
+    use concrete_csprng::generators::SoftwareRandomGenerator;
+    use concrete_csprng::seeders::Seed;
+
+    fn test_glwe_ksk_seeded_gen_equivalence<T: UnsignedTorus>() {
+        for _ in 0..10 {
+            let input_glwe_dim =
+                GlweDimension(crate::commons::test_tools::random_usize_between(1..4));
+            let output_glwe_dim =
+                GlweDimension(crate::commons::test_tools::random_usize_between(1..4));
+            let poly_size = PolynomialSize(crate::commons::test_tools::random_usize_between(5..10));
+            let level =
+                DecompositionLevelCount(crate::commons::test_tools::random_usize_between(2..5));
+            let base_log =
+                DecompositionBaseLog(crate::commons::test_tools::random_usize_between(2..5));
+            let mask_seed = Seed(crate::commons::test_tools::any_usize() as u128);
+            let deterministic_seeder_seed = Seed(crate::commons::test_tools::any_usize() as u128);
+
+            let compression_seed = CompressionSeed { seed: mask_seed };
+
+            let mut secret_generator = new_secret_random_generator();
+
+            let input_key =
+                GlweSecretKey::generate_binary(input_glwe_dim, poly_size, &mut secret_generator);
+            let output_key =
+                GlweSecretKey::generate_binary(output_glwe_dim, poly_size, &mut secret_generator);
+
+            let mut ksk = GlweKeyswitchKey::allocate(
+                T::ZERO,
+                input_glwe_dim,
+                output_glwe_dim,
+                poly_size,
+                level,
+                base_log,
+            );
+
+            let mut encryption_generator =
+                EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(
+                    mask_seed,
+                    &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(
+                        deterministic_seeder_seed,
+                    ),
+                );
+
+            ksk.fill_with_keyswitch_key(
+                &input_key,
+                &output_key,
+                StandardDev::from_standard_dev(10.),
+                &mut encryption_generator,
+            );
+
+            let mut seeded_ksk = GlweSeededKeyswitchKey::allocate(
+                input_glwe_dim,
+                output_glwe_dim,
+                poly_size,
+                level,
+                base_log,
+                compression_seed,
+            );
+
+            seeded_ksk
+                .fill_with_seeded_keyswitch_key::<_, _, _, _, _, _, _, SoftwareRandomGenerator>(
+                    &input_key,
+                    &output_key,
+                    StandardDev::from_standard_dev(10.),
+                    &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(
+                        deterministic_seeder_seed,
+                    ),
+                );
+
+            let mut expanded_ksk = GlweKeyswitchKey::allocate(
+                T::ZERO,
+                input_glwe_dim,
+                output_glwe_dim,
+                poly_size,
+                level,
+                base_log,
+            );
+
+            seeded_ksk.expand_into::<_, _, SoftwareRandomGenerator>(&mut expanded_ksk);
+
+            assert_eq!(ksk, expanded_ksk);
+        }
+    }
+
+    #[test]
+    fn test_glwe_ksk_seeded_gen_equivalence_u32() {
+        test_glwe_ksk_seeded_gen_equivalence::<u32>()
+    }
+
+    #[test]
+    fn test_glwe_ksk_seeded_gen_equivalence_u64() {
+        test_glwe_ksk_seeded_gen_equivalence::<u64>()
+    }
+}
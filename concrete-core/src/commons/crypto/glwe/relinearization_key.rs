@@ -0,0 +1,281 @@
+use super::GlweList;
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::Numeric;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension, GlweSize,
+    KeyKind, PlaintextCount, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A relinearization key, used to bring back the output of a GLWE tensor product under the
+/// original GLWE secret key.
+///
+/// For every pair $(i, j)$ with $0 \le i \le j < k$ (in lexicographic order), the key contains
+/// `level_count` GLWE ciphertexts, the ciphertext of level $l$ encrypting
+/// $S\_i \cdot S\_j \cdot \frac{q}{\beta^l}$.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweRelinearizationKey<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomp_level: DecompositionLevelCount,
+    decomp_base_log: DecompositionBaseLog,
+}
+
+tensor_traits!(GlweRelinearizationKey);
+
+/// Returns the number of products $S\_i \cdot S\_j$, $i \le j$, for a GLWE dimension $k$.
+pub(crate) fn relinearization_pair_count(glwe_dimension: GlweDimension) -> usize {
+    glwe_dimension.0 * (glwe_dimension.0 + 1) / 2
+}
+
+impl<Scalar> GlweRelinearizationKey<Vec<Scalar>> {
+    /// Allocates a new relinearization key whose polynomials coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweRelinearizationKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let rlk = GlweRelinearizationKey::allocate(
+    ///     9u32,
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    /// );
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(rlk.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(rlk.level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(rlk.base_log(), DecompositionBaseLog(5));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> GlweRelinearizationKey<Vec<Scalar>>
+    where
+        Scalar: UnsignedTorus,
+    {
+        let glwe_size = glwe_dimension.to_glwe_size();
+        GlweRelinearizationKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                relinearization_pair_count(glwe_dimension)
+                    * decomp_level.0
+                    * glwe_size.0
+                    * poly_size.0
+            ]),
+            poly_size,
+            glwe_size,
+            decomp_level,
+            decomp_base_log,
+        }
+    }
+}
+
+impl<Cont> GlweRelinearizationKey<Cont> {
+    /// Creates a relinearization key from an existing container of values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweRelinearizationKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let vector = vec![0u32; 3 * 2 * 3 * 256];
+    /// let rlk = GlweRelinearizationKey::from_container(
+    ///     vector.as_slice(),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(2),
+    ///     DecompositionBaseLog(5),
+    /// );
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(rlk.level_count(), DecompositionLevelCount(2));
+    /// ```
+    pub fn from_container<Coef>(
+        cont: Cont,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> GlweRelinearizationKey<Cont>
+    where
+        Cont: AsRefSlice<Element = Coef>,
+    {
+        let tensor = Tensor::from_container(cont);
+        let glwe_size = glwe_dimension.to_glwe_size();
+        ck_dim_eq!(
+            tensor.len() =>
+            relinearization_pair_count(glwe_dimension) * decomp_level.0 * glwe_size.0 * poly_size.0
+        );
+        GlweRelinearizationKey {
+            tensor,
+            poly_size,
+            glwe_size,
+            decomp_level,
+            decomp_base_log,
+        }
+    }
+
+    pub fn into_container(self) -> Cont {
+        self.tensor.into_container()
+    }
+
+    pub fn as_view(&self) -> GlweRelinearizationKey<&'_ [Cont::Element]>
+    where
+        Cont: Container,
+    {
+        GlweRelinearizationKey {
+            tensor: Tensor::from_container(self.tensor.as_container().as_ref()),
+            poly_size: self.poly_size,
+            glwe_size: self.glwe_size,
+            decomp_level: self.decomp_level,
+            decomp_base_log: self.decomp_base_log,
+        }
+    }
+
+    /// Returns the size of the polynomials used in the relinearization key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the dimension of the GLWE secret key the key relinearizes to.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the number of levels used to decompose the tensored masks.
+    pub fn level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level
+    }
+
+    /// Returns the logarithm of the base used to decompose the tensored masks.
+    pub fn base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns a borrowed list of all the GLWE ciphertexts of the key.
+    ///
+    /// The ciphertexts are ordered by pair $(i, j)$, then by increasing level.
+    pub fn as_glwe_list(&self) -> GlweList<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        GlweList::from_container(
+            self.as_tensor().as_slice(),
+            self.glwe_dimension(),
+            self.poly_size,
+        )
+    }
+
+    /// Generates a new relinearization key from the input parameters, and fills the current
+    /// container with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweRelinearizationKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    ///
+    /// let (glwe_dim, poly_size) = (GlweDimension(2), PolynomialSize(256));
+    /// let mut rlk = GlweRelinearizationKey::allocate(
+    ///     0u64,
+    ///     glwe_dim,
+    ///     poly_size,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// let glwe_sk = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    /// rlk.fill_with_new_key(
+    ///     &glwe_sk,
+    ///     LogStandardDev::from_log_standard_dev(-50.),
+    ///     &mut encryption_generator,
+    /// );
+    /// ```
+    pub fn fill_with_new_key<Kind, KeyCont, Scalar, Gen>(
+        &mut self,
+        glwe_secret_key: &GlweSecretKey<Kind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<Kind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Kind: KeyKind,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.glwe_dimension() => glwe_secret_key.key_size());
+        ck_dim_eq!(self.polynomial_size() => glwe_secret_key.polynomial_size());
+
+        let glwe_dimension = self.glwe_dimension();
+        let poly_size = self.poly_size;
+        let base_log = self.decomp_base_log;
+        let level_count = self.decomp_level;
+        let pair_count = relinearization_pair_count(glwe_dimension);
+
+        // We encode all the scaled products S_i * S_j in a single plaintext list, so as to encrypt
+        // the whole key at once.
+        let mut encoded = PlaintextList::allocate(
+            Scalar::ZERO,
+            PlaintextCount(pair_count * level_count.0 * poly_size.0),
+        );
+        let mut product = Polynomial::allocate(Scalar::ZERO, poly_size);
+        let key_polys = glwe_secret_key.as_polynomial_list();
+        let mut pair_plaintexts =
+            encoded.sublist_iter_mut(PlaintextCount(level_count.0 * poly_size.0));
+        for i in 0..glwe_dimension.0 {
+            for j in i..glwe_dimension.0 {
+                product.fill_with_wrapping_mul(
+                    &key_polys.get_polynomial(i),
+                    &key_polys.get_polynomial(j),
+                );
+                let mut levels = pair_plaintexts.next().unwrap();
+                for (level, mut level_plaintexts) in levels
+                    .sublist_iter_mut(PlaintextCount(poly_size.0))
+                    .enumerate()
+                {
+                    let factor = Scalar::ONE << (Scalar::BITS - (base_log.0 * (level + 1)));
+                    level_plaintexts
+                        .as_mut_tensor()
+                        .fill_with_one(product.as_tensor(), |s| s.wrapping_mul(factor));
+                }
+            }
+        }
+
+        let mut glwe_list = GlweList::from_container(
+            self.as_mut_tensor().as_mut_slice(),
+            glwe_dimension,
+            poly_size,
+        );
+        glwe_secret_key.encrypt_glwe_list(&mut glwe_list, &encoded, noise_parameters, generator);
+    }
+}
@@ -0,0 +1,343 @@
+use super::keyswitch_key::encode_keyswitch_key_plaintexts;
+use super::{GlweKeyswitchKey, GlweList, GlweSeededList};
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::random::{
+    ByteRandomGenerator, CompressionSeed, RandomGenerable, Seeder, Uniform,
+};
+use crate::commons::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::Numeric;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension, KeyKind,
+    PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A seeded GLWE keyswitching key.
+///
+/// Only the bodies of the GLWE ciphertexts are stored, the masks being regenerated from the
+/// compression seed. See [`GlweKeyswitchKey`] for more details on GLWE keyswitching keys.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweSeededKeyswitchKey<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    input_glwe_dimension: GlweDimension,
+    output_glwe_dimension: GlweDimension,
+    decomp_level: DecompositionLevelCount,
+    decomp_base_log: DecompositionBaseLog,
+    compression_seed: CompressionSeed,
+}
+
+tensor_traits!(GlweSeededKeyswitchKey);
+
+impl<Scalar> GlweSeededKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Numeric,
+{
+    /// Allocates a new seeded GLWE keyswitching key whose bodies coefficients are all zero.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a seeded keyswitch key, but merely allocates a container
+    /// of the right size. See [`GlweSeededKeyswitchKey::fill_with_seeded_keyswitch_key`] to fill
+    /// the container with a proper seeded keyswitching key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweSeededKeyswitchKey;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    /// let ksk = GlweSeededKeyswitchKey::<Vec<u32>>::allocate(
+    ///     GlweDimension(2),
+    ///     GlweDimension(3),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     compression_seed,
+    /// );
+    /// assert_eq!(ksk.input_glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(ksk.output_glwe_dimension(), GlweDimension(3));
+    /// assert_eq!(ksk.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(ksk.level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(ksk.base_log(), DecompositionBaseLog(5));
+    /// assert_eq!(ksk.compression_seed(), compression_seed);
+    /// ```
+    pub fn allocate(
+        input_glwe_dimension: GlweDimension,
+        output_glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        compression_seed: CompressionSeed,
+    ) -> GlweSeededKeyswitchKey<Vec<Scalar>> {
+        GlweSeededKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                Scalar::ZERO;
+                input_glwe_dimension.0
+                    * decomp_level.0
+                    * poly_size.0
+            ]),
+            poly_size,
+            input_glwe_dimension,
+            output_glwe_dimension,
+            decomp_level,
+            decomp_base_log,
+            compression_seed,
+        }
+    }
+}
+
+impl<Cont> GlweSeededKeyswitchKey<Cont> {
+    /// Creates a seeded GLWE keyswitching key from an existing container of values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweSeededKeyswitchKey;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let vector = vec![0u32; 2 * 2 * 256];
+    /// let ksk = GlweSeededKeyswitchKey::from_container(
+    ///     vector.as_slice(),
+    ///     GlweDimension(2),
+    ///     GlweDimension(3),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(2),
+    ///     DecompositionBaseLog(5),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// assert_eq!(ksk.input_glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(ksk.output_glwe_dimension(), GlweDimension(3));
+    /// assert_eq!(ksk.level_count(), DecompositionLevelCount(2));
+    /// ```
+    pub fn from_container<Coef>(
+        cont: Cont,
+        input_glwe_dimension: GlweDimension,
+        output_glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        compression_seed: CompressionSeed,
+    ) -> GlweSeededKeyswitchKey<Cont>
+    where
+        Cont: AsRefSlice<Element = Coef>,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_eq!(tensor.len() => input_glwe_dimension.0 * decomp_level.0 * poly_size.0);
+        GlweSeededKeyswitchKey {
+            tensor,
+            poly_size,
+            input_glwe_dimension,
+            output_glwe_dimension,
+            decomp_level,
+            decomp_base_log,
+            compression_seed,
+        }
+    }
+
+    pub fn into_container(self) -> Cont {
+        self.tensor.into_container()
+    }
+
+    pub fn as_view(&self) -> GlweSeededKeyswitchKey<&'_ [Cont::Element]>
+    where
+        Cont: Container,
+    {
+        GlweSeededKeyswitchKey {
+            tensor: Tensor::from_container(self.tensor.as_container().as_ref()),
+            poly_size: self.poly_size,
+            input_glwe_dimension: self.input_glwe_dimension,
+            output_glwe_dimension: self.output_glwe_dimension,
+            decomp_level: self.decomp_level,
+            decomp_base_log: self.decomp_base_log,
+            compression_seed: self.compression_seed,
+        }
+    }
+
+    /// Returns the size of the polynomials used in the keyswitching key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the dimension of the GLWE secret key the key switches from.
+    pub fn input_glwe_dimension(&self) -> GlweDimension {
+        self.input_glwe_dimension
+    }
+
+    /// Returns the dimension of the GLWE secret key the key switches to.
+    pub fn output_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_dimension
+    }
+
+    /// Returns the number of levels used to decompose the input masks.
+    pub fn level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level
+    }
+
+    /// Returns the logarithm of the base used to decompose the input masks.
+    pub fn base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the seed used to generate the masks of the key.
+    pub fn compression_seed(&self) -> CompressionSeed {
+        self.compression_seed
+    }
+
+    /// Generates a new seeded GLWE keyswitching key from the input parameters, and fills the
+    /// current container with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweSeededKeyswitchKey;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::random::CompressionSeed;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    ///
+    /// let poly_size = PolynomialSize(256);
+    /// let (input_glwe_dim, output_glwe_dim) = (GlweDimension(2), GlweDimension(1));
+    /// let mut ksk = GlweSeededKeyswitchKey::<Vec<u64>>::allocate(
+    ///     input_glwe_dim,
+    ///     output_glwe_dim,
+    ///     poly_size,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// let input_key =
+    ///     GlweSecretKey::generate_binary(input_glwe_dim, poly_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_glwe_dim, poly_size, &mut secret_generator);
+    /// ksk.fill_with_seeded_keyswitch_key::<_, _, _, _, _, _, _, SoftwareRandomGenerator>(
+    ///     &input_key,
+    ///     &output_key,
+    ///     LogStandardDev::from_log_standard_dev(-50.),
+    ///     &mut UnixSeeder::new(0),
+    /// );
+    /// ```
+    pub fn fill_with_seeded_keyswitch_key<
+        InKind,
+        OutKind,
+        InKeyCont,
+        OutKeyCont,
+        Scalar,
+        NoiseParameter,
+        NoiseSeeder,
+        Gen,
+    >(
+        &mut self,
+        input_key: &GlweSecretKey<InKind, InKeyCont>,
+        output_key: &GlweSecretKey<OutKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        noise_seeder: &mut NoiseSeeder,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<InKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<OutKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        InKind: KeyKind,
+        OutKind: KeyKind,
+        Scalar: UnsignedTorus,
+        NoiseParameter: DispersionParameter,
+        NoiseSeeder: Seeder,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_glwe_dimension() => input_key.key_size());
+        ck_dim_eq!(self.output_glwe_dimension() => output_key.key_size());
+        ck_dim_eq!(self.polynomial_size() => input_key.polynomial_size());
+        ck_dim_eq!(self.polynomial_size() => output_key.polynomial_size());
+
+        let encoded =
+            encode_keyswitch_key_plaintexts(input_key, self.decomp_level, self.decomp_base_log);
+
+        let output_glwe_dimension = self.output_glwe_dimension;
+        let poly_size = self.poly_size;
+        let compression_seed = self.compression_seed;
+        let mut seeded_list = GlweSeededList::from_container(
+            self.as_mut_tensor().as_mut_slice(),
+            output_glwe_dimension,
+            poly_size,
+            compression_seed,
+        );
+        output_key.encrypt_seeded_glwe_list::<_, _, _, _, _, Gen>(
+            &mut seeded_list,
+            &encoded,
+            noise_parameters,
+            noise_seeder,
+        );
+    }
+
+    /// Expands the seeded GLWE keyswitching key into a standard one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::{GlweKeyswitchKey, GlweSeededKeyswitchKey};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// let seeded_ksk = GlweSeededKeyswitchKey::<Vec<u64>>::allocate(
+    ///     GlweDimension(2),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// let mut ksk = GlweKeyswitchKey::allocate(
+    ///     0u64,
+    ///     GlweDimension(2),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// seeded_ksk.expand_into::<_, _, SoftwareRandomGenerator>(&mut ksk);
+    /// ```
+    pub fn expand_into<OutCont, Scalar, Gen>(self, output: &mut GlweKeyswitchKey<OutCont>)
+    where
+        GlweKeyswitchKey<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: Numeric + RandomGenerable<Uniform>,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_glwe_dimension() => output.input_glwe_dimension());
+        ck_dim_eq!(self.output_glwe_dimension() => output.output_glwe_dimension());
+        ck_dim_eq!(self.polynomial_size() => output.polynomial_size());
+        ck_dim_eq!(self.level_count() => output.level_count());
+
+        let output_glwe_dimension = self.output_glwe_dimension;
+        let poly_size = self.poly_size;
+        let seeded_list = GlweSeededList::from_container(
+            self.as_tensor().as_slice(),
+            output_glwe_dimension,
+            poly_size,
+            self.compression_seed,
+        );
+        let mut glwe_list = GlweList::from_container(
+            output.as_mut_tensor().as_mut_slice(),
+            output_glwe_dimension,
+            poly_size,
+        );
+        seeded_list.expand_into::<_, _, Gen>(&mut glwe_list);
+    }
+}
@@ -11,8 +11,8 @@ use crate::commons::math::tensor::{
 };
 use crate::commons::math::torus::UnsignedTorus;
 use crate::prelude::{
-    BinaryKeyKind, CiphertextCount, DecompositionBaseLog, DecompositionLevelCount,
-    DispersionParameter, LweDimension, LweSize,
+    CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, KeyKind,
+    LweDimension, LweSize,
 };
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
@@ -348,16 +348,18 @@ impl<Cont> LweKeyswitchKey<Cont> {
     ///
     /// assert!(!ksk.as_tensor().iter().all(|a| *a == 0));
     /// ```
-    pub fn fill_with_keyswitch_key<InKeyCont, OutKeyCont, Scalar, Gen>(
+    pub fn fill_with_keyswitch_key<InKind, OutKind, InKeyCont, OutKeyCont, Scalar, Gen>(
         &mut self,
-        before_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
-        after_key: &LweSecretKey<BinaryKeyKind, OutKeyCont>,
+        before_key: &LweSecretKey<InKind, InKeyCont>,
+        after_key: &LweSecretKey<OutKind, OutKeyCont>,
         noise_parameters: impl DispersionParameter,
         generator: &mut EncryptionRandomGenerator<Gen>,
     ) where
         Self: AsMutTensor<Element = Scalar>,
-        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
-        LweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        InKind: KeyKind,
+        OutKind: KeyKind,
+        LweSecretKey<InKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        LweSecretKey<OutKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
         Gen: ByteRandomGenerator,
     {
@@ -17,8 +17,8 @@ use crate::commons::math::tensor::{
 use crate::commons::math::torus::UnsignedTorus;
 use crate::commons::numeric::Numeric;
 use crate::prelude::{
-    BinaryKeyKind, DispersionParameter, GaussianKeyKind, GlweDimension, KeyKind, PlaintextCount,
-    PolynomialSize, TernaryKeyKind, UniformKeyKind,
+    BinaryKeyKind, DispersionParameter, GaloisElement, GaussianKeyKind, GlweDimension, KeyKind,
+    PlaintextCount, PolynomialSize, TernaryKeyKind, UniformKeyKind,
 };
 #[cfg(feature = "__commons_parallel")]
 use rayon::{iter::IndexedParallelIterator, prelude::*};
@@ -460,6 +460,31 @@ where
         PolynomialList::from_container(self.as_mut_tensor().as_mut_slice(), poly_size)
     }
 
+    /// Returns a new key whose polynomials are the images of the current key polynomials by the
+    /// automorphism $X \mapsto X^k$, $k$ being the given Galois element.
+    pub(crate) fn to_automorphism_image<Scalar>(
+        &self,
+        galois_element: GaloisElement,
+    ) -> GlweSecretKey<Kind, Vec<Scalar>>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let mut image = GlweSecretKey {
+            tensor: Tensor::allocate(Scalar::ZERO, self.as_tensor().len()),
+            poly_size: self.poly_size,
+            kind: PhantomData,
+        };
+        for (mut image_poly, key_poly) in image
+            .as_mut_polynomial_list()
+            .polynomial_iter_mut()
+            .zip(self.as_polynomial_list().polynomial_iter())
+        {
+            image_poly.fill_with_automorphism(&key_poly, galois_element);
+        }
+        image
+    }
+
     fn fill_glwe_mask_and_body_for_encryption<InputCont, BodyCont, MaskCont, Scalar, Gen>(
         &self,
         mut output_body: GlweBody<BodyCont>,
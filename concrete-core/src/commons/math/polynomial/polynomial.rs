@@ -8,7 +8,7 @@ use crate::commons::math::tensor::{
 
 use super::*;
 use crate::commons::numeric::UnsignedInteger;
-use crate::prelude::{MonomialDegree, PolynomialSize};
+use crate::prelude::{GaloisElement, MonomialDegree, PolynomialSize};
 
 // stop the induction when polynomials have KARATUSBA_STOP elements
 const KARATUSBA_STOP: usize = 32;
@@ -685,6 +685,26 @@ impl<Cont> Polynomial<Cont> {
             .update_with_wrapping_sub(other.as_tensor());
     }
 
+    /// Negates an integer polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, Polynomial};
+    /// let mut poly = Polynomial::from_container(vec![1u8, 2, 3]);
+    /// poly.update_with_wrapping_neg();
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 255);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 254);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 253);
+    /// ```
+    pub fn update_with_wrapping_neg<Coef>(&mut self)
+    where
+        Self: AsMutTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.as_mut_tensor().update_with_wrapping_neg();
+    }
+
     /// Multiplies (mod $(X^N+1)$), the current polynomial with a monomial of a given degree, and
     /// a coefficient of one.
     ///
@@ -758,6 +778,48 @@ impl<Cont> Polynomial<Cont> {
             .for_each(|a| *a = a.wrapping_neg());
     }
 
+    /// Fills the current polynomial with the image of the input polynomial by the automorphism
+    /// $X \mapsto X^k$ (mod $(X^N+1)$), where $k$ is the odd Galois element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, Polynomial};
+    /// use concrete_core::prelude::GaloisElement;
+    /// let input = Polynomial::from_container(vec![1u8, 2, 3, 4]);
+    /// let mut output = Polynomial::from_container(vec![0u8; 4]);
+    /// output.fill_with_automorphism(&input, GaloisElement(3));
+    /// assert_eq!(*output.get_monomial(MonomialDegree(0)).get_coefficient(), 1);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(1)).get_coefficient(), 4);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(2)).get_coefficient(), 253);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(3)).get_coefficient(), 2);
+    /// ```
+    pub fn fill_with_automorphism<Coef, InCont>(
+        &mut self,
+        input: &Polynomial<InCont>,
+        galois_element: GaloisElement,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<InCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.as_tensor().len() => input.as_tensor().len());
+        debug_assert_eq!(galois_element.0 % 2, 1);
+        let poly_size = self.as_tensor().len();
+        // X^(2N) = 1, so the Galois element is reduced first to keep the products small.
+        let k = galois_element.0 % (2 * poly_size);
+        let output = self.as_mut_tensor().as_mut_slice();
+        for (degree, coef) in input.as_tensor().iter().enumerate() {
+            // X^degree is sent to X^(degree * k) = (-1)^(degree * k / N) X^(degree * k % N).
+            let image_degree = (degree * k) % (2 * poly_size);
+            if image_degree < poly_size {
+                output[image_degree] = *coef;
+            } else {
+                output[image_degree - poly_size] = coef.wrapping_neg();
+            }
+        }
+    }
+
     /// Adds multiple integer polynomials to the current one.
     ///
     /// # Examples
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-use crate::prelude::{MonomialDegree, PolynomialSize};
+use crate::prelude::{GaloisElement, MonomialDegree, PolynomialSize};
 
 use crate::commons::math::polynomial::Polynomial;
 use crate::commons::math::torus::UnsignedTorus;
@@ -94,6 +94,36 @@ fn test_multiply_karatsuba<T: UnsignedTorus>() {
     }
 }
 
+/// test if Galois elements which are equal modulo 2N give the same automorphism
+fn test_automorphism_galois_element_reduction<T: UnsignedTorus>() {
+    let mut rng = rand::thread_rng();
+    let mut generator = new_random_generator();
+
+    // random settings
+    let polynomial_log = (rng.gen::<usize>() % 7) + 6;
+    let polynomial_size = PolynomialSize(1 << polynomial_log);
+    let modulus = 2 * polynomial_size.0;
+
+    // generates a random Torus polynomial and a random odd Galois element
+    let poly = Polynomial::from_container(
+        generator
+            .random_uniform_tensor::<T>(polynomial_size.0)
+            .into_container(),
+    );
+    let k = (rng.gen::<usize>() % polynomial_size.0) * 2 + 1;
+
+    // computes the automorphism for k, k + 2N and a large element congruent to k
+    let mut reference = Polynomial::allocate(T::ZERO, polynomial_size);
+    reference.fill_with_automorphism(&poly, GaloisElement(k));
+    for element in [k + modulus, k + (usize::MAX / modulus - 1) * modulus] {
+        let mut output = Polynomial::allocate(T::ZERO, polynomial_size);
+        output.fill_with_automorphism(&poly, GaloisElement(element));
+
+        // test
+        assert_eq!(&output, &reference);
+    }
+}
+
 #[test]
 pub fn test_multiply_divide_unit_monomial_u32() {
     test_multiply_divide_unit_monomial::<u32>()
@@ -113,3 +143,13 @@ pub fn test_multiply_karatsuba_u32() {
 pub fn test_multiply_karatsuba_u64() {
     test_multiply_karatsuba::<u64>()
 }
+
+#[test]
+pub fn test_automorphism_galois_element_reduction_u32() {
+    test_automorphism_galois_element_reduction::<u32>()
+}
+
+#[test]
+pub fn test_automorphism_galois_element_reduction_u64() {
+    test_automorphism_galois_element_reduction::<u64>()
+}
@@ -39,6 +39,8 @@ macro_rules! modular_distance {
 pub mod crypto;
 pub mod math;
 pub mod numeric;
+#[cfg(feature = "__commons_serialization")]
+pub(crate) mod serialization;
 pub mod utils;
 
 #[doc(hidden)]
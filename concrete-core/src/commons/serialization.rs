@@ -0,0 +1,616 @@
+//! Utilities to serialize entities along with a self-describing header and a checksum.
+//!
+//! An entity serialized with a header is laid out as follows:
+//! + the 8 magic bytes `CCENTITY`,
+//! + the size of the header in bytes, as a little endian `u64`,
+//! + the header, which stores the kind, the precision and the parameters of the entity,
+//! + the size of the payload in bytes, as a little endian `u64`,
+//! + the payload, which contains the versioned entity,
+//! + the checksum of all the previous bytes, as a little endian `u64`.
+//!
+//! Entities serialized before the introduction of the header do not start with the magic bytes,
+//! and only contain the payload. They are rejected, unless the deserialization is asked to accept
+//! such legacy entities, in which case the payload is decoded without any check.
+//!
+//! This module also provides [`PackedIntegers`], used by the payloads of entities serialized in a
+//! compressed form.
+
+use crate::commons::numeric::{CastFrom, CastInto, UnsignedInteger};
+use crate::commons::utils::Checksum;
+use crate::specification::engines::EntityDeserializationError;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Read, Write};
+
+/// The magic bytes starting every entity serialized with a header.
+const MAGIC: [u8; 8] = *b"CCENTITY";
+
+/// A description of an entity, stored in the header of its serialized representation.
+pub(crate) trait EntityDescription {
+    /// The kind of the entity, which is shared by all its precisions and views.
+    const KIND: &'static str;
+    /// The precision of the entity, in bits.
+    const PRECISION: u32;
+
+    /// Returns the dimension parameters of the entity.
+    fn parameters(&self) -> Vec<usize>;
+}
+
+/// Implements [`EntityDescription`] for entities sharing the same kind, views being described as
+/// the entity they are serialized to.
+macro_rules! describe_entities {
+    ($($kind:literal => [$($entity:ty: $precision:literal),+] |$e:ident| $parameters:expr;)+) => {
+        $($(
+            impl $crate::commons::serialization::EntityDescription for $entity {
+                const KIND: &'static str = $kind;
+                const PRECISION: u32 = $precision;
+
+                fn parameters(&self) -> Vec<usize> {
+                    let $e = self;
+                    $parameters
+                }
+            }
+        )+)+
+    };
+}
+pub(crate) use describe_entities;
+
+/// The header of an entity serialized with [`serialize_into_with_header`].
+#[derive(Serialize, Deserialize)]
+struct EntityHeader {
+    kind: String,
+    precision: u32,
+    parameters: Vec<u64>,
+}
+
+impl EntityHeader {
+    fn describe<Entity: EntityDescription>(entity: &Entity) -> Self {
+        EntityHeader {
+            kind: Entity::KIND.to_owned(),
+            precision: Entity::PRECISION,
+            parameters: parameters_of(entity),
+        }
+    }
+}
+
+fn parameters_of<Entity: EntityDescription>(entity: &Entity) -> Vec<u64> {
+    entity
+        .parameters()
+        .into_iter()
+        .map(|parameter| parameter as u64)
+        .collect()
+}
+
+/// The parameters stored in the header of a serialized entity, which remain to be checked against
+/// the ones of the deserialized entity. Legacy entities, serialized without header, have no
+/// parameters to check.
+#[must_use]
+pub(crate) struct ParameterCheck(Option<Vec<u64>>);
+
+impl ParameterCheck {
+    /// Returns the deserialized entity if its parameters match the ones stored in the header.
+    pub(crate) fn verify<Entity, Error>(self, entity: Entity) -> Result<Entity, Error>
+    where
+        Entity: EntityDescription,
+        Error: From<EntityDeserializationError<bincode::Error>>,
+    {
+        if matches!(self.0, Some(ref parameters) if *parameters != parameters_of(&entity)) {
+            return Err(EntityDeserializationError::<bincode::Error>::ParameterMismatch.into());
+        }
+        Ok(entity)
+    }
+}
+
+/// A writer computing the checksum of the bytes written to it.
+struct ChecksumWriter<W> {
+    inner: W,
+    checksum: Checksum,
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.checksum.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A reader computing the checksum of the bytes read from it.
+struct ChecksumReader<R> {
+    inner: R,
+    checksum: Checksum,
+}
+
+impl<R: Read> Read for ChecksumReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.checksum.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// Serializes a payload describing `entity` into a writer, along with a header and a checksum.
+pub(crate) fn serialize_into_with_header<Writer, Entity, Payload>(
+    writer: Writer,
+    entity: &Entity,
+    payload: &Payload,
+) -> bincode::Result<()>
+where
+    Writer: Write,
+    Entity: EntityDescription,
+    Payload: Serialize,
+{
+    let header = bincode::serialize(&EntityHeader::describe(entity))?;
+    let payload_size = bincode::serialized_size(payload)?;
+    let mut writer = ChecksumWriter {
+        inner: writer,
+        checksum: Checksum::new(),
+    };
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&(header.len() as u64).to_le_bytes())?;
+    writer.write_all(&header)?;
+    writer.write_all(&payload_size.to_le_bytes())?;
+    bincode::serialize_into(&mut writer, payload)?;
+    let checksum = writer.checksum.finish();
+    writer.inner.write_all(&checksum.to_le_bytes())?;
+    Ok(())
+}
+
+/// Serializes a payload describing `entity` into a vector of bytes, along with a header and a
+/// checksum.
+pub(crate) fn serialize_with_header<Entity, Payload>(
+    entity: &Entity,
+    payload: &Payload,
+) -> bincode::Result<Vec<u8>>
+where
+    Entity: EntityDescription,
+    Payload: Serialize,
+{
+    let mut serialized = Vec::new();
+    serialize_into_with_header(&mut serialized, entity, payload)?;
+    Ok(serialized)
+}
+
+fn io_error(error: io::Error) -> EntityDeserializationError<bincode::Error> {
+    EntityDeserializationError::Engine(error.into())
+}
+
+/// The error returned when the reader ends before the end of the serialized entity.
+fn truncated() -> EntityDeserializationError<bincode::Error> {
+    io_error(io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        "The serialized entity is truncated.",
+    ))
+}
+
+/// Reads exactly `size` bytes into `bytes`, a missing byte meaning that the entity was truncated.
+fn read_bytes<R: Read>(
+    reader: &mut R,
+    size: u64,
+    bytes: &mut Vec<u8>,
+) -> Result<(), EntityDeserializationError<bincode::Error>> {
+    let read = reader.take(size).read_to_end(bytes).map_err(io_error)?;
+    if read as u64 != size {
+        return Err(truncated());
+    }
+    Ok(())
+}
+
+/// Reads a little endian `u64`, a missing byte meaning that the entity was truncated.
+fn read_u64<R: Read>(reader: &mut R) -> Result<u64, EntityDeserializationError<bincode::Error>> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes).map_err(io_error)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Deserializes the payload of an `Entity` from a reader, and verifies its header and checksum.
+///
+/// Only the bytes of the serialized entity are consumed from the reader. The parameters of the
+/// header must then be checked against the ones of the entity built from the payload.
+///
+/// Input which does not start with the magic bytes is rejected as an invalid header, unless
+/// `accept_legacy` is set, in which case it is decoded as the payload of a legacy entity,
+/// serialized without header. Input ending before the end of the serialized entity is reported as
+/// an `UnexpectedEof` I/O error.
+pub(crate) fn deserialize_from_with_header<Entity, Payload, Reader>(
+    mut reader: Reader,
+    accept_legacy: bool,
+) -> Result<(Payload, ParameterCheck), EntityDeserializationError<bincode::Error>>
+where
+    Entity: EntityDescription,
+    Payload: DeserializeOwned,
+    Reader: Read,
+{
+    let mut magic = Vec::with_capacity(MAGIC.len());
+    (&mut reader)
+        .take(MAGIC.len() as u64)
+        .read_to_end(&mut magic)
+        .map_err(io_error)?;
+    if accept_legacy && magic != MAGIC {
+        // A legacy entity only contains the payload, which starts with the bytes already read.
+        let payload = bincode::deserialize_from(magic.as_slice().chain(reader))
+            .map_err(EntityDeserializationError::Engine)?;
+        return Ok((payload, ParameterCheck(None)));
+    }
+    if !MAGIC.starts_with(&magic) {
+        return Err(EntityDeserializationError::InvalidHeader);
+    }
+    if magic.len() != MAGIC.len() {
+        return Err(truncated());
+    }
+
+    let mut checksum = Checksum::new();
+    checksum.update(&MAGIC);
+    let mut reader = ChecksumReader {
+        inner: reader,
+        checksum,
+    };
+    let header_size = read_u64(&mut reader)?;
+    let mut header = Vec::new();
+    read_bytes(&mut reader, header_size, &mut header)?;
+    let header = bincode::deserialize::<EntityHeader>(&header);
+    let payload_size = read_u64(&mut reader)?;
+
+    // The payload is only decoded if it has the requested kind, but it is always read to compute
+    // the checksum.
+    let requested_kind = (Entity::KIND, Entity::PRECISION);
+    let stored_kind = header
+        .as_ref()
+        .ok()
+        .map(|header| (header.kind.as_str(), header.precision));
+    let mut payload_reader = (&mut reader).take(payload_size);
+    let payload = if stored_kind == Some(requested_kind) {
+        Some(bincode::deserialize_from::<_, Payload>(&mut payload_reader))
+    } else {
+        None
+    };
+    io::copy(&mut payload_reader, &mut io::sink()).map_err(io_error)?;
+    if payload_reader.limit() != 0 {
+        return Err(truncated());
+    }
+    let computed_checksum = reader.checksum.finish();
+    let stored_checksum = read_u64(&mut reader.inner)?;
+
+    if stored_checksum != computed_checksum {
+        return Err(EntityDeserializationError::ChecksumMismatch);
+    }
+    let header = header.map_err(EntityDeserializationError::Engine)?;
+    EntityDeserializationError::<bincode::Error>::perform_generic_checks(
+        (header.kind.as_str(), header.precision),
+        requested_kind,
+    )?;
+    match payload {
+        Some(payload) => Ok((
+            payload.map_err(EntityDeserializationError::Engine)?,
+            ParameterCheck(Some(header.parameters)),
+        )),
+        None => Err(EntityDeserializationError::EntityKindMismatch),
+    }
+}
+
+/// Deserializes the payload of an `Entity` from a slice of bytes, and verifies its header and
+/// checksum.
+pub(crate) fn deserialize_with_header<Entity, Payload>(
+    serialized: &[u8],
+    accept_legacy: bool,
+) -> Result<(Payload, ParameterCheck), EntityDeserializationError<bincode::Error>>
+where
+    Entity: EntityDescription,
+    Payload: DeserializeOwned,
+{
+    deserialize_from_with_header::<Entity, Payload, _>(serialized, accept_legacy)
+}
+
+/// A list of unsigned integers whose `shift` least significant bits are dropped, the remaining
+/// `bit_width` bits of every integer being packed contiguously in 64 bits words.
+///
+/// Every integer takes at least one bit, so that the number of packed integers is bounded by the
+/// size of the packed words.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct PackedIntegers {
+    shift: u32,
+    bit_width: u32,
+    len: u64,
+    words: Vec<u64>,
+}
+
+impl PackedIntegers {
+    /// Packs integers after rounding them to the closest multiple of $2^{shift}$.
+    pub(crate) fn pack_rounded<Scalar>(values: &[Scalar], shift: usize) -> Self
+    where
+        Scalar: UnsignedInteger + CastInto<u64>,
+    {
+        debug_assert!(shift < Scalar::BITS);
+        let rounding = if shift == 0 {
+            Scalar::ZERO
+        } else {
+            Scalar::ONE << (shift - 1)
+        };
+        Self::pack(
+            values
+                .iter()
+                .map(|value| value.wrapping_add(rounding) >> shift),
+            shift,
+            Scalar::BITS - shift,
+        )
+    }
+
+    /// Packs integers without loss, by dropping the low and high bits which are zero in all of
+    /// them.
+    pub(crate) fn pack_lossless<Scalar>(values: &[Scalar]) -> Self
+    where
+        Scalar: UnsignedInteger + CastInto<u64>,
+    {
+        let used_bits: u64 = values
+            .iter()
+            .fold(Scalar::ZERO, |acc, value| acc | *value)
+            .cast_into();
+        if used_bits == 0 {
+            return Self::pack(values.iter().copied(), 0, 1);
+        }
+        let shift = used_bits.trailing_zeros() as usize;
+        let bit_width = u64::BITS as usize - used_bits.leading_zeros() as usize - shift;
+        Self::pack(values.iter().map(|value| *value >> shift), shift, bit_width)
+    }
+
+    fn pack<Scalar, Values>(values: Values, shift: usize, bit_width: usize) -> Self
+    where
+        Scalar: CastInto<u64>,
+        Values: ExactSizeIterator<Item = Scalar>,
+    {
+        let len = values.len();
+        let mut words = vec![0u64; (len * bit_width + 63) / 64];
+        if bit_width != 0 {
+            for (index, value) in values.enumerate() {
+                let value: u64 = value.cast_into();
+                let (word, offset) = ((index * bit_width) / 64, (index * bit_width) % 64);
+                words[word] |= value << offset;
+                if offset + bit_width > 64 {
+                    words[word + 1] |= value >> (64 - offset);
+                }
+            }
+        }
+        PackedIntegers {
+            shift: shift as u32,
+            bit_width: bit_width as u32,
+            len: len as u64,
+            words,
+        }
+    }
+
+    /// Checks that `len` integers are packed, `len` being `None` when the number of integers
+    /// expected by an entity overflows.
+    ///
+    /// This only reads the packed integers, and allows to validate the dimensions of an entity
+    /// before allocating it.
+    pub(crate) fn check_len(&self, len: Option<usize>) -> bincode::Result<()> {
+        use serde::de::Error;
+        let bit_width = self.bit_width as usize;
+        let expected_words = len
+            .and_then(|len| len.checked_mul(bit_width))
+            .map(|bits| bits / 64 + usize::from(bits % 64 != 0));
+        if len.map(|len| len as u64) != Some(self.len)
+            || expected_words != Some(self.words.len())
+            || (bit_width == 0 && self.len != 0)
+        {
+            return Err(bincode::Error::custom(
+                "The number of packed integers does not match the entity size.",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Unpacks the integers into `output`, whose length must match the number of packed integers.
+    pub(crate) fn unpack_into<Scalar>(&self, output: &mut [Scalar]) -> bincode::Result<()>
+    where
+        Scalar: UnsignedInteger + CastFrom<u64>,
+    {
+        use serde::de::Error;
+        let (shift, bit_width) = (self.shift as usize, self.bit_width as usize);
+        if shift + bit_width > Scalar::BITS {
+            return Err(bincode::Error::custom(
+                "The packed integers do not fit in the entity scalars.",
+            ));
+        }
+        self.check_len(Some(output.len()))?;
+        if output.is_empty() {
+            return Ok(());
+        }
+        let mask = u64::MAX >> (64 - bit_width);
+        for (index, output) in output.iter_mut().enumerate() {
+            let (word, offset) = ((index * bit_width) / 64, (index * bit_width) % 64);
+            let mut value = self.words[word] >> offset;
+            if offset + bit_width > 64 {
+                value |= self.words[word + 1] << (64 - offset);
+            }
+            *output = Scalar::cast_from(value & mask) << shift;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Vector(Vec<u32>);
+
+    impl EntityDescription for Vector {
+        const KIND: &'static str = "Vector";
+        const PRECISION: u32 = 32;
+
+        fn parameters(&self) -> Vec<usize> {
+            vec![self.0.len()]
+        }
+    }
+
+    struct Scalar;
+
+    impl EntityDescription for Scalar {
+        const KIND: &'static str = "Scalar";
+        const PRECISION: u32 = 32;
+
+        fn parameters(&self) -> Vec<usize> {
+            vec![]
+        }
+    }
+
+    fn deserialize(
+        serialized: &[u8],
+    ) -> Result<Vector, EntityDeserializationError<bincode::Error>> {
+        let (payload, check) = deserialize_with_header::<Vector, Vec<u32>>(serialized, false)?;
+        check.verify(Vector(payload))
+    }
+
+    fn is_truncated(result: &Result<Vector, EntityDeserializationError<bincode::Error>>) -> bool {
+        match result {
+            Err(EntityDeserializationError::Engine(error)) => matches!(
+                **error,
+                bincode::ErrorKind::Io(ref error) if error.kind() == io::ErrorKind::UnexpectedEof
+            ),
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_serialization_with_header_roundtrip() {
+        let vector = Vector(vec![1, 2, 3, 4]);
+        let mut serialized = serialize_with_header(&vector, &vector.0).unwrap();
+        assert_eq!(deserialize(&serialized).unwrap(), vector);
+
+        // Only the bytes of the entity are consumed from the reader.
+        let length = serialized.len();
+        serialized.extend_from_slice(&serialized.clone());
+        let mut reader = serialized.as_slice();
+        for _ in 0..2 {
+            let (payload, check) =
+                deserialize_from_with_header::<Vector, Vec<u32>, _>(&mut reader, false).unwrap();
+            assert_eq!(
+                check
+                    .verify::<_, EntityDeserializationError<bincode::Error>>(Vector(payload))
+                    .unwrap(),
+                vector
+            );
+            assert_eq!(reader.len() % length, 0);
+        }
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_serialization_without_header() {
+        let vector = Vector(vec![1, 2, 3, 4]);
+        let serialized = bincode::serialize(&vector.0).unwrap();
+        assert!(matches!(
+            deserialize(&serialized),
+            Err(EntityDeserializationError::InvalidHeader)
+        ));
+
+        // Legacy entities are decoded without check when they are accepted.
+        let (payload, check) =
+            deserialize_with_header::<Vector, Vec<u32>>(&serialized, true).unwrap();
+        assert_eq!(
+            check
+                .verify::<_, EntityDeserializationError<bincode::Error>>(Vector(payload))
+                .unwrap(),
+            vector
+        );
+        // Entities serialized with a header are still verified.
+        let mut corrupted = serialize_with_header(&vector, &vector.0).unwrap();
+        *corrupted.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            deserialize_with_header::<Vector, Vec<u32>>(&corrupted, true),
+            Err(EntityDeserializationError::ChecksumMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_serialization_with_header_errors() {
+        let vector = Vector(vec![1, 2, 3, 4]);
+        let serialized = serialize_with_header(&vector, &vector.0).unwrap();
+
+        for index in 0..MAGIC.len() {
+            let mut corrupted = serialized.clone();
+            corrupted[index] ^= 1;
+            assert!(matches!(
+                deserialize(&corrupted),
+                Err(EntityDeserializationError::InvalidHeader)
+            ));
+        }
+        // A corrupted size can make the entity look longer than the input, which is then reported
+        // as truncated.
+        let header_size = u64::from_le_bytes(serialized[8..16].try_into().unwrap()) as usize;
+        let payload_size_index = 16 + header_size;
+        let sizes = [8..16, payload_size_index..payload_size_index + 8];
+        for index in MAGIC.len()..serialized.len() {
+            let mut corrupted = serialized.clone();
+            corrupted[index] ^= 1;
+            let result = deserialize(&corrupted);
+            if sizes.iter().any(|size| size.contains(&index)) && is_truncated(&result) {
+                continue;
+            }
+            assert!(matches!(
+                result,
+                Err(EntityDeserializationError::ChecksumMismatch)
+            ));
+        }
+        for length in 0..serialized.len() {
+            assert!(is_truncated(&deserialize(&serialized[..length])));
+        }
+
+        let scalar = serialize_with_header(&Scalar, &vector.0).unwrap();
+        assert!(matches!(
+            deserialize(&scalar),
+            Err(EntityDeserializationError::EntityKindMismatch)
+        ));
+
+        let inconsistent = serialize_with_header(&Vector(vec![1, 2]), &vector.0).unwrap();
+        assert!(matches!(
+            deserialize(&inconsistent),
+            Err(EntityDeserializationError::ParameterMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_packed_integers_roundtrip() {
+        let values: Vec<u32> = vec![0, 1 << 12, 3 << 12, 1023 << 12, 5 << 12];
+        let packed = PackedIntegers::pack_lossless(&values);
+        assert_eq!((packed.shift, packed.bit_width), (12, 10));
+        assert_eq!(packed.words.len(), 1);
+        let mut unpacked = vec![0u32; values.len()];
+        packed.unpack_into(&mut unpacked).unwrap();
+        assert_eq!(unpacked, values);
+
+        let zeros = PackedIntegers::pack_lossless(&[0u64; 7]);
+        assert_eq!((zeros.shift, zeros.bit_width), (0, 1));
+        assert_eq!(zeros.words, vec![0]);
+        let mut unpacked = vec![1u64; 7];
+        zeros.unpack_into(&mut unpacked).unwrap();
+        assert_eq!(unpacked, vec![0u64; 7]);
+
+        let values: Vec<u64> = (0..100u64)
+            .map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15))
+            .collect();
+        for shift in [0, 1, 17, 63] {
+            let packed = PackedIntegers::pack_rounded(&values, shift);
+            let mut unpacked = vec![0u64; values.len()];
+            packed.unpack_into(&mut unpacked).unwrap();
+            for (value, unpacked) in values.iter().zip(unpacked.iter()) {
+                let error = value.wrapping_sub(*unpacked) as i64;
+                assert!(error.unsigned_abs() <= (1u64 << shift) / 2);
+                assert!(unpacked.trailing_zeros() as usize >= shift);
+            }
+        }
+    }
+
+    #[test]
+    fn test_packed_integers_errors() {
+        let packed = PackedIntegers::pack_rounded(&[1u64 << 40, 3 << 40], 8);
+        assert!(packed.unpack_into(&mut [0u64; 3]).is_err());
+        assert!(packed.unpack_into(&mut [0u32; 2]).is_err());
+    }
+}
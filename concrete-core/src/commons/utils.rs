@@ -87,6 +87,41 @@ macro_rules! izip {
 #[allow(unused_imports)]
 pub(crate) use izip;
 
+/// A 64 bits FNV-1a checksum, used to detect the corruption of serialized entities.
+///
+/// The checksum can be fed incrementally, which allows to compute it on streamed data.
+#[cfg(feature = "__commons_serialization")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Checksum(u64);
+
+#[cfg(feature = "__commons_serialization")]
+impl Checksum {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    pub(crate) fn new() -> Self {
+        Checksum(Self::OFFSET_BASIS)
+    }
+
+    /// Feeds the checksum with new bytes.
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    pub(crate) fn finish(self) -> u64 {
+        self.0
+    }
+
+    /// Computes the checksum of a slice of bytes.
+    pub(crate) fn of(bytes: &[u8]) -> u64 {
+        let mut checksum = Self::new();
+        checksum.update(bytes);
+        checksum.finish()
+    }
+}
+
 #[cfg(test)]
 mod test {
     #![allow(clippy::many_single_char_names)]
@@ -125,4 +160,19 @@ mod test {
             (3, (6, (9, (12, (15, (18, 21))))))
         );
     }
+
+    #[cfg(feature = "__commons_serialization")]
+    #[test]
+    fn test_checksum() {
+        use super::Checksum;
+        // Reference values of the 64 bits FNV-1a hash.
+        assert_eq!(Checksum::of(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(Checksum::of(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(Checksum::of(b"foobar"), 0x8594_4171_f739_67e8);
+
+        let mut checksum = Checksum::new();
+        checksum.update(b"foo");
+        checksum.update(b"bar");
+        assert_eq!(checksum.finish(), Checksum::of(b"foobar"));
+    }
 }
@@ -16,6 +16,12 @@ pub use super::backends::fft::engines::*;
 #[cfg(feature = "backend_fft")]
 pub use super::backends::fft::entities::*;
 
+// --------------------------------------------------------------------------------- NTT BACKEND
+#[cfg(feature = "backend_ntt")]
+pub use super::backends::ntt::engines::*;
+#[cfg(feature = "backend_ntt")]
+pub use super::backends::ntt::entities::*;
+
 // ------------------------------------------------------------------------------------ CUDA BACKEND
 #[cfg(feature = "backend_cuda")]
 pub use super::backends::cuda::engines::*;
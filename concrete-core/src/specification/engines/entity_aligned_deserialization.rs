@@ -0,0 +1,55 @@
+use super::engine_error;
+use crate::prelude::{AbstractEntity, ChecksumVerification};
+use crate::specification::engines::AbstractEngine;
+
+engine_error! {
+    EntityAlignedDeserializationError for EntityAlignedDeserializationEngine @
+    ChecksumMismatch => "The checksum of the serialized entity does not match its content.",
+    EntityKindMismatch => "The serialized entity is not of the kind of the requested entity."
+}
+
+impl<EngineError: std::error::Error> EntityAlignedDeserializationError<EngineError> {
+    /// Validates the kind stored in the header of a serialized entity against the requested one.
+    pub fn perform_generic_checks<Kind>(stored_kind: Kind, requested_kind: Kind) -> Result<(), Self>
+    where
+        Kind: PartialEq,
+    {
+        if stored_kind != requested_kind {
+            return Err(Self::EntityKindMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines deserializing entities serialized with an aligned layout.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a view entity borrowing the
+/// `serialized` data, which was laid out by an
+/// [`EntityAlignedSerializationEngine`](super::EntityAlignedSerializationEngine). The header of
+/// the serialized entity is always validated, but its checksum is only verified if
+/// `checksum_verification` is set, as this requires to read the whole serialized data.
+pub trait EntityAlignedDeserializationEngine<Serialized, Entity>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Deserializes an entity serialized with an aligned layout.
+    fn deserialize_aligned(
+        &mut self,
+        serialized: Serialized,
+        checksum_verification: ChecksumVerification,
+    ) -> Result<Entity, EntityAlignedDeserializationError<Self::EngineError>>;
+
+    /// Unsafely deserializes an entity serialized with an aligned layout.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityAlignedDeserializationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn deserialize_aligned_unchecked(
+        &mut self,
+        serialized: Serialized,
+        checksum_verification: ChecksumVerification,
+    ) -> Entity;
+}
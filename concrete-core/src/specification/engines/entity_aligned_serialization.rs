@@ -0,0 +1,33 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+
+engine_error! {
+    EntityAlignedSerializationError for EntityAlignedSerializationEngine @
+}
+
+/// A trait for engines serializing entities with an aligned layout.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a serialized version of the `entity`
+/// laid out so that it can later be deserialized into a view entity, borrowing the serialized
+/// data instead of copying it. The exact layout is documented by the implementing engine.
+pub trait EntityAlignedSerializationEngine<Entity, Serialized>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Serializes an entity with an aligned layout.
+    fn serialize_aligned(
+        &mut self,
+        entity: &Entity,
+    ) -> Result<Serialized, EntityAlignedSerializationError<Self::EngineError>>;
+
+    /// Unsafely serializes an entity with an aligned layout.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityAlignedSerializationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn serialize_aligned_unchecked(&mut self, entity: &Entity) -> Serialized;
+}
@@ -4,6 +4,24 @@ use crate::specification::engines::AbstractEngine;
 
 engine_error! {
     EntityDeserializationError for EntityDeserializationEngine @
+    InvalidHeader => "The serialized entity does not start with a valid header.",
+    ChecksumMismatch => "The checksum of the serialized entity does not match its content.",
+    EntityKindMismatch => "The serialized entity is not of the kind of the requested entity.",
+    ParameterMismatch => "The parameters of the serialized entity do not match the ones stored in \
+                          its header."
+}
+
+impl<EngineError: std::error::Error> EntityDeserializationError<EngineError> {
+    /// Validates the kind stored in the header of a serialized entity against the requested one.
+    pub fn perform_generic_checks<Kind>(stored_kind: Kind, requested_kind: Kind) -> Result<(), Self>
+    where
+        Kind: PartialEq,
+    {
+        if stored_kind != requested_kind {
+            return Err(Self::EntityKindMismatch);
+        }
+        Ok(())
+    }
 }
 
 /// A trait for engines deserializing entities.
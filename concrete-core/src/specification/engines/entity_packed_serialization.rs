@@ -0,0 +1,34 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+
+engine_error! {
+    EntityPackedSerializationError for EntityPackedSerializationEngine @
+}
+
+/// A trait for engines serializing entities with bit-packed coefficients.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a serialized version of the `entity`
+/// in which the coefficients are packed on the smallest number of bits able to represent all of
+/// them. The serialization is lossless, and is the most effective when the coefficients live in a
+/// modulus smaller than the one of the entity scalars.
+pub trait EntityPackedSerializationEngine<Entity, Serialized>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Serializes an entity with bit-packed coefficients.
+    fn serialize_packed(
+        &mut self,
+        entity: &Entity,
+    ) -> Result<Serialized, EntityPackedSerializationError<Self::EngineError>>;
+
+    /// Unsafely serializes an entity with bit-packed coefficients.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityPackedSerializationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn serialize_packed_unchecked(&mut self, entity: &Entity) -> Serialized;
+}
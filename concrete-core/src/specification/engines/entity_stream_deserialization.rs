@@ -0,0 +1,53 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+use std::io::Read;
+
+engine_error! {
+    EntityStreamDeserializationError for EntityStreamDeserializationEngine @
+    InvalidHeader => "The serialized entity does not start with a valid header.",
+    ChecksumMismatch => "The checksum of the serialized entity does not match its content.",
+    EntityKindMismatch => "The serialized entity is not of the kind of the requested entity.",
+    ParameterMismatch => "The parameters of the serialized entity do not match the ones stored in \
+                          its header."
+}
+
+impl<EngineError: std::error::Error> EntityStreamDeserializationError<EngineError> {
+    /// Validates the kind stored in the header of a serialized entity against the requested one.
+    pub fn perform_generic_checks<Kind>(stored_kind: Kind, requested_kind: Kind) -> Result<(), Self>
+    where
+        Kind: PartialEq,
+    {
+        if stored_kind != requested_kind {
+            return Err(Self::EntityKindMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines deserializing entities from a reader.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an entity containing the
+/// deserialization of the bytes read from `reader`. Only the bytes of the serialized entity are
+/// consumed from the reader, which allows to read several entities in a row from the same stream.
+pub trait EntityStreamDeserializationEngine<Reader, Entity>: AbstractEngine
+where
+    Reader: Read,
+    Entity: AbstractEntity,
+{
+    /// Deserializes an entity from a reader.
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<Entity, EntityStreamDeserializationError<Self::EngineError>>;
+
+    /// Unsafely deserializes an entity from a reader.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityStreamDeserializationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> Entity;
+}
@@ -0,0 +1,37 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+use std::io::Write;
+
+engine_error! {
+    EntityStreamSerializationError for EntityStreamSerializationEngine @
+}
+
+/// A trait for engines serializing entities into a writer.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation writes the serialization of `entity` to
+/// `writer`, without materializing it in memory first. The bytes written are the same as the ones
+/// produced by the [`EntitySerializationEngine`](super::EntitySerializationEngine) of the same
+/// engine.
+pub trait EntityStreamSerializationEngine<Entity, Writer>: AbstractEngine
+where
+    Entity: AbstractEntity,
+    Writer: Write,
+{
+    /// Serializes an entity into a writer.
+    fn serialize_into(
+        &mut self,
+        entity: &Entity,
+        writer: Writer,
+    ) -> Result<(), EntityStreamSerializationError<Self::EngineError>>;
+
+    /// Unsafely serializes an entity into a writer.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityStreamSerializationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn serialize_into_unchecked(&mut self, entity: &Entity, writer: Writer);
+}
@@ -0,0 +1,60 @@
+use super::engine_error;
+use crate::prelude::{AbstractEntity, TruncatedBitCount};
+use crate::specification::engines::AbstractEngine;
+
+engine_error! {
+    EntityTruncatedSerializationError for EntityTruncatedSerializationEngine @
+    TruncatedBitCountTooLarge => "The number of truncated bits must be smaller than the bit width \
+                                  of the entity scalars."
+}
+
+impl<EngineError: std::error::Error> EntityTruncatedSerializationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        truncated_bit_count: TruncatedBitCount,
+        scalar_bits: usize,
+    ) -> Result<(), Self> {
+        if truncated_bit_count.0 >= scalar_bits {
+            return Err(Self::TruncatedBitCountTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines serializing entities with a truncated body.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a serialized version of the `entity`
+/// in which the `truncated_bit_count` least significant bits of every body coefficient are
+/// dropped, after rounding. The serialization is lossy: deserializing it yields an entity whose
+/// body coefficients are rounded to the closest multiple of $2^t$, with $t$ the
+/// `truncated_bit_count`. This is harmless as long as the dropped bits only contain noise.
+///
+/// Each body coefficient is moved by at most $2^{t-1}$, which amounts to adding a uniform noise of
+/// variance $2^{2t} / 12$ to the body of every ciphertext composing the entity. The noise added by
+/// a keyswitch or a bootstrap performed with a truncated key grows accordingly, as if the key had
+/// been generated with this extra variance.
+pub trait EntityTruncatedSerializationEngine<Entity, Serialized>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Serializes an entity with a truncated body.
+    fn serialize_truncated(
+        &mut self,
+        entity: &Entity,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Result<Serialized, EntityTruncatedSerializationError<Self::EngineError>>;
+
+    /// Unsafely serializes an entity with a truncated body.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityTruncatedSerializationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn serialize_truncated_unchecked(
+        &mut self,
+        entity: &Entity,
+        truncated_bit_count: TruncatedBitCount,
+    ) -> Serialized;
+}
@@ -0,0 +1,39 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GgswCiphertextVectorEntity;
+
+engine_error! {
+    GgswCiphertextVectorConsumingRetrievalError for GgswCiphertextVectorConsumingRetrievalEngine @
+}
+
+/// A trait for engines retrieving the content of the container from a GGSW ciphertext
+/// vector consuming it in the process.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation retrieves the content of the container from the
+/// `input` GGSW ciphertext vector consuming it in the process.
+pub trait GgswCiphertextVectorConsumingRetrievalEngine<CiphertextVector, Container>:
+    AbstractEngine
+where
+    CiphertextVector: GgswCiphertextVectorEntity,
+{
+    /// Retrieves the content of the container from a GGSW ciphertext vector, consuming it in the
+    /// process.
+    fn consume_retrieve_ggsw_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: CiphertextVector,
+    ) -> Result<Container, GgswCiphertextVectorConsumingRetrievalError<Self::EngineError>>;
+
+    /// Unsafely retrieves the content of the container from a GGSW ciphertext vector, consuming
+    /// it in the process.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswCiphertextVectorConsumingRetrievalError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn consume_retrieve_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        ciphertext_vector: CiphertextVector,
+    ) -> Container;
+}
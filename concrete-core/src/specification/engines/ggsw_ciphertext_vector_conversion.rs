@@ -0,0 +1,36 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GgswCiphertextVectorEntity;
+
+engine_error! {
+    GgswCiphertextVectorConversionError for GgswCiphertextVectorConversionEngine @
+}
+
+/// A trait for engines converting GGSW ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GGSW ciphertext vector containing
+/// the conversion of the `input` GGSW ciphertext vector to a type with a different representation
+/// (for instance from standard to Fourier domain).
+///
+/// # Formal Definition
+pub trait GgswCiphertextVectorConversionEngine<Input, Output>: AbstractEngine
+where
+    Input: GgswCiphertextVectorEntity,
+    Output: GgswCiphertextVectorEntity,
+{
+    /// Converts a GGSW ciphertext vector.
+    fn convert_ggsw_ciphertext_vector(
+        &mut self,
+        input: &Input,
+    ) -> Result<Output, GgswCiphertextVectorConversionError<Self::EngineError>>;
+
+    /// Unsafely converts a GGSW ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswCiphertextVectorConversionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn convert_ggsw_ciphertext_vector_unchecked(&mut self, input: &Input) -> Output;
+}
@@ -0,0 +1,69 @@
+use super::engine_error;
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, Variance};
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GgswCiphertextVectorEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    GgswCiphertextVectorScalarEncryptionError for GgswCiphertextVectorScalarEncryptionEngine @
+    EmptyInput => "The input plaintext vector must contain at least one plaintext."
+}
+
+impl<EngineError: std::error::Error> GgswCiphertextVectorScalarEncryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<PlaintextVector>(input: &PlaintextVector) -> Result<(), Self>
+    where
+        PlaintextVector: PlaintextVectorEntity,
+    {
+        if input.plaintext_count().0 == 0 {
+            return Err(Self::EmptyInput);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines encrypting GGSW ciphertext vectors, each ciphertext containing a single
+/// plaintext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GGSW ciphertext vector whose
+/// $i$-th ciphertext contains the encryption of the $i$-th plaintext of the `input` plaintext
+/// vector, under the `key` secret key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::GgswCiphertextScalarEncryptionEngine`)
+pub trait GgswCiphertextVectorScalarEncryptionEngine<SecretKey, PlaintextVector, CiphertextVector>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    CiphertextVector: GgswCiphertextVectorEntity,
+{
+    /// Encrypts a plaintext vector into a GGSW ciphertext vector.
+    fn encrypt_scalar_ggsw_ciphertext_vector(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<CiphertextVector, GgswCiphertextVectorScalarEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a plaintext vector into a GGSW ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswCiphertextVectorScalarEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> CiphertextVector;
+}
@@ -0,0 +1,40 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GgswCiphertextEntity, GgswSeededCiphertextEntity};
+
+engine_error! {
+    GgswSeededCiphertextConversionError for GgswSeededCiphertextConversionEngine @
+}
+
+/// A trait for engines converting seeded GGSW ciphertexts into GGSW ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GGSW ciphertext containing the
+/// expansion of the `input` seeded GGSW ciphertext, in a representation which may differ from the
+/// standard one (for instance in the Fourier domain).
+///
+/// # Formal Definition
+///
+/// The masks of the seeded ciphertext are regenerated as described in the
+/// [`transformation`](`super::GgswSeededCiphertextToGgswCiphertextTransformationEngine`) of seeded
+/// GGSW ciphertexts, and the resulting GGSW ciphertext is converted to the output representation.
+pub trait GgswSeededCiphertextConversionEngine<Input, Output>: AbstractEngine
+where
+    Input: GgswSeededCiphertextEntity,
+    Output: GgswCiphertextEntity,
+{
+    /// Converts a seeded GGSW ciphertext.
+    fn convert_ggsw_seeded_ciphertext(
+        &mut self,
+        input: &Input,
+    ) -> Result<Output, GgswSeededCiphertextConversionError<Self::EngineError>>;
+
+    /// Unsafely converts a seeded GGSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswSeededCiphertextConversionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn convert_ggsw_seeded_ciphertext_unchecked(&mut self, input: &Input) -> Output;
+}
@@ -0,0 +1,71 @@
+use super::engine_error;
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, Variance};
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GgswSeededCiphertextEntity, GlweSecretKeyEntity, PlaintextEntity,
+};
+
+engine_error! {
+    GgswSeededCiphertextScalarEncryptionError for GgswSeededCiphertextScalarEncryptionEngine @
+}
+
+/// A trait for engines encrypting seeded GGSW ciphertexts containing a single plaintext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a seeded GGSW ciphertext
+/// containing the encryption of the `input` plaintext, under the `key` secret key.
+///
+/// # Formal Definition
+///
+/// ## Seeded GGSW Encryption
+/// ###### inputs:
+/// - $\mathsf{PT}\in\mathbb{Z}\_q$: a plaintext
+/// - $\vec{S} \in\mathcal{R}\_q^k$: a secret key
+/// - $\mathsf{seed} \in\mathcal{S}$: a public seed
+/// - $G$: a CSPRNG working with seeds from $\mathcal{S}$
+/// - $\mathcal{D\_{\sigma^2,\mu}}$: a normal distribution of variance $\sigma^2$ and a mean $\mu$
+/// - $\ell$: the number of levels of the decomposition
+/// - $\beta$: the base of the decomposition
+///
+/// ###### outputs:
+/// - $\tilde{\overline{\overline{\mathsf{CT}}}} \in \mathsf{SeededGGSW}\_{\vec{S}, G}^{\beta,
+///   \ell}( \mathsf{PT} )$: a seeded GGSW ciphertext
+///
+/// ###### algorithm:
+/// 1. generate the $(k+1)\cdot\ell$ GLWE ciphertexts of a GGSW encryption of $\mathsf{PT}$, as
+/// in the [`GGSW encryption`](`super::GgswCiphertextScalarEncryptionEngine`), sampling every
+/// mask $\vec{A}$ from $G$ with the seed $\mathsf{seed}$
+/// 2. output $\mathsf{seed}$ along with the bodies $B$ of the $(k+1)\cdot\ell$ GLWE ciphertexts
+pub trait GgswSeededCiphertextScalarEncryptionEngine<SecretKey, Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: GgswSeededCiphertextEntity,
+{
+    /// Encrypts a plaintext into a seeded GGSW ciphertext.
+    fn encrypt_scalar_ggsw_seeded_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<Ciphertext, GgswSeededCiphertextScalarEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a plaintext into a seeded GGSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswSeededCiphertextScalarEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn encrypt_scalar_ggsw_seeded_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Ciphertext;
+}
@@ -0,0 +1,61 @@
+use super::engine_error;
+use crate::prelude::AbstractEngine;
+
+use crate::specification::entities::{GgswCiphertextEntity, GgswSeededCiphertextEntity};
+
+engine_error! {
+    GgswSeededCiphertextToGgswCiphertextTransformationError for GgswSeededCiphertextToGgswCiphertextTransformationEngine @
+}
+
+/// A trait for engines transforming GGSW seeded ciphertexts into GGSW ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation moves the existing GGSW seeded ciphertext into
+/// a GGSW ciphertext.
+///
+/// # Formal Definition
+///
+/// ## GGSW seeded ciphertext to GGSW ciphertext transformation
+/// ###### inputs:
+/// - $G$: a CSPRNG working with seeds from $\mathcal{S}$
+/// - $\tilde{\overline{\overline{\mathsf{CT}}}} \in \mathsf{SeededGGSW}\_{\vec{S}, G}^{\beta,
+///   \ell}( \mathsf{PT} )$: a seeded GGSW ciphertext, made of a seed $\mathsf{seed}\in\mathcal{S}$
+///   and of the bodies of $(k+1)\cdot\ell$ GLWE ciphertexts
+///
+/// ###### outputs:
+/// - $\overline{\overline{\mathsf{CT}}} \in \mathsf{GGSW}\_{\vec{S}}^{\beta, \ell}( \mathsf{PT}
+///   )$: a GGSW ciphertext
+///
+/// ###### algorithm:
+/// 1. for each of the $(k+1)\cdot\ell$ GLWE bodies $B$, in order, uniformly sample each
+/// coefficient of the polynomial vector $\vec{A}\in\mathcal{R}^k\_q$ from $G$ with the seed
+/// $\mathsf{seed}\in\mathcal{S}$
+/// 2. output the $(k+1)\cdot\ell$ GLWE ciphertexts $\left( \vec{A} , B \right)$
+pub trait GgswSeededCiphertextToGgswCiphertextTransformationEngine<
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext: GgswSeededCiphertextEntity,
+    OutputCiphertext: GgswCiphertextEntity,
+{
+    /// Does the transformation of the GGSW seeded ciphertext into a GGSW ciphertext
+    fn transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext(
+        &mut self,
+        ggsw_seeded_ciphertext: InputCiphertext,
+    ) -> Result<
+        OutputCiphertext,
+        GgswSeededCiphertextToGgswCiphertextTransformationError<Self::EngineError>,
+    >;
+
+    /// Unsafely transforms a GGSW seeded ciphertext into a GGSW ciphertext
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswSeededCiphertextToGgswCiphertextTransformationError`].
+    /// For safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext_unchecked(
+        &mut self,
+        ggsw_seeded_ciphertext: InputCiphertext,
+    ) -> OutputCiphertext;
+}
@@ -0,0 +1,36 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweAutomorphismKeyEntity;
+
+engine_error! {
+    GlweAutomorphismKeyConversionError for GlweAutomorphismKeyConversionEngine @
+}
+
+/// A trait for engines converting GLWE automorphism keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE automorphism key containing
+/// the conversion of the `input` GLWE automorphism key to a type with a different representation
+/// (for instance from the standard to the Fourier domain).
+///
+/// # Formal Definition
+pub trait GlweAutomorphismKeyConversionEngine<Input, Output>: AbstractEngine
+where
+    Input: GlweAutomorphismKeyEntity,
+    Output: GlweAutomorphismKeyEntity,
+{
+    /// Converts a GLWE automorphism key.
+    fn convert_glwe_automorphism_key(
+        &mut self,
+        input: &Input,
+    ) -> Result<Output, GlweAutomorphismKeyConversionError<Self::EngineError>>;
+
+    /// Unsafely converts a GLWE automorphism key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweAutomorphismKeyConversionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn convert_glwe_automorphism_key_unchecked(&mut self, input: &Input) -> Output;
+}
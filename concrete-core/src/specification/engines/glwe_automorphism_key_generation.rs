@@ -0,0 +1,85 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, Variance};
+use crate::specification::entities::{GlweAutomorphismKeyEntity, GlweSecretKeyEntity};
+
+engine_error! {
+    GlweAutomorphismKeyGenerationError for GlweAutomorphismKeyGenerationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext.",
+    PolynomialSizeNotPowerOfTwo => "The polynomial size of the key must be a power of two."
+}
+
+impl<EngineError: std::error::Error> GlweAutomorphismKeyGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey>(
+        key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        ciphertext_modulus_log: usize,
+    ) -> Result<(), Self>
+    where
+        SecretKey: GlweSecretKeyEntity,
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > ciphertext_modulus_log {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        if !key.polynomial_size().0.is_power_of_two() {
+            return Err(Self::PolynomialSizeNotPowerOfTwo);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new GLWE automorphism keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new GLWE automorphism key for the
+/// `key` GLWE secret key, allowing to evaluate the automorphisms $X \mapsto X^{2^j+1}$ on the
+/// ciphertexts encrypted under this key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::GlweAutomorphismKeyEntity`)
+pub trait GlweAutomorphismKeyGenerationEngine<SecretKey, AutomorphismKey>: AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+{
+    /// Generates a new GLWE automorphism key.
+    fn generate_new_glwe_automorphism_key(
+        &mut self,
+        key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<AutomorphismKey, GlweAutomorphismKeyGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new GLWE automorphism key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweAutomorphismKeyGenerationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn generate_new_glwe_automorphism_key_unchecked(
+        &mut self,
+        key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> AutomorphismKey;
+}
@@ -0,0 +1,103 @@
+use super::engine_error;
+use crate::prelude::{GlweDimension, MessageBitsCount, PaddingBitsCount, PolynomialSize};
+
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+
+engine_error! {
+    GlweCiphertextAccumulatorGenerationError for GlweCiphertextAccumulatorGenerationEngine @
+    NullMessageBitsCount => "The number of message bits must be greater than zero.",
+    NullPaddingBitsCount => "At least one bit of padding is required.",
+    BitsCountTooLarge => "The number of message and padding bits must not exceed the precision \
+                          of the ciphertext.",
+    PolynomialSizeNotPowerOfTwo => "The polynomial size must be a power of two.",
+    PolynomialSizeTooSmall => "The polynomial size must be greater or equal to the number of \
+                               boxes of the lookup table (2^(message bits + padding bits - 1))."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextAccumulatorGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        polynomial_size: PolynomialSize,
+        ciphertext_modulus_log: usize,
+    ) -> Result<(), Self> {
+        if message_bits.0 == 0 {
+            return Err(Self::NullMessageBitsCount);
+        }
+
+        if padding_bits.0 == 0 {
+            return Err(Self::NullPaddingBitsCount);
+        }
+
+        if message_bits.0 + padding_bits.0 > ciphertext_modulus_log {
+            return Err(Self::BitsCountTooLarge);
+        }
+
+        if !polynomial_size.0.is_power_of_two() {
+            return Err(Self::PolynomialSizeNotPowerOfTwo);
+        }
+
+        if polynomial_size.0 < 1 << (message_bits.0 + padding_bits.0 - 1) {
+            return Err(Self::PolynomialSizeTooSmall);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines generating accumulators for the programmable bootstrap.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext containing the
+/// trivial encryption of the lookup table of the function `f`, which can then be used as the
+/// accumulator of a programmable bootstrap.
+///
+/// # Formal Definition
+///
+/// The messages are assumed to be encoded on the `message_bits` bits lying right below the
+/// `padding_bits` most significant bits of the plaintext, such that the encoding of a message
+/// $m$ is $\Delta\cdot m$ with $\Delta = 2^{\log\_2(q) - \mathsf{message\\_bits} -
+/// \mathsf{padding\\_bits}}$. The values returned by `f` are encoded the same way.
+///
+/// The $N$ coefficients of the lookup table are split in $2^{\mathsf{message\\_bits} +
+/// \mathsf{padding\\_bits} - 1}$ boxes of consecutive coefficients, one for each value the
+/// message and the padding bits (except the most significant one) can take. Every coefficient of
+/// a box contains $\Delta\cdot f(m)$, where $m$ is the value of the message bits of the box, the
+/// padding bits being considered as carries to discard. The lookup table is then rotated by
+/// half a box in the negacyclic ring $\mathbb{Z}\_q\lbrack X\rbrack/(X^N+1)$, so that the noisy
+/// encryption of a message gets mapped to the middle of its box by the blind rotation.
+///
+/// The lookup table is then trivially encrypted, using a zero mask and no noise, in a GLWE
+/// ciphertext of dimension `glwe_dimension`.
+pub trait GlweCiphertextAccumulatorGenerationEngine<Accumulator, FunctionScalarType>:
+    AbstractEngine
+where
+    Accumulator: GlweCiphertextEntity,
+{
+    /// Generates the accumulator of a function.
+    fn generate_new_glwe_ciphertext_accumulator(
+        &mut self,
+        f: &dyn Fn(FunctionScalarType) -> FunctionScalarType,
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<Accumulator, GlweCiphertextAccumulatorGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates the accumulator of a function.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextAccumulatorGenerationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn generate_new_glwe_ciphertext_accumulator_unchecked(
+        &mut self,
+        f: &dyn Fn(FunctionScalarType) -> FunctionScalarType,
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Accumulator;
+}
@@ -0,0 +1,97 @@
+use super::engine_error;
+use crate::prelude::GaloisElement;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{GlweAutomorphismKeyEntity, GlweCiphertextEntity};
+
+engine_error! {
+    GlweCiphertextDiscardingAutomorphismError for GlweCiphertextDiscardingAutomorphismEngine @
+    GlweDimensionMismatch => "The input ciphertext, output ciphertext and automorphism key GLWE \
+                              dimensions must be the same.",
+    PolynomialSizeMismatch => "The input ciphertext, output ciphertext and automorphism key \
+                               polynomial sizes must be the same.",
+    UnsupportedGaloisElement => "The automorphism key only supports the Galois elements of the \
+                                 form 2^j + 1, with 1 <= j <= log2(N)."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingAutomorphismError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<AutomorphismKey, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        ak: &AutomorphismKey,
+        galois_element: GaloisElement,
+    ) -> Result<(), Self>
+    where
+        AutomorphismKey: GlweAutomorphismKeyEntity,
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if input.glwe_dimension() != ak.glwe_dimension()
+            || output.glwe_dimension() != ak.glwe_dimension()
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if input.polynomial_size() != ak.polynomial_size()
+            || output.polynomial_size() != ak.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        let poly_size = ak.polynomial_size().0;
+        let power = galois_element.0.wrapping_sub(1);
+        if !(power.is_power_of_two() && (2..=poly_size).contains(&power)) {
+            return Err(Self::UnsupportedGaloisElement);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines applying (discarding) Galois automorphisms to GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// an encryption of $M(X^k)$, where $M(X)$ is the polynomial encrypted by the `input` GLWE
+/// ciphertext and $k$ is the `galois_element`. The output is encrypted under the same key as the
+/// input.
+///
+/// # Formal Definition
+///
+/// Given an input $\mathsf{CT} = \left( \vec{A}(X), B(X) \right) \in
+/// \mathsf{GLWE}\_{\vec{S}}(M)$, the ciphertext $\left( \vec{A}(X^k), B(X^k) \right)$ encrypts
+/// $M(X^k)$ under the key $\vec{S}(X^k)$. It is then switched back to the key $\vec{S}$ using the
+/// key switching key stored in the
+/// [`GLWE automorphism key`](`crate::specification::entities::GlweAutomorphismKeyEntity`) for
+/// the element $k$.
+pub trait GlweCiphertextDiscardingAutomorphismEngine<
+    AutomorphismKey,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Applies a Galois automorphism to a GLWE ciphertext.
+    fn discard_automorphism_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ak: &AutomorphismKey,
+        galois_element: GaloisElement,
+    ) -> Result<(), GlweCiphertextDiscardingAutomorphismError<Self::EngineError>>;
+
+    /// Unsafely applies a Galois automorphism to a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingAutomorphismError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_automorphism_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ak: &AutomorphismKey,
+        galois_element: GaloisElement,
+    );
+}
@@ -0,0 +1,107 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GgswCiphertextVectorEntity, GlweCiphertextEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    GlweCiphertextDiscardingCmuxTreeError for GlweCiphertextDiscardingCmuxTreeEngine @
+    OutputPolynomialSizeMismatch => "The output ciphertext and GGSW ciphertexts polynomial sizes \
+                                     must be the same.",
+    OutputGlweDimensionMismatch => "The output ciphertext and GGSW ciphertexts GLWE dimensions \
+                                    must be the same.",
+    InvalidLookUpTablesSize => "The look-up tables must contain 2 to the power the number of GGSW \
+                                ciphertexts polynomials of the GGSW ciphertexts polynomial size."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingCmuxTreeError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<GgswCiphertextVector, LookUpTables, OutputCiphertext>(
+        output: &OutputCiphertext,
+        luts: &LookUpTables,
+        ggsw_vector: &GgswCiphertextVector,
+    ) -> Result<(), Self>
+    where
+        GgswCiphertextVector: GgswCiphertextVectorEntity,
+        LookUpTables: PlaintextVectorEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if output.polynomial_size() != ggsw_vector.polynomial_size() {
+            return Err(Self::OutputPolynomialSizeMismatch);
+        }
+        if output.glwe_dimension() != ggsw_vector.glwe_dimension() {
+            return Err(Self::OutputGlweDimensionMismatch);
+        }
+        let expected_lut_size = 1usize
+            .checked_shl(ggsw_vector.ggsw_ciphertext_count().0 as u32)
+            .and_then(|count| count.checked_mul(ggsw_vector.polynomial_size().0));
+        if expected_lut_size != Some(luts.plaintext_count().0) {
+            return Err(Self::InvalidLookUpTablesSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines evaluating (discarding) a tree of cmux selecting a look-up table with a
+/// vector of GGSW ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// an encryption of the look-up table polynomial selected by the bits encrypted in the
+/// `ggsw_vector` GGSW ciphertexts. The `luts` plaintext vector is read as a list of $2^k$
+/// polynomials, where $k$ is the number of GGSW ciphertexts in `ggsw_vector`. The GGSW
+/// ciphertexts are ordered from the most significant bit to the least significant bit of the
+/// index of the selected polynomial.
+///
+/// # Formal Definition
+///
+/// ## Cmux Tree
+/// ###### inputs:
+/// - $\left(\mathsf{CT}\_0, \cdots, \mathsf{CT}\_{k-1}\right)$: a vector of [`GGSW
+///   ciphertexts`](`GgswCiphertextVectorEntity`) with $\mathsf{CT}\_j \in
+///   \mathsf{GGSW}\_{\vec{S}}(b\_j)$ and $b\_j \in \\{0, 1\\}$
+/// - $\left(\mathsf{LUT}\_0, \cdots, \mathsf{LUT}\_{2^k-1}\right)$: $2^k$ look-up table
+///   polynomials stored in a [`plaintext vector`](`PlaintextVectorEntity`)
+///
+/// ###### outputs:
+/// - $\mathsf{ct}\_{\mathsf{out}} \in \mathsf{GLWE}\_{\vec{S}}(\mathsf{LUT}\_i)$: a [`GLWE
+///   ciphertext`](`GlweCiphertextEntity`) where $i = \sum\_{j=0}^{k-1} b\_j 2^{k-1-j}$
+///
+/// ###### algorithm:
+/// 1. Trivially encrypt every look-up table polynomial.
+/// 2. For $j$ from $k-1$ down to $0$, pair the ciphertexts of the current layer two by two, and
+/// replace each pair $\left(\mathsf{ct}\_{2l}, \mathsf{ct}\_{2l+1}\right)$ by the cmux
+/// $\mathsf{CT}\_j \boxdot \left(\mathsf{ct}\_{2l+1} - \mathsf{ct}\_{2l}\right) +
+/// \mathsf{ct}\_{2l}$.
+/// 3. Output the only ciphertext of the last layer.
+pub trait GlweCiphertextDiscardingCmuxTreeEngine<
+    GgswCiphertextVector,
+    LookUpTables,
+    OutputCiphertext,
+>: AbstractEngine where
+    GgswCiphertextVector: GgswCiphertextVectorEntity,
+    LookUpTables: PlaintextVectorEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Evaluates a cmux tree over the look-up tables.
+    fn discard_cmux_tree_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        luts: &LookUpTables,
+        ggsw_vector: &GgswCiphertextVector,
+    ) -> Result<(), GlweCiphertextDiscardingCmuxTreeError<Self::EngineError>>;
+
+    /// Unsafely evaluates a cmux tree over the look-up tables.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingCmuxTreeError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn discard_cmux_tree_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        luts: &LookUpTables,
+        ggsw_vector: &GgswCiphertextVector,
+    );
+}
@@ -0,0 +1,113 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{GlweCiphertextEntity, GlweKeyswitchKeyEntity};
+
+engine_error! {
+    GlweCiphertextDiscardingKeyswitchError for GlweCiphertextDiscardingKeyswitchEngine @
+    InputGlweDimensionMismatch => "The input ciphertext GLWE dimension and keyswitch key input \
+                                   GLWE dimensions must be the same.",
+    OutputGlweDimensionMismatch => "The output ciphertext GLWE dimension and keyswitch key output \
+                                    GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "The input ciphertext, output ciphertext and keyswitch key \
+                               polynomial sizes must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingKeyswitchError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<KeyswitchKey, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), Self>
+    where
+        KeyswitchKey: GlweKeyswitchKeyEntity,
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if input.glwe_dimension() != ksk.input_glwe_dimension() {
+            return Err(Self::InputGlweDimensionMismatch);
+        }
+        if output.glwe_dimension() != ksk.output_glwe_dimension() {
+            return Err(Self::OutputGlweDimensionMismatch);
+        }
+        if input.polynomial_size() != ksk.polynomial_size()
+            || output.polynomial_size() != ksk.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines keyswitching (discarding) GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the keyswitch of the `input` GLWE ciphertext, using the `ksk` GLWE keyswitch key.
+///
+/// # Formal Definition
+///
+/// ## GLWE Keyswitch
+///
+/// This homomorphic procedure transforms an input
+/// [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`)
+/// $\mathsf{CT}\_{\mathsf{in}} = \left( \vec{A}\_{\mathsf{in}} , B\_{\mathsf{in}}\right) \in
+/// \mathsf{GLWE}\_{\vec{S}\_{\mathsf{in}}}( \mathsf{PT} ) \subseteq
+/// \mathcal{R}\_q^{(k\_{\mathsf{in}}+1)}$ into an output
+/// [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`)
+/// $\mathsf{CT}\_{\mathsf{out}} = \left( \vec{A}\_{\mathsf{out}} , B\_{\mathsf{out}}\right) \in
+/// \mathsf{GLWE}\_{\vec{S}\_{\mathsf{out}}}( \mathsf{PT} ) \subseteq
+/// \mathcal{R}\_q^{(k\_{\mathsf{out}}+1)}$ where $k\_{\mathsf{in}} = |\vec{S}\_{\mathsf{in}}|$ and
+/// $k\_{\mathsf{out}} = |\vec{S}\_{\mathsf{out}}|$. It requires a
+/// [`GLWE key switching key`](`crate::specification::entities::GlweKeyswitchKeyEntity`).
+///
+/// ## Algorithm
+/// ###### inputs:
+/// - $\mathsf{CT}\_{\mathsf{in}} = \left( \vec{A}\_{\mathsf{in}} , B\_{\mathsf{in}}\right) \in
+///   \mathsf{GLWE}\_{\vec{S}\_{\mathsf{in}}}( \mathsf{PT} )$: a [`GLWE
+///   ciphertext`](`GlweCiphertextEntity`) with $\vec{A}\_{\mathsf{in}}=\left(A\_0, \cdots
+///   A\_{k\_{\mathsf{in}}-1}\right)$
+/// - $\mathsf{KSK}\_{\vec{S}\_{\mathsf{in}}\rightarrow \vec{S}\_{\mathsf{out}}}$: a
+/// [`GLWE key switching key`](`crate::specification::entities::GlweKeyswitchKeyEntity`)
+///
+/// ###### outputs:
+/// - $\mathsf{CT}\_{\mathsf{out}} \in \mathsf{GLWE}\_{\vec{S}\_{\mathsf{out}}} \left( \mathsf{PT}
+///   \right)$: a [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`)
+///
+/// ###### algorithm:
+/// 1. set $\mathsf{CT}=\left( 0 , \cdots , 0 ,  B\_{\mathsf{in}} \right) \in
+/// \mathcal{R}\_q^{(k\_{\mathsf{out}}+1)}$
+/// 2. compute $\mathsf{CT}\_{\mathsf{out}} = \mathsf{CT} -
+/// \sum\_{i=0}^{k\_{\mathsf{in}}-1} \mathsf{decompProduct}\left( A\_i , \overline{\mathsf{CT}\_i}
+/// \right)$
+/// 3. output $\mathsf{CT}\_{\mathsf{out}}$
+pub trait GlweCiphertextDiscardingKeyswitchEngine<KeyswitchKey, InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    KeyswitchKey: GlweKeyswitchKeyEntity,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Keyswitch a GLWE ciphertext.
+    fn discard_keyswitch_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), GlweCiphertextDiscardingKeyswitchError<Self::EngineError>>;
+
+    /// Unsafely keyswitch a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingKeyswitchError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn discard_keyswitch_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    );
+}
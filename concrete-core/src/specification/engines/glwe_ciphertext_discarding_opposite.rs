@@ -0,0 +1,66 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+
+engine_error! {
+    GlweCiphertextDiscardingOppositeError for GlweCiphertextDiscardingOppositeEngine @
+    GlweDimensionMismatch => "The input and output GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "The input and output polynomial sizes must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingOppositeError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the opposite (discarding) GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the opposite of the `input` GLWE ciphertext.
+///
+/// # Formal Definition
+///
+/// Given a GLWE ciphertext $\mathsf{CT} = \left( \vec{A}, B\right)$ encrypting $\mathsf{PT}$, the
+/// ciphertext $\left( -\vec{A}, -B\right)$ encrypts $-\mathsf{PT}$ under the same secret key.
+pub trait GlweCiphertextDiscardingOppositeEngine<InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Computes the opposite of a GLWE ciphertext.
+    fn discard_opp_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+    ) -> Result<(), GlweCiphertextDiscardingOppositeError<Self::EngineError>>;
+
+    /// Unsafely computes the opposite of a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingOppositeError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn discard_opp_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+    );
+}
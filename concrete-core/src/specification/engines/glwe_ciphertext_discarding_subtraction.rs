@@ -0,0 +1,77 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+
+engine_error! {
+    GlweCiphertextDiscardingSubtractionError for GlweCiphertextDiscardingSubtractionEngine @
+    GlweDimensionMismatch => "All the ciphertext GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "All the ciphertext polynomial sizes must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingSubtractionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if output.glwe_dimension() != input_1.glwe_dimension()
+            || output.glwe_dimension() != input_2.glwe_dimension()
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input_1.polynomial_size()
+            || output.polynomial_size() != input_2.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines subtracting (discarding) GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the subtraction of the `input_2` GLWE ciphertext from the `input_1` GLWE ciphertext.
+///
+/// # Formal Definition
+///
+/// The subtraction is computed polynomial-wise, as for the
+/// [`addition`](`crate::specification::engines::GlweCiphertextDiscardingAdditionEngine`): given
+/// two GLWE ciphertexts $\mathsf{CT}\_1 = \left( \vec{A}\_1, B\_1\right)$ and $\mathsf{CT}\_2 =
+/// \left( \vec{A}\_2, B\_2\right)$ encrypting $\mathsf{PT}\_1$ and $\mathsf{PT}\_2$ under the same
+/// secret key, the ciphertext $\left( \vec{A}\_1 - \vec{A}\_2, B\_1 - B\_2\right)$ encrypts
+/// $\mathsf{PT}\_1 - \mathsf{PT}\_2$.
+pub trait GlweCiphertextDiscardingSubtractionEngine<InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Subtracts two GLWE ciphertexts.
+    fn discard_sub_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+    ) -> Result<(), GlweCiphertextDiscardingSubtractionError<Self::EngineError>>;
+
+    /// Unsafely subtracts two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingSubtractionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_sub_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+    );
+}
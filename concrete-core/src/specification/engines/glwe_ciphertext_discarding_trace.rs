@@ -0,0 +1,89 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{GlweAutomorphismKeyEntity, GlweCiphertextEntity};
+
+engine_error! {
+    GlweCiphertextDiscardingTraceError for GlweCiphertextDiscardingTraceEngine @
+    GlweDimensionMismatch => "The input ciphertext, output ciphertext and automorphism key GLWE \
+                              dimensions must be the same.",
+    PolynomialSizeMismatch => "The input ciphertext, output ciphertext and automorphism key \
+                               polynomial sizes must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingTraceError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<AutomorphismKey, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        ak: &AutomorphismKey,
+    ) -> Result<(), Self>
+    where
+        AutomorphismKey: GlweAutomorphismKeyEntity,
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if input.glwe_dimension() != ak.glwe_dimension()
+            || output.glwe_dimension() != ak.glwe_dimension()
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if input.polynomial_size() != ak.polynomial_size()
+            || output.polynomial_size() != ak.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing (discarding) the homomorphic trace of GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the trace of the `input` GLWE ciphertext. If the input encrypts the polynomial $M(X)$, the
+/// output encrypts the constant polynomial $N \cdot M\_0$, every other coefficient being
+/// canceled.
+///
+/// Since the polynomial size $N$ is not invertible modulo a power of two, the $N$ factor can not
+/// be removed homomorphically. The input message is expected to be encoded with a scaling factor
+/// already divided by $N$.
+///
+/// # Formal Definition
+///
+/// For $N = 2^n$, the trace is computed with $n$ automorphisms:
+///
+/// ###### algorithm:
+/// 1. set $\mathsf{CT} = \mathsf{CT}\_{\mathsf{in}}$
+/// 2. for $j$ from $n$ down to $1$, set $\mathsf{CT} = \mathsf{CT} +
+/// \mathsf{Aut}\_{2^j+1}(\mathsf{CT})$
+/// 3. output $\mathsf{CT}$
+pub trait GlweCiphertextDiscardingTraceEngine<AutomorphismKey, InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Computes the trace of a GLWE ciphertext.
+    fn discard_trace_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ak: &AutomorphismKey,
+    ) -> Result<(), GlweCiphertextDiscardingTraceError<Self::EngineError>>;
+
+    /// Unsafely computes the trace of a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingTraceError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn discard_trace_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ak: &AutomorphismKey,
+    );
+}
@@ -0,0 +1,65 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+
+engine_error! {
+    GlweCiphertextFusingAdditionError for GlweCiphertextFusingAdditionEngine @
+    GlweDimensionMismatch => "The input and output GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "The input and output polynomial sizes must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextFusingAdditionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines adding (fusing) GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation adds the `input` GLWE ciphertext to the
+/// `output` GLWE ciphertext.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::GlweCiphertextDiscardingAdditionEngine`)
+pub trait GlweCiphertextFusingAdditionEngine<InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Adds a GLWE ciphertext to an other.
+    fn fuse_add_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+    ) -> Result<(), GlweCiphertextFusingAdditionError<Self::EngineError>>;
+
+    /// Unsafely adds a GLWE ciphertext to an other.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextFusingAdditionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn fuse_add_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+    );
+}
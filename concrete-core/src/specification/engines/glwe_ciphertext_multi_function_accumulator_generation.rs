@@ -0,0 +1,120 @@
+use super::engine_error;
+use crate::prelude::{GlweDimension, MessageBitsCount, PaddingBitsCount, PolynomialSize};
+
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+
+engine_error! {
+    GlweCiphertextMultiFunctionAccumulatorGenerationError for
+    GlweCiphertextMultiFunctionAccumulatorGenerationEngine @
+    NullMessageBitsCount => "The number of message bits must be greater than zero.",
+    NullPaddingBitsCount => "At least one bit of padding is required.",
+    BitsCountTooLarge => "The number of message and padding bits must not exceed the precision \
+                          of the ciphertext.",
+    FunctionCountNotPowerOfTwo => "The number of functions must be a non-zero power of two.",
+    PolynomialSizeNotPowerOfTwo => "The polynomial size must be a power of two.",
+    PolynomialSizeTooSmall => "The polynomial size must be greater or equal to the number of \
+                               boxes of the lookup table (2^(message bits + padding bits - 1)), \
+                               times twice the number of functions when there are several."
+}
+
+impl<EngineError: std::error::Error>
+    GlweCiphertextMultiFunctionAccumulatorGenerationError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        function_count: usize,
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        polynomial_size: PolynomialSize,
+        ciphertext_modulus_log: usize,
+    ) -> Result<(), Self> {
+        if message_bits.0 == 0 {
+            return Err(Self::NullMessageBitsCount);
+        }
+
+        if padding_bits.0 == 0 {
+            return Err(Self::NullPaddingBitsCount);
+        }
+
+        if message_bits.0 + padding_bits.0 > ciphertext_modulus_log {
+            return Err(Self::BitsCountTooLarge);
+        }
+
+        if !function_count.is_power_of_two() {
+            return Err(Self::FunctionCountNotPowerOfTwo);
+        }
+
+        if !polynomial_size.0.is_power_of_two() {
+            return Err(Self::PolynomialSizeNotPowerOfTwo);
+        }
+
+        let box_count = 1 << (message_bits.0 + padding_bits.0 - 1);
+        let minimal_box_size = if function_count == 1 {
+            1
+        } else {
+            2 * function_count
+        };
+        if polynomial_size.0 < box_count * minimal_box_size {
+            return Err(Self::PolynomialSizeTooSmall);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines generating accumulators packing several functions for the multi-value
+/// bootstrap.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext containing the
+/// trivial encryption of the lookup tables of the `functions`, interleaved so that it can be used
+/// as the accumulator of a
+/// [`multi-value bootstrap`](`crate::specification::engines::LweCiphertextMultiValueBootstrapEngine`)
+/// with $\mathsf{lut\\_count\\_log} = \log\_2(k)$, $k$ being the number of functions.
+///
+/// # Formal Definition
+///
+/// The messages and the values returned by the functions are encoded as in the
+/// [`accumulator generation`](`crate::specification::engines::GlweCiphertextAccumulatorGenerationEngine`),
+/// and the $N$ coefficients of the lookup table are split in the same boxes. Every coefficient of
+/// index $i$ of the box of the message $m$ contains $\Delta\cdot f\_j(m)$, where $j = i \bmod k$.
+/// The lookup table is then rotated by half a box in the negacyclic ring, which must be a multiple
+/// of $k$ for every coefficient to stay assigned to its function: when $k > 1$, the boxes must
+/// hold at least $2k$ coefficients.
+///
+/// The lookup table is then trivially encrypted, using a zero mask and no noise, in a GLWE
+/// ciphertext of dimension `glwe_dimension`.
+pub trait GlweCiphertextMultiFunctionAccumulatorGenerationEngine<Accumulator, FunctionScalarType>:
+    AbstractEngine
+where
+    Accumulator: GlweCiphertextEntity,
+{
+    /// Generates the accumulator of several functions.
+    fn generate_new_glwe_ciphertext_multi_function_accumulator(
+        &mut self,
+        functions: &[&dyn Fn(FunctionScalarType) -> FunctionScalarType],
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<
+        Accumulator,
+        GlweCiphertextMultiFunctionAccumulatorGenerationError<Self::EngineError>,
+    >;
+
+    /// Unsafely generates the accumulator of several functions.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextMultiFunctionAccumulatorGenerationError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn generate_new_glwe_ciphertext_multi_function_accumulator_unchecked(
+        &mut self,
+        functions: &[&dyn Fn(FunctionScalarType) -> FunctionScalarType],
+        message_bits: MessageBitsCount,
+        padding_bits: PaddingBitsCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Accumulator;
+}
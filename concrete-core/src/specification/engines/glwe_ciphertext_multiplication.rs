@@ -0,0 +1,105 @@
+use super::engine_error;
+use crate::prelude::DeltaLog;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweRelinearizationKeyEntity};
+
+engine_error! {
+    GlweCiphertextMultiplicationError for GlweCiphertextMultiplicationEngine @
+    GlweDimensionMismatch => "The GLWE dimensions of the input ciphertexts and of the \
+                              relinearization key must be the same.",
+    PolynomialSizeMismatch => "The polynomial sizes of the input ciphertexts and of the \
+                               relinearization key must be the same.",
+    DeltaLogTooLarge => "The scaling factor must not exceed the precision of the ciphertexts."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextMultiplicationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext1, InputCiphertext2, RelinearizationKey>(
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+        ciphertext_modulus_log: usize,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext1: GlweCiphertextEntity,
+        InputCiphertext2: GlweCiphertextEntity,
+        RelinearizationKey: GlweRelinearizationKeyEntity,
+    {
+        if input_1.glwe_dimension() != input_2.glwe_dimension()
+            || input_1.glwe_dimension() != relinearization_key.glwe_dimension()
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if input_1.polynomial_size() != input_2.polynomial_size()
+            || input_1.polynomial_size() != relinearization_key.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if delta_log.0 > ciphertext_modulus_log {
+            return Err(Self::DeltaLogTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext containing the
+/// product of the `input_1` and `input_2` GLWE ciphertexts, divided by $2^{\mathsf{delta\\_log}}$.
+///
+/// # Formal Definition
+///
+/// ## GLWE multiplication
+/// The multiplication is made of a
+/// [`tensor product`](`crate::specification::engines::GlweCiphertextTensorProductEngine`) of the
+/// inputs, followed by a relinearization using the `relinearization_key`.
+///
+/// Let $\left( (T\_{i,j})\_{0 \le i \le j < k}, (R\_i)\_{0 \le i < k}, B \right)$ be the tensor
+/// product of the inputs, and $\overline{\mathsf{CT}\_{i,j}} = \left(\mathsf{CT}\_{i,j,1}, \cdots,
+/// \mathsf{CT}\_{i,j,\ell}\right)$ the GLev ciphertexts of the
+/// [`relinearization key`](`crate::specification::entities::GlweRelinearizationKeyEntity`).
+///
+/// ###### algorithm:
+/// 1. Set $\mathsf{CT} = \left( (R\_i)\_{0 \le i < k}, B \right)$
+/// 2. For each $0 \le i \le j < k$, decompose $-T\_{i,j}$ into $\left(D\_{i,j,1}, \cdots,
+///    D\_{i,j,\ell}\right)$ with the base $\beta$ of the key
+/// 3. Compute $\mathsf{CT} = \mathsf{CT} + \sum\_{0 \le i \le j < k} \sum\_{l=1}^{\ell} D\_{i,j,l}
+///    \cdot \mathsf{CT}\_{i,j,l}$
+/// 4. Output $\mathsf{CT}$, a GLWE ciphertext encrypted under $\vec{S}$
+pub trait GlweCiphertextMultiplicationEngine<
+    InputCiphertext1,
+    InputCiphertext2,
+    RelinearizationKey,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext1: GlweCiphertextEntity,
+    InputCiphertext2: GlweCiphertextEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Multiplies two GLWE ciphertexts.
+    fn mul_glwe_ciphertext(
+        &mut self,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+    ) -> Result<OutputCiphertext, GlweCiphertextMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextMultiplicationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn mul_glwe_ciphertext_unchecked(
+        &mut self,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+    ) -> OutputCiphertext;
+}
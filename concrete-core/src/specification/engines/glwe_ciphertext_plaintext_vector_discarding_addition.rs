@@ -0,0 +1,85 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, PlaintextVectorEntity};
+
+engine_error! {
+    GlweCiphertextPlaintextVectorDiscardingAdditionError for
+    GlweCiphertextPlaintextVectorDiscardingAdditionEngine @
+    GlweDimensionMismatch => "The input and output GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "The input and output polynomial sizes must be the same.",
+    PlaintextCountMismatch => "The plaintext count must be the same as the ciphertext polynomial \
+                               size."
+}
+
+impl<EngineError: std::error::Error>
+    GlweCiphertextPlaintextVectorDiscardingAdditionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, PlaintextVector, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: GlweCiphertextEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if output.glwe_dimension() != input_1.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input_1.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if input_2.plaintext_count().0 != input_1.polynomial_size().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines adding (discarding) plaintext vectors to GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the addition of the `input_1` GLWE ciphertext with the `input_2` plaintext vector, interpreted
+/// as a polynomial.
+///
+/// # Formal Definition
+///
+/// The plaintext vector is interpreted as the plaintext polynomial $\mathsf{PT}' \in
+/// \mathcal{R}\_q$ whose coefficients are listed from the constant term. Given a GLWE ciphertext
+/// $\mathsf{CT} = \left( \vec{A}, B\right)$ encrypting $\mathsf{PT}$, the ciphertext
+/// $\left( \vec{A}, B + \mathsf{PT}'\right)$ encrypts $\mathsf{PT} + \mathsf{PT}'$ under the same
+/// secret key.
+pub trait GlweCiphertextPlaintextVectorDiscardingAdditionEngine<
+    InputCiphertext,
+    PlaintextVector,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext: GlweCiphertextEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Adds a plaintext vector to a GLWE ciphertext.
+    fn discard_add_glwe_ciphertext_plaintext_vector(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &PlaintextVector,
+    ) -> Result<(), GlweCiphertextPlaintextVectorDiscardingAdditionError<Self::EngineError>>;
+
+    /// Unsafely adds a plaintext vector to a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextPlaintextVectorDiscardingAdditionError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_add_glwe_ciphertext_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &PlaintextVector,
+    );
+}
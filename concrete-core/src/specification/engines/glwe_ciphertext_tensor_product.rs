@@ -0,0 +1,106 @@
+use super::engine_error;
+use crate::prelude::DeltaLog;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+
+engine_error! {
+    GlweCiphertextTensorProductError for GlweCiphertextTensorProductEngine @
+    GlweDimensionMismatch => "The GLWE dimensions of the input ciphertexts must be the same.",
+    PolynomialSizeMismatch => "The polynomial sizes of the input ciphertexts must be the same.",
+    DeltaLogTooLarge => "The scaling factor must not exceed the precision of the ciphertexts."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextTensorProductError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext1, InputCiphertext2>(
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        delta_log: DeltaLog,
+        ciphertext_modulus_log: usize,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext1: GlweCiphertextEntity,
+        InputCiphertext2: GlweCiphertextEntity,
+    {
+        if input_1.glwe_dimension() != input_2.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if input_1.polynomial_size() != input_2.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if delta_log.0 > ciphertext_modulus_log {
+            return Err(Self::DeltaLogTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the tensor product of GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext containing the
+/// tensor product of the `input_1` and `input_2` GLWE ciphertexts, divided by
+/// $2^{\mathsf{delta\\_log}}$.
+///
+/// If the inputs have a GLWE dimension $k$, the output has a GLWE dimension $\frac{k(k+3)}{2}$,
+/// and is encrypted under the tensored key described
+/// [`here`](`crate::specification::entities::GlweRelinearizationKeyEntity`).
+///
+/// # Formal Definition
+///
+/// ## GLWE tensor product
+/// Let two GLWE ciphertexts encrypted under the same
+/// [`GLWE secret key`](`crate::specification::entities::GlweSecretKeyEntity`)
+/// $\vec{S} \in \mathcal{R}\_q^k$:
+/// $$
+/// \begin{cases}
+/// \mathsf{CT}\_1 = \left( \vec{A}\_1, B\_1\right) \in \mathsf{GLWE}\_{\vec{S}} \left(
+/// \Delta\_1 \cdot \mathsf{M}\_1 \right) \\\\ \mathsf{CT}\_2 = \left( \vec{A}\_2, B\_2\right) \in
+/// \mathsf{GLWE}\_{\vec{S}} \left( \Delta\_2 \cdot \mathsf{M}\_2 \right) \end{cases} $$
+/// and a scaling factor $\Delta = 2^{\mathsf{delta\\_log}}$, usually $\min(\Delta\_1, \Delta\_2)$.
+///
+/// The polynomials of the ciphertexts are lifted to $\mathcal{R}$ with coefficients in
+/// $\left[-\frac{q}{2}, \frac{q}{2}\right[$, and multiplied together:
+///
+/// ###### outputs:
+/// - $T\_{i,i} = -\left\lfloor \frac{A\_{1,i} \cdot A\_{2,i}}{\Delta} \right\rceil$ for
+///   $0 \le i < k$
+/// - $T\_{i,j} = -\left\lfloor \frac{A\_{1,i} \cdot A\_{2,j} + A\_{1,j} \cdot A\_{2,i}}{\Delta}
+///   \right\rceil$ for $0 \le i < j < k$
+/// - $R\_i = \left\lfloor \frac{A\_{1,i} \cdot B\_2 + B\_1 \cdot A\_{2,i}}{\Delta} \right\rceil$
+///   for $0 \le i < k$
+/// - $B = \left\lfloor \frac{B\_1 \cdot B\_2}{\Delta} \right\rceil$
+///
+/// The output ciphertext $\left( (T\_{i,j})\_{0 \le i \le j < k}, (R\_i)\_{0 \le i < k}, B
+/// \right)$ encrypts $\frac{\Delta\_1 \cdot \Delta\_2}{\Delta} \cdot \mathsf{M}\_1 \cdot
+/// \mathsf{M}\_2$ under the tensored key $\left( (S\_i \cdot S\_j)\_{0 \le i \le j < k},
+/// (S\_i)\_{0 \le i < k} \right)$.
+pub trait GlweCiphertextTensorProductEngine<InputCiphertext1, InputCiphertext2, OutputCiphertext>:
+    AbstractEngine
+where
+    InputCiphertext1: GlweCiphertextEntity,
+    InputCiphertext2: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Computes the tensor product of two GLWE ciphertexts.
+    fn tensor_product_glwe_ciphertext(
+        &mut self,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        delta_log: DeltaLog,
+    ) -> Result<OutputCiphertext, GlweCiphertextTensorProductError<Self::EngineError>>;
+
+    /// Unsafely computes the tensor product of two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextTensorProductError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        delta_log: DeltaLog,
+    ) -> OutputCiphertext;
+}
@@ -0,0 +1,36 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweKeyswitchKeyEntity;
+
+engine_error! {
+    GlweKeyswitchKeyConversionError for GlweKeyswitchKeyConversionEngine @
+}
+
+/// A trait for engines converting GLWE keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE keyswitch key containing the
+/// conversion of the `input` GLWE keyswitch key to a type with a different representation (for
+/// instance from the standard to the Fourier domain).
+///
+/// # Formal Definition
+pub trait GlweKeyswitchKeyConversionEngine<Input, Output>: AbstractEngine
+where
+    Input: GlweKeyswitchKeyEntity,
+    Output: GlweKeyswitchKeyEntity,
+{
+    /// Converts a GLWE keyswitch key.
+    fn convert_glwe_keyswitch_key(
+        &mut self,
+        input: &Input,
+    ) -> Result<Output, GlweKeyswitchKeyConversionError<Self::EngineError>>;
+
+    /// Unsafely converts a GLWE keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweKeyswitchKeyConversionError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn convert_glwe_keyswitch_key_unchecked(&mut self, input: &Input) -> Output;
+}
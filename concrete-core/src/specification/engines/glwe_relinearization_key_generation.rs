@@ -0,0 +1,72 @@
+use super::engine_error;
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, Variance};
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweRelinearizationKeyEntity, GlweSecretKeyEntity};
+
+engine_error! {
+    GlweRelinearizationKeyGenerationError for GlweRelinearizationKeyGenerationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error> GlweRelinearizationKeyGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        ciphertext_modulus_log: usize,
+    ) -> Result<(), Self> {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > ciphertext_modulus_log {
+            return Err(Self::DecompositionTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new GLWE relinearization keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new GLWE relinearization key from
+/// the `secret_key` GLWE secret key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::GlweRelinearizationKeyEntity`)
+pub trait GlweRelinearizationKeyGenerationEngine<SecretKey, RelinearizationKey>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+{
+    /// Generates a new GLWE relinearization key.
+    fn generate_new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &SecretKey,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<RelinearizationKey, GlweRelinearizationKeyGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new GLWE relinearization key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweRelinearizationKeyGenerationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn generate_new_glwe_relinearization_key_unchecked(
+        &mut self,
+        secret_key: &SecretKey,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> RelinearizationKey;
+}
@@ -0,0 +1,92 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, Variance};
+use crate::specification::entities::{GlweSecretKeyEntity, GlweSeededKeyswitchKeyEntity};
+
+engine_error! {
+    GlweSeededKeyswitchKeyGenerationError for GlweSeededKeyswitchKeyGenerationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext.",
+    PolynomialSizeMismatch => "The input and output keys polynomial sizes must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweSeededKeyswitchKeyGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputSecretKey, OutputSecretKey>(
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        ciphertext_modulus_log: usize,
+    ) -> Result<(), Self>
+    where
+        InputSecretKey: GlweSecretKeyEntity,
+        OutputSecretKey: GlweSecretKeyEntity,
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > ciphertext_modulus_log {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        if input_key.polynomial_size() != output_key.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new seeded GLWE keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new seeded GLWE keyswitch key
+/// allowing to switch from the `input_key` GLWE secret key to the `output_key` GLWE secret key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::GlweSeededKeyswitchKeyEntity`)
+pub trait GlweSeededKeyswitchKeyGenerationEngine<
+    InputSecretKey,
+    OutputSecretKey,
+    SeededKeyswitchKey,
+>: AbstractEngine where
+    InputSecretKey: GlweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    SeededKeyswitchKey: GlweSeededKeyswitchKeyEntity,
+{
+    /// Generates a new seeded GLWE keyswitch key.
+    fn generate_new_glwe_seeded_keyswitch_key(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<SeededKeyswitchKey, GlweSeededKeyswitchKeyGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new seeded GLWE keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSeededKeyswitchKeyGenerationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn generate_new_glwe_seeded_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> SeededKeyswitchKey;
+}
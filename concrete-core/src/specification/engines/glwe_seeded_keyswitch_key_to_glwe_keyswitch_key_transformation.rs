@@ -0,0 +1,44 @@
+use super::engine_error;
+use crate::prelude::AbstractEngine;
+
+use crate::specification::entities::{GlweKeyswitchKeyEntity, GlweSeededKeyswitchKeyEntity};
+
+engine_error! {
+    GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationError for
+    GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationEngine @
+}
+
+/// A trait for engines transforming seeded GLWE keyswitch keys into GLWE keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation moves the existing seeded GLWE keyswitch key
+/// into a GLWE keyswitch key, by regenerating the masks of its ciphertexts from the seed.
+///
+/// # Formal Definition
+pub trait GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationEngine<InputKey, OutputKey>:
+    AbstractEngine
+where
+    InputKey: GlweSeededKeyswitchKeyEntity,
+    OutputKey: GlweKeyswitchKeyEntity,
+{
+    /// Does the transformation of the seeded GLWE keyswitch key into a GLWE keyswitch key
+    fn transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key(
+        &mut self,
+        glwe_seeded_keyswitch_key: InputKey,
+    ) -> Result<
+        OutputKey,
+        GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationError<Self::EngineError>,
+    >;
+
+    /// Unsafely transforms a seeded GLWE keyswitch key into a GLWE keyswitch key
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationError`].
+    /// For safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_unchecked(
+        &mut self,
+        glwe_seeded_keyswitch_key: InputKey,
+    ) -> OutputKey;
+}
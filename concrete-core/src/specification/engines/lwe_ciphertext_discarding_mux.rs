@@ -0,0 +1,98 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweBootstrapKeyEntity, LweCiphertextEntity, LweKeyswitchKeyEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingMuxError for LweCiphertextDiscardingMuxEngine @
+    LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    BootstrapKeyDimensionMismatch => "The bootstrap key input LWE dimension must be the same as \
+                                      the input LWE dimension.",
+    KeyswitchKeyDimensionMismatch => "The keyswitch key input LWE dimension must be the same as \
+                                      the bootstrap key output LWE dimension, and its output LWE \
+                                      dimension must be the same as the input LWE dimension."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingMuxError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, OutputCiphertext, BootstrapKey, KeyswitchKey>(
+        output: &OutputCiphertext,
+        select: &InputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity,
+        BootstrapKey: LweBootstrapKeyEntity,
+        KeyswitchKey: LweKeyswitchKeyEntity,
+    {
+        if output.lwe_dimension() != select.lwe_dimension()
+            || output.lwe_dimension() != input_1.lwe_dimension()
+            || output.lwe_dimension() != input_2.lwe_dimension()
+        {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if bsk.input_lwe_dimension() != input_1.lwe_dimension() {
+            return Err(Self::BootstrapKeyDimensionMismatch);
+        }
+        if ksk.input_lwe_dimension() != bsk.output_lwe_dimension()
+            || ksk.output_lwe_dimension() != input_1.lwe_dimension()
+        {
+            return Err(Self::KeyswitchKeyDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines evaluating the MUX boolean gate on LWE ciphertexts (discarding).
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the `input_1` LWE ciphertext if the `select` LWE ciphertext encrypts `true`, and with the
+/// `input_2` LWE ciphertext otherwise. The inputs are bootstrapped with the `bsk` bootstrap key,
+/// and the result is keyswitched back to the input key with the `ksk` keyswitch key.
+///
+/// Booleans are encoded as `q/8` for `true`, and `-q/8` for `false`.
+pub trait LweCiphertextDiscardingMuxEngine<
+    InputCiphertext,
+    OutputCiphertext,
+    BootstrapKey,
+    KeyswitchKey,
+>: AbstractEngine where
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+{
+    /// Evaluates the MUX gate on LWE ciphertexts.
+    fn discard_mux_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        select: &InputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), LweCiphertextDiscardingMuxError<Self::EngineError>>;
+
+    /// Unsafely evaluates the MUX gate on LWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingMuxError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn discard_mux_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        select: &InputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+    );
+}
@@ -0,0 +1,60 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextEntity;
+
+engine_error! {
+    LweCiphertextDiscardingNotError for LweCiphertextDiscardingNotEngine @
+    LweDimensionMismatch => "The input and output LWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingNotError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity,
+    {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines evaluating the NOT boolean gate on LWE ciphertexts (discarding).
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the NOT of the `input` LWE ciphertext.
+///
+/// Booleans are encoded as `q/8` for `true`, and `-q/8` for `false`, so that this operation does
+/// not require a bootstrap.
+pub trait LweCiphertextDiscardingNotEngine<InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Evaluates the NOT gate on an LWE ciphertext.
+    fn discard_not_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+    ) -> Result<(), LweCiphertextDiscardingNotError<Self::EngineError>>;
+
+    /// Unsafely evaluates the NOT gate on an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingNotError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn discard_not_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+    );
+}
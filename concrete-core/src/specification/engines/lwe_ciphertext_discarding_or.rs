@@ -0,0 +1,94 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweBootstrapKeyEntity, LweCiphertextEntity, LweKeyswitchKeyEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingOrError for LweCiphertextDiscardingOrEngine @
+    LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    BootstrapKeyDimensionMismatch => "The bootstrap key input LWE dimension must be the same as \
+                                      the input LWE dimension.",
+    KeyswitchKeyDimensionMismatch => "The keyswitch key input LWE dimension must be the same as \
+                                      the bootstrap key output LWE dimension, and its output LWE \
+                                      dimension must be the same as the input LWE dimension."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingOrError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, OutputCiphertext, BootstrapKey, KeyswitchKey>(
+        output: &OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity,
+        BootstrapKey: LweBootstrapKeyEntity,
+        KeyswitchKey: LweKeyswitchKeyEntity,
+    {
+        if output.lwe_dimension() != input_1.lwe_dimension()
+            || output.lwe_dimension() != input_2.lwe_dimension()
+        {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if bsk.input_lwe_dimension() != input_1.lwe_dimension() {
+            return Err(Self::BootstrapKeyDimensionMismatch);
+        }
+        if ksk.input_lwe_dimension() != bsk.output_lwe_dimension()
+            || ksk.output_lwe_dimension() != input_1.lwe_dimension()
+        {
+            return Err(Self::KeyswitchKeyDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines evaluating the OR boolean gate on LWE ciphertexts (discarding).
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the OR of the `input_1` and `input_2` LWE ciphertexts. The inputs are bootstrapped with the
+/// `bsk` bootstrap key, and the result is keyswitched back to the input key with the `ksk`
+/// keyswitch key.
+///
+/// Booleans are encoded as `q/8` for `true`, and `-q/8` for `false`.
+pub trait LweCiphertextDiscardingOrEngine<
+    InputCiphertext,
+    OutputCiphertext,
+    BootstrapKey,
+    KeyswitchKey,
+>: AbstractEngine where
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+{
+    /// Evaluates the OR gate on LWE ciphertexts.
+    fn discard_or_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), LweCiphertextDiscardingOrError<Self::EngineError>>;
+
+    /// Unsafely evaluates the OR gate on LWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingOrError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn discard_or_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+    );
+}
@@ -0,0 +1,119 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GgswCiphertextVectorEntity, LweCiphertextEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingVerticalPackingError for LweCiphertextDiscardingVerticalPackingEngine @
+    OutputLweDimensionMismatch => "The output ciphertext LWE dimension must be the same as the \
+                                   GGSW ciphertexts GLWE dimension times their polynomial size.",
+    MalformedLookUpTable => "The look-up table must contain a power of two number of polynomials \
+                             of the GGSW ciphertexts polynomial size.",
+    InvalidLookUpTableSize => "The look-up table must contain at most 2 to the power the number of \
+                               GGSW ciphertexts polynomials, and enough polynomials for the \
+                               remaining GGSW ciphertexts to index a single polynomial."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingVerticalPackingError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<GgswCiphertextVector, LookUpTable, OutputCiphertext>(
+        output: &OutputCiphertext,
+        lut: &LookUpTable,
+        ggsw_vector: &GgswCiphertextVector,
+    ) -> Result<(), Self>
+    where
+        GgswCiphertextVector: GgswCiphertextVectorEntity,
+        LookUpTable: PlaintextVectorEntity,
+        OutputCiphertext: LweCiphertextEntity,
+    {
+        let polynomial_size = ggsw_vector.polynomial_size().0;
+        if output.lwe_dimension().0 != ggsw_vector.glwe_dimension().0 * polynomial_size {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        let lut_size = lut.plaintext_count().0;
+        if lut_size % polynomial_size != 0 || !(lut_size / polynomial_size).is_power_of_two() {
+            return Err(Self::MalformedLookUpTable);
+        }
+        let cmux_tree_ggsw_count = (lut_size / polynomial_size).trailing_zeros() as usize;
+        let ggsw_count = ggsw_vector.ggsw_ciphertext_count().0;
+        if cmux_tree_ggsw_count > ggsw_count
+            || ggsw_count - cmux_tree_ggsw_count > polynomial_size.trailing_zeros() as usize
+        {
+            return Err(Self::InvalidLookUpTableSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines performing a (discarding) vertical packing, evaluating a look-up table on
+/// the bits encrypted in a vector of GGSW ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// an encryption of the `lut` coefficient indexed by the bits encrypted in the `ggsw_vector` GGSW
+/// ciphertexts. The GGSW ciphertexts are ordered from the most significant bit to the least
+/// significant bit of the index.
+///
+/// The `lut` plaintext vector is read as a list of $2^m$ polynomials of the GGSW polynomial size
+/// $N$. The $m$ most significant GGSW ciphertexts select a polynomial with a
+/// [`cmux tree`](`super::GlweCiphertextDiscardingCmuxTreeEngine`), and the $k - m$ remaining ones
+/// select a coefficient of this polynomial with a blind rotation. When the look-up table contains
+/// exactly $2^k$ values, the index is the integer whose bits are encrypted in `ggsw_vector`.
+///
+/// Since the GGSW ciphertexts are only read, the same vector can be used to evaluate several
+/// look-up tables.
+///
+/// # Formal Definition
+///
+/// ## Vertical Packing
+/// ###### inputs:
+/// - $\left(\mathsf{CT}\_0, \cdots, \mathsf{CT}\_{k-1}\right)$: a vector of [`GGSW
+///   ciphertexts`](`GgswCiphertextVectorEntity`) with $\mathsf{CT}\_j \in
+///   \mathsf{GGSW}\_{\vec{S}}(b\_j)$ and $b\_j \in \\{0, 1\\}$
+/// - $\mathsf{LUT}$: a look-up table of $2^m \cdot N$ values stored in a [`plaintext
+///   vector`](`PlaintextVectorEntity`), with $m \leq k$ and $2^{k-m} \leq N$
+///
+/// ###### outputs:
+/// - $\mathsf{ct}\_{\mathsf{out}} \in \mathsf{LWE}\_{\vec{s}}(\mathsf{LUT}\[p \cdot N + r\])$: an
+///   [`LWE ciphertext`](`LweCiphertextEntity`) where $p = \sum\_{j=0}^{m-1} b\_j 2^{m-1-j}$ and
+///   $r = \sum\_{j=m}^{k-1} b\_j 2^{k-1-j}$, and $\vec{s}$ is the LWE key obtained from
+///   $\vec{S}$
+///
+/// ###### algorithm:
+/// 1. Evaluate a cmux tree with $\left(\mathsf{CT}\_0, \cdots, \mathsf{CT}\_{m-1}\right)$ over
+/// the $2^m$ polynomials of $\mathsf{LUT}$, to get an encryption of the $p$-th polynomial.
+/// 2. Blind rotate this GLWE ciphertext by $X^{-r}$ with a cmux for each of the
+/// $\left(\mathsf{CT}\_m, \cdots, \mathsf{CT}\_{k-1}\right)$.
+/// 3. Sample extract the constant coefficient of the rotated GLWE ciphertext.
+pub trait LweCiphertextDiscardingVerticalPackingEngine<
+    GgswCiphertextVector,
+    LookUpTable,
+    OutputCiphertext,
+>: AbstractEngine where
+    GgswCiphertextVector: GgswCiphertextVectorEntity,
+    LookUpTable: PlaintextVectorEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Evaluates the look-up table on the bits encrypted in the GGSW ciphertexts.
+    fn discard_vertical_packing_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        lut: &LookUpTable,
+        ggsw_vector: &GgswCiphertextVector,
+    ) -> Result<(), LweCiphertextDiscardingVerticalPackingError<Self::EngineError>>;
+
+    /// Unsafely evaluates the look-up table on the bits encrypted in the GGSW ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingVerticalPackingError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_vertical_packing_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        lut: &LookUpTable,
+        ggsw_vector: &GgswCiphertextVector,
+    );
+}
@@ -0,0 +1,76 @@
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GswCiphertextEntity, LweCiphertextEntity};
+
+use super::engine_error;
+
+engine_error! {
+    LweCiphertextGswCiphertextExternalProductError for
+    LweCiphertextGswCiphertextExternalProductEngine @
+    LweDimensionMismatch => "The GSW ciphertext and LWE ciphertext LWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextGswCiphertextExternalProductError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<LweCiphertext, GswCiphertext>(
+        lwe_input: &LweCiphertext,
+        gsw_input: &GswCiphertext,
+    ) -> Result<(), Self>
+    where
+        LweCiphertext: LweCiphertextEntity,
+        GswCiphertext: GswCiphertextEntity,
+    {
+        if lwe_input.lwe_dimension() != gsw_input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the external product between an LWE ciphertext and a GSW
+/// ciphertext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext containing the
+/// result of the external product between a `lwe_input` LWE ciphertext and a `gsw_input` GSW
+/// ciphertext.
+///
+/// # Formal Definition
+///
+/// The external product takes an
+/// [`LWE ciphertext`](`crate::specification::entities::LweCiphertextEntity`)
+/// $\mathsf{ct} \in \mathsf{LWE}\_{\vec{s}}( \mathsf{pt}\_1 )$ and a
+/// [`GSW ciphertext`](`crate::specification::entities::GswCiphertextEntity`)
+/// $\overline{\overline{\mathsf{CT}}} \in \mathsf{GSW}\_{\vec{s}}^{\beta, \ell}( \mathsf{pt}\_2
+/// )$, and returns an LWE ciphertext $\mathsf{ct}\_{\mathsf{out}} \in \mathsf{LWE}\_{\vec{s}}(
+/// \mathsf{pt}\_1 \cdot \mathsf{pt}\_2 )$.
+///
+/// It is computed as the vector-matrix product between the signed decomposition of the input
+/// LWE ciphertext (in base $\beta$ with $\ell$ levels) and the GSW ciphertext, seen as a matrix
+/// whose rows are the LWE ciphertexts of its Lev ciphertexts.
+pub trait LweCiphertextGswCiphertextExternalProductEngine<LweInput, GswInput, Output>:
+    AbstractEngine
+where
+    LweInput: LweCiphertextEntity,
+    GswInput: GswCiphertextEntity,
+    Output: LweCiphertextEntity,
+{
+    /// Computes the external product between an LWE and a GSW ciphertext.
+    fn compute_external_product_lwe_ciphertext_gsw_ciphertext(
+        &mut self,
+        lwe_input: &LweInput,
+        gsw_input: &GswInput,
+    ) -> Result<Output, LweCiphertextGswCiphertextExternalProductError<Self::EngineError>>;
+
+    /// Unsafely computes the external product between an LWE and a GSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextGswCiphertextExternalProductError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn compute_external_product_lwe_ciphertext_gsw_ciphertext_unchecked(
+        &mut self,
+        lwe_input: &LweInput,
+        gsw_input: &GswInput,
+    ) -> Output;
+}
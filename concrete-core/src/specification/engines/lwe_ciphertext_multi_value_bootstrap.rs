@@ -0,0 +1,105 @@
+use super::engine_error;
+use crate::prelude::LutCountLog;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweCiphertextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextMultiValueBootstrapError for LweCiphertextMultiValueBootstrapEngine @
+    InputLweDimensionMismatch => "The input ciphertext and key LWE dimension must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and key polynomial sizes must be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and key GLWE dimensions must be the same.",
+    LutCountTooLarge => "The number of lookup tables must not exceed the polynomial size."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextMultiValueBootstrapError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<BootstrapKey, Accumulator, InputCiphertext>(
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        lut_count_log: LutCountLog,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        Accumulator: GlweCiphertextEntity,
+        InputCiphertext: LweCiphertextEntity,
+    {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        if lut_count_log.0 > acc.polynomial_size().log2().0 {
+            return Err(Self::LutCountTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines bootstrapping LWE ciphertexts with several lookup tables at once.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext vector containing
+/// the evaluations of the `2^lut_count_log` lookup tables packed in the `acc` accumulator on the
+/// `input` LWE ciphertext, using a single blind rotation with the `bsk` bootstrap key. Such an
+/// accumulator can be generated with the
+/// [`GlweCiphertextMultiFunctionAccumulatorGenerationEngine`](`crate::specification::engines::GlweCiphertextMultiFunctionAccumulatorGenerationEngine`).
+///
+/// # Formal Definition
+///
+/// This operation follows the same steps as the
+/// [`programmable bootstrap`](`crate::specification::engines::LweCiphertextDiscardingBootstrapEngine`),
+/// except that the input ciphertext is switched to $\mathbb{Z}\_{2N}$ with its $\ell =
+/// \mathsf{lut\\_count\\_log}$ least significant bits cleared. The rotation applied to the
+/// accumulator is then a multiple of $2^\ell$, which allows to pack $k = 2^\ell$ lookup tables
+/// in it: the lookup table $\mathsf{LUT}\_j$ is stored in the coefficients of index $j$ modulo
+/// $k$, for $j = 0, 1, \ldots, k-1$, each of its values being repeated over the coefficients of
+/// its box. After the blind rotation, the $j$-th output ciphertext is obtained by extracting the
+/// coefficient of degree $j$ of the accumulator, and encrypts $\mathsf{LUT}\_j(\mathsf{pt})$.
+///
+/// Since the input is rounded to a multiple of $2^\ell$, the $\ell$ bits of precision below the
+/// message must be left free in the input ciphertext, in addition to the usual margin required by
+/// the noise.
+pub trait LweCiphertextMultiValueBootstrapEngine<
+    BootstrapKey,
+    Accumulator,
+    InputCiphertext,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Bootstraps an LWE ciphertext with several lookup tables.
+    fn multi_value_bootstrap_lwe_ciphertext(
+        &mut self,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        lut_count_log: LutCountLog,
+    ) -> Result<OutputCiphertextVector, LweCiphertextMultiValueBootstrapError<Self::EngineError>>;
+
+    /// Unsafely bootstraps an LWE ciphertext with several lookup tables.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextMultiValueBootstrapError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn multi_value_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        lut_count_log: LutCountLog,
+    ) -> OutputCiphertextVector;
+}
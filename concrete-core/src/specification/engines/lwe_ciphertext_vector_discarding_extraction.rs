@@ -0,0 +1,80 @@
+use super::engine_error;
+use crate::prelude::{LweDimension, MonomialIndex};
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, LweCiphertextVectorEntity};
+
+engine_error! {
+    LweCiphertextVectorDiscardingExtractionError for LweCiphertextVectorDiscardingExtractionEngine @
+    SizeMismatch => "The sizes of the output LWE ciphertexts (LWE dimension) and the input GLWE \
+                     (GLWE dimension * poly size) must be compatible.",
+    MonomialIndexTooLarge => "The first monomial index plus the number of output LWE ciphertexts \
+                              must not exceed the GLWE polynomial size."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingExtractionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<GlweCiphertext, LweCiphertextVector>(
+        output: &LweCiphertextVector,
+        input: &GlweCiphertext,
+        first: MonomialIndex,
+    ) -> Result<(), Self>
+    where
+        GlweCiphertext: GlweCiphertextEntity,
+        LweCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if output.lwe_dimension()
+            != LweDimension(input.polynomial_size().0 * input.glwe_dimension().0)
+        {
+            return Err(Self::SizeMismatch);
+        }
+        if first
+            .0
+            .checked_add(output.lwe_ciphertext_count().0)
+            .map_or(true, |end| end > input.polynomial_size().0)
+        {
+            return Err(Self::MonomialIndexTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines extracting (discarding) LWE ciphertext vectors from GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext
+/// vector with the extraction of consecutive coefficients of the `input` GLWE ciphertext. The
+/// i-th LWE ciphertext of the vector receives the coefficient of index `first + i`, such that
+/// an output vector of `N` ciphertexts with `first` set to zero extracts every coefficient.
+///
+/// # Formal definition
+///
+/// This operation amounts to a _sample extract_ of every coefficient in the range
+/// `first..first + output.lwe_ciphertext_count()`.
+pub trait LweCiphertextVectorDiscardingExtractionEngine<GlweCiphertext, LweCiphertextVector>:
+    AbstractEngine
+where
+    GlweCiphertext: GlweCiphertextEntity,
+    LweCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Extracts an LWE ciphertext vector from a GLWE ciphertext.
+    fn discard_extract_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector,
+        input: &GlweCiphertext,
+        first: MonomialIndex,
+    ) -> Result<(), LweCiphertextVectorDiscardingExtractionError<Self::EngineError>>;
+
+    /// Unsafely extracts an LWE ciphertext vector from a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingExtractionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_extract_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector,
+        input: &GlweCiphertext,
+        first: MonomialIndex,
+    );
+}
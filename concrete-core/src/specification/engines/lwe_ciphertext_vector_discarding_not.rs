@@ -28,23 +28,37 @@ impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingNotError<Engin
     }
 }
 
+/// A trait for engines evaluating the NOT boolean gate on LWE ciphertext vectors (discarding).
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the element-wise NOT of the `input` LWE ciphertext vector.
+///
+/// Booleans are encoded as `q/8` for `true`, and `-q/8` for `false`, so that this operation does
+/// not require a bootstrap.
 pub trait LweCiphertextVectorDiscardingNotEngine<InputCiphertextVector, OutputCiphertextVector>:
     AbstractEngine
 where
     InputCiphertextVector: LweCiphertextVectorEntity,
     OutputCiphertextVector: LweCiphertextVectorEntity,
 {
+    /// Evaluates the NOT gate on an LWE ciphertext vector.
     fn discard_not_lwe_ciphertext_vector(
         &self,
         output: &mut OutputCiphertextVector,
         input: &InputCiphertextVector,
-        stream_idx: usize,
     ) -> Result<(), LweCiphertextVectorDiscardingNotError<Self::EngineError>>;
 
+    /// Unsafely evaluates the NOT gate on an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingNotError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
     unsafe fn discard_not_lwe_ciphertext_vector_unchecked(
         &self,
         output: &mut OutputCiphertextVector,
         input: &InputCiphertextVector,
-        stream_idx: usize,
     );
 }
@@ -0,0 +1,78 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LwePublicKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorDiscardingPublicKeyEncryptionError
+    for LweCiphertextVectorDiscardingPublicKeyEncryptionEngine @
+    LweDimensionMismatch => "The public key and output LWE dimensions must be the same.",
+    PlaintextCountMismatch => "The input plaintext count and the output ciphertext count must be \
+                               the same."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorDiscardingPublicKeyEncryptionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<PublicKey, PlaintextVector, CiphertextVector>(
+        key: &PublicKey,
+        output: &CiphertextVector,
+        input: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        PublicKey: LwePublicKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        CiphertextVector: LweCiphertextVectorEntity,
+    {
+        if key.lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if input.plaintext_count().0 != output.lwe_ciphertext_count().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines encrypting (discarding) LWE ciphertext vectors with a public key.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the element-wise encryption of the `input` plaintext vector, using the public `key`.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::LweCiphertextPublicKeyEncryptionEngine`)
+pub trait LweCiphertextVectorDiscardingPublicKeyEncryptionEngine<
+    PublicKey,
+    PlaintextVector,
+    CiphertextVector,
+>: AbstractEngine where
+    PublicKey: LwePublicKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    CiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Encrypts an LWE ciphertext vector using a public key.
+    fn discard_encrypt_lwe_ciphertext_vector_with_public_key(
+        &mut self,
+        key: &PublicKey,
+        output: &mut CiphertextVector,
+        input: &PlaintextVector,
+    ) -> Result<(), LweCiphertextVectorDiscardingPublicKeyEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts an LWE ciphertext vector using a public key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingPublicKeyEncryptionError`]. For safety concerns _specific_
+    /// to an engine, refer to the implementer safety section.
+    unsafe fn discard_encrypt_lwe_ciphertext_vector_with_public_key_unchecked(
+        &mut self,
+        key: &PublicKey,
+        output: &mut CiphertextVector,
+        input: &PlaintextVector,
+    );
+}
@@ -7,19 +7,33 @@ use crate::specification::entities::{
 engine_error! {
     LweCiphertextVectorDiscardingXnorError for LweCiphertextVectorDiscardingXnorEngine @
     LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
-    CiphertextCountMismatch => "The input and output ciphertext count must be the same."
+    CiphertextCountMismatch => "The input and output ciphertext count must be the same.",
+    BootstrapKeyDimensionMismatch => "The bootstrap key input LWE dimension must be the same as \
+                                      the input LWE dimension.",
+    KeyswitchKeyDimensionMismatch => "The keyswitch key input LWE dimension must be the same as \
+                                      the bootstrap key output LWE dimension, and its output LWE \
+                                      dimension must be the same as the input LWE dimension."
 }
 
 impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingXnorError<EngineError> {
     /// Validates the inputs
-    pub fn perform_generic_checks<OutputCiphertextVector, InputCiphertextVector>(
+    pub fn perform_generic_checks<
+        OutputCiphertextVector,
+        InputCiphertextVector,
+        BootstrapKey,
+        KeyswitchKey,
+    >(
         output: &OutputCiphertextVector,
         input_1: &InputCiphertextVector,
         input_2: &InputCiphertextVector,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
     ) -> Result<(), Self>
     where
         InputCiphertextVector: LweCiphertextVectorEntity,
         OutputCiphertextVector: LweCiphertextVectorEntity,
+        BootstrapKey: LweBootstrapKeyEntity,
+        KeyswitchKey: LweKeyswitchKeyEntity,
     {
         if output.lwe_dimension() != input_1.lwe_dimension() {
             return Err(Self::LweDimensionMismatch);
@@ -33,10 +47,28 @@ impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingXnorError<Engi
         if output.lwe_ciphertext_count() != input_2.lwe_ciphertext_count() {
             return Err(Self::CiphertextCountMismatch);
         }
+        if bsk.input_lwe_dimension() != input_1.lwe_dimension() {
+            return Err(Self::BootstrapKeyDimensionMismatch);
+        }
+        if ksk.input_lwe_dimension() != bsk.output_lwe_dimension()
+            || ksk.output_lwe_dimension() != input_1.lwe_dimension()
+        {
+            return Err(Self::KeyswitchKeyDimensionMismatch);
+        }
         Ok(())
     }
 }
 
+/// A trait for engines evaluating the XNOR boolean gate on LWE ciphertext vectors (discarding).
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the element-wise XNOR of the `input_1` and `input_2` LWE ciphertext vectors. The inputs are
+/// bootstrapped with the `bsk` bootstrap key, and the result is keyswitched back to the input key
+/// with the `ksk` keyswitch key.
+///
+/// Booleans are encoded as `q/8` for `true`, and `-q/8` for `false`.
 pub trait LweCiphertextVectorDiscardingXnorEngine<
     InputCiphertextVector,
     OutputCiphertextVector,
@@ -48,6 +80,7 @@ pub trait LweCiphertextVectorDiscardingXnorEngine<
     BootstrapKey: LweBootstrapKeyEntity,
     KeyswitchKey: LweKeyswitchKeyEntity,
 {
+    /// Evaluates the XNOR gate on LWE ciphertext vectors.
     fn discard_xnor_lwe_ciphertext_vector(
         &self,
         output: &mut OutputCiphertextVector,
@@ -55,9 +88,14 @@ pub trait LweCiphertextVectorDiscardingXnorEngine<
         input_2: &InputCiphertextVector,
         bsk: &BootstrapKey,
         ksk: &KeyswitchKey,
-        stream_idx: usize,
     ) -> Result<(), LweCiphertextVectorDiscardingXnorError<Self::EngineError>>;
 
+    /// Unsafely evaluates the XNOR gate on LWE ciphertext vectors.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingXnorError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
     unsafe fn discard_xnor_lwe_ciphertext_vector_unchecked(
         &self,
         output: &mut OutputCiphertextVector,
@@ -65,6 +103,5 @@ pub trait LweCiphertextVectorDiscardingXnorEngine<
         input_2: &InputCiphertextVector,
         bsk: &BootstrapKey,
         ksk: &KeyswitchKey,
-        stream_idx: usize,
     );
 }
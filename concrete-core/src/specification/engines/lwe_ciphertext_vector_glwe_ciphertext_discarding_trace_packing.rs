@@ -0,0 +1,112 @@
+use super::engine_error;
+use crate::prelude::{GlweAutomorphismKeyEntity, GlweCiphertextEntity};
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::LweCiphertextVectorEntity;
+
+engine_error! {
+    LweCiphertextVectorGlweCiphertextDiscardingTracePackingError for LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine @
+    InputLweDimensionMismatch => "The input ciphertext vector LWE dimension must be equal to the \
+                                  automorphism key GLWE dimension times its polynomial size.",
+    OutputGlweDimensionMismatch => "The output ciphertext and automorphism key GLWE dimensions \
+                                    must be the same.",
+    OutputPolynomialSizeMismatch => "The output ciphertext and automorphism key polynomial sizes \
+                                     must be the same.",
+    CiphertextCountMismatch => "The input ciphertext count is bigger than the output polynomial \
+                                size."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorGlweCiphertextDiscardingTracePackingError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<AutomorphismKey, InputCiphertextVector, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertextVector,
+        ak: &AutomorphismKey,
+    ) -> Result<(), Self>
+    where
+        AutomorphismKey: GlweAutomorphismKeyEntity,
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if input.lwe_dimension().0 != ak.glwe_dimension().0 * ak.polynomial_size().0 {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+
+        if output.glwe_dimension() != ak.glwe_dimension() {
+            return Err(Self::OutputGlweDimensionMismatch);
+        }
+
+        if output.polynomial_size() != ak.polynomial_size() {
+            return Err(Self::OutputPolynomialSizeMismatch);
+        }
+
+        if input.lwe_ciphertext_count().0 > output.polynomial_size().0 {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines packing (discarding) LWE ciphertext vectors into a GLWE ciphertext using
+/// homomorphic traces.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext
+/// with the packing of the `input` LWE ciphertext vector, using the `ak` automorphism key. The
+/// input ciphertexts must be encrypted under the LWE key obtained by flattening the GLWE key of
+/// the automorphism key.
+///
+/// Denoting $2^l$ the smallest power of two greater than or equal to the number of input
+/// ciphertexts, the message $m\_i$ of the $i$-th input ends up multiplied by the polynomial size
+/// $N$ in the coefficient of degree $i \cdot N / 2^l$ of the output, every other coefficient
+/// encrypting zero. When the input vector contains $N$ ciphertexts, the messages hence land in
+/// consecutive coefficients. As for the trace, the $N$ factor can not be removed homomorphically
+/// and must be accounted for in the encoding of the inputs.
+///
+/// Compared to the
+/// [`packing keyswitch`](`super::LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine`),
+/// this operation only requires $\log\_2(N)$ GLWE key switching keys.
+///
+/// # Formal Definition
+///
+/// Each input LWE ciphertext is first embedded into a GLWE ciphertext whose constant coefficient
+/// encrypts its message. The ciphertexts are then merged pairwise over $l$ levels, level $m$
+/// combining $\mathsf{CT}\_{\mathsf{even}}$ and $\mathsf{CT}\_{\mathsf{odd}}$ into
+/// $\mathsf{CT}\_{\mathsf{even}} + X^{N/2^m}\mathsf{CT}\_{\mathsf{odd}} +
+/// \mathsf{Aut}\_{2^m+1}\left(\mathsf{CT}\_{\mathsf{even}} -
+/// X^{N/2^m}\mathsf{CT}\_{\mathsf{odd}}\right)$. The result is finally traced down to the subring
+/// of polynomials in $X^{N/2^l}$, following Chen, Dai, Kim and Song, "Efficient Homomorphic
+/// Conversion Between (Ring) LWE Ciphertexts".
+pub trait LweCiphertextVectorGlweCiphertextDiscardingTracePackingEngine<
+    AutomorphismKey,
+    InputCiphertextVector,
+    OutputCiphertext,
+>: AbstractEngine where
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Packs an LWE ciphertext vector into a GLWE ciphertext using homomorphic traces.
+    fn discard_trace_packing_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        ak: &AutomorphismKey,
+    ) -> Result<(), LweCiphertextVectorGlweCiphertextDiscardingTracePackingError<Self::EngineError>>;
+
+    /// Unsafely packs an LWE ciphertext vector into a GLWE ciphertext using homomorphic traces.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorGlweCiphertextDiscardingTracePackingError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_trace_packing_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        ak: &AutomorphismKey,
+    );
+}
@@ -0,0 +1,46 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LwePublicKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorPublicKeyEncryptionError for LweCiphertextVectorPublicKeyEncryptionEngine @
+}
+
+/// A trait for engines encrypting LWE ciphertext vectors with a public key.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext vector containing
+/// the element-wise encryption of the `input` plaintext vector, using the public `key`.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::LweCiphertextPublicKeyEncryptionEngine`)
+pub trait LweCiphertextVectorPublicKeyEncryptionEngine<PublicKey, PlaintextVector, CiphertextVector>:
+    AbstractEngine
+where
+    PublicKey: LwePublicKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    CiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Encrypts an LWE ciphertext vector using a public key.
+    fn encrypt_lwe_ciphertext_vector_with_public_key(
+        &mut self,
+        key: &PublicKey,
+        input: &PlaintextVector,
+    ) -> Result<CiphertextVector, LweCiphertextVectorPublicKeyEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts an LWE ciphertext vector using a public key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorPublicKeyEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn encrypt_lwe_ciphertext_vector_with_public_key_unchecked(
+        &mut self,
+        key: &PublicKey,
+        input: &PlaintextVector,
+    ) -> CiphertextVector;
+}
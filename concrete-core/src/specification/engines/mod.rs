@@ -163,27 +163,60 @@ mod cleartext_vector_encoding;
 mod cleartext_vector_retrieval;
 mod encoder_creation;
 mod encoder_vector_creation;
+mod entity_aligned_deserialization;
+mod entity_aligned_serialization;
 mod entity_deserialization;
+mod entity_packed_serialization;
 mod entity_serialization;
+mod entity_stream_deserialization;
+mod entity_stream_serialization;
+mod entity_truncated_serialization;
 mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_conversion_gpu;
 mod ggsw_ciphertext_discarding_conversion;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod ggsw_ciphertext_vector_consuming_retrieval;
+mod ggsw_ciphertext_vector_conversion;
+mod ggsw_ciphertext_vector_creation;
+mod ggsw_ciphertext_vector_scalar_encryption;
+mod ggsw_seeded_ciphertext_conversion;
+mod ggsw_seeded_ciphertext_scalar_encryption;
+mod ggsw_seeded_ciphertext_to_ggsw_ciphertext_transformation;
+mod glwe_automorphism_key_conversion;
+mod glwe_automorphism_key_generation;
+mod glwe_ciphertext_accumulator_generation;
+mod glwe_ciphertext_cleartext_vector_discarding_multiplication;
+mod glwe_ciphertext_cleartext_vector_fusing_multiplication;
 mod glwe_ciphertext_consuming_retrieval;
 mod glwe_ciphertext_conversion;
 mod glwe_ciphertext_conversion_gpu;
 mod glwe_ciphertext_creation;
 mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_discarding_addition;
+mod glwe_ciphertext_discarding_automorphism;
+mod glwe_ciphertext_discarding_cmux_tree;
 mod glwe_ciphertext_discarding_conversion;
 mod glwe_ciphertext_discarding_conversion_gpu;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_keyswitch;
+mod glwe_ciphertext_discarding_opposite;
+mod glwe_ciphertext_discarding_subtraction;
+mod glwe_ciphertext_discarding_trace;
 mod glwe_ciphertext_discarding_trivial_encryption;
 mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_fusing_addition;
+mod glwe_ciphertext_fusing_opposite;
+mod glwe_ciphertext_fusing_subtraction;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
+mod glwe_ciphertext_multi_function_accumulator_generation;
+mod glwe_ciphertext_multiplication;
+mod glwe_ciphertext_plaintext_vector_discarding_addition;
+mod glwe_ciphertext_plaintext_vector_fusing_addition;
+mod glwe_ciphertext_tensor_product;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_consuming_retrieval;
@@ -201,6 +234,10 @@ mod glwe_ciphertext_vector_trivial_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
 mod glwe_ciphertexts_ggsw_ciphertext_fusing_cmux;
+mod glwe_keyswitch_key_conversion;
+mod glwe_keyswitch_key_generation;
+mod glwe_relinearization_key_conversion;
+mod glwe_relinearization_key_generation;
 mod glwe_secret_key_conversion;
 mod glwe_secret_key_discarding_conversion;
 mod glwe_secret_key_generation;
@@ -208,7 +245,10 @@ mod glwe_seeded_ciphertext_encryption;
 mod glwe_seeded_ciphertext_to_glwe_ciphertext_transformation;
 mod glwe_seeded_ciphertext_vector_encryption;
 mod glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector_transformation;
+mod glwe_seeded_keyswitch_key_generation;
+mod glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_transformation;
 mod glwe_to_lwe_secret_key_transformation;
+mod gsw_ciphertext_scalar_encryption;
 mod lwe_bootstrap_key_consuming_retrieval;
 mod lwe_bootstrap_key_conversion;
 mod lwe_bootstrap_key_conversion_gpu;
@@ -223,8 +263,10 @@ mod lwe_ciphertext_conversion_gpu;
 mod lwe_ciphertext_creation;
 mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
+mod lwe_ciphertext_discarding_and;
 mod lwe_ciphertext_discarding_bit_extraction;
 mod lwe_ciphertext_discarding_bit_extraction_gpu;
+mod lwe_ciphertext_discarding_blind_rotation;
 mod lwe_ciphertext_discarding_bootstrap;
 mod lwe_ciphertext_discarding_bootstrap_gpu;
 mod lwe_ciphertext_discarding_conversion;
@@ -235,19 +277,30 @@ mod lwe_ciphertext_discarding_extraction;
 mod lwe_ciphertext_discarding_keyswitch;
 mod lwe_ciphertext_discarding_keyswitch_gpu;
 mod lwe_ciphertext_discarding_loading;
+mod lwe_ciphertext_discarding_mux;
+mod lwe_ciphertext_discarding_nand;
+mod lwe_ciphertext_discarding_nor;
+mod lwe_ciphertext_discarding_not;
 mod lwe_ciphertext_discarding_opposite;
+mod lwe_ciphertext_discarding_or;
 mod lwe_ciphertext_discarding_public_key_encryption;
 mod lwe_ciphertext_discarding_storing;
 mod lwe_ciphertext_discarding_subtraction;
+mod lwe_ciphertext_discarding_vertical_packing;
+mod lwe_ciphertext_discarding_xnor;
+mod lwe_ciphertext_discarding_xor;
 mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_opposite;
 mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_gsw_ciphertext_external_product;
 mod lwe_ciphertext_loading;
+mod lwe_ciphertext_multi_value_bootstrap;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
 mod lwe_ciphertext_plaintext_fusing_subtraction;
+mod lwe_ciphertext_public_key_encryption;
 mod lwe_ciphertext_trivial_decryption;
 mod lwe_ciphertext_trivial_encryption;
 mod lwe_ciphertext_vector_cleartext_vector_discarding_multiplication;
@@ -270,6 +323,7 @@ mod lwe_ciphertext_vector_discarding_conversion;
 mod lwe_ciphertext_vector_discarding_conversion_gpu;
 mod lwe_ciphertext_vector_discarding_decryption;
 mod lwe_ciphertext_vector_discarding_encryption;
+mod lwe_ciphertext_vector_discarding_extraction;
 mod lwe_ciphertext_vector_discarding_keyswitch;
 mod lwe_ciphertext_vector_discarding_keyswitch_gpu;
 mod lwe_ciphertext_vector_discarding_loading;
@@ -278,6 +332,7 @@ mod lwe_ciphertext_vector_discarding_nor;
 mod lwe_ciphertext_vector_discarding_not;
 mod lwe_ciphertext_vector_discarding_opposite;
 mod lwe_ciphertext_vector_discarding_or;
+mod lwe_ciphertext_vector_discarding_public_key_encryption;
 mod lwe_ciphertext_vector_discarding_subtraction;
 mod lwe_ciphertext_vector_discarding_xnor;
 mod lwe_ciphertext_vector_discarding_xor;
@@ -288,13 +343,16 @@ mod lwe_ciphertext_vector_fusing_subtraction;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch_gpu;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_trace_packing;
 mod lwe_ciphertext_vector_loading;
 mod lwe_ciphertext_vector_plaintext_vector_discarding_addition;
 mod lwe_ciphertext_vector_plaintext_vector_discarding_addition_gpu;
+mod lwe_ciphertext_vector_public_key_encryption;
 mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
+mod lwe_ciphertexts_gsw_ciphertext_fusing_cmux;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_conversion;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_conversion_gpu;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
@@ -320,6 +378,8 @@ mod lwe_seeded_ciphertext_vector_encryption;
 mod lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation;
 mod lwe_seeded_keyswitch_key_generation;
 mod lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation;
+mod lwe_seeded_public_key_generation;
+mod lwe_seeded_public_key_to_lwe_public_key_transformation;
 mod lwe_to_glwe_secret_key_transformation;
 mod plaintext_conversion;
 mod plaintext_creation;
@@ -350,27 +410,60 @@ pub use cleartext_vector_encoding::*;
 pub use cleartext_vector_retrieval::*;
 pub use encoder_creation::*;
 pub use encoder_vector_creation::*;
+pub use entity_aligned_deserialization::*;
+pub use entity_aligned_serialization::*;
 pub use entity_deserialization::*;
+pub use entity_packed_serialization::*;
 pub use entity_serialization::*;
+pub use entity_stream_deserialization::*;
+pub use entity_stream_serialization::*;
+pub use entity_truncated_serialization::*;
 pub use ggsw_ciphertext_conversion::*;
 pub use ggsw_ciphertext_conversion_gpu::*;
 pub use ggsw_ciphertext_discarding_conversion::*;
 pub use ggsw_ciphertext_scalar_discarding_encryption::*;
 pub use ggsw_ciphertext_scalar_encryption::*;
 pub use ggsw_ciphertext_scalar_trivial_encryption::*;
+pub use ggsw_ciphertext_vector_consuming_retrieval::*;
+pub use ggsw_ciphertext_vector_conversion::*;
+pub use ggsw_ciphertext_vector_creation::*;
+pub use ggsw_ciphertext_vector_scalar_encryption::*;
+pub use ggsw_seeded_ciphertext_conversion::*;
+pub use ggsw_seeded_ciphertext_scalar_encryption::*;
+pub use ggsw_seeded_ciphertext_to_ggsw_ciphertext_transformation::*;
+pub use glwe_automorphism_key_conversion::*;
+pub use glwe_automorphism_key_generation::*;
+pub use glwe_ciphertext_accumulator_generation::*;
+pub use glwe_ciphertext_cleartext_vector_discarding_multiplication::*;
+pub use glwe_ciphertext_cleartext_vector_fusing_multiplication::*;
 pub use glwe_ciphertext_consuming_retrieval::*;
 pub use glwe_ciphertext_conversion::*;
 pub use glwe_ciphertext_conversion_gpu::*;
 pub use glwe_ciphertext_creation::*;
 pub use glwe_ciphertext_decryption::*;
+pub use glwe_ciphertext_discarding_addition::*;
+pub use glwe_ciphertext_discarding_automorphism::*;
+pub use glwe_ciphertext_discarding_cmux_tree::*;
 pub use glwe_ciphertext_discarding_conversion::*;
 pub use glwe_ciphertext_discarding_conversion_gpu::*;
 pub use glwe_ciphertext_discarding_decryption::*;
 pub use glwe_ciphertext_discarding_encryption::*;
+pub use glwe_ciphertext_discarding_keyswitch::*;
+pub use glwe_ciphertext_discarding_opposite::*;
+pub use glwe_ciphertext_discarding_subtraction::*;
+pub use glwe_ciphertext_discarding_trace::*;
 pub use glwe_ciphertext_discarding_trivial_encryption::*;
 pub use glwe_ciphertext_encryption::*;
+pub use glwe_ciphertext_fusing_addition::*;
+pub use glwe_ciphertext_fusing_opposite::*;
+pub use glwe_ciphertext_fusing_subtraction::*;
 pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product::*;
 pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
+pub use glwe_ciphertext_multi_function_accumulator_generation::*;
+pub use glwe_ciphertext_multiplication::*;
+pub use glwe_ciphertext_plaintext_vector_discarding_addition::*;
+pub use glwe_ciphertext_plaintext_vector_fusing_addition::*;
+pub use glwe_ciphertext_tensor_product::*;
 pub use glwe_ciphertext_trivial_decryption::*;
 pub use glwe_ciphertext_trivial_encryption::*;
 pub use glwe_ciphertext_vector_consuming_retrieval::*;
@@ -388,6 +481,10 @@ pub use glwe_ciphertext_vector_trivial_encryption::*;
 pub use glwe_ciphertext_vector_zero_encryption::*;
 pub use glwe_ciphertext_zero_encryption::*;
 pub use glwe_ciphertexts_ggsw_ciphertext_fusing_cmux::*;
+pub use glwe_keyswitch_key_conversion::*;
+pub use glwe_keyswitch_key_generation::*;
+pub use glwe_relinearization_key_conversion::*;
+pub use glwe_relinearization_key_generation::*;
 pub use glwe_secret_key_conversion::*;
 pub use glwe_secret_key_discarding_conversion::*;
 pub use glwe_secret_key_generation::*;
@@ -395,7 +492,10 @@ pub use glwe_seeded_ciphertext_encryption::*;
 pub use glwe_seeded_ciphertext_to_glwe_ciphertext_transformation::*;
 pub use glwe_seeded_ciphertext_vector_encryption::*;
 pub use glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector_transformation::*;
+pub use glwe_seeded_keyswitch_key_generation::*;
+pub use glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_transformation::*;
 pub use glwe_to_lwe_secret_key_transformation::*;
+pub use gsw_ciphertext_scalar_encryption::*;
 pub use lwe_bootstrap_key_consuming_retrieval::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_conversion_gpu::*;
@@ -410,8 +510,10 @@ pub use lwe_ciphertext_conversion_gpu::*;
 pub use lwe_ciphertext_creation::*;
 pub use lwe_ciphertext_decryption::*;
 pub use lwe_ciphertext_discarding_addition::*;
+pub use lwe_ciphertext_discarding_and::*;
 pub use lwe_ciphertext_discarding_bit_extraction::*;
 pub use lwe_ciphertext_discarding_bit_extraction_gpu::*;
+pub use lwe_ciphertext_discarding_blind_rotation::*;
 pub use lwe_ciphertext_discarding_bootstrap::*;
 pub use lwe_ciphertext_discarding_bootstrap_gpu::*;
 pub use lwe_ciphertext_discarding_conversion::*;
@@ -422,19 +524,30 @@ pub use lwe_ciphertext_discarding_extraction::*;
 pub use lwe_ciphertext_discarding_keyswitch::*;
 pub use lwe_ciphertext_discarding_keyswitch_gpu::*;
 pub use lwe_ciphertext_discarding_loading::*;
+pub use lwe_ciphertext_discarding_mux::*;
+pub use lwe_ciphertext_discarding_nand::*;
+pub use lwe_ciphertext_discarding_nor::*;
+pub use lwe_ciphertext_discarding_not::*;
 pub use lwe_ciphertext_discarding_opposite::*;
+pub use lwe_ciphertext_discarding_or::*;
 pub use lwe_ciphertext_discarding_public_key_encryption::*;
 pub use lwe_ciphertext_discarding_storing::*;
 pub use lwe_ciphertext_discarding_subtraction::*;
+pub use lwe_ciphertext_discarding_vertical_packing::*;
+pub use lwe_ciphertext_discarding_xnor::*;
+pub use lwe_ciphertext_discarding_xor::*;
 pub use lwe_ciphertext_encryption::*;
 pub use lwe_ciphertext_fusing_addition::*;
 pub use lwe_ciphertext_fusing_opposite::*;
 pub use lwe_ciphertext_fusing_subtraction::*;
+pub use lwe_ciphertext_gsw_ciphertext_external_product::*;
 pub use lwe_ciphertext_loading::*;
+pub use lwe_ciphertext_multi_value_bootstrap::*;
 pub use lwe_ciphertext_plaintext_discarding_addition::*;
 pub use lwe_ciphertext_plaintext_discarding_subtraction::*;
 pub use lwe_ciphertext_plaintext_fusing_addition::*;
 pub use lwe_ciphertext_plaintext_fusing_subtraction::*;
+pub use lwe_ciphertext_public_key_encryption::*;
 pub use lwe_ciphertext_trivial_decryption::*;
 pub use lwe_ciphertext_trivial_encryption::*;
 pub use lwe_ciphertext_vector_cleartext_vector_discarding_multiplication::*;
@@ -457,6 +570,7 @@ pub use lwe_ciphertext_vector_discarding_conversion::*;
 pub use lwe_ciphertext_vector_discarding_conversion_gpu::*;
 pub use lwe_ciphertext_vector_discarding_decryption::*;
 pub use lwe_ciphertext_vector_discarding_encryption::*;
+pub use lwe_ciphertext_vector_discarding_extraction::*;
 pub use lwe_ciphertext_vector_discarding_keyswitch::*;
 pub use lwe_ciphertext_vector_discarding_keyswitch_gpu::*;
 pub use lwe_ciphertext_vector_discarding_loading::*;
@@ -465,6 +579,7 @@ pub use lwe_ciphertext_vector_discarding_nor::*;
 pub use lwe_ciphertext_vector_discarding_not::*;
 pub use lwe_ciphertext_vector_discarding_opposite::*;
 pub use lwe_ciphertext_vector_discarding_or::*;
+pub use lwe_ciphertext_vector_discarding_public_key_encryption::*;
 pub use lwe_ciphertext_vector_discarding_subtraction::*;
 pub use lwe_ciphertext_vector_discarding_xnor::*;
 pub use lwe_ciphertext_vector_discarding_xor::*;
@@ -475,13 +590,16 @@ pub use lwe_ciphertext_vector_fusing_subtraction::*;
 pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch::*;
 pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch::*;
 pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch_gpu::*;
+pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_trace_packing::*;
 pub use lwe_ciphertext_vector_loading::*;
 pub use lwe_ciphertext_vector_plaintext_vector_discarding_addition::*;
 pub use lwe_ciphertext_vector_plaintext_vector_discarding_addition_gpu::*;
+pub use lwe_ciphertext_vector_public_key_encryption::*;
 pub use lwe_ciphertext_vector_trivial_decryption::*;
 pub use lwe_ciphertext_vector_trivial_encryption::*;
 pub use lwe_ciphertext_vector_zero_encryption::*;
 pub use lwe_ciphertext_zero_encryption::*;
+pub use lwe_ciphertexts_gsw_ciphertext_fusing_cmux::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_conversion::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_conversion_gpu::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation::*;
@@ -507,6 +625,8 @@ pub use lwe_seeded_ciphertext_vector_encryption::*;
 pub use lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation::*;
 pub use lwe_seeded_keyswitch_key_generation::*;
 pub use lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation::*;
+pub use lwe_seeded_public_key_generation::*;
+pub use lwe_seeded_public_key_to_lwe_public_key_transformation::*;
 pub use lwe_to_glwe_secret_key_transformation::*;
 pub use plaintext_conversion::*;
 pub use plaintext_creation::*;
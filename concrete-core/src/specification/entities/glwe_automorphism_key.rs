@@ -0,0 +1,35 @@
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::GlweAutomorphismKeyKind;
+use crate::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a GLWE automorphism key.
+///
+/// # Formal Definition
+///
+/// ## GLWE Automorphism Key
+///
+/// For a polynomial size $N = 2^n$, a GLWE automorphism key is a vector of $n$
+/// [`GLWE key switching keys`](`crate::specification::entities::GlweKeyswitchKeyEntity`). For
+/// every $1 \le j \le n$, the key switching key of index $j$ switches from the key
+/// $\vec{S}(X^{2^j+1})$ to the key $\vec{S}(X)$, where $\vec{S}$ is a
+/// [`GLWE secret key`](`crate::specification::entities::GlweSecretKeyEntity`):
+///
+/// $$\mathsf{AK}\_{\vec{S}} = \left( \mathsf{KSK}\_{\vec{S}(X^{3})\rightarrow \vec{S}}, \cdots,
+/// \mathsf{KSK}\_{\vec{S}(X^{N+1})\rightarrow \vec{S}} \right)$$
+///
+/// These are the automorphisms needed to evaluate the trace of a GLWE ciphertext.
+pub trait GlweAutomorphismKeyEntity: AbstractEntity<Kind = GlweAutomorphismKeyKind> {
+    /// Returns the GLWE dimension of the key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
@@ -0,0 +1,41 @@
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::GlweKeyswitchKeyKind;
+use crate::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a GLWE keyswitch key.
+///
+/// # Formal Definition
+///
+/// ## GLWE Key Switching Key
+///
+/// A GLWE key switching key is a vector of GLev ciphertexts. It encrypts the polynomials of the
+/// [`GLWE secret key`](`crate::specification::entities::GlweSecretKeyEntity`)
+/// $\vec{S}\_{\mathsf{in}}$ under the
+/// [`GLWE secret key`](`crate::specification::entities::GlweSecretKeyEntity`)
+/// $\vec{S}\_{\mathsf{out}}$, both keys sharing the same polynomial size $N$.
+///
+/// $$\mathsf{KSK}\_{\vec{S}\_{\mathsf{in}}\rightarrow \vec{S}\_{\mathsf{out}}} = \left(
+/// \overline{\mathsf{CT}\_0}, \cdots , \overline{\mathsf{CT}\_{k\_{\mathsf{in}}-1}}\right)
+/// \subseteq \mathcal{R}\_q^{(k\_{\mathsf{out}}+1)\cdot \ell \cdot k\_{\mathsf{in}}}$$
+///
+/// where $\vec{S}\_{\mathsf{in}} = \left( S\_0 , \cdots , S\_{k\_{\mathsf{in}}-1} \right)$ and for
+/// all $0\le i <k\_{\mathsf{in}}$ we have $\overline{\mathsf{CT}\_i} \in
+/// \mathsf{GLev}\_{\vec{S}\_{\mathsf{out}}}^{\beta, \ell}\left(S\_i\right)$.
+pub trait GlweKeyswitchKeyEntity: AbstractEntity<Kind = GlweKeyswitchKeyKind> {
+    /// Returns the input GLWE dimension of the key.
+    fn input_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the output GLWE dimension of the key.
+    fn output_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
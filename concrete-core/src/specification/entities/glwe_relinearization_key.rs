@@ -0,0 +1,39 @@
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::GlweRelinearizationKeyKind;
+use crate::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a GLWE relinearization key.
+///
+/// # Formal Definition
+///
+/// ## Relinearization Key
+/// The tensor product of two GLWE ciphertexts encrypted under the
+/// [`GLWE secret key`](`crate::specification::entities::GlweSecretKeyEntity`)
+/// $\vec{S}=\left(S\_0, \cdots, S\_{k-1} \right) \in\mathcal{R}\_q^k$ is a GLWE ciphertext
+/// encrypted under the tensored key, made of the $\frac{k(k+1)}{2}$ products $S\_i \cdot S\_j$
+/// for $0 \le i \le j < k$, followed by the $k$ polynomials of $\vec{S}$.
+///
+/// A relinearization key is a vector of $\frac{k(k+1)}{2}$ GLev ciphertexts, each encrypting one
+/// of the products $S\_i \cdot S\_j$ under $\vec{S}$:
+///
+/// $$\mathsf{RLK}\_{\vec{S}} = \left( \overline{\mathsf{CT}\_{i,j}} \right)\_{0 \le i \le j < k}
+/// \quad \mathsf{with} \quad \overline{\mathsf{CT}\_{i,j}} \in \mathsf{GLev}\_{\vec{S}}^{\beta,
+/// \ell}\left(S\_i \cdot S\_j\right) \subseteq \mathcal{R}\_q^{\ell \cdot (k+1)}$$
+///
+/// It is used to switch the quadratic part of a tensor product back to a GLWE ciphertext
+/// encrypted under $\vec{S}$.
+pub trait GlweRelinearizationKeyEntity: AbstractEntity<Kind = GlweRelinearizationKeyKind> {
+    /// Returns the GLWE dimension of the key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+}
@@ -0,0 +1,25 @@
+use crate::commons::math::random::CompressionSeed;
+use crate::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount};
+use crate::specification::entities::markers::LweSeededPublicKeyKind;
+use crate::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a seeded LWE public key.
+///
+/// A seeded LWE public key is a compressed version of a regular LWE public key, whose encryptions
+/// of 0 are stored as seeded LWE ciphertexts. Only the bodies of the encryptions and the seed used
+/// to generate their masks are stored, which makes the seeded public key much lighter to
+/// distribute. It can then be decompressed into a regular LWE public key to encrypt data.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::LwePublicKeyEntity`)
+pub trait LweSeededPublicKeyEntity: AbstractEntity<Kind = LweSeededPublicKeyKind> {
+    /// Returns the LWE dimension of the key.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of LWE encryption of 0 in the key.
+    fn lwe_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount;
+
+    /// Returns the seed used to compress the LWE encryptions of 0 in the key.
+    fn compression_seed(&self) -> CompressionSeed;
+}
@@ -62,6 +62,8 @@ entity_kind_marker! {
             => "An empty type representing the GSW ciphertext vector kind in the type system.",
         LwePublicKeyKind
             => "An empty type representing the LWE public key kind in the type system.",
+        LweSeededPublicKeyKind
+            => "An empty type representing the seeded LWE public key kind in the type system.",
         LweSecretKeyKind
             => "An empty type representing the LWE secret key kind in the type system.",
         GlweSecretKeyKind
@@ -82,6 +84,14 @@ entity_kind_marker! {
             => "An empty type representing the LWE bootstrap key kind in the type system.",
         LweSeededBootstrapKeyKind
             => "An empty type representing the seeded LWE bootstrap key kind in the type system.",
+        GlweAutomorphismKeyKind
+            => "An empty type representing the GLWE automorphism key kind in the type system.",
+        GlweKeyswitchKeyKind
+            => "An empty type representing the GLWE keyswitch key kind in the type system.",
+        GlweSeededKeyswitchKeyKind
+            => "An empty type representing the seeded GLWE keyswitch key kind in the type system.",
+        GlweRelinearizationKeyKind
+            => "An empty type representing the GLWE relinearization key kind in the type system.",
         EncoderKind
             => "An empty type representing the encoder kind in the type system.",
         EncoderVectorKind
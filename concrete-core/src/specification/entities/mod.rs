@@ -41,11 +41,15 @@ mod encoder_vector;
 mod ggsw_ciphertext;
 mod ggsw_ciphertext_vector;
 mod ggsw_seeded_ciphertext;
+mod glwe_automorphism_key;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
+mod glwe_keyswitch_key;
+mod glwe_relinearization_key;
 mod glwe_secret_key;
 mod glwe_seeded_ciphertext;
 mod glwe_seeded_ciphertext_vector;
+mod glwe_seeded_keyswitch_key;
 mod gsw_ciphertext;
 mod gsw_ciphertext_vector;
 mod lwe_bootstrap_key;
@@ -61,6 +65,7 @@ mod lwe_seeded_bootstrap_key;
 mod lwe_seeded_ciphertext;
 mod lwe_seeded_ciphertext_vector;
 mod lwe_seeded_keyswitch_key;
+mod lwe_seeded_public_key;
 mod plaintext;
 mod plaintext_vector;
 
@@ -71,11 +76,15 @@ pub use encoder_vector::*;
 pub use ggsw_ciphertext::*;
 pub use ggsw_ciphertext_vector::*;
 pub use ggsw_seeded_ciphertext::*;
+pub use glwe_automorphism_key::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
+pub use glwe_keyswitch_key::*;
+pub use glwe_relinearization_key::*;
 pub use glwe_secret_key::*;
 pub use glwe_seeded_ciphertext::*;
 pub use glwe_seeded_ciphertext_vector::*;
+pub use glwe_seeded_keyswitch_key::*;
 pub use gsw_ciphertext::*;
 pub use gsw_ciphertext_vector::*;
 pub use lwe_bootstrap_key::*;
@@ -91,5 +100,6 @@ pub use lwe_seeded_bootstrap_key::*;
 pub use lwe_seeded_ciphertext::*;
 pub use lwe_seeded_ciphertext_vector::*;
 pub use lwe_seeded_keyswitch_key::*;
+pub use lwe_seeded_public_key::*;
 pub use plaintext::*;
 pub use plaintext_vector::*;
@@ -160,6 +160,11 @@ pub struct MonomialDegree(pub usize);
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
 pub struct MonomialIndex(pub usize);
 
+/// The odd integer $k$ defining the Galois automorphism $X \mapsto X^k$ of a polynomial ring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+pub struct GaloisElement(pub usize);
+
 /// The logarithm of the base used in a decomposition.
 ///
 /// When decomposing an integer over powers of the $2^B$ basis, this type represents the $B$ value.
@@ -204,6 +209,11 @@ pub struct ExtractedBitsCount(pub usize);
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
 pub struct MessageBitsCount(pub usize);
 
+/// The number of bits of padding above the message in a ciphertext.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+pub struct PaddingBitsCount(pub usize);
+
 /// The number of functional packing keyswitch key in a functional packing keyswitch key list.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
@@ -213,3 +223,14 @@ pub struct FunctionalPackingKeyswitchKeyCount(pub usize);
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
 pub struct CiphertextModulusLog(pub usize);
+
+/// The number of least significant bits dropped from the body of an entity serialized in a
+/// truncated form.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+pub struct TruncatedBitCount(pub usize);
+
+/// Whether the checksum of a serialized entity is verified when deserializing it.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+pub struct ChecksumVerification(pub bool);